@@ -0,0 +1,90 @@
+// assets/shaders/character.wgsl
+//
+// `CharacterMaterial` fragment stage: standard PBR plus a damage flash (mix toward
+// `flash_color`), a noise dissolve that discards fragments and glows at the edge, and
+// optional toon shading (lit luminance quantized into `toon_bands` steps).
+
+#import bevy_pbr::{
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::alpha_discard,
+}
+
+#ifdef PREPASS_PIPELINE
+#import bevy_pbr::{
+    prepass_io::{VertexOutput, FragmentOutput},
+    pbr_deferred_functions::deferred_output,
+}
+#else
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing},
+}
+#endif
+
+struct CharacterFxUniform {
+    flash_color: vec4<f32>,
+    edge_color: vec4<f32>,
+    flash: f32,
+    dissolve: f32,
+    edge_width: f32,
+    noise_scale: f32,
+    toon_bands: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> fx: CharacterFxUniform;
+
+fn hash3(p: vec3<f32>) -> f32 {
+    return fract(sin(dot(p, vec3<f32>(12.9898, 78.233, 37.719))) * 43758.5453);
+}
+
+// Trilinear value noise in 0..1.
+fn value_noise(p: vec3<f32>) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    let x00 = mix(hash3(i), hash3(i + vec3(1.0, 0.0, 0.0)), u.x);
+    let x10 = mix(hash3(i + vec3(0.0, 1.0, 0.0)), hash3(i + vec3(1.0, 1.0, 0.0)), u.x);
+    let x01 = mix(hash3(i + vec3(0.0, 0.0, 1.0)), hash3(i + vec3(1.0, 0.0, 1.0)), u.x);
+    let x11 = mix(hash3(i + vec3(0.0, 1.0, 1.0)), hash3(i + vec3(1.0, 1.0, 1.0)), u.x);
+    return mix(mix(x00, x10, u.y), mix(x01, x11, u.y), u.z);
+}
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+    let noise = value_noise(in.world_position.xyz * fx.noise_scale);
+    if fx.dissolve > 0.0 && noise < fx.dissolve {
+        discard;
+    }
+
+    pbr_input.material.base_color = alpha_discard(pbr_input.material, pbr_input.material.base_color);
+    pbr_input.material.base_color = vec4(
+        mix(pbr_input.material.base_color.rgb, fx.flash_color.rgb, fx.flash),
+        pbr_input.material.base_color.a,
+    );
+    pbr_input.material.emissive = vec4(
+        pbr_input.material.emissive.rgb + fx.flash_color.rgb * fx.flash,
+        pbr_input.material.emissive.a,
+    );
+    if fx.dissolve > 0.0 && noise < fx.dissolve + fx.edge_width {
+        pbr_input.material.emissive = vec4(fx.edge_color.rgb * 4.0, 1.0);
+    }
+
+#ifdef PREPASS_PIPELINE
+    let out = deferred_output(in, pbr_input);
+#else
+    var out: FragmentOutput;
+    out.color = apply_pbr_lighting(pbr_input);
+    if fx.toon_bands > 0.0 {
+        let luminance = dot(out.color.rgb, vec3(0.2126, 0.7152, 0.0722));
+        let banded = ceil(luminance * fx.toon_bands) / fx.toon_bands;
+        out.color = vec4(out.color.rgb * (banded / max(luminance, 1e-4)), out.color.a);
+    }
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+#endif
+    return out;
+}
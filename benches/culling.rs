@@ -0,0 +1,72 @@
+// benches/culling.rs
+//
+// Benchmark scene for chunk culling.
+//
+// Run with:
+//   cargo bench --bench culling
+//
+// Each case scatters N props over a square map and runs chunk assignment +
+// culling with the camera at the center. Before measuring, the case prints the
+// `CullingStats` of one frame, so the report shows how many chunk contents are
+// still drawn (and thus submitted as draw calls) versus the whole scene.
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use to_be_free::features::culling::component::{ChunkCulling, Cullable, CullingStats};
+use to_be_free::features::culling::systems::{assign_chunks, cull_chunks};
+
+/// Props to scatter; the map grows with the count so density stays constant.
+const PROP_COUNTS: [usize; 3] = [1_000, 10_000, 50_000];
+
+/// Props per square world unit.
+const DENSITY: f32 = 0.05;
+
+fn build_world(props: usize) -> (World, Schedule) {
+    let mut world = World::new();
+    world.init_resource::<ChunkCulling>();
+    world.init_resource::<CullingStats>();
+    world.spawn((Camera3d::default(), GlobalTransform::IDENTITY));
+
+    let side = (props as f32 / DENSITY).sqrt();
+    let per_row = (props as f32).sqrt().ceil() as usize;
+    world.spawn_batch((0..props).map(move |i| {
+        let (row, col) = (i / per_row, i % per_row);
+        let position = Vec3::new(
+            (col as f32 / per_row as f32 - 0.5) * side,
+            0.0,
+            (row as f32 / per_row as f32 - 0.5) * side,
+        );
+        (Cullable::default(), GlobalTransform::from_translation(position))
+    }));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((assign_chunks, cull_chunks).chain());
+    (world, schedule)
+}
+
+fn chunk_culling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chunk_culling");
+
+    for &props in &PROP_COUNTS {
+        let (mut world, mut schedule) = build_world(props);
+        schedule.run(&mut world);
+        let stats = *world.resource::<CullingStats>();
+        println!(
+            "{props} props: {} / {} chunks visible, {} / {} entities drawn ({:.0}% culled)",
+            stats.visible_chunks,
+            stats.chunks,
+            stats.drawn(),
+            stats.entities,
+            100.0 * stats.hidden as f32 / stats.entities.max(1) as f32,
+        );
+
+        group.throughput(Throughput::Elements(props as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(props), &props, |b, _| {
+            b.iter(|| schedule.run(&mut world));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, chunk_culling);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+// benches/movement.rs
+//
+// Scaling benchmark for the hot FixedUpdate mover systems.
+//
+// Run with:
+//   cargo bench --bench movement
+//
+// Each case builds a bare `World` with N player-like movers and runs the
+// velocity + integration pipeline once per iteration, so the numbers show how
+// `par_iter_mut` batching behaves as entity counts grow.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use to_be_free::features::movement::component::{MoveConfig, MoveInput, Velocity};
+use to_be_free::features::movement::systems::{compute_velocity_from_input, integrate_velocity};
+use to_be_free::features::player::component::Player;
+
+/// Mover counts to sweep. The small end shows per-call overhead,
+/// the large end shows parallel throughput.
+const MOVER_COUNTS: [usize; 4] = [100, 1_000, 10_000, 50_000];
+
+fn build_world(movers: usize) -> (World, Schedule) {
+    let mut world = World::new();
+
+    let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+    fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+    world.insert_resource(fixed_time);
+
+    world.spawn_batch((0..movers).map(|i| {
+        let angle = i as f32 * 0.01;
+        (
+            Player,
+            MoveInput(Vec3::new(angle.cos(), 0.0, angle.sin())),
+            MoveConfig::uniform(5.0),
+            Velocity(Vec3::ZERO),
+            Transform::from_rotation(Quat::from_rotation_y(angle)),
+        )
+    }));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((compute_velocity_from_input, integrate_velocity).chain());
+
+    (world, schedule)
+}
+
+fn mover_pipeline(c: &mut Criterion) {
+    // Normally created by `TaskPoolPlugin`; `par_iter_mut` requires it.
+    ComputeTaskPool::get_or_init(TaskPool::default);
+
+    let mut group = c.benchmark_group("mover_pipeline");
+
+    for &movers in &MOVER_COUNTS {
+        let (mut world, mut schedule) = build_world(movers);
+
+        // Warm up once so schedule initialization isn't measured.
+        schedule.run(&mut world);
+
+        group.throughput(Throughput::Elements(movers as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(movers), &movers, |b, _| {
+            b.iter(|| schedule.run(&mut world));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, mover_pipeline);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+// benches/simulation.rs
+//
+// Benchmarks for the hot simulation paths, one group per stage:
+// - input: keyboard state -> `MoveInput`
+// - velocity: `MoveInput` -> `Velocity`
+// - integration: `Velocity` -> `Transform`
+// - collision: sphere casts against every `Collider` (the brute-force broadphase)
+//
+// Run with:
+//   cargo xtask bench                      (all benches, tests first)
+//   cargo xtask bench --save main          (store a baseline)
+//   cargo xtask bench --compare main       (report regressions against it)
+//
+// Everything runs headless on a bare `World`: no window, renderer or assets.
+use std::time::Duration;
+
+use bevy::ecs::system::{RunSystemOnce, ScheduleSystem};
+use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use to_be_free::features::collision::component::Collider;
+use to_be_free::features::collision::query::CollisionWorld;
+use to_be_free::features::movement::component::{MoveConfig, MoveInput, Velocity};
+use to_be_free::features::movement::systems::{compute_velocity_from_input, integrate_velocity};
+use to_be_free::features::player::component::Player;
+use to_be_free::features::player::input::{PlayerKeybindings, read_player_input};
+
+/// Mover counts for the per-entity stages.
+const MOVER_COUNTS: [usize; 3] = [100, 1_000, 10_000];
+
+/// Collider counts for the broadphase.
+const COLLIDER_COUNTS: [usize; 3] = [100, 1_000, 10_000];
+
+/// Sphere casts per collision iteration (roughly a busy frame's worth).
+const CASTS: usize = 64;
+
+/// Headless world with `movers` players walking diagonally.
+fn mover_world(movers: usize) -> World {
+    let mut world = World::new();
+
+    let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+    fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+    world.insert_resource(fixed_time);
+
+    let mut keyboard = ButtonInput::<KeyCode>::default();
+    keyboard.press(KeyCode::KeyW);
+    keyboard.press(KeyCode::KeyD);
+    world.insert_resource(keyboard);
+    world.init_resource::<PlayerKeybindings>();
+
+    world.spawn_batch((0..movers).map(|i| {
+        let angle = i as f32 * 0.01;
+        (
+            Player,
+            MoveInput(Vec3::ZERO),
+            MoveConfig::uniform(5.0),
+            Velocity(Vec3::ZERO),
+            Transform::from_rotation(Quat::from_rotation_y(angle)),
+        )
+    }));
+    world
+}
+
+fn bench_stage<M>(
+    c: &mut Criterion,
+    name: &str,
+    system: impl IntoScheduleConfigs<ScheduleSystem, M> + Clone,
+) {
+    let mut group = c.benchmark_group(name);
+    for &movers in &MOVER_COUNTS {
+        let mut world = mover_world(movers);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(system.clone());
+        // Warm up once so schedule initialization isn't measured.
+        schedule.run(&mut world);
+
+        group.throughput(Throughput::Elements(movers as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(movers), &movers, |b, _| {
+            b.iter(|| schedule.run(&mut world));
+        });
+    }
+    group.finish();
+}
+
+fn simulation_stages(c: &mut Criterion) {
+    // Normally created by `TaskPoolPlugin`; `par_iter_mut` requires it.
+    ComputeTaskPool::get_or_init(TaskPool::default);
+
+    bench_stage(c, "input", read_player_input);
+    bench_stage(c, "velocity", compute_velocity_from_input);
+    bench_stage(c, "integration", integrate_velocity);
+}
+
+fn collision_broadphase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collision");
+    for &colliders in &COLLIDER_COUNTS {
+        let mut world = World::new();
+        let per_row = (colliders as f32).sqrt().ceil() as usize;
+        world.spawn_batch((0..colliders).map(|i| {
+            let (x, z) = ((i % per_row) as f32 * 4.0, (i / per_row) as f32 * 4.0);
+            (
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::from_xyz(x, 0.0, z),
+            )
+        }));
+        let origins: Vec<Vec3> = (0..CASTS).map(|i| Vec3::new(i as f32 * 1.5, 0.0, -2.0)).collect();
+
+        group.throughput(Throughput::Elements((colliders * CASTS) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(colliders), &colliders, |b, _| {
+            b.iter(|| {
+                let origins = origins.clone();
+                world
+                    .run_system_once(move |collision: CollisionWorld| {
+                        origins
+                            .iter()
+                            .filter_map(|o| collision.sphere_cast(*o, Vec3::Z, 50.0, 0.3, &[]))
+                            .count()
+                    })
+                    .unwrap()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, simulation_stages, collision_broadphase);
+criterion_main!(benches);
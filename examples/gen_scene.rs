@@ -0,0 +1,89 @@
+// examples/gen_scene.rs
+//
+// Writes a seeded placeholder scene (terrain columns, props, spawners, spawn
+// points) generated from the built-in biomes, for test levels and the benchmark
+// scene.
+//
+// Run with:
+//   cargo xtask gen-scene --size 128 --seed 42
+//   cargo xtask gen-scene --size 64 --seed 7 --out assets/scenes/arena.ron
+//
+// Without `--out` the file goes to `assets/scenes/gen-<size>-<seed>.ron`; list it in
+// `BUILTIN_SCENES` (scenes feature) to load it in game.
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use to_be_free::features::biome::component::BiomeLibrary;
+use to_be_free::features::scenes::generate::{GenerateOptions, generate_scene};
+
+const USAGE: &str = "usage: gen_scene --size <units> --seed <seed> [--out <path>]";
+
+fn main() -> ExitCode {
+    let Some((options, out)) = parse_args(std::env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    let out = out.unwrap_or_else(|| {
+        PathBuf::from(format!("assets/scenes/gen-{}-{}.ron", options.size, options.seed))
+    });
+
+    let scene = generate_scene(options, &BiomeLibrary::default());
+    let text = match scene.to_ron() {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("could not serialize the scene: {err}");
+            return ExitCode::from(1);
+        }
+    };
+    let header = format!(
+        "// Generated by `cargo xtask gen-scene --size {} --seed {}`; regenerate rather than edit.\n",
+        options.size, options.seed
+    );
+    if let Err(err) = std::fs::write(&out, header + &text) {
+        eprintln!("could not write {}: {err}", out.display());
+        return ExitCode::from(1);
+    }
+    eprintln!(
+        "wrote {} ({} pieces, {} spawners, {} spawn points)",
+        out.display(),
+        scene.pieces.len(),
+        scene.spawners.len(),
+        scene.spawn_points.len()
+    );
+    ExitCode::SUCCESS
+}
+
+/// `--size` and `--seed` are required whole numbers; anything unknown is a usage
+/// error. The only parser of these options (`cargo xtask gen-scene` forwards them).
+fn parse_args(mut args: impl Iterator<Item = String>) -> Option<(GenerateOptions, Option<PathBuf>)> {
+    let (mut size, mut seed, mut out) = (None, None, None);
+    while let Some(flag) = args.next() {
+        let Some(value) = args.next() else {
+            eprintln!("{flag} needs a value");
+            return None;
+        };
+        match flag.as_str() {
+            "--size" => size = Some(whole_number(&flag, &value)?),
+            "--seed" => seed = Some(whole_number(&flag, &value)?),
+            "--out" => out = Some(PathBuf::from(value)),
+            _ => {
+                eprintln!("unknown option: {flag}");
+                return None;
+            }
+        }
+    }
+    let (Some(size), Some(seed)) = (size, seed) else {
+        eprintln!("needs both --size and --seed");
+        return None;
+    };
+    Some((GenerateOptions { size, seed }, out))
+}
+
+/// `value` as a whole number; reports it against `flag` if it isn't one.
+fn whole_number(flag: &str, value: &str) -> Option<u32> {
+    let number = value.parse().ok();
+    if number.is_none() {
+        eprintln!("{flag} needs a whole number, got `{value}`");
+    }
+    number
+}
@@ -0,0 +1,185 @@
+// src/app/despawn.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Marks an entity that will be despawned at the end of the tick.
+///
+/// Systems that act on other features' entities can filter `Without<Despawning>`
+/// so they don't pick up something that is already on its way out.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Despawning;
+
+/// Pool entry: a despawn request hides it and hands it back to its pool instead of
+/// despawning it.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Pooled;
+
+/// Child that outlives its parent: detached in place (keeping its world transform)
+/// instead of being despawned with it.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DetachOnDespawn;
+
+/// Ask for an entity to be despawned at the end of the tick (stale and repeated
+/// requests are fine).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DespawnRequest {
+    pub entity: Entity,
+}
+
+/// An entity left play this tick (despawned, or returned to its pool): drop any
+/// reference to it.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Despawned {
+    pub entity: Entity,
+    /// Returned to its pool: the entity still exists, hidden.
+    pub pooled: bool,
+}
+
+/// What features use instead of `commands.entity(e).despawn()`.
+///
+/// Marks the entity `Despawning` right away (from the next sync point on); the
+/// actual despawn happens once, in `Last`, after every feature had its say.
+#[derive(SystemParam)]
+pub struct DespawnQueue<'w, 's> {
+    commands: Commands<'w, 's>,
+}
+
+impl DespawnQueue<'_, '_> {
+    pub fn despawn(&mut self, entity: Entity) {
+        self.commands.entity(entity).try_insert(Despawning);
+    }
+}
+
+/// Last: mark the entities requested through `DespawnRequest`.
+pub fn mark_requested_despawns(mut queue: DespawnQueue, mut requests: MessageReader<DespawnRequest>) {
+    for request in requests.read() {
+        queue.despawn(request.entity);
+    }
+}
+
+/// Last (after marking): despawn everything marked `Despawning`.
+///
+/// - `DetachOnDespawn` children are detached in place first; other children go
+///   with their parent
+/// - `Pooled` entries are hidden and unmarked instead
+/// - every entity that left play gets a `Despawned`
+#[expect(clippy::type_complexity)]
+pub fn despawn_marked(
+    mut commands: Commands,
+    q_marked: Query<(Entity, Has<Pooled>, Option<&Children>), With<Despawning>>,
+    q_detach: Query<(), With<DetachOnDespawn>>,
+    mut despawned: MessageWriter<Despawned>,
+) {
+    for (entity, pooled, children) in &q_marked {
+        for &child in children.into_iter().flatten() {
+            if q_detach.contains(child) {
+                commands.entity(child).remove_parent_in_place();
+            }
+        }
+        if pooled {
+            commands
+                .entity(entity)
+                .remove::<Despawning>()
+                .try_insert(Visibility::Hidden);
+        } else {
+            // A marked child may already have gone with its marked parent.
+            commands.entity(entity).try_despawn();
+        }
+        despawned.write(Despawned { entity, pooled });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<DespawnRequest>>();
+        world.init_resource::<Messages<Despawned>>();
+        world
+    }
+
+    fn end_of_tick(world: &mut World) {
+        let _ = world.run_system_once(mark_requested_despawns);
+        let _ = world.run_system_once(despawn_marked);
+    }
+
+    fn despawned(world: &World) -> Vec<Despawned> {
+        world
+            .resource::<Messages<Despawned>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn requests_are_marked_then_despawned_once_at_the_end_of_the_tick() {
+        let mut world = setup_world();
+        let marked = world.spawn_empty().id();
+        let requested = world.spawn_empty().id();
+        let gone = world.spawn_empty().id();
+        world.despawn(gone);
+
+        let _ = world.run_system_once(move |mut queue: DespawnQueue| {
+            queue.despawn(marked);
+            queue.despawn(marked);
+        });
+        assert!(world.entity(marked).contains::<Despawning>(), "marked, still alive");
+
+        for entity in [requested, requested, gone] {
+            world.write_message(DespawnRequest { entity });
+        }
+        end_of_tick(&mut world);
+
+        assert!(world.get_entity(marked).is_err());
+        assert!(world.get_entity(requested).is_err());
+        let mut left: Vec<Entity> = despawned(&world).iter().map(|d| d.entity).collect();
+        left.sort();
+        let mut expected = vec![marked, requested];
+        expected.sort();
+        assert_eq!(left, expected, "one cleanup message each, none for the stale request");
+    }
+
+    #[test]
+    fn children_go_with_their_parent_unless_they_detach() {
+        let mut world = setup_world();
+        let parent = world
+            .spawn((Transform::from_xyz(1.0, 0.0, 0.0), GlobalTransform::from_xyz(1.0, 0.0, 0.0)))
+            .id();
+        let attached = world.spawn(ChildOf(parent)).id();
+        let detached = world
+            .spawn((
+                DetachOnDespawn,
+                Transform::from_xyz(0.0, 2.0, 0.0),
+                GlobalTransform::from_xyz(1.0, 2.0, 0.0),
+                ChildOf(parent),
+            ))
+            .id();
+
+        world.write_message(DespawnRequest { entity: parent });
+        end_of_tick(&mut world);
+
+        assert!(world.get_entity(parent).is_err());
+        assert!(world.get_entity(attached).is_err());
+        let survivor = world.entity(detached);
+        assert!(!survivor.contains::<ChildOf>());
+        assert_eq!(survivor.get::<Transform>().unwrap().translation, Vec3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn pool_entries_are_hidden_and_returned_instead() {
+        let mut world = setup_world();
+        let entry = world.spawn((Pooled, Visibility::Inherited)).id();
+
+        world.write_message(DespawnRequest { entity: entry });
+        end_of_tick(&mut world);
+
+        let entry_ref = world.entity(entry);
+        assert!(!entry_ref.contains::<Despawning>());
+        assert_eq!(entry_ref.get::<Visibility>(), Some(&Visibility::Hidden));
+        assert_eq!(despawned(&world), vec![Despawned { entity: entry, pooled: true }]);
+    }
+}
@@ -0,0 +1,120 @@
+// src/app/determinism.rs
+use bevy::prelude::*;
+
+/// Positions and velocities snap to multiples of `1 / QUANTUM_SCALE` (~1 mm) in
+/// quantized mode. A power of two, so snapping is exact: every snapped value is a
+/// representable `f32` as long as `|value| < 2^24 / QUANTUM_SCALE` (16384 units).
+pub const QUANTUM_SCALE: f32 = 1024.0;
+
+/// How the fixed simulation does its math.
+///
+/// `Float` is plain `f32` (fast, but `exp` & co. may round differently per
+/// platform / libm, and those differences grow over a long run). `Quantized` is the
+/// opt-in deterministic mode for rollback networking and cross-platform replays:
+/// - movement snaps positions and velocities to the `QUANTUM_SCALE` grid every
+///   step, so last-bit differences are absorbed instead of accumulating
+/// - transcendental functions go through `SimMath::exp` (and friends), which only
+///   use IEEE-exact operations (`+ - * /`, rounding), never the platform libm
+///
+/// Both peers / the recorder and the player must use the same mode.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimMath {
+    #[default]
+    Float,
+    Quantized,
+}
+
+impl SimMath {
+    pub fn is_quantized(self) -> bool {
+        self == Self::Quantized
+    }
+
+    /// Snap a scalar to the grid (unchanged in `Float` mode).
+    pub fn quantize(self, value: f32) -> f32 {
+        match self {
+            Self::Float => value,
+            Self::Quantized => (value * QUANTUM_SCALE).round() / QUANTUM_SCALE,
+        }
+    }
+
+    /// Snap every component of a vector to the grid (unchanged in `Float` mode).
+    pub fn quantize_vec3(self, value: Vec3) -> Vec3 {
+        match self {
+            Self::Float => value,
+            Self::Quantized => (value * QUANTUM_SCALE).round() / QUANTUM_SCALE,
+        }
+    }
+
+    /// `e^x`: the platform's in `Float` mode, `deterministic_exp` in quantized mode.
+    pub fn exp(self, x: f32) -> f32 {
+        match self {
+            Self::Float => x.exp(),
+            Self::Quantized => deterministic_exp(x),
+        }
+    }
+}
+
+/// `e^x` from IEEE-exact operations only, so every platform gets the same bits.
+///
+/// Range reduction `x = k ln 2 + r` (`|r| <= ln 2 / 2`, `ln 2` split in two so
+/// `k ln 2` stays exact), a degree-6 Taylor polynomial for `e^r` (relative error
+/// below `2e-7` there) and an exact `2^k` built from the exponent bits. Flushes to `0` / saturates to `f32::MAX` outside
+/// the normal range instead of producing subnormals / infinity.
+pub fn deterministic_exp(x: f32) -> f32 {
+    const LN_2: f32 = std::f32::consts::LN_2;
+    const LN_2_HI: f32 = 0.693_145_75;
+    const LN_2_LO: f32 = 1.428_606_8e-6;
+    if x.is_nan() {
+        return x;
+    }
+    if x < -87.0 {
+        return 0.0;
+    }
+    if x > 88.0 {
+        return f32::MAX;
+    }
+    let k = (x / LN_2).round();
+    let r = (x - k * LN_2_HI) - k * LN_2_LO;
+    let mut poly = 1.0 / 720.0;
+    for coefficient in [1.0 / 120.0, 1.0 / 24.0, 1.0 / 6.0, 0.5, 1.0, 1.0] {
+        poly = poly * r + coefficient;
+    }
+    // k is in -126..=127 here, so 2^k is a normal float.
+    let two_k = f32::from_bits(((k as i32 + 127) as u32) << 23);
+    poly * two_k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic_exp_tracks_the_float_path() {
+        for i in -800..=800 {
+            let x = i as f32 * 0.1;
+            let (fast, exact) = (x.exp(), deterministic_exp(x));
+            let error = ((exact - fast) / fast).abs();
+            assert!(error < 1e-6, "exp({x}): {exact} vs {fast}");
+        }
+        assert_eq!(deterministic_exp(0.0), 1.0);
+        assert_eq!(deterministic_exp(-200.0), 0.0);
+    }
+
+    #[test]
+    fn quantizing_absorbs_last_bit_differences() {
+        let math = SimMath::Quantized;
+        let value = 12.345_678_f32;
+        // Neighbouring floats (what two platforms might disagree on) land on the same
+        // grid point.
+        let below = f32::from_bits(value.to_bits() - 1);
+        let above = f32::from_bits(value.to_bits() + 1);
+        assert_eq!(math.quantize(below), math.quantize(value));
+        assert_eq!(math.quantize(above), math.quantize(value));
+        assert!((math.quantize(value) - value).abs() <= 0.5 / QUANTUM_SCALE);
+
+        // Snapped values are fixed points.
+        let snapped = math.quantize_vec3(Vec3::new(-3.3, 0.7, 1000.1));
+        assert_eq!(math.quantize_vec3(snapped), snapped);
+        assert_eq!(SimMath::Float.quantize(value), value);
+    }
+}
@@ -0,0 +1,76 @@
+// src/app/effects.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Photosensitivity option picked in the settings menu.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EffectsLevel {
+    #[default]
+    Full,
+    /// Shake, flashes and pulsing lights dampened well below full strength.
+    Reduced,
+    /// No shake, flashes or pulsing lights.
+    Off,
+}
+
+impl EffectsLevel {
+    pub const ALL: [Self; 3] = [Self::Full, Self::Reduced, Self::Off];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Full => "Full",
+            Self::Reduced => "Reduced",
+            Self::Off => "Off",
+        }
+    }
+
+    /// The next option (menu button cycles through them).
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// How strong presentation effects may be right now.
+///
+/// Every system that shakes the camera, flashes something or rapidly changes
+/// lights / post-processing multiplies its strength by the matching factor here
+/// instead of hardcoding it. The accessibility feature derives it from the
+/// player's `EffectsLevel`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct EffectsIntensity {
+    /// Camera shake amplitude.
+    pub shake: f32,
+    /// Brightness of damage, hit and explosion flashes.
+    pub flash: f32,
+    /// Amplitude of pulsing glows and other rapid light changes.
+    pub pulse: f32,
+}
+
+impl Default for EffectsIntensity {
+    fn default() -> Self {
+        Self::for_level(EffectsLevel::Full)
+    }
+}
+
+impl EffectsIntensity {
+    pub fn for_level(level: EffectsLevel) -> Self {
+        match level {
+            EffectsLevel::Full => Self {
+                shake: 1.0,
+                flash: 1.0,
+                pulse: 1.0,
+            },
+            EffectsLevel::Reduced => Self {
+                shake: 0.25,
+                flash: 0.3,
+                pulse: 0.2,
+            },
+            EffectsLevel::Off => Self {
+                shake: 0.0,
+                flash: 0.0,
+                pulse: 0.0,
+            },
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// src/app/faction.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::tuning::Tuning;
+
+/// Owner links followed before giving up (a summon's summon is fine, a cycle isn't).
+const MAX_OWNER_DEPTH: usize = 4;
+
+/// Side a character fights on. How sides treat each other is the `FactionTable`
+/// in `Tuning::factions`.
+#[derive(
+    Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum Faction {
+    /// Every player (co-op partners are allies).
+    Players,
+    /// Enemies from spawners, dungeons and game modes.
+    Monsters,
+    /// Townsfolk, vendors and quest givers.
+    Villagers,
+    /// Animals minding their own business.
+    Wildlife,
+}
+
+/// How one faction regards another.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Relation {
+    Ally,
+    #[default]
+    Neutral,
+    Hostile,
+}
+
+/// Relationship matrix between factions (`assets/data/tuning.ron`).
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct FactionTable {
+    /// Allies hurt each other (a hit on yourself lands either way).
+    pub friendly_fire: bool,
+    /// Symmetric pairs; a faction is its own ally, unlisted pairs are neutral.
+    pub relations: Vec<(Faction, Faction, Relation)>,
+}
+
+impl FactionTable {
+    pub fn relation(&self, a: Faction, b: Faction) -> Relation {
+        if a == b {
+            return Relation::Ally;
+        }
+        self.relations
+            .iter()
+            .find(|&&(x, y, _)| (x, y) == (a, b) || (x, y) == (b, a))
+            .map_or(Relation::Neutral, |&(.., relation)| relation)
+    }
+}
+
+/// Acts on behalf of the entity: fights for its faction, and its hits count as
+/// that entity's (companions, summons, placed turrets).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Owner(pub Entity);
+
+/// Who is on whose side, for any feature that picks targets, applies hits or
+/// colors UI.
+///
+/// Entities without a faction (and without an owner that has one) are neutral to
+/// everyone. Without `Tuning` (minimal apps) only a faction's own members are
+/// allies and friendly fire is off.
+#[derive(SystemParam)]
+pub struct Allegiance<'w, 's> {
+    tuning: Option<Res<'w, Tuning>>,
+    q_members: Query<'w, 's, (Option<&'static Faction>, Option<&'static Owner>)>,
+}
+
+impl Allegiance<'_, '_> {
+    /// `entity`'s faction, or its owner's.
+    pub fn faction(&self, entity: Entity) -> Option<Faction> {
+        let mut current = entity;
+        for _ in 0..=MAX_OWNER_DEPTH {
+            let (faction, owner) = self.q_members.get(current).ok()?;
+            if let Some(faction) = faction {
+                return Some(*faction);
+            }
+            current = owner?.0;
+        }
+        None
+    }
+
+    pub fn table_relation(&self, a: Faction, b: Faction) -> Relation {
+        match &self.tuning {
+            Some(tuning) => tuning.factions.relation(a, b),
+            None => FactionTable::default().relation(a, b),
+        }
+    }
+
+    /// How `a` regards `b`.
+    pub fn relation(&self, a: Entity, b: Entity) -> Relation {
+        match (self.faction(a), self.faction(b)) {
+            (Some(a), Some(b)) => self.table_relation(a, b),
+            _ => Relation::Neutral,
+        }
+    }
+
+    pub fn is_hostile(&self, a: Entity, b: Entity) -> bool {
+        self.relation(a, b) == Relation::Hostile
+    }
+
+    /// Whether a hit from `attacker` lands on `target` (friendly fire setting).
+    pub fn can_hurt(&self, attacker: Entity, target: Entity) -> bool {
+        attacker == target
+            || self.relation(attacker, target) != Relation::Ally
+            || self.tuning.as_ref().is_some_and(|t| t.factions.friendly_fire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn relations_are_symmetric_and_default_to_neutral() {
+        let table = FactionTable {
+            friendly_fire: false,
+            relations: vec![(Faction::Players, Faction::Monsters, Relation::Hostile)],
+        };
+        assert_eq!(table.relation(Faction::Monsters, Faction::Players), Relation::Hostile);
+        assert_eq!(table.relation(Faction::Players, Faction::Players), Relation::Ally);
+        assert_eq!(table.relation(Faction::Players, Faction::Wildlife), Relation::Neutral);
+    }
+
+    #[test]
+    fn owned_entities_fight_for_their_owner() {
+        let mut world = World::new();
+        world.init_resource::<Tuning>();
+        let player = world.spawn(Faction::Players).id();
+        let partner = world.spawn(Faction::Players).id();
+        let companion = world.spawn(Owner(player)).id();
+        let monster = world.spawn(Faction::Monsters).id();
+        let rock = world.spawn_empty().id();
+
+        let checks = world
+            .run_system_once(move |allegiance: Allegiance| {
+                (
+                    allegiance.faction(companion),
+                    allegiance.is_hostile(monster, companion),
+                    allegiance.relation(rock, player),
+                    allegiance.can_hurt(partner, companion),
+                    allegiance.can_hurt(player, player),
+                )
+            })
+            .unwrap();
+        assert_eq!(
+            checks,
+            (Some(Faction::Players), true, Relation::Neutral, false, true)
+        );
+    }
+}
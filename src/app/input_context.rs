@@ -0,0 +1,127 @@
+// src/app/input_context.rs
+use bevy::prelude::*;
+
+/// Who currently owns the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputContext {
+    /// In-world controls (movement, combat, camera).
+    Gameplay,
+    /// A menu / full-screen UI is up; only UI and global hotkeys react.
+    Menu,
+    /// The developer console has focus.
+    Console,
+    /// A text field has focus: every key is text.
+    TextEntry,
+    /// A scripted transition (portal fade) moves the player; nothing reads input.
+    Transition,
+}
+
+/// Actions that only make sense while controlling the character.
+pub const GAMEPLAY: &[InputContext] = &[InputContext::Gameplay];
+
+/// Global hotkeys (pause, menu, debug toggles): work in play and in menus, but not
+/// while typing.
+pub const HOTKEYS: &[InputContext] = &[InputContext::Gameplay, InputContext::Menu];
+
+/// Menu navigation (focus, activate): only while a menu owns the keyboard.
+pub const MENUS: &[InputContext] = &[InputContext::Menu];
+
+/// Stack of input contexts; only the top one receives input.
+///
+/// UI that takes the keyboard pushes its context when it opens and pops it when it
+/// closes (console, text fields); input systems declare the contexts they are
+/// active in with `in_input_context`. `Gameplay` is the permanent bottom entry.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct InputContextStack(Vec<InputContext>);
+
+impl Default for InputContextStack {
+    fn default() -> Self {
+        Self(vec![InputContext::Gameplay])
+    }
+}
+
+impl InputContextStack {
+    /// The context receiving input.
+    pub fn top(&self) -> InputContext {
+        self.0.last().copied().unwrap_or(InputContext::Gameplay)
+    }
+
+    pub fn push(&mut self, context: InputContext) {
+        self.0.push(context);
+    }
+
+    /// Remove the topmost `context` (wherever it is, so UIs closing out of order
+    /// don't strand each other). Returns `false` if it wasn't on the stack; the
+    /// bottom `Gameplay` entry is never removed.
+    pub fn pop(&mut self, context: InputContext) -> bool {
+        match self.0.iter().rposition(|c| *c == context) {
+            Some(index) if index > 0 => {
+                self.0.remove(index);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Run condition: the top input context is one of `contexts`.
+///
+/// Without an `InputContextStack` (minimal apps) everything runs as `Gameplay`.
+pub fn in_input_context(
+    contexts: &'static [InputContext],
+) -> impl FnMut(Option<Res<InputContextStack>>) -> bool + Clone {
+    move |stack| contexts.contains(&stack.map_or(InputContext::Gameplay, |s| s.top()))
+}
+
+/// OnExit(Playing): every other run state (paused, menus, onboarding) is UI.
+///
+/// Run states only ever change to and from `Playing`, so these two stay paired.
+pub fn push_menu_context(mut stack: ResMut<InputContextStack>) {
+    stack.push(InputContext::Menu);
+}
+
+/// OnEnter(Playing): back to play.
+pub fn pop_menu_context(mut stack: ResMut<InputContextStack>) {
+    stack.pop(InputContext::Menu);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn only_the_top_context_is_active_and_gameplay_stays_at_the_bottom() {
+        let mut stack = InputContextStack::default();
+        stack.push(InputContext::Menu);
+        stack.push(InputContext::Console);
+        assert_eq!(stack.top(), InputContext::Console);
+
+        // Out-of-order close: the menu goes, the console keeps focus.
+        assert!(stack.pop(InputContext::Menu));
+        assert_eq!(stack.top(), InputContext::Console);
+        assert!(stack.pop(InputContext::Console));
+        assert!(!stack.pop(InputContext::Gameplay));
+        assert!(!stack.pop(InputContext::TextEntry));
+        assert_eq!(stack.top(), InputContext::Gameplay);
+    }
+
+    #[test]
+    fn run_condition_follows_the_top_context() {
+        let mut world = World::new();
+        let gameplay =
+            |world: &mut World| world.run_system_once(in_input_context(GAMEPLAY)).unwrap();
+        let hotkeys =
+            |world: &mut World| world.run_system_once(in_input_context(HOTKEYS)).unwrap();
+
+        assert!(gameplay(&mut world), "no stack: gameplay");
+
+        world.init_resource::<InputContextStack>();
+        world.resource_mut::<InputContextStack>().push(InputContext::Menu);
+        assert!(!gameplay(&mut world));
+        assert!(hotkeys(&mut world));
+
+        world.resource_mut::<InputContextStack>().push(InputContext::TextEntry);
+        assert!(!hotkeys(&mut world));
+    }
+}
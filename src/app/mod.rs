@@ -1,14 +1,50 @@
 use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::transform::TransformSystems;
 
+mod despawn;
+mod determinism;
+mod effects;
+mod faction;
+mod input_context;
+mod mode;
 mod schedules;
+mod state;
+mod storage;
+mod tick;
+mod tuning;
 
+pub use despawn::{DespawnQueue, DespawnRequest, Despawned, Despawning, DetachOnDespawn, Pooled};
+pub use determinism::{QUANTUM_SCALE, SimMath, deterministic_exp};
+pub use effects::{EffectsIntensity, EffectsLevel};
+pub use faction::{Allegiance, Faction, FactionTable, Owner, Relation};
+pub use input_context::{GAMEPLAY, HOTKEYS, InputContext, InputContextStack, MENUS, in_input_context};
+pub use mode::{GameMode, RunStarted};
 pub use schedules::AppSet;
+pub use state::{GameState, PauseKeybinding};
+pub use storage::{Platform, StoragePaths};
+pub use tick::{CLIENT_TICK_HZ, SERVER_TICK_HZ, SimTick, TickRate};
+pub use tuning::{Difficulty, FallTuning, GameplayTuning, Tuning, TuningSource};
 
 /// Centralized engine / application configuration plugin.
 ///
 /// This plugin is the single authority for:
-/// - Global engine configuration (fixed timestep, etc.)
-/// - Schedule-level system sets (Update vs FixedUpdate ordering points)
+/// - Global engine configuration (fixed timestep from `TickRate`, etc.)
+/// - Schedule-level system sets (Update vs FixedUpdate ordering points, and the
+///   movement stages inside `AppSet::FixedMovement`)
+/// - Final transforms for world-anchored UI (`AppSet::UiSync`)
+/// - The top-level `GameState` (playing / paused / menu) and the pause key
+/// - The `GameMode` of the current run and the `RunStarted` message that switches it
+/// - The input context stack (`InputContextStack`: who owns the keyboard)
+/// - End-of-tick despawning (`DespawnQueue` / `DespawnRequest` -> `Despawned`)
+/// - The simulation tick counter (`SimTick`) and how the simulation does its math
+///   (`SimMath`: plain floats, or quantized for rollback / replays)
+/// - Difficulty multipliers of the current run (`GameplayTuning`)
+/// - Gameplay feel constants (`Tuning`), reloaded from disk in dev builds
+/// - Factions and ownership (`Faction`, `Owner`): who is on whose side, from the
+///   tuning's relationship matrix (`Allegiance`)
+/// - How strong shake / flash / pulse effects may be (`EffectsIntensity`)
+/// - Where persisted files live (`StoragePaths`)
 ///
 /// Keeping this out of `main.rs` prevents startup from turning into a junk drawer.
 pub struct AppPlugin;
@@ -18,17 +54,95 @@ impl Plugin for AppPlugin {
         // Fixed timestep configuration.
         //
         // Bevy's default fixed timestep is 64 Hz.
-        // We override it to 60 Hz by inserting Time<Fixed> at startup.
+        // We override it with the app's `TickRate` (60 Hz on clients, the configured
+        // rate on dedicated servers) by inserting Time<Fixed> at startup.
         // Ref: Bevy notes mention restoring 60 Hz via Time::<Fixed>::from_hz(60.0).
         // https://bevy-cheatbook.github.io/fundamentals/fixed-timestep.html
-        app.insert_resource(Time::<Fixed>::from_hz(60.0));
+        let tick_rate = app.world().get_resource::<TickRate>().copied().unwrap_or_default();
+        app.insert_resource(tick_rate);
+        app.insert_resource(Time::<Fixed>::from_hz(tick_rate.0));
+
+        // Tick counter: advanced before any FixedUpdate system of the step runs.
+        app.init_resource::<SimTick>();
+        app.add_systems(FixedFirst, tick::advance_sim_tick);
+
+        // Simulation math: plain floats unless netcode / replays opt into quantized.
+        app.init_resource::<SimMath>();
+
+        // Difficulty: set from the user's preset when a run starts (settings / menu).
+        app.init_resource::<GameplayTuning>();
+
+        // Feel: from the data file; dev builds pick up edits without a restart.
+        app.init_resource::<Tuning>();
+        if cfg!(debug_assertions) {
+            app.init_resource::<TuningSource>();
+            app.add_systems(Update, tuning::reload_tuning);
+        }
+
+        // Effect strength: set from the user's photosensitivity option (accessibility).
+        app.init_resource::<EffectsIntensity>();
+
+        // Storage: the game resolves real locations in `build_app`; anything else
+        // (tests, tools) keeps the working-directory default unless it inserts its own.
+        app.init_resource::<StoragePaths>();
 
         // Define ordering / grouping labels for systems.
         //
         // Important Bevy rule: system set configuration is stored *per schedule*,
-        // so we configure sets separately for Update and FixedUpdate.
+        // so we configure sets separately for Update, PostUpdate and FixedUpdate.
         // https://bevy-cheatbook.github.io/programming/system-sets.html
-        app.configure_sets(Update, AppSet::Input);
-        app.configure_sets(FixedUpdate, AppSet::FixedMovement);
+        app.configure_sets(Update, (AppSet::Input, AppSet::CameraUpdate).chain());
+        // World-anchored UI reads `GlobalTransform`s, which Bevy only propagates in
+        // PostUpdate: sync after that pass so it sees this frame's camera and targets.
+        app.configure_sets(PostUpdate, AppSet::UiSync.after(TransformSystems::Propagate));
+        app.configure_sets(
+            FixedUpdate,
+            (AppSet::FixedMovement, AppSet::FixedGameplay).chain(),
+        );
+        app.configure_sets(
+            FixedUpdate,
+            (
+                AppSet::ReadIntent,
+                AppSet::ApplyForces,
+                AppSet::Integrate,
+                AppSet::ResolveCollisions,
+                AppSet::PostMovement,
+            )
+                .chain()
+                .in_set(AppSet::FixedMovement),
+        );
+
+        // Run state. `DefaultPlugins` brings `StatesPlugin`; headless apps
+        // (`MinimalPlugins`) don't, so add it when missing.
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        app.init_state::<GameState>();
+        app.init_resource::<PauseKeybinding>();
+        app.init_state::<GameMode>();
+        app.add_message::<RunStarted>();
+        app.add_systems(Update, mode::switch_game_mode.after(AppSet::Input));
+        app.add_systems(
+            Update,
+            state::toggle_pause
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+
+        // Despawns: features only mark entities; one pass in `Last` despawns them, so
+        // nothing is pulled out from under a system that still runs this tick.
+        app.add_message::<DespawnRequest>();
+        app.add_message::<Despawned>();
+        app.add_systems(
+            Last,
+            (despawn::mark_requested_despawns, despawn::despawn_marked).chain(),
+        );
+
+        // Input contexts: anything but `Playing` is a menu for the keyboard.
+        app.init_resource::<InputContextStack>();
+        app.add_systems(OnExit(GameState::Playing), input_context::push_menu_context);
+        app.add_systems(OnEnter(GameState::Playing), input_context::pop_menu_context);
+        app.add_systems(OnEnter(GameState::Playing), state::resume_virtual_time);
+        app.add_systems(OnExit(GameState::Playing), state::pause_virtual_time);
     }
 }
@@ -0,0 +1,61 @@
+// src/app/mode.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Rules the current run is played by (picked in the main menu).
+///
+/// Each mode's rules (win / lose, HUD, spawners, scoring) live in its plugin under
+/// `game::modes`; features gate mode-specific systems on this state.
+#[derive(
+    States, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum GameMode {
+    /// Sandbox: the world as authored, no goal and no score.
+    #[default]
+    FreePlay,
+    /// Endless enemy waves; the run ends when the player dies.
+    Survival,
+    /// Reach the finish as fast as possible.
+    TimeTrial,
+}
+
+impl GameMode {
+    pub const ALL: [Self; 3] = [Self::FreePlay, Self::Survival, Self::TimeTrial];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::FreePlay => "Free play",
+            Self::Survival => "Survival",
+            Self::TimeTrial => "Time trial",
+        }
+    }
+
+    /// Key the mode's results are stored under (`RunResult::mode`).
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::FreePlay => "free_play",
+            Self::Survival => "survival",
+            Self::TimeTrial => "time_trial",
+        }
+    }
+
+    /// The next mode (menu button cycles through them).
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// A new run of `mode` starts: every mode tears down what it left in the world,
+/// and `mode` sets itself up (the `GameMode` state switches on the next frame).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunStarted {
+    pub mode: GameMode,
+}
+
+/// Update: switch `GameMode` to the mode of a starting run.
+pub fn switch_game_mode(mut started: MessageReader<RunStarted>, mut next: ResMut<NextState<GameMode>>) {
+    if let Some(run) = started.read().last() {
+        next.set(run.mode);
+    }
+}
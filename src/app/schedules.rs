@@ -13,6 +13,122 @@ pub enum AppSet {
     /// Variable timestep (`Update`) input gathering and intent generation.
     Input,
 
-    /// Fixed timestep (`FixedUpdate`) movement / physics stepping.
+    /// `Update`, after `Input`: cameras take this frame's pose (follow, orbit, shake,
+    /// `apply_camera_rig`).
+    CameraUpdate,
+
+    /// `PostUpdate`, after transform propagation: UI that tracks the world (HUD
+    /// projections, labels) reads this frame's final `GlobalTransform`s.
+    UiSync,
+
+    /// Fixed timestep (`FixedUpdate`) movement / physics stepping. Holds the stages
+    /// below, in order.
     FixedMovement,
+
+    /// `FixedMovement` stage 1: movers decide where they want to go (`MoveInput`,
+    /// facing, AI steering, server-side client inputs).
+    ReadIntent,
+
+    /// `FixedMovement` stage 2: intent and forces become this tick's `Velocity` /
+    /// `ExternalForce` (gravity, wind, buoyancy, root motion, pushes).
+    ApplyForces,
+
+    /// `FixedMovement` stage 3: velocities move transforms (`integrate_velocity`,
+    /// kinematic motion).
+    Integrate,
+
+    /// `FixedMovement` stage 4: fix up where movers ended (resting on the ground,
+    /// future contact solving).
+    ResolveCollisions,
+
+    /// `FixedMovement` stage 5: react to final positions (riders carried by moving
+    /// platforms, server position checks).
+    PostMovement,
+
+    /// Fixed timestep (`FixedUpdate`) gameplay rules that react to the stepped world
+    /// (damage, death, respawn timers). Runs after `FixedMovement`.
+    FixedGameplay,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppPlugin;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Ran(Vec<&'static str>);
+
+    fn record(stage: &'static str) -> impl FnMut(ResMut<Ran>) {
+        move |mut ran: ResMut<Ran>| ran.0.push(stage)
+    }
+
+    #[test]
+    fn movement_stages_run_in_order_before_gameplay() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AppPlugin));
+        // The pause hotkey reads the keyboard (normally created by InputPlugin).
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Ran>();
+        // Registered backwards: only the set configuration orders them.
+        app.add_systems(FixedUpdate, record("gameplay").in_set(AppSet::FixedGameplay));
+        app.add_systems(FixedUpdate, record("post").in_set(AppSet::PostMovement));
+        app.add_systems(FixedUpdate, record("resolve").in_set(AppSet::ResolveCollisions));
+        app.add_systems(FixedUpdate, record("integrate").in_set(AppSet::Integrate));
+        app.add_systems(FixedUpdate, record("forces").in_set(AppSet::ApplyForces));
+        app.add_systems(FixedUpdate, record("intent").in_set(AppSet::ReadIntent));
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f64(1.5 / 60.0),
+        ));
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Ran>().0,
+            ["intent", "forces", "integrate", "resolve", "post", "gameplay"]
+        );
+    }
+
+    #[derive(Component)]
+    struct Rig;
+
+    #[derive(Resource, Default)]
+    struct Seen(Vec<Vec3>);
+
+    fn move_rig(mut q: Query<&mut Transform, With<Rig>>, mut ran: ResMut<Ran>) {
+        ran.0.push("camera");
+        for mut tr in &mut q {
+            tr.translation.x += 1.0;
+        }
+    }
+
+    fn sample_rig(q: Query<&GlobalTransform, With<Rig>>, mut seen: ResMut<Seen>, mut ran: ResMut<Ran>) {
+        ran.0.push("ui");
+        seen.0.extend(q.iter().map(GlobalTransform::translation));
+    }
+
+    #[test]
+    fn ui_sync_sees_the_camera_moved_this_frame() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AppPlugin));
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Ran>();
+        app.init_resource::<Seen>();
+        app.add_systems(PostUpdate, sample_rig.in_set(AppSet::UiSync));
+        app.add_systems(Update, move_rig.in_set(AppSet::CameraUpdate));
+        app.add_systems(Update, record("input").in_set(AppSet::Input));
+        let parent = app.world_mut().spawn(Transform::from_xyz(0.0, 5.0, 0.0)).id();
+        app.world_mut().spawn((Rig, Transform::default(), ChildOf(parent)));
+
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Ran>().0, ["input", "camera", "ui", "input", "camera", "ui"]);
+        assert_eq!(
+            app.world().resource::<Seen>().0,
+            [Vec3::new(1.0, 5.0, 0.0), Vec3::new(2.0, 5.0, 0.0)],
+            "no frame of lag, parents included"
+        );
+    }
 }
@@ -0,0 +1,61 @@
+// src/app/state.rs
+use bevy::prelude::*;
+
+/// Top-level run state of the game.
+///
+/// Gameplay simulation only advances in `Playing`: entering any other state pauses
+/// virtual time (and with it `FixedUpdate`); features that must keep running
+/// while paused (UI, UI sounds) read real time or ignore the state.
+#[derive(States, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameState {
+    #[default]
+    Playing,
+    Paused,
+    /// A full-screen menu (settings, main menu) is open.
+    Menu,
+    /// First-launch setup (language, volume, controls) before play.
+    Onboarding,
+}
+
+impl GameState {
+    pub fn is_playing(self) -> bool {
+        self == Self::Playing
+    }
+}
+
+/// Key that toggles `Playing` <-> `Paused`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PauseKeybinding(pub KeyCode);
+
+impl Default for PauseKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::KeyP)
+    }
+}
+
+/// Update: pause / unpause on the pause key (menus handle their own exit).
+pub fn toggle_pause(
+    keys: Res<ButtonInput<KeyCode>>,
+    binding: Res<PauseKeybinding>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if !keys.just_pressed(binding.0) {
+        return;
+    }
+    match state.get() {
+        GameState::Playing => next.set(GameState::Paused),
+        GameState::Paused => next.set(GameState::Playing),
+        GameState::Menu | GameState::Onboarding => {}
+    }
+}
+
+/// OnEnter(Playing): the simulation runs again.
+pub fn resume_virtual_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+/// OnExit(Playing): freeze virtual time (and with it `FixedUpdate`).
+pub fn pause_virtual_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
@@ -0,0 +1,244 @@
+// src/app/storage.rs
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+/// Folder name under the platform's data / config directories.
+const APP_DIR: &str = "to_be_free";
+
+/// Where every persisted file lives.
+///
+/// - saves and progress go to `data_dir`, settings to `config_dir`; on Windows both
+///   sit in the roaming profile (`%APPDATA%`) so cloud / profile sync picks them up
+/// - portable mode keeps both in `saves/` next to the executable
+/// - `--data-dir <dir>` / `TO_BE_FREE_DATA_DIR` put everything in one directory
+///   (tests, multiple profiles); `--portable` / `TO_BE_FREE_PORTABLE=1` force portable
+///
+/// The default (no resolution) is `saves/` in the working directory, which keeps
+/// headless apps and tests away from the user's real files.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct StoragePaths {
+    pub data_dir: PathBuf,
+    pub config_dir: PathBuf,
+    pub portable: bool,
+}
+
+impl Default for StoragePaths {
+    fn default() -> Self {
+        Self::in_dir("saves")
+    }
+}
+
+/// Operating system families with different conventions for app data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    Windows,
+    MacOs,
+    /// Linux and other XDG desktops.
+    Unix,
+}
+
+impl Platform {
+    pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else {
+            Self::Unix
+        }
+    }
+}
+
+impl StoragePaths {
+    /// Everything in one directory.
+    pub fn in_dir(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        Self {
+            data_dir: dir.clone(),
+            config_dir: dir,
+            portable: false,
+        }
+    }
+
+    /// Portable install: `saves/` next to the executable.
+    pub fn portable(exe_dir: &Path) -> Self {
+        Self {
+            portable: true,
+            ..Self::in_dir(exe_dir.join("saves"))
+        }
+    }
+
+    /// The platform's per-user directories, from the environment (`None` when the
+    /// variables they derive from are missing).
+    pub fn platform(platform: Platform, env: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let var = |name: &str| env(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+        let (data, config) = match platform {
+            Platform::Windows => {
+                let roaming = var("APPDATA")?;
+                (roaming.clone(), roaming)
+            }
+            Platform::MacOs => {
+                let support = var("HOME")?.join("Library/Application Support");
+                (support.clone(), support)
+            }
+            Platform::Unix => {
+                let home = var("HOME");
+                let data = var("XDG_DATA_HOME")
+                    .or_else(|| home.clone().map(|h| h.join(".local/share")))?;
+                let config = var("XDG_CONFIG_HOME").or_else(|| home.map(|h| h.join(".config")))?;
+                (data, config)
+            }
+        };
+        Some(Self {
+            data_dir: data.join(APP_DIR),
+            config_dir: config.join(APP_DIR),
+            portable: false,
+        })
+    }
+
+    /// Pick the storage location: explicit directory (CLI, then env), then portable
+    /// mode, then platform directories, then the working-directory default.
+    pub fn resolve(
+        args: &[String],
+        env: impl Fn(&str) -> Option<String>,
+        exe_dir: Option<&Path>,
+        platform: Platform,
+    ) -> Self {
+        let flag_value = |flag: &str| {
+            args.iter()
+                .position(|a| a == flag)
+                .and_then(|i| args.get(i + 1))
+                .cloned()
+        };
+        if let Some(dir) = flag_value("--data-dir").or_else(|| env("TO_BE_FREE_DATA_DIR")) {
+            return Self::in_dir(dir);
+        }
+        let portable = args.iter().any(|a| a == "--portable")
+            || env("TO_BE_FREE_PORTABLE").is_some_and(|v| v == "1" || v == "true");
+        if portable && let Some(exe_dir) = exe_dir {
+            return Self::portable(exe_dir);
+        }
+        Self::platform(platform, env).unwrap_or_default()
+    }
+
+    /// `resolve` against this process's arguments, environment and executable.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let exe = std::env::current_exe().ok();
+        Self::resolve(
+            &args,
+            |name| std::env::var(name).ok(),
+            exe.as_deref().and_then(Path::parent),
+            Platform::current(),
+        )
+    }
+
+    /// The paths in `world`, or the default when none were set up.
+    pub fn of(world: &World) -> Self {
+        world.get_resource::<Self>().cloned().unwrap_or_default()
+    }
+
+    /// A save / progress file.
+    pub fn data_file(&self, name: &str) -> PathBuf {
+        self.data_dir.join(name)
+    }
+
+    /// A settings file.
+    pub fn config_file(&self, name: &str) -> PathBuf {
+        self.config_dir.join(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn platform_directories_follow_each_os_convention() {
+        let unix = StoragePaths::platform(Platform::Unix, env(&[("HOME", "/home/ada")])).unwrap();
+        assert_eq!(
+            unix.data_dir,
+            Path::new("/home/ada/.local/share/to_be_free")
+        );
+        assert_eq!(
+            unix.config_file("settings.ron"),
+            Path::new("/home/ada/.config/to_be_free/settings.ron")
+        );
+
+        let xdg = StoragePaths::platform(
+            Platform::Unix,
+            env(&[("HOME", "/home/ada"), ("XDG_DATA_HOME", "/data")]),
+        )
+        .unwrap();
+        assert_eq!(xdg.data_dir, Path::new("/data/to_be_free"));
+
+        let windows = StoragePaths::platform(Platform::Windows, env(&[("APPDATA", "C:/Roaming")]));
+        assert_eq!(
+            windows.unwrap().config_dir,
+            Path::new("C:/Roaming/to_be_free")
+        );
+        assert_eq!(StoragePaths::platform(Platform::MacOs, env(&[])), None);
+    }
+
+    #[test]
+    fn explicit_directories_beat_portable_mode_which_beats_platform_dirs() {
+        let home = [("HOME", "/home/ada"), ("TO_BE_FREE_PORTABLE", "1")];
+        let exe = Path::new("/games/to_be_free");
+
+        let cli = StoragePaths::resolve(
+            &args(&["--data-dir", "/tmp/p2"]),
+            env(&home),
+            Some(exe),
+            Platform::Unix,
+        );
+        assert_eq!(cli, StoragePaths::in_dir("/tmp/p2"));
+
+        let portable = StoragePaths::resolve(&[], env(&home), Some(exe), Platform::Unix);
+        assert!(portable.portable);
+        assert_eq!(portable.data_file("game.ron"), exe.join("saves/game.ron"));
+
+        let installed = StoragePaths::resolve(&[], env(&home[..1]), Some(exe), Platform::Unix);
+        assert!(!installed.portable);
+        assert_eq!(
+            installed.data_dir,
+            Path::new("/home/ada/.local/share/to_be_free")
+        );
+
+        let bare = StoragePaths::resolve(&[], env(&[]), None, Platform::Unix);
+        assert_eq!(bare, StoragePaths::default());
+    }
+
+    #[test]
+    fn files_land_in_an_overridden_temp_directory() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_storage_{}", std::process::id()));
+        let dir_arg = dir.to_string_lossy().to_string();
+        let paths = StoragePaths::resolve(
+            &args(&["--data-dir", &dir_arg]),
+            env(&[]),
+            None,
+            Platform::current(),
+        );
+
+        let file = paths.data_file("probe.txt");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, "ok").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("probe.txt")).unwrap(),
+            "ok"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
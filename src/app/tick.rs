@@ -0,0 +1,62 @@
+// src/app/tick.rs
+use bevy::prelude::*;
+
+/// Number of the fixed simulation tick currently running.
+///
+/// Incremented at the start of every `FixedUpdate` step (in `FixedFirst`), so all
+/// fixed systems of one step see the same value; the first step is tick `1`, `0`
+/// means the simulation hasn't stepped yet. Paused time doesn't tick.
+///
+/// Use it to stamp gameplay events (replays, rollback, "what happened on tick 4312?")
+/// rather than wall-clock or virtual time, which drift between runs.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SimTick(pub u64);
+
+/// Client simulation rate (`FixedUpdate` steps per second).
+pub const CLIENT_TICK_HZ: f64 = 60.0;
+
+/// Default dedicated server simulation rate.
+pub const SERVER_TICK_HZ: f64 = 30.0;
+
+/// `FixedUpdate` rate this app simulates at.
+///
+/// Inserted before `AppPlugin` builds (`build_app_with` does, per `AppMode`);
+/// `AppPlugin` configures `Time<Fixed>` from it and falls back to the client rate.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TickRate(pub f64);
+
+impl Default for TickRate {
+    fn default() -> Self {
+        Self(CLIENT_TICK_HZ)
+    }
+}
+
+/// FixedFirst: advance `SimTick`.
+pub fn advance_sim_tick(mut tick: ResMut<SimTick>) {
+    tick.0 += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn ticks_once_per_fixed_step() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<SimTick>();
+        app.add_systems(FixedFirst, advance_sim_tick);
+        // 2.5 fixed steps per frame at 64 Hz (Bevy's default).
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f64(2.5 / 64.0),
+        ));
+
+        app.update();
+        app.update();
+        app.update();
+
+        // The first frame only starts the clock; 2 * 2.5 steps afterwards.
+        assert_eq!(*app.world().resource::<SimTick>(), SimTick(5));
+    }
+}
@@ -0,0 +1,291 @@
+// src/app/tuning.rs
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::faction::FactionTable;
+
+/// Difficulty preset picked in the settings menu.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Difficulty {
+    Story,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Self; 3] = [Self::Story, Self::Normal, Self::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Story => "Story",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+        }
+    }
+
+    /// The next preset (menu button cycles through them).
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Gameplay multipliers of the current run.
+///
+/// The single place difficulty lands: gameplay systems read these factors instead
+/// of checking `Difficulty` themselves. Fixed when a run starts, so changing the
+/// preset mid-run doesn't rescale enemies already in the world.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameplayTuning {
+    /// Preset the multipliers came from (recorded with run results).
+    pub difficulty: Difficulty,
+    /// Damage dealt by enemies.
+    pub enemy_damage: f32,
+    /// Max health of newly spawned enemies.
+    pub enemy_health: f32,
+    /// Enemy spawner speed (`2.0` = spawns twice as often).
+    pub spawn_rate: f32,
+    /// Stamina cost of abilities.
+    pub stamina_drain: f32,
+    /// Landing impact thresholds (same on every difficulty).
+    pub fall: FallTuning,
+}
+
+/// When a landing hurts (speeds in world units/sec of peak downward velocity).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FallTuning {
+    /// Landings at or below this speed are harmless.
+    pub safe_speed: f32,
+    /// Damage per unit/sec above `safe_speed`.
+    pub damage_per_speed: f32,
+    /// Landings above this speed stagger (briefly slow) the lander.
+    pub stagger_speed: f32,
+    pub stagger_secs: f32,
+    /// Move-speed reduction while staggered (fraction, `0.5` = -50%).
+    pub stagger_slow: f32,
+}
+
+impl Default for FallTuning {
+    fn default() -> Self {
+        Self {
+            // About a 7 m drop at 9.81 m/s².
+            safe_speed: 12.0,
+            damage_per_speed: 8.0,
+            // About a 4 m drop.
+            stagger_speed: 9.0,
+            stagger_secs: 0.6,
+            stagger_slow: 0.5,
+        }
+    }
+}
+
+impl GameplayTuning {
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        let (enemy_damage, enemy_health, spawn_rate, stamina_drain) = match difficulty {
+            Difficulty::Story => (0.5, 0.75, 0.5, 0.5),
+            Difficulty::Normal => (1.0, 1.0, 1.0, 1.0),
+            Difficulty::Hard => (1.5, 1.5, 1.5, 1.25),
+        };
+        Self {
+            difficulty,
+            enemy_damage,
+            enemy_health,
+            spawn_rate,
+            stamina_drain,
+            fall: FallTuning::default(),
+        }
+    }
+}
+
+impl Default for GameplayTuning {
+    fn default() -> Self {
+        Self::for_difficulty(Difficulty::default())
+    }
+}
+
+/// Gameplay feel constants, from `assets/data/tuning.ron`.
+///
+/// The one place designers tune how the game feels; features read these values
+/// where they use them instead of hardcoding their own numbers. Dev builds reload
+/// the file while the game runs (`TuningSource`), so values may change at any tick.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct Tuning {
+    /// Player run speed (units/sec); players spawned afterwards get it.
+    pub player_speed: f32,
+    /// Downward pull on props (units/sec²).
+    pub gravity: f32,
+    /// Peak of a jump (units).
+    pub jump_height: f32,
+    /// Horizontal speed props lose per second while resting on the ground (1/sec).
+    pub ground_friction: f32,
+    /// Push of the basic dash ability (units/sec).
+    pub dash_speed: f32,
+    /// How far enemies notice the player (awareness gain fades to 0 there).
+    pub aggro_range: f32,
+    /// Who is hostile to whom, and whether allies hurt each other.
+    pub factions: FactionTable,
+}
+
+const BUILTIN_TUNING: &str = include_str!("../../assets/data/tuning.ron");
+
+impl Tuning {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Launch speed that reaches `jump_height` under `gravity`.
+    pub fn jump_speed(&self) -> f32 {
+        (2.0 * self.gravity.max(0.0) * self.jump_height.max(0.0)).sqrt()
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_TUNING).expect("assets/data/tuning.ron must parse")
+    }
+}
+
+/// Dev builds: the tuning file watched for edits.
+#[derive(Resource, Debug, Clone)]
+pub struct TuningSource {
+    pub path: PathBuf,
+    /// Seconds between checks of the file's modification time.
+    pub poll_secs: f32,
+    since_poll: f32,
+    modified: Option<SystemTime>,
+}
+
+impl Default for TuningSource {
+    fn default() -> Self {
+        Self::new("assets/data/tuning.ron")
+    }
+}
+
+impl TuningSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self {
+            modified: modified_at(&path),
+            path,
+            poll_secs: 0.5,
+            since_poll: 0.0,
+        }
+    }
+}
+
+fn modified_at(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Update (dev builds): reload `Tuning` when its file changes on disk.
+///
+/// A file that doesn't parse is reported and skipped; the previous values stay
+/// until the next good save.
+pub fn reload_tuning(time: Res<Time<Real>>, mut source: ResMut<TuningSource>, mut tuning: ResMut<Tuning>) {
+    source.since_poll += time.delta_secs();
+    if source.since_poll < source.poll_secs {
+        return;
+    }
+    source.since_poll = 0.0;
+
+    let modified = modified_at(&source.path);
+    if modified.is_none() || modified == source.modified {
+        return;
+    }
+    source.modified = modified;
+
+    let Ok(text) = std::fs::read_to_string(&source.path) else {
+        return;
+    };
+    match Tuning::from_ron(&text) {
+        Ok(reloaded) => {
+            info!("reloaded tuning from {}", source.path.display());
+            tuning.set_if_neq(reloaded);
+        }
+        Err(err) => warn!("{}: {err}", source.path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{Faction, Relation};
+
+    #[test]
+    fn normal_is_neutral_and_presets_scale_monotonically() {
+        let normal = GameplayTuning::default();
+        assert_eq!(normal.difficulty, Difficulty::Normal);
+        assert_eq!(
+            (normal.enemy_damage, normal.enemy_health, normal.spawn_rate, normal.stamina_drain),
+            (1.0, 1.0, 1.0, 1.0)
+        );
+
+        let [story, _, hard] = Difficulty::ALL.map(GameplayTuning::for_difficulty);
+        assert!(story.enemy_damage < 1.0 && hard.enemy_damage > 1.0);
+        assert!(story.enemy_health < 1.0 && hard.enemy_health > 1.0);
+        assert!(story.spawn_rate < 1.0 && hard.spawn_rate > 1.0);
+        assert!(story.stamina_drain < 1.0 && hard.stamina_drain > 1.0);
+    }
+
+    #[test]
+    fn next_cycles_through_all_presets() {
+        assert_eq!(Difficulty::Story.next(), Difficulty::Normal);
+        assert_eq!(Difficulty::Hard.next(), Difficulty::Story);
+    }
+
+    #[test]
+    fn builtin_tuning_parses_and_derives_the_jump_speed() {
+        let tuning = Tuning::default();
+        assert_eq!(tuning.gravity, 9.81);
+        assert_eq!(tuning.player_speed, 5.0);
+        assert_eq!(
+            tuning.factions.relation(Faction::Monsters, Faction::Players),
+            Relation::Hostile
+        );
+        // v² = 2gh
+        assert!((tuning.jump_speed().powi(2) - 2.0 * 9.81 * tuning.jump_height).abs() < 1e-4);
+    }
+
+    #[test]
+    fn edits_on_disk_are_reloaded_and_broken_files_skipped() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let dir = std::env::temp_dir().join(format!("to_be_free_tuning_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tuning.ron");
+        std::fs::write(&path, BUILTIN_TUNING).unwrap();
+        // Filesystems with coarse timestamps: move the clock along explicitly.
+        let touch = |text: &str, secs: u64| {
+            std::fs::write(&path, text).unwrap();
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(secs)).unwrap();
+        };
+
+        let mut world = World::new();
+        world.init_resource::<Tuning>();
+        world.init_resource::<Time<Real>>();
+        world.insert_resource(TuningSource {
+            poll_secs: 0.0,
+            ..TuningSource::new(&path)
+        });
+        let reload = |world: &mut World| world.run_system_once(reload_tuning).unwrap();
+
+        reload(&mut world);
+        assert_eq!(*world.resource::<Tuning>(), Tuning::default(), "unchanged file");
+
+        touch(&BUILTIN_TUNING.replace("dash_speed: 14.0", "dash_speed: 30.0"), 1_000);
+        reload(&mut world);
+        assert_eq!(world.resource::<Tuning>().dash_speed, 30.0);
+
+        touch("(player_speed: oops)", 2_000);
+        reload(&mut world);
+        assert_eq!(world.resource::<Tuning>().dash_speed, 30.0, "kept the last good values");
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,190 @@
+// src/features/ability/component.rs
+use bevy::prelude::*;
+
+/// A regenerating resource pool (stamina, mana).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pool {
+    pub current: f32,
+    pub max: f32,
+    /// Units restored per second.
+    pub regen_per_sec: f32,
+}
+
+impl Pool {
+    pub fn new(max: f32, regen_per_sec: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen_per_sec,
+        }
+    }
+
+    /// Spend `amount` if available. Returns `false` (and spends nothing) otherwise.
+    pub fn try_spend(&mut self, amount: f32) -> bool {
+        if self.current + f32::EPSILON < amount {
+            return false;
+        }
+        self.current = (self.current - amount).max(0.0);
+        true
+    }
+
+    pub fn regen(&mut self, dt: f32) {
+        self.current = (self.current + self.regen_per_sec * dt).min(self.max);
+    }
+}
+
+/// Stamina pool (physical abilities: dash, sprint, heavy attacks).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Stamina(pub Pool);
+
+/// Mana pool (magical abilities).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Mana(pub Pool);
+
+/// What an ability costs to activate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AbilityCost {
+    Free,
+    Stamina(f32),
+    Mana(f32),
+}
+
+/// What happens when an ability activates.
+///
+/// Effects are executed by whichever feature owns them, by reading
+/// [`super::systems::AbilityActivated`]; this enum is only the shared vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AbilityEffect {
+    /// Burst of speed along the current movement direction (world units/sec).
+    Dash { speed: f32 },
+    /// Feature-defined effect identified by name (grapple, attacks, ...).
+    Custom(&'static str),
+}
+
+/// Static description of an ability.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityDef {
+    pub name: &'static str,
+    pub cost: AbilityCost,
+    /// Wind-up before the effect fires. `0.0` = instant.
+    pub cast_secs: f32,
+    /// Time the ability stays active after firing (beams, channels). `0.0` = none.
+    pub channel_secs: f32,
+    /// Cooldown starting once cast + channel are over.
+    pub cooldown_secs: f32,
+    pub effect: AbilityEffect,
+}
+
+impl AbilityDef {
+    /// Default dash: instant, cheap, short cooldown.
+    pub fn dash() -> Self {
+        Self {
+            name: "dash",
+            cost: AbilityCost::Stamina(25.0),
+            cast_secs: 0.0,
+            channel_secs: 0.0,
+            cooldown_secs: 1.0,
+            effect: AbilityEffect::Dash { speed: 14.0 },
+        }
+    }
+
+    /// Long-range dash paid with mana (unlocked through the skill tree).
+    pub fn blink() -> Self {
+        Self {
+            name: "blink",
+            cost: AbilityCost::Mana(20.0),
+            cast_secs: 0.0,
+            channel_secs: 0.0,
+            cooldown_secs: 4.0,
+            effect: AbilityEffect::Dash { speed: 26.0 },
+        }
+    }
+
+    /// Built-in ability by name (data files refer to abilities this way).
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "dash" => Some(Self::dash()),
+            "blink" => Some(Self::blink()),
+            _ => None,
+        }
+    }
+}
+
+/// Lifecycle of a slotted ability.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbilityState {
+    Ready,
+    Casting(Timer),
+    Channeling(Timer),
+    Cooldown(Timer),
+}
+
+/// One equipped ability and its runtime state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilitySlot {
+    pub def: AbilityDef,
+    pub state: AbilityState,
+}
+
+impl AbilitySlot {
+    pub fn new(def: AbilityDef) -> Self {
+        Self {
+            def,
+            state: AbilityState::Ready,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        matches!(self.state, AbilityState::Ready)
+    }
+
+    /// Remaining cooldown in seconds (`0.0` unless cooling down), for HUD display.
+    pub fn cooldown_remaining_secs(&self) -> f32 {
+        match &self.state {
+            AbilityState::Cooldown(t) => t.remaining_secs(),
+            _ => 0.0,
+        }
+    }
+}
+
+/// All abilities an entity can activate, indexed by slot number.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct AbilitySlots(pub Vec<AbilitySlot>);
+
+impl AbilitySlots {
+    /// `true` while any ability is casting or channeling (hands busy).
+    pub fn is_busy(&self) -> bool {
+        self.0.iter().any(|slot| {
+            matches!(
+                slot.state,
+                AbilityState::Casting(_) | AbilityState::Channeling(_)
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_spend_and_regen() {
+        let mut pool = Pool::new(50.0, 10.0);
+
+        assert!(pool.try_spend(30.0));
+        assert_eq!(pool.current, 20.0);
+
+        assert!(!pool.try_spend(30.0), "insufficient pool must not spend");
+        assert_eq!(pool.current, 20.0);
+
+        pool.regen(10.0);
+        assert_eq!(pool.current, 50.0, "regen clamps to max");
+    }
+
+    #[test]
+    fn slot_starts_ready_without_cooldown() {
+        let slot = AbilitySlot::new(AbilityDef::dash());
+        assert!(slot.is_ready());
+        assert_eq!(slot.cooldown_remaining_secs(), 0.0);
+    }
+}
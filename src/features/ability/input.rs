@@ -0,0 +1,100 @@
+// src/features/ability/input.rs
+use bevy::prelude::*;
+
+use super::component::AbilitySlots;
+use super::systems::AbilityRequest;
+use crate::features::player::death::AlivePlayer;
+
+/// Keybindings for ability slots (index = slot number).
+#[derive(Resource, Debug, Clone)]
+pub struct AbilityKeybindings {
+    pub slots: Vec<KeyCode>,
+}
+
+impl Default for AbilityKeybindings {
+    fn default() -> Self {
+        Self {
+            slots: vec![KeyCode::KeyQ, KeyCode::KeyE, KeyCode::KeyR, KeyCode::KeyF],
+        }
+    }
+}
+
+/// Update: turn just-pressed ability keys into `AbilityRequest`s for living players.
+///
+/// Only *requests* activation; readiness and cost are checked in FixedUpdate.
+pub fn read_ability_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<AbilityKeybindings>,
+    q_player: Query<(Entity, &AbilitySlots), AlivePlayer>,
+    mut requests: MessageWriter<AbilityRequest>,
+) {
+    for (slot, key) in bindings.slots.iter().enumerate() {
+        if !keyboard.just_pressed(*key) {
+            continue;
+        }
+
+        for (entity, slots) in &q_player {
+            if slot < slots.0.len() {
+                requests.write(AbilityRequest { entity, slot });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::ability::component::{AbilityDef, AbilitySlot};
+    use crate::features::player::component::Player;
+    use crate::features::player::death::Dead;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(AbilityKeybindings::default());
+        world.init_resource::<Messages<AbilityRequest>>();
+        world
+    }
+
+    fn requests(world: &World) -> Vec<AbilityRequest> {
+        world
+            .resource::<Messages<AbilityRequest>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn pressed_slot_key_requests_activation() {
+        let mut world = setup_world();
+        let e = world
+            .spawn((Player, AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())])))
+            .id();
+
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyQ);
+        let _ = world.run_system_once(read_ability_input);
+
+        assert_eq!(requests(&world), vec![AbilityRequest { entity: e, slot: 0 }]);
+    }
+
+    #[test]
+    fn empty_slots_and_dead_players_are_ignored() {
+        let mut world = setup_world();
+        world.spawn((Player, AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())])));
+        world.spawn((
+            Player,
+            Dead,
+            AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())]),
+        ));
+
+        // Slot 1 (E) is empty on both players; slot 0 only for the live one.
+        let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::KeyE);
+        keyboard.press(KeyCode::KeyQ);
+        let _ = world.run_system_once(read_ability_input);
+
+        assert_eq!(requests(&world).len(), 1);
+    }
+}
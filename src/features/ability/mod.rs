@@ -0,0 +1,54 @@
+// src/features/ability/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, Tuning, in_input_context};
+
+pub mod component;
+pub mod input;
+pub mod systems;
+
+/// Ability feature plugin: shared activation / cost / cooldown plumbing.
+///
+/// Scope:
+/// - Update: ability keys -> `AbilityRequest`
+/// - FixedUpdate: requests -> cost + cast/channel/cooldown state machine -> `AbilityActivated`
+/// - Built-in effect: `Dash` (impulse through `ExternalForce`); the basic dash
+///   pushes at `Tuning::dash_speed`
+///
+/// Design constraints:
+/// - Abilities never run bespoke timers; they describe themselves with `AbilityDef`.
+/// - Effects other than dash are executed by their owning features reading
+///   `AbilityActivated` (match on `AbilityEffect::Custom(name)`).
+pub struct AbilityPlugin;
+
+impl Plugin for AbilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<input::AbilityKeybindings>();
+        app.init_resource::<Tuning>();
+
+        app.add_message::<systems::AbilityRequest>();
+        app.add_message::<systems::AbilityActivated>();
+        app.add_message::<systems::AbilityRejected>();
+
+        app.add_systems(
+            Update,
+            input::read_ability_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_default_player_abilities,
+                systems::retune_dash,
+                systems::regen_pools,
+                systems::tick_abilities,
+                systems::process_ability_requests,
+                systems::apply_dash,
+            )
+                .chain()
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
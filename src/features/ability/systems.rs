@@ -0,0 +1,476 @@
+// src/features/ability/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    AbilityCost, AbilityDef, AbilityEffect, AbilitySlot, AbilitySlots, AbilityState, Mana, Pool,
+    Stamina,
+};
+use crate::app::{GameplayTuning, Tuning};
+use crate::features::movement::component::{ExternalForce, Velocity};
+use crate::features::player::component::Player;
+
+/// Request to activate the ability in `slot` on `entity` (from input, AI, scripts).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbilityRequest {
+    pub entity: Entity,
+    pub slot: usize,
+}
+
+/// Emitted when an ability's effect fires (end of cast, or immediately if instant).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct AbilityActivated {
+    pub entity: Entity,
+    pub slot: usize,
+    pub effect: AbilityEffect,
+}
+
+/// Why an activation request was refused (UI feedback / audio "denied" cue).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbilityRejection {
+    /// Slot is casting, channeling or cooling down.
+    NotReady,
+    /// Not enough stamina / mana (or the entity has no such pool).
+    InsufficientResource,
+    /// No ability in that slot.
+    EmptySlot,
+}
+
+/// Emitted when an `AbilityRequest` could not be honored.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbilityRejected {
+    pub entity: Entity,
+    pub slot: usize,
+    pub reason: AbilityRejection,
+}
+
+/// State to enter once the effect has fired.
+fn state_after_fire(def: &AbilityDef) -> AbilityState {
+    if def.channel_secs > 0.0 {
+        AbilityState::Channeling(Timer::from_seconds(def.channel_secs, TimerMode::Once))
+    } else {
+        state_after_channel(def)
+    }
+}
+
+/// State to enter once cast + channel are over.
+fn state_after_channel(def: &AbilityDef) -> AbilityState {
+    if def.cooldown_secs > 0.0 {
+        AbilityState::Cooldown(Timer::from_seconds(def.cooldown_secs, TimerMode::Once))
+    } else {
+        AbilityState::Ready
+    }
+}
+
+fn try_pay(
+    cost: AbilityCost,
+    stamina_drain: f32,
+    stamina: Option<&mut Pool>,
+    mana: Option<&mut Pool>,
+) -> bool {
+    match cost {
+        AbilityCost::Free => true,
+        AbilityCost::Stamina(amount) => stamina.is_some_and(|p| p.try_spend(amount * stamina_drain)),
+        AbilityCost::Mana(amount) => mana.is_some_and(|p| p.try_spend(amount)),
+    }
+}
+
+/// FixedUpdate: validate requests, pay costs, start casts (or fire instant abilities).
+///
+/// Stamina costs scale with `GameplayTuning::stamina_drain`.
+pub fn process_ability_requests(
+    tuning: Res<GameplayTuning>,
+    mut requests: MessageReader<AbilityRequest>,
+    mut q_caster: Query<(&mut AbilitySlots, Option<&mut Stamina>, Option<&mut Mana>)>,
+    mut activated: MessageWriter<AbilityActivated>,
+    mut rejected: MessageWriter<AbilityRejected>,
+) {
+    for req in requests.read() {
+        let Ok((mut slots, mut stamina, mut mana)) = q_caster.get_mut(req.entity) else {
+            continue;
+        };
+
+        let reject = |reason| AbilityRejected {
+            entity: req.entity,
+            slot: req.slot,
+            reason,
+        };
+
+        let Some(slot) = slots.0.get_mut(req.slot) else {
+            rejected.write(reject(AbilityRejection::EmptySlot));
+            continue;
+        };
+
+        if !slot.is_ready() {
+            rejected.write(reject(AbilityRejection::NotReady));
+            continue;
+        }
+
+        let paid = try_pay(
+            slot.def.cost,
+            tuning.stamina_drain,
+            stamina.as_mut().map(|s| &mut s.0),
+            mana.as_mut().map(|m| &mut m.0),
+        );
+        if !paid {
+            rejected.write(reject(AbilityRejection::InsufficientResource));
+            continue;
+        }
+
+        if slot.def.cast_secs > 0.0 {
+            slot.state =
+                AbilityState::Casting(Timer::from_seconds(slot.def.cast_secs, TimerMode::Once));
+        } else {
+            activated.write(AbilityActivated {
+                entity: req.entity,
+                slot: req.slot,
+                effect: slot.def.effect,
+            });
+            slot.state = state_after_fire(&slot.def);
+        }
+    }
+}
+
+/// FixedUpdate: advance cast / channel / cooldown timers, firing effects when casts complete.
+pub fn tick_abilities(
+    time: Res<Time<Fixed>>,
+    mut q_slots: Query<(Entity, &mut AbilitySlots)>,
+    mut activated: MessageWriter<AbilityActivated>,
+) {
+    let dt = time.delta();
+
+    for (entity, mut slots) in &mut q_slots {
+        for (index, slot) in slots.0.iter_mut().enumerate() {
+            let AbilitySlot { def, state } = slot;
+
+            let next = match state {
+                AbilityState::Ready => None,
+                AbilityState::Casting(timer) => timer.tick(dt).is_finished().then(|| {
+                    activated.write(AbilityActivated {
+                        entity,
+                        slot: index,
+                        effect: def.effect,
+                    });
+                    state_after_fire(def)
+                }),
+                AbilityState::Channeling(timer) => timer
+                    .tick(dt)
+                    .is_finished()
+                    .then(|| state_after_channel(def)),
+                AbilityState::Cooldown(timer) => timer
+                    .tick(dt)
+                    .is_finished()
+                    .then_some(AbilityState::Ready),
+            };
+
+            if let Some(next) = next {
+                *state = next;
+            }
+        }
+    }
+}
+
+/// FixedUpdate: regenerate stamina and mana pools.
+pub fn regen_pools(
+    time: Res<Time<Fixed>>,
+    mut q_stamina: Query<&mut Stamina>,
+    mut q_mana: Query<&mut Mana>,
+) {
+    let dt = time.delta_secs();
+
+    for mut stamina in &mut q_stamina {
+        stamina.0.regen(dt);
+    }
+    for mut mana in &mut q_mana {
+        mana.0.regen(dt);
+    }
+}
+
+/// FixedUpdate: execute `Dash` activations as an impulse through `ExternalForce`.
+///
+/// Direction: current horizontal velocity if moving, otherwise the entity's forward (-Z).
+pub fn apply_dash(
+    mut activated: MessageReader<AbilityActivated>,
+    mut q_target: Query<(&Transform, Option<&Velocity>, &mut ExternalForce)>,
+) {
+    for ev in activated.read() {
+        let AbilityEffect::Dash { speed } = ev.effect else {
+            continue;
+        };
+        let Ok((transform, velocity, mut external)) = q_target.get_mut(ev.entity) else {
+            continue;
+        };
+
+        let moving = velocity
+            .map(|v| Vec3::new(v.0.x, 0.0, v.0.z))
+            .unwrap_or(Vec3::ZERO)
+            .normalize_or_zero();
+        let dir = if moving == Vec3::ZERO {
+            transform.forward().as_vec3()
+        } else {
+            moving
+        };
+
+        external.apply_impulse(dir * speed);
+    }
+}
+
+/// Default player loadout: dash in slot 0 plus stamina / mana pools.
+pub fn grant_default_player_abilities(
+    mut commands: Commands,
+    q_new_players: Query<Entity, (Added<Player>, Without<AbilitySlots>)>,
+) {
+    for entity in &q_new_players {
+        commands.entity(entity).insert((
+            AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())]),
+            Stamina(Pool::new(100.0, 20.0)),
+            Mana(Pool::new(50.0, 5.0)),
+        ));
+    }
+}
+
+/// FixedUpdate (before requests): basic dashes push at `Tuning::dash_speed`; newly
+/// granted ones from their first tick, all of them again whenever the tuning changes.
+pub fn retune_dash(tuning: Res<Tuning>, mut q_slots: Query<&mut AbilitySlots>) {
+    let dash = AbilityEffect::Dash {
+        speed: tuning.dash_speed,
+    };
+    for mut slots in &mut q_slots {
+        if !tuning.is_changed() && !slots.is_changed() {
+            continue;
+        }
+        for slot in &mut slots.bypass_change_detection().0 {
+            if slot.def.name == "dash" {
+                slot.def.effect = dash;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<AbilityRequest>>();
+        world.init_resource::<Messages<AbilityActivated>>();
+        world.init_resource::<Messages<AbilityRejected>>();
+        world.init_resource::<GameplayTuning>();
+        world
+    }
+
+    fn advance_fixed(world: &mut World, secs: f32) {
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(secs));
+        world.insert_resource(fixed);
+    }
+
+    fn activated(world: &World) -> usize {
+        world.resource::<Messages<AbilityActivated>>().len()
+    }
+
+    fn rejections(world: &World) -> Vec<AbilityRejection> {
+        world
+            .resource::<Messages<AbilityRejected>>()
+            .iter_current_update_messages()
+            .map(|r| r.reason)
+            .collect()
+    }
+
+    fn cast_def() -> AbilityDef {
+        AbilityDef {
+            name: "fireball",
+            cost: AbilityCost::Mana(10.0),
+            cast_secs: 0.5,
+            channel_secs: 0.0,
+            cooldown_secs: 2.0,
+            effect: AbilityEffect::Custom("fireball"),
+        }
+    }
+
+    #[test]
+    fn stamina_costs_scale_with_difficulty() {
+        let mut world = setup_world();
+        world.insert_resource(GameplayTuning::for_difficulty(crate::app::Difficulty::Hard));
+        let e = world
+            .spawn((
+                AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())]),
+                Stamina(Pool::new(100.0, 0.0)),
+            ))
+            .id();
+
+        world.write_message(AbilityRequest { entity: e, slot: 0 });
+        let _ = world.run_system_once(process_ability_requests);
+
+        // Dash costs 25, x1.25 on Hard.
+        assert_eq!(world.get::<Stamina>(e).unwrap().0.current, 68.75);
+    }
+
+    #[test]
+    fn instant_ability_fires_pays_and_enters_cooldown() {
+        let mut world = setup_world();
+        let e = world
+            .spawn((
+                AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())]),
+                Stamina(Pool::new(100.0, 0.0)),
+            ))
+            .id();
+
+        world.write_message(AbilityRequest { entity: e, slot: 0 });
+        let _ = world.run_system_once(process_ability_requests);
+
+        assert_eq!(activated(&world), 1);
+        assert_eq!(world.get::<Stamina>(e).unwrap().0.current, 75.0);
+        let slot = &world.get::<AbilitySlots>(e).unwrap().0[0];
+        assert_eq!(slot.cooldown_remaining_secs(), 1.0);
+
+        // Second request during cooldown is rejected.
+        // (`run_system_once` uses a fresh reader, so drop the already-handled request.)
+        world.resource_mut::<Messages<AbilityRequest>>().clear();
+        world.write_message(AbilityRequest { entity: e, slot: 0 });
+        let _ = world.run_system_once(process_ability_requests);
+        assert_eq!(rejections(&world), vec![AbilityRejection::NotReady]);
+    }
+
+    #[test]
+    fn insufficient_resource_and_empty_slot_are_rejected() {
+        let mut world = setup_world();
+        let e = world
+            .spawn((
+                AbilitySlots(vec![AbilitySlot::new(cast_def())]),
+                Mana(Pool {
+                    current: 5.0,
+                    max: 50.0,
+                    regen_per_sec: 0.0,
+                }),
+            ))
+            .id();
+
+        world.write_message(AbilityRequest { entity: e, slot: 0 });
+        world.write_message(AbilityRequest { entity: e, slot: 3 });
+        let _ = world.run_system_once(process_ability_requests);
+
+        assert_eq!(
+            rejections(&world),
+            vec![
+                AbilityRejection::InsufficientResource,
+                AbilityRejection::EmptySlot
+            ]
+        );
+        assert!(world.get::<AbilitySlots>(e).unwrap().0[0].is_ready());
+    }
+
+    #[test]
+    fn cast_fires_after_wind_up_then_cools_down() {
+        let mut world = setup_world();
+        let e = world
+            .spawn((
+                AbilitySlots(vec![AbilitySlot::new(cast_def())]),
+                Mana(Pool::new(50.0, 0.0)),
+            ))
+            .id();
+
+        world.write_message(AbilityRequest { entity: e, slot: 0 });
+        let _ = world.run_system_once(process_ability_requests);
+        assert_eq!(activated(&world), 0, "casting abilities don't fire immediately");
+
+        advance_fixed(&mut world, 0.5);
+        let _ = world.run_system_once(tick_abilities);
+        assert_eq!(activated(&world), 1);
+        assert_eq!(
+            world.get::<AbilitySlots>(e).unwrap().0[0].cooldown_remaining_secs(),
+            2.0
+        );
+
+        advance_fixed(&mut world, 2.0);
+        let _ = world.run_system_once(tick_abilities);
+        assert!(world.get::<AbilitySlots>(e).unwrap().0[0].is_ready());
+    }
+
+    #[test]
+    fn dash_pushes_along_movement_direction() {
+        let mut world = setup_world();
+        let e = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec3::new(3.0, 0.0, 0.0)),
+                ExternalForce::default(),
+            ))
+            .id();
+
+        world.write_message(AbilityActivated {
+            entity: e,
+            slot: 0,
+            effect: AbilityEffect::Dash { speed: 10.0 },
+        });
+        let _ = world.run_system_once(apply_dash);
+
+        assert_eq!(
+            world.get::<ExternalForce>(e).unwrap().velocity,
+            Vec3::new(10.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn basic_dashes_follow_the_tuned_speed() {
+        let mut world = setup_world();
+        world.init_resource::<Tuning>();
+        world.resource_mut::<Tuning>().dash_speed = 20.0;
+        let retune = world.register_system(retune_dash);
+        let e = world
+            .spawn(AbilitySlots(vec![
+                AbilitySlot::new(AbilityDef::dash()),
+                AbilitySlot::new(AbilityDef::blink()),
+            ]))
+            .id();
+        let effects = |world: &World| -> Vec<AbilityEffect> {
+            world.get::<AbilitySlots>(e).unwrap().0.iter().map(|slot| slot.def.effect).collect()
+        };
+
+        world.run_system(retune).unwrap();
+        assert_eq!(
+            effects(&world),
+            [AbilityEffect::Dash { speed: 20.0 }, AbilityEffect::Dash { speed: 26.0 }],
+            "blink keeps its own speed"
+        );
+
+        world.resource_mut::<Tuning>().dash_speed = 9.0;
+        world.run_system(retune).unwrap();
+        assert_eq!(effects(&world)[0], AbilityEffect::Dash { speed: 9.0 });
+    }
+
+    #[test]
+    fn dash_defaults_to_forward_when_idle() {
+        let mut world = setup_world();
+        let e = world
+            .spawn((Transform::default(), ExternalForce::default()))
+            .id();
+
+        world.write_message(AbilityActivated {
+            entity: e,
+            slot: 0,
+            effect: AbilityEffect::Dash { speed: 10.0 },
+        });
+        let _ = world.run_system_once(apply_dash);
+
+        assert_eq!(
+            world.get::<ExternalForce>(e).unwrap().velocity,
+            Vec3::NEG_Z * 10.0
+        );
+    }
+
+    #[test]
+    fn new_players_get_default_loadout_once() {
+        let mut world = World::new();
+        let e = world.spawn(Player).id();
+
+        let _ = world.run_system_once(grant_default_player_abilities);
+
+        assert_eq!(world.get::<AbilitySlots>(e).unwrap().0.len(), 1);
+        assert!(world.get::<Stamina>(e).is_some());
+        assert!(world.get::<Mana>(e).is_some());
+    }
+}
@@ -0,0 +1,51 @@
+// src/features/accessibility/component.rs
+use bevy::prelude::*;
+
+/// Outline drawn around the focused menu button.
+pub const FOCUS_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+/// A UI text's authored size, before the player's text size is applied.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BaseFontSize(pub f32);
+
+/// The menu button keyboard / gamepad input acts on.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Focused;
+
+/// Keyboard / gamepad focus among the visible menu buttons.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MenuFocus {
+    pub focused: Option<Entity>,
+    /// Position of the focus in reading order; kept when a menu rebuilds its buttons
+    /// so the focus lands on the same slot.
+    pub index: usize,
+    /// Button pressed from the keyboard / gamepad, released again the next frame.
+    pub pressed: Option<Entity>,
+}
+
+/// Menu navigation keys and gamepad buttons.
+///
+/// Up / left and down / right step through the buttons in reading order (`Tab` and
+/// `Shift+Tab` too); confirm presses the focused one.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct NavigationBindings {
+    pub previous: Vec<KeyCode>,
+    pub next: Vec<KeyCode>,
+    pub confirm: Vec<KeyCode>,
+    pub gamepad_previous: Vec<GamepadButton>,
+    pub gamepad_next: Vec<GamepadButton>,
+    pub gamepad_confirm: Vec<GamepadButton>,
+}
+
+impl Default for NavigationBindings {
+    fn default() -> Self {
+        Self {
+            previous: vec![KeyCode::ArrowUp, KeyCode::ArrowLeft],
+            next: vec![KeyCode::ArrowDown, KeyCode::ArrowRight, KeyCode::Tab],
+            confirm: vec![KeyCode::Enter, KeyCode::NumpadEnter],
+            gamepad_previous: vec![GamepadButton::DPadUp, GamepadButton::DPadLeft],
+            gamepad_next: vec![GamepadButton::DPadDown, GamepadButton::DPadRight],
+            gamepad_confirm: vec![GamepadButton::South],
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// src/features/accessibility/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, MENUS, in_input_context};
+
+pub mod component;
+pub mod systems;
+
+/// Accessibility baseline for the HUD and menus.
+///
+/// Scope:
+/// - UI scale (`UiScale`) and text size from `UserSettings::accessibility`, applied
+///   live to every UI node and text (HUD, menus, prompts)
+/// - photosensitivity: the stored `EffectsLevel` sets `EffectsIntensity`, which every
+///   shake / flash / pulsing-light system scales by
+/// - keyboard / gamepad menu navigation: arrows / D-pad / `Tab` move a visible focus
+///   outline through the buttons of whatever menu is up, `Enter` / south presses it
+///
+/// Design constraints:
+/// - menus need no navigation code of their own: confirming presses the focused
+///   `Button` through its `Interaction`, so their click handlers also serve keyboard
+///   and gamepad; they only have to run after `AppSet::Input`
+/// - texts are authored at scale 1 (`BaseFontSize` remembers the authored size)
+/// - Bevy's AccessKit integration already exposes buttons and texts to screen readers;
+///   this feature does not add speech of its own
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        // Also present in headless apps without the UI plugin.
+        app.init_resource::<UiScale>();
+        app.init_resource::<component::MenuFocus>();
+        app.init_resource::<component::NavigationBindings>();
+
+        app.add_systems(
+            Update,
+            systems::navigate_menus
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(MENUS)),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::apply_ui_scale,
+                systems::apply_effects_level,
+                systems::scale_ui_text,
+                systems::show_menu_focus.after(systems::navigate_menus),
+            ),
+        );
+    }
+}
@@ -0,0 +1,306 @@
+// src/features/accessibility/systems.rs
+use bevy::prelude::*;
+
+use super::component::{BaseFontSize, FOCUS_COLOR, Focused, MenuFocus, NavigationBindings};
+use crate::app::EffectsIntensity;
+use crate::features::settings::component::UserSettings;
+
+/// Update: the stored UI scale drives Bevy's `UiScale` (every node, every screen).
+pub fn apply_ui_scale(settings: Res<UserSettings>, mut ui_scale: ResMut<UiScale>) {
+    let scale = settings.accessibility.ui_scale();
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
+
+/// Update: the stored photosensitivity option sets the central `EffectsIntensity`.
+pub fn apply_effects_level(settings: Res<UserSettings>, mut effects: ResMut<EffectsIntensity>) {
+    effects.set_if_neq(EffectsIntensity::for_level(settings.accessibility.effects));
+}
+
+/// Update: apply the text size to every UI text.
+///
+/// New texts remember their authored size (`BaseFontSize`) and are scaled once;
+/// changing the setting rescales them all from that size.
+#[expect(clippy::type_complexity)]
+pub fn scale_ui_text(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    mut q_new: Query<(Entity, &mut TextFont), (With<Text>, Without<BaseFontSize>)>,
+    mut q_scaled: Query<(&BaseFontSize, &mut TextFont), With<Text>>,
+) {
+    let scale = settings.accessibility.font_scale();
+    if settings.is_changed() {
+        for (base, mut font) in &mut q_scaled {
+            let size = base.0 * scale;
+            if font.font_size != size {
+                font.font_size = size;
+            }
+        }
+    }
+    for (entity, mut font) in &mut q_new {
+        commands
+            .entity(entity)
+            .try_insert(BaseFontSize(font.font_size));
+        font.font_size *= scale;
+    }
+}
+
+/// Update (menus): move the focus through the visible buttons and press the focused
+/// one, all without a mouse.
+///
+/// - buttons are ordered as read: top to bottom, then left to right
+/// - when a menu opens (or rebuilds its buttons) the focus lands on the slot it was
+///   on before, the first button the first time
+/// - confirm sets the button's `Interaction` to `Pressed` for one frame, so menus
+///   handle it exactly like a click
+pub fn navigate_menus(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<NavigationBindings>,
+    q_gamepads: Query<&Gamepad>,
+    mut focus: ResMut<MenuFocus>,
+    mut q_buttons: Query<
+        (
+            Entity,
+            &mut Interaction,
+            &InheritedVisibility,
+            Option<&UiGlobalTransform>,
+        ),
+        With<Button>,
+    >,
+) {
+    let mut next = *focus;
+    if let Some(pressed) = next.pressed.take()
+        && let Ok((_, mut interaction, ..)) = q_buttons.get_mut(pressed)
+        && *interaction == Interaction::Pressed
+    {
+        *interaction = Interaction::None;
+    }
+
+    let mut buttons: Vec<(Entity, Vec2)> = q_buttons
+        .iter()
+        .filter(|(_, _, visibility, _)| visibility.get())
+        .map(|(entity, _, _, transform)| (entity, transform.map_or(Vec2::ZERO, |t| t.translation)))
+        .collect();
+    buttons.sort_by(|(a, at), (b, bt)| {
+        at.y.total_cmp(&bt.y)
+            .then(at.x.total_cmp(&bt.x))
+            .then(a.cmp(b))
+    });
+    if buttons.is_empty() {
+        next.focused = None;
+        focus.set_if_neq(next);
+        return;
+    }
+
+    let gamepad = |list: &[GamepadButton]| {
+        q_gamepads
+            .iter()
+            .any(|pad| list.iter().any(|button| pad.just_pressed(*button)))
+    };
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let back_tab = shift && keyboard.just_pressed(KeyCode::Tab);
+    let previous = back_tab
+        || keyboard.any_just_pressed(bindings.previous.iter().copied())
+        || gamepad(&bindings.gamepad_previous);
+    let forward = !back_tab
+        && (keyboard.any_just_pressed(bindings.next.iter().copied())
+            || gamepad(&bindings.gamepad_next));
+    let confirm = keyboard.any_just_pressed(bindings.confirm.iter().copied())
+        || gamepad(&bindings.gamepad_confirm);
+
+    let len = buttons.len();
+    let current = next
+        .focused
+        .and_then(|focused| buttons.iter().position(|(entity, _)| *entity == focused));
+    let mut index = current.unwrap_or(next.index.min(len - 1));
+    if current.is_some() {
+        if forward && !previous {
+            index = (index + 1) % len;
+        } else if previous && !forward {
+            index = (index + len - 1) % len;
+        }
+    }
+    next.index = index;
+    next.focused = Some(buttons[index].0);
+
+    if confirm && let Ok((entity, mut interaction, ..)) = q_buttons.get_mut(buttons[index].0) {
+        *interaction = Interaction::Pressed;
+        next.pressed = Some(entity);
+    }
+    focus.set_if_neq(next);
+}
+
+/// Update: outline the focused button.
+pub fn show_menu_focus(
+    mut commands: Commands,
+    focus: Res<MenuFocus>,
+    q_focused: Query<Entity, With<Focused>>,
+) {
+    for entity in &q_focused {
+        if focus.focused != Some(entity) {
+            commands.entity(entity).try_remove::<(Focused, Outline)>();
+        }
+    }
+    if let Some(entity) = focus.focused
+        && !q_focused.contains(entity)
+    {
+        commands.entity(entity).try_insert((
+            Focused,
+            Outline::new(Val::Px(3.0), Val::Px(2.0), FOCUS_COLOR),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::EffectsLevel;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<NavigationBindings>();
+        world.init_resource::<MenuFocus>();
+        world
+    }
+
+    fn spawn_button(world: &mut World, x: f32, y: f32) -> Entity {
+        world
+            .spawn((
+                Button,
+                Interaction::None,
+                InheritedVisibility::VISIBLE,
+                UiGlobalTransform::from_xy(x, y),
+            ))
+            .id()
+    }
+
+    fn tap(world: &mut World, key: KeyCode) {
+        world.resource_mut::<ButtonInput<KeyCode>>().press(key);
+        let _ = world.run_system_once(navigate_menus);
+        let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+    }
+
+    #[test]
+    fn focus_follows_reading_order_and_wraps() {
+        let mut world = setup_world();
+        let second_row = spawn_button(&mut world, 0.0, 100.0);
+        let right = spawn_button(&mut world, 50.0, 0.0);
+        let left = spawn_button(&mut world, 0.0, 0.0);
+
+        let _ = world.run_system_once(navigate_menus);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(left));
+
+        tap(&mut world, KeyCode::ArrowDown);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(right));
+        tap(&mut world, KeyCode::Tab);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(second_row));
+        tap(&mut world, KeyCode::ArrowRight);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(left), "wraps");
+        tap(&mut world, KeyCode::ArrowUp);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(second_row));
+    }
+
+    #[test]
+    fn confirm_presses_the_focused_button_for_one_frame() {
+        let mut world = setup_world();
+        let button = spawn_button(&mut world, 0.0, 0.0);
+        let _ = world.run_system_once(navigate_menus);
+
+        tap(&mut world, KeyCode::Enter);
+        assert_eq!(
+            *world.get::<Interaction>(button).unwrap(),
+            Interaction::Pressed
+        );
+
+        let _ = world.run_system_once(navigate_menus);
+        assert_eq!(
+            *world.get::<Interaction>(button).unwrap(),
+            Interaction::None
+        );
+    }
+
+    #[test]
+    fn rebuilt_menus_keep_the_focused_slot_and_hidden_buttons_are_skipped() {
+        let mut world = setup_world();
+        spawn_button(&mut world, 0.0, 0.0);
+        let hidden = spawn_button(&mut world, 0.0, 25.0);
+        let last = spawn_button(&mut world, 0.0, 50.0);
+        world.entity_mut(hidden).insert(InheritedVisibility::HIDDEN);
+        let _ = world.run_system_once(navigate_menus);
+
+        tap(&mut world, KeyCode::ArrowDown);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(last));
+
+        let old: Vec<Entity> = world
+            .query_filtered::<Entity, With<Button>>()
+            .iter(&world)
+            .collect();
+        for entity in old {
+            world.despawn(entity);
+        }
+        spawn_button(&mut world, 0.0, 0.0);
+        let rebuilt = spawn_button(&mut world, 0.0, 50.0);
+        let _ = world.run_system_once(navigate_menus);
+        assert_eq!(world.resource::<MenuFocus>().focused, Some(rebuilt));
+    }
+
+    #[test]
+    fn focus_outline_moves_with_the_focus() {
+        let mut world = setup_world();
+        let a = spawn_button(&mut world, 0.0, 0.0);
+        let b = spawn_button(&mut world, 0.0, 50.0);
+        let _ = world.run_system_once(navigate_menus);
+        let _ = world.run_system_once(show_menu_focus);
+        assert!(world.get::<Outline>(a).is_some());
+
+        tap(&mut world, KeyCode::ArrowDown);
+        let _ = world.run_system_once(show_menu_focus);
+        assert!(world.get::<Outline>(a).is_none());
+        assert!(world.get::<Focused>(b).is_some());
+    }
+
+    #[test]
+    fn text_scales_from_its_authored_size() {
+        let mut world = World::new();
+        world.init_resource::<UserSettings>();
+        world.insert_resource(UiScale(1.0));
+        world
+            .resource_mut::<UserSettings>()
+            .accessibility
+            .font_scale = 1.5;
+        let text = world
+            .spawn((Text::new("hi"), TextFont::from_font_size(20.0)))
+            .id();
+
+        let _ = world.run_system_once(scale_ui_text);
+        assert_eq!(world.get::<TextFont>(text).unwrap().font_size, 30.0);
+
+        world.resource_mut::<UserSettings>().accessibility = default();
+        let _ = world.run_system_once(scale_ui_text);
+        let _ = world.run_system_once(apply_ui_scale);
+        assert_eq!(world.get::<TextFont>(text).unwrap().font_size, 20.0);
+
+        world.resource_mut::<UserSettings>().accessibility.ui_scale = 1.25;
+        let _ = world.run_system_once(apply_ui_scale);
+        assert_eq!(world.resource::<UiScale>().0, 1.25);
+    }
+
+    #[test]
+    fn effects_level_sets_the_effects_intensity() {
+        let mut world = World::new();
+        world.init_resource::<UserSettings>();
+        world.init_resource::<EffectsIntensity>();
+        world.resource_mut::<UserSettings>().accessibility.effects = EffectsLevel::Reduced;
+
+        let _ = world.run_system_once(apply_effects_level);
+
+        let effects = *world.resource::<EffectsIntensity>();
+        assert_eq!(effects, EffectsIntensity::for_level(EffectsLevel::Reduced));
+        assert!(effects.shake < 1.0 && effects.flash < 1.0 && effects.pulse < 1.0);
+    }
+}
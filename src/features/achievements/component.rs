@@ -0,0 +1,117 @@
+// src/features/achievements/component.rs
+use std::collections::{BTreeMap, BTreeSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Lifetime statistic tracked for achievements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Stat {
+    EnemiesKilled,
+    CoinsEarned,
+    ItemsCollected,
+    Steps,
+    Deaths,
+    Trades,
+    /// Survival waves cleared (reported by the mode through `RecordStat`).
+    WavesCleared,
+}
+
+/// When an achievement unlocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum AchievementTrigger {
+    /// The statistic reached the value.
+    Stat(Stat, u64),
+    /// Only through `UnlockAchievement` (quests, scripted moments).
+    Scripted,
+}
+
+/// One achievement, loaded from `assets/data/achievements.ron`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AchievementDef {
+    /// Stable key (saves, platform backends).
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub trigger: AchievementTrigger,
+}
+
+/// All achievement definitions.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct AchievementLibrary(pub Vec<AchievementDef>);
+
+const BUILTIN_ACHIEVEMENTS: &str = include_str!("../../../assets/data/achievements.ron");
+
+impl AchievementLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&AchievementDef> {
+        self.0.iter().find(|def| def.id == id)
+    }
+}
+
+impl Default for AchievementLibrary {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_ACHIEVEMENTS).expect("assets/data/achievements.ron must parse")
+    }
+}
+
+/// Persisted progress: lifetime statistics and unlocked ids.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AchievementProgress {
+    pub stats: BTreeMap<Stat, u64>,
+    pub unlocked: BTreeSet<String>,
+}
+
+impl AchievementProgress {
+    pub fn stat(&self, stat: Stat) -> u64 {
+        self.stats.get(&stat).copied().unwrap_or(0)
+    }
+
+    pub fn add(&mut self, stat: Stat, amount: u64) {
+        let value = self.stats.entry(stat).or_default();
+        *value = value.saturating_add(amount);
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(id)
+    }
+
+    /// Whether `def`'s trigger condition currently holds.
+    pub fn satisfies(&self, def: &AchievementDef) -> bool {
+        match def.trigger {
+            AchievementTrigger::Stat(stat, at_least) => self.stat(stat) >= at_least,
+            AchievementTrigger::Scripted => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_achievements_parse_with_unique_ids() {
+        let library = AchievementLibrary::default();
+        let ids: BTreeSet<_> = library.0.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids.len(), library.0.len());
+        assert!(library.get("first_blood").is_some());
+    }
+
+    #[test]
+    fn stat_triggers_unlock_at_the_threshold() {
+        let def = AchievementDef {
+            id: "x".into(),
+            name: "X".into(),
+            description: String::new(),
+            trigger: AchievementTrigger::Stat(Stat::Trades, 2),
+        };
+        let mut progress = AchievementProgress::default();
+        progress.add(Stat::Trades, 1);
+        assert!(!progress.satisfies(&def));
+        progress.add(Stat::Trades, 1);
+        assert!(progress.satisfies(&def));
+    }
+}
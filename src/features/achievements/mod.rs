@@ -0,0 +1,44 @@
+// src/features/achievements/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod storage;
+pub mod systems;
+
+/// Achievements.
+///
+/// Scope:
+/// - definitions in `assets/data/achievements.ron`: a statistic threshold or scripted
+/// - lifetime statistics counted from gameplay messages (kills, coins earned,
+///   items collected, steps, deaths, trades); modes report their own through
+///   `RecordStat` (survival waves cleared)
+/// - unlocking announces `AchievementUnlocked` and a toast (`features::notifications`)
+/// - progress is saved through an `AchievementBackend`: a local RON file by
+///   default, swappable for a platform backend by replacing `AchievementStorage`
+///
+/// Design constraints:
+/// - saves happen on unlock and on exit, not every frame.
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::AchievementLibrary>();
+        app.init_resource::<component::AchievementProgress>();
+        app.init_resource::<storage::AchievementStorage>();
+
+        app.add_message::<systems::UnlockAchievement>();
+        app.add_message::<systems::RecordStat>();
+        app.add_message::<systems::AchievementUnlocked>();
+
+        app.add_systems(Startup, systems::load_achievement_progress);
+        app.add_systems(
+            Update,
+            (systems::record_statistics, systems::evaluate_achievements)
+                .chain()
+                .after(AppSet::Input),
+        );
+        app.add_systems(Last, systems::save_progress_on_exit);
+    }
+}
@@ -0,0 +1,111 @@
+// src/features/achievements/storage.rs
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use super::component::AchievementProgress;
+use crate::app::StoragePaths;
+
+/// Where achievement progress lives.
+///
+/// The local file backend is the default; a platform backend (Steam, console
+/// trophies) implements the same trait and mirrors unlocks to the platform in
+/// `unlocked`, while still keeping statistics locally if the platform can't.
+pub trait AchievementBackend: Send + Sync + 'static {
+    /// Stored progress (empty when there is none yet).
+    fn load(&mut self) -> AchievementProgress;
+
+    /// Persist the whole progress.
+    fn store(&mut self, progress: &AchievementProgress);
+
+    /// An achievement was just unlocked (platform popups / sync).
+    fn unlocked(&mut self, _id: &str) {}
+}
+
+/// The active backend.
+#[derive(Resource)]
+pub struct AchievementStorage(pub Box<dyn AchievementBackend>);
+
+impl FromWorld for AchievementStorage {
+    fn from_world(world: &mut World) -> Self {
+        Self(Box::new(LocalFileBackend {
+            path: StoragePaths::of(world).data_file("achievements.ron"),
+        }))
+    }
+}
+
+/// Progress as a RON file on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalFileBackend {
+    pub path: PathBuf,
+}
+
+impl AchievementBackend for LocalFileBackend {
+    fn load(&mut self) -> AchievementProgress {
+        let Ok(source) = std::fs::read_to_string(&self.path) else {
+            return AchievementProgress::default();
+        };
+        ron::from_str(&source).unwrap_or_else(|err| {
+            warn!("ignoring unreadable {}: {err}", self.path.display());
+            AchievementProgress::default()
+        })
+    }
+
+    fn store(&mut self, progress: &AchievementProgress) {
+        let result = ron::ser::to_string_pretty(progress, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())
+            .and_then(|text| {
+                if let Some(dir) = self.path.parent() {
+                    std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+                }
+                std::fs::write(&self.path, text).map_err(|err| err.to_string())
+            });
+        if let Err(err) = result {
+            warn!("could not save {}: {err}", self.path.display());
+        }
+    }
+}
+
+/// In-memory backend (tests, demo builds without saving).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryBackend {
+    pub progress: AchievementProgress,
+    pub unlocked: Vec<String>,
+}
+
+impl AchievementBackend for MemoryBackend {
+    fn load(&mut self) -> AchievementProgress {
+        self.progress.clone()
+    }
+
+    fn store(&mut self, progress: &AchievementProgress) {
+        self.progress = progress.clone();
+    }
+
+    fn unlocked(&mut self, id: &str) {
+        self.unlocked.push(id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::achievements::component::Stat;
+
+    #[test]
+    fn local_file_round_trips_progress() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_ach_{}", std::process::id()));
+        let mut backend = LocalFileBackend {
+            path: dir.join("achievements.ron"),
+        };
+        assert_eq!(backend.load(), AchievementProgress::default());
+
+        let mut progress = AchievementProgress::default();
+        progress.add(Stat::Steps, 12);
+        progress.unlocked.insert("wanderer".into());
+        backend.store(&progress);
+
+        assert_eq!(backend.load(), progress);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,223 @@
+// src/features/achievements/systems.rs
+use bevy::prelude::*;
+
+use super::component::{AchievementLibrary, AchievementProgress, Stat};
+use super::storage::AchievementStorage;
+use crate::features::enemy::component::Enemy;
+use crate::features::health::damage::Died;
+use crate::features::inventory::ItemsAdded;
+use crate::features::notifications::component::NotifyKind;
+use crate::features::notifications::systems::Notify;
+use crate::features::player::component::Player;
+use crate::features::player::death::PlayerDied;
+use crate::features::shop::systems::TradeCompleted;
+use crate::features::surface::systems::{ContactKind, SurfaceContact};
+use crate::features::wallet::systems::WalletChanged;
+
+/// Unlock a (usually `Scripted`) achievement by id.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct UnlockAchievement {
+    pub id: String,
+}
+
+/// An achievement was unlocked just now.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AchievementUnlocked {
+    pub id: String,
+}
+
+/// Add to a statistic no gameplay message here covers (game modes: waves cleared).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordStat {
+    pub stat: Stat,
+    pub amount: u64,
+}
+
+/// Startup: read stored progress from the backend.
+pub fn load_achievement_progress(
+    mut storage: ResMut<AchievementStorage>,
+    mut progress: ResMut<AchievementProgress>,
+) {
+    *progress = storage.0.load();
+}
+
+/// Update: count the player's lifetime statistics from gameplay messages.
+///
+/// Coins restored from a save aren't earnings.
+#[expect(clippy::too_many_arguments)]
+pub fn record_statistics(
+    mut progress: ResMut<AchievementProgress>,
+    q_players: Query<(), With<Player>>,
+    q_enemies: Query<(), With<Enemy>>,
+    mut died: MessageReader<Died>,
+    mut player_died: MessageReader<PlayerDied>,
+    mut wallet: MessageReader<WalletChanged>,
+    mut items: MessageReader<ItemsAdded>,
+    mut trades: MessageReader<TradeCompleted>,
+    mut contacts: MessageReader<SurfaceContact>,
+    mut reported: MessageReader<RecordStat>,
+) {
+    let kills = died.read().filter(|ev| q_enemies.contains(ev.entity)).count();
+    let deaths = player_died.read().count();
+    let earned: i64 = wallet
+        .read()
+        .filter(|ev| ev.delta > 0 && !ev.restored && q_players.contains(ev.entity))
+        .map(|ev| ev.delta)
+        .sum();
+    let collected: u64 = items
+        .read()
+        .filter(|ev| q_players.contains(ev.entity))
+        .map(|ev| u64::from(ev.amount))
+        .sum();
+    let traded = trades
+        .read()
+        .filter(|ev| q_players.contains(ev.customer))
+        .count();
+    let steps = contacts
+        .read()
+        .filter(|c| c.kind == ContactKind::Footstep && q_players.contains(c.source))
+        .count();
+
+    for (stat, amount) in [
+        (Stat::EnemiesKilled, kills as u64),
+        (Stat::Deaths, deaths as u64),
+        (Stat::CoinsEarned, earned as u64),
+        (Stat::ItemsCollected, collected),
+        (Stat::Trades, traded as u64),
+        (Stat::Steps, steps as u64),
+    ] {
+        if amount > 0 {
+            progress.add(stat, amount);
+        }
+    }
+    for report in reported.read() {
+        if report.amount > 0 {
+            progress.add(report.stat, report.amount);
+        }
+    }
+}
+
+/// Update: unlock achievements whose trigger holds (or that were requested),
+/// toast them and save right away.
+pub fn evaluate_achievements(
+    library: Res<AchievementLibrary>,
+    mut progress: ResMut<AchievementProgress>,
+    mut storage: ResMut<AchievementStorage>,
+    mut requests: MessageReader<UnlockAchievement>,
+    mut unlocked: MessageWriter<AchievementUnlocked>,
+    mut notify: MessageWriter<Notify>,
+) {
+    let requested: Vec<String> = requests.read().map(|r| r.id.clone()).collect();
+    if requested.is_empty() && !progress.is_changed() {
+        return;
+    }
+
+    let mut any = false;
+    for def in &library.0 {
+        if progress.is_unlocked(&def.id)
+            || !(progress.satisfies(def) || requested.contains(&def.id))
+        {
+            continue;
+        }
+        progress.unlocked.insert(def.id.clone());
+        storage.0.unlocked(&def.id);
+        unlocked.write(AchievementUnlocked { id: def.id.clone() });
+        notify.write(Notify::new(
+            NotifyKind::Achievement,
+            format!("Achievement unlocked: {}", def.name),
+        ));
+        any = true;
+    }
+    if any {
+        storage.0.store(&progress);
+    }
+}
+
+/// Last: keep statistics gathered since the last unlock.
+pub fn save_progress_on_exit(
+    mut exit: MessageReader<AppExit>,
+    progress: Res<AchievementProgress>,
+    mut storage: ResMut<AchievementStorage>,
+) {
+    if exit.read().count() > 0 {
+        storage.0.store(&progress);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::achievements::storage::MemoryBackend;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AchievementLibrary>();
+        world.init_resource::<AchievementProgress>();
+        world.insert_resource(AchievementStorage(Box::new(MemoryBackend::default())));
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<PlayerDied>>();
+        world.init_resource::<Messages<WalletChanged>>();
+        world.init_resource::<Messages<ItemsAdded>>();
+        world.init_resource::<Messages<TradeCompleted>>();
+        world.init_resource::<Messages<SurfaceContact>>();
+        world.init_resource::<Messages<RecordStat>>();
+        world.init_resource::<Messages<UnlockAchievement>>();
+        world.init_resource::<Messages<AchievementUnlocked>>();
+        world.init_resource::<Messages<Notify>>();
+        world
+    }
+
+    #[test]
+    fn killing_an_enemy_unlocks_first_blood_once() {
+        let mut world = setup_world();
+        let enemy = world.spawn(Enemy).id();
+        world.write_message(Died { entity: enemy });
+
+        let _ = world.run_system_once(record_statistics);
+        let _ = world.run_system_once(evaluate_achievements);
+        let _ = world.run_system_once(evaluate_achievements);
+
+        let progress = world.resource::<AchievementProgress>();
+        assert_eq!(progress.stat(Stat::EnemiesKilled), 1);
+        assert!(progress.is_unlocked("first_blood"));
+        assert_eq!(world.resource::<Messages<AchievementUnlocked>>().len(), 1);
+        assert_eq!(
+            world
+                .resource::<Messages<Notify>>()
+                .iter_current_update_messages()
+                .next()
+                .map(|n| n.kind),
+            Some(NotifyKind::Achievement)
+        );
+    }
+
+    #[test]
+    fn only_player_activity_counts() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+        let npc = world.spawn_empty().id();
+        world.write_message(WalletChanged::new(player, 0, 7));
+        world.write_message(WalletChanged::new(npc, 0, 50));
+        world.write_message(WalletChanged::new(player, 7, 2));
+
+        let _ = world.run_system_once(record_statistics);
+
+        assert_eq!(world.resource::<AchievementProgress>().stat(Stat::CoinsEarned), 7);
+    }
+
+    #[test]
+    fn loading_a_save_earns_nothing() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+        world.write_message(WalletChanged {
+            restored: true,
+            ..WalletChanged::new(player, 0, 500)
+        });
+
+        let _ = world.run_system_once(record_statistics);
+
+        assert_eq!(world.resource::<AchievementProgress>().stat(Stat::CoinsEarned), 0);
+    }
+}
@@ -0,0 +1,123 @@
+// src/features/aim/component.rs
+use bevy::prelude::*;
+
+use crate::features::movement::component::SpeedMultiplier;
+
+/// Aim-down-sights state shared by camera, input, movement speed and HUD.
+///
+/// - Input sets `aiming`; the aim feature tweens `amount` (0 = hip, 1 = fully aimed).
+/// - Consumers only read the eased `*_now()` values, so they all stay in step:
+///   camera FOV, orbit/look sensitivity, move speed, crosshair spread.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(SpeedMultiplier)]
+pub struct AimState {
+    pub aiming: bool,
+    /// Linear transition progress in `0.0..=1.0`.
+    pub amount: f32,
+    /// Seconds for a full hip <-> aim transition.
+    pub transition_secs: f32,
+    /// Fully-aimed multipliers (1.0 = no change).
+    pub fov_scale: f32,
+    pub move_speed_scale: f32,
+    pub sensitivity_scale: f32,
+    pub spread_scale: f32,
+}
+
+impl Default for AimState {
+    fn default() -> Self {
+        Self {
+            aiming: false,
+            amount: 0.0,
+            transition_secs: 0.2,
+            fov_scale: 0.6,
+            move_speed_scale: 0.5,
+            sensitivity_scale: 0.5,
+            spread_scale: 0.3,
+        }
+    }
+}
+
+impl AimState {
+    /// Eased transition weight (smoothstep of `amount`).
+    pub fn weight(&self) -> f32 {
+        let x = self.amount.clamp(0.0, 1.0);
+        x * x * (3.0 - 2.0 * x)
+    }
+
+    fn blend(&self, aimed: f32) -> f32 {
+        1.0 + (aimed - 1.0) * self.weight()
+    }
+
+    pub fn fov_scale_now(&self) -> f32 {
+        self.blend(self.fov_scale)
+    }
+
+    pub fn move_speed_scale_now(&self) -> f32 {
+        self.blend(self.move_speed_scale)
+    }
+
+    pub fn sensitivity_scale_now(&self) -> f32 {
+        self.blend(self.sensitivity_scale)
+    }
+
+    pub fn spread_scale_now(&self) -> f32 {
+        self.blend(self.spread_scale)
+    }
+
+    /// Advance `amount` towards `aiming` by `dt`.
+    pub fn step(&mut self, dt: f32) {
+        let target = if self.aiming { 1.0 } else { 0.0 };
+        let rate = if self.transition_secs > 0.0 {
+            dt / self.transition_secs
+        } else {
+            1.0
+        };
+        self.amount += (target - self.amount).clamp(-rate, rate);
+    }
+}
+
+/// Mouse / gamepad inputs held to aim.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AimBindings {
+    pub mouse_button: MouseButton,
+    pub gamepad_button: GamepadButton,
+}
+
+impl Default for AimBindings {
+    fn default() -> Self {
+        Self {
+            mouse_button: MouseButton::Right,
+            gamepad_button: GamepadButton::LeftTrigger2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_tweens_linearly_and_clamps() {
+        let mut aim = AimState {
+            aiming: true,
+            ..default()
+        };
+        aim.step(0.1);
+        assert!((aim.amount - 0.5).abs() < 1e-6);
+        aim.step(1.0);
+        assert_eq!(aim.amount, 1.0);
+
+        aim.aiming = false;
+        aim.step(1.0);
+        assert_eq!(aim.amount, 0.0);
+    }
+
+    #[test]
+    fn scales_blend_from_neutral_to_aimed() {
+        let mut aim = AimState::default();
+        assert_eq!(aim.move_speed_scale_now(), 1.0);
+        aim.amount = 1.0;
+        assert_eq!(aim.move_speed_scale_now(), aim.move_speed_scale);
+        assert_eq!(aim.fov_scale_now(), aim.fov_scale);
+    }
+}
@@ -0,0 +1,53 @@
+// src/features/aim/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::camera::systems as camera_systems;
+use crate::features::movement::systems::{compute_velocity_from_input, reset_speed_multipliers};
+
+pub mod component;
+pub mod systems;
+
+/// Aim-down-sights feature.
+///
+/// Scope:
+/// - Update: aim button held -> `AimState::aiming`, tweened into `AimState::amount`
+/// - camera: FOV scale on cameras following the aimer (`CameraFov::scale`)
+/// - FixedUpdate: move speed scale multiplied into the movement `SpeedMultiplier`
+///
+/// Other features read `AimState` directly so they move in lockstep with the camera:
+/// - camera input: orbit sensitivity scale
+/// - HUD: crosshair spread scale
+pub struct AimPlugin;
+
+impl Plugin for AimPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::AimBindings>();
+        // Normally provided by `InputPlugin`; keeps the feature usable headless.
+        app.init_resource::<ButtonInput<MouseButton>>();
+
+        app.add_systems(
+            Update,
+            systems::read_aim_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            Update,
+            systems::update_aim
+                .after(AppSet::Input)
+                .before(camera_systems::apply_camera_fov),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::grant_player_aim.in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::apply_aim_move_speed
+                .in_set(AppSet::ApplyForces)
+                .after(reset_speed_multipliers)
+                .before(compute_velocity_from_input),
+        );
+    }
+}
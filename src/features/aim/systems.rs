@@ -0,0 +1,122 @@
+// src/features/aim/systems.rs
+use bevy::prelude::*;
+
+use super::component::{AimBindings, AimState};
+use crate::features::camera::component::{CameraFov, FollowCamera};
+use crate::features::movement::component::SpeedMultiplier;
+use crate::features::player::component::Player;
+use crate::features::player::death::AlivePlayer;
+
+/// Every player can aim.
+pub fn grant_player_aim(
+    mut commands: Commands,
+    q_new_players: Query<Entity, (Added<Player>, Without<AimState>)>,
+) {
+    for entity in &q_new_players {
+        commands.entity(entity).insert(AimState::default());
+    }
+}
+
+/// Update: aim button held (mouse or any gamepad) -> `AimState::aiming` on living players.
+pub fn read_aim_input(
+    mouse: Res<ButtonInput<MouseButton>>,
+    bindings: Res<AimBindings>,
+    q_gamepads: Query<&Gamepad>,
+    mut q_players: Query<&mut AimState, AlivePlayer>,
+) {
+    let held = mouse.pressed(bindings.mouse_button)
+        || q_gamepads.iter().any(|g| g.pressed(bindings.gamepad_button));
+
+    for mut aim in &mut q_players {
+        if aim.aiming != held {
+            aim.aiming = held;
+        }
+    }
+}
+
+/// Update: tween aim transitions and push the FOV scale to cameras following the aimer.
+pub fn update_aim(
+    time: Res<Time>,
+    mut q_aimers: Query<&mut AimState>,
+    mut q_cameras: Query<(&FollowCamera, &mut CameraFov)>,
+) {
+    let dt = time.delta_secs();
+    for mut aim in &mut q_aimers {
+        let before = aim.amount;
+        aim.bypass_change_detection().step(dt);
+        if aim.amount != before {
+            aim.set_changed();
+        }
+    }
+
+    for (follow, mut fov) in &mut q_cameras {
+        let scale = follow
+            .target
+            .and_then(|t| q_aimers.get(t).ok())
+            .map_or(1.0, AimState::fov_scale_now);
+        if fov.scale != scale {
+            fov.scale = scale;
+        }
+    }
+}
+
+/// FixedUpdate: aimers move slower, in step with the aim transition.
+pub fn apply_aim_move_speed(mut q_aimers: Query<(&AimState, &mut SpeedMultiplier)>) {
+    for (aim, mut multiplier) in &mut q_aimers {
+        multiplier.0 *= aim.move_speed_scale_now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    #[test]
+    fn holding_aim_narrows_follow_camera_fov() {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.insert_resource(AimBindings::default());
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        mouse.press(MouseButton::Right);
+        world.insert_resource(mouse);
+
+        let player = world.spawn((Player, AimState::default())).id();
+        let cam = world
+            .spawn(FollowCamera {
+                target: Some(player),
+                ..default()
+            })
+            .id();
+
+        let _ = world.run_system_once(read_aim_input);
+        let _ = world.run_system_once(update_aim);
+
+        let aim = world.get::<AimState>(player).unwrap();
+        assert!(aim.aiming && aim.amount == 1.0);
+        assert_eq!(world.get::<CameraFov>(cam).unwrap().scale, aim.fov_scale);
+    }
+
+    #[test]
+    fn aiming_slows_movement() {
+        let mut world = World::new();
+        let aimer = world
+            .spawn((
+                AimState {
+                    aiming: true,
+                    amount: 1.0,
+                    move_speed_scale: 0.5,
+                    ..default()
+                },
+                SpeedMultiplier(0.8),
+            ))
+            .id();
+
+        let _ = world.run_system_once(apply_aim_move_speed);
+
+        assert_eq!(world.get::<SpeedMultiplier>(aimer).unwrap().0, 0.4);
+    }
+}
@@ -0,0 +1,220 @@
+// src/features/animation/blend_space.rs
+use bevy::animation::graph::AnimationNodeIndex;
+use bevy::prelude::*;
+
+use crate::features::movement::component::{MoveConfig, MoveInput, SpeedMultiplier};
+
+/// One clip placed in a 2D blend space.
+///
+/// `position` is `(strafe, forward)` speed in world units/sec in the character's
+/// local frame: `+x` = right, `+y` = forward, `-y` = backwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlendPoint {
+    pub position: Vec2,
+    pub node: AnimationNodeIndex,
+}
+
+/// Freeform 2D blend space (gradient band interpolation).
+///
+/// - works for any point layout (no triangulation needed)
+/// - a sample exactly on a point gives that clip full weight
+/// - weights are non-negative and sum to 1 (when there is at least one point)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlendSpace2d {
+    pub points: Vec<BlendPoint>,
+}
+
+impl BlendSpace2d {
+    pub fn new(points: impl IntoIterator<Item = BlendPoint>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+        }
+    }
+
+    /// Per-point weights for `sample`, in `points` order.
+    pub fn weights(&self, sample: Vec2) -> Vec<f32> {
+        let mut weights: Vec<f32> = self
+            .points
+            .iter()
+            .enumerate()
+            .map(|(i, pi)| {
+                self.points
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, pj)| {
+                        let edge = pj.position - pi.position;
+                        let len_sq = edge.length_squared();
+                        if len_sq <= f32::EPSILON {
+                            return 1.0;
+                        }
+                        (1.0 - (sample - pi.position).dot(edge) / len_sq).clamp(0.0, 1.0)
+                    })
+                    .fold(1.0, f32::min)
+            })
+            .collect();
+
+        let total: f32 = weights.iter().sum();
+        if total > f32::EPSILON {
+            weights.iter_mut().for_each(|w| *w /= total);
+        } else if let Some(nearest) = self.nearest(sample) {
+            weights.iter_mut().for_each(|w| *w = 0.0);
+            weights[nearest] = 1.0;
+        }
+        weights
+    }
+
+    fn nearest(&self, sample: Vec2) -> Option<usize> {
+        self.points
+            .iter()
+            .enumerate()
+            .min_by(|a, b| {
+                a.1.position
+                    .distance_squared(sample)
+                    .total_cmp(&b.1.position.distance_squared(sample))
+            })
+            .map(|(i, _)| i)
+    }
+}
+
+/// Locomotion blending on a character, driving the `AnimationPlayer` on `animation_player`
+/// (usually the rigged model child).
+///
+/// - `sample` is the smoothed `(strafe, forward)` speed currently fed into `space`
+/// - `smoothing` is the exponential approach rate (1/sec); `0` snaps instantly
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct LocomotionBlend {
+    pub space: BlendSpace2d,
+    pub animation_player: Entity,
+    pub sample: Vec2,
+    pub smoothing: f32,
+}
+
+impl LocomotionBlend {
+    pub const DEFAULT_SMOOTHING: f32 = 10.0;
+
+    pub fn new(space: BlendSpace2d, animation_player: Entity) -> Self {
+        Self {
+            space,
+            animation_player,
+            sample: Vec2::ZERO,
+            smoothing: Self::DEFAULT_SMOOTHING,
+        }
+    }
+}
+
+/// Local movement velocity (`+X` right, `-Z` forward) as a `(strafe, forward)` blend sample.
+pub fn locomotion_sample(local_velocity: Vec3) -> Vec2 {
+    Vec2::new(local_velocity.x, -local_velocity.z)
+}
+
+/// Update: feed `MoveInput` (local frame) into each character's blend space and
+/// write the resulting clip weights to its `AnimationPlayer`.
+///
+/// - Reads: MoveInput, MoveConfig, SpeedMultiplier (optional)
+/// - Writes: LocomotionBlend.sample, AnimationPlayer weights (all space clips kept playing/looping)
+pub fn drive_locomotion_blend(
+    time: Res<Time>,
+    mut q_characters: Query<(
+        &mut LocomotionBlend,
+        &MoveInput,
+        &MoveConfig,
+        Option<&SpeedMultiplier>,
+    )>,
+    mut q_players: Query<&mut AnimationPlayer>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut blend, move_input, config, multiplier) in &mut q_characters {
+        let local = config.local_velocity(move_input.0) * multiplier.map_or(1.0, |m| m.0);
+        let target = locomotion_sample(local);
+        let t = if blend.smoothing > 0.0 {
+            1.0 - (-blend.smoothing * dt).exp()
+        } else {
+            1.0
+        };
+        blend.sample = blend.sample.lerp(target, t);
+
+        let Ok(mut player) = q_players.get_mut(blend.animation_player) else {
+            continue;
+        };
+
+        let weights = blend.space.weights(blend.sample);
+        for (point, weight) in blend.space.points.iter().zip(weights) {
+            player.play(point.node).repeat().set_weight(weight);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    const EPS: f32 = 1e-4;
+
+    fn node(i: u32) -> AnimationNodeIndex {
+        AnimationNodeIndex::new(i as usize)
+    }
+
+    /// Idle, walk forward/back, strafe left/right at 4 u/s.
+    fn space() -> BlendSpace2d {
+        BlendSpace2d::new([
+            (Vec2::ZERO, 0),
+            (Vec2::new(0.0, 4.0), 1),
+            (Vec2::new(0.0, -4.0), 2),
+            (Vec2::new(-4.0, 0.0), 3),
+            (Vec2::new(4.0, 0.0), 4),
+        ]
+        .map(|(position, i)| BlendPoint {
+            position,
+            node: node(i),
+        }))
+    }
+
+    #[test]
+    fn sample_on_a_point_selects_that_clip() {
+        let w = space().weights(Vec2::new(0.0, -4.0));
+        assert!((w[2] - 1.0).abs() < EPS, "{w:?}");
+        assert!((w.iter().sum::<f32>() - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn diagonal_blends_forward_and_strafe_equally() {
+        let w = space().weights(Vec2::new(2.0, 2.0));
+        assert!((w[1] - w[4]).abs() < EPS, "{w:?}");
+        assert!(w[1] > 0.2);
+        assert!(w[2] < EPS && w[3] < EPS, "backwards/left must not contribute: {w:?}");
+        assert!((w.iter().sum::<f32>() - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn outside_the_space_stays_normalized() {
+        let w = space().weights(Vec2::new(0.0, 10.0));
+        assert!((w[1] - 1.0).abs() < EPS, "{w:?}");
+    }
+
+    #[test]
+    fn move_input_maps_to_local_strafe_forward() {
+        assert_eq!(locomotion_sample(Vec3::NEG_Z * 4.0), Vec2::new(0.0, 4.0));
+        assert_eq!(locomotion_sample(Vec3::X * 2.0), Vec2::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn drive_writes_weights_to_animation_player() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+
+        let model = world.spawn(AnimationPlayer::default()).id();
+        let mut blend = LocomotionBlend::new(space(), model);
+        blend.smoothing = 0.0;
+        world.spawn((blend, MoveInput(Vec3::Z), MoveConfig::uniform(4.0)));
+
+        let _ = world.run_system_once(drive_locomotion_blend);
+
+        let player = world.get::<AnimationPlayer>(model).unwrap();
+        let weight = |i| player.animation(node(i)).map(|a| a.weight()).unwrap();
+        assert!((weight(2) - 1.0).abs() < EPS, "walking backwards");
+        assert!(weight(0).abs() < EPS && weight(1).abs() < EPS);
+    }
+}
@@ -0,0 +1,39 @@
+// src/features/animation/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::movement::systems as movement;
+
+pub mod blend_space;
+pub mod root_motion;
+
+/// Character animation feature.
+///
+/// Scope:
+/// - `RootMotionClip`: horizontal root track extracted per clip (looping)
+/// - FixedUpdate: characters in `MovementMode::RootMotion` take their `Velocity`
+///   from the clip instead of `MoveInput` * `MoveConfig`
+/// - Update: 2D locomotion blend space (strafe vs forward speed) driven by local
+///   `MoveInput`, writing clip weights to the character's `AnimationPlayer`
+///
+/// Design constraints:
+/// - Root motion only produces velocity; integration (and later collision) stays
+///   in the shared fixed-step pipeline, so both modes are reconciled the same way.
+pub struct CharacterAnimationPlugin;
+
+impl Plugin for CharacterAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        // Visual only: runs on the variable timestep after input has been read.
+        app.add_systems(
+            Update,
+            blend_space::drive_locomotion_blend.after(AppSet::Input),
+        );
+
+        app.add_systems(
+            FixedUpdate,
+            root_motion::apply_root_motion
+                .in_set(AppSet::ApplyForces)
+                .after(movement::compute_velocity_from_input),
+        );
+    }
+}
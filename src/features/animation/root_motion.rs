@@ -0,0 +1,205 @@
+// src/features/animation/root_motion.rs
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::features::movement::component::{MovementMode, Velocity};
+use crate::features::player::death::AlivePlayer;
+
+/// Horizontal root-bone track extracted from one animation clip.
+///
+/// Extraction contract:
+/// - samples are root-bone positions in the character's local space, sorted by time
+/// - vertical motion is dropped (bobbing stays in the pose, not the capsule)
+/// - positions are re-based so the clip starts at the origin
+/// - the clip loops: `displacement` across the end wraps and adds the full-cycle offset
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootMotionClip {
+    duration: f32,
+    samples: Vec<(f32, Vec3)>,
+}
+
+impl RootMotionClip {
+    /// Build a clip from raw root-bone samples (`(time_secs, local_position)`).
+    ///
+    /// Returns `None` for clips with no duration or no samples.
+    pub fn extract(duration: f32, samples: &[(f32, Vec3)]) -> Option<Self> {
+        if duration <= 0.0 || samples.is_empty() {
+            return None;
+        }
+
+        let origin = samples[0].1.with_y(0.0);
+        let mut samples: Vec<(f32, Vec3)> = samples
+            .iter()
+            .map(|&(t, p)| (t.clamp(0.0, duration), p.with_y(0.0) - origin))
+            .collect();
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Some(Self { duration, samples })
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Root position at `t` within one cycle (linear between samples, held at the ends).
+    pub fn position_at(&self, t: f32) -> Vec3 {
+        let t = t.clamp(0.0, self.duration);
+        let i = self.samples.partition_point(|&(st, _)| st <= t);
+
+        match (i.checked_sub(1).map(|i| self.samples[i]), self.samples.get(i)) {
+            (Some((t0, p0)), Some(&(t1, p1))) if t1 > t0 => p0.lerp(p1, (t - t0) / (t1 - t0)),
+            (Some((_, p0)), _) => p0,
+            (None, Some(&(_, p1))) => p1,
+            (None, None) => Vec3::ZERO,
+        }
+    }
+
+    /// Root displacement over one full loop.
+    pub fn cycle_offset(&self) -> Vec3 {
+        self.position_at(self.duration) - self.position_at(0.0)
+    }
+
+    /// Local displacement from clip time `from` to `from + dt` (looping).
+    pub fn displacement(&self, from: f32, dt: f32) -> Vec3 {
+        let start = from.rem_euclid(self.duration);
+        let end = start + dt.max(0.0);
+        let wraps = (end / self.duration).floor();
+        let end_in_cycle = end - wraps * self.duration;
+
+        self.cycle_offset() * wraps + self.position_at(end_in_cycle) - self.position_at(start)
+    }
+}
+
+/// Root-motion playback on a character using `MovementMode::RootMotion`.
+///
+/// - `clip` is shared (`Arc`) so many characters can play the same extracted track.
+/// - `rate` is the playback speed (0 = paused); whoever drives the visual animation
+///   should use the same clip time so feet and displacement stay in sync.
+#[derive(Component, Debug, Clone)]
+pub struct RootMotion {
+    pub clip: Arc<RootMotionClip>,
+    pub time: f32,
+    pub rate: f32,
+}
+
+impl RootMotion {
+    pub fn new(clip: Arc<RootMotionClip>) -> Self {
+        Self {
+            clip,
+            time: 0.0,
+            rate: 1.0,
+        }
+    }
+}
+
+/// FixedUpdate: advance root-motion clips and convert this tick's displacement into `Velocity`.
+///
+/// - Reads: RootMotion, Transform.rotation, MovementMode
+/// - Writes: RootMotion.time, Velocity (world units/sec)
+/// - Runs before `integrate_velocity`, so collision/integration treat it like any other velocity.
+/// - Entities in `MovementMode::Analytic` keep their clip paused and their velocity untouched.
+pub fn apply_root_motion(
+    time: Res<Time<Fixed>>,
+    mut q_movers: Query<(&MovementMode, &mut RootMotion, &Transform, &mut Velocity), AlivePlayer>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+
+    for (mode, mut root_motion, transform, mut velocity) in &mut q_movers {
+        if *mode != MovementMode::RootMotion {
+            continue;
+        }
+
+        let clip_dt = dt * root_motion.rate.max(0.0);
+        let local = root_motion.clip.displacement(root_motion.time, clip_dt);
+        root_motion.time = (root_motion.time + clip_dt).rem_euclid(root_motion.clip.duration());
+
+        velocity.0 = transform.rotation * local / dt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::player::component::Player;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    /// One-second walk cycle moving 2 units forward (-Z), with vertical bob.
+    fn walk() -> RootMotionClip {
+        RootMotionClip::extract(
+            1.0,
+            &[
+                (0.0, Vec3::new(0.0, 1.0, 0.0)),
+                (0.5, Vec3::new(0.0, 1.1, -1.0)),
+                (1.0, Vec3::new(0.0, 1.0, -2.0)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn advance_fixed(world: &mut World, secs: f32) {
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(secs));
+        world.insert_resource(fixed);
+    }
+
+    #[test]
+    fn extraction_drops_vertical_and_rebases() {
+        let clip = walk();
+        assert_eq!(clip.position_at(0.0), Vec3::ZERO);
+        assert!((clip.position_at(0.25) - Vec3::new(0.0, 0.0, -0.5)).length() < 1e-6);
+        assert!(RootMotionClip::extract(0.0, &[(0.0, Vec3::ZERO)]).is_none());
+        assert!(RootMotionClip::extract(1.0, &[]).is_none());
+    }
+
+    #[test]
+    fn displacement_wraps_across_loop_end() {
+        let clip = walk();
+        // 0.9 -> 1.1 crosses the loop: 0.2s at 2 units/sec.
+        let d = clip.displacement(0.9, 0.2);
+        assert!((d - Vec3::new(0.0, 0.0, -0.4)).length() < 1e-5, "got {d:?}");
+        // Multiple loops accumulate the cycle offset.
+        let d = clip.displacement(0.0, 2.5);
+        assert!((d - Vec3::new(0.0, 0.0, -5.0)).length() < 1e-5, "got {d:?}");
+    }
+
+    #[test]
+    fn root_motion_writes_rotated_velocity_only_in_root_motion_mode() {
+        let mut world = World::new();
+        advance_fixed(&mut world, 0.1);
+        let clip = Arc::new(walk());
+
+        let rm = world
+            .spawn((
+                Player,
+                MovementMode::RootMotion,
+                RootMotion::new(clip.clone()),
+                Transform::from_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2)),
+                Velocity(Vec3::ZERO),
+            ))
+            .id();
+        let analytic = world
+            .spawn((
+                Player,
+                MovementMode::Analytic,
+                RootMotion::new(clip),
+                Transform::default(),
+                Velocity(Vec3::Y),
+            ))
+            .id();
+
+        let _ = world.run_system_once(apply_root_motion);
+
+        // Local -Z at 2 u/s, rotated +90° about Y -> world -X.
+        let v = world.get::<Velocity>(rm).unwrap().0;
+        assert!((v - Vec3::new(-2.0, 0.0, 0.0)).length() < 1e-4, "got {v:?}");
+        assert!((world.get::<RootMotion>(rm).unwrap().time - 0.1).abs() < 1e-6);
+
+        assert_eq!(world.get::<Velocity>(analytic).unwrap().0, Vec3::Y);
+        assert_eq!(world.get::<RootMotion>(analytic).unwrap().time, 0.0);
+    }
+}
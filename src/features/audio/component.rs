@@ -0,0 +1,212 @@
+// src/features/audio/component.rs
+use bevy::prelude::*;
+
+/// Mixer group a sound plays through. Sounds without one play on `Sfx`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[require(SoundVolume)]
+pub enum AudioBus {
+    /// Scales every other bus; nothing plays on it directly.
+    Master,
+    Music,
+    #[default]
+    Sfx,
+    Ui,
+}
+
+impl AudioBus {
+    /// Whether sounds on this bus are suspended while the game isn't playing
+    /// (UI sounds keep going so menus stay audible).
+    pub fn suspends_when_paused(self) -> bool {
+        !matches!(self, Self::Ui)
+    }
+}
+
+/// The sound's own volume (`0.0..=1.0`) before bus gains; features fade this
+/// instead of touching `AudioSink`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SoundVolume(pub f32);
+
+impl Default for SoundVolume {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Loud one-shot: briefly ducks the music bus when it starts.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DucksMusic;
+
+/// User-facing audio settings (what the volume sliders edit).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub ui: f32,
+    /// How much (`0.0..=1.0`) `DucksMusic` sounds attenuate the music.
+    pub music_duck_amount: f32,
+    /// Seconds the music stays ducked, followed by `duck_release_secs` of recovery.
+    pub music_duck_secs: f32,
+    pub duck_release_secs: f32,
+    /// `AudioMixer::muffle` while the player's head is underwater.
+    pub underwater_muffle: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 0.7,
+            sfx: 1.0,
+            ui: 0.8,
+            music_duck_amount: 0.5,
+            music_duck_secs: 0.4,
+            duck_release_secs: 0.6,
+            underwater_muffle: 0.6,
+        }
+    }
+}
+
+impl AudioSettings {
+    /// Slider value of `bus`, clamped to `0.0..=1.0`.
+    pub fn volume(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Master => self.master,
+            AudioBus::Music => self.music,
+            AudioBus::Sfx => self.sfx,
+            AudioBus::Ui => self.ui,
+        }
+        .clamp(0.0, 1.0)
+    }
+
+    pub fn volume_mut(&mut self, bus: AudioBus) -> &mut f32 {
+        match bus {
+            AudioBus::Master => &mut self.master,
+            AudioBus::Music => &mut self.music,
+            AudioBus::Sfx => &mut self.sfx,
+            AudioBus::Ui => &mut self.ui,
+        }
+    }
+}
+
+/// A temporary attenuation of one bus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Duck {
+    pub bus: AudioBus,
+    pub amount: f32,
+    /// Seconds of full attenuation left (goes negative during the release).
+    pub hold_secs: f32,
+    pub release_secs: f32,
+}
+
+impl Duck {
+    /// Current attenuation factor (`1.0` = untouched).
+    pub fn factor(&self) -> f32 {
+        let envelope = if self.hold_secs >= 0.0 {
+            1.0
+        } else {
+            (1.0 + self.hold_secs / self.release_secs.max(f32::EPSILON)).max(0.0)
+        };
+        1.0 - self.amount.clamp(0.0, 1.0) * envelope
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.hold_secs <= -self.release_secs
+    }
+}
+
+/// Runtime mixer state: active ducks and the sounds suspended by a pause.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct AudioMixer {
+    pub ducks: Vec<Duck>,
+    /// Sounds the mixer paused when leaving `GameState::Playing`; only these are
+    /// resumed (sounds paused for other reasons stay paused).
+    pub suspended: Vec<Entity>,
+    /// How muffled (`0.0..=1.0`) world sounds are (the listener is underwater,
+    /// stunned, ...); attenuates the SFX bus.
+    pub muffle: f32,
+}
+
+impl AudioMixer {
+    pub fn duck(&mut self, bus: AudioBus, amount: f32, hold_secs: f32, release_secs: f32) {
+        self.ducks.push(Duck {
+            bus,
+            amount,
+            hold_secs,
+            release_secs,
+        });
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        for duck in &mut self.ducks {
+            duck.hold_secs -= dt;
+        }
+        self.ducks.retain(|d| !d.is_finished());
+    }
+
+    /// Final gain of `bus`: master x bus slider x the strongest active duck
+    /// (x the muffle, for SFX).
+    pub fn gain(&self, settings: &AudioSettings, bus: AudioBus) -> f32 {
+        let duck = self
+            .ducks
+            .iter()
+            .filter(|d| d.bus == bus)
+            .map(Duck::factor)
+            .fold(1.0, f32::min);
+        let bus_volume = if bus == AudioBus::Master {
+            1.0
+        } else {
+            settings.volume(bus)
+        };
+        let muffle = if bus == AudioBus::Sfx {
+            1.0 - self.muffle.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        settings.volume(AudioBus::Master) * bus_volume * duck * muffle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_ui_sounds_keep_playing_when_paused() {
+        assert!(AudioBus::Music.suspends_when_paused());
+        assert!(AudioBus::Sfx.suspends_when_paused());
+        assert!(!AudioBus::Ui.suspends_when_paused());
+    }
+
+    #[test]
+    fn gain_multiplies_master_bus_and_duck() {
+        let settings = AudioSettings {
+            master: 0.5,
+            music: 0.8,
+            ..default()
+        };
+        let mut mixer = AudioMixer::default();
+        assert!((mixer.gain(&settings, AudioBus::Music) - 0.4).abs() < 1e-6);
+
+        mixer.duck(AudioBus::Music, 0.5, 0.2, 0.4);
+        assert!((mixer.gain(&settings, AudioBus::Music) - 0.2).abs() < 1e-6);
+        assert!((mixer.gain(&settings, AudioBus::Sfx) - 0.5).abs() < 1e-6);
+
+        mixer.tick(0.4);
+        assert!((mixer.gain(&settings, AudioBus::Music) - 0.3).abs() < 1e-6, "releasing");
+        mixer.tick(0.3);
+        assert!(mixer.ducks.is_empty());
+    }
+
+    #[test]
+    fn muffle_only_attenuates_world_sounds() {
+        let settings = AudioSettings::default();
+        let mixer = AudioMixer {
+            muffle: 0.75,
+            ..default()
+        };
+        assert!((mixer.gain(&settings, AudioBus::Sfx) - 0.25).abs() < 1e-6);
+        assert_eq!(mixer.gain(&settings, AudioBus::Ui), settings.ui);
+        assert_eq!(mixer.gain(&settings, AudioBus::Music), settings.music);
+    }
+}
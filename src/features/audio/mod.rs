@@ -0,0 +1,42 @@
+// src/features/audio/mod.rs
+use bevy::prelude::*;
+
+use crate::app::GameState;
+
+pub mod component;
+pub mod systems;
+
+/// Audio mixer.
+///
+/// Scope:
+/// - `AudioBus` (Master / Music / SFX / UI) on sound entities; untagged sounds play on SFX
+/// - `AudioSettings`: one volume per bus (the settings sliders), master scales the rest
+/// - ducking: `DucksMusic` sounds briefly attenuate the music bus (`AudioMixer`)
+/// - muffling: features set `AudioMixer::muffle` (e.g. underwater) to dull world sounds
+/// - pause-aware: leaving `GameState::Playing` suspends (not stops) every
+///   non-UI sound, including ones started meanwhile; returning resumes exactly those
+///
+/// Design constraints:
+/// - features set `SoundVolume`, never `AudioSink` volumes; the mixer applies
+///   the final volume in `PostUpdate`, after everything has faded.
+pub struct AudioMixerPlugin;
+
+impl Plugin for AudioMixerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::AudioSettings>();
+        app.init_resource::<component::AudioMixer>();
+
+        app.add_systems(
+            Update,
+            (systems::duck_music_on_loud_sounds, systems::tick_mixer).chain(),
+        );
+        app.add_systems(PostUpdate, systems::apply_bus_volumes);
+
+        app.add_systems(OnExit(GameState::Playing), systems::suspend_gameplay_audio);
+        app.add_systems(OnEnter(GameState::Playing), systems::resume_gameplay_audio);
+        app.add_systems(
+            Update,
+            systems::suspend_new_gameplay_audio.run_if(not(in_state(GameState::Playing))),
+        );
+    }
+}
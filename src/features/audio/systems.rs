@@ -0,0 +1,98 @@
+// src/features/audio/systems.rs
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use super::component::{AudioBus, AudioMixer, AudioSettings, DucksMusic, SoundVolume};
+
+/// Update: start a music duck for every loud sound that began playing.
+pub fn duck_music_on_loud_sounds(
+    settings: Res<AudioSettings>,
+    mut mixer: ResMut<AudioMixer>,
+    q_new: Query<(), Added<DucksMusic>>,
+) {
+    for () in &q_new {
+        mixer.duck(
+            AudioBus::Music,
+            settings.music_duck_amount,
+            settings.music_duck_secs,
+            settings.duck_release_secs,
+        );
+    }
+}
+
+/// Update: age the active ducks.
+pub fn tick_mixer(time: Res<Time>, mut mixer: ResMut<AudioMixer>) {
+    if !mixer.ducks.is_empty() {
+        mixer.tick(time.delta_secs());
+    }
+}
+
+/// OnExit(Playing): suspend every playing non-UI sound, remembering which.
+pub fn suspend_gameplay_audio(
+    mut mixer: ResMut<AudioMixer>,
+    q_sinks: Query<(Entity, Option<&AudioBus>, &AudioSink)>,
+) {
+    for (entity, bus, sink) in &q_sinks {
+        if bus.copied().unwrap_or_default().suspends_when_paused() && !sink.is_paused() {
+            sink.pause();
+            mixer.suspended.push(entity);
+        }
+    }
+}
+
+/// Update (while not playing): sounds that start during a pause are held too.
+pub fn suspend_new_gameplay_audio(
+    mut mixer: ResMut<AudioMixer>,
+    q_sinks: Query<(Entity, Option<&AudioBus>, &AudioSink), Added<AudioSink>>,
+) {
+    for (entity, bus, sink) in &q_sinks {
+        if bus.copied().unwrap_or_default().suspends_when_paused() && !sink.is_paused() {
+            sink.pause();
+            mixer.suspended.push(entity);
+        }
+    }
+}
+
+/// OnEnter(Playing): resume what the pause suspended (despawned sounds are skipped).
+pub fn resume_gameplay_audio(mut mixer: ResMut<AudioMixer>, q_sinks: Query<&AudioSink>) {
+    for entity in mixer.suspended.drain(..) {
+        if let Ok(sink) = q_sinks.get(entity) {
+            sink.play();
+        }
+    }
+}
+
+/// PostUpdate: sink volume = the sound's own volume x its bus gain.
+pub fn apply_bus_volumes(
+    settings: Res<AudioSettings>,
+    mixer: Res<AudioMixer>,
+    mut q_sinks: Query<(Option<&AudioBus>, Option<&SoundVolume>, &mut AudioSink)>,
+) {
+    for (bus, volume, mut sink) in &mut q_sinks {
+        let gain = mixer.gain(&settings, bus.copied().unwrap_or_default());
+        let volume = volume.map_or(1.0, |v| v.0) * gain;
+        sink.set_volume(Volume::Linear(volume));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loud_sounds_duck_the_music_once() {
+        let mut world = World::new();
+        world.init_resource::<AudioSettings>();
+        world.init_resource::<AudioMixer>();
+        world.spawn((AudioBus::Sfx, DucksMusic));
+
+        let mut system = IntoSystem::into_system(duck_music_on_loud_sounds);
+        system.initialize(&mut world);
+        let _ = system.run((), &mut world);
+        let _ = system.run((), &mut world);
+
+        let mixer = world.resource::<AudioMixer>();
+        assert_eq!(mixer.ducks.len(), 1);
+        assert_eq!(mixer.ducks[0].bus, AudioBus::Music);
+    }
+}
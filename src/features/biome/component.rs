@@ -0,0 +1,205 @@
+// src/features/biome/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::features::surface::component::SurfaceMaterial;
+
+/// One enemy table entry: relative weight and the spawner it produces.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct BiomeEnemy {
+    pub weight: u32,
+    pub max_alive: u32,
+    pub interval_secs: f32,
+}
+
+/// Kind of scattered vegetation / prop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum ScatterKind {
+    Grass,
+    Rock,
+    Tree,
+}
+
+impl ScatterKind {
+    /// Small, numerous kinds are baked into one mesh per chunk instead of one
+    /// entity per instance.
+    pub fn is_batched(self) -> bool {
+        matches!(self, Self::Grass | Self::Rock)
+    }
+}
+
+/// One scatter table entry: relative weight and the steepest ground it grows on.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ScatterRule {
+    pub kind: ScatterKind,
+    pub weight: f32,
+    pub max_slope_deg: f32,
+}
+
+/// A biome, loaded from `assets/data/biomes.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BiomeDef {
+    pub id: String,
+    pub name: String,
+    /// Band of the region noise (`0..1`) that selects this biome.
+    pub noise: (f32, f32),
+    pub ground_layer: u8,
+    pub surface: SurfaceMaterial,
+    pub height_amplitude: f32,
+    pub prop_density: f32,
+    pub enemy_chance: f32,
+    #[serde(default)]
+    pub enemies: Vec<BiomeEnemy>,
+    #[serde(default)]
+    pub ambience: Option<String>,
+    #[serde(default)]
+    pub scatter: Vec<ScatterRule>,
+}
+
+impl BiomeDef {
+    /// Enemy entry for a uniform `roll` in `0..1` (weighted; `None` if the table is empty).
+    pub fn pick_enemy(&self, roll: f32) -> Option<BiomeEnemy> {
+        let total: u32 = self.enemies.iter().map(|e| e.weight).sum();
+        let mut target = roll * total as f32;
+        for enemy in &self.enemies {
+            target -= enemy.weight as f32;
+            if target < 0.0 {
+                return Some(*enemy);
+            }
+        }
+        self.enemies.last().copied()
+    }
+
+    /// Scatter entry for a uniform `roll` in `0..1` (weighted; `None` if the table is empty).
+    pub fn pick_scatter(&self, roll: f32) -> Option<ScatterRule> {
+        let total: f32 = self.scatter.iter().map(|r| r.weight).sum();
+        let mut target = roll * total;
+        for rule in &self.scatter {
+            target -= rule.weight;
+            if target < 0.0 {
+                return Some(*rule);
+            }
+        }
+        self.scatter.last().copied()
+    }
+}
+
+/// All biomes plus the noise that lays them out over the world.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct BiomeLibrary {
+    pub seed: u32,
+    pub noise_scale: f32,
+    pub biomes: Vec<BiomeDef>,
+}
+
+const BUILTIN_BIOMES: &str = include_str!("../../../assets/data/biomes.ron");
+
+impl BiomeLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Region noise (`0..1`) at a world XZ position.
+    pub fn region_noise(&self, xz: Vec2) -> f32 {
+        fbm(xz * self.noise_scale, self.seed)
+    }
+
+    /// Biome at a world XZ position (the last biome catches values outside every band).
+    pub fn at(&self, xz: Vec2) -> &BiomeDef {
+        let n = self.region_noise(xz);
+        self.biomes
+            .iter()
+            .find(|b| n >= b.noise.0 && n < b.noise.1)
+            .or(self.biomes.last())
+            .expect("biome library must not be empty")
+    }
+
+    pub fn get(&self, id: &str) -> Option<&BiomeDef> {
+        self.biomes.iter().find(|b| b.id == id)
+    }
+}
+
+impl Default for BiomeLibrary {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_BIOMES).expect("assets/data/biomes.ron must parse")
+    }
+}
+
+fn hash2(x: i32, y: i32, seed: u32) -> f32 {
+    let mut h = (x as u32).wrapping_mul(0x8DA6_B343)
+        ^ (y as u32).wrapping_mul(0xD816_3841)
+        ^ seed.wrapping_mul(0xCB1A_B31F);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0x5BD1_E995);
+    h ^= h >> 15;
+    (h >> 8) as f32 / (1u32 << 24) as f32
+}
+
+/// Deterministic roll in `0..1` for a grid cell (chunk placement decisions).
+pub fn cell_roll(cell: IVec2, seed: u32, salt: u32) -> f32 {
+    hash2(cell.x, cell.y, seed ^ salt.wrapping_mul(0x9E37_79B9))
+}
+
+/// Smooth value noise in `0..1`.
+pub fn value_noise(p: Vec2, seed: u32) -> f32 {
+    let i = p.floor();
+    let f = p - i;
+    let u = f * f * (Vec2::splat(3.0) - 2.0 * f);
+    let (x, y) = (i.x as i32, i.y as i32);
+    let a = hash2(x, y, seed).lerp(hash2(x + 1, y, seed), u.x);
+    let b = hash2(x, y + 1, seed).lerp(hash2(x + 1, y + 1, seed), u.x);
+    a.lerp(b, u.y)
+}
+
+/// Three octaves of `value_noise`, still in `0..1`.
+pub fn fbm(p: Vec2, seed: u32) -> f32 {
+    let mut sum = 0.0;
+    let mut amplitude = 0.5;
+    let mut frequency = 1.0;
+    for octave in 0..3 {
+        sum += value_noise(p * frequency, seed.wrapping_add(octave)) * amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+    sum / 0.875
+}
+
+/// Biome the player is in (`None` before the first update).
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CurrentBiome(pub Option<String>);
+
+/// Looping ambience of one biome (audible while the player is inside).
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct BiomeAmbience {
+    pub biome: String,
+}
+
+/// Volume change per second of biome ambience cross-fades.
+pub const AMBIENCE_FADE_PER_SEC: f32 = 0.5;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_biomes_cover_the_noise_range() {
+        let library = BiomeLibrary::default();
+        let mut seen = std::collections::BTreeSet::new();
+        for i in 0..400 {
+            let xz = Vec2::new(i as f32 * 7.3, i as f32 * -3.1);
+            let n = library.region_noise(xz);
+            assert!((0.0..=1.0).contains(&n), "{n}");
+            seen.insert(library.at(xz).id.clone());
+        }
+        assert!(seen.len() >= 2, "only {seen:?}");
+    }
+
+    #[test]
+    fn enemy_table_is_weighted() {
+        let library = BiomeLibrary::default();
+        let badlands = library.get("badlands").unwrap();
+        assert_eq!(badlands.pick_enemy(0.0).unwrap().max_alive, 2);
+        assert_eq!(badlands.pick_enemy(0.8).unwrap().max_alive, 4);
+        assert_eq!(library.get("meadow").unwrap().pick_enemy(0.5), None);
+    }
+}
@@ -0,0 +1,64 @@
+// src/features/biome/mod.rs
+use bevy::prelude::*;
+
+use crate::features::notifications::systems::Notify;
+use crate::features::terrain::systems::{rebuild_dirty_chunks, spawn_terrain};
+
+pub mod component;
+pub mod scatter;
+pub mod systems;
+
+/// Biomes.
+///
+/// Scope:
+/// - `BiomeLibrary` (`assets/data/biomes.ron`): noise-driven regions, each with a
+///   ground paint layer, surface material, hill height, prop density, enemy table
+///   and optional ambience
+/// - terrain without stored heights is generated from the biomes at startup;
+///   terrain chunks take their biome's surface material
+/// - terrain chunks roll for enemy spawners from their biome's table
+/// - grass / rocks / trees are scattered over each chunk by its biome's density,
+///   density map and slope limits (seeded), as children of the chunk; edited
+///   chunks scatter again
+/// - grass and rocks are baked into one mesh per kind per chunk (`ScatterBatch`),
+///   so dense scatter costs a few draws per chunk rather than an entity per blade
+/// - `CurrentBiome` follows the player; `BiomeEntered` + a toast on crossing,
+///   ambience cross-fades
+///
+/// New biomes are data only: add an entry and give it a noise band.
+pub struct BiomePlugin;
+
+impl Plugin for BiomePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::BiomeLibrary>();
+        app.init_resource::<component::CurrentBiome>();
+
+        app.add_message::<systems::BiomeEntered>();
+        app.add_message::<Notify>();
+
+        app.add_systems(
+            Startup,
+            (
+                systems::generate_biome_terrain.before(spawn_terrain),
+                systems::place_biome_spawners.after(systems::generate_biome_terrain),
+                systems::spawn_biome_ambience,
+                scatter::setup_scatter_assets,
+            ),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::assign_chunk_surfaces,
+                systems::track_current_biome,
+                systems::fade_biome_ambience,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            (scatter::scatter_new_chunks, scatter::rescatter_dirty_chunks)
+                .chain()
+                .before(rebuild_dirty_chunks),
+        );
+    }
+}
@@ -0,0 +1,369 @@
+// src/features/biome/scatter.rs
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::component::{BiomeLibrary, ScatterKind, cell_roll, value_noise};
+use crate::features::character_material::toon::Stylized;
+use crate::features::culling::component::Cullable;
+use crate::features::terrain::component::{
+    DirtyTerrainChunks, TERRAIN_CHUNK_CELLS, TerrainChunk, TerrainData,
+};
+
+/// Frequency of the density map that thins and thickens scatter (per world unit).
+const DENSITY_MAP_SCALE: f32 = 0.15;
+
+/// A scattered piece of vegetation / rock (child of its terrain chunk).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scattered {
+    pub kind: ScatterKind,
+}
+
+/// All instances of one batched kind in a chunk, baked into a single mesh
+/// (child of its terrain chunk, placed at the chunk center).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScatterBatch {
+    pub kind: ScatterKind,
+    pub instances: usize,
+}
+
+/// One placement decided by `scatter_chunk`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScatterInstance {
+    pub kind: ScatterKind,
+    /// Ground point the instance stands on.
+    pub position: Vec3,
+    pub yaw: f32,
+    pub scale: f32,
+}
+
+/// Shared mesh / material per scatter kind, plus the mesh's half height.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ScatterAssets(pub HashMap<ScatterKind, (Handle<Mesh>, Handle<StandardMaterial>, f32)>);
+
+/// Ground slope at `xz` in degrees (samples are clamped to the terrain, so edges
+/// use one-sided differences).
+pub fn slope_deg(terrain: &TerrainData, xz: Vec2) -> f32 {
+    let last = terrain.size - 1;
+    let (min, max) = (terrain.vertex_xz(0, 0), terrain.vertex_xz(last, last));
+    let e = terrain.spacing * 0.5;
+    let sample = |offset: Vec2| {
+        let p = (xz + offset).clamp(min, max);
+        (p, terrain.height_at(p).unwrap_or(0.0))
+    };
+    let ((l, hl), (r, hr)) = (sample(Vec2::new(-e, 0.0)), sample(Vec2::new(e, 0.0)));
+    let ((b, hb), (f, hf)) = (sample(Vec2::new(0.0, -e)), sample(Vec2::new(0.0, e)));
+    let gradient = Vec2::new(
+        (hr - hl) / (r.x - l.x).max(f32::EPSILON),
+        (hf - hb) / (f.y - b.y).max(f32::EPSILON),
+    );
+    gradient.length().atan().to_degrees()
+}
+
+/// Seeded placements for one chunk: the same terrain and library always give the
+/// same result.
+///
+/// Candidates are thrown uniformly over the chunk at the densest rate any biome
+/// could want; each keeps with its biome's `prop_density` times the density map,
+/// picks a kind from the biome's table and is dropped if the ground is too steep.
+pub fn scatter_chunk(library: &BiomeLibrary, terrain: &TerrainData, coord: UVec2) -> Vec<ScatterInstance> {
+    let max_density = library
+        .biomes
+        .iter()
+        .map(|b| b.prop_density)
+        .fold(0.0, f32::max)
+        * 2.0;
+    let last = terrain.size - 1;
+    let min = coord * TERRAIN_CHUNK_CELLS;
+    let max = (min + UVec2::splat(TERRAIN_CHUNK_CELLS)).min(UVec2::splat(last));
+    let (from, to) = (terrain.vertex_xz(min.x, min.y), terrain.vertex_xz(max.x, max.y));
+    let extent = to - from;
+    let candidates = (extent.x * extent.y * max_density).ceil() as u32;
+    let cell = coord.as_ivec2();
+
+    let mut placed = Vec::new();
+    for i in 0..candidates {
+        let roll = |k: u32| cell_roll(cell, library.seed, i * 8 + k);
+        let xz = from + Vec2::new(roll(0), roll(1)) * extent;
+        let biome = library.at(xz);
+        let density = biome.prop_density * 2.0 * value_noise(xz * DENSITY_MAP_SCALE, library.seed ^ 0x5CA7);
+        if roll(2) * max_density >= density {
+            continue;
+        }
+        let Some(rule) = biome.pick_scatter(roll(3)) else {
+            continue;
+        };
+        let Some(ground) = terrain.height_at(xz) else {
+            continue;
+        };
+        if slope_deg(terrain, xz) > rule.max_slope_deg {
+            continue;
+        }
+        placed.push(ScatterInstance {
+            kind: rule.kind,
+            position: Vec3::new(xz.x, ground, xz.y),
+            yaw: roll(4) * std::f32::consts::TAU,
+            scale: 0.75 + roll(5) * 0.5,
+        });
+    }
+    placed
+}
+
+/// `base` copied once per transform into one mesh (positions, normals, indices).
+///
+/// One draw per batch instead of one entity per blade; the copies are static,
+/// which is fine since scatter only changes when its chunk is edited.
+pub fn bake_instances(base: &Mesh, transforms: &[Transform]) -> Mesh {
+    let positions = base
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|a| a.as_float3())
+        .unwrap_or(&[]);
+    let normals = base
+        .attribute(Mesh::ATTRIBUTE_NORMAL)
+        .and_then(|a| a.as_float3())
+        .unwrap_or(&[]);
+    let indices: Vec<u32> = match base.indices() {
+        Some(indices) => indices.iter().map(|i| i as u32).collect(),
+        None => (0..positions.len() as u32).collect(),
+    };
+
+    let mut out_positions = Vec::with_capacity(positions.len() * transforms.len());
+    let mut out_normals = Vec::with_capacity(normals.len() * transforms.len());
+    let mut out_indices = Vec::with_capacity(indices.len() * transforms.len());
+    for transform in transforms {
+        let offset = out_positions.len() as u32;
+        let matrix = transform.to_matrix();
+        out_positions.extend(positions.iter().map(|p| matrix.transform_point3(Vec3::from(*p)).to_array()));
+        out_normals.extend(
+            normals
+                .iter()
+                .map(|n| (transform.rotation * Vec3::from(*n)).to_array()),
+        );
+        out_indices.extend(indices.iter().map(|i| i + offset));
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, out_positions)
+        .with_inserted_indices(Indices::U32(out_indices));
+    if !out_normals.is_empty() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, out_normals);
+    }
+    mesh
+}
+
+/// Startup: meshes and materials shared by every scattered instance.
+pub fn setup_scatter_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mut assets = ScatterAssets::default();
+    let mut add = |kind, mesh: Mesh, color: Color, half_height: f32| {
+        assets.0.insert(kind, (meshes.add(mesh), materials.add(color), half_height));
+    };
+    add(ScatterKind::Grass, Cuboid::new(0.08, 0.5, 0.08).into(), Color::srgb_u8(95, 150, 60), 0.25);
+    add(ScatterKind::Rock, Cuboid::new(0.7, 0.45, 0.55).into(), Color::srgb_u8(120, 115, 105), 0.2);
+    add(ScatterKind::Tree, Cone::new(0.9, 3.0).into(), Color::srgb_u8(45, 100, 50), 1.5);
+    commands.insert_resource(assets);
+}
+
+fn spawn_scatter(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    assets: &ScatterAssets,
+    library: &BiomeLibrary,
+    terrain: &TerrainData,
+    chunk: Entity,
+    coord: UVec2,
+) {
+    let center = terrain.chunk_center(coord);
+    let center = Vec3::new(center.x, 0.0, center.y);
+    let mut batches: HashMap<ScatterKind, Vec<Transform>> = HashMap::default();
+
+    commands.entity(chunk).with_children(|parent| {
+        for instance in scatter_chunk(library, terrain, coord) {
+            let Some((mesh, material, half_height)) = assets.0.get(&instance.kind) else {
+                continue;
+            };
+            let transform =
+                Transform::from_translation(instance.position + Vec3::Y * half_height * instance.scale)
+                    .with_rotation(Quat::from_rotation_y(instance.yaw))
+                    .with_scale(Vec3::splat(instance.scale));
+            if instance.kind.is_batched() {
+                let local = transform.with_translation(transform.translation - center);
+                batches.entry(instance.kind).or_default().push(local);
+                continue;
+            }
+            parent.spawn((
+                Scattered { kind: instance.kind },
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Stylized,
+                Cullable::default(),
+                transform,
+            ));
+        }
+
+        for (kind, transforms) in batches {
+            let (base, material, _) = &assets.0[&kind];
+            let Some(base) = meshes.get(base) else {
+                continue;
+            };
+            let baked = bake_instances(base, &transforms);
+            parent.spawn((
+                ScatterBatch {
+                    kind,
+                    instances: transforms.len(),
+                },
+                Mesh3d(meshes.add(baked)),
+                MeshMaterial3d(material.clone()),
+                Stylized,
+                Cullable::default(),
+                Transform::from_translation(center),
+            ));
+        }
+    });
+}
+
+/// Update: new terrain chunks get their scatter as children (despawned with them).
+pub fn scatter_new_chunks(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    assets: Res<ScatterAssets>,
+    library: Res<BiomeLibrary>,
+    terrain: Res<TerrainData>,
+    q_new: Query<(Entity, &TerrainChunk), Added<TerrainChunk>>,
+) {
+    for (entity, chunk) in &q_new {
+        spawn_scatter(&mut commands, &mut meshes, &assets, &library, &terrain, entity, chunk.coord);
+    }
+}
+
+/// Update (before the chunk meshes are rebuilt): edited chunks drop their scatter
+/// and scatter again on the new ground.
+#[expect(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn rescatter_dirty_chunks(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    assets: Res<ScatterAssets>,
+    library: Res<BiomeLibrary>,
+    terrain: Res<TerrainData>,
+    dirty: Res<DirtyTerrainChunks>,
+    q_chunks: Query<(Entity, &TerrainChunk, Option<&Children>)>,
+    q_scattered: Query<(), Or<(With<Scattered>, With<ScatterBatch>)>>,
+) {
+    if dirty.0.is_empty() {
+        return;
+    }
+    for (entity, chunk, children) in &q_chunks {
+        if !dirty.0.contains(&chunk.coord) {
+            continue;
+        }
+        for child in children.into_iter().flatten() {
+            if q_scattered.contains(*child) {
+                commands.entity(*child).despawn();
+            }
+        }
+        spawn_scatter(&mut commands, &mut meshes, &assets, &library, &terrain, entity, chunk.coord);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn generated_terrain() -> TerrainData {
+        let mut world = World::new();
+        world.init_resource::<BiomeLibrary>();
+        world.init_resource::<TerrainData>();
+        let _ = world.run_system_once(super::super::systems::generate_biome_terrain);
+        world.remove_resource::<TerrainData>().unwrap()
+    }
+
+    #[test]
+    fn scatter_is_seeded_and_stays_on_the_chunk() {
+        let library = BiomeLibrary::default();
+        let terrain = generated_terrain();
+        let a = scatter_chunk(&library, &terrain, UVec2::ZERO);
+        assert!(!a.is_empty());
+        assert_eq!(a, scatter_chunk(&library, &terrain, UVec2::ZERO));
+
+        let (from, to) = (terrain.vertex_xz(0, 0), terrain.vertex_xz(16, 16));
+        for instance in &a {
+            let xz = instance.position.xz();
+            assert!(xz.cmpge(from).all() && xz.cmple(to).all(), "{xz}");
+            assert_eq!(Some(instance.position.y), terrain.height_at(xz));
+        }
+    }
+
+    #[test]
+    fn baking_copies_the_base_mesh_per_transform() {
+        let base: Mesh = Cuboid::new(1.0, 1.0, 1.0).into();
+        let transforms = [Transform::default(), Transform::from_xyz(10.0, 0.0, 0.0)];
+        let baked = bake_instances(&base, &transforms);
+
+        assert_eq!(baked.count_vertices(), base.count_vertices() * 2);
+        assert_eq!(baked.indices().unwrap().len(), base.indices().unwrap().len() * 2);
+        let positions = baked.attribute(Mesh::ATTRIBUTE_POSITION).unwrap().as_float3().unwrap();
+        let n = base.count_vertices();
+        assert_eq!(positions[n][0], positions[0][0] + 10.0);
+        let max_index = baked.indices().unwrap().iter().max().unwrap();
+        assert_eq!(max_index, n * 2 - 1);
+    }
+
+    #[test]
+    fn steep_ground_stays_bare() {
+        let library = BiomeLibrary::default();
+        let mut terrain = TerrainData::default();
+        for z in 0..terrain.size {
+            for x in 0..terrain.size {
+                let i = terrain.index(x, z);
+                terrain.heights[i] = x as f32 * 6.0;
+            }
+        }
+        assert!(slope_deg(&terrain, terrain.chunk_center(UVec2::ZERO)) > 80.0);
+        assert!(scatter_chunk(&library, &terrain, UVec2::ZERO).is_empty());
+    }
+
+    #[test]
+    fn scatter_lives_and_dies_with_its_chunk() {
+        let mut world = World::new();
+        world.init_resource::<BiomeLibrary>();
+        world.insert_resource(generated_terrain());
+        world.init_resource::<DirtyTerrainChunks>();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        let _ = world.run_system_once(setup_scatter_assets);
+        let chunk = world.spawn((TerrainChunk { coord: UVec2::ZERO }, Transform::default())).id();
+
+        let _ = world.run_system_once(scatter_new_chunks);
+        let count = |world: &mut World| {
+            let single = world.query::<&Scattered>().iter(world).count();
+            let batched: usize = world.query::<&ScatterBatch>().iter(world).map(|b| b.instances).sum();
+            single + batched
+        };
+        let before = count(&mut world);
+        let expected = scatter_chunk(
+            world.resource::<BiomeLibrary>(),
+            world.resource::<TerrainData>(),
+            UVec2::ZERO,
+        );
+        assert_eq!(before, expected.len());
+        assert!(before > 0);
+        // One batch entity per batched kind present, none per blade.
+        let mut batched_kinds: Vec<_> = expected.iter().map(|i| i.kind).filter(|k| k.is_batched()).collect();
+        batched_kinds.sort_by_key(|k| *k as u8);
+        batched_kinds.dedup();
+        assert_eq!(world.query::<&ScatterBatch>().iter(&world).count(), batched_kinds.len());
+        assert!(world.query::<&Scattered>().iter(&world).all(|s| !s.kind.is_batched()));
+
+        world.resource_mut::<DirtyTerrainChunks>().mark(UVec2::ZERO);
+        let _ = world.run_system_once(rescatter_dirty_chunks);
+        assert_eq!(count(&mut world), before, "rescatter replaces, never stacks");
+
+        world.entity_mut(chunk).despawn();
+        assert_eq!(count(&mut world), 0);
+    }
+}
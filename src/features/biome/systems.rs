@@ -0,0 +1,239 @@
+// src/features/biome/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    AMBIENCE_FADE_PER_SEC, BiomeAmbience, BiomeLibrary, CurrentBiome, cell_roll, fbm,
+};
+use crate::features::audio::component::{AudioBus, SoundVolume};
+use crate::features::enemy::component::{EnemySpawner, SpawnerDef};
+use crate::features::notifications::systems::Notify;
+use crate::features::player::component::Player;
+use crate::features::surface::component::SurfaceMaterial;
+use crate::features::terrain::component::{TerrainChunk, TerrainData};
+
+/// Hill noise frequency of generated terrain (per world unit).
+const HILL_SCALE: f32 = 0.08;
+
+/// The player crossed into another biome.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct BiomeEntered {
+    pub id: String,
+}
+
+/// Hill amplitude at `xz`, averaged over a small neighbourhood so heights blend
+/// across biome borders instead of stepping.
+fn blended_amplitude(library: &BiomeLibrary, xz: Vec2) -> f32 {
+    const OFFSETS: [Vec2; 5] = [
+        Vec2::ZERO,
+        Vec2::new(4.0, 0.0),
+        Vec2::new(-4.0, 0.0),
+        Vec2::new(0.0, 4.0),
+        Vec2::new(0.0, -4.0),
+    ];
+    OFFSETS
+        .iter()
+        .map(|o| library.at(xz + *o).height_amplitude)
+        .sum::<f32>()
+        / OFFSETS.len() as f32
+}
+
+/// Shape and paint `terrain` from the biome at each vertex (same library, same
+/// terrain; also used offline by the scene generator).
+pub fn shape_terrain(library: &BiomeLibrary, terrain: &mut TerrainData) {
+    for z in 0..terrain.size {
+        for x in 0..terrain.size {
+            let xz = terrain.vertex_xz(x, z);
+            let hills = fbm(xz * HILL_SCALE, library.seed.wrapping_add(101));
+            let i = terrain.index(x, z);
+            terrain.heights[i] = hills * blended_amplitude(library, xz);
+            terrain.paint[i] = library.at(xz).ground_layer;
+        }
+    }
+    terrain.needs_generation = false;
+}
+
+/// Startup (before the terrain is meshed): shape and paint terrain that has no
+/// stored heights.
+pub fn generate_biome_terrain(library: Res<BiomeLibrary>, mut terrain: ResMut<TerrainData>) {
+    if terrain.needs_generation {
+        shape_terrain(&library, &mut terrain);
+    }
+}
+
+/// Spawners `terrain` gets from its biomes: each chunk may get one from its biome's
+/// table (`enemy_chance`, seeded per chunk), paired with the biome's name.
+pub fn biome_spawners(library: &BiomeLibrary, terrain: &TerrainData) -> Vec<(String, SpawnerDef)> {
+    let mut spawners = Vec::new();
+    for z in 0..terrain.chunks() {
+        for x in 0..terrain.chunks() {
+            let cell = IVec2::new(x as i32, z as i32);
+            let center = terrain.chunk_center(UVec2::new(x, z));
+            let biome = library.at(center);
+            if cell_roll(cell, library.seed, 1) >= biome.enemy_chance {
+                continue;
+            }
+            let Some(enemy) = biome.pick_enemy(cell_roll(cell, library.seed, 2)) else {
+                continue;
+            };
+            let ground = terrain.height_at(center).unwrap_or(0.0);
+            let def = SpawnerDef {
+                position: (center.x, ground, center.y),
+                radius: 3.0,
+                max_alive: enemy.max_alive,
+                interval_secs: enemy.interval_secs,
+                hours: None,
+                retreat_outside_hours: false,
+                total: None,
+            };
+            spawners.push((biome.name.clone(), def));
+        }
+    }
+    spawners
+}
+
+/// Update: terrain chunks take the surface material of their biome.
+pub fn assign_chunk_surfaces(
+    mut commands: Commands,
+    library: Res<BiomeLibrary>,
+    terrain: Res<TerrainData>,
+    q_new: Query<(Entity, &TerrainChunk), Added<TerrainChunk>>,
+) {
+    for (entity, chunk) in &q_new {
+        let surface: SurfaceMaterial = library.at(terrain.chunk_center(chunk.coord)).surface;
+        commands.entity(entity).insert(surface);
+    }
+}
+
+/// Startup: spawn the terrain's `biome_spawners` (seeded per chunk so the world is
+/// the same every launch).
+pub fn place_biome_spawners(
+    mut commands: Commands,
+    library: Res<BiomeLibrary>,
+    terrain: Res<TerrainData>,
+) {
+    for (biome, def) in biome_spawners(&library, &terrain) {
+        let position = Vec3::from(def.position);
+        commands.spawn((
+            EnemySpawner::new(def),
+            Transform::from_translation(position),
+            Name::new(format!("{biome} Spawner")),
+        ));
+    }
+}
+
+/// Update: follow the biome under the player; entering a new one is announced.
+pub fn track_current_biome(
+    library: Res<BiomeLibrary>,
+    mut current: ResMut<CurrentBiome>,
+    q_player: Query<&GlobalTransform, With<Player>>,
+    mut entered: MessageWriter<BiomeEntered>,
+    mut notify: MessageWriter<Notify>,
+) {
+    let Some(position) = q_player.iter().next().map(GlobalTransform::translation) else {
+        return;
+    };
+    let biome = library.at(Vec2::new(position.x, position.z));
+    if current.0.as_deref() == Some(biome.id.as_str()) {
+        return;
+    }
+    if current.0.is_some() {
+        notify.write(Notify::info(format!("Entering {}", biome.name)));
+    }
+    current.0 = Some(biome.id.clone());
+    entered.write(BiomeEntered {
+        id: biome.id.clone(),
+    });
+}
+
+/// Startup: one silent looping player per biome ambience (none headless).
+pub fn spawn_biome_ambience(
+    mut commands: Commands,
+    library: Res<BiomeLibrary>,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    for biome in &library.biomes {
+        let Some(path) = &biome.ambience else {
+            continue;
+        };
+        commands.spawn((
+            BiomeAmbience {
+                biome: biome.id.clone(),
+            },
+            AudioBus::Sfx,
+            AudioPlayer::new(asset_server.load(path.clone())),
+            PlaybackSettings::LOOP.with_volume(bevy::audio::Volume::SILENT),
+            Name::new(format!("{} Ambience", biome.name)),
+        ));
+    }
+}
+
+/// Update: cross-fade ambience towards the current biome's.
+pub fn fade_biome_ambience(
+    time: Res<Time>,
+    current: Res<CurrentBiome>,
+    mut q_ambience: Query<(&BiomeAmbience, &mut SoundVolume)>,
+) {
+    let step = AMBIENCE_FADE_PER_SEC * time.delta_secs();
+    for (ambience, mut volume) in &mut q_ambience {
+        let target = if current.0.as_deref() == Some(ambience.biome.as_str()) {
+            1.0
+        } else {
+            0.0
+        };
+        volume.0 += (target - volume.0).clamp(-step, step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn generated_terrain_is_painted_by_biome() {
+        let mut world = World::new();
+        let library = BiomeLibrary::default();
+        world.insert_resource(library.clone());
+        world.init_resource::<TerrainData>();
+        assert!(world.resource::<TerrainData>().needs_generation);
+
+        let _ = world.run_system_once(generate_biome_terrain);
+
+        let terrain = world.resource::<TerrainData>();
+        assert!(!terrain.needs_generation);
+        for (x, z) in [(0, 0), (16, 16), (32, 32)] {
+            let expected = library.at(terrain.vertex_xz(x, z)).ground_layer;
+            assert_eq!(terrain.paint[terrain.index(x, z)], expected);
+        }
+        assert!(terrain.heights.iter().any(|h| *h > 0.0));
+    }
+
+    #[test]
+    fn crossing_biomes_is_announced_once() {
+        let mut world = World::new();
+        let library = BiomeLibrary::default();
+        // Two points in different biomes.
+        let mut points = (0..200).map(|i| Vec2::new(i as f32 * 9.0, 0.0));
+        let a = points.next().unwrap();
+        let b = points.find(|p| library.at(*p).id != library.at(a).id).unwrap();
+        world.insert_resource(library);
+        world.init_resource::<CurrentBiome>();
+        world.init_resource::<Messages<BiomeEntered>>();
+        world.init_resource::<Messages<Notify>>();
+        let player = world.spawn((Player, GlobalTransform::from_xyz(a.x, 0.0, a.y))).id();
+
+        let _ = world.run_system_once(track_current_biome);
+        let _ = world.run_system_once(track_current_biome);
+        assert_eq!(world.resource::<Messages<BiomeEntered>>().len(), 1);
+        assert_eq!(world.resource::<Messages<Notify>>().len(), 0, "no toast on spawn");
+
+        world.entity_mut(player).insert(GlobalTransform::from_xyz(b.x, 0.0, b.y));
+        let _ = world.run_system_once(track_current_biome);
+        assert_eq!(world.resource::<Messages<BiomeEntered>>().len(), 2);
+        assert_eq!(world.resource::<Messages<Notify>>().len(), 1);
+    }
+}
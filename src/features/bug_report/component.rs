@@ -0,0 +1,109 @@
+// src/features/bug_report/component.rs
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+
+use crate::app::{GameMode, StoragePaths};
+
+/// Frames to wait for the screenshot before writing the report without one.
+pub const SCREENSHOT_WAIT_FRAMES: u32 = 30;
+
+/// Key that captures a bug report.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BugReportKeybinding(pub KeyCode);
+
+impl Default for BugReportKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::F12)
+    }
+}
+
+/// Where reports are written.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct BugReportDir(pub PathBuf);
+
+impl FromWorld for BugReportDir {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).data_file("reports"))
+    }
+}
+
+/// `summary.ron`: what the game was doing when the report was taken.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ReportSummary {
+    pub version: String,
+    pub created_unix_secs: u64,
+    pub tick: u64,
+    pub seed: Option<u64>,
+    pub mode: Option<GameMode>,
+    pub scenes: Vec<String>,
+    pub player_position: Option<(f32, f32, f32)>,
+    pub entities: usize,
+    /// Session gameplay log bundled as `gameplay_log.jsonl` (when recording).
+    pub gameplay_log: Option<PathBuf>,
+}
+
+/// The files of one report, archived together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BugReport {
+    pub files: Vec<(String, Vec<u8>)>,
+}
+
+impl BugReport {
+    pub fn add(&mut self, name: &str, contents: impl Into<Vec<u8>>) {
+        self.files.push((name.to_owned(), contents.into()));
+    }
+
+    /// Write every file into one zip at `path`.
+    pub fn write_zip(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path)?);
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for (name, contents) in &self.files {
+            zip.start_file(name.as_str(), options).map_err(std::io::Error::other)?;
+            zip.write_all(contents)?;
+        }
+        zip.finish().map_err(std::io::Error::other)?;
+        Ok(())
+    }
+}
+
+/// A report waiting for its screenshot.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct PendingBugReport {
+    /// File name without extension (`bug-<unix secs>`).
+    pub stem: String,
+    pub report: BugReport,
+    /// Where the screenshot is being saved (`None`: no window, no screenshot).
+    pub screenshot: Option<PathBuf>,
+    pub frames_waited: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn reports_zip_every_file() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_bug_report_{}", std::process::id()))
+            .join("bug-1.zip");
+        let mut report = BugReport::default();
+        report.add("summary.ron", "(tick: 12)");
+        report.add("world.txt", vec![b'x'; 4096]);
+        report.write_zip(&path).unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut summary = String::new();
+        archive.by_name("summary.ron").unwrap().read_to_string(&mut summary).unwrap();
+        assert_eq!(summary, "(tick: 12)");
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
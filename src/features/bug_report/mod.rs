@@ -0,0 +1,46 @@
+// src/features/bug_report/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+use crate::features::notifications::systems::Notify;
+
+pub mod component;
+pub mod systems;
+
+/// In-app bug reports (playtesting).
+///
+/// Scope:
+/// - `BugReportKeybinding` (F12) or `CaptureBugReport` bundles a screenshot, a
+///   summary (version, tick, seed, mode, loaded scenes, player position), the
+///   user's settings, the recent gameplay events (and the session's gameplay log
+///   when recording) and a world snapshot into one zip in `<data dir>/reports`
+/// - a notification names the written file
+///
+/// Design constraints:
+/// - Everything but the screenshot is gathered on the frame the key was pressed;
+///   the screenshot arrives a few frames later and the report waits for it
+///   (bounded: headless builds and failed captures still get their report).
+pub struct BugReportPlugin;
+
+impl Plugin for BugReportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::BugReportKeybinding>();
+        app.init_resource::<component::BugReportDir>();
+
+        app.add_message::<systems::CaptureBugReport>();
+        app.add_message::<Notify>();
+
+        app.add_systems(
+            Update,
+            systems::read_bug_report_key
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(
+            Update,
+            (systems::capture_bug_report, systems::finish_bug_report)
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,226 @@
+// src/features/bug_report/systems.rs
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+use bevy::window::PrimaryWindow;
+
+use super::component::{
+    BugReport, BugReportDir, BugReportKeybinding, PendingBugReport, ReportSummary,
+    SCREENSHOT_WAIT_FRAMES,
+};
+use crate::app::{GameMode, SimTick};
+use crate::features::diagnostics::timeline::EventTimeline;
+use crate::features::gameplay_log::component::GameplayLog;
+use crate::features::inspector::systems::entity_report;
+use crate::features::leaderboard::component::{GAME_VERSION, RunSeed, unix_secs};
+use crate::features::notifications::component::NotifyKind;
+use crate::features::notifications::systems::Notify;
+use crate::features::player::component::Player;
+use crate::features::scenes::component::LoadedScenes;
+use crate::features::settings::component::UserSettings;
+
+/// Capture a bug report (ignored while one is still being written).
+#[derive(Message, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureBugReport;
+
+/// Update: the bug report key.
+pub fn read_bug_report_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<BugReportKeybinding>,
+    mut capture: MessageWriter<CaptureBugReport>,
+) {
+    if keyboard.just_pressed(binding.0) {
+        capture.write(CaptureBugReport);
+    }
+}
+
+fn pretty_ron(value: &impl serde::Serialize) -> String {
+    ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+        .unwrap_or_else(|err| format!("// not serializable: {err}"))
+}
+
+/// Update (exclusive): on request, gather everything but the screenshot right away
+/// (so it all describes the same frame) and ask for the screenshot.
+///
+/// - `summary.ron`: version, tick, seed, mode, loaded scenes, player position
+/// - `settings.ron`: the user's settings
+/// - `recent_events.txt`: the diagnostics `EventTimeline`
+/// - `gameplay_log.jsonl`: this session's gameplay log, when recording
+/// - `world.txt`: every entity with a `Transform` (inspector report)
+pub fn capture_bug_report(world: &mut World) {
+    let requested = world
+        .get_resource_mut::<Messages<CaptureBugReport>>()
+        .is_some_and(|mut requests| requests.drain().count() > 0);
+    if !requested || world.contains_resource::<PendingBugReport>() {
+        return;
+    }
+    let created = unix_secs();
+    let stem = format!("bug-{created}");
+    let mut report = BugReport::default();
+
+    let player_position = world
+        .query_filtered::<&Transform, With<Player>>()
+        .iter(world)
+        .next()
+        .map(|tr| tr.translation.into());
+    let entities = world.query::<Entity>().iter(world).count();
+    let gameplay_log = world.get_resource::<GameplayLog>().map(|log| log.path().to_owned());
+    let summary = ReportSummary {
+        version: GAME_VERSION.into(),
+        created_unix_secs: created,
+        tick: world.get_resource::<SimTick>().map_or(0, |tick| tick.0),
+        seed: world.get_resource::<RunSeed>().map(|seed| seed.0),
+        mode: world.get_resource::<State<GameMode>>().map(|mode| *mode.get()),
+        scenes: world
+            .get_resource::<LoadedScenes>()
+            .map(|loaded| loaded.0.clone())
+            .unwrap_or_default(),
+        player_position,
+        entities,
+        gameplay_log: gameplay_log.clone(),
+    };
+    report.add("summary.ron", pretty_ron(&summary));
+
+    if let Some(settings) = world.get_resource::<UserSettings>() {
+        report.add("settings.ron", pretty_ron(settings));
+    }
+    if let Some(timeline) = world.get_resource::<EventTimeline>() {
+        let lines: String = timeline
+            .entries()
+            .map(|entry| format!("{}: {:?}\n", entry.tick, entry.event))
+            .collect();
+        report.add("recent_events.txt", lines);
+    }
+    if let Some(path) = gameplay_log {
+        match std::fs::read(&path) {
+            Ok(contents) => report.add("gameplay_log.jsonl", contents),
+            Err(err) => warn!("bug report: could not read {}: {err}", path.display()),
+        }
+    }
+
+    let mut placed: Vec<Entity> = world
+        .query_filtered::<Entity, With<Transform>>()
+        .iter(world)
+        .collect();
+    placed.sort();
+    let snapshot: Vec<String> = placed
+        .into_iter()
+        .filter_map(|entity| entity_report(world, entity))
+        .collect();
+    report.add("world.txt", snapshot.join("\n\n"));
+
+    let has_window = world
+        .query_filtered::<(), With<PrimaryWindow>>()
+        .iter(world)
+        .next()
+        .is_some();
+    let screenshot = has_window.then(|| {
+        let path = world.resource::<BugReportDir>().0.join(format!("{stem}.png"));
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        world
+            .spawn(Screenshot::primary_window())
+            .observe(save_to_disk(path.clone()));
+        path
+    });
+
+    world.insert_resource(PendingBugReport {
+        stem,
+        report,
+        screenshot,
+        frames_waited: 0,
+    });
+}
+
+/// Update: once the screenshot is on disk (or after `SCREENSHOT_WAIT_FRAMES`), zip
+/// the pending report into the reports directory.
+pub fn finish_bug_report(
+    mut commands: Commands,
+    dir: Res<BugReportDir>,
+    pending: Option<ResMut<PendingBugReport>>,
+    mut notify: MessageWriter<Notify>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    let mut report = pending.report.clone();
+    if let Some(path) = pending.screenshot.clone() {
+        match std::fs::read(&path) {
+            Ok(png) => {
+                report.add("screenshot.png", png);
+                let _ = std::fs::remove_file(&path);
+            }
+            Err(_) if pending.frames_waited < SCREENSHOT_WAIT_FRAMES => {
+                pending.frames_waited += 1;
+                return;
+            }
+            Err(err) => warn!("bug report: no screenshot ({err})"),
+        }
+    }
+
+    let path = dir.0.join(format!("{}.zip", pending.stem));
+    commands.remove_resource::<PendingBugReport>();
+    match report.write_zip(&path) {
+        Ok(()) => {
+            info!("bug report written to {}", path.display());
+            notify.write(Notify::new(
+                NotifyKind::Info,
+                format!("Bug report saved: {}", path.display()),
+            ));
+        }
+        Err(err) => {
+            warn!("could not write bug report {}: {err}", path.display());
+            notify.write(Notify::new(NotifyKind::Warning, "Bug report could not be saved"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn reports_bundle_summary_settings_and_world_without_a_window() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_bug_reports_{}", std::process::id()));
+        let mut world = World::new();
+        world.insert_resource(BugReportDir(dir.clone()));
+        world.insert_resource(SimTick(300));
+        world.insert_resource(RunSeed(7));
+        world.init_resource::<UserSettings>();
+        world.init_resource::<EventTimeline>();
+        world.init_resource::<Messages<CaptureBugReport>>();
+        world.init_resource::<Messages<Notify>>();
+        world.spawn((Player, Transform::from_xyz(1.0, 2.0, 3.0), Name::new("Player")));
+
+        world.write_message(CaptureBugReport);
+        let _ = world.run_system_once(capture_bug_report);
+        let pending = world.resource::<PendingBugReport>();
+        assert_eq!(pending.screenshot, None, "no window, no screenshot");
+        let stem = pending.stem.clone();
+
+        world.write_message(CaptureBugReport);
+        let _ = world.run_system_once(capture_bug_report);
+        assert_eq!(world.resource::<PendingBugReport>().stem, stem, "one report at a time");
+
+        let _ = world.run_system_once(finish_bug_report);
+        assert!(!world.contains_resource::<PendingBugReport>());
+
+        let file = std::fs::File::open(dir.join(format!("{stem}.zip"))).unwrap();
+        let mut archive = zip::ZipArchive::new(file).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort();
+        assert_eq!(names, ["recent_events.txt", "settings.ron", "summary.ron", "world.txt"]);
+        let mut summary = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("summary.ron").unwrap(), &mut summary)
+            .unwrap();
+        assert!(summary.contains("tick: 300"), "{summary}");
+        assert!(summary.contains("seed: Some(7)"), "{summary}");
+        let mut snapshot = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("world.txt").unwrap(), &mut snapshot)
+            .unwrap();
+        assert!(snapshot.starts_with("Player"), "{snapshot}");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,159 @@
+// src/features/building/component.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::placement::PlacementError;
+
+/// Placeable structure prefabs.
+///
+/// Serialized by name (`"Floor"`): saves pass through untyped data on their way in
+/// (see the save feature's migrations), which has no room for RON enum variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "String")]
+pub enum StructureKind {
+    Floor,
+    Wall,
+    Crate,
+}
+
+impl StructureKind {
+    /// Cycle order for the "next structure" key.
+    pub const ALL: [StructureKind; 3] = [StructureKind::Floor, StructureKind::Wall, StructureKind::Crate];
+
+    pub fn def(self) -> StructureDef {
+        match self {
+            StructureKind::Floor => StructureDef {
+                half_extents: Vec3::new(1.0, 0.1, 1.0),
+                cost: &[("wood", 4)],
+                max_slope_deg: 30.0,
+                color: Color::srgb_u8(150, 120, 80),
+            },
+            StructureKind::Wall => StructureDef {
+                half_extents: Vec3::new(1.0, 1.0, 0.1),
+                cost: &[("wood", 2), ("stone", 2)],
+                max_slope_deg: 10.0,
+                color: Color::srgb_u8(160, 160, 165),
+            },
+            StructureKind::Crate => StructureDef {
+                half_extents: Vec3::splat(0.4),
+                cost: &[("wood", 2)],
+                max_slope_deg: 20.0,
+                color: Color::srgb_u8(170, 130, 70),
+            },
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|k| *k == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StructureKind::Floor => "Floor",
+            StructureKind::Wall => "Wall",
+            StructureKind::Crate => "Crate",
+        }
+    }
+}
+
+impl From<StructureKind> for &'static str {
+    fn from(kind: StructureKind) -> Self {
+        kind.name()
+    }
+}
+
+impl TryFrom<String> for StructureKind {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| format!("unknown structure `{name}`"))
+    }
+}
+
+/// Data describing a structure prefab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StructureDef {
+    pub half_extents: Vec3,
+    /// Inventory items consumed on placement.
+    pub cost: &'static [(&'static str, u32)],
+    /// Steepest supporting surface (degrees from horizontal) this can sit on.
+    pub max_slope_deg: f32,
+    pub color: Color,
+}
+
+/// A committed, player-built structure.
+///
+/// This is the persistent record of the build: kind + `Transform` translation fully
+/// describe it; the save layer stores both and loads them back with `RestoreStructures`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlacedStructure {
+    pub kind: StructureKind,
+}
+
+/// The translucent placement preview entity.
+///
+/// Carries the last placement evaluation so the commit step doesn't recompute it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildGhost {
+    pub kind: StructureKind,
+    pub placement: Result<(), PlacementError>,
+}
+
+/// Current state of the placement mode.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct BuildMode {
+    pub active: bool,
+    pub selected: StructureKind,
+}
+
+impl Default for BuildMode {
+    fn default() -> Self {
+        Self {
+            active: false,
+            selected: StructureKind::Floor,
+        }
+    }
+}
+
+/// Keys for the placement mode.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct BuildKeybindings {
+    pub toggle: KeyCode,
+    pub next_structure: KeyCode,
+    pub place: KeyCode,
+}
+
+impl Default for BuildKeybindings {
+    fn default() -> Self {
+        Self {
+            toggle: KeyCode::KeyB,
+            next_structure: KeyCode::Tab,
+            place: KeyCode::Enter,
+        }
+    }
+}
+
+/// Ghost materials (shared handles, recolored by validity).
+#[derive(Resource, Debug, Clone)]
+pub struct BuildGhostMaterials {
+    pub valid: Handle<StandardMaterial>,
+    pub invalid: Handle<StandardMaterial>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_cycles_through_all_kinds() {
+        let mut k = StructureKind::Floor;
+        for _ in 0..StructureKind::ALL.len() {
+            k = k.next();
+        }
+        assert_eq!(k, StructureKind::Floor);
+    }
+}
@@ -0,0 +1,50 @@
+// src/features/building/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::meshes::component::MeshLibrary;
+
+pub mod component;
+pub mod placement;
+pub mod systems;
+
+/// Building / placement mode.
+///
+/// Scope:
+/// - toggle build mode, cycle structure prefabs
+/// - ghost preview in front of the player: grid-snapped, resting on the ground or on
+///   the structure below, tinted by validity (overlap / slope / cost)
+/// - committing spends inventory items and spawns a `PlacedStructure`
+///
+/// Persistence: a placed structure is fully described by `PlacedStructure` + its
+/// translation, which is what the save layer records; loading replaces every placed
+/// structure through `RestoreStructures`.
+pub struct BuildingPlugin;
+
+impl Plugin for BuildingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::BuildMode>();
+        app.init_resource::<component::BuildKeybindings>();
+        app.init_resource::<MeshLibrary>();
+        app.add_message::<systems::StructurePlaced>();
+        app.add_message::<systems::RestoreStructures>();
+
+        app.add_systems(Startup, systems::setup_ghost_materials);
+        app.add_systems(
+            Update,
+            systems::read_build_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::restore_structures,
+                systems::update_build_ghost,
+                systems::place_structure,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,219 @@
+// src/features/building/placement.rs
+//
+// Pure placement math (no ECS), so snapping / validity rules are unit-testable.
+use bevy::prelude::*;
+
+/// Placement grid cell size in world units (XZ).
+pub const GRID_SIZE: f32 = 1.0;
+
+/// Distance in front of the builder where the ghost is placed.
+pub const BUILD_DISTANCE: f32 = 3.0;
+
+/// Overlap tolerance so structures resting on / next to each other aren't "overlapping".
+const OVERLAP_EPSILON: f32 = 1e-3;
+
+/// Axis-aligned box (center + half extents).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb3 {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+impl Aabb3 {
+    pub fn top(&self) -> f32 {
+        self.center.y + self.half_extents.y
+    }
+
+    /// Strict overlap (touching faces don't count).
+    pub fn overlaps(&self, other: &Aabb3) -> bool {
+        let d = (self.center - other.center).abs();
+        let r = self.half_extents + other.half_extents - Vec3::splat(OVERLAP_EPSILON);
+        d.x < r.x && d.y < r.y && d.z < r.z
+    }
+
+    fn contains_xz(&self, point: Vec3) -> bool {
+        let d = (self.center - point).abs();
+        d.x <= self.half_extents.x && d.z <= self.half_extents.z
+    }
+}
+
+/// A surface a structure can rest on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Support {
+    pub height: f32,
+    pub normal: Vec3,
+}
+
+impl Support {
+    /// Flat ground at y = 0, where nothing else is below.
+    pub const GROUND_PLANE: Self = Self {
+        height: 0.0,
+        normal: Vec3::Y,
+    };
+}
+
+/// Why a placement is not allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    Overlap,
+    TooSteep,
+    CannotAfford,
+}
+
+/// Snap an XZ position to the placement grid (Y is left alone).
+pub fn snap_to_grid(pos: Vec3) -> Vec3 {
+    Vec3::new(
+        (pos.x / GRID_SIZE).round() * GRID_SIZE,
+        pos.y,
+        (pos.z / GRID_SIZE).round() * GRID_SIZE,
+    )
+}
+
+/// Highest supporting surface under a point: `ground` (the level there, with its
+/// surface normal; the ground plane if there is none) or the top of any existing
+/// structure directly below it.
+///
+/// Only what's under the *center* supports; neighbours that merely intrude into the
+/// footprint are caught by the overlap check instead.
+pub fn find_support(center_xz: Vec3, ground: Option<Support>, existing: &[Aabb3]) -> Support {
+    existing
+        .iter()
+        .filter(|b| b.contains_xz(center_xz))
+        .map(|b| Support {
+            height: b.top(),
+            normal: Vec3::Y,
+        })
+        .fold(ground.unwrap_or(Support::GROUND_PLANE), |best, s| {
+            if s.height > best.height { s } else { best }
+        })
+}
+
+/// Full placement evaluation: snapped, surface-aligned box + validity.
+///
+/// `ground` looks up the level under a snapped position (see `find_support`).
+/// Checks (first failure wins): slope, overlap, affordability.
+pub fn evaluate_placement(
+    target: Vec3,
+    half_extents: Vec3,
+    max_slope_deg: f32,
+    ground: impl Fn(Vec3) -> Option<Support>,
+    existing: &[Aabb3],
+    affordable: bool,
+) -> (Aabb3, Result<(), PlacementError>) {
+    let snapped = snap_to_grid(target);
+    let support = find_support(snapped, ground(snapped), existing);
+
+    let aabb = Aabb3 {
+        center: Vec3::new(snapped.x, support.height + half_extents.y, snapped.z),
+        half_extents,
+    };
+
+    let slope_deg = support.normal.angle_between(Vec3::Y).to_degrees();
+    let result = if slope_deg > max_slope_deg {
+        Err(PlacementError::TooSteep)
+    } else if existing.iter().any(|b| b.overlaps(&aabb)) {
+        Err(PlacementError::Overlap)
+    } else if !affordable {
+        Err(PlacementError::CannotAfford)
+    } else {
+        Ok(())
+    };
+
+    (aabb, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flat(_: Vec3) -> Option<Support> {
+        None
+    }
+
+    fn cube(center: Vec3, half: f32) -> Aabb3 {
+        Aabb3 {
+            center,
+            half_extents: Vec3::splat(half),
+        }
+    }
+
+    #[test]
+    fn snap_rounds_xz_only() {
+        assert_eq!(
+            snap_to_grid(Vec3::new(1.4, 2.3, -0.6)),
+            Vec3::new(1.0, 2.3, -1.0)
+        );
+    }
+
+    #[test]
+    fn touching_boxes_do_not_overlap() {
+        let a = cube(Vec3::ZERO, 0.5);
+        let b = cube(Vec3::new(1.0, 0.0, 0.0), 0.5);
+        let c = cube(Vec3::new(0.9, 0.0, 0.0), 0.5);
+        assert!(!a.overlaps(&b));
+        assert!(a.overlaps(&c));
+    }
+
+    #[test]
+    fn placement_rests_on_ground_when_clear() {
+        let (aabb, result) = evaluate_placement(Vec3::new(2.2, 5.0, 0.1), Vec3::splat(0.5), 20.0, flat, &[], true);
+        assert_eq!(result, Ok(()));
+        assert_eq!(aabb.center, Vec3::new(2.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn placement_stacks_on_existing_structure() {
+        let floor = Aabb3 {
+            center: Vec3::new(0.0, 0.1, 0.0),
+            half_extents: Vec3::new(1.0, 0.1, 1.0),
+        };
+        let (aabb, result) = evaluate_placement(Vec3::ZERO, Vec3::splat(0.4), 20.0, flat, &[floor], true);
+        assert_eq!(result, Ok(()));
+        assert!((aabb.center.y - 0.6).abs() < 1e-6, "got {:?}", aabb.center);
+    }
+
+    #[test]
+    fn intruding_neighbour_is_rejected_as_overlap() {
+        // Wall beside the target: not under its center, but its extents reach into
+        // the crate's footprint.
+        let wall = Aabb3 {
+            center: Vec3::new(0.45, 1.0, 0.0),
+            half_extents: Vec3::new(0.1, 1.0, 1.0),
+        };
+        let (aabb, result) = evaluate_placement(Vec3::ZERO, Vec3::splat(0.4), 20.0, flat, &[wall], true);
+        assert_eq!(aabb.center.y, 0.4, "rests on the ground, not on the wall");
+        assert_eq!(result, Err(PlacementError::Overlap));
+    }
+
+    #[test]
+    fn unaffordable_placement_is_rejected_last() {
+        let (_, result) = evaluate_placement(Vec3::ZERO, Vec3::splat(0.5), 20.0, flat, &[], false);
+        assert_eq!(result, Err(PlacementError::CannotAfford));
+    }
+
+    #[test]
+    fn slopes_steeper_than_the_structure_allows_are_rejected() {
+        let slope = |deg: f32| {
+            move |_: Vec3| {
+                Some(Support {
+                    height: 1.0,
+                    normal: Quat::from_rotation_x(deg.to_radians()) * Vec3::Y,
+                })
+            }
+        };
+        let (aabb, result) = evaluate_placement(Vec3::ZERO, Vec3::splat(0.5), 20.0, slope(10.0), &[], true);
+        assert_eq!(result, Ok(()));
+        assert!((aabb.center.y - 1.5).abs() < 1e-6, "rests on the slope, got {:?}", aabb.center);
+
+        let (_, result) = evaluate_placement(Vec3::ZERO, Vec3::splat(0.5), 20.0, slope(30.0), &[], true);
+        assert_eq!(result, Err(PlacementError::TooSteep));
+
+        // A flat structure top above the slope supports instead.
+        let floor = Aabb3 {
+            center: Vec3::new(0.0, 1.1, 0.0),
+            half_extents: Vec3::new(1.0, 0.1, 1.0),
+        };
+        let (_, result) = evaluate_placement(Vec3::ZERO, Vec3::splat(0.4), 20.0, slope(30.0), &[floor], true);
+        assert_eq!(result, Ok(()));
+    }
+}
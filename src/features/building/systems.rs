@@ -0,0 +1,426 @@
+// src/features/building/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    BuildGhost, BuildGhostMaterials, BuildKeybindings, BuildMode, PlacedStructure, StructureKind,
+};
+use super::placement::{Aabb3, BUILD_DISTANCE, Support, evaluate_placement};
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::inventory::component::Inventory;
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::player::component::Player;
+use crate::features::player::death::AlivePlayer;
+use crate::features::terrain::component::TerrainData;
+
+/// World units above the builder where the ground probe starts.
+const GROUND_PROBE_HEIGHT: f32 = 4.0;
+
+/// World units below the builder the ground probe reaches.
+const GROUND_PROBE_DEPTH: f32 = 8.0;
+
+/// Emitted when a structure has been committed to the world.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructurePlaced {
+    pub entity: Entity,
+    pub kind: StructureKind,
+    pub builder: Entity,
+}
+
+/// Replace every placed structure with saved ones (`(kind, translation)` pairs).
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct RestoreStructures {
+    pub structures: Vec<(StructureKind, Vec3)>,
+}
+
+/// Query filter: the ghost (never a player or a committed structure).
+type GhostOnly = (Without<Player>, Without<PlacedStructure>);
+
+/// Startup: create the shared ghost materials.
+pub fn setup_ghost_materials(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let ghost = |color: Color| StandardMaterial {
+        base_color: color,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    };
+
+    commands.insert_resource(BuildGhostMaterials {
+        valid: materials.add(ghost(Color::srgba(0.2, 0.9, 0.3, 0.4))),
+        invalid: materials.add(ghost(Color::srgba(0.9, 0.2, 0.2, 0.4))),
+    });
+}
+
+/// Update: toggle build mode and cycle the selected structure.
+///
+/// Leaving build mode removes the ghost.
+pub fn read_build_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<BuildKeybindings>,
+    mut mode: ResMut<BuildMode>,
+    q_ghost: Query<Entity, With<BuildGhost>>,
+) {
+    if keyboard.just_pressed(bindings.toggle) {
+        mode.active = !mode.active;
+
+        if !mode.active {
+            for ghost in &q_ghost {
+                commands.entity(ghost).despawn();
+            }
+        }
+    }
+
+    if mode.active && keyboard.just_pressed(bindings.next_structure) {
+        mode.selected = mode.selected.next();
+    }
+}
+
+/// The level under `xz`: the first collider a ray straight down from above the
+/// builder hits, or the terrain there if it's higher, with its surface normal.
+fn ground_support(
+    collision: &CollisionWorld,
+    terrain: Option<&TerrainData>,
+    xz: Vec3,
+    builder_y: f32,
+    ignore: &[Entity],
+) -> Option<Support> {
+    let origin = Vec3::new(xz.x, builder_y + GROUND_PROBE_HEIGHT, xz.z);
+    let level = collision
+        .ray_cast(origin, Vec3::NEG_Y, GROUND_PROBE_HEIGHT + GROUND_PROBE_DEPTH, ignore)
+        .map(|hit| Support {
+            height: origin.y - hit.distance,
+            normal: hit.normal,
+        });
+    let terrain = terrain.and_then(|terrain| {
+        let xz = Vec2::new(xz.x, xz.z);
+        Some(Support {
+            height: terrain.height_at(xz)?,
+            normal: terrain.normal_at(xz)?,
+        })
+    });
+    level.into_iter().chain(terrain).max_by(|a, b| a.height.total_cmp(&b.height))
+}
+
+/// Update: keep the ghost in front of the builder, snapped onto the ground (or a
+/// structure) below, and colored by validity.
+#[expect(clippy::too_many_arguments)]
+pub fn update_build_ghost(
+    mut commands: Commands,
+    mode: Res<BuildMode>,
+    ghost_materials: Res<BuildGhostMaterials>,
+    meshes: Res<MeshLibrary>,
+    collision: CollisionWorld,
+    terrain: Option<Res<TerrainData>>,
+    q_player: Query<(Entity, &Transform, &Inventory), AlivePlayer>,
+    q_structures: Query<(Entity, &PlacedStructure, &Transform)>,
+    mut q_ghost: Query<
+        (
+            Entity,
+            &mut BuildGhost,
+            &mut Transform,
+            &mut MeshMaterial3d<StandardMaterial>,
+        ),
+        GhostOnly,
+    >,
+) {
+    if !mode.active {
+        return;
+    }
+    let Ok((builder, player_tr, inventory)) = q_player.single() else {
+        return;
+    };
+
+    let def = mode.selected.def();
+    let existing: Vec<Aabb3> = q_structures
+        .iter()
+        .map(|(_, s, tr)| Aabb3 {
+            center: tr.translation,
+            half_extents: s.kind.def().half_extents,
+        })
+        .collect();
+    // Structures support through `existing`; the probe only looks for the level.
+    let ignore: Vec<Entity> = q_structures
+        .iter()
+        .map(|(entity, ..)| entity)
+        .chain([builder])
+        .collect();
+
+    let target = player_tr.translation + player_tr.forward().as_vec3() * BUILD_DISTANCE;
+    let (aabb, placement) = evaluate_placement(
+        target,
+        def.half_extents,
+        def.max_slope_deg,
+        |xz| ground_support(&collision, terrain.as_deref(), xz, player_tr.translation.y, &ignore),
+        &existing,
+        inventory.has_all(def.cost),
+    );
+    let material = if placement.is_ok() {
+        ghost_materials.valid.clone()
+    } else {
+        ghost_materials.invalid.clone()
+    };
+
+    // Reuse the ghost while the selection is unchanged; otherwise rebuild it.
+    let mut reused = false;
+    for (entity, mut ghost, mut transform, mut mat) in &mut q_ghost {
+        if ghost.kind == mode.selected {
+            ghost.placement = placement;
+            transform.translation = aabb.center;
+            mat.0 = material.clone();
+            reused = true;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if !reused {
+        commands.spawn((
+            BuildGhost {
+                kind: mode.selected,
+                placement,
+            },
+            Mesh3d(meshes.get(MeshPrimitive::Cube)),
+            MeshMaterial3d(material),
+            Transform::from_translation(aabb.center).with_scale(def.half_extents * 2.0),
+        ));
+    }
+}
+
+/// Update: commit the ghost as a real structure, paying its cost from the inventory.
+#[expect(clippy::too_many_arguments)]
+pub fn place_structure(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<BuildKeybindings>,
+    mode: Res<BuildMode>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_ghost: Query<(&BuildGhost, &Transform)>,
+    mut q_player: Query<(Entity, &mut Inventory), AlivePlayer>,
+    mut placed: MessageWriter<StructurePlaced>,
+) {
+    if !mode.active || !keyboard.just_pressed(bindings.place) {
+        return;
+    }
+    let Ok((ghost, ghost_tr)) = q_ghost.single() else {
+        return;
+    };
+    let Ok((builder, mut inventory)) = q_player.single_mut() else {
+        return;
+    };
+    if ghost.placement.is_err() {
+        return;
+    }
+
+    let def = ghost.kind.def();
+    if !inventory.remove_all(def.cost) {
+        return;
+    }
+
+    let entity = commands
+        .spawn(structure_bundle(ghost.kind, ghost_tr.translation, &meshes, &mut materials))
+        .id();
+
+    placed.write(StructurePlaced {
+        entity,
+        kind: ghost.kind,
+        builder,
+    });
+}
+
+/// Update: swap the placed structures for saved ones.
+pub fn restore_structures(
+    mut commands: Commands,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut restores: MessageReader<RestoreStructures>,
+    q_structures: Query<Entity, With<PlacedStructure>>,
+) {
+    let Some(restore) = restores.read().last() else {
+        return;
+    };
+    for entity in &q_structures {
+        commands.entity(entity).despawn();
+    }
+    for &(kind, translation) in &restore.structures {
+        commands.spawn(structure_bundle(kind, translation, &meshes, &mut materials));
+    }
+}
+
+/// A committed structure of `kind`, centered at `translation`.
+fn structure_bundle(
+    kind: StructureKind,
+    translation: Vec3,
+    meshes: &MeshLibrary,
+    materials: &mut Assets<StandardMaterial>,
+) -> impl Bundle {
+    let def = kind.def();
+    (
+        PlacedStructure { kind },
+        Mesh3d(meshes.get(MeshPrimitive::Cube)),
+        MeshMaterial3d(materials.add(def.color)),
+        Transform::from_translation(translation).with_scale(def.half_extents * 2.0),
+        Collider::cuboid(def.half_extents),
+        Name::new(kind.name()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::building::placement::PlacementError;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<MeshLibrary>();
+        world.insert_resource(Assets::<StandardMaterial>::default());
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(BuildKeybindings::default());
+        world.insert_resource(BuildMode {
+            active: true,
+            selected: StructureKind::Crate,
+        });
+        world.init_resource::<Messages<StructurePlaced>>();
+        world.init_resource::<Messages<RestoreStructures>>();
+        let _ = world.run_system_once(setup_ghost_materials);
+        world
+    }
+
+    fn spawn_builder(world: &mut World, wood: u32) -> Entity {
+        let mut inventory = Inventory::default();
+        inventory.add("wood", wood);
+        world.spawn((Player, Transform::default(), inventory)).id()
+    }
+
+    fn ghost(world: &mut World) -> (BuildGhost, Vec3) {
+        let (g, tr) = world
+            .query::<(&BuildGhost, &Transform)>()
+            .single(world)
+            .expect("exactly one ghost");
+        (*g, tr.translation)
+    }
+
+    #[test]
+    fn ghost_appears_in_front_of_builder_and_reflects_cost() {
+        let mut world = setup_world();
+        spawn_builder(&mut world, 0);
+
+        let _ = world.run_system_once(update_build_ghost);
+
+        let (g, pos) = ghost(&mut world);
+        assert_eq!(g.kind, StructureKind::Crate);
+        assert_eq!(g.placement, Err(PlacementError::CannotAfford));
+        assert_eq!(pos, Vec3::new(0.0, 0.4, -3.0));
+    }
+
+    #[test]
+    fn selection_change_rebuilds_ghost() {
+        let mut world = setup_world();
+        spawn_builder(&mut world, 10);
+
+        let _ = world.run_system_once(update_build_ghost);
+        world.resource_mut::<BuildMode>().selected = StructureKind::Floor;
+        let _ = world.run_system_once(update_build_ghost);
+
+        assert_eq!(ghost(&mut world).0.kind, StructureKind::Floor);
+    }
+
+    #[test]
+    fn placing_spends_cost_and_spawns_structure() {
+        let mut world = setup_world();
+        let builder = spawn_builder(&mut world, 3);
+
+        let _ = world.run_system_once(update_build_ghost);
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        let _ = world.run_system_once(place_structure);
+
+        assert_eq!(world.get::<Inventory>(builder).unwrap().count("wood"), 1);
+        let placed: Vec<_> = world
+            .query::<(&PlacedStructure, &Transform)>()
+            .iter(&world)
+            .map(|(s, tr)| (s.kind, tr.translation))
+            .collect();
+        assert_eq!(placed, vec![(StructureKind::Crate, Vec3::new(0.0, 0.4, -3.0))]);
+        assert_eq!(world.resource::<Messages<StructurePlaced>>().len(), 1);
+
+        // Same spot again: the ghost now rests on top of the new crate.
+        let _ = world.run_system_once(update_build_ghost);
+        assert_eq!(ghost(&mut world).1, Vec3::new(0.0, 1.2, -3.0));
+    }
+
+    #[test]
+    fn ghost_follows_the_terrain_and_rejects_steep_slopes() {
+        let mut world = setup_world();
+        spawn_builder(&mut world, 10);
+        // Rises half a unit per unit along +X (about 27 degrees) around the builder.
+        let mut terrain =
+            TerrainData::from_ron("(origin: (-8.0, -8.0), size: 17, spacing: 1.0)").unwrap();
+        for z in 0..17 {
+            for x in 0..17 {
+                let i = terrain.index(x, z);
+                terrain.heights[i] = x as f32 * 0.5 - 4.0;
+            }
+        }
+        world.insert_resource(terrain);
+
+        let _ = world.run_system_once(update_build_ghost);
+        let (g, pos) = ghost(&mut world);
+        assert_eq!(g.placement, Err(PlacementError::TooSteep), "crates need 20 degrees or less");
+        assert!((pos.y - 0.4).abs() < 1e-5, "rests on the slope, got {pos:?}");
+
+        world.resource_mut::<BuildMode>().selected = StructureKind::Floor;
+        let _ = world.run_system_once(update_build_ghost);
+        assert_eq!(ghost(&mut world).0.placement, Ok(()), "floors take up to 30 degrees");
+    }
+
+    #[test]
+    fn restoring_replaces_the_placed_structures() {
+        let mut world = setup_world();
+        let builder = spawn_builder(&mut world, 2);
+        let _ = world.run_system_once(update_build_ghost);
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        let _ = world.run_system_once(place_structure);
+
+        world.write_message(RestoreStructures {
+            structures: vec![
+                (StructureKind::Floor, Vec3::new(4.0, 0.1, 0.0)),
+                (StructureKind::Wall, Vec3::new(4.0, 1.2, 0.0)),
+            ],
+        });
+        let _ = world.run_system_once(restore_structures);
+
+        let mut placed: Vec<_> = world
+            .query::<(&PlacedStructure, &Transform)>()
+            .iter(&world)
+            .map(|(s, tr)| (s.kind, tr.translation))
+            .collect();
+        placed.sort_by(|a, b| a.1.y.total_cmp(&b.1.y));
+        assert_eq!(
+            placed,
+            vec![
+                (StructureKind::Floor, Vec3::new(4.0, 0.1, 0.0)),
+                (StructureKind::Wall, Vec3::new(4.0, 1.2, 0.0)),
+            ]
+        );
+        assert_eq!(world.get::<Inventory>(builder).unwrap().count("wood"), 0, "restoring is free");
+    }
+
+    #[test]
+    fn toggling_off_removes_ghost() {
+        let mut world = setup_world();
+        spawn_builder(&mut world, 0);
+        let _ = world.run_system_once(update_build_ghost);
+
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyB);
+        let _ = world.run_system_once(read_build_input);
+
+        assert!(!world.resource::<BuildMode>().active);
+        assert_eq!(world.query::<&BuildGhost>().iter(&world).count(), 0);
+    }
+}
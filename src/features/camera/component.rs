@@ -0,0 +1,182 @@
+// src/features/camera/component.rs
+use bevy::prelude::*;
+
+use super::rig::CameraRig;
+
+/// Third-person camera that follows a target from behind and above.
+///
+/// Placement: `pivot = target + pivot_offset`, camera sits `distance` away from the
+/// pivot along the direction given by `yaw` (around +Y) and `pitch` (negative = above),
+/// always looking at the pivot.
+///
+/// `target: None` means "follow the player"; the camera feature fills it in.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(CameraCollision, CameraZoom, CameraFov, CameraRig)]
+pub struct FollowCamera {
+    pub target: Option<Entity>,
+    pub pivot_offset: Vec3,
+    pub distance: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl Default for FollowCamera {
+    fn default() -> Self {
+        Self {
+            target: None,
+            pivot_offset: Vec3::new(0.0, 1.5, 0.0),
+            distance: 8.0,
+            yaw: 0.0,
+            pitch: -0.4,
+        }
+    }
+}
+
+impl FollowCamera {
+    /// Unit direction from the pivot towards the (unobstructed) camera position.
+    pub fn boom_direction(&self) -> Vec3 {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0) * Vec3::Z
+    }
+}
+
+/// Camera that renders into a texture (picture-in-picture, mirrors, monitors)
+/// rather than the screen; screen-space features and camera-following effects
+/// ignore it.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OffscreenCamera;
+
+/// Keeps geometry from getting between the camera and its target.
+///
+/// - Each frame a sphere of `radius` is cast from the pivot to the desired position.
+/// - If something is in the way, the boom shortens to the hit immediately (no clipping).
+/// - Once clear, it grows back towards the desired distance at `recover_rate` (1/sec).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CameraCollision {
+    pub radius: f32,
+    pub recover_rate: f32,
+    /// Never pull in closer than this to the pivot.
+    pub min_distance: f32,
+    /// Current (possibly shortened) boom length. Negative = not initialized yet.
+    pub current_distance: f32,
+}
+
+impl Default for CameraCollision {
+    fn default() -> Self {
+        Self {
+            radius: 0.3,
+            recover_rate: 4.0,
+            min_distance: 0.5,
+            current_distance: -1.0,
+        }
+    }
+}
+
+/// Tunables shared by camera features (zoom limits, smoothing, input rates).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CameraSettings {
+    /// Follow distance limits (world units).
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Distance change per scroll line.
+    pub distance_step: f32,
+    /// Vertical FOV limits (degrees) for FOV-based zoom.
+    pub min_fov_deg: f32,
+    pub max_fov_deg: f32,
+    /// FOV change per scroll line (degrees).
+    pub fov_step_deg: f32,
+    /// Scroll lines per second while a gamepad zoom button is held.
+    pub gamepad_zoom_rate: f32,
+    /// Exponential approach rate (1/sec) towards the zoom target; `0` snaps.
+    pub zoom_smoothing: f32,
+    /// Orbit rotation per pixel of mouse drag (radians).
+    pub orbit_mouse_sensitivity: f32,
+    /// Orbit rotation speed at full right-stick deflection (radians/sec).
+    pub orbit_stick_speed: f32,
+    /// Pitch limits (radians, negative = above the pivot).
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// Seconds without orbit input before the camera swings back behind the target.
+    pub recenter_delay_secs: f32,
+    /// Exponential approach rate (1/sec) of the recenter swing.
+    pub recenter_rate: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            min_distance: 2.0,
+            max_distance: 15.0,
+            distance_step: 1.0,
+            min_fov_deg: 30.0,
+            max_fov_deg: 90.0,
+            fov_step_deg: 5.0,
+            gamepad_zoom_rate: 6.0,
+            zoom_smoothing: 12.0,
+            orbit_mouse_sensitivity: 0.005,
+            orbit_stick_speed: 2.5,
+            min_pitch: -1.3,
+            max_pitch: 0.2,
+            recenter_delay_secs: 2.0,
+            recenter_rate: 3.0,
+        }
+    }
+}
+
+/// What zooming changes on a camera.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ZoomMode {
+    /// Third person: move along the boom (`FollowCamera::distance`).
+    #[default]
+    Distance,
+    /// First person: narrow / widen the perspective FOV.
+    Fov,
+}
+
+/// Vertical field of view of a camera, composed from independent sources.
+///
+/// - `base_deg`: the zoom-controlled FOV (first-person zoom writes this)
+/// - `scale`: transient multiplier (aim-down-sights writes this)
+///
+/// `apply_camera_fov` is the only system writing `Projection::Perspective::fov`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CameraFov {
+    pub base_deg: f32,
+    pub scale: f32,
+}
+
+impl Default for CameraFov {
+    fn default() -> Self {
+        Self {
+            // Same as Bevy's default perspective projection (PI / 4).
+            base_deg: 45.0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl CameraFov {
+    pub fn effective_deg(&self) -> f32 {
+        self.base_deg * self.scale
+    }
+}
+
+/// Zoom state on a camera: input moves `target`, smoothing moves the camera towards it.
+///
+/// `target` is a distance (world units) or a vertical FOV (degrees) depending on `mode`;
+/// `None` until the first input, so the camera's authored value is the starting point.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CameraZoom {
+    pub mode: ZoomMode,
+    pub target: Option<f32>,
+}
+
+/// Orbit state on a `FollowCamera` (used while the rig is in `CameraMode::Orbit`).
+///
+/// - Dragging (mouse button held) or the right stick rotates yaw/pitch around the target,
+///   independently of which way the target is facing.
+/// - After `CameraSettings::recenter_delay_secs` without input, yaw swings back behind it.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct OrbitCamera {
+    /// Seconds since the last orbit input.
+    pub idle_secs: f32,
+}
@@ -0,0 +1,317 @@
+// src/features/camera/input.rs
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+
+use super::component::{
+    CameraFov, CameraSettings, CameraZoom, FollowCamera, OrbitCamera, ZoomMode,
+};
+use super::rig::{CameraMode, CameraRig, SetCameraMode};
+use crate::features::aim::component::AimState;
+
+/// Pixel-based scroll (touchpads) reported per "line" of zoom.
+const PIXELS_PER_SCROLL_LINE: f32 = 100.0;
+
+/// Gamepad buttons held to zoom in / out.
+///
+/// Scrolling while `scroll_suppressed_by` is held belongs to another action
+/// (weapon switching), so zoom ignores it.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ZoomBindings {
+    pub gamepad_in: GamepadButton,
+    pub gamepad_out: GamepadButton,
+    pub scroll_suppressed_by: KeyCode,
+}
+
+impl Default for ZoomBindings {
+    fn default() -> Self {
+        Self {
+            gamepad_in: GamepadButton::DPadUp,
+            gamepad_out: GamepadButton::DPadDown,
+            scroll_suppressed_by: KeyCode::AltLeft,
+        }
+    }
+}
+
+/// Update: mouse wheel / gamepad -> `CameraZoom.target`, clamped to `CameraSettings`.
+///
+/// Scrolling up (or holding "zoom in") moves closer / narrows the FOV.
+/// Only the target changes here; `apply_camera_zoom` smooths the camera towards it.
+pub fn read_zoom_input(
+    time: Res<Time>,
+    scroll: Res<AccumulatedMouseScroll>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<CameraSettings>,
+    bindings: Res<ZoomBindings>,
+    q_gamepads: Query<&Gamepad>,
+    mut q_cameras: Query<(&mut CameraZoom, Option<&FollowCamera>, Option<&CameraFov>)>,
+) {
+    let mut lines = match scroll.unit {
+        _ if keyboard.pressed(bindings.scroll_suppressed_by) => 0.0,
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / PIXELS_PER_SCROLL_LINE,
+    };
+    for gamepad in &q_gamepads {
+        let held = gamepad.pressed(bindings.gamepad_in) as i32 as f32
+            - gamepad.pressed(bindings.gamepad_out) as i32 as f32;
+        lines += held * settings.gamepad_zoom_rate * time.delta_secs();
+    }
+    if lines == 0.0 {
+        return;
+    }
+
+    for (mut zoom, follow, fov) in &mut q_cameras {
+        let current = zoom.target.or_else(|| match zoom.mode {
+            ZoomMode::Distance => follow.map(|f| f.distance),
+            ZoomMode::Fov => fov.map(|f| f.base_deg),
+        });
+        let Some(current) = current else {
+            continue;
+        };
+
+        zoom.target = Some(match zoom.mode {
+            ZoomMode::Distance => (current - lines * settings.distance_step)
+                .clamp(settings.min_distance, settings.max_distance),
+            ZoomMode::Fov => (current - lines * settings.fov_step_deg)
+                .clamp(settings.min_fov_deg, settings.max_fov_deg),
+        });
+    }
+}
+
+/// Mouse button held to orbit (right button is aim).
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct OrbitBindings {
+    pub mouse_button: MouseButton,
+}
+
+impl Default for OrbitBindings {
+    fn default() -> Self {
+        Self {
+            mouse_button: MouseButton::Middle,
+        }
+    }
+}
+
+/// Update: mouse drag / right stick -> orbit yaw & pitch on `OrbitCamera`s in orbit mode.
+///
+/// - Pitch is clamped to `CameraSettings::{min_pitch, max_pitch}`.
+/// - Tracks `OrbitCamera::idle_secs` so recentering knows when the player let go.
+/// - Sensitivity is scaled by the target's `AimState` (finer control while aiming).
+#[expect(clippy::too_many_arguments)]
+pub fn read_orbit_input(
+    time: Res<Time>,
+    motion: Res<AccumulatedMouseMotion>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    settings: Res<CameraSettings>,
+    bindings: Res<OrbitBindings>,
+    q_gamepads: Query<&Gamepad>,
+    q_aim: Query<&AimState>,
+    mut q_cameras: Query<(&mut FollowCamera, &mut OrbitCamera, &CameraRig)>,
+) {
+    let dt = time.delta_secs();
+
+    let mut delta = Vec2::ZERO;
+    if mouse.pressed(bindings.mouse_button) {
+        delta += motion.delta * settings.orbit_mouse_sensitivity;
+    }
+    for gamepad in &q_gamepads {
+        // Stick up (+y) looks up, i.e. the same as dragging the mouse up (-y).
+        let stick = gamepad.right_stick() * Vec2::new(1.0, -1.0);
+        delta += stick * settings.orbit_stick_speed * dt;
+    }
+
+    for (mut follow, mut orbit, rig) in &mut q_cameras {
+        if rig.mode != CameraMode::Orbit {
+            continue;
+        }
+        if delta == Vec2::ZERO {
+            orbit.idle_secs += dt;
+            continue;
+        }
+
+        let scale = follow
+            .target
+            .and_then(|t| q_aim.get(t).ok())
+            .map_or(1.0, AimState::sensitivity_scale_now);
+        let delta = delta * scale;
+
+        orbit.idle_secs = 0.0;
+        follow.yaw -= delta.x;
+        follow.pitch = (follow.pitch - delta.y).clamp(settings.min_pitch, settings.max_pitch);
+    }
+}
+
+/// Keys that switch camera modes (toggling again returns to the previous mode).
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct CameraModeBindings {
+    pub toggle_first_person: KeyCode,
+    pub toggle_debug: KeyCode,
+}
+
+impl Default for CameraModeBindings {
+    fn default() -> Self {
+        Self {
+            toggle_first_person: KeyCode::KeyV,
+            toggle_debug: KeyCode::F10,
+        }
+    }
+}
+
+/// Update: mode keys -> `SetCameraMode` requests for every rigged camera.
+pub fn read_camera_mode_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<CameraModeBindings>,
+    q_cameras: Query<(Entity, &CameraRig)>,
+    mut requests: MessageWriter<SetCameraMode>,
+) {
+    let toggle = if keyboard.just_pressed(bindings.toggle_debug) {
+        CameraMode::Debug
+    } else if keyboard.just_pressed(bindings.toggle_first_person) {
+        CameraMode::FirstPerson
+    } else {
+        return;
+    };
+
+    for (camera, rig) in &q_cameras {
+        requests.write(SetCameraMode {
+            camera,
+            mode: rig.toggled(toggle),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world(scroll_lines: f32) -> World {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(CameraSettings::default());
+        world.insert_resource(ZoomBindings::default());
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(AccumulatedMouseScroll {
+            unit: MouseScrollUnit::Line,
+            delta: Vec2::new(0.0, scroll_lines),
+        });
+        world
+    }
+
+    #[test]
+    fn scroll_up_moves_distance_target_closer_and_clamps() {
+        let mut world = setup_world(2.0);
+        let cam = world.spawn(FollowCamera::default()).id();
+
+        let _ = world.run_system_once(read_zoom_input);
+        assert_eq!(world.get::<CameraZoom>(cam).unwrap().target, Some(6.0));
+
+        world.resource_mut::<AccumulatedMouseScroll>().delta.y = -100.0;
+        let _ = world.run_system_once(read_zoom_input);
+        let max = CameraSettings::default().max_distance;
+        assert_eq!(world.get::<CameraZoom>(cam).unwrap().target, Some(max));
+    }
+
+    #[test]
+    fn fov_mode_zooms_the_base_fov() {
+        let mut world = setup_world(1.0);
+        let cam = world
+            .spawn((
+                CameraZoom {
+                    mode: ZoomMode::Fov,
+                    target: None,
+                },
+                CameraFov {
+                    base_deg: 60.0,
+                    scale: 1.0,
+                },
+            ))
+            .id();
+
+        let _ = world.run_system_once(read_zoom_input);
+
+        let target = world.get::<CameraZoom>(cam).unwrap().target.unwrap();
+        assert!((target - 55.0).abs() < 1e-3, "got {target}");
+    }
+
+    fn setup_orbit_world(drag: Vec2, held: bool) -> World {
+        let mut world = setup_world(0.0);
+        world.insert_resource(OrbitBindings::default());
+        world.insert_resource(AccumulatedMouseMotion { delta: drag });
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        if held {
+            mouse.press(OrbitBindings::default().mouse_button);
+        }
+        world.insert_resource(mouse);
+        world
+    }
+
+    #[test]
+    fn drag_orbits_and_clamps_pitch() {
+        let mut world = setup_orbit_world(Vec2::new(100.0, 10_000.0), true);
+        let cam = world
+            .spawn((
+                FollowCamera::default(),
+                OrbitCamera { idle_secs: 5.0 },
+                CameraRig::new(CameraMode::Orbit),
+            ))
+            .id();
+
+        let _ = world.run_system_once(read_orbit_input);
+
+        let follow = world.get::<FollowCamera>(cam).unwrap();
+        assert!((follow.yaw + 0.5).abs() < 1e-5, "yaw {}", follow.yaw);
+        assert_eq!(follow.pitch, CameraSettings::default().min_pitch);
+        assert_eq!(world.get::<OrbitCamera>(cam).unwrap().idle_secs, 0.0);
+    }
+
+    #[test]
+    fn mouse_motion_without_button_does_not_orbit() {
+        let mut world = setup_orbit_world(Vec2::new(100.0, 0.0), false);
+        let cam = world
+            .spawn((
+                FollowCamera::default(),
+                OrbitCamera::default(),
+                CameraRig::new(CameraMode::Orbit),
+            ))
+            .id();
+
+        let _ = world.run_system_once(read_orbit_input);
+
+        assert_eq!(world.get::<FollowCamera>(cam).unwrap().yaw, 0.0);
+    }
+
+    #[test]
+    fn drag_outside_orbit_mode_does_not_orbit() {
+        let mut world = setup_orbit_world(Vec2::new(100.0, 0.0), true);
+        let cam = world
+            .spawn((FollowCamera::default(), OrbitCamera::default()))
+            .id();
+
+        let _ = world.run_system_once(read_orbit_input);
+
+        assert_eq!(world.get::<FollowCamera>(cam).unwrap().yaw, 0.0);
+    }
+
+    #[test]
+    fn scroll_with_weapon_modifier_does_not_zoom() {
+        let mut world = setup_world(2.0);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(ZoomBindings::default().scroll_suppressed_by);
+        let cam = world.spawn(FollowCamera::default()).id();
+
+        let _ = world.run_system_once(read_zoom_input);
+
+        assert_eq!(world.get::<CameraZoom>(cam).unwrap().target, None);
+    }
+
+    #[test]
+    fn no_input_leaves_target_untouched() {
+        let mut world = setup_world(0.0);
+        let cam = world.spawn(FollowCamera::default()).id();
+
+        let _ = world.run_system_once(read_zoom_input);
+
+        assert_eq!(world.get::<CameraZoom>(cam).unwrap().target, None);
+    }
+}
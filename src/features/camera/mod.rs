@@ -0,0 +1,107 @@
+// src/features/camera/mod.rs
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::explosion::systems::Explosion;
+use crate::features::fall_damage::systems::HardLanding;
+
+pub mod component;
+pub mod input;
+pub mod pip;
+pub mod rig;
+pub mod screens;
+pub mod shake;
+pub mod systems;
+
+/// Third-person camera feature.
+///
+/// Scope:
+/// - `CameraRig`: per-camera mode (Follow / Orbit / FirstPerson / Cinematic / Debug),
+///   the single writer of the camera `Transform`, blending smoothly on mode switches
+/// - `FollowCamera`: boom behind a target (the player by default), looking at a pivot
+/// - obstruction handling: sphere-cast from the pivot, pull in on hit, smooth recovery
+/// - zoom: mouse wheel / gamepad, boom length (third person) or FOV (first person),
+///   limits and smoothing in `CameraSettings`
+/// - orbit (`OrbitCamera` in orbit mode): mouse drag / right stick, pitch clamps,
+///   auto-recenter behind the target after idle time
+/// - picture-in-picture: `PipCamera`s render into a shared texture shown in a corner
+///   panel; `SetPipFeed` picks the camera (or hides the panel), `O` cycles for debugging
+/// - monitors and mirrors (`ScreenSurface`) show a screen camera's texture; screen
+///   cameras only render while their screen is near and visible, nearest first up
+///   to `ScreenSettings::max_active`
+/// - shake (`CameraShake` on every rig camera): trauma from the player's hard landings
+///   and nearby explosions, scaled by `EffectsIntensity::shake`
+///
+/// Design constraints:
+/// - Runs in `AppSet::CameraUpdate` (after input), reading the target's `Transform`
+///   written in FixedUpdate.
+/// - Mode systems only produce a desired pose; `rig::apply_camera_rig` runs last.
+/// - The scene decides *which* camera follows (by adding `FollowCamera`); this feature
+///   decides *how*.
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::CameraSettings>();
+        app.init_resource::<input::ZoomBindings>();
+        app.init_resource::<input::OrbitBindings>();
+        app.init_resource::<input::CameraModeBindings>();
+        app.init_resource::<pip::PipSettings>();
+        app.init_resource::<screens::ScreenSettings>();
+        app.init_resource::<screens::ScreenSpawns>();
+        app.init_resource::<shake::ShakeSettings>();
+        app.add_message::<rig::SetCameraMode>();
+        app.add_message::<pip::SetPipFeed>();
+        app.add_message::<HardLanding>();
+        app.add_message::<Explosion>();
+        // Normally provided by `InputPlugin`; keeps the feature usable headless.
+        app.init_resource::<AccumulatedMouseScroll>();
+        app.init_resource::<AccumulatedMouseMotion>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+
+        app.add_systems(
+            Update,
+            (
+                input::read_zoom_input,
+                input::read_orbit_input,
+                input::read_camera_mode_input,
+                pip::read_pip_debug_input,
+            )
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(Startup, (pip::setup_pip, screens::spawn_screens));
+        app.add_systems(
+            Update,
+            (pip::attach_pip_cameras, pip::apply_pip_feed)
+                .chain()
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            Update,
+            (screens::update_screen_cameras, screens::update_mirror_cameras)
+                .chain()
+                .after(rig::apply_camera_rig)
+                .in_set(AppSet::CameraUpdate),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::assign_follow_target,
+                rig::apply_camera_mode_requests,
+                systems::apply_camera_zoom,
+                systems::recenter_orbit_camera,
+                systems::update_follow_camera,
+                rig::update_first_person_camera,
+                rig::update_cinematic_camera,
+                shake::add_camera_trauma,
+                shake::update_camera_shake,
+                rig::apply_camera_rig,
+                systems::apply_camera_fov,
+            )
+                .chain()
+                .in_set(AppSet::CameraUpdate),
+        );
+    }
+}
@@ -0,0 +1,228 @@
+// src/features/camera/pip.rs
+use bevy::camera::RenderTarget;
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+
+use super::component::OffscreenCamera;
+
+/// A secondary camera that can feed the picture-in-picture panel (security
+/// camera, rear view, boss intro). Inactive until selected with `SetPipFeed`.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+#[require(OffscreenCamera)]
+pub struct PipCamera {
+    /// Caption shown under the feed.
+    pub label: String,
+}
+
+/// Show `camera`'s view in the PiP panel (`None` hides the panel).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetPipFeed {
+    pub camera: Option<Entity>,
+}
+
+/// Size / placement of the PiP panel and its texture.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct PipSettings {
+    /// Texture resolution (physical pixels).
+    pub resolution: UVec2,
+    /// Panel width on screen (logical pixels); height follows the aspect ratio.
+    pub panel_width: f32,
+    /// Debug key cycling through the PiP cameras and off.
+    pub cycle_key: KeyCode,
+}
+
+impl Default for PipSettings {
+    fn default() -> Self {
+        Self {
+            resolution: UVec2::new(480, 270),
+            panel_width: 320.0,
+            cycle_key: KeyCode::KeyO,
+        }
+    }
+}
+
+/// The shared PiP texture and which camera renders into it.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct PipFeed {
+    pub image: Handle<Image>,
+    pub active: Option<Entity>,
+}
+
+/// UI panel showing the PiP texture.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct PipPanel;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct PipCaption;
+
+/// Startup: the render texture (none headless) and the hidden panel.
+pub fn setup_pip(
+    mut commands: Commands,
+    settings: Res<PipSettings>,
+    images: Option<ResMut<Assets<Image>>>,
+) {
+    let image = images.map_or_else(Handle::default, |mut images| {
+        images.add(Image::new_target_texture(
+            settings.resolution.x,
+            settings.resolution.y,
+            TextureFormat::Bgra8UnormSrgb,
+            None,
+        ))
+    });
+    let aspect = settings.resolution.y as f32 / settings.resolution.x.max(1) as f32;
+
+    commands
+        .spawn((
+            PipPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(24.0),
+                bottom: Val::Px(24.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(4.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            Visibility::Hidden,
+            Name::new("PipPanel"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                ImageNode::new(image.clone()),
+                Node {
+                    width: Val::Px(settings.panel_width),
+                    height: Val::Px(settings.panel_width * aspect),
+                    ..default()
+                },
+            ));
+            panel.spawn((PipCaption, Text::new(""), TextFont::from_font_size(14.0)));
+        });
+    commands.insert_resource(PipFeed {
+        image,
+        active: None,
+    });
+}
+
+/// Update: new PiP cameras render into the shared texture, switched off.
+pub fn attach_pip_cameras(
+    mut commands: Commands,
+    feed: Res<PipFeed>,
+    mut q_new: Query<(Entity, &mut Camera), Added<PipCamera>>,
+) {
+    for (entity, mut camera) in &mut q_new {
+        camera.is_active = false;
+        // Render before the main camera so the panel shows this frame's image.
+        camera.order = -1;
+        commands
+            .entity(entity)
+            .insert(RenderTarget::Image(feed.image.clone().into()));
+    }
+}
+
+/// Update: debug key cycles through the PiP cameras, then off.
+pub fn read_pip_debug_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<PipSettings>,
+    feed: Res<PipFeed>,
+    q_cameras: Query<Entity, With<PipCamera>>,
+    mut requests: MessageWriter<SetPipFeed>,
+) {
+    if !keys.just_pressed(settings.cycle_key) {
+        return;
+    }
+    let mut cameras: Vec<Entity> = q_cameras.iter().collect();
+    cameras.sort();
+    let next = match feed.active.and_then(|a| cameras.iter().position(|&c| c == a)) {
+        Some(i) => cameras.get(i + 1).copied(),
+        None => cameras.first().copied(),
+    };
+    requests.write(SetPipFeed { camera: next });
+}
+
+/// Update: switch the feed; only the selected camera renders, the panel shows
+/// while one does. A despawned feed camera hides the panel.
+pub fn apply_pip_feed(
+    mut feed: ResMut<PipFeed>,
+    mut requests: MessageReader<SetPipFeed>,
+    mut q_cameras: Query<(Entity, &PipCamera, &mut Camera)>,
+    mut q_panel: Query<&mut Visibility, With<PipPanel>>,
+    mut q_caption: Query<&mut Text, With<PipCaption>>,
+) {
+    let mut active = feed.active;
+    for request in requests.read() {
+        active = request.camera;
+    }
+    active = active.filter(|&e| q_cameras.contains(e));
+    if active == feed.active && !feed.is_added() {
+        return;
+    }
+    feed.active = active;
+
+    for (entity, pip, mut camera) in &mut q_cameras {
+        let on = Some(entity) == active;
+        if camera.is_active != on {
+            camera.is_active = on;
+        }
+        if on {
+            for mut caption in &mut q_caption {
+                caption.0.clone_from(&pip.label);
+            }
+        }
+    }
+    for mut vis in &mut q_panel {
+        *vis = if active.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<PipSettings>();
+        world.init_resource::<Messages<SetPipFeed>>();
+        let _ = world.run_system_once(setup_pip);
+        world
+    }
+
+    #[test]
+    fn feed_activates_one_camera_and_shows_the_panel() {
+        let mut world = setup_world();
+        let a = world
+            .spawn((PipCamera { label: "Gate".into() }, Camera::default()))
+            .id();
+        let b = world
+            .spawn((PipCamera { label: "Pond".into() }, Camera::default()))
+            .id();
+        let _ = world.run_system_once(attach_pip_cameras);
+        assert!(!world.get::<Camera>(a).unwrap().is_active);
+
+        world.write_message(SetPipFeed { camera: Some(b) });
+        let _ = world.run_system_once(apply_pip_feed);
+
+        assert!(!world.get::<Camera>(a).unwrap().is_active);
+        assert!(world.get::<Camera>(b).unwrap().is_active);
+        let vis = *world
+            .query_filtered::<&Visibility, With<PipPanel>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(vis, Visibility::Inherited);
+        let caption = world
+            .query_filtered::<&Text, With<PipCaption>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(caption.0, "Pond");
+
+        world.despawn(b);
+        let _ = world.run_system_once(apply_pip_feed);
+        assert_eq!(world.resource::<PipFeed>().active, None);
+    }
+}
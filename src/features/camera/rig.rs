@@ -0,0 +1,372 @@
+// src/features/camera/rig.rs
+use bevy::prelude::*;
+
+use super::component::{CameraZoom, FollowCamera, ZoomMode};
+use super::shake::CameraShake;
+
+/// Which behaviour currently drives a camera.
+///
+/// - `Follow`: boom behind the target, always swinging back behind it
+/// - `Orbit`: boom the player rotates freely (recenters after idle time)
+/// - `FirstPerson`: at the target's eye, facing where it faces; zoom changes FOV
+/// - `Cinematic`: authored shot (`CinematicShot`)
+/// - `Debug`: detached; nothing in this feature moves it (debug tools write `desired`)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CameraMode {
+    #[default]
+    Follow,
+    Orbit,
+    FirstPerson,
+    Cinematic,
+    Debug,
+}
+
+impl CameraMode {
+    /// Modes that place the camera on the `FollowCamera` boom.
+    pub fn uses_boom(self) -> bool {
+        matches!(self, Self::Follow | Self::Orbit)
+    }
+}
+
+/// A camera placement (what each mode produces and what the rig blends).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPose {
+    pub translation: Vec3,
+    pub rotation: Quat,
+}
+
+impl CameraPose {
+    pub fn looking_at(eye: Vec3, target: Vec3) -> Self {
+        let tr = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+        Self {
+            translation: tr.translation,
+            rotation: tr.rotation,
+        }
+    }
+
+    pub fn from_transform(transform: &Transform) -> Self {
+        Self {
+            translation: transform.translation,
+            rotation: transform.rotation,
+        }
+    }
+
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+        }
+    }
+}
+
+/// In-progress transition between two modes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RigBlend {
+    pub from: CameraPose,
+    pub elapsed: f32,
+}
+
+/// Single owner of a camera's `Transform`.
+///
+/// Mode systems only write `desired` (and only while their mode is active);
+/// `apply_camera_rig` is the one system that writes the `Transform`, blending from
+/// the pose at the moment of a mode switch over `blend_secs`, plus any shake.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(CameraShake)]
+pub struct CameraRig {
+    pub mode: CameraMode,
+    /// Mode to return to when a toggled mode is toggled off.
+    pub previous_mode: CameraMode,
+    pub desired: Option<CameraPose>,
+    pub blend: Option<RigBlend>,
+    pub blend_secs: f32,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self::new(CameraMode::default())
+    }
+}
+
+impl CameraRig {
+    pub const DEFAULT_BLEND_SECS: f32 = 0.4;
+
+    pub fn new(mode: CameraMode) -> Self {
+        Self {
+            mode,
+            previous_mode: mode,
+            desired: None,
+            blend: None,
+            blend_secs: Self::DEFAULT_BLEND_SECS,
+        }
+    }
+
+    /// Switch modes, starting a blend from `current` (the camera's present pose).
+    pub fn set_mode(&mut self, mode: CameraMode, current: CameraPose) {
+        if mode == self.mode {
+            return;
+        }
+        self.previous_mode = self.mode;
+        self.mode = mode;
+        self.blend = Some(RigBlend {
+            from: current,
+            elapsed: 0.0,
+        });
+    }
+
+    /// `mode` if not already in it, otherwise back to the previous mode.
+    pub fn toggled(&self, mode: CameraMode) -> CameraMode {
+        if self.mode == mode {
+            self.previous_mode
+        } else {
+            mode
+        }
+    }
+}
+
+/// Authored camera shot used by `CameraMode::Cinematic`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CinematicShot {
+    pub eye: Vec3,
+    pub look_at: Vec3,
+}
+
+/// Request a mode change on a camera (input, cutscenes, lock-on, ...).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetCameraMode {
+    pub camera: Entity,
+    pub mode: CameraMode,
+}
+
+/// Update: apply mode requests.
+///
+/// - Starts a blend from the camera's current `Transform`.
+/// - Entering first person switches zoom to FOV; leaving it switches back to distance.
+/// - Entering `Debug` freezes `desired` at the current pose.
+pub fn apply_camera_mode_requests(
+    mut requests: MessageReader<SetCameraMode>,
+    mut q_cameras: Query<(&mut CameraRig, &Transform, Option<&mut CameraZoom>)>,
+) {
+    for req in requests.read() {
+        let Ok((mut rig, transform, zoom)) = q_cameras.get_mut(req.camera) else {
+            continue;
+        };
+        if rig.mode == req.mode {
+            continue;
+        }
+
+        let current = CameraPose::from_transform(transform);
+        rig.set_mode(req.mode, current);
+        if req.mode == CameraMode::Debug {
+            rig.desired = Some(current);
+        }
+
+        if let Some(mut zoom) = zoom {
+            let mode = if req.mode == CameraMode::FirstPerson {
+                ZoomMode::Fov
+            } else {
+                ZoomMode::Distance
+            };
+            if zoom.mode != mode {
+                zoom.mode = mode;
+                zoom.target = None;
+            }
+        }
+    }
+}
+
+/// Update: first-person pose at the target's eye (`FollowCamera::pivot_offset`),
+/// facing the target's forward, tilted by the camera pitch.
+pub fn update_first_person_camera(
+    q_targets: Query<&Transform, Without<CameraRig>>,
+    mut q_cameras: Query<(&FollowCamera, &mut CameraRig)>,
+) {
+    for (follow, mut rig) in &mut q_cameras {
+        if rig.mode != CameraMode::FirstPerson {
+            continue;
+        }
+        let Some(target) = follow.target.and_then(|e| q_targets.get(e).ok()) else {
+            continue;
+        };
+
+        let (yaw, _, _) = target.rotation.to_euler(EulerRot::YXZ);
+        // Boom pitch is "camera above = negative"; looking down is the same sign.
+        rig.desired = Some(CameraPose {
+            translation: target.translation + follow.pivot_offset,
+            rotation: Quat::from_euler(EulerRot::YXZ, yaw, follow.pitch, 0.0),
+        });
+    }
+}
+
+/// Update: cinematic pose from the camera's `CinematicShot`.
+pub fn update_cinematic_camera(mut q_cameras: Query<(&CinematicShot, &mut CameraRig)>) {
+    for (shot, mut rig) in &mut q_cameras {
+        if rig.mode == CameraMode::Cinematic {
+            rig.desired = Some(CameraPose::looking_at(shot.eye, shot.look_at));
+        }
+    }
+}
+
+/// Update (last camera system): write `Transform` from the rig, blending after a switch.
+///
+/// Blend weight is smoothstepped so transitions ease in and out; `CameraShake` is
+/// added on top in camera space.
+pub fn apply_camera_rig(
+    time: Res<Time>,
+    mut q_cameras: Query<(&mut CameraRig, &mut Transform, Option<&CameraShake>)>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut rig, mut transform, shake) in &mut q_cameras {
+        let Some(desired) = rig.desired else {
+            continue;
+        };
+
+        let pose = match rig.blend {
+            Some(blend) if rig.blend_secs > 0.0 && blend.elapsed + dt < rig.blend_secs => {
+                let elapsed = blend.elapsed + dt;
+                let x = elapsed / rig.blend_secs;
+                rig.blend = Some(RigBlend { elapsed, ..blend });
+                blend.from.lerp(&desired, x * x * (3.0 - 2.0 * x))
+            }
+            _ => {
+                rig.blend = None;
+                desired
+            }
+        };
+
+        let shake = shake.copied().unwrap_or_default();
+        transform.translation = pose.translation + pose.rotation * shake.offset;
+        transform.rotation = pose.rotation * Quat::from_rotation_z(shake.roll);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn world_with_time(dt: f32) -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(dt));
+        world.insert_resource(time);
+        world.init_resource::<Messages<SetCameraMode>>();
+        world
+    }
+
+    #[test]
+    fn mode_switch_blends_from_current_pose() {
+        let mut world = world_with_time(0.1);
+        let cam = world
+            .spawn((
+                CameraRig::new(CameraMode::Follow),
+                Transform::from_xyz(0.0, 0.0, 10.0),
+                CinematicShot {
+                    eye: Vec3::ZERO,
+                    look_at: Vec3::NEG_Z,
+                },
+            ))
+            .id();
+
+        world.write_message(SetCameraMode {
+            camera: cam,
+            mode: CameraMode::Cinematic,
+        });
+        let _ = world.run_system_once(apply_camera_mode_requests);
+        let _ = world.run_system_once(update_cinematic_camera);
+        let _ = world.run_system_once(apply_camera_rig);
+
+        // Part way there after one 0.1s step of a 0.4s blend.
+        let z = world.get::<Transform>(cam).unwrap().translation.z;
+        assert!(z > 0.0 && z < 10.0, "mid-blend: {z}");
+
+        for _ in 0..5 {
+            let _ = world.run_system_once(apply_camera_rig);
+        }
+        let rig = world.get::<CameraRig>(cam).unwrap();
+        assert!(rig.blend.is_none());
+        assert_eq!(world.get::<Transform>(cam).unwrap().translation, Vec3::ZERO);
+    }
+
+    #[test]
+    fn first_person_switches_zoom_to_fov_and_back() {
+        let mut world = world_with_time(0.1);
+        let cam = world
+            .spawn((
+                CameraRig::new(CameraMode::Follow),
+                Transform::default(),
+                CameraZoom {
+                    mode: ZoomMode::Distance,
+                    target: Some(4.0),
+                },
+            ))
+            .id();
+
+        world.write_message(SetCameraMode {
+            camera: cam,
+            mode: CameraMode::FirstPerson,
+        });
+        let _ = world.run_system_once(apply_camera_mode_requests);
+        let zoom = *world.get::<CameraZoom>(cam).unwrap();
+        assert_eq!((zoom.mode, zoom.target), (ZoomMode::Fov, None));
+
+        let back = world.get::<CameraRig>(cam).unwrap().toggled(CameraMode::FirstPerson);
+        assert_eq!(back, CameraMode::Follow);
+
+        world.resource_mut::<Messages<SetCameraMode>>().clear();
+        world.write_message(SetCameraMode {
+            camera: cam,
+            mode: back,
+        });
+        let _ = world.run_system_once(apply_camera_mode_requests);
+        assert_eq!(world.get::<CameraZoom>(cam).unwrap().mode, ZoomMode::Distance);
+    }
+
+    #[test]
+    fn first_person_sits_at_eye_facing_target_forward() {
+        let mut world = world_with_time(0.1);
+        let target = world
+            .spawn(Transform::from_xyz(1.0, 0.0, 0.0).with_rotation(Quat::from_rotation_y(
+                std::f32::consts::FRAC_PI_2,
+            )))
+            .id();
+        let cam = world
+            .spawn((
+                FollowCamera {
+                    target: Some(target),
+                    pitch: 0.0,
+                    ..default()
+                },
+                CameraRig::new(CameraMode::FirstPerson),
+            ))
+            .id();
+
+        let _ = world.run_system_once(update_first_person_camera);
+
+        let pose = world.get::<CameraRig>(cam).unwrap().desired.unwrap();
+        assert!((pose.translation - Vec3::new(1.0, 1.5, 0.0)).length() < 1e-5);
+        // Target faces -X after +90° yaw; so does the camera.
+        assert!((pose.rotation * Vec3::NEG_Z - Vec3::NEG_X).length() < 1e-5);
+    }
+
+    #[test]
+    fn debug_mode_freezes_current_pose() {
+        let mut world = world_with_time(0.1);
+        let cam = world
+            .spawn((CameraRig::default(), Transform::from_xyz(3.0, 2.0, 1.0)))
+            .id();
+
+        world.write_message(SetCameraMode {
+            camera: cam,
+            mode: CameraMode::Debug,
+        });
+        let _ = world.run_system_once(apply_camera_mode_requests);
+
+        let rig = world.get::<CameraRig>(cam).unwrap();
+        assert_eq!(rig.desired.unwrap().translation, Vec3::new(3.0, 2.0, 1.0));
+        assert_eq!(rig.previous_mode, CameraMode::Follow);
+    }
+}
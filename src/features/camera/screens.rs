@@ -0,0 +1,279 @@
+// src/features/camera/screens.rs
+use bevy::camera::RenderTarget;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+
+use super::component::OffscreenCamera;
+
+/// A prop whose material shows what `camera` renders (monitor, mirror).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ScreenSurface {
+    pub camera: Entity,
+    /// Beyond this distance from the viewer the feed stops rendering.
+    pub max_distance: f32,
+}
+
+/// Camera feeding one or more `ScreenSurface`s; switched on only while one of
+/// them is near and on screen.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[require(OffscreenCamera)]
+pub struct ScreenCamera;
+
+/// The screen camera of a mirror: each frame it is placed at the main camera's
+/// reflection across the mirror plane (the mirror's local +Z is the plane normal).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MirrorCamera {
+    pub mirror: Entity,
+}
+
+/// Render budget for screen cameras.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ScreenSettings {
+    /// At most this many screen cameras render per frame (nearest screens win).
+    pub max_active: usize,
+    pub resolution: UVec2,
+}
+
+impl Default for ScreenSettings {
+    fn default() -> Self {
+        Self {
+            max_active: 2,
+            resolution: UVec2::new(512, 512),
+        }
+    }
+}
+
+/// What kind of screen to place.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScreenKind {
+    /// Shows a fixed camera at `eye`, looking at `look_at`.
+    Monitor { eye: Vec3, look_at: Vec3 },
+    Mirror,
+}
+
+/// One screen placed in the scene.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenSpawn {
+    pub kind: ScreenKind,
+    /// Center of the screen; it faces along `facing`.
+    pub position: Vec3,
+    pub facing: Vec3,
+    pub size: Vec2,
+    pub max_distance: f32,
+}
+
+/// Scene data: monitors and mirrors placed at startup.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ScreenSpawns(pub Vec<ScreenSpawn>);
+
+impl Default for ScreenSpawns {
+    fn default() -> Self {
+        Self(vec![
+            ScreenSpawn {
+                kind: ScreenKind::Monitor {
+                    eye: Vec3::new(0.0, 14.0, 4.0),
+                    look_at: Vec3::new(0.0, 0.0, 3.0),
+                },
+                position: Vec3::new(3.5, 1.6, 5.5),
+                facing: Vec3::NEG_Z,
+                size: Vec2::new(1.6, 0.9),
+                max_distance: 15.0,
+            },
+            ScreenSpawn {
+                kind: ScreenKind::Mirror,
+                position: Vec3::new(-7.0, 1.5, -1.0),
+                facing: Vec3::X,
+                size: Vec2::new(2.0, 3.0),
+                max_distance: 20.0,
+            },
+        ])
+    }
+}
+
+/// `point` reflected across the plane through `origin` with unit `normal`.
+pub fn reflect_point(point: Vec3, origin: Vec3, normal: Vec3) -> Vec3 {
+    point - 2.0 * (point - origin).dot(normal) * normal
+}
+
+/// `dir` reflected across a plane with unit `normal`.
+pub fn reflect_dir(dir: Vec3, normal: Vec3) -> Vec3 {
+    dir - 2.0 * dir.dot(normal) * normal
+}
+
+/// Screen cameras allowed to render: those with a visible screen within its
+/// `max_distance`, nearest first, at most `budget`.
+///
+/// `screens` holds `(camera, distance, visible, max_distance)` per screen.
+pub fn select_screen_cameras(screens: &[(Entity, f32, bool, f32)], budget: usize) -> Vec<Entity> {
+    let mut candidates: Vec<(Entity, f32)> = screens
+        .iter()
+        .filter(|(_, distance, visible, max)| *visible && distance <= max)
+        .map(|&(camera, distance, _, _)| (camera, distance))
+        .collect();
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let mut active = Vec::new();
+    for (camera, _) in candidates {
+        if active.len() == budget {
+            break;
+        }
+        if !active.contains(&camera) {
+            active.push(camera);
+        }
+    }
+    active
+}
+
+/// Startup: a render texture, camera and screen quad per `ScreenSpawns` entry
+/// (nothing headless, where there are no images to render into).
+pub fn spawn_screens(
+    mut commands: Commands,
+    spawns: Res<ScreenSpawns>,
+    settings: Res<ScreenSettings>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut images) = images else {
+        return;
+    };
+    for spawn in &spawns.0 {
+        let image = images.add(Image::new_target_texture(
+            settings.resolution.x,
+            settings.resolution.y,
+            TextureFormat::Bgra8UnormSrgb,
+            None,
+        ));
+        let camera_transform = match spawn.kind {
+            ScreenKind::Monitor { eye, look_at } => Transform::from_translation(eye).looking_at(look_at, Vec3::Y),
+            ScreenKind::Mirror => Transform::from_translation(spawn.position),
+        };
+        let camera = commands
+            .spawn((
+                Camera3d::default(),
+                Camera {
+                    is_active: false,
+                    order: -1,
+                    ..default()
+                },
+                RenderTarget::Image(image.clone().into()),
+                ScreenCamera,
+                camera_transform,
+                Name::new("Screen Camera"),
+            ))
+            .id();
+
+        let mut mesh = Mesh::from(Rectangle::from_size(spawn.size));
+        if spawn.kind == ScreenKind::Mirror {
+            // Mirrors show the reflected view flipped left-right.
+            if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
+                for uv in uvs {
+                    uv[0] = 1.0 - uv[0];
+                }
+            }
+        }
+        let screen = commands
+            .spawn((
+                ScreenSurface {
+                    camera,
+                    max_distance: spawn.max_distance,
+                },
+                Mesh3d(meshes.add(mesh)),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color_texture: Some(image),
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_translation(spawn.position)
+                    .looking_to(-spawn.facing.normalize_or(Vec3::Z), Vec3::Y),
+                Name::new(match spawn.kind {
+                    ScreenKind::Monitor { .. } => "Monitor",
+                    ScreenKind::Mirror => "Mirror",
+                }),
+            ))
+            .id();
+        if spawn.kind == ScreenKind::Mirror {
+            commands.entity(camera).insert(MirrorCamera { mirror: screen });
+        }
+    }
+}
+
+/// Update: switch screen cameras on only for near, visible screens within the budget.
+pub fn update_screen_cameras(
+    settings: Res<ScreenSettings>,
+    q_viewer: Query<&GlobalTransform, (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_screens: Query<(&ScreenSurface, &GlobalTransform, Option<&ViewVisibility>)>,
+    mut q_cameras: Query<(Entity, &mut Camera), With<ScreenCamera>>,
+) {
+    let Some(viewer) = q_viewer.iter().next().map(GlobalTransform::translation) else {
+        return;
+    };
+    let screens: Vec<_> = q_screens
+        .iter()
+        .map(|(screen, transform, visibility)| {
+            (
+                screen.camera,
+                transform.translation().distance(viewer),
+                visibility.is_none_or(|v| v.get()),
+                screen.max_distance,
+            )
+        })
+        .collect();
+    let active = select_screen_cameras(&screens, settings.max_active);
+    for (entity, mut camera) in &mut q_cameras {
+        let on = active.contains(&entity);
+        if camera.is_active != on {
+            camera.is_active = on;
+        }
+    }
+}
+
+/// Update: place mirror cameras at the viewer's reflection.
+pub fn update_mirror_cameras(
+    q_viewer: Query<&GlobalTransform, (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_mirrors: Query<&GlobalTransform, Without<MirrorCamera>>,
+    mut q_cameras: Query<(&MirrorCamera, &Camera, &mut Transform)>,
+) {
+    let Some(viewer) = q_viewer.iter().next() else {
+        return;
+    };
+    for (mirror, camera, mut transform) in &mut q_cameras {
+        if !camera.is_active {
+            continue;
+        }
+        let Ok(plane) = q_mirrors.get(mirror.mirror) else {
+            continue;
+        };
+        let normal = plane.back().as_vec3();
+        let origin = plane.translation();
+        let eye = reflect_point(viewer.translation(), origin, normal);
+        let forward = reflect_dir(viewer.forward().as_vec3(), normal);
+        let up = reflect_dir(viewer.up().as_vec3(), normal);
+        *transform = Transform::from_translation(eye).looking_to(forward, up);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_near_visible_screens_render_within_budget() {
+        let [a, b, c] = [1, 2, 3].map(|i| Entity::from_raw_u32(i).unwrap());
+        let screens = [
+            (a, 5.0, true, 10.0),
+            (b, 2.0, true, 10.0),
+            (c, 1.0, false, 10.0),
+            (a, 1.0, true, 0.5),
+        ];
+        assert_eq!(select_screen_cameras(&screens, 2), vec![b, a]);
+        assert_eq!(select_screen_cameras(&screens, 1), vec![b]);
+    }
+
+    #[test]
+    fn reflection_mirrors_across_the_plane() {
+        let p = reflect_point(Vec3::new(3.0, 1.0, 2.0), Vec3::new(1.0, 0.0, 0.0), Vec3::X);
+        assert_eq!(p, Vec3::new(-1.0, 1.0, 2.0));
+        assert_eq!(reflect_dir(Vec3::new(-1.0, 0.0, -1.0), Vec3::X), Vec3::new(1.0, 0.0, -1.0));
+    }
+}
@@ -0,0 +1,189 @@
+// src/features/camera/shake.rs
+use bevy::prelude::*;
+
+use crate::app::EffectsIntensity;
+use crate::features::explosion::systems::Explosion;
+use crate::features::fall_damage::systems::HardLanding;
+use crate::features::player::component::Player;
+
+/// Camera shake tuning.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ShakeSettings {
+    /// Sideways / vertical offset at full trauma (world units, camera space).
+    pub max_offset: f32,
+    /// Roll at full trauma (degrees).
+    pub max_roll_deg: f32,
+    /// Trauma lost per second.
+    pub recovery_per_sec: f32,
+    /// Shake oscillations per second.
+    pub frequency: f32,
+    /// Trauma from the player landing hard enough to stagger (half for lighter ones).
+    pub hard_landing: f32,
+    /// Trauma from an explosion at the camera, fading to nothing at `explosion_range`.
+    pub explosion: f32,
+    pub explosion_range: f32,
+}
+
+impl Default for ShakeSettings {
+    fn default() -> Self {
+        Self {
+            max_offset: 0.25,
+            max_roll_deg: 3.0,
+            recovery_per_sec: 1.2,
+            frequency: 22.0,
+            hard_landing: 0.5,
+            explosion: 0.9,
+            explosion_range: 25.0,
+        }
+    }
+}
+
+/// Trauma-based shake on a rig camera: events add trauma (`0..1`), the shake grows
+/// with trauma squared and fades as it recovers.
+///
+/// `offset` / `roll` are this frame's shake; `apply_camera_rig` adds them on top of
+/// the rig's pose.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CameraShake {
+    pub trauma: f32,
+    pub elapsed: f32,
+    pub offset: Vec3,
+    pub roll: f32,
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount.max(0.0)).min(1.0);
+    }
+}
+
+/// Update: the player's hard landings and nearby explosions shake every camera.
+pub fn add_camera_trauma(
+    settings: Res<ShakeSettings>,
+    mut landings: MessageReader<HardLanding>,
+    mut explosions: MessageReader<Explosion>,
+    q_players: Query<(), With<Player>>,
+    mut q_cameras: Query<(&Transform, &mut CameraShake)>,
+) {
+    let landing: f32 = landings
+        .read()
+        .filter(|landing| q_players.contains(landing.entity))
+        .map(|landing| {
+            if landing.impact.stagger {
+                settings.hard_landing
+            } else {
+                settings.hard_landing * 0.5
+            }
+        })
+        .sum();
+    let blasts: Vec<Vec3> = explosions.read().map(|e| e.position).collect();
+
+    for (transform, mut shake) in &mut q_cameras {
+        let mut trauma = landing;
+        for position in &blasts {
+            let distance = transform.translation.distance(*position);
+            let falloff = 1.0 - distance / settings.explosion_range.max(f32::EPSILON);
+            trauma += settings.explosion * falloff.max(0.0);
+        }
+        shake.add_trauma(trauma);
+    }
+}
+
+/// Update (before the rig): recover trauma and compute this frame's shake, scaled by
+/// `EffectsIntensity::shake`.
+pub fn update_camera_shake(
+    time: Res<Time>,
+    settings: Res<ShakeSettings>,
+    effects: Res<EffectsIntensity>,
+    mut q_cameras: Query<&mut CameraShake>,
+) {
+    let dt = time.delta_secs();
+    for mut shake in &mut q_cameras {
+        if shake.trauma <= 0.0 && shake.offset == Vec3::ZERO && shake.roll == 0.0 {
+            continue;
+        }
+        shake.trauma = (shake.trauma - settings.recovery_per_sec * dt).max(0.0);
+        shake.elapsed += dt;
+
+        let amount = shake.trauma * shake.trauma * effects.shake;
+        let t = shake.elapsed * settings.frequency;
+        // Sines at unrelated rates: irregular, but smooth and cheap.
+        let wave =
+            |seed: f32| (t * (1.0 + 0.31 * seed) + seed).sin() * (t * 0.43 + seed * 2.1).cos();
+        shake.offset = Vec3::new(wave(1.0), wave(2.0), 0.0) * settings.max_offset * amount;
+        shake.roll = wave(3.0) * settings.max_roll_deg.to_radians() * amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::EffectsLevel;
+    use crate::features::explosion::component::ExplosionDef;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<ShakeSettings>();
+        world.init_resource::<EffectsIntensity>();
+        world.init_resource::<Messages<HardLanding>>();
+        world.init_resource::<Messages<Explosion>>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(0.05));
+        world.insert_resource(time);
+        world
+    }
+
+    fn explode_at(world: &mut World, position: Vec3) {
+        world.write_message(Explosion {
+            source: None,
+            position,
+            def: ExplosionDef {
+                radius: 3.0,
+                damage: 10.0,
+                knockback: 0.0,
+            },
+        });
+    }
+
+    #[test]
+    fn near_explosions_shake_harder_and_far_ones_not_at_all() {
+        let mut world = setup_world();
+        let camera = world
+            .spawn((Transform::default(), CameraShake::default()))
+            .id();
+
+        explode_at(&mut world, Vec3::new(40.0, 0.0, 0.0));
+        let _ = world.run_system_once(add_camera_trauma);
+        assert_eq!(world.get::<CameraShake>(camera).unwrap().trauma, 0.0);
+
+        explode_at(&mut world, Vec3::new(5.0, 0.0, 0.0));
+        let _ = world.run_system_once(add_camera_trauma);
+        let trauma = world.get::<CameraShake>(camera).unwrap().trauma;
+        assert!((trauma - 0.72).abs() < 1e-5, "trauma {trauma}");
+    }
+
+    #[test]
+    fn shake_fades_and_respects_the_effects_intensity() {
+        let mut world = setup_world();
+        let camera = world
+            .spawn(CameraShake {
+                trauma: 1.0,
+                ..default()
+            })
+            .id();
+
+        let _ = world.run_system_once(update_camera_shake);
+        let full = *world.get::<CameraShake>(camera).unwrap();
+        assert!(full.offset.length() > 0.0);
+        assert!(full.trauma < 1.0);
+
+        *world.resource_mut::<EffectsIntensity>() = EffectsIntensity::for_level(EffectsLevel::Off);
+        let _ = world.run_system_once(update_camera_shake);
+        let off = *world.get::<CameraShake>(camera).unwrap();
+        assert_eq!((off.offset, off.roll), (Vec3::ZERO, 0.0));
+        assert!(off.trauma < full.trauma, "trauma still recovers");
+    }
+}
@@ -0,0 +1,357 @@
+// src/features/camera/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    CameraCollision, CameraFov, CameraSettings, CameraZoom, FollowCamera, OrbitCamera, ZoomMode,
+};
+use super::rig::{CameraMode, CameraPose, CameraRig};
+use crate::features::collision::query::CollisionWorld;
+use crate::features::player::component::Player;
+
+/// Update: point untargeted follow cameras at the player.
+pub fn assign_follow_target(
+    mut q_cameras: Query<&mut FollowCamera>,
+    q_player: Query<Entity, With<Player>>,
+) {
+    let Some(player) = q_player.iter().next() else {
+        return;
+    };
+
+    for mut camera in &mut q_cameras {
+        if camera.target.is_none() {
+            camera.target = Some(player);
+        }
+    }
+}
+
+/// Update: move cameras towards their zoom target (boom length or base FOV).
+pub fn apply_camera_zoom(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut q_cameras: Query<(&CameraZoom, Option<&mut FollowCamera>, Option<&mut CameraFov>)>,
+) {
+    let t = if settings.zoom_smoothing > 0.0 {
+        1.0 - (-settings.zoom_smoothing * time.delta_secs()).exp()
+    } else {
+        1.0
+    };
+
+    for (zoom, follow, fov) in &mut q_cameras {
+        let Some(target) = zoom.target else {
+            continue;
+        };
+
+        match zoom.mode {
+            ZoomMode::Distance => {
+                if let Some(mut follow) = follow {
+                    follow.distance += (target - follow.distance) * t;
+                }
+            }
+            ZoomMode::Fov => {
+                if let Some(mut fov) = fov {
+                    fov.base_deg += (target - fov.base_deg) * t;
+                }
+            }
+        }
+    }
+}
+
+/// Update: write the composed `CameraFov` into the perspective projection.
+pub fn apply_camera_fov(mut q_cameras: Query<(&CameraFov, &mut Projection), Changed<CameraFov>>) {
+    for (fov, mut projection) in &mut q_cameras {
+        if let Projection::Perspective(p) = projection.as_mut() {
+            p.fov = fov.effective_deg().to_radians();
+        }
+    }
+}
+
+/// Update: swing boom cameras back behind their target.
+///
+/// - `Follow` mode: always.
+/// - `Orbit` mode: only after `recenter_delay_secs` without orbit input.
+///
+/// "Behind" = the target's back (+Z local), so yaw converges to the target's own yaw.
+/// Takes the shortest way around; pitch is left where the player put it.
+pub fn recenter_orbit_camera(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    q_targets: Query<&Transform, Without<FollowCamera>>,
+    mut q_cameras: Query<(&mut FollowCamera, &CameraRig, Option<&OrbitCamera>)>,
+) {
+    let t = 1.0 - (-settings.recenter_rate.max(0.0) * time.delta_secs()).exp();
+
+    for (mut follow, rig, orbit) in &mut q_cameras {
+        let recenter = match rig.mode {
+            CameraMode::Follow => true,
+            CameraMode::Orbit => orbit.is_some_and(|o| o.idle_secs >= settings.recenter_delay_secs),
+            _ => false,
+        };
+        if !recenter {
+            continue;
+        }
+        let Some(target) = follow.target.and_then(|e| q_targets.get(e).ok()) else {
+            continue;
+        };
+
+        let (target_yaw, _, _) = target.rotation.to_euler(EulerRot::YXZ);
+        follow.yaw += wrap_angle(target_yaw - follow.yaw) * t;
+    }
+}
+
+/// Wrap an angle into `(-PI, PI]`.
+pub(crate) fn wrap_angle(angle: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+    let a = (angle + PI).rem_euclid(TAU) - PI;
+    if a <= -PI { a + TAU } else { a }
+}
+
+/// Update: place boom cameras behind their target, pulled in by obstructions.
+///
+/// - Reads: FollowCamera, target Transform, colliders
+/// - Writes: CameraRig.desired (Follow / Orbit modes only), CameraCollision.current_distance
+pub fn update_follow_camera(
+    time: Res<Time>,
+    collision: CollisionWorld,
+    q_targets: Query<&Transform, Without<FollowCamera>>,
+    mut q_cameras: Query<(&FollowCamera, &mut CameraCollision, &mut CameraRig)>,
+) {
+    let dt = time.delta_secs();
+
+    for (camera, mut obstruction, mut rig) in &mut q_cameras {
+        if !rig.mode.uses_boom() {
+            continue;
+        }
+        let Some(target) = camera.target.and_then(|t| q_targets.get(t).ok().map(|tr| (t, tr))) else {
+            continue;
+        };
+        let (target_entity, target_tr) = target;
+
+        let pivot = target_tr.translation + camera.pivot_offset;
+        let dir = camera.boom_direction();
+        let desired = camera.distance.max(obstruction.min_distance);
+
+        let allowed = collision
+            .sphere_cast(pivot, dir, desired, obstruction.radius, &[target_entity])
+            .map_or(desired, |hit| hit.distance)
+            .max(obstruction.min_distance);
+
+        obstruction.current_distance = next_boom_distance(
+            obstruction.current_distance,
+            allowed,
+            obstruction.recover_rate,
+            dt,
+        );
+
+        rig.desired = Some(CameraPose::looking_at(
+            pivot + dir * obstruction.current_distance,
+            pivot,
+        ));
+    }
+}
+
+/// Pull in instantly, recover smoothly (exponential approach).
+fn next_boom_distance(current: f32, allowed: f32, recover_rate: f32, dt: f32) -> f32 {
+    if current < 0.0 || allowed <= current {
+        return allowed;
+    }
+    let t = 1.0 - (-recover_rate.max(0.0) * dt).exp();
+    current + (allowed - current) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::collision::component::Collider;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn world_with_time(dt: f32) -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(dt));
+        world.insert_resource(time);
+        world
+    }
+
+    fn desired(world: &World, cam: Entity) -> CameraPose {
+        world.get::<CameraRig>(cam).unwrap().desired.unwrap()
+    }
+
+    /// Camera straight behind the target (+Z), level, 8 units out.
+    fn spawn_rig(world: &mut World) -> Entity {
+        let target = world.spawn((Player, Transform::default())).id();
+        world
+            .spawn((
+                FollowCamera {
+                    target: Some(target),
+                    pivot_offset: Vec3::Y,
+                    pitch: 0.0,
+                    ..default()
+                },
+                Transform::default(),
+            ))
+            .id()
+    }
+
+    fn wall(world: &mut World, z: f32) -> Entity {
+        world
+            .spawn((
+                Collider::cuboid(Vec3::new(5.0, 5.0, 0.25)),
+                GlobalTransform::from_translation(Vec3::new(0.0, 0.0, z)),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn unobstructed_camera_sits_at_full_distance_looking_at_pivot() {
+        let mut world = world_with_time(1.0 / 60.0);
+        let cam = spawn_rig(&mut world);
+
+        let _ = world.run_system_once(update_follow_camera);
+
+        let pose = desired(&world, cam);
+        assert!((pose.translation - Vec3::new(0.0, 1.0, 8.0)).length() < 1e-4);
+        assert!((pose.rotation * Vec3::NEG_Z - Vec3::NEG_Z).length() < 1e-4);
+    }
+
+    #[test]
+    fn wall_pulls_camera_in_and_recovery_is_smooth() {
+        let mut world = world_with_time(1.0 / 60.0);
+        let cam = spawn_rig(&mut world);
+        let blocker = wall(&mut world, 4.0);
+
+        let _ = world.run_system_once(update_follow_camera);
+        // Wall front face at z = 3.75, minus the 0.3 probe radius.
+        let z = desired(&world, cam).translation.z;
+        assert!((z - 3.45).abs() < 1e-4, "pulled in: {z}");
+
+        world.despawn(blocker);
+        let _ = world.run_system_once(update_follow_camera);
+        let z = desired(&world, cam).translation.z;
+        assert!(z > 3.45 && z < 8.0, "recovers gradually: {z}");
+    }
+
+    #[test]
+    fn boom_distance_pulls_in_instantly_and_recovers_exponentially() {
+        assert_eq!(next_boom_distance(-1.0, 5.0, 4.0, 0.1), 5.0);
+        assert_eq!(next_boom_distance(6.0, 2.0, 4.0, 0.1), 2.0);
+        let d = next_boom_distance(2.0, 6.0, 4.0, 0.1);
+        assert!(d > 2.0 && d < 6.0);
+    }
+
+    #[test]
+    fn zoom_approaches_target_smoothly() {
+        let mut world = world_with_time(1.0 / 60.0);
+        world.insert_resource(CameraSettings::default());
+        let cam = world
+            .spawn((
+                FollowCamera::default(),
+                CameraZoom {
+                    mode: ZoomMode::Distance,
+                    target: Some(4.0),
+                },
+            ))
+            .id();
+
+        let _ = world.run_system_once(apply_camera_zoom);
+
+        let d = world.get::<FollowCamera>(cam).unwrap().distance;
+        assert!(d < 8.0 && d > 4.0, "got {d}");
+    }
+
+    #[test]
+    fn fov_composes_base_and_scale_into_projection() {
+        let mut world = World::new();
+        let cam = world
+            .spawn((
+                CameraFov {
+                    base_deg: 60.0,
+                    scale: 0.5,
+                },
+                Projection::Perspective(PerspectiveProjection::default()),
+            ))
+            .id();
+
+        let _ = world.run_system_once(apply_camera_fov);
+
+        let Projection::Perspective(p) = world.get::<Projection>(cam).unwrap() else {
+            panic!("perspective projection expected");
+        };
+        assert!((p.fov - 30f32.to_radians()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn idle_orbit_camera_recenters_behind_target_the_short_way() {
+        let mut world = world_with_time(0.1);
+        world.insert_resource(CameraSettings::default());
+        let target = world
+            .spawn(Transform::from_rotation(Quat::from_rotation_y(3.0)))
+            .id();
+        let cam = world
+            .spawn((
+                FollowCamera {
+                    target: Some(target),
+                    yaw: -3.0,
+                    ..default()
+                },
+                OrbitCamera { idle_secs: 10.0 },
+                CameraRig::new(CameraMode::Orbit),
+            ))
+            .id();
+
+        let _ = world.run_system_once(recenter_orbit_camera);
+
+        // -3.0 -> 3.0 is shorter through -PI (decreasing yaw).
+        let yaw = world.get::<FollowCamera>(cam).unwrap().yaw;
+        assert!(yaw < -3.0, "got {yaw}");
+    }
+
+    #[test]
+    fn active_orbit_camera_does_not_recenter() {
+        let mut world = world_with_time(0.1);
+        world.insert_resource(CameraSettings::default());
+        let target = world.spawn(Transform::from_rotation(Quat::from_rotation_y(1.0))).id();
+        let cam = world
+            .spawn((
+                FollowCamera {
+                    target: Some(target),
+                    ..default()
+                },
+                OrbitCamera { idle_secs: 0.5 },
+                CameraRig::new(CameraMode::Orbit),
+            ))
+            .id();
+
+        let _ = world.run_system_once(recenter_orbit_camera);
+
+        assert_eq!(world.get::<FollowCamera>(cam).unwrap().yaw, 0.0);
+    }
+
+    #[test]
+    fn non_boom_modes_leave_desired_pose_alone() {
+        let mut world = world_with_time(1.0 / 60.0);
+        let cam = spawn_rig(&mut world);
+        world.get_mut::<CameraRig>(cam).unwrap().mode = CameraMode::FirstPerson;
+
+        let _ = world.run_system_once(update_follow_camera);
+
+        assert!(world.get::<CameraRig>(cam).unwrap().desired.is_none());
+    }
+
+    #[test]
+    fn wrap_angle_stays_in_range() {
+        assert!((wrap_angle(3.0 * std::f32::consts::PI) - std::f32::consts::PI).abs() < 1e-5);
+        assert!((wrap_angle(-0.5) + 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn untargeted_camera_follows_the_player() {
+        let mut world = World::new();
+        let player = world.spawn(Player).id();
+        let cam = world.spawn(FollowCamera::default()).id();
+
+        let _ = world.run_system_once(assign_follow_target);
+
+        assert_eq!(world.get::<FollowCamera>(cam).unwrap().target, Some(player));
+    }
+}
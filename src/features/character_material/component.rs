@@ -0,0 +1,118 @@
+// src/features/character_material/component.rs
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderType};
+use bevy::shader::ShaderRef;
+
+/// Material of characters (player, enemies): `StandardMaterial` plus the
+/// damage-flash / death-dissolve extension.
+pub type CharacterMaterial = ExtendedMaterial<StandardMaterial, CharacterExtension>;
+
+/// Shader parameters of the character effects (mirrors `assets/shaders/character.wgsl`).
+#[derive(ShaderType, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct CharacterFxUniform {
+    pub flash_color: LinearRgba,
+    pub edge_color: LinearRgba,
+    /// `0.0` = no flash, `1.0` = fully `flash_color`.
+    pub flash: f32,
+    /// `0.0` = intact, `1.0` = fully dissolved.
+    pub dissolve: f32,
+    /// Width (in noise units) of the glowing dissolve edge.
+    pub edge_width: f32,
+    /// Noise frequency in world space.
+    pub noise_scale: f32,
+    /// Lighting bands of the toon style (`0.0` = standard shading).
+    pub toon_bands: f32,
+}
+
+impl Default for CharacterFxUniform {
+    fn default() -> Self {
+        Self {
+            flash_color: LinearRgba::WHITE,
+            edge_color: LinearRgba::rgb(1.0, 0.45, 0.1),
+            flash: 0.0,
+            dissolve: 0.0,
+            edge_width: 0.06,
+            noise_scale: 6.0,
+            toon_bands: 0.0,
+        }
+    }
+}
+
+#[derive(Asset, AsBindGroup, Reflect, Debug, Default, Clone)]
+pub struct CharacterExtension {
+    #[uniform(100)]
+    pub fx: CharacterFxUniform,
+}
+
+impl MaterialExtension for CharacterExtension {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/character.wgsl".into()
+    }
+
+    fn deferred_fragment_shader() -> ShaderRef {
+        "shaders/character.wgsl".into()
+    }
+}
+
+/// `CharacterMaterial` with `color` as the base color and no effect active.
+pub fn character_material(color: Color) -> CharacterMaterial {
+    ExtendedMaterial {
+        base: StandardMaterial::from(color),
+        extension: CharacterExtension::default(),
+    }
+}
+
+/// Effect state of a character; drives its (per-entity) `CharacterMaterial`.
+///
+/// Flashes whenever `Health` drops; dissolves after `Died` and resets once health
+/// is restored (respawn).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CharacterFx {
+    /// Seconds of damage flash left.
+    pub flash_secs: f32,
+    /// Seconds since the dissolve started (`None` = not dissolving).
+    pub dissolve_secs: Option<f32>,
+    /// Health seen last frame (damage detection).
+    pub last_health: Option<f32>,
+}
+
+impl CharacterFx {
+    /// Flash strength (`0..1`) for the current state.
+    pub fn flash_amount(&self, settings: &CharacterFxSettings) -> f32 {
+        if settings.flash_secs <= 0.0 {
+            return 0.0;
+        }
+        (self.flash_secs / settings.flash_secs).clamp(0.0, 1.0)
+    }
+
+    /// Dissolve progress (`0..1`) for the current state.
+    pub fn dissolve_amount(&self, settings: &CharacterFxSettings) -> f32 {
+        self.dissolve_secs
+            .map_or(0.0, |t| (t / settings.dissolve_secs.max(f32::EPSILON)).clamp(0.0, 1.0))
+    }
+}
+
+/// Marks a `CharacterMaterial` handle as already unique to its entity.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OwnedCharacterMaterial;
+
+/// Timings and colors of the character effects.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CharacterFxSettings {
+    pub flash_secs: f32,
+    pub flash_color: Color,
+    pub dissolve_secs: f32,
+    pub edge_color: Color,
+}
+
+impl Default for CharacterFxSettings {
+    fn default() -> Self {
+        Self {
+            flash_secs: 0.12,
+            flash_color: Color::WHITE,
+            dissolve_secs: 1.5,
+            edge_color: Color::srgb(1.0, 0.45, 0.1),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// src/features/character_material/mod.rs
+use bevy::pbr::PbrPlugin;
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+
+pub mod component;
+pub mod systems;
+pub mod toon;
+
+/// Character material feature.
+///
+/// Scope:
+/// - `CharacterMaterial`: `StandardMaterial` extended with a damage flash and a
+///   noise dissolve (`assets/shaders/character.wgsl`); player and enemies use it
+/// - `CharacterFx` drives it: `Health` drops flash, depleted health dissolves,
+///   restored health (respawn) resets
+/// - shared handles are copied per entity on spawn so effects stay per character
+/// - `ShadingStyle` (graphics settings): `Toon` bands the lighting of every
+///   `CharacterMaterial`; `Stylized` props / NPCs get one in place of their
+///   `StandardMaterial` (cached per source material); `F9` toggles and saves
+///
+/// Design constraints:
+/// - Effects read gameplay state (`Health`), never drive it.
+/// - Headless apps (no renderer) skip the material plugin; the systems then only
+///   track `CharacterFx`.
+pub struct CharacterMaterialPlugin;
+
+impl Plugin for CharacterMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        if app.is_plugin_added::<PbrPlugin>() {
+            app.add_plugins(MaterialPlugin::<component::CharacterMaterial>::default());
+        }
+        app.init_resource::<component::CharacterFxSettings>();
+        app.init_resource::<toon::StylizedMaterials>();
+        app.init_resource::<toon::ShadingKeybinding>();
+        app.add_systems(
+            Update,
+            (
+                systems::own_character_materials,
+                systems::track_character_fx,
+                systems::apply_character_fx,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                toon::stylize_materials,
+                toon::toggle_shading_style
+                    .in_set(AppSet::Input)
+                    .run_if(in_input_context(HOTKEYS)),
+                toon::apply_shading_style,
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,225 @@
+// src/features/character_material/systems.rs
+use bevy::prelude::*;
+
+use super::component::{CharacterFx, CharacterFxSettings, CharacterMaterial, OwnedCharacterMaterial};
+use crate::app::EffectsIntensity;
+use crate::features::health::component::Health;
+
+/// `CharacterFx` driving the material on `entity`: its own, or its parent's when
+/// the mesh is a visual child of the character's logic root.
+fn fx_source<'a>(
+    q_fx: &'a Query<&CharacterFx>,
+    entity: Entity,
+    parent: Option<&ChildOf>,
+) -> Option<&'a CharacterFx> {
+    q_fx.get(entity)
+        .ok()
+        .or_else(|| parent.and_then(|p| q_fx.get(p.parent()).ok()))
+}
+
+/// Update: give each new character its own copy of its material, so effects on one
+/// enemy don't light up every enemy sharing the handle.
+#[expect(clippy::type_complexity)]
+pub fn own_character_materials(
+    mut commands: Commands,
+    materials: Option<ResMut<Assets<CharacterMaterial>>>,
+    q_fx: Query<&CharacterFx>,
+    mut q_new: Query<
+        (Entity, &mut MeshMaterial3d<CharacterMaterial>, Option<&ChildOf>),
+        Without<OwnedCharacterMaterial>,
+    >,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    for (entity, mut handle, parent) in &mut q_new {
+        if fx_source(&q_fx, entity, parent).is_none() {
+            continue;
+        }
+        if let Some(material) = materials.get(&handle.0).cloned() {
+            handle.0 = materials.add(material);
+        }
+        commands.entity(entity).insert(OwnedCharacterMaterial);
+    }
+}
+
+/// Update: start a flash when health drops, dissolve while depleted, reset on restore.
+pub fn track_character_fx(
+    time: Res<Time>,
+    settings: Res<CharacterFxSettings>,
+    mut q_characters: Query<(&mut CharacterFx, &Health)>,
+) {
+    let dt = time.delta_secs();
+    for (mut fx, health) in &mut q_characters {
+        let took_damage = fx.last_health.is_some_and(|last| health.current < last);
+        fx.last_health = Some(health.current);
+
+        fx.flash_secs = if took_damage {
+            settings.flash_secs
+        } else {
+            (fx.flash_secs - dt).max(0.0)
+        };
+        fx.dissolve_secs = if health.is_depleted() {
+            Some(fx.dissolve_secs.map_or(0.0, |t| t + dt))
+        } else {
+            None
+        };
+    }
+}
+
+/// Update: push each character's effect state into its material (only on change,
+/// so idle characters don't re-upload every frame). The flash is scaled by
+/// `EffectsIntensity::flash`.
+#[expect(clippy::type_complexity)]
+pub fn apply_character_fx(
+    settings: Res<CharacterFxSettings>,
+    effects: Res<EffectsIntensity>,
+    materials: Option<ResMut<Assets<CharacterMaterial>>>,
+    q_fx: Query<&CharacterFx>,
+    q_characters: Query<
+        (Entity, &MeshMaterial3d<CharacterMaterial>, Option<&ChildOf>),
+        With<OwnedCharacterMaterial>,
+    >,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    for (entity, handle, parent) in &q_characters {
+        let Some(fx) = fx_source(&q_fx, entity, parent) else {
+            continue;
+        };
+        let flash = fx.flash_amount(&settings) * effects.flash;
+        let dissolve = fx.dissolve_amount(&settings);
+        let Some(current) = materials.get(&handle.0).map(|m| m.extension.fx) else {
+            continue;
+        };
+        if current.flash == flash && current.dissolve == dissolve {
+            continue;
+        }
+        if let Some(material) = materials.get_mut(&handle.0) {
+            let uniform = &mut material.extension.fx;
+            uniform.flash = flash;
+            uniform.dissolve = dissolve;
+            uniform.flash_color = settings.flash_color.into();
+            uniform.edge_color = settings.edge_color.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::EffectsLevel;
+    use crate::features::character_material::component::character_material;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<CharacterFxSettings>();
+        world.init_resource::<EffectsIntensity>();
+        world.init_resource::<Assets<CharacterMaterial>>();
+        world.insert_resource(Time::<()>::default());
+        world
+    }
+
+    fn advance(world: &mut World, secs: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+    }
+
+    #[test]
+    fn shared_materials_are_split_per_character() {
+        let mut world = setup_world();
+        let shared = world
+            .resource_mut::<Assets<CharacterMaterial>>()
+            .add(character_material(Color::WHITE));
+        let a = world.spawn((CharacterFx::default(), MeshMaterial3d(shared.clone()))).id();
+        let b = world.spawn((CharacterFx::default(), MeshMaterial3d(shared.clone()))).id();
+
+        let _ = world.run_system_once(own_character_materials);
+
+        let ha = &world.get::<MeshMaterial3d<CharacterMaterial>>(a).unwrap().0;
+        let hb = &world.get::<MeshMaterial3d<CharacterMaterial>>(b).unwrap().0;
+        assert_ne!(ha, hb);
+        assert_ne!(*ha, shared);
+    }
+
+    #[test]
+    fn damage_flashes_and_death_dissolves_until_restored() {
+        let mut world = setup_world();
+        let handle = world
+            .resource_mut::<Assets<CharacterMaterial>>()
+            .add(character_material(Color::WHITE));
+        let e = world
+            .spawn((
+                CharacterFx::default(),
+                Health::new(10.0),
+                MeshMaterial3d(handle.clone()),
+                OwnedCharacterMaterial,
+            ))
+            .id();
+        let run = |world: &mut World| {
+            let _ = world.run_system_once(track_character_fx);
+            let _ = world.run_system_once(apply_character_fx);
+        };
+        let fx_of = |world: &World| {
+            world
+                .resource::<Assets<CharacterMaterial>>()
+                .get(&handle)
+                .unwrap()
+                .extension
+                .fx
+        };
+
+        run(&mut world);
+        assert_eq!(fx_of(&world).flash, 0.0);
+
+        world.get_mut::<Health>(e).unwrap().current = 4.0;
+        run(&mut world);
+        assert_eq!(fx_of(&world).flash, 1.0);
+        advance(&mut world, 1.0);
+        run(&mut world);
+        assert_eq!(fx_of(&world).flash, 0.0, "flash fades");
+
+        world.get_mut::<Health>(e).unwrap().current = 0.0;
+        run(&mut world);
+        advance(&mut world, 0.75);
+        run(&mut world);
+        assert!((fx_of(&world).dissolve - 0.5).abs() < 1e-4);
+
+        world.get_mut::<Health>(e).unwrap().current = 10.0;
+        run(&mut world);
+        assert_eq!(fx_of(&world).dissolve, 0.0, "respawn resets the dissolve");
+
+        *world.resource_mut::<EffectsIntensity>() = EffectsIntensity::for_level(EffectsLevel::Off);
+        world.get_mut::<Health>(e).unwrap().current = 5.0;
+        run(&mut world);
+        assert_eq!(fx_of(&world).flash, 0.0, "flashes can be turned off");
+    }
+
+    #[test]
+    fn visual_children_follow_their_roots_fx() {
+        let mut world = setup_world();
+        let shared = world
+            .resource_mut::<Assets<CharacterMaterial>>()
+            .add(character_material(Color::WHITE));
+        let root = world.spawn((CharacterFx::default(), Health::new(10.0))).id();
+        let visual = world.spawn((MeshMaterial3d(shared.clone()), ChildOf(root))).id();
+        let unrelated = world.spawn(MeshMaterial3d(shared.clone())).id();
+
+        let _ = world.run_system_once(own_character_materials);
+        let _ = world.run_system_once(track_character_fx);
+        world.get_mut::<Health>(root).unwrap().current = 4.0;
+        let _ = world.run_system_once(track_character_fx);
+        let _ = world.run_system_once(apply_character_fx);
+
+        let owned = world.get::<MeshMaterial3d<CharacterMaterial>>(visual).unwrap().0.clone();
+        assert_ne!(owned, shared);
+        assert!(world.get::<OwnedCharacterMaterial>(unrelated).is_none());
+        let materials = world.resource::<Assets<CharacterMaterial>>();
+        assert_eq!(materials.get(&owned).unwrap().extension.fx.flash, 1.0);
+        assert_eq!(materials.get(&shared).unwrap().extension.fx.flash, 0.0);
+    }
+}
@@ -0,0 +1,154 @@
+// src/features/character_material/toon.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::component::{CharacterExtension, CharacterMaterial};
+use crate::features::settings::component::{ShadingStyle, UserSettings};
+use crate::features::settings::systems::SaveSettings;
+
+/// Lighting bands used by `ShadingStyle::Toon`.
+pub const TOON_BANDS: f32 = 4.0;
+
+/// Non-character mesh (props, NPCs) that follows the shading style: its
+/// `StandardMaterial` is swapped for a shared `CharacterMaterial` copy on spawn.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stylized;
+
+/// One `CharacterMaterial` per source `StandardMaterial`, so stylized meshes that
+/// shared a material keep sharing one.
+#[derive(Resource, Debug, Default)]
+pub struct StylizedMaterials(pub HashMap<AssetId<StandardMaterial>, Handle<CharacterMaterial>>);
+
+/// Key that switches between standard and toon shading (saved to the settings file).
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShadingKeybinding(pub KeyCode);
+
+impl Default for ShadingKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::F9)
+    }
+}
+
+/// `toon_bands` uniform value for a shading style.
+pub fn toon_bands(style: ShadingStyle) -> f32 {
+    match style {
+        ShadingStyle::Standard => 0.0,
+        ShadingStyle::Toon => TOON_BANDS,
+    }
+}
+
+/// Update: swap the `StandardMaterial` of new `Stylized` meshes for its cached
+/// `CharacterMaterial` counterpart.
+pub fn stylize_materials(
+    mut commands: Commands,
+    mut cache: ResMut<StylizedMaterials>,
+    standard: Option<Res<Assets<StandardMaterial>>>,
+    materials: Option<ResMut<Assets<CharacterMaterial>>>,
+    q_new: Query<(Entity, &MeshMaterial3d<StandardMaterial>), With<Stylized>>,
+) {
+    let (Some(standard), Some(mut materials)) = (standard, materials) else {
+        return;
+    };
+    for (entity, source) in &q_new {
+        let handle = match cache.0.get(&source.id()) {
+            Some(handle) => handle.clone(),
+            None => {
+                let Some(base) = standard.get(&source.0).cloned() else {
+                    continue;
+                };
+                let handle = materials.add(CharacterMaterial {
+                    base,
+                    extension: CharacterExtension::default(),
+                });
+                cache.0.insert(source.id(), handle.clone());
+                handle
+            }
+        };
+        commands
+            .entity(entity)
+            .remove::<MeshMaterial3d<StandardMaterial>>()
+            .insert(MeshMaterial3d(handle));
+    }
+}
+
+/// Update: the shading key flips the style and saves it.
+pub fn toggle_shading_style(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<ShadingKeybinding>,
+    mut settings: ResMut<UserSettings>,
+    mut save: MessageWriter<SaveSettings>,
+) {
+    if !keyboard.just_pressed(binding.0) {
+        return;
+    }
+    settings.graphics.shading = match settings.graphics.shading {
+        ShadingStyle::Standard => ShadingStyle::Toon,
+        ShadingStyle::Toon => ShadingStyle::Standard,
+    };
+    save.write(SaveSettings);
+}
+
+/// Update: keep every `CharacterMaterial` on the configured shading style (new
+/// materials included; only mismatching ones are touched).
+pub fn apply_shading_style(
+    settings: Res<UserSettings>,
+    materials: Option<ResMut<Assets<CharacterMaterial>>>,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    let bands = toon_bands(settings.graphics.shading);
+    let stale: Vec<_> = materials
+        .iter()
+        .filter(|(_, m)| m.extension.fx.toon_bands != bands)
+        .map(|(id, _)| id)
+        .collect();
+    for id in stale {
+        if let Some(material) = materials.get_mut(id) {
+            material.extension.fx.toon_bands = bands;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::character_material::component::character_material;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn stylized_meshes_share_a_converted_material() {
+        let mut world = World::new();
+        world.init_resource::<StylizedMaterials>();
+        world.init_resource::<Assets<CharacterMaterial>>();
+        let mut standard = Assets::<StandardMaterial>::default();
+        let wood = standard.add(Color::srgb(0.6, 0.4, 0.2));
+        world.insert_resource(standard);
+        let a = world.spawn((Stylized, MeshMaterial3d(wood.clone()))).id();
+        let b = world.spawn((Stylized, MeshMaterial3d(wood))).id();
+
+        let _ = world.run_system_once(stylize_materials);
+
+        let ha = &world.get::<MeshMaterial3d<CharacterMaterial>>(a).unwrap().0;
+        let hb = &world.get::<MeshMaterial3d<CharacterMaterial>>(b).unwrap().0;
+        assert_eq!(ha, hb);
+        assert!(world.get::<MeshMaterial3d<StandardMaterial>>(a).is_none());
+    }
+
+    #[test]
+    fn toon_style_bands_every_material() {
+        let mut world = World::new();
+        let mut materials = Assets::<CharacterMaterial>::default();
+        let handle = materials.add(character_material(Color::WHITE));
+        world.insert_resource(materials);
+        let mut settings = UserSettings::default();
+        settings.graphics.shading = ShadingStyle::Toon;
+        world.insert_resource(settings);
+
+        let _ = world.run_system_once(apply_shading_style);
+
+        let material = world.resource::<Assets<CharacterMaterial>>().get(&handle).unwrap();
+        assert_eq!(material.extension.fx.toon_bands, TOON_BANDS);
+    }
+}
@@ -0,0 +1,58 @@
+// src/features/collision/component.rs
+use bevy::prelude::*;
+
+/// Axis-aligned box collider centered on the entity's world translation.
+///
+/// Rotation and scale are ignored on purpose: level geometry is built from
+/// axis-aligned pieces for now, and AABBs keep every query trivially cheap.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Collider {
+    pub half_extents: Vec3,
+}
+
+impl Collider {
+    pub fn cuboid(half_extents: Vec3) -> Self {
+        Self {
+            half_extents: half_extents.abs(),
+        }
+    }
+
+    /// World-space bounds for a collider at `center`.
+    pub fn aabb(&self, center: Vec3) -> Aabb {
+        Aabb {
+            min: center - self.half_extents,
+            max: center + self.half_extents,
+        }
+    }
+}
+
+/// World-space axis-aligned bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Same box grown by `amount` on every side (Minkowski sum with a cube).
+    pub fn expanded(&self, amount: f32) -> Self {
+        self.grown(Vec3::splat(amount))
+    }
+
+    /// Same box grown by `half_extents` per axis (Minkowski sum with a box).
+    pub fn grown(&self, half_extents: Vec3) -> Self {
+        Self {
+            min: self.min - half_extents,
+            max: self.max + half_extents,
+        }
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Point of the box nearest to `point` (`point` itself when inside).
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point.clamp(self.min, self.max)
+    }
+}
@@ -0,0 +1,118 @@
+// src/features/collision/debug.rs
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+
+use super::component::Collider;
+use super::query::CastHit;
+use crate::features::trigger::component::TriggerVolume;
+
+/// Debug overlay: colliders, trigger volumes, this frame's casts and their contacts.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionDebug {
+    pub enabled: bool,
+    pub toggle: KeyCode,
+}
+
+impl Default for CollisionDebug {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle: KeyCode::F6,
+        }
+    }
+}
+
+/// One ray / sphere / box cast made through `CollisionWorld`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastRecord {
+    pub origin: Vec3,
+    /// Normalized cast direction.
+    pub dir: Vec3,
+    pub max_distance: f32,
+    /// Sphere radius (largest half extent for box casts).
+    pub radius: f32,
+    pub hit: Option<CastHit>,
+}
+
+impl CastRecord {
+    /// World-space point where the cast stopped (contact point on a hit).
+    pub fn end(&self) -> Vec3 {
+        let distance = self.hit.map_or(self.max_distance, |hit| hit.distance);
+        self.origin + self.dir * distance
+    }
+}
+
+/// Casts recorded since the overlay last drew (only while it is enabled).
+///
+/// Behind a mutex so `CollisionWorld` stays a read-only param: recording never
+/// makes gameplay systems conflict with each other.
+#[derive(Resource, Debug, Default)]
+pub struct CastLog {
+    pub recording: bool,
+    casts: Mutex<Vec<CastRecord>>,
+}
+
+impl CastLog {
+    pub fn record(&self, record: CastRecord) {
+        if self.recording
+            && let Ok(mut casts) = self.casts.lock()
+        {
+            casts.push(record);
+        }
+    }
+
+    /// Recorded casts, leaving the log empty.
+    pub fn take(&self) -> Vec<CastRecord> {
+        self.casts.lock().map(|mut c| std::mem::take(&mut *c)).unwrap_or_default()
+    }
+}
+
+/// Update: toggle the overlay (recording follows it).
+pub fn toggle_collision_debug(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut debug: ResMut<CollisionDebug>,
+    mut log: ResMut<CastLog>,
+) {
+    if keyboard.just_pressed(debug.toggle) {
+        debug.enabled = !debug.enabled;
+        log.recording = debug.enabled;
+        log.take();
+    }
+}
+
+pub fn collision_debug_enabled(debug: Res<CollisionDebug>) -> bool {
+    debug.enabled
+}
+
+/// PostUpdate (debug only): draw collider boxes, trigger volumes, then every cast
+/// since the last draw with its contact point and normal.
+pub fn draw_collision_debug(
+    mut gizmos: Gizmos,
+    log: Res<CastLog>,
+    q_colliders: Query<(&Collider, &GlobalTransform)>,
+    q_triggers: Query<(&TriggerVolume, &GlobalTransform)>,
+) {
+    for (collider, tr) in &q_colliders {
+        gizmos.cube(
+            Transform::from_translation(tr.translation()).with_scale(collider.half_extents * 2.0),
+            Color::srgb(1.0, 0.6, 0.1),
+        );
+    }
+    for (trigger, tr) in &q_triggers {
+        gizmos.cube(
+            Transform::from_translation(tr.translation()).with_scale(trigger.half_extents * 2.0),
+            Color::srgba(0.2, 1.0, 0.4, 0.5),
+        );
+    }
+    for cast in log.take() {
+        let end = cast.end();
+        let Some(hit) = cast.hit else {
+            gizmos.line(cast.origin, end, Color::srgba(0.6, 0.6, 0.6, 0.6));
+            continue;
+        };
+        gizmos.line(cast.origin, end, Color::srgb(1.0, 0.2, 0.2));
+        gizmos.sphere(Isometry3d::from_translation(end), cast.radius.max(0.05), Color::srgb(1.0, 1.0, 0.2));
+        gizmos.arrow(end, end + hit.normal * 0.5, Color::srgb(0.2, 0.6, 1.0));
+    }
+}
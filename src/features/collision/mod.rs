@@ -0,0 +1,42 @@
+// src/features/collision/mod.rs
+//
+// Minimal collision geometry shared by features (camera, movement, combat).
+//
+// Scope:
+// - `Collider`: axis-aligned box around an entity's `GlobalTransform` translation
+// - scene queries: ray cast / sphere cast against every collider (`CollisionWorld`)
+// - debug overlay (`F6`): collider boxes, trigger volumes, casts made since the
+//   last frame with their contact points and normals (`CollisionDebugPlugin`)
+//
+// Design constraints:
+// - Queries only; nothing here moves entities. Resolution is up to the caller.
+// - Brute force over all colliders: fine for a handful of level pieces; a broadphase
+//   can slot in behind `CollisionWorld` without changing callers.
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+
+pub mod component;
+pub mod debug;
+pub mod query;
+
+/// Collision debug overlay (the queries themselves need no plugin).
+pub struct CollisionDebugPlugin;
+
+impl Plugin for CollisionDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<debug::CollisionDebug>();
+        app.init_resource::<debug::CastLog>();
+
+        app.add_systems(
+            Update,
+            debug::toggle_collision_debug
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(
+            PostUpdate,
+            debug::draw_collision_debug.run_if(debug::collision_debug_enabled),
+        );
+    }
+}
@@ -0,0 +1,354 @@
+// src/features/collision/query.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::component::{Aabb, Collider};
+use super::debug::{CastLog, CastRecord};
+
+/// A collider reached by an overlap query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Overlap {
+    pub entity: Entity,
+    /// Point of the collider nearest to the query center.
+    pub point: Vec3,
+    /// Distance from the query center to `point` (0 when the center is inside).
+    pub distance: f32,
+}
+
+/// First contact of a cast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastHit {
+    pub entity: Entity,
+    /// Distance travelled along the (normalized) cast direction.
+    pub distance: f32,
+    /// Surface normal of the face that was hit.
+    pub normal: Vec3,
+}
+
+/// Ray vs box (slab method). Returns `(distance, normal)` of the entry point.
+///
+/// - `dir` must be normalized.
+/// - Rays starting inside the box report no hit: callers care about geometry
+///   *between* two points, not what they're already touching.
+pub fn ray_aabb(origin: Vec3, dir: Vec3, max_distance: f32, aabb: &Aabb) -> Option<(f32, Vec3)> {
+    if aabb.contains(origin) {
+        return None;
+    }
+
+    let mut t_enter = 0.0_f32;
+    let mut t_exit = max_distance;
+    let mut normal = Vec3::ZERO;
+
+    for axis in 0..3 {
+        let (o, d) = (origin[axis], dir[axis]);
+        let (lo, hi) = (aabb.min[axis], aabb.max[axis]);
+
+        if d.abs() < f32::EPSILON {
+            if o < lo || o > hi {
+                return None;
+            }
+            continue;
+        }
+
+        let (mut t0, mut t1) = ((lo - o) / d, (hi - o) / d);
+        let mut axis_normal = Vec3::ZERO;
+        axis_normal[axis] = -d.signum();
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+
+        if t0 > t_enter {
+            t_enter = t0;
+            normal = axis_normal;
+        }
+        t_exit = t_exit.min(t1);
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    Some((t_enter, normal))
+}
+
+/// Distance from `origin` (inside or on the box) along `dir` to where it leaves the box.
+///
+/// `dir` must be normalized. Pairs with a `ray_aabb` entry point to measure how much
+/// of the box lies along a path (e.g. material a projectile has to punch through).
+pub fn ray_aabb_exit(origin: Vec3, dir: Vec3, aabb: &Aabb) -> f32 {
+    (0..3)
+        .filter(|&axis| dir[axis].abs() >= f32::EPSILON)
+        .map(|axis| {
+            let bound = if dir[axis] > 0.0 { aabb.max[axis] } else { aabb.min[axis] };
+            (bound - origin[axis]) / dir[axis]
+        })
+        .fold(f32::INFINITY, f32::min)
+        .max(0.0)
+}
+
+/// Read-only view of every collider in the world.
+#[derive(SystemParam)]
+pub struct CollisionWorld<'w, 's> {
+    colliders: Query<'w, 's, (Entity, &'static Collider, &'static GlobalTransform)>,
+    log: Option<Res<'w, CastLog>>,
+}
+
+impl CollisionWorld<'_, '_> {
+    /// Nearest ray hit from `origin` along `dir` within `max_distance`, skipping `ignore`.
+    pub fn ray_cast(
+        &self,
+        origin: Vec3,
+        dir: Vec3,
+        max_distance: f32,
+        ignore: &[Entity],
+    ) -> Option<CastHit> {
+        self.sphere_cast(origin, dir, max_distance, 0.0, ignore)
+    }
+
+    /// Nearest hit of a sphere of `radius` swept from `origin` along `dir`.
+    ///
+    /// Approximation: boxes are grown by `radius` (corners are square, not rounded),
+    /// which errs on the side of reporting contact slightly early.
+    pub fn sphere_cast(
+        &self,
+        origin: Vec3,
+        dir: Vec3,
+        max_distance: f32,
+        radius: f32,
+        ignore: &[Entity],
+    ) -> Option<CastHit> {
+        self.cast(origin, dir, max_distance, Vec3::splat(radius.max(0.0)), ignore)
+    }
+
+    /// Nearest hit of a box of `half_extents` (centered on `origin`) swept along `dir`.
+    ///
+    /// Exact for the world-axis boxes colliders use: each collider is grown by the
+    /// swept box, then ray cast.
+    pub fn box_cast(
+        &self,
+        origin: Vec3,
+        dir: Vec3,
+        max_distance: f32,
+        half_extents: Vec3,
+        ignore: &[Entity],
+    ) -> Option<CastHit> {
+        self.cast(origin, dir, max_distance, half_extents.abs(), ignore)
+    }
+
+    fn cast(
+        &self,
+        origin: Vec3,
+        dir: Vec3,
+        max_distance: f32,
+        grow: Vec3,
+        ignore: &[Entity],
+    ) -> Option<CastHit> {
+        let dir = dir.try_normalize()?;
+
+        let hit = self
+            .colliders
+            .iter()
+            .filter(|(entity, ..)| !ignore.contains(entity))
+            .filter_map(|(entity, collider, global)| {
+                let aabb = collider.aabb(global.translation()).grown(grow);
+                ray_aabb(origin, dir, max_distance, &aabb).map(|(distance, normal)| CastHit {
+                    entity,
+                    distance,
+                    normal,
+                })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance));
+        if let Some(log) = &self.log {
+            log.record(CastRecord {
+                origin,
+                dir,
+                max_distance,
+                radius: grow.max_element(),
+                hit,
+            });
+        }
+        hit
+    }
+
+    /// Colliders whose box comes within `radius` of `center`, nearest first.
+    pub fn overlap_sphere(&self, center: Vec3, radius: f32, ignore: &[Entity]) -> Vec<Overlap> {
+        let mut overlaps: Vec<Overlap> = self
+            .colliders
+            .iter()
+            .filter(|(entity, ..)| !ignore.contains(entity))
+            .filter_map(|(entity, collider, global)| {
+                let point = collider.aabb(global.translation()).closest_point(center);
+                let distance = point.distance(center);
+                (distance <= radius).then_some(Overlap {
+                    entity,
+                    point,
+                    distance,
+                })
+            })
+            .collect();
+        overlaps.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        overlaps
+    }
+
+    /// Nothing but `target` lies between `from` and the center of `target`'s collider.
+    ///
+    /// `from` inside `target` always sees it; targets without a collider never are.
+    pub fn line_of_sight(&self, from: Vec3, target: Entity, ignore: &[Entity]) -> bool {
+        let Ok((_, collider, global)) = self.colliders.get(target) else {
+            return false;
+        };
+        let center = global.translation();
+        if collider.aabb(center).contains(from) {
+            return true;
+        }
+        let to_center = center - from;
+        self.ray_cast(from, to_center, to_center.length(), ignore)
+            .is_some_and(|hit| hit.entity == target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn unit_box() -> Aabb {
+        Collider::cuboid(Vec3::splat(0.5)).aabb(Vec3::ZERO)
+    }
+
+    #[test]
+    fn ray_hits_front_face_with_normal() {
+        let (t, n) = ray_aabb(Vec3::new(0.0, 0.0, 5.0), Vec3::NEG_Z, 10.0, &unit_box()).unwrap();
+        assert!((t - 4.5).abs() < 1e-6);
+        assert_eq!(n, Vec3::Z);
+    }
+
+    #[test]
+    fn exit_distance_measures_the_box_along_the_ray() {
+        let b = unit_box();
+        assert!((ray_aabb_exit(Vec3::new(0.0, 0.0, 0.5), Vec3::NEG_Z, &b) - 1.0).abs() < 1e-6);
+        let diagonal = Vec3::new(1.0, 0.0, -1.0).normalize();
+        let exit = ray_aabb_exit(Vec3::new(-0.5, 0.0, 0.5), diagonal, &b);
+        assert!((exit - 2.0_f32.sqrt()).abs() < 1e-5);
+        assert_eq!(ray_aabb_exit(Vec3::new(0.0, 0.0, -0.5), Vec3::NEG_Z, &b), 0.0);
+    }
+
+    #[test]
+    fn ray_misses_short_parallel_and_inside() {
+        let b = unit_box();
+        assert!(ray_aabb(Vec3::new(0.0, 0.0, 5.0), Vec3::NEG_Z, 4.0, &b).is_none());
+        assert!(ray_aabb(Vec3::new(0.0, 2.0, 5.0), Vec3::NEG_Z, 10.0, &b).is_none());
+        assert!(ray_aabb(Vec3::ZERO, Vec3::X, 10.0, &b).is_none());
+    }
+
+    #[test]
+    fn sphere_cast_returns_nearest_and_respects_ignore() {
+        let mut world = World::new();
+        let near = world
+            .spawn((
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -3.0)),
+            ))
+            .id();
+        let far = world
+            .spawn((
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -6.0)),
+            ))
+            .id();
+
+        let hit = world
+            .run_system_once(|cw: CollisionWorld| cw.sphere_cast(Vec3::ZERO, Vec3::NEG_Z, 10.0, 0.25, &[]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.entity, near);
+        assert!((hit.distance - 2.25).abs() < 1e-5);
+
+        let hit = world
+            .run_system_once(move |cw: CollisionWorld| cw.ray_cast(Vec3::ZERO, Vec3::NEG_Z, 10.0, &[near]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.entity, far);
+    }
+
+    #[test]
+    fn box_cast_grows_colliders_per_axis() {
+        let mut world = World::new();
+        let post = world
+            .spawn((
+                Collider::cuboid(Vec3::new(0.1, 2.0, 0.1)),
+                GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -5.0)),
+            ))
+            .id();
+
+        // A wide, flat box clips the post a ray (or small sphere) would pass beside.
+        let cast = |origin: Vec3, half_extents: Vec3| {
+            move |cw: CollisionWorld| cw.box_cast(origin, Vec3::NEG_Z, 10.0, half_extents, &[])
+        };
+        let beside = Vec3::new(0.8, 0.0, 0.0);
+        assert!(world.run_system_once(cast(beside, Vec3::splat(0.2))).unwrap().is_none());
+        let hit = world
+            .run_system_once(cast(beside, Vec3::new(1.0, 0.1, 0.5)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.entity, post);
+        assert!((hit.distance - 4.4).abs() < 1e-5);
+        assert_eq!(hit.normal, Vec3::Z);
+    }
+
+    #[test]
+    fn overlaps_measure_to_the_box_and_walls_block_line_of_sight() {
+        let mut world = World::new();
+        let target = world
+            .spawn((
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::from_translation(Vec3::new(3.0, 0.0, 0.0)),
+            ))
+            .id();
+        let wall = world
+            .spawn((
+                Collider::cuboid(Vec3::new(0.1, 2.0, 2.0)),
+                GlobalTransform::from_translation(Vec3::new(1.5, 0.0, 0.0)),
+            ))
+            .id();
+
+        let overlaps = world
+            .run_system_once(|cw: CollisionWorld| cw.overlap_sphere(Vec3::ZERO, 2.6, &[]))
+            .unwrap();
+        let found: Vec<(Entity, f32)> = overlaps.iter().map(|o| (o.entity, o.distance)).collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, wall);
+        assert_eq!(found[1], (target, 2.5));
+
+        let sees = |ignore: Vec<Entity>| {
+            move |cw: CollisionWorld| cw.line_of_sight(Vec3::ZERO, target, &ignore)
+        };
+        assert!(!world.run_system_once(sees(vec![])).unwrap());
+        assert!(world.run_system_once(sees(vec![wall])).unwrap());
+    }
+
+    #[test]
+    fn casts_are_logged_only_while_recording() {
+        let mut world = World::new();
+        world.spawn((
+            Collider::cuboid(Vec3::splat(0.5)),
+            GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -3.0)),
+        ));
+        world.init_resource::<CastLog>();
+        let cast = |cw: CollisionWorld| {
+            cw.ray_cast(Vec3::ZERO, Vec3::NEG_Z, 10.0, &[]);
+            cw.ray_cast(Vec3::ZERO, Vec3::X, 10.0, &[]);
+        };
+
+        let _ = world.run_system_once(cast);
+        assert!(world.resource::<CastLog>().take().is_empty());
+
+        world.resource_mut::<CastLog>().recording = true;
+        let _ = world.run_system_once(cast);
+        let casts = world.resource::<CastLog>().take();
+        assert_eq!(casts.len(), 2);
+        assert_eq!(casts[0].end(), Vec3::new(0.0, 0.0, -2.5));
+        assert_eq!(casts[0].hit.unwrap().normal, Vec3::Z);
+        assert_eq!((casts[1].hit, casts[1].end()), (None, Vec3::X * 10.0));
+        assert!(world.resource::<CastLog>().take().is_empty());
+    }
+}
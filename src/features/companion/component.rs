@@ -0,0 +1,105 @@
+// src/features/companion/component.rs
+use bevy::prelude::*;
+
+/// Companion tunables (world units / seconds).
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct CompanionSettings {
+    /// Spawn one next to each new player.
+    pub enabled: bool,
+    pub name: String,
+    /// Running speed; a little faster than the player so it can catch up.
+    pub speed: f32,
+    /// Distance kept from the owner while following.
+    pub follow_distance: f32,
+    /// Further behind than this, it reappears next to the owner.
+    pub teleport_distance: f32,
+    /// How far from the companion a pickup can be to be fetched.
+    pub fetch_range: f32,
+    /// Distance at which a pickup is grabbed.
+    pub grab_distance: f32,
+}
+
+impl Default for CompanionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            name: "Biscuit".into(),
+            speed: 6.5,
+            follow_distance: 2.0,
+            teleport_distance: 25.0,
+            fetch_range: 15.0,
+            grab_distance: 0.6,
+        }
+    }
+}
+
+/// What the companion was last told to do.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CompanionCommand {
+    /// Stay close to the owner.
+    #[default]
+    Follow,
+    /// Wait where it is.
+    Stay,
+    /// Run to `item` and bring it back to the owner.
+    Fetch { item: Entity, carrying: bool },
+}
+
+impl CompanionCommand {
+    /// Interaction prompt offering the next command ("Tell Biscuit to stay").
+    pub fn prompt(self, name: &str) -> String {
+        match self {
+            Self::Follow => format!("Tell {name} to stay"),
+            Self::Stay => format!("Send {name} to fetch"),
+            Self::Fetch { .. } => format!("Call {name} back"),
+        }
+    }
+}
+
+/// A pet following `owner` around.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Companion {
+    pub owner: Entity,
+    pub command: CompanionCommand,
+}
+
+/// Already has a companion (players get one).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HasCompanion(pub Entity);
+
+/// Shared companion mesh and material.
+#[derive(Resource, Debug, Clone)]
+pub struct CompanionAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Half size of the placeholder companion body.
+pub const COMPANION_HALF_EXTENTS: Vec3 = Vec3::new(0.25, 0.25, 0.4);
+
+/// Velocity moving `from` towards `to` at `speed`, stopping `stop_distance` short
+/// (exactly, within one tick of `dt`). Horizontal only.
+pub fn seek(from: Vec3, to: Vec3, stop_distance: f32, speed: f32, dt: f32) -> Vec3 {
+    let offset = (to - from).with_y(0.0);
+    let gap = offset.length() - stop_distance;
+    if gap <= 0.0 || dt <= 0.0 {
+        return Vec3::ZERO;
+    }
+    offset.normalize() * speed.min(gap / dt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seek_arrives_at_the_stop_distance() {
+        let far = seek(Vec3::ZERO, Vec3::new(10.0, 3.0, 0.0), 2.0, 5.0, 0.1);
+        assert_eq!(far, Vec3::X * 5.0, "full speed, horizontal only");
+
+        let close = seek(Vec3::ZERO, Vec3::X * 2.2, 2.0, 5.0, 0.1);
+        assert!((close - Vec3::X * 2.0).length() < 1e-5, "covers the last 0.2 exactly");
+
+        assert_eq!(seek(Vec3::ZERO, Vec3::X * 1.5, 2.0, 5.0, 0.1), Vec3::ZERO);
+    }
+}
@@ -0,0 +1,47 @@
+// src/features/companion/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::interaction::systems::Interacted;
+
+pub mod component;
+pub mod systems;
+
+/// Companion (pet) following the player.
+///
+/// Scope:
+/// - every new player gets a `Companion` beside them (`CompanionSettings`), owned by
+///   that player (`Owner`: it is on the player's side)
+/// - follow: keeps `follow_distance` from the owner, steering `Velocity` like NPCs;
+///   left too far behind it reappears next to the owner
+/// - commands through the interaction key on the companion: follow -> stay ->
+///   fetch the nearest pickup (carried back and dropped at the owner's feet) -> follow
+///
+/// Design constraints:
+/// - Steering goes straight for the goal; there is no navigation mesh yet, so the
+///   teleport is what keeps it from getting stuck for good.
+pub struct CompanionPlugin;
+
+impl Plugin for CompanionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::CompanionSettings>();
+
+        app.add_message::<Interacted>();
+
+        app.add_systems(Startup, systems::setup_companion_assets);
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_companions,
+                systems::command_companions,
+                systems::steer_companions,
+            )
+                .chain()
+                .in_set(AppSet::ReadIntent),
+        );
+        app.add_systems(
+            Update,
+            systems::update_companion_prompts.after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,364 @@
+// src/features/companion/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    COMPANION_HALF_EXTENTS, Companion, CompanionAssets, CompanionCommand, CompanionSettings,
+    HasCompanion, seek,
+};
+use crate::app::Owner;
+use crate::features::dungeon::component::DungeonPickup;
+use crate::features::interaction::component::Interactable;
+use crate::features::interaction::systems::Interacted;
+use crate::features::movement::component::Velocity;
+use crate::features::player::component::Player;
+
+/// Half height assumed for owners (the player box).
+const OWNER_HALF_HEIGHT: f32 = 0.5;
+
+/// Slack on "arrived next to the owner" (seek stops exactly at the follow distance).
+const ARRIVE_SLACK: f32 = 0.05;
+
+/// Interaction reach: inside the follow distance, so a companion at heel doesn't
+/// steal the focus from whatever the owner walks up to.
+const COMMAND_REACH: f32 = 1.5;
+
+/// Height above the companion's center a carried item rides at.
+const CARRY_HEIGHT: f32 = 0.4;
+
+/// Center height of a companion standing on the owner's ground.
+fn ground_level(owner: Vec3) -> f32 {
+    owner.y - OWNER_HALF_HEIGHT + COMPANION_HALF_EXTENTS.y
+}
+
+fn horizontal_distance(a: Vec3, b: Vec3) -> f32 {
+    (a - b).with_y(0.0).length()
+}
+
+/// Startup: companion mesh and material.
+pub fn setup_companion_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(CompanionAssets {
+        mesh: meshes.add(Cuboid::from_size(COMPANION_HALF_EXTENTS * 2.0)),
+        material: materials.add(Color::srgb_u8(200, 150, 90)),
+    });
+}
+
+/// FixedUpdate: every new player gets a companion at their side.
+#[expect(clippy::type_complexity)]
+pub fn grant_companions(
+    mut commands: Commands,
+    settings: Res<CompanionSettings>,
+    assets: Option<Res<CompanionAssets>>,
+    q_players: Query<(Entity, &Transform), (Added<Player>, Without<HasCompanion>)>,
+) {
+    if !settings.enabled {
+        return;
+    }
+    for (player, transform) in &q_players {
+        let beside = transform.translation + transform.rotation * Vec3::new(1.0, 0.0, 1.0);
+        let command = CompanionCommand::default();
+        let mut companion = commands.spawn((
+            Companion {
+                owner: player,
+                command,
+            },
+            Owner(player),
+            Interactable {
+                reach: COMMAND_REACH,
+                ..Interactable::new(command.prompt(&settings.name))
+            },
+            Velocity::default(),
+            Transform::from_translation(beside.with_y(ground_level(transform.translation)))
+                .with_rotation(transform.rotation),
+            Name::new(settings.name.clone()),
+        ));
+        if let Some(assets) = &assets {
+            companion.insert((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+            ));
+        }
+        let companion = companion.id();
+        commands.entity(player).insert(HasCompanion(companion));
+    }
+}
+
+/// FixedUpdate: the owner interacting with their companion gives the next command:
+/// follow -> stay -> fetch the nearest pickup in range (or follow again if there's
+/// none) -> follow.
+pub fn command_companions(
+    settings: Res<CompanionSettings>,
+    mut interacted: MessageReader<Interacted>,
+    mut q_companions: Query<(&mut Companion, &Transform)>,
+    q_pickups: Query<(Entity, &GlobalTransform), With<DungeonPickup>>,
+) {
+    for ev in interacted.read() {
+        let Ok((mut companion, transform)) = q_companions.get_mut(ev.target) else {
+            continue;
+        };
+        if companion.owner != ev.actor {
+            continue;
+        }
+        companion.command = match companion.command {
+            CompanionCommand::Follow => CompanionCommand::Stay,
+            CompanionCommand::Stay => q_pickups
+                .iter()
+                .map(|(item, at)| (item, at.translation().distance(transform.translation)))
+                .filter(|(_, distance)| *distance <= settings.fetch_range)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(CompanionCommand::Follow, |(item, _)| CompanionCommand::Fetch {
+                    item,
+                    carrying: false,
+                }),
+            CompanionCommand::Fetch { .. } => CompanionCommand::Follow,
+        };
+    }
+}
+
+/// FixedUpdate (before integration): follow / wait / fetch by steering `Velocity`.
+///
+/// - Left further than `teleport_distance` behind (unless staying), the companion
+///   reappears behind its owner.
+/// - Fetching: run to the item, grab it, and drop it at the owner's feet, where the
+///   owner collects it like any pickup; if someone else takes it first, follow.
+pub fn steer_companions(
+    time: Res<Time<Fixed>>,
+    settings: Res<CompanionSettings>,
+    mut q_companions: Query<(&mut Companion, &mut Transform, &mut Velocity)>,
+    q_owners: Query<&Transform, (Without<Companion>, Without<DungeonPickup>)>,
+    mut q_items: Query<&mut Transform, (With<DungeonPickup>, Without<Companion>)>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut companion, mut transform, mut velocity) in &mut q_companions {
+        velocity.0 = Vec3::ZERO;
+        let Ok(owner) = q_owners.get(companion.owner) else {
+            continue;
+        };
+        let owner_pos = owner.translation;
+
+        if companion.command != CompanionCommand::Stay
+            && transform.translation.distance(owner_pos) > settings.teleport_distance
+        {
+            let behind = owner_pos + owner.rotation * Vec3::Z * settings.follow_distance;
+            transform.translation = behind.with_y(ground_level(owner_pos));
+        }
+
+        if let CompanionCommand::Fetch { item, carrying } = companion.command {
+            match q_items.get_mut(item) {
+                Err(_) => companion.command = CompanionCommand::Follow,
+                Ok(item_tr) if !carrying => {
+                    if horizontal_distance(transform.translation, item_tr.translation)
+                        <= settings.grab_distance
+                    {
+                        companion.command = CompanionCommand::Fetch {
+                            item,
+                            carrying: true,
+                        };
+                    }
+                }
+                Ok(mut item_tr) => {
+                    if horizontal_distance(transform.translation, owner_pos)
+                        <= settings.follow_distance + ARRIVE_SLACK
+                    {
+                        item_tr.translation = owner_pos;
+                        companion.command = CompanionCommand::Follow;
+                    }
+                }
+            }
+        }
+
+        let goal = match companion.command {
+            CompanionCommand::Follow
+            | CompanionCommand::Fetch {
+                carrying: true, ..
+            } => Some((owner_pos, settings.follow_distance)),
+            CompanionCommand::Fetch { item, .. } => {
+                q_items.get(item).ok().map(|item_tr| (item_tr.translation, 0.0))
+            }
+            CompanionCommand::Stay => None,
+        };
+        if let Some((to, stop_distance)) = goal {
+            velocity.0 = seek(transform.translation, to, stop_distance, settings.speed, dt);
+        }
+        if velocity.0.length_squared() > 1e-6 {
+            transform.rotation = Transform::IDENTITY.looking_to(velocity.0, Vec3::Y).rotation;
+        }
+
+        // Carried items ride along where integration is about to put the companion.
+        if let CompanionCommand::Fetch {
+            item,
+            carrying: true,
+        } = companion.command
+            && let Ok(mut item_tr) = q_items.get_mut(item)
+        {
+            item_tr.translation = transform.translation + velocity.0 * dt + Vec3::Y * CARRY_HEIGHT;
+        }
+    }
+}
+
+/// Update: the interaction prompt offers the next command.
+pub fn update_companion_prompts(
+    settings: Res<CompanionSettings>,
+    mut q_companions: Query<(&Companion, &mut Interactable), Changed<Companion>>,
+) {
+    for (companion, mut interactable) in &mut q_companions {
+        let prompt = companion.command.prompt(&settings.name);
+        if interactable.prompt != prompt {
+            interactable.prompt = prompt;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::movement::systems::integrate_velocity;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<CompanionSettings>();
+        world.init_resource::<Messages<Interacted>>();
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(0.1));
+        world.insert_resource(fixed);
+        world
+    }
+
+    /// Player at the origin (standing on y = 0) with their companion.
+    fn player_and_companion(world: &mut World) -> (Entity, Entity) {
+        let player = world.spawn((Player, Transform::from_xyz(0.0, 0.5, 0.0))).id();
+        let _ = world.run_system_once(grant_companions);
+        let companion = world.get::<HasCompanion>(player).unwrap().0;
+        (player, companion)
+    }
+
+    fn command_of(world: &World, companion: Entity) -> CompanionCommand {
+        world.get::<Companion>(companion).unwrap().command
+    }
+
+    fn interact(world: &mut World, actor: Entity, target: Entity) {
+        world.write_message(Interacted { actor, target });
+        let _ = world.run_system_once(command_companions);
+        world.resource_mut::<Messages<Interacted>>().update();
+        world.resource_mut::<Messages<Interacted>>().update();
+    }
+
+    fn step(world: &mut World) {
+        let _ = world.run_system_once(steer_companions);
+        let _ = world.run_system_once(integrate_velocity);
+    }
+
+    #[test]
+    fn players_get_a_companion_on_their_ground() {
+        let mut world = setup_world();
+        let (player, companion) = player_and_companion(&mut world);
+
+        let state = *world.get::<Companion>(companion).unwrap();
+        assert_eq!(state.owner, player);
+        assert_eq!(state.command, CompanionCommand::Follow);
+        let position = world.get::<Transform>(companion).unwrap().translation;
+        assert_eq!(position, Vec3::new(1.0, COMPANION_HALF_EXTENTS.y, 1.0));
+        assert_eq!(
+            world.get::<Interactable>(companion).unwrap().prompt,
+            "Tell Biscuit to stay"
+        );
+    }
+
+    #[test]
+    fn follows_at_a_distance_and_teleports_when_left_behind() {
+        let mut world = setup_world();
+        let (player, companion) = player_and_companion(&mut world);
+        let speed = world.resource::<CompanionSettings>().speed;
+
+        world.get_mut::<Transform>(player).unwrap().translation.x = 10.0;
+        step(&mut world);
+        assert!((world.get::<Velocity>(companion).unwrap().0.length() - speed).abs() < 1e-4);
+
+        for _ in 0..30 {
+            step(&mut world);
+        }
+        let position = world.get::<Transform>(companion).unwrap().translation;
+        let gap = horizontal_distance(position, Vec3::new(10.0, 0.5, 0.0));
+        assert!((gap - 2.0).abs() < 1e-3, "stops at the follow distance");
+
+        world.get_mut::<Transform>(player).unwrap().translation.x = 100.0;
+        step(&mut world);
+        let position = world.get::<Transform>(companion).unwrap().translation;
+        assert!(horizontal_distance(position, Vec3::new(100.0, 0.5, 0.0)) <= 2.0 + 1e-3);
+    }
+
+    #[test]
+    fn interacting_cycles_stay_fetch_and_follow() {
+        let mut world = setup_world();
+        let (player, companion) = player_and_companion(&mut world);
+
+        interact(&mut world, player, companion);
+        assert_eq!(command_of(&world, companion), CompanionCommand::Stay);
+        // Nothing to fetch: back to following.
+        interact(&mut world, player, companion);
+        assert_eq!(command_of(&world, companion), CompanionCommand::Follow);
+
+        let pickup = DungeonPickup {
+            item: "coin".into(),
+            amount: 1,
+        };
+        world.spawn((pickup.clone(), GlobalTransform::from_xyz(40.0, 0.0, 0.0)));
+        let near = world
+            .spawn((pickup, GlobalTransform::from_xyz(5.0, 0.0, 0.0)))
+            .id();
+        interact(&mut world, player, companion);
+        interact(&mut world, player, companion);
+        assert_eq!(
+            command_of(&world, companion),
+            CompanionCommand::Fetch {
+                item: near,
+                carrying: false
+            }
+        );
+
+        // Only the owner gives commands.
+        let stranger = world.spawn_empty().id();
+        interact(&mut world, stranger, companion);
+        assert!(matches!(command_of(&world, companion), CompanionCommand::Fetch { .. }));
+        interact(&mut world, player, companion);
+        assert_eq!(command_of(&world, companion), CompanionCommand::Follow);
+    }
+
+    #[test]
+    fn fetched_items_are_dropped_at_the_owners_feet() {
+        let mut world = setup_world();
+        let (player, companion) = player_and_companion(&mut world);
+        let item = world
+            .spawn((
+                DungeonPickup {
+                    item: "coin".into(),
+                    amount: 1,
+                },
+                Transform::from_xyz(6.0, 0.2, -4.0),
+            ))
+            .id();
+        world.get_mut::<Companion>(companion).unwrap().command = CompanionCommand::Fetch {
+            item,
+            carrying: false,
+        };
+
+        for _ in 0..40 {
+            step(&mut world);
+        }
+
+        assert_eq!(command_of(&world, companion), CompanionCommand::Follow);
+        assert_eq!(
+            world.get::<Transform>(item).unwrap().translation,
+            world.get::<Transform>(player).unwrap().translation
+        );
+    }
+}
@@ -0,0 +1,54 @@
+// src/features/console/component.rs
+use std::collections::{BTreeMap, VecDeque};
+
+use bevy::prelude::*;
+
+/// Lines of output kept (oldest dropped first).
+pub const MAX_CONSOLE_LINES: usize = 40;
+
+/// Developer console state.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Console {
+    pub open: bool,
+    /// The line being typed.
+    pub input: String,
+    /// Output, oldest first.
+    pub lines: VecDeque<String>,
+}
+
+impl Console {
+    /// Add a line of output.
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.lines.push_back(line.into());
+        while self.lines.len() > MAX_CONSOLE_LINES {
+            self.lines.pop_front();
+        }
+    }
+}
+
+/// Commands features registered: name -> one-line usage, listed by `help`.
+///
+/// Features register theirs while building (`register`) and handle them by reading
+/// `ConsoleCommand` messages with their name.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConsoleCommands(pub BTreeMap<&'static str, &'static str>);
+
+impl ConsoleCommands {
+    pub fn register(&mut self, name: &'static str, usage: &'static str) {
+        self.0.insert(name, usage);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+}
+
+/// Opens / closes the console.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsoleKeybinding(pub KeyCode);
+
+impl Default for ConsoleKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::Backquote)
+    }
+}
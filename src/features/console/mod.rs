@@ -0,0 +1,50 @@
+// src/features/console/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, InputContext, in_input_context};
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// The console toggles in play, in menus and while it has the keyboard itself.
+const CONSOLE_TOGGLE: &[InputContext] = &[
+    InputContext::Gameplay,
+    InputContext::Menu,
+    InputContext::Console,
+];
+
+/// Developer console (dev tool).
+///
+/// Scope:
+/// - opened / closed with `ConsoleKeybinding` (`` ` ``, Escape closes); owns the
+///   keyboard (`InputContext::Console`) while open
+/// - one input line; Enter runs it: `help` lists the registered commands, `clear`
+///   empties the output
+/// - features register commands with `register_console_command` and handle the
+///   `ConsoleCommand` messages carrying their name, printing replies to `Console`
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::Console>();
+        app.init_resource::<component::ConsoleCommands>();
+        app.init_resource::<component::ConsoleKeybinding>();
+        app.add_message::<systems::ConsoleCommand>();
+        app.add_message::<bevy::input::keyboard::KeyboardInput>();
+        systems::register_console_command(app, "help", "help: list commands");
+        systems::register_console_command(app, "clear", "clear: clear the output");
+
+        app.add_systems(Startup, ui::spawn_console_panel);
+        app.add_systems(
+            Update,
+            (
+                systems::toggle_console.run_if(in_input_context(CONSOLE_TOGGLE)),
+                systems::edit_console_input.run_if(in_input_context(&[InputContext::Console])),
+            )
+                .chain()
+                .in_set(AppSet::Input),
+        );
+        app.add_systems(Update, ui::update_console_panel.after(AppSet::Input));
+    }
+}
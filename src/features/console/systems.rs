@@ -0,0 +1,179 @@
+// src/features/console/systems.rs
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::component::{Console, ConsoleCommands, ConsoleKeybinding};
+use crate::app::{InputContext, InputContextStack};
+
+/// A submitted console line, for the feature that registered `name`.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleCommand {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+impl ConsoleCommand {
+    /// Split a line on whitespace: the first word is the command.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut words = line.split_whitespace().map(str::to_owned);
+        Some(Self {
+            name: words.next()?,
+            args: words.collect(),
+        })
+    }
+}
+
+/// Register a console command from a plugin (idempotent).
+pub fn register_console_command(app: &mut App, name: &'static str, usage: &'static str) {
+    app.init_resource::<ConsoleCommands>();
+    app.world_mut()
+        .resource_mut::<ConsoleCommands>()
+        .register(name, usage);
+}
+
+/// Update: the console key opens / closes the console, which owns the keyboard
+/// while open.
+pub fn toggle_console(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<ConsoleKeybinding>,
+    mut console: ResMut<Console>,
+    mut stack: ResMut<InputContextStack>,
+) {
+    let close = console.open && keyboard.just_pressed(KeyCode::Escape);
+    if !keyboard.just_pressed(binding.0) && !close {
+        return;
+    }
+    console.open = !console.open;
+    if console.open {
+        stack.push(InputContext::Console);
+    } else {
+        stack.pop(InputContext::Console);
+        console.input.clear();
+    }
+}
+
+/// Update (console open): type into the input line; Enter runs it.
+///
+/// `help` and `clear` are built in; other registered commands go out as
+/// `ConsoleCommand` messages.
+pub fn edit_console_input(
+    binding: Res<ConsoleKeybinding>,
+    commands: Res<ConsoleCommands>,
+    mut keys: MessageReader<KeyboardInput>,
+    mut console: ResMut<Console>,
+    mut submitted: MessageWriter<ConsoleCommand>,
+) {
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed || key.key_code == binding.0 {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Character(text) => console.input.push_str(text),
+            Key::Space => console.input.push(' '),
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                let Some(command) = ConsoleCommand::parse(&line) else {
+                    continue;
+                };
+                console.print(format!("> {}", line.trim()));
+                match command.name.as_str() {
+                    "help" => {
+                        let usage: Vec<String> = commands.0.values().map(|u| (*u).to_owned()).collect();
+                        for line in usage {
+                            console.print(line);
+                        }
+                    }
+                    "clear" => console.lines.clear(),
+                    name if commands.contains(name) => {
+                        submitted.write(command);
+                    }
+                    name => console.print(format!("unknown command `{name}` (try `help`)")),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<ConsoleKeybinding>();
+        world.init_resource::<Console>();
+        world.init_resource::<InputContextStack>();
+        world.init_resource::<Messages<KeyboardInput>>();
+        world.init_resource::<Messages<ConsoleCommand>>();
+        let mut commands = ConsoleCommands::default();
+        commands.register("net_sim", "net_sim on|off");
+        world.insert_resource(commands);
+        world
+    }
+
+    fn type_keys(world: &mut World, keys: &[Key]) {
+        let window = world.spawn_empty().id();
+        for key in keys {
+            world.write_message(KeyboardInput {
+                key_code: KeyCode::KeyA,
+                logical_key: key.clone(),
+                state: ButtonState::Pressed,
+                text: None,
+                repeat: false,
+                window,
+            });
+        }
+        let _ = world.run_system_once(edit_console_input);
+    }
+
+    #[test]
+    fn console_key_opens_and_escape_closes_with_the_input_context() {
+        let mut world = setup_world();
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Backquote);
+        let _ = world.run_system_once(toggle_console);
+        assert!(world.resource::<Console>().open);
+        assert_eq!(world.resource::<InputContextStack>().top(), InputContext::Console);
+
+        let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.clear();
+        keyboard.press(KeyCode::Escape);
+        let _ = world.run_system_once(toggle_console);
+        assert!(!world.resource::<Console>().open);
+        assert_eq!(world.resource::<InputContextStack>().top(), InputContext::Gameplay);
+    }
+
+    #[test]
+    fn registered_commands_are_sent_and_unknown_ones_reported() {
+        let mut world = setup_world();
+        let mut keys: Vec<Key> = "net_sim"
+            .chars()
+            .map(|c| Key::Character(c.to_string().into()))
+            .collect();
+        keys.extend([Key::Space, Key::Character("of".into()), Key::Character("x".into())]);
+        keys.extend([Key::Backspace, Key::Character("f".into()), Key::Enter]);
+        type_keys(&mut world, &keys);
+
+        let sent: Vec<ConsoleCommand> = world
+            .resource_mut::<Messages<ConsoleCommand>>()
+            .drain()
+            .collect();
+        assert_eq!(sent, vec![ConsoleCommand {
+            name: "net_sim".into(),
+            args: vec!["off".into()],
+        }]);
+
+        let bogus: Vec<Key> = "nope".chars().map(|c| Key::Character(c.to_string().into())).collect();
+        type_keys(&mut world, &[bogus, vec![Key::Enter]].concat());
+        let console = world.resource::<Console>();
+        assert!(console.lines.back().unwrap().contains("unknown command `nope`"));
+        assert!(console.input.is_empty());
+    }
+}
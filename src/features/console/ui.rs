@@ -0,0 +1,51 @@
+// src/features/console/ui.rs
+use bevy::prelude::*;
+
+use super::component::Console;
+
+/// Console panel (hidden until opened).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ConsolePanel;
+
+/// Startup: spawn the panel across the top of the screen.
+pub fn spawn_console_panel(mut commands: Commands) {
+    commands.spawn((
+        ConsolePanel,
+        Text::new(""),
+        TextFont::from_font_size(14.0),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            top: Val::Px(0.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.9)),
+        GlobalZIndex(100),
+        Pickable::IGNORE,
+        Visibility::Hidden,
+        Name::new("ConsolePanel"),
+    ));
+}
+
+/// Update: panel visibility, output and the input line.
+pub fn update_console_panel(
+    console: Res<Console>,
+    mut q_panel: Query<(&mut Text, &mut Visibility), With<ConsolePanel>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    for (mut text, mut vis) in &mut q_panel {
+        *vis = if console.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        let mut shown: Vec<&str> = console.lines.iter().map(String::as_str).collect();
+        let prompt = format!("> {}_", console.input);
+        shown.push(&prompt);
+        text.0 = shown.join("\n");
+    }
+}
@@ -0,0 +1,109 @@
+// src/features/culling/component.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+/// Chunk grid and view ranges for chunk culling.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ChunkCulling {
+    /// Chunk edge length on X / Z (world units).
+    pub chunk_size: f32,
+    /// Chunks whose nearest point is farther than this from the camera are hidden.
+    pub default_range: f32,
+    /// Per-chunk overrides of `default_range` (open fields, dense interiors).
+    pub ranges: HashMap<IVec2, f32>,
+    /// Cosmetic contents (`Cullable::despawn_when_far`) despawn beyond
+    /// `range * despawn_factor`.
+    pub despawn_factor: f32,
+}
+
+impl Default for ChunkCulling {
+    fn default() -> Self {
+        Self {
+            chunk_size: 16.0,
+            default_range: 80.0,
+            ranges: HashMap::new(),
+            despawn_factor: 2.0,
+        }
+    }
+}
+
+impl ChunkCulling {
+    pub fn chunk_of(&self, position: Vec3) -> IVec2 {
+        IVec2::new(
+            (position.x / self.chunk_size).floor() as i32,
+            (position.z / self.chunk_size).floor() as i32,
+        )
+    }
+
+    pub fn range_of(&self, chunk: IVec2) -> f32 {
+        self.ranges.get(&chunk).copied().unwrap_or(self.default_range)
+    }
+
+    /// Horizontal distance from `viewer` to the nearest point of `chunk`.
+    pub fn distance_to(&self, chunk: IVec2, viewer: Vec3) -> f32 {
+        let min = chunk.as_vec2() * self.chunk_size;
+        let max = min + Vec2::splat(self.chunk_size);
+        let p = Vec2::new(viewer.x, viewer.z);
+        p.clamp(min, max).distance(p)
+    }
+}
+
+/// Chunk content culled by camera distance (whole chunks at a time).
+///
+/// Fine-grained LOD inside visible chunks stays with Bevy's `VisibilityRange`;
+/// this only hides (or despawns) what is out of range altogether.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[require(Visibility)]
+pub struct Cullable {
+    /// Cosmetic content that may be dropped instead of hidden when far away.
+    pub despawn_when_far: bool,
+    /// Hidden by culling (only culling transitions touch `Visibility`).
+    pub culled: bool,
+}
+
+impl Cullable {
+    pub fn cosmetic() -> Self {
+        Self {
+            despawn_when_far: true,
+            culled: false,
+        }
+    }
+}
+
+/// The chunk a `Cullable` currently sits in.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkCoord(pub IVec2);
+
+/// Culling results of the last frame.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CullingStats {
+    pub chunks: usize,
+    pub visible_chunks: usize,
+    pub entities: usize,
+    /// Entities hidden this frame (not submitted for drawing).
+    pub hidden: usize,
+    /// Entities despawned this frame.
+    pub despawned: usize,
+}
+
+impl CullingStats {
+    /// Entities still drawn (upper bound on mesh draw calls from chunk content).
+    pub fn drawn(&self) -> usize {
+        self.entities - self.hidden - self.despawned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_distance_is_zero_inside_and_grows_outside() {
+        let culling = ChunkCulling::default();
+        let chunk = culling.chunk_of(Vec3::new(-3.0, 5.0, 20.0));
+        assert_eq!(chunk, IVec2::new(-1, 1));
+        assert_eq!(culling.distance_to(chunk, Vec3::new(-3.0, 0.0, 20.0)), 0.0);
+        assert_eq!(culling.distance_to(IVec2::new(2, 0), Vec3::new(0.0, 0.0, 8.0)), 32.0);
+    }
+}
@@ -0,0 +1,225 @@
+// src/features/culling/effects.rs
+use bevy::camera::primitives::{Frustum, Sphere};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::app::{DespawnQueue, Despawning};
+use crate::features::camera::component::OffscreenCamera;
+
+/// How much of a cosmetic effect to spawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectDetail {
+    Full,
+    /// Far away: a fraction of the particles (`EffectBudget::simplified_fraction`).
+    Simplified,
+    /// Off-screen or out of reach: nothing.
+    Skipped,
+}
+
+/// Spawn policy for cosmetic effects (particles, flashes, debris) around the main
+/// camera.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct EffectBudget {
+    /// Effects farther than this from the camera are skipped.
+    pub max_distance: f32,
+    /// Effects farther than this spawn simplified.
+    pub simplify_distance: f32,
+    /// Share of the particles a simplified effect keeps (at least one).
+    pub simplified_fraction: f32,
+    /// Live `CosmeticEffect` entities; above this the farthest despawn first.
+    pub max_live: usize,
+}
+
+impl Default for EffectBudget {
+    fn default() -> Self {
+        Self {
+            max_distance: 60.0,
+            simplify_distance: 25.0,
+            simplified_fraction: 0.25,
+            max_live: 400,
+        }
+    }
+}
+
+impl EffectBudget {
+    /// Detail for an effect at `position` reaching `radius`, seen from `viewer`;
+    /// without a `frustum` only distance counts.
+    pub fn detail(
+        &self,
+        viewer: Vec3,
+        frustum: Option<&Frustum>,
+        position: Vec3,
+        radius: f32,
+    ) -> EffectDetail {
+        let distance = (viewer.distance(position) - radius).max(0.0);
+        let sphere = Sphere {
+            center: position.into(),
+            radius,
+        };
+        if distance > self.max_distance || frustum.is_some_and(|f| !f.intersects_sphere(&sphere, true)) {
+            EffectDetail::Skipped
+        } else if distance > self.simplify_distance {
+            EffectDetail::Simplified
+        } else {
+            EffectDetail::Full
+        }
+    }
+
+    /// Particles to spawn out of `count` at `detail`.
+    pub fn count(&self, detail: EffectDetail, count: u32) -> u32 {
+        match detail {
+            EffectDetail::Full => count,
+            EffectDetail::Simplified if count == 0 => 0,
+            EffectDetail::Simplified => ((count as f32 * self.simplified_fraction).ceil() as u32).clamp(1, count),
+            EffectDetail::Skipped => 0,
+        }
+    }
+}
+
+/// Cosmetic effect decisions since startup (shown in the diagnostics panel).
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EffectStats {
+    pub full: u64,
+    pub simplified: u64,
+    pub skipped: u64,
+    /// Live effects despawned early to stay within `EffectBudget::max_live`.
+    pub evicted: u64,
+}
+
+impl EffectStats {
+    pub fn record(&mut self, detail: EffectDetail) {
+        match detail {
+            EffectDetail::Full => self.full += 1,
+            EffectDetail::Simplified => self.simplified += 1,
+            EffectDetail::Skipped => self.skipped += 1,
+        }
+    }
+}
+
+/// Purely cosmetic entity: may be despawned early when too many are alive.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CosmeticEffect;
+
+/// What effect spawners ask before spawning: how much to spawn where.
+#[derive(SystemParam)]
+#[expect(clippy::type_complexity)]
+pub struct EffectSpawns<'w, 's> {
+    budget: Res<'w, EffectBudget>,
+    stats: ResMut<'w, EffectStats>,
+    q_camera: Query<
+        'w,
+        's,
+        (&'static Camera, &'static GlobalTransform, Option<&'static Frustum>),
+        (With<Camera3d>, Without<OffscreenCamera>),
+    >,
+}
+
+impl EffectSpawns<'_, '_> {
+    /// Detail for an effect at `position` reaching `radius` (recorded in
+    /// `EffectStats`). Without an active camera everything spawns in full.
+    pub fn detail(&mut self, position: Vec3, radius: f32) -> EffectDetail {
+        let detail = self
+            .q_camera
+            .iter()
+            .find(|(camera, ..)| camera.is_active)
+            .map_or(EffectDetail::Full, |(_, tr, frustum)| {
+                self.budget.detail(tr.translation(), frustum, position, radius)
+            });
+        self.stats.record(detail);
+        detail
+    }
+
+    /// Particles to spawn out of `count` at `detail`.
+    pub fn count(&self, detail: EffectDetail, count: u32) -> u32 {
+        self.budget.count(detail, count)
+    }
+}
+
+/// PostUpdate: above `max_live` cosmetic effects, despawn the ones farthest from
+/// the camera first, so a burst far away never crowds out what the player sees.
+#[expect(clippy::type_complexity)]
+pub fn evict_far_effects(
+    mut despawns: DespawnQueue,
+    budget: Res<EffectBudget>,
+    mut stats: ResMut<EffectStats>,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_effects: Query<(Entity, &GlobalTransform), (With<CosmeticEffect>, Without<Despawning>)>,
+) {
+    let excess = q_effects.iter().count().saturating_sub(budget.max_live);
+    if excess == 0 {
+        return;
+    }
+    let viewer = q_camera
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .map_or(Vec3::ZERO, |(_, tr)| tr.translation());
+
+    let mut effects: Vec<(Entity, f32)> = q_effects
+        .iter()
+        .map(|(entity, tr)| (entity, tr.translation().distance_squared(viewer)))
+        .collect();
+    effects.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for &(entity, _) in &effects[..excess] {
+        despawns.despawn(entity);
+    }
+    stats.evicted += excess as u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// Camera at the origin looking down -Z.
+    fn frustum() -> Frustum {
+        let projection = Mat4::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 1000.0);
+        Frustum::from_clip_from_world(&projection)
+    }
+
+    #[test]
+    fn effects_behind_or_far_skip_and_mid_range_simplify() {
+        let budget = EffectBudget::default();
+        let frustum = frustum();
+        let detail = |at: Vec3| budget.detail(Vec3::ZERO, Some(&frustum), at, 0.5);
+
+        assert_eq!(detail(Vec3::new(0.0, 0.0, -10.0)), EffectDetail::Full);
+        assert_eq!(detail(Vec3::new(0.0, 0.0, -40.0)), EffectDetail::Simplified);
+        assert_eq!(detail(Vec3::new(0.0, 0.0, -90.0)), EffectDetail::Skipped);
+        assert_eq!(detail(Vec3::new(0.0, 0.0, 10.0)), EffectDetail::Skipped, "behind the camera");
+        assert_eq!(
+            budget.detail(Vec3::ZERO, None, Vec3::new(0.0, 0.0, 10.0), 0.5),
+            EffectDetail::Full,
+            "no frustum: distance only"
+        );
+
+        assert_eq!(budget.count(EffectDetail::Full, 12), 12);
+        assert_eq!(budget.count(EffectDetail::Simplified, 12), 3);
+        assert_eq!(budget.count(EffectDetail::Simplified, 2), 1);
+        assert_eq!(budget.count(EffectDetail::Skipped, 12), 0);
+    }
+
+    #[test]
+    fn over_budget_the_farthest_effects_go_first() {
+        let mut world = World::new();
+        world.insert_resource(EffectBudget {
+            max_live: 2,
+            ..default()
+        });
+        world.init_resource::<EffectStats>();
+        world.spawn((Camera3d::default(), GlobalTransform::IDENTITY));
+        let at = |z: f32| (CosmeticEffect, GlobalTransform::from_xyz(0.0, 0.0, z));
+        let near = world.spawn(at(-2.0)).id();
+        let far = world.spawn(at(-50.0)).id();
+        let mid = world.spawn(at(-10.0)).id();
+
+        let _ = world.run_system_once(evict_far_effects);
+
+        assert!(world.entity(far).contains::<Despawning>());
+        assert!(!world.entity(near).contains::<Despawning>());
+        assert!(!world.entity(mid).contains::<Despawning>());
+        assert_eq!(world.resource::<EffectStats>().evicted, 1);
+
+        let _ = world.run_system_once(evict_far_effects);
+        assert_eq!(world.resource::<EffectStats>().evicted, 1, "already on its way out");
+    }
+}
@@ -0,0 +1,47 @@
+// src/features/culling/mod.rs
+use bevy::camera::visibility::VisibilitySystems;
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+
+pub mod component;
+pub mod effects;
+pub mod systems;
+
+/// Chunk culling and the cosmetic effect budget.
+///
+/// Scope:
+/// - `Cullable` content is bucketed into `ChunkCulling::chunk_size` chunks
+/// - chunks farther from the main camera than their range (per-chunk overrides in
+///   `ChunkCulling::ranges`) have their contents hidden; cosmetic content despawns
+///   beyond `despawn_factor` times the range
+/// - `CullingStats` reports chunks / entities drawn each frame (see
+///   `benches/culling.rs` for the reduction on a large scene)
+/// - cosmetic effects (particles, flashes) ask `EffectSpawns` before spawning:
+///   skipped outside the camera frustum or past `EffectBudget::max_distance`,
+///   simplified past `simplify_distance`; above `max_live` live `CosmeticEffect`s
+///   the farthest despawn first. `EffectStats` counts the work avoided (shown in
+///   the diagnostics panel)
+///
+/// Design constraints:
+/// - Coarse only: per-mesh LOD inside visible chunks is Bevy's `VisibilityRange`.
+/// - Culling only writes `Visibility` when an entity crosses its range, so other
+///   features hiding the same entity keep control in between.
+pub struct CullingPlugin;
+
+impl Plugin for CullingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::ChunkCulling>();
+        app.init_resource::<component::CullingStats>();
+        app.init_resource::<effects::EffectBudget>();
+        app.init_resource::<effects::EffectStats>();
+
+        app.add_systems(
+            PostUpdate,
+            (systems::assign_chunks, systems::cull_chunks)
+                .chain()
+                .after(TransformSystems::Propagate)
+                .before(VisibilitySystems::VisibilityPropagate),
+        );
+        app.add_systems(PostUpdate, effects::evict_far_effects);
+    }
+}
@@ -0,0 +1,114 @@
+// src/features/culling/systems.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::component::{ChunkCoord, ChunkCulling, Cullable, CullingStats};
+use crate::app::DespawnQueue;
+use crate::features::camera::component::OffscreenCamera;
+
+/// Update: keep each cullable's `ChunkCoord` current (new and moved entities only).
+#[expect(clippy::type_complexity)]
+pub fn assign_chunks(
+    mut commands: Commands,
+    culling: Res<ChunkCulling>,
+    mut q_moved: Query<
+        (Entity, &GlobalTransform, Option<&mut ChunkCoord>),
+        (With<Cullable>, Changed<GlobalTransform>),
+    >,
+) {
+    for (entity, transform, coord) in &mut q_moved {
+        let chunk = culling.chunk_of(transform.translation());
+        match coord {
+            Some(mut coord) if coord.0 != chunk => coord.0 = chunk,
+            Some(_) => {}
+            None => {
+                commands.entity(entity).insert(ChunkCoord(chunk));
+            }
+        }
+    }
+}
+
+/// Update: hide the contents of out-of-range chunks (show them again once in
+/// range), despawn far cosmetic content, and record `CullingStats`.
+pub fn cull_chunks(
+    mut despawns: DespawnQueue,
+    culling: Res<ChunkCulling>,
+    mut stats: ResMut<CullingStats>,
+    q_camera: Query<&GlobalTransform, (With<Camera3d>, Without<OffscreenCamera>)>,
+    mut q_contents: Query<(Entity, &ChunkCoord, &mut Cullable, &mut Visibility)>,
+) {
+    let Some(viewer) = q_camera.iter().next().map(GlobalTransform::translation) else {
+        return;
+    };
+    // (in range, within despawn range) per chunk, computed once per frame.
+    let mut chunks: HashMap<IVec2, (bool, bool)> = HashMap::new();
+    let mut frame = CullingStats::default();
+
+    for (entity, coord, mut cullable, mut visibility) in &mut q_contents {
+        let (in_range, keep) = *chunks.entry(coord.0).or_insert_with(|| {
+            let distance = culling.distance_to(coord.0, viewer);
+            let range = culling.range_of(coord.0);
+            (distance <= range, distance <= range * culling.despawn_factor)
+        });
+        frame.entities += 1;
+
+        if cullable.despawn_when_far && !keep {
+            despawns.despawn(entity);
+            frame.despawned += 1;
+            continue;
+        }
+        if in_range == cullable.culled {
+            cullable.culled = !in_range;
+            *visibility = if in_range {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+        if cullable.culled {
+            frame.hidden += 1;
+        }
+    }
+
+    frame.chunks = chunks.len();
+    frame.visible_chunks = chunks.values().filter(|(in_range, _)| *in_range).count();
+    *stats = frame;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn far_chunks_hide_and_far_cosmetics_despawn() {
+        let mut world = World::new();
+        world.insert_resource(ChunkCulling {
+            chunk_size: 10.0,
+            default_range: 30.0,
+            ..default()
+        });
+        world.init_resource::<CullingStats>();
+        world.spawn((Camera3d::default(), GlobalTransform::IDENTITY));
+        let near = world.spawn((Cullable::default(), GlobalTransform::from_xyz(5.0, 0.0, 5.0))).id();
+        let far = world.spawn((Cullable::default(), GlobalTransform::from_xyz(45.0, 0.0, 5.0))).id();
+        let gone = world.spawn((Cullable::cosmetic(), GlobalTransform::from_xyz(95.0, 0.0, 5.0))).id();
+
+        let _ = world.run_system_once(assign_chunks);
+        let _ = world.run_system_once(cull_chunks);
+
+        assert_eq!(*world.get::<Visibility>(near).unwrap(), Visibility::Inherited);
+        assert_eq!(*world.get::<Visibility>(far).unwrap(), Visibility::Hidden);
+        assert!(world.entity(gone).contains::<Despawning>());
+        let stats = *world.resource::<CullingStats>();
+        assert_eq!((stats.chunks, stats.visible_chunks), (3, 1));
+        assert_eq!((stats.hidden, stats.despawned, stats.drawn()), (1, 1, 1));
+
+        world.entity_mut(far).insert(GlobalTransform::from_xyz(15.0, 0.0, 5.0));
+        let _ = world.run_system_once(assign_chunks);
+        let _ = world.run_system_once(cull_chunks);
+        assert_eq!(*world.get::<Visibility>(far).unwrap(), Visibility::Inherited);
+    }
+}
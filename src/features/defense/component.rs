@@ -0,0 +1,158 @@
+// src/features/defense/component.rs
+use bevy::prelude::*;
+
+use crate::features::health::damage::DamageEvent;
+
+/// Guard stance of a character.
+///
+/// - While `raised`, frontal hits with attack metadata are blocked (scaled down).
+/// - For the first `parry_window_ticks` after raising, parryable hits are negated
+///   and the attacker is staggered.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Guard {
+    pub raised: bool,
+    /// Fixed ticks since the guard was raised.
+    pub raised_ticks: u32,
+    /// Damage multiplier for blocked hits.
+    pub block_scale: f32,
+    pub parry_window_ticks: u32,
+    /// Hits arriving within this angle of the facing direction can be guarded.
+    pub guard_half_angle_deg: f32,
+}
+
+impl Default for Guard {
+    fn default() -> Self {
+        Self {
+            raised: false,
+            raised_ticks: 0,
+            block_scale: 0.25,
+            // ~130ms at 60 Hz.
+            parry_window_ticks: 8,
+            guard_half_angle_deg: 75.0,
+        }
+    }
+}
+
+/// How a guard handled a hit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuardOutcome {
+    Unguarded,
+    /// Damage multiplied by the guard's `block_scale`.
+    Blocked { scale: f32 },
+    /// Damage negated; the attacker gets staggered.
+    Parried,
+}
+
+impl Guard {
+    /// Classify a hit against a defender facing `forward`.
+    ///
+    /// Sourceless damage and hits from outside the guard cone are never guarded.
+    pub fn resolve(&self, hit: &DamageEvent, forward: Vec3) -> GuardOutcome {
+        let Some(attack) = hit.attack else {
+            return GuardOutcome::Unguarded;
+        };
+        if !self.raised {
+            return GuardOutcome::Unguarded;
+        }
+        // The attack travels towards the defender, so it comes from `-direction`.
+        let incoming = (-attack.direction).with_y(0.0).normalize_or_zero();
+        let facing = forward.with_y(0.0).normalize_or_zero();
+        if incoming.dot(facing) < self.guard_half_angle_deg.to_radians().cos() {
+            return GuardOutcome::Unguarded;
+        }
+
+        if attack.parryable && self.raised_ticks < self.parry_window_ticks {
+            GuardOutcome::Parried
+        } else {
+            GuardOutcome::Blocked {
+                scale: self.block_scale,
+            }
+        }
+    }
+}
+
+/// Guard intent, written by input (held = guard up).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GuardInput {
+    pub held: bool,
+}
+
+/// Off-balance after being parried: no attacks or guarding until it runs out.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Staggered {
+    pub remaining_ticks: u32,
+}
+
+/// Fixed ticks a parried attacker stays staggered (~0.75s at 60 Hz).
+pub const PARRY_STAGGER_TICKS: u32 = 45;
+
+/// Guard input bindings.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct GuardBindings {
+    pub key: KeyCode,
+    pub gamepad: GamepadButton,
+}
+
+impl Default for GuardBindings {
+    fn default() -> Self {
+        Self {
+            key: KeyCode::KeyZ,
+            gamepad: GamepadButton::LeftTrigger,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::health::damage::IncomingAttack;
+
+    fn hit(direction: Vec3, parryable: bool) -> DamageEvent {
+        DamageEvent::from_attack(
+            Entity::PLACEHOLDER,
+            10.0,
+            IncomingAttack {
+                source: Entity::PLACEHOLDER,
+                direction,
+                parryable,
+            },
+        )
+    }
+
+    #[test]
+    fn guard_parries_early_then_blocks_frontal_hits_only() {
+        // Defender faces -Z; a frontal hit travels +Z.
+        let forward = Vec3::NEG_Z;
+        let mut guard = Guard {
+            raised: true,
+            ..default()
+        };
+
+        assert_eq!(guard.resolve(&hit(Vec3::Z, true), forward), GuardOutcome::Parried);
+        assert!(matches!(
+            guard.resolve(&hit(Vec3::Z, false), forward),
+            GuardOutcome::Blocked { .. }
+        ));
+
+        guard.raised_ticks = guard.parry_window_ticks;
+        assert!(matches!(
+            guard.resolve(&hit(Vec3::Z, true), forward),
+            GuardOutcome::Blocked { .. }
+        ));
+        // From behind.
+        assert_eq!(guard.resolve(&hit(Vec3::NEG_Z, true), forward), GuardOutcome::Unguarded);
+    }
+
+    #[test]
+    fn lowered_guard_and_sourceless_damage_are_unguarded() {
+        let guard = Guard::default();
+        assert_eq!(guard.resolve(&hit(Vec3::Z, true), Vec3::NEG_Z), GuardOutcome::Unguarded);
+
+        let raised = Guard {
+            raised: true,
+            ..default()
+        };
+        let poison = DamageEvent::new(Entity::PLACEHOLDER, 3.0);
+        assert_eq!(raised.resolve(&poison, Vec3::NEG_Z), GuardOutcome::Unguarded);
+    }
+}
@@ -0,0 +1,70 @@
+// src/features/defense/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::explosion::systems::resolve_explosions;
+use crate::features::health::damage::{DamageEvent, apply_damage};
+use crate::features::melee::systems::advance_melee_attacks;
+use crate::features::weapon::ballistics::apply_weapon_hits;
+
+pub mod component;
+pub mod systems;
+
+/// Blocking and parrying.
+///
+/// Scope:
+/// - Update: guard button held -> `GuardInput`
+/// - FixedUpdate: `Guard` raised / lowered, ticks since raising (parry window)
+/// - FixedUpdate, before health's `apply_damage`: the target's `Guard` treats hits
+///   with attack metadata (`IncomingAttack`) by rewriting their `DamageEvent`:
+///   block (scaled), parry (negated) or not at all; outcomes are announced as `GuardedHit`
+/// - parried attackers are `Staggered`: swing cancelled, no attacks or guard for a while
+///
+/// Design constraints:
+/// - Only frontal hits (within the guard cone) with a source can be guarded;
+///   poison, hazards and hits from behind always land.
+/// - Attackers decide what is parryable (`IncomingAttack::parryable`).
+pub struct DefensePlugin;
+
+impl Plugin for DefensePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::GuardBindings>();
+
+        app.add_message::<DamageEvent>();
+        app.add_message::<systems::GuardedHit>();
+
+        app.add_systems(
+            Update,
+            systems::read_guard_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_player_guard,
+                systems::tick_stagger,
+                systems::update_guards,
+            )
+                .chain()
+                .before(apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::resolve_guarded_hits
+                .after(systems::update_guards)
+                .after(advance_melee_attacks)
+                .after(apply_weapon_hits)
+                .after(resolve_explosions)
+                .before(apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::stagger_parried_attackers
+                .after(apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
@@ -0,0 +1,251 @@
+// src/features/defense/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    Guard, GuardBindings, GuardInput, GuardOutcome, PARRY_STAGGER_TICKS, Staggered,
+};
+use crate::app::Allegiance;
+use crate::features::health::component::Health;
+use crate::features::health::damage::DamageEvent;
+use crate::features::melee::component::MeleeState;
+use crate::features::player::component::Player;
+use crate::features::player::death::AlivePlayer;
+
+/// A hit was blocked or parried (audio, sparks, HUD feedback).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct GuardedHit {
+    pub defender: Entity,
+    pub attacker: Entity,
+    pub outcome: GuardOutcome,
+}
+
+/// Players can guard.
+pub fn grant_player_guard(
+    mut commands: Commands,
+    q_new: Query<Entity, (Added<Player>, Without<Guard>)>,
+) {
+    for entity in &q_new {
+        commands
+            .entity(entity)
+            .insert((Guard::default(), GuardInput::default()));
+    }
+}
+
+/// Update: guard button held -> `GuardInput`.
+pub fn read_guard_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<GuardBindings>,
+    q_gamepads: Query<&Gamepad>,
+    mut q_players: Query<&mut GuardInput, AlivePlayer>,
+) {
+    let held = keyboard.pressed(bindings.key)
+        || q_gamepads.iter().any(|g| g.pressed(bindings.gamepad));
+    for mut input in &mut q_players {
+        if input.held != held {
+            input.held = held;
+        }
+    }
+}
+
+/// FixedUpdate: raise / lower guards and count ticks since raising (parry window).
+///
+/// A staggered character can't guard.
+pub fn update_guards(mut q_guards: Query<(&GuardInput, &mut Guard, Has<Staggered>)>) {
+    for (input, mut guard, staggered) in &mut q_guards {
+        let raise = input.held && !staggered;
+        if raise && guard.raised {
+            guard.raised_ticks = guard.raised_ticks.saturating_add(1);
+        } else if raise != guard.raised {
+            guard.raised = raise;
+            guard.raised_ticks = 0;
+        }
+    }
+}
+
+/// FixedUpdate: let targets' guards treat incoming hits before health applies them.
+///
+/// - Blocked hits are scaled by the guard's `block_scale`, parried ones drop to 0.
+/// - Writes `GuardedHit` for each; hits health would ignore anyway (depleted
+///   targets, allies without friendly fire) are left alone.
+pub fn resolve_guarded_hits(
+    allegiance: Allegiance,
+    mut damage: MessageMutator<DamageEvent>,
+    q_guards: Query<(&Guard, Option<&Transform>, Option<&Health>)>,
+    mut guarded: MessageWriter<GuardedHit>,
+) {
+    for ev in damage.read() {
+        let Some(attack) = ev.attack else {
+            continue;
+        };
+        let Ok((guard, transform, health)) = q_guards.get(ev.target) else {
+            continue;
+        };
+        if ev.amount <= 0.0
+            || health.is_some_and(Health::is_depleted)
+            || !allegiance.can_hurt(attack.source, ev.target)
+        {
+            continue;
+        }
+
+        let forward = transform.map_or(Vec3::NEG_Z, |t| t.rotation * Vec3::NEG_Z);
+        let outcome = guard.resolve(ev, forward);
+        match outcome {
+            GuardOutcome::Unguarded => continue,
+            GuardOutcome::Blocked { scale } => ev.amount *= scale,
+            GuardOutcome::Parried => ev.amount = 0.0,
+        }
+        guarded.write(GuardedHit {
+            defender: ev.target,
+            attacker: attack.source,
+            outcome,
+        });
+    }
+}
+
+/// FixedUpdate: parried attackers are staggered and lose their current swing.
+pub fn stagger_parried_attackers(
+    mut commands: Commands,
+    mut guarded: MessageReader<GuardedHit>,
+    mut q_melee: Query<&mut MeleeState>,
+) {
+    for hit in guarded.read() {
+        if hit.outcome != GuardOutcome::Parried {
+            continue;
+        }
+        let Ok(mut attacker) = commands.get_entity(hit.attacker) else {
+            continue;
+        };
+        attacker.insert(Staggered {
+            remaining_ticks: PARRY_STAGGER_TICKS,
+        });
+        if let Ok(mut melee) = q_melee.get_mut(hit.attacker) {
+            melee.swing = None;
+            melee.chain_ticks_left = 0;
+        }
+    }
+}
+
+/// FixedUpdate: count staggers down and remove them when they run out.
+pub fn tick_stagger(mut commands: Commands, mut q_staggered: Query<(Entity, &mut Staggered)>) {
+    for (entity, mut stagger) in &mut q_staggered {
+        stagger.remaining_ticks = stagger.remaining_ticks.saturating_sub(1);
+        if stagger.remaining_ticks == 0 {
+            commands.entity(entity).remove::<Staggered>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::health::damage::{Died, IncomingAttack, apply_damage};
+    use crate::features::melee::component::MeleeSwing;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn guard_counts_ticks_while_held_and_resets_on_release() {
+        let mut world = World::new();
+        let e = world.spawn((Guard::default(), GuardInput { held: true })).id();
+
+        for _ in 0..3 {
+            let _ = world.run_system_once(update_guards);
+        }
+        let guard = *world.get::<Guard>(e).unwrap();
+        assert!(guard.raised);
+        assert_eq!(guard.raised_ticks, 2);
+
+        world.get_mut::<GuardInput>(e).unwrap().held = false;
+        let _ = world.run_system_once(update_guards);
+        assert!(!world.get::<Guard>(e).unwrap().raised);
+
+        // Staggered: can't raise.
+        world.get_mut::<GuardInput>(e).unwrap().held = true;
+        world.entity_mut(e).insert(Staggered { remaining_ticks: 1 });
+        let _ = world.run_system_once(update_guards);
+        assert!(!world.get::<Guard>(e).unwrap().raised);
+    }
+
+    #[test]
+    fn parry_staggers_attacker_and_cancels_swing_until_it_wears_off() {
+        let mut world = World::new();
+        world.init_resource::<Messages<GuardedHit>>();
+        let defender = world.spawn_empty().id();
+        let attacker = world
+            .spawn(MeleeState {
+                swing: Some(MeleeSwing {
+                    step: 0,
+                    tick: 3,
+                    hit: Vec::new(),
+                    queued: true,
+                }),
+                ..default()
+            })
+            .id();
+
+        world.write_message(GuardedHit {
+            defender,
+            attacker,
+            outcome: GuardOutcome::Parried,
+        });
+        let _ = world.run_system_once(stagger_parried_attackers);
+
+        assert!(world.get::<Staggered>(attacker).is_some());
+        assert!(!world.get::<MeleeState>(attacker).unwrap().is_swinging());
+
+        for _ in 0..PARRY_STAGGER_TICKS {
+            let _ = world.run_system_once(tick_stagger);
+        }
+        assert!(world.get::<Staggered>(attacker).is_none());
+    }
+
+    #[test]
+    fn guard_scales_blocked_hits_and_negates_parries() {
+        let mut world = World::new();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<GuardedHit>>();
+        let attacker = world.spawn_empty().id();
+        // Facing -Z (default); the hit travels +Z, i.e. comes from the front.
+        let e = world
+            .spawn((
+                Health::new(100.0),
+                Transform::default(),
+                Guard {
+                    raised: true,
+                    raised_ticks: 100,
+                    block_scale: 0.5,
+                    ..default()
+                },
+            ))
+            .id();
+        let attack = IncomingAttack {
+            source: attacker,
+            direction: Vec3::Z,
+            parryable: true,
+        };
+        let hit = |world: &mut World| {
+            world.write_message(DamageEvent::from_attack(e, 20.0, attack));
+            let _ = world.run_system_once(resolve_guarded_hits);
+            let _ = world.run_system_once(apply_damage);
+            world.resource_mut::<Messages<DamageEvent>>().clear();
+        };
+
+        hit(&mut world);
+        assert_eq!(world.get::<Health>(e).unwrap().current, 90.0);
+
+        world.get_mut::<Guard>(e).unwrap().raised_ticks = 0;
+        hit(&mut world);
+        assert_eq!(world.get::<Health>(e).unwrap().current, 90.0);
+
+        let outcomes: Vec<_> = world
+            .resource::<Messages<GuardedHit>>()
+            .iter_current_update_messages()
+            .map(|hit| hit.outcome)
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![GuardOutcome::Blocked { scale: 0.5 }, GuardOutcome::Parried]
+        );
+    }
+}
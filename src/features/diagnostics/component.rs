@@ -0,0 +1,177 @@
+// src/features/diagnostics/component.rs
+use std::any::TypeId;
+
+use bevy::diagnostic::DiagnosticPath;
+use bevy::prelude::*;
+
+use crate::features::biome::scatter::{ScatterBatch, Scattered};
+use crate::features::collision::component::Collider;
+use crate::features::culling::component::Cullable;
+use crate::features::culling::effects::{CosmeticEffect, EffectStats};
+use crate::features::dungeon::component::DungeonPiece;
+use crate::features::enemy::component::Enemy;
+use crate::features::npc::component::Npc;
+use crate::features::player::component::Player;
+use crate::features::props::component::Prop;
+use crate::features::scenes::component::SceneMember;
+use crate::features::terrain::component::TerrainChunk;
+use crate::features::trigger::component::TriggerVolume;
+use crate::features::weather::component::WeatherParticle;
+
+pub const ENTITIES: DiagnosticPath = DiagnosticPath::const_new("ecs/entities");
+pub const ARCHETYPES: DiagnosticPath = DiagnosticPath::const_new("ecs/archetypes");
+pub const RESOURCES: DiagnosticPath = DiagnosticPath::const_new("ecs/resources");
+pub const RESOURCE_BYTES: DiagnosticPath = DiagnosticPath::const_new("ecs/resource_bytes");
+pub const EFFECTS_SIMPLIFIED: DiagnosticPath = DiagnosticPath::const_new("effects/simplified");
+pub const EFFECTS_SKIPPED: DiagnosticPath = DiagnosticPath::const_new("effects/skipped");
+pub const EFFECTS_EVICTED: DiagnosticPath = DiagnosticPath::const_new("effects/evicted");
+
+/// Diagnostic path of one feature's entity count (`ecs/feature/<label>`).
+pub fn feature_path(label: &str) -> DiagnosticPath {
+    DiagnosticPath::from_components(["ecs", "feature", label])
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticsKeybinding(pub KeyCode);
+
+impl Default for DiagnosticsKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::F4)
+    }
+}
+
+/// Marker components counted per feature, by label.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct FeatureMarkers(pub Vec<(&'static str, TypeId)>);
+
+impl FeatureMarkers {
+    pub fn with<T: Component>(mut self, label: &'static str) -> Self {
+        self.0.push((label, TypeId::of::<T>()));
+        self
+    }
+}
+
+impl Default for FeatureMarkers {
+    fn default() -> Self {
+        Self(Vec::new())
+            .with::<Player>("player")
+            .with::<Enemy>("enemy")
+            .with::<Npc>("npc")
+            .with::<Prop>("prop")
+            .with::<Collider>("collider")
+            .with::<TriggerVolume>("trigger")
+            .with::<TerrainChunk>("terrain")
+            .with::<Scattered>("scatter")
+            .with::<ScatterBatch>("scatter_batch")
+            .with::<DungeonPiece>("dungeon")
+            .with::<SceneMember>("scene")
+            .with::<WeatherParticle>("weather_particle")
+            .with::<Cullable>("cullable")
+            .with::<CosmeticEffect>("effect")
+    }
+}
+
+/// One snapshot of the world's size.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EcsSample {
+    pub entities: usize,
+    /// Archetypes holding at least one entity.
+    pub archetypes: usize,
+    pub resources: usize,
+    /// Shallow size of all resources (heap data they own is not counted).
+    pub resource_bytes: usize,
+    pub features: Vec<(&'static str, usize)>,
+    /// Largest resources by shallow size, biggest first.
+    pub largest_resources: Vec<(String, usize)>,
+    /// Cosmetic effect budget totals (work avoided by skipping / simplifying).
+    pub effects: EffectStats,
+}
+
+/// Resources listed in `EcsSample::largest_resources`.
+const LARGEST_RESOURCES: usize = 5;
+
+impl EcsSample {
+    /// Count entities, archetypes and resources; feature counts sum the archetypes
+    /// that contain each marker, so no query runs per entity.
+    pub fn capture(world: &World, markers: &FeatureMarkers) -> Self {
+        let archetypes = world.archetypes().iter().filter(|a| !a.is_empty());
+        let (entities, archetype_count) =
+            archetypes.fold((0, 0), |(entities, count), a| (entities + a.len() as usize, count + 1));
+
+        let features = markers
+            .0
+            .iter()
+            .map(|(label, type_id)| {
+                let count = world.components().get_id(*type_id).map_or(0, |id| {
+                    world
+                        .archetypes()
+                        .iter()
+                        .filter(|a| a.contains(id))
+                        .map(|a| a.len() as usize)
+                        .sum()
+                });
+                (*label, count)
+            })
+            .collect();
+
+        let mut resources: Vec<(String, usize)> = world
+            .iter_resources()
+            .map(|(info, _)| (info.name().shortname().to_string(), info.layout().size()))
+            .collect();
+        resources.sort_by_key(|(_, size)| std::cmp::Reverse(*size));
+        Self {
+            entities,
+            archetypes: archetype_count,
+            resources: resources.len(),
+            resource_bytes: resources.iter().map(|(_, size)| size).sum(),
+            features,
+            largest_resources: resources.into_iter().take(LARGEST_RESOURCES).collect(),
+            effects: world.get_resource::<EffectStats>().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Panel text.
+    pub fn report(&self) -> String {
+        let mut text = format!(
+            "Entities {}  Archetypes {}\nResources {} (~{:.1} KiB)\n",
+            self.entities,
+            self.archetypes,
+            self.resources,
+            self.resource_bytes as f32 / 1024.0
+        );
+        for (label, count) in self.features.iter().filter(|(_, count)| *count > 0) {
+            text.push_str(&format!("  {label}: {count}\n"));
+        }
+        let e = &self.effects;
+        text.push_str(&format!(
+            "Effects: {} full, {} simplified, {} skipped, {} evicted\n",
+            e.full, e.simplified, e.skipped, e.evicted
+        ));
+        text.push_str("Largest resources:\n");
+        for (name, size) in &self.largest_resources {
+            text.push_str(&format!("  {name}: {size} B\n"));
+        }
+        text
+    }
+}
+
+/// Sampling state and the last sample (also fed to the `DiagnosticsStore`).
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EcsDiagnostics {
+    pub open: bool,
+    pub interval_secs: f32,
+    pub since_sample_secs: f32,
+    pub last: Option<EcsSample>,
+}
+
+impl Default for EcsDiagnostics {
+    fn default() -> Self {
+        Self {
+            open: false,
+            interval_secs: 0.5,
+            // Sample on the first frame.
+            since_sample_secs: f32::INFINITY,
+            last: None,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// src/features/diagnostics/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+use crate::features::health::damage::{DamageEvent, Died};
+use crate::features::player::bundles::PlayerSpawned;
+use crate::features::player::death::{PlayerDied, PlayerRespawned};
+
+pub mod component;
+pub mod systems;
+pub mod timeline;
+pub mod ui;
+
+/// ECS diagnostics (dev tool).
+///
+/// Scope:
+/// - entity / archetype counts, entities per feature (by marker component,
+///   `FeatureMarkers`) and a shallow resource memory estimate, sampled every
+///   `interval_secs`, plus the cosmetic effect budget's totals (`EffectStats`)
+/// - samples go to the `DiagnosticsStore` under `ecs/...` / `effects/...`, so
+///   diagnostic loggers and exporters pick them up
+/// - panel toggled with `DiagnosticsKeybinding` (F4)
+/// - `EventTimeline`: recent gameplay events (damage, deaths, player spawns) stamped
+///   with the `SimTick` they happened on
+///
+/// Design constraints:
+/// - Feature counts sum archetype lengths: no per-entity work, cheap enough to
+///   sample in release builds.
+pub struct EcsDiagnosticsPlugin;
+
+impl Plugin for EcsDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::EcsDiagnostics>();
+        app.init_resource::<component::FeatureMarkers>();
+        app.init_resource::<component::DiagnosticsKeybinding>();
+        app.init_resource::<bevy::diagnostic::DiagnosticsStore>();
+        app.init_resource::<timeline::EventTimeline>();
+
+        // Recorded messages; registering them here too is idempotent.
+        app.add_message::<DamageEvent>();
+        app.add_message::<Died>();
+        app.add_message::<PlayerSpawned>();
+        app.add_message::<PlayerDied>();
+        app.add_message::<PlayerRespawned>();
+
+        app.add_systems(Startup, ui::spawn_diagnostics_panel);
+        app.add_systems(
+            Update,
+            systems::toggle_ecs_diagnostics
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(Update, ui::update_diagnostics_panel.after(AppSet::Input));
+        app.add_systems(Last, systems::sample_ecs_diagnostics);
+        app.add_systems(FixedLast, timeline::record_gameplay_events);
+    }
+}
@@ -0,0 +1,109 @@
+// src/features/diagnostics/systems.rs
+use bevy::diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+use super::component::{
+    ARCHETYPES, DiagnosticsKeybinding, EFFECTS_EVICTED, EFFECTS_SIMPLIFIED, EFFECTS_SKIPPED,
+    ENTITIES, EcsDiagnostics, EcsSample, FeatureMarkers, RESOURCE_BYTES, RESOURCES, feature_path,
+};
+
+/// Update: the diagnostics key opens / closes the panel.
+pub fn toggle_ecs_diagnostics(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<DiagnosticsKeybinding>,
+    mut diagnostics: ResMut<EcsDiagnostics>,
+) {
+    if keyboard.just_pressed(binding.0) {
+        diagnostics.open = !diagnostics.open;
+    }
+}
+
+fn measure(store: &mut DiagnosticsStore, path: DiagnosticPath, value: usize) {
+    if store.get(&path).is_none() {
+        store.add(Diagnostic::new(path.clone()));
+    }
+    if let Some(diagnostic) = store.get_mut(&path) {
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: value as f64,
+        });
+    }
+}
+
+/// Last (exclusive): every `interval_secs`, snapshot the world into
+/// `EcsDiagnostics` and the `DiagnosticsStore` (so loggers / exporters see it too).
+pub fn sample_ecs_diagnostics(world: &mut World) {
+    let dt = world.get_resource::<Time>().map_or(0.0, |t| t.delta_secs());
+    let markers = {
+        let mut diagnostics = world.resource_mut::<EcsDiagnostics>();
+        diagnostics.since_sample_secs += dt;
+        if diagnostics.since_sample_secs < diagnostics.interval_secs {
+            return;
+        }
+        diagnostics.since_sample_secs = 0.0;
+        world.resource::<FeatureMarkers>().clone()
+    };
+
+    let sample = EcsSample::capture(world, &markers);
+    if let Some(mut store) = world.get_resource_mut::<DiagnosticsStore>() {
+        measure(&mut store, ENTITIES, sample.entities);
+        measure(&mut store, ARCHETYPES, sample.archetypes);
+        measure(&mut store, RESOURCES, sample.resources);
+        measure(&mut store, RESOURCE_BYTES, sample.resource_bytes);
+        for (label, count) in &sample.features {
+            measure(&mut store, feature_path(label), *count);
+        }
+        measure(&mut store, EFFECTS_SIMPLIFIED, sample.effects.simplified as usize);
+        measure(&mut store, EFFECTS_SKIPPED, sample.effects.skipped as usize);
+        measure(&mut store, EFFECTS_EVICTED, sample.effects.evicted as usize);
+    }
+    world.resource_mut::<EcsDiagnostics>().last = Some(sample);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::enemy::component::Enemy;
+    use crate::features::player::component::Player;
+
+    #[test]
+    fn samples_count_features_and_feed_the_store() {
+        let mut world = World::new();
+        world.init_resource::<EcsDiagnostics>();
+        world.init_resource::<FeatureMarkers>();
+        world.init_resource::<DiagnosticsStore>();
+        world.spawn(Player);
+        world.spawn(Enemy);
+        world.spawn((Enemy, Name::new("Named")));
+
+        sample_ecs_diagnostics(&mut world);
+
+        let sample = world.resource::<EcsDiagnostics>().last.clone().unwrap();
+        assert_eq!(sample.entities, 3);
+        assert_eq!(sample.archetypes, 3);
+        let count = |label| sample.features.iter().find(|(l, _)| *l == label).unwrap().1;
+        assert_eq!((count("player"), count("enemy"), count("npc")), (1, 2, 0));
+        assert!(sample.resources >= 3 && sample.resource_bytes > 0);
+
+        let store = world.resource::<DiagnosticsStore>();
+        assert_eq!(store.get(&ENTITIES).unwrap().value(), Some(3.0));
+        assert_eq!(store.get(&feature_path("enemy")).unwrap().value(), Some(2.0));
+        assert_eq!(store.get(&EFFECTS_SKIPPED).unwrap().value(), Some(0.0));
+    }
+
+    #[test]
+    fn sampling_is_throttled() {
+        let mut world = World::new();
+        world.init_resource::<EcsDiagnostics>();
+        world.init_resource::<FeatureMarkers>();
+        world.insert_resource(Time::<()>::default());
+
+        sample_ecs_diagnostics(&mut world);
+        world.spawn(Player);
+        sample_ecs_diagnostics(&mut world);
+
+        let sample = world.resource::<EcsDiagnostics>().last.clone().unwrap();
+        assert_eq!(sample.entities, 0, "second sample waits for the interval");
+    }
+}
@@ -0,0 +1,154 @@
+// src/features/diagnostics/timeline.rs
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::app::SimTick;
+use crate::features::health::damage::{DamageEvent, Died};
+use crate::features::player::bundles::PlayerSpawned;
+use crate::features::player::death::{PlayerDied, PlayerRespawned};
+
+/// Entries kept before the oldest are dropped (~1 minute of busy combat).
+pub const TIMELINE_CAPACITY: usize = 4096;
+
+/// Gameplay event as recorded on the timeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimelineEvent {
+    Damage { target: Entity, amount: f32 },
+    Died { entity: Entity },
+    PlayerSpawned { entity: Entity },
+    PlayerDied { entity: Entity },
+    PlayerRespawned { entity: Entity },
+}
+
+/// One gameplay event stamped with the `SimTick` it happened on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineEntry {
+    pub tick: u64,
+    pub event: TimelineEvent,
+}
+
+/// Recent gameplay events in tick order, for debugging ("what happened on tick
+/// 4312?") and as the raw material for replays.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EventTimeline {
+    entries: VecDeque<TimelineEntry>,
+    capacity: usize,
+}
+
+impl Default for EventTimeline {
+    fn default() -> Self {
+        Self::with_capacity(TIMELINE_CAPACITY)
+    }
+}
+
+impl EventTimeline {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Append an event, dropping the oldest one when full.
+    pub fn record(&mut self, tick: u64, event: TimelineEvent) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(TimelineEntry { tick, event });
+    }
+
+    /// Everything still on the timeline, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter()
+    }
+
+    /// Events recorded on `tick`.
+    pub fn on_tick(&self, tick: u64) -> impl Iterator<Item = &TimelineEvent> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.tick == tick)
+            .map(|entry| &entry.event)
+    }
+}
+
+/// FixedLast: stamp this step's gameplay events with the current `SimTick`.
+///
+/// Messages written outside `FixedUpdate` (player spawns in `Update`) are stamped
+/// with the next step's tick, which is when the simulation first sees them.
+pub fn record_gameplay_events(
+    tick: Res<SimTick>,
+    mut timeline: ResMut<EventTimeline>,
+    mut damage: MessageReader<DamageEvent>,
+    mut died: MessageReader<Died>,
+    mut spawned: MessageReader<PlayerSpawned>,
+    mut player_died: MessageReader<PlayerDied>,
+    mut respawned: MessageReader<PlayerRespawned>,
+) {
+    let tick = tick.0;
+    for ev in spawned.read() {
+        timeline.record(tick, TimelineEvent::PlayerSpawned { entity: ev.entity });
+    }
+    for ev in damage.read() {
+        let (target, amount) = (ev.target, ev.amount);
+        timeline.record(tick, TimelineEvent::Damage { target, amount });
+    }
+    for ev in died.read() {
+        timeline.record(tick, TimelineEvent::Died { entity: ev.entity });
+    }
+    for ev in player_died.read() {
+        timeline.record(tick, TimelineEvent::PlayerDied { entity: ev.entity });
+    }
+    for ev in respawned.read() {
+        timeline.record(tick, TimelineEvent::PlayerRespawned { entity: ev.entity });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+
+    #[test]
+    fn events_are_stamped_with_the_tick_they_were_read_on() {
+        let mut world = World::new();
+        world.init_resource::<EventTimeline>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<PlayerSpawned>>();
+        world.init_resource::<Messages<PlayerDied>>();
+        world.init_resource::<Messages<PlayerRespawned>>();
+        let mut system = IntoSystem::into_system(record_gameplay_events);
+        system.initialize(&mut world);
+        let target = world.spawn_empty().id();
+
+        world.insert_resource(SimTick(41));
+        world.write_message(DamageEvent::new(target, 10.0));
+        let _ = system.run((), &mut world);
+        world.insert_resource(SimTick(42));
+        world.write_message(Died { entity: target });
+        let _ = system.run((), &mut world);
+
+        let timeline = world.resource::<EventTimeline>();
+        assert_eq!(
+            timeline.on_tick(41).collect::<Vec<_>>(),
+            [&TimelineEvent::Damage { target, amount: 10.0 }]
+        );
+        assert_eq!(
+            timeline.on_tick(42).collect::<Vec<_>>(),
+            [&TimelineEvent::Died { entity: target }]
+        );
+    }
+
+    #[test]
+    fn full_timeline_drops_the_oldest_entries() {
+        let mut timeline = EventTimeline::with_capacity(2);
+        let entity = Entity::PLACEHOLDER;
+        for tick in 1..=3 {
+            timeline.record(tick, TimelineEvent::Died { entity });
+        }
+
+        let ticks: Vec<u64> = timeline.entries().map(|entry| entry.tick).collect();
+        assert_eq!(ticks, [2, 3]);
+    }
+}
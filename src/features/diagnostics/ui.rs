@@ -0,0 +1,50 @@
+// src/features/diagnostics/ui.rs
+use bevy::prelude::*;
+
+use super::component::EcsDiagnostics;
+
+/// ECS diagnostics panel (hidden until toggled).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DiagnosticsPanel;
+
+/// Startup: spawn the panel in the top-left corner.
+pub fn spawn_diagnostics_panel(mut commands: Commands) {
+    commands.spawn((
+        DiagnosticsPanel,
+        Text::new(""),
+        TextFont::from_font_size(13.0),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(12.0),
+            top: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.8)),
+        Pickable::IGNORE,
+        Visibility::Hidden,
+        Name::new("DiagnosticsPanel"),
+    ));
+}
+
+/// Update: panel visibility and the last sample's report.
+pub fn update_diagnostics_panel(
+    diagnostics: Res<EcsDiagnostics>,
+    mut q_panel: Query<(&mut Text, &mut Visibility), With<DiagnosticsPanel>>,
+) {
+    if !diagnostics.is_changed() {
+        return;
+    }
+    for (mut text, mut vis) in &mut q_panel {
+        *vis = if diagnostics.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if diagnostics.open
+            && let Some(sample) = &diagnostics.last
+        {
+            text.0 = sample.report();
+        }
+    }
+}
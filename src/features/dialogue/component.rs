@@ -0,0 +1,99 @@
+// src/features/dialogue/component.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// One reply the player can pick.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DialogueChoice {
+    pub text: String,
+    /// Node to jump to; `None` ends the conversation.
+    #[serde(default)]
+    pub next: Option<String>,
+    /// Named hook fired when picked (other features react to `DialogueHook`).
+    #[serde(default)]
+    pub hook: Option<String>,
+}
+
+/// One line of dialogue.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DialogueNode {
+    pub speaker: String,
+    pub text: String,
+    /// Empty = a single "Continue" that ends the conversation.
+    #[serde(default)]
+    pub choices: Vec<DialogueChoice>,
+}
+
+/// A whole conversation tree.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DialogueDef {
+    pub start: String,
+    pub nodes: BTreeMap<String, DialogueNode>,
+}
+
+/// All dialogue trees by id.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct DialogueLibrary(pub BTreeMap<String, DialogueDef>);
+
+/// The dialogue shipped with the game (compiled in, so it can't go missing).
+const BUILTIN_DIALOGUE: &str = include_str!("../../../assets/data/dialogue.ron");
+
+impl DialogueLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+
+    pub fn node(&self, dialogue: &str, node: &str) -> Option<&DialogueNode> {
+        self.0.get(dialogue)?.nodes.get(node)
+    }
+}
+
+impl Default for DialogueLibrary {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_DIALOGUE).expect("assets/data/dialogue.ron must parse")
+    }
+}
+
+/// Someone the player can talk to (pairs with `Interactable`).
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Talkable {
+    /// `DialogueLibrary` id.
+    pub dialogue: String,
+}
+
+/// On the actor (player) while talking.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Conversation {
+    pub speaker: Entity,
+    pub dialogue: String,
+    pub node: String,
+}
+
+/// On the speaker while talking (NPCs stop walking and face the actor).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InConversation {
+    pub with: Entity,
+}
+
+/// Walking further than this from the speaker ends the conversation.
+pub const CONVERSATION_BREAK_DISTANCE: f32 = 5.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_dialogue_links_resolve() {
+        let lib = DialogueLibrary::default();
+        for (id, def) in &lib.0 {
+            assert!(def.nodes.contains_key(&def.start), "{id}: missing start");
+            for node in def.nodes.values() {
+                for next in node.choices.iter().filter_map(|c| c.next.as_ref()) {
+                    assert!(def.nodes.contains_key(next), "{id}: dangling {next}");
+                }
+            }
+        }
+    }
+}
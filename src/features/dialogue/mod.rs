@@ -0,0 +1,47 @@
+// src/features/dialogue/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::interaction::systems::Interacted;
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Dialogue feature.
+///
+/// Scope:
+/// - `DialogueLibrary`: conversation trees from `assets/data/dialogue.ron`
+/// - interacting with a `Talkable` starts its tree: `Conversation` on the actor,
+///   `InConversation` on the speaker (which stops being interactable meanwhile)
+/// - dialogue box with clickable replies -> `ChooseDialogueOption`
+/// - hooks for other features: `DialogueStarted`, `DialogueHook` (named, per choice),
+///   `DialogueEnded`
+/// - walking away ends the conversation
+pub struct DialoguePlugin;
+
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::DialogueLibrary>();
+
+        app.add_message::<Interacted>();
+        app.add_message::<systems::ChooseDialogueOption>();
+        app.add_message::<systems::DialogueStarted>();
+        app.add_message::<systems::DialogueHook>();
+        app.add_message::<systems::DialogueEnded>();
+
+        app.add_systems(Startup, ui::spawn_dialogue_box);
+        app.add_systems(Update, ui::click_dialogue_choices.in_set(AppSet::Input));
+        app.add_systems(
+            Update,
+            (
+                systems::start_conversations,
+                systems::choose_dialogue_options,
+                systems::end_broken_conversations,
+                ui::update_dialogue_box,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
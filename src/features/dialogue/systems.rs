@@ -0,0 +1,224 @@
+// src/features/dialogue/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    CONVERSATION_BREAK_DISTANCE, Conversation, DialogueLibrary, InConversation, Talkable,
+};
+use crate::features::interaction::component::InteractionDisabled;
+use crate::features::interaction::systems::Interacted;
+
+/// Pick reply `index` of the actor's current node (UI buttons, scripts).
+///
+/// On nodes without choices, index `0` is the implicit "Continue".
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChooseDialogueOption {
+    pub actor: Entity,
+    pub index: usize,
+}
+
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DialogueStarted {
+    pub actor: Entity,
+    pub speaker: Entity,
+    pub dialogue: String,
+}
+
+/// A picked choice carried a hook (open a shop, start a quest, give an item).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DialogueHook {
+    pub actor: Entity,
+    pub speaker: Entity,
+    pub hook: String,
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogueEnded {
+    pub actor: Entity,
+    pub speaker: Entity,
+}
+
+/// Update: interacting with a free `Talkable` starts its dialogue.
+pub fn start_conversations(
+    mut commands: Commands,
+    library: Res<DialogueLibrary>,
+    mut interacted: MessageReader<Interacted>,
+    q_speakers: Query<&Talkable, Without<InConversation>>,
+    q_actors: Query<(), With<Conversation>>,
+    mut started: MessageWriter<DialogueStarted>,
+) {
+    for ev in interacted.read() {
+        let Ok(talkable) = q_speakers.get(ev.target) else {
+            continue;
+        };
+        let Some(def) = library.0.get(&talkable.dialogue) else {
+            continue;
+        };
+        if q_actors.contains(ev.actor) {
+            continue;
+        }
+
+        commands.entity(ev.actor).insert(Conversation {
+            speaker: ev.target,
+            dialogue: talkable.dialogue.clone(),
+            node: def.start.clone(),
+        });
+        commands
+            .entity(ev.target)
+            .insert((InConversation { with: ev.actor }, InteractionDisabled));
+        started.write(DialogueStarted {
+            actor: ev.actor,
+            speaker: ev.target,
+            dialogue: talkable.dialogue.clone(),
+        });
+    }
+}
+
+/// Update: apply picked replies (fire hooks, move to the next node or end).
+pub fn choose_dialogue_options(
+    mut commands: Commands,
+    library: Res<DialogueLibrary>,
+    mut choices: MessageReader<ChooseDialogueOption>,
+    mut q_actors: Query<&mut Conversation>,
+    mut hooks: MessageWriter<DialogueHook>,
+    mut ended: MessageWriter<DialogueEnded>,
+) {
+    for pick in choices.read() {
+        let Ok(mut conversation) = q_actors.get_mut(pick.actor) else {
+            continue;
+        };
+        let Some(node) = library.node(&conversation.dialogue, &conversation.node) else {
+            end_conversation(&mut commands, pick.actor, conversation.speaker, &mut ended);
+            continue;
+        };
+
+        let next = if node.choices.is_empty() {
+            if pick.index != 0 {
+                continue;
+            }
+            None
+        } else {
+            let Some(choice) = node.choices.get(pick.index) else {
+                continue;
+            };
+            if let Some(hook) = &choice.hook {
+                hooks.write(DialogueHook {
+                    actor: pick.actor,
+                    speaker: conversation.speaker,
+                    hook: hook.clone(),
+                });
+            }
+            choice.next.clone()
+        };
+
+        match next {
+            Some(next) => conversation.node = next,
+            None => end_conversation(&mut commands, pick.actor, conversation.speaker, &mut ended),
+        }
+    }
+}
+
+/// Update: walking away (or the speaker disappearing) ends the conversation.
+pub fn end_broken_conversations(
+    mut commands: Commands,
+    q_actors: Query<(Entity, &Conversation, &GlobalTransform)>,
+    q_speakers: Query<&GlobalTransform>,
+    mut ended: MessageWriter<DialogueEnded>,
+) {
+    for (actor, conversation, actor_tr) in &q_actors {
+        let in_range = q_speakers.get(conversation.speaker).is_ok_and(|speaker_tr| {
+            speaker_tr.translation().distance(actor_tr.translation()) <= CONVERSATION_BREAK_DISTANCE
+        });
+        if !in_range {
+            end_conversation(&mut commands, actor, conversation.speaker, &mut ended);
+        }
+    }
+}
+
+fn end_conversation(
+    commands: &mut Commands,
+    actor: Entity,
+    speaker: Entity,
+    ended: &mut MessageWriter<DialogueEnded>,
+) {
+    commands.entity(actor).remove::<Conversation>();
+    if let Ok(mut speaker) = commands.get_entity(speaker) {
+        speaker.remove::<(InConversation, InteractionDisabled)>();
+    }
+    ended.write(DialogueEnded { actor, speaker });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(DialogueLibrary::default());
+        world.init_resource::<Messages<Interacted>>();
+        world.init_resource::<Messages<ChooseDialogueOption>>();
+        world.init_resource::<Messages<DialogueStarted>>();
+        world.init_resource::<Messages<DialogueHook>>();
+        world.init_resource::<Messages<DialogueEnded>>();
+        world
+    }
+
+    fn choose(world: &mut World, actor: Entity, index: usize) {
+        world.resource_mut::<Messages<ChooseDialogueOption>>().clear();
+        world.write_message(ChooseDialogueOption { actor, index });
+        let _ = world.run_system_once(choose_dialogue_options);
+    }
+
+    #[test]
+    fn talking_walks_the_tree_and_frees_the_speaker_at_the_end() {
+        let mut world = setup_world();
+        let actor = world.spawn_empty().id();
+        let speaker = world
+            .spawn(Talkable {
+                dialogue: "mara".into(),
+            })
+            .id();
+
+        world.write_message(Interacted {
+            actor,
+            target: speaker,
+        });
+        let _ = world.run_system_once(start_conversations);
+        assert_eq!(world.get::<Conversation>(actor).unwrap().node, "hello");
+        assert!(world.get::<InConversation>(speaker).is_some());
+        assert!(world.get::<InteractionDisabled>(speaker).is_some());
+
+        choose(&mut world, actor, 0);
+        assert_eq!(world.get::<Conversation>(actor).unwrap().node, "place");
+
+        choose(&mut world, actor, 0);
+        assert!(world.get::<Conversation>(actor).is_none());
+        assert!(world.get::<InConversation>(speaker).is_none());
+        assert!(world.get::<InteractionDisabled>(speaker).is_none());
+        assert_eq!(world.resource::<Messages<DialogueEnded>>().len(), 1);
+    }
+
+    #[test]
+    fn walking_away_ends_the_conversation() {
+        let mut world = setup_world();
+        let speaker = world
+            .spawn((InConversation { with: Entity::PLACEHOLDER }, GlobalTransform::default()))
+            .id();
+        let actor = world
+            .spawn((
+                Conversation {
+                    speaker,
+                    dialogue: "mara".into(),
+                    node: "hello".into(),
+                },
+                GlobalTransform::from_translation(Vec3::X * (CONVERSATION_BREAK_DISTANCE + 1.0)),
+            ))
+            .id();
+
+        let _ = world.run_system_once(end_broken_conversations);
+
+        assert!(world.get::<Conversation>(actor).is_none());
+        assert!(world.get::<InConversation>(speaker).is_none());
+    }
+}
@@ -0,0 +1,174 @@
+// src/features/dialogue/ui.rs
+use bevy::prelude::*;
+
+use super::component::{Conversation, DialogueLibrary};
+use super::systems::ChooseDialogueOption;
+use crate::features::player::component::Player;
+
+/// Bottom-of-screen dialogue box (hidden outside conversations).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DialogueBox;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DialogueSpeakerText;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DialogueBodyText;
+
+/// Container the reply buttons are rebuilt into when the node changes.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DialogueChoiceList;
+
+/// Reply button (`index` into the node's choices; `0` = "Continue" on choice-less nodes).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogueChoiceButton {
+    pub index: usize,
+}
+
+/// Startup: spawn the (empty, hidden) dialogue box.
+pub fn spawn_dialogue_box(mut commands: Commands) {
+    commands
+        .spawn((
+            DialogueBox,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(20.0),
+                right: Val::Percent(20.0),
+                bottom: Val::Px(24.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.85)),
+            Visibility::Hidden,
+            Name::new("DialogueBox"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((DialogueSpeakerText, Text::new(""), TextFont::from_font_size(18.0)));
+            panel.spawn((DialogueBodyText, Text::new(""), TextFont::from_font_size(16.0)));
+            panel.spawn((
+                DialogueChoiceList,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Update: show the player's current node; reply buttons are rebuilt only when it changes.
+pub fn update_dialogue_box(
+    mut commands: Commands,
+    library: Res<DialogueLibrary>,
+    q_player: Query<Ref<Conversation>, With<Player>>,
+    mut q_box: Query<&mut Visibility, With<DialogueBox>>,
+    mut q_speaker: Query<&mut Text, (With<DialogueSpeakerText>, Without<DialogueBodyText>)>,
+    mut q_body: Query<&mut Text, (With<DialogueBodyText>, Without<DialogueSpeakerText>)>,
+    q_list: Query<Entity, With<DialogueChoiceList>>,
+) {
+    let conversation = q_player.iter().next();
+    let node = conversation
+        .as_ref()
+        .and_then(|c| library.node(&c.dialogue, &c.node));
+
+    for mut vis in &mut q_box {
+        *vis = if node.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    let (Some(conversation), Some(node)) = (conversation, node) else {
+        return;
+    };
+    if !conversation.is_changed() {
+        return;
+    }
+
+    for mut text in &mut q_speaker {
+        text.0.clone_from(&node.speaker);
+    }
+    for mut text in &mut q_body {
+        text.0.clone_from(&node.text);
+    }
+
+    let labels: Vec<&str> = if node.choices.is_empty() {
+        vec!["Continue"]
+    } else {
+        node.choices.iter().map(|c| c.text.as_str()).collect()
+    };
+    for list in &q_list {
+        commands.entity(list).despawn_related::<Children>();
+        commands.entity(list).with_children(|list| {
+            for (index, label) in labels.iter().enumerate() {
+                list.spawn((
+                    Button,
+                    DialogueChoiceButton { index },
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+                ))
+                .with_child((Text::new(format!("{}. {label}", index + 1)), TextFont::from_font_size(15.0)));
+            }
+        });
+    }
+}
+
+/// Update: clicking a reply -> `ChooseDialogueOption`.
+pub fn click_dialogue_choices(
+    q_buttons: Query<(&Interaction, &DialogueChoiceButton), Changed<Interaction>>,
+    q_player: Query<Entity, (With<Player>, With<Conversation>)>,
+    mut choices: MessageWriter<ChooseDialogueOption>,
+) {
+    let Some(actor) = q_player.iter().next() else {
+        return;
+    };
+    for (interaction, button) in &q_buttons {
+        if *interaction == Interaction::Pressed {
+            choices.write(ChooseDialogueOption {
+                actor,
+                index: button.index,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn box_shows_node_text_and_one_button_per_reply() {
+        let mut world = World::new();
+        world.insert_resource(DialogueLibrary::default());
+        let _ = world.run_system_once(spawn_dialogue_box);
+        world.spawn((
+            Player,
+            Conversation {
+                speaker: Entity::PLACEHOLDER,
+                dialogue: "mara".into(),
+                node: "hello".into(),
+            },
+        ));
+
+        let _ = world.run_system_once(update_dialogue_box);
+
+        let speaker = world
+            .query_filtered::<&Text, With<DialogueSpeakerText>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(speaker.0, "Mara");
+        let buttons = world.query::<&DialogueChoiceButton>().iter(&world).count();
+        assert_eq!(buttons, 3);
+        let vis = world
+            .query_filtered::<&Visibility, With<DialogueBox>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(*vis, Visibility::Inherited);
+    }
+}
@@ -0,0 +1,378 @@
+// src/features/dungeon/component.rs
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::tasks::Task;
+use serde::Deserialize;
+
+/// A room prefab: a `width` x `depth` tile rectangle and what it contains.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RoomPrefab {
+    pub name: String,
+    pub width: u32,
+    pub depth: u32,
+    pub spawners: u32,
+    pub pickups: u32,
+}
+
+/// Generator settings, loaded from `assets/data/dungeon.ron`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct DungeonDef {
+    pub grid: u32,
+    pub tile_size: f32,
+    pub origin: (f32, f32),
+    pub room_count: u32,
+    pub rooms: Vec<RoomPrefab>,
+    pub loot: Vec<(String, u32)>,
+}
+
+const BUILTIN_DUNGEON: &str = include_str!("../../../assets/data/dungeon.ron");
+
+impl DungeonDef {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// World position (ground level) of the center of `tile`.
+    pub fn world_position(&self, tile: UVec2) -> Vec3 {
+        Vec3::new(
+            self.origin.0 + (tile.x as f32 + 0.5) * self.tile_size,
+            0.0,
+            self.origin.1 + (tile.y as f32 + 0.5) * self.tile_size,
+        )
+    }
+}
+
+impl Default for DungeonDef {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_DUNGEON).expect("assets/data/dungeon.ron must parse")
+    }
+}
+
+/// Seeded generator RNG (splitmix64): the same seed always builds the same dungeon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DungeonRng(pub u64);
+
+impl DungeonRng {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n` (`0` when `n == 0`).
+    pub fn below(&mut self, n: u32) -> u32 {
+        if n == 0 {
+            return 0;
+        }
+        (self.next_u64() % n as u64) as u32
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Room,
+    Corridor,
+}
+
+/// A prefab placed at `min` (tile coordinates).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacedRoom {
+    pub prefab: usize,
+    pub min: UVec2,
+    pub size: UVec2,
+}
+
+impl PlacedRoom {
+    pub fn center(&self) -> UVec2 {
+        self.min + self.size / 2
+    }
+
+    /// Overlap test with a one-tile gap kept between rooms.
+    fn overlaps(&self, other: &Self) -> bool {
+        let (a0, a1) = (self.min.as_ivec2() - 1, (self.min + self.size).as_ivec2() + 1);
+        let (b0, b1) = (other.min.as_ivec2(), (other.min + other.size).as_ivec2());
+        a0.x < b1.x && b0.x < a1.x && a0.y < b1.y && b0.y < a1.y
+    }
+}
+
+/// Output of the generator, in tile coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DungeonLayout {
+    pub grid: u32,
+    pub tiles: Vec<Tile>,
+    pub rooms: Vec<PlacedRoom>,
+    pub start: UVec2,
+    pub exit: UVec2,
+    pub spawners: Vec<UVec2>,
+    /// `(tile, loot index)`.
+    pub pickups: Vec<(UVec2, usize)>,
+}
+
+impl DungeonLayout {
+    pub fn tile(&self, at: UVec2) -> Tile {
+        if at.x >= self.grid || at.y >= self.grid {
+            return Tile::Empty;
+        }
+        self.tiles[(at.y * self.grid + at.x) as usize]
+    }
+
+    pub fn is_walkable(&self, at: UVec2) -> bool {
+        self.tile(at) != Tile::Empty
+    }
+
+    fn set(&mut self, at: UVec2, tile: Tile) {
+        let i = (at.y * self.grid + at.x) as usize;
+        self.tiles[i] = tile;
+    }
+
+    fn neighbours(&self, at: UVec2) -> impl Iterator<Item = UVec2> + use<> {
+        let grid = self.grid as i32;
+        [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y]
+            .into_iter()
+            .map(move |d| at.as_ivec2() + d)
+            .filter(move |p| p.x >= 0 && p.y >= 0 && p.x < grid && p.y < grid)
+            .map(|p| p.as_uvec2())
+    }
+
+    /// Walking distance (4-neighbour steps) from `from` to every tile (`None` =
+    /// unreachable or solid).
+    pub fn distances_from(&self, from: UVec2) -> Vec<Option<u32>> {
+        let mut dist = vec![None; self.tiles.len()];
+        if !self.is_walkable(from) {
+            return dist;
+        }
+        let index = |p: UVec2| (p.y * self.grid + p.x) as usize;
+        dist[index(from)] = Some(0);
+        let mut queue = VecDeque::from([from]);
+        while let Some(p) = queue.pop_front() {
+            let d = dist[index(p)].unwrap_or(0);
+            for n in self.neighbours(p) {
+                if self.is_walkable(n) && dist[index(n)].is_none() {
+                    dist[index(n)] = Some(d + 1);
+                    queue.push_back(n);
+                }
+            }
+        }
+        dist
+    }
+
+    /// Every walkable tile, the exit, spawners and pickups are reachable from the start.
+    pub fn is_connected(&self) -> bool {
+        let dist = self.distances_from(self.start);
+        let reachable = |p: UVec2| dist[(p.y * self.grid + p.x) as usize].is_some();
+        let all_floor = (0..self.grid * self.grid)
+            .map(|i| UVec2::new(i % self.grid, i / self.grid))
+            .filter(|p| self.is_walkable(*p))
+            .all(reachable);
+        all_floor
+            && reachable(self.exit)
+            && self.spawners.iter().all(|p| reachable(*p))
+            && self.pickups.iter().all(|(p, _)| reachable(*p))
+    }
+
+    /// Assemble a dungeon from `def`'s prefabs, deterministically for `seed`.
+    ///
+    /// - rooms are placed at random non-overlapping spots
+    /// - each room is joined to the previous one by an L-shaped corridor, so the
+    ///   whole dungeon is one connected component
+    /// - the start is the first room; the exit sits in the room farthest from it
+    /// - spawners / pickups go to random tiles of their rooms (never the start room)
+    pub fn generate(def: &DungeonDef, seed: u64) -> Self {
+        let mut rng = DungeonRng(seed);
+        let grid = def.grid.max(8);
+        let mut layout = Self {
+            grid,
+            tiles: vec![Tile::Empty; (grid * grid) as usize],
+            rooms: Vec::new(),
+            start: UVec2::ZERO,
+            exit: UVec2::ZERO,
+            spawners: Vec::new(),
+            pickups: Vec::new(),
+        };
+
+        for _ in 0..def.room_count.max(1) * 20 {
+            if layout.rooms.len() as u32 >= def.room_count.max(1) || def.rooms.is_empty() {
+                break;
+            }
+            let prefab = rng.below(def.rooms.len() as u32) as usize;
+            let size = UVec2::new(def.rooms[prefab].width, def.rooms[prefab].depth)
+                .clamp(UVec2::ONE, UVec2::splat(grid - 2));
+            let min = UVec2::new(
+                1 + rng.below(grid - 1 - size.x),
+                1 + rng.below(grid - 1 - size.y),
+            );
+            let room = PlacedRoom { prefab, min, size };
+            if layout.rooms.iter().all(|r| !room.overlaps(r)) {
+                layout.rooms.push(room);
+            }
+        }
+        if layout.rooms.is_empty() {
+            // Degenerate settings (no prefabs): a single 3x3 room.
+            layout.rooms.push(PlacedRoom {
+                prefab: 0,
+                min: UVec2::ONE,
+                size: UVec2::splat(3),
+            });
+        }
+
+        for room in layout.rooms.clone() {
+            for z in room.min.y..room.min.y + room.size.y {
+                for x in room.min.x..room.min.x + room.size.x {
+                    layout.set(UVec2::new(x, z), Tile::Room);
+                }
+            }
+        }
+        for pair in layout.rooms.clone().windows(2) {
+            let (a, b) = (pair[0].center(), pair[1].center());
+            let corner = if rng.below(2) == 0 {
+                UVec2::new(b.x, a.y)
+            } else {
+                UVec2::new(a.x, b.y)
+            };
+            layout.carve_line(a, corner);
+            layout.carve_line(corner, b);
+        }
+
+        layout.start = layout.rooms[0].center();
+        let dist = layout.distances_from(layout.start);
+        let far_room = layout
+            .rooms
+            .iter()
+            .skip(1)
+            .max_by_key(|r| dist[(r.center().y * grid + r.center().x) as usize])
+            .unwrap_or(&layout.rooms[0]);
+        layout.exit = far_room.center();
+
+        for room in layout.rooms.clone().iter().skip(1) {
+            let (spawners, pickups) = def
+                .rooms
+                .get(room.prefab)
+                .map_or((0, 0), |p| (p.spawners, p.pickups));
+            for _ in 0..spawners {
+                let tile = layout.free_tile_in(room, &mut rng);
+                layout.spawners.push(tile);
+            }
+            for _ in 0..pickups {
+                if def.loot.is_empty() {
+                    break;
+                }
+                let tile = layout.free_tile_in(room, &mut rng);
+                let loot = rng.below(def.loot.len() as u32) as usize;
+                layout.pickups.push((tile, loot));
+            }
+        }
+        layout
+    }
+
+    fn carve_line(&mut self, from: UVec2, to: UVec2) {
+        let (mut p, step) = (from.as_ivec2(), (to.as_ivec2() - from.as_ivec2()).signum());
+        loop {
+            if self.tile(p.as_uvec2()) == Tile::Empty {
+                self.set(p.as_uvec2(), Tile::Corridor);
+            }
+            if p == to.as_ivec2() {
+                break;
+            }
+            p += step;
+        }
+    }
+
+    /// Random room tile not used by the exit, a spawner or a pickup (falls back to
+    /// any room tile when the room is full).
+    fn free_tile_in(&self, room: &PlacedRoom, rng: &mut DungeonRng) -> UVec2 {
+        let mut tile = room.min;
+        for _ in 0..16 {
+            tile = room.min + UVec2::new(rng.below(room.size.x), rng.below(room.size.y));
+            let used = tile == self.exit
+                || self.spawners.contains(&tile)
+                || self.pickups.iter().any(|(p, _)| *p == tile);
+            if !used {
+                break;
+            }
+        }
+        tile
+    }
+}
+
+/// Seed shared by everyone on the same (UTC) day.
+pub fn daily_seed(unix_secs: u64) -> u64 {
+    DungeonRng(unix_secs / 86_400).next_u64()
+}
+
+/// Part of the current dungeon (removed when a new one is generated).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DungeonPiece;
+
+/// A dungeon layout being generated on the async compute pool; a newer request
+/// replaces (and cancels) it.
+#[derive(Resource, Debug)]
+pub struct PendingDungeon {
+    pub seed: u64,
+    pub task: Task<DungeonLayout>,
+}
+
+/// Item lying in the dungeon; walking over it collects it.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct DungeonPickup {
+    pub item: String,
+    pub amount: u32,
+}
+
+/// Trigger volume ending the dungeon run.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DungeonExit;
+
+/// The dungeon run in progress.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct DungeonRun {
+    pub seed: u64,
+    pub active: bool,
+    /// `Time<Virtual>` elapsed seconds when the run started.
+    pub started_secs: f32,
+    pub collected: u32,
+}
+
+/// Distance at which a pickup is collected.
+pub const PICKUP_RADIUS: f32 = 1.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_dungeons_are_connected_for_many_seeds() {
+        let def = DungeonDef::default();
+        for seed in 0..200 {
+            let layout = DungeonLayout::generate(&def, seed);
+            assert!(layout.rooms.len() >= 2, "seed {seed}: {} rooms", layout.rooms.len());
+            assert!(layout.is_connected(), "seed {seed} is not connected");
+            assert_ne!(layout.start, layout.exit, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn same_seed_same_dungeon() {
+        let def = DungeonDef::default();
+        assert_eq!(DungeonLayout::generate(&def, 7), DungeonLayout::generate(&def, 7));
+        assert_ne!(DungeonLayout::generate(&def, 7), DungeonLayout::generate(&def, 8));
+    }
+
+    #[test]
+    fn disconnected_layouts_are_detected() {
+        let mut layout = DungeonLayout::generate(&DungeonDef::default(), 3);
+        // An isolated floor tile in the corner.
+        layout.tiles[0] = Tile::Room;
+        assert!(!layout.is_connected());
+    }
+
+    #[test]
+    fn daily_seed_changes_once_a_day() {
+        assert_eq!(daily_seed(86_400 * 3 + 5), daily_seed(86_400 * 3 + 80_000));
+        assert_ne!(daily_seed(86_400 * 3), daily_seed(86_400 * 4));
+    }
+}
@@ -0,0 +1,54 @@
+// src/features/dungeon/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::inventory::ItemsAdded;
+use crate::features::leaderboard::systems::RunFinished;
+use crate::features::notifications::systems::Notify;
+use crate::features::trigger::systems::TriggerEntered;
+
+pub mod component;
+pub mod systems;
+
+/// Procedural dungeon runs.
+///
+/// Scope:
+/// - `DungeonLayout::generate`: rooms from prefab tiles (`assets/data/dungeon.ron`)
+///   joined by corridors, enemy spawners, pickups and an exit, all from a seed
+/// - `GenerateDungeon { seed }` replaces the current dungeon, stamps `RunSeed` and
+///   puts the player at the start (the main menu's "Daily seed" run sends it with
+///   `daily_seed`)
+/// - pickups are collected by walking over them; the exit ends the run as a
+///   `RunFinished` in the `dungeon` leaderboard mode
+///
+/// Design constraints:
+/// - Layouts are generated on the `AsyncComputeTaskPool` and built once ready, so a
+///   new run never hitches the frame.
+/// - Generation is pure and deterministic per seed; connectivity is checked by
+///   `DungeonLayout::is_connected` (tests sweep many seeds).
+pub struct DungeonPlugin;
+
+impl Plugin for DungeonPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::DungeonDef>();
+        app.init_resource::<component::DungeonRun>();
+
+        app.add_message::<systems::GenerateDungeon>();
+        app.add_message::<ItemsAdded>();
+        app.add_message::<RunFinished>();
+        app.add_message::<Notify>();
+        app.add_message::<TriggerEntered>();
+
+        app.add_systems(
+            Update,
+            (
+                systems::start_dungeon_generation,
+                systems::build_dungeon,
+                systems::collect_dungeon_pickups,
+                systems::finish_dungeon_at_exit,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,307 @@
+// src/features/dungeon/systems.rs
+use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::tasks::futures::check_ready;
+
+use super::component::{
+    DungeonDef, DungeonExit, DungeonLayout, DungeonPickup, DungeonPiece, DungeonRun,
+    PICKUP_RADIUS, PendingDungeon, Tile,
+};
+use crate::features::collision::component::Collider;
+use crate::features::culling::component::Cullable;
+use crate::features::enemy::component::{EnemySpawner, SpawnerDef};
+use crate::features::inventory::ItemsAdded;
+use crate::features::inventory::component::Inventory;
+use crate::features::leaderboard::component::RunSeed;
+use crate::features::leaderboard::systems::RunFinished;
+use crate::features::notifications::systems::Notify;
+use crate::features::player::component::Player;
+use crate::features::surface::component::SurfaceMaterial;
+use crate::features::trigger::component::TriggerVolume;
+use crate::features::trigger::systems::TriggerEntered;
+
+/// Leaderboard mode of dungeon runs.
+pub const DUNGEON_MODE: &str = "dungeon";
+
+/// Height of dungeon walls (world units).
+const WALL_HEIGHT: f32 = 2.5;
+
+/// Replace the current dungeon with a freshly generated one and start a run in it.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerateDungeon {
+    pub seed: u64,
+}
+
+/// Update: generate the layout for the latest `GenerateDungeon` in the background.
+pub fn start_dungeon_generation(
+    mut commands: Commands,
+    mut requests: MessageReader<GenerateDungeon>,
+    def: Res<DungeonDef>,
+) {
+    let Some(request) = requests.read().last().copied() else {
+        return;
+    };
+    let def = def.clone();
+    let task = AsyncComputeTaskPool::get()
+        .spawn(async move { DungeonLayout::generate(&def, request.seed) });
+    commands.insert_resource(PendingDungeon {
+        seed: request.seed,
+        task,
+    });
+}
+
+/// Update: once the pending layout is ready, build it (floor, walls, spawners,
+/// pickups, exit), stamp the run seed and move the player to the start.
+#[expect(clippy::too_many_arguments)]
+pub fn build_dungeon(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingDungeon>>,
+    def: Res<DungeonDef>,
+    time: Res<Time<Virtual>>,
+    mut run: ResMut<DungeonRun>,
+    mut seed: ResMut<RunSeed>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_pieces: Query<Entity, With<DungeonPiece>>,
+    mut q_player: Query<&mut Transform, With<Player>>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    let Some(layout) = check_ready(&mut pending.task) else {
+        return;
+    };
+    let run_seed = pending.seed;
+    commands.remove_resource::<PendingDungeon>();
+    for piece in &q_pieces {
+        commands.entity(piece).despawn();
+    }
+    let size = def.tile_size;
+
+    let floor_mesh = meshes.add(Cuboid::new(size, 0.1, size));
+    let wall_mesh = meshes.add(Cuboid::new(size, WALL_HEIGHT, size));
+    let floor_material = materials.add(Color::srgb(0.3, 0.28, 0.26));
+    let wall_material = materials.add(Color::srgb(0.45, 0.42, 0.4));
+    for z in 0..layout.grid {
+        for x in 0..layout.grid {
+            let tile = UVec2::new(x, z);
+            let ground = def.world_position(tile);
+            if layout.tile(tile) != Tile::Empty {
+                commands.spawn((
+                    DungeonPiece,
+                    Mesh3d(floor_mesh.clone()),
+                    MeshMaterial3d(floor_material.clone()),
+                    Transform::from_translation(ground - Vec3::Y * 0.05),
+                    SurfaceMaterial::Stone,
+                    Cullable::default(),
+                ));
+                continue;
+            }
+            let borders_floor = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y]
+                .into_iter()
+                .map(|d| tile.as_ivec2() + d)
+                .any(|p| p.x >= 0 && p.y >= 0 && layout.is_walkable(p.as_uvec2()));
+            if borders_floor {
+                commands.spawn((
+                    DungeonPiece,
+                    Mesh3d(wall_mesh.clone()),
+                    MeshMaterial3d(wall_material.clone()),
+                    Transform::from_translation(ground + Vec3::Y * WALL_HEIGHT * 0.5),
+                    Collider::cuboid(Vec3::new(size, WALL_HEIGHT, size) * 0.5),
+                    SurfaceMaterial::Stone,
+                    Cullable::default(),
+                ));
+            }
+        }
+    }
+
+    for &tile in &layout.spawners {
+        commands.spawn((
+            DungeonPiece,
+            EnemySpawner::new(SpawnerDef {
+                position: def.world_position(tile).into(),
+                radius: 1.0,
+                max_alive: 2,
+                interval_secs: 12.0,
+                hours: None,
+                retreat_outside_hours: false,
+                total: None,
+            }),
+            Transform::from_translation(def.world_position(tile)),
+            Name::new("Dungeon Spawner"),
+        ));
+    }
+
+    let pickup_mesh = meshes.add(Cuboid::from_length(0.4));
+    let pickup_material = materials.add(Color::srgb(0.95, 0.8, 0.2));
+    for &(tile, loot) in &layout.pickups {
+        let (item, amount) = def.loot[loot].clone();
+        commands.spawn((
+            DungeonPiece,
+            DungeonPickup { item, amount },
+            Mesh3d(pickup_mesh.clone()),
+            MeshMaterial3d(pickup_material.clone()),
+            Transform::from_translation(def.world_position(tile) + Vec3::Y * 0.4),
+            Name::new("Dungeon Pickup"),
+        ));
+    }
+
+    commands.spawn((
+        DungeonPiece,
+        DungeonExit,
+        TriggerVolume {
+            half_extents: Vec3::new(size * 0.5, 1.0, size * 0.5),
+        },
+        Mesh3d(meshes.add(Cylinder::new(size * 0.4, 0.05))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.3, 0.7, 1.0),
+            emissive: LinearRgba::rgb(0.5, 1.5, 3.0),
+            ..default()
+        })),
+        Transform::from_translation(def.world_position(layout.exit) + Vec3::Y * 1.0),
+        Name::new("Dungeon Exit"),
+    ));
+
+    for mut transform in &mut q_player {
+        transform.translation = def.world_position(layout.start) + Vec3::Y * 0.5;
+    }
+    seed.0 = run_seed;
+    *run = DungeonRun {
+        seed: run_seed,
+        active: true,
+        started_secs: time.elapsed_secs(),
+        collected: 0,
+    };
+}
+
+/// Update: players walking over a pickup take it.
+pub fn collect_dungeon_pickups(
+    mut commands: Commands,
+    mut run: ResMut<DungeonRun>,
+    q_pickups: Query<(Entity, &DungeonPickup, &GlobalTransform)>,
+    mut q_players: Query<(Entity, &GlobalTransform, &mut Inventory), With<Player>>,
+    mut added: MessageWriter<ItemsAdded>,
+) {
+    for (pickup, item, at) in &q_pickups {
+        let Some((player, _, mut inventory)) = q_players
+            .iter_mut()
+            .find(|(_, p, _)| p.translation().distance(at.translation()) <= PICKUP_RADIUS)
+        else {
+            continue;
+        };
+        inventory.add(&item.item, item.amount);
+        added.write(ItemsAdded {
+            entity: player,
+            item: item.item.clone(),
+            amount: item.amount,
+        });
+        run.collected += item.amount;
+        commands.entity(pickup).despawn();
+    }
+}
+
+/// Update: reaching the exit finishes the run (score = items collected).
+pub fn finish_dungeon_at_exit(
+    time: Res<Time<Virtual>>,
+    mut run: ResMut<DungeonRun>,
+    mut entered: MessageReader<TriggerEntered>,
+    q_exits: Query<(), With<DungeonExit>>,
+    q_players: Query<(), With<Player>>,
+    mut finished: MessageWriter<RunFinished>,
+    mut notify: MessageWriter<Notify>,
+) {
+    for ev in entered.read() {
+        if !run.active || !q_exits.contains(ev.volume) || !q_players.contains(ev.entity) {
+            continue;
+        }
+        run.active = false;
+        let secs = time.elapsed_secs() - run.started_secs;
+        finished.write(RunFinished {
+            mode: DUNGEON_MODE.into(),
+            score: run.collected as u64 * 100,
+            wave_reached: None,
+            completion_secs: Some(secs),
+        });
+        notify.write(Notify::info(format!("Dungeon cleared in {secs:.0}s")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::TaskPool;
+
+    fn setup_world() -> World {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<DungeonDef>();
+        world.init_resource::<DungeonRun>();
+        world.insert_resource(RunSeed(0));
+        world.insert_resource(Time::<Virtual>::default());
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Messages<GenerateDungeon>>();
+        world.init_resource::<Messages<ItemsAdded>>();
+        world
+    }
+
+    /// Request a dungeon and run the build until the background layout lands.
+    fn generate(world: &mut World, seed: u64) {
+        world.write_message(GenerateDungeon { seed });
+        let _ = world.run_system_once(start_dungeon_generation);
+        for _ in 0..1000 {
+            let _ = world.run_system_once(build_dungeon);
+            if !world.contains_resource::<PendingDungeon>() {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("dungeon layout never finished");
+    }
+
+    #[test]
+    fn generating_builds_the_layout_and_moves_the_player_to_the_start() {
+        let mut world = setup_world();
+        let player = world.spawn((Player, Transform::default())).id();
+        generate(&mut world, 11);
+
+        let layout = DungeonLayout::generate(&DungeonDef::default(), 11);
+        let def = DungeonDef::default();
+        let at = world.get::<Transform>(player).unwrap().translation;
+        assert_eq!(at, def.world_position(layout.start) + Vec3::Y * 0.5);
+        assert_eq!(world.resource::<RunSeed>().0, 11);
+        let spawners = world.query::<&EnemySpawner>().iter(&world).count();
+        let pickups = world.query::<&DungeonPickup>().iter(&world).count();
+        let exits = world.query::<&DungeonExit>().iter(&world).count();
+        assert_eq!((spawners, pickups, exits), (layout.spawners.len(), layout.pickups.len(), 1));
+
+        // A second run replaces the first dungeon.
+        generate(&mut world, 12);
+        assert_eq!(world.query::<&DungeonExit>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn walking_over_a_pickup_collects_it() {
+        let mut world = setup_world();
+        let player = world
+            .spawn((Player, GlobalTransform::default(), Inventory::default()))
+            .id();
+        let pickup = world
+            .spawn((
+                DungeonPickup {
+                    item: "wood".into(),
+                    amount: 5,
+                },
+                GlobalTransform::from_xyz(0.5, 0.0, 0.0),
+            ))
+            .id();
+
+        let _ = world.run_system_once(collect_dungeon_pickups);
+
+        assert_eq!(world.get::<Inventory>(player).unwrap().count("wood"), 5);
+        assert!(world.get_entity(pickup).is_err());
+        assert_eq!(world.resource::<DungeonRun>().collected, 5);
+    }
+}
@@ -0,0 +1,58 @@
+// src/features/editor/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+
+/// In-game editor mode.
+///
+/// Scope:
+/// - `EditorMode` on / off, toggled with `EditorKeybinding` (F2)
+/// - `editor_active` run condition for editing tools (terrain brushes, ...)
+///
+/// The editor owns no tools itself; features add theirs behind `editor_active`.
+pub struct EditorPlugin;
+
+/// Whether editor tools are active.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EditorMode {
+    pub enabled: bool,
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditorKeybinding(pub KeyCode);
+
+impl Default for EditorKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::F2)
+    }
+}
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorMode>();
+        app.init_resource::<EditorKeybinding>();
+        app.add_systems(
+            Update,
+            toggle_editor_mode
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+    }
+}
+
+/// Run condition: editor mode is on.
+pub fn editor_active(mode: Res<EditorMode>) -> bool {
+    mode.enabled
+}
+
+/// Update: the editor key switches editor mode.
+pub fn toggle_editor_mode(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<EditorKeybinding>,
+    mut mode: ResMut<EditorMode>,
+) {
+    if keyboard.just_pressed(binding.0) {
+        mode.enabled = !mode.enabled;
+        info!("editor mode {}", if mode.enabled { "on" } else { "off" });
+    }
+}
@@ -0,0 +1,118 @@
+// src/features/enemy/component.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::features::character_material::component::CharacterMaterial;
+use crate::features::time_of_day::HourRange;
+
+/// Tag for hostile entities (lock-on candidates, melee / weapon targets, health bars).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Enemy;
+
+/// Enemy hit points for the default training dummy.
+pub const DUMMY_MAX_HEALTH: f32 = 60.0;
+
+/// Half-size of the training dummy box (world units).
+pub const DUMMY_HALF_EXTENTS: Vec3 = Vec3::new(0.4, 0.9, 0.4);
+
+/// Scene data: ground positions of training dummies spawned at startup.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EnemySpawns(pub Vec<Vec3>);
+
+impl Default for EnemySpawns {
+    fn default() -> Self {
+        Self(vec![Vec3::new(3.0, 0.0, -5.0), Vec3::new(-4.0, 0.0, -3.0)])
+    }
+}
+
+/// One enemy spawner, loaded from `assets/data/spawners.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SpawnerDef {
+    pub position: (f32, f32, f32),
+    /// Enemies are placed on a ring of this radius around `position`.
+    pub radius: f32,
+    /// Spawning pauses while this many of its enemies are alive.
+    pub max_alive: u32,
+    pub interval_secs: f32,
+    /// Only spawn between these hours (`None` = always).
+    #[serde(default)]
+    pub hours: Option<HourRange>,
+    /// Spawned enemies retreat (despawn) once `hours` are over, e.g. night
+    /// creatures fleeing the daylight.
+    #[serde(default)]
+    pub retreat_outside_hours: bool,
+    /// Stop for good after this many spawns (`None` = endless), e.g. a survival wave.
+    #[serde(default)]
+    pub total: Option<u32>,
+}
+
+/// Scene data: enemy spawners placed at startup.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EnemySpawners(pub Vec<SpawnerDef>);
+
+const BUILTIN_SPAWNERS: &str = include_str!("../../../assets/data/spawners.ron");
+
+impl EnemySpawners {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+}
+
+impl Default for EnemySpawners {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_SPAWNERS).expect("assets/data/spawners.ron must parse")
+    }
+}
+
+/// Runtime state of a placed spawner.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct EnemySpawner {
+    pub def: SpawnerDef,
+    /// Seconds until the next spawn may happen.
+    pub cooldown_secs: f32,
+    /// Enemies spawned so far (drives the ring placement).
+    pub spawned: u32,
+}
+
+impl EnemySpawner {
+    pub fn new(def: SpawnerDef) -> Self {
+        Self {
+            def,
+            cooldown_secs: 0.0,
+            spawned: 0,
+        }
+    }
+
+    /// Spawned its `total` and won't spawn again.
+    pub fn is_exhausted(&self) -> bool {
+        self.def.total.is_some_and(|total| self.spawned >= total)
+    }
+
+    /// Ground point for the `n`-th enemy: golden-angle steps around the ring, so
+    /// consecutive spawns never stack.
+    pub fn spawn_point(&self, n: u32) -> Vec3 {
+        const GOLDEN_ANGLE: f32 = 2.399_963;
+        let angle = n as f32 * GOLDEN_ANGLE;
+        Vec3::from(self.def.position)
+            + Vec3::new(angle.cos(), 0.0, angle.sin()) * self.def.radius
+    }
+}
+
+/// A spawner switched off (by the game mode); it keeps its state.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Dormant;
+
+/// The spawner an enemy came from (alive counts, retreat rules).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpawnedBy(pub Entity);
+
+/// Light-sensitive enemies: they retreat (despawn) outside these hours.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ActiveHours(pub HourRange);
+
+/// Shared mesh / material for dummies and spawned enemies.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct EnemyAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<CharacterMaterial>,
+}
@@ -0,0 +1,112 @@
+// src/features/enemy/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, Faction, GameplayTuning};
+use crate::features::character_material::component::{
+    CharacterFx, CharacterMaterial, character_material,
+};
+use crate::features::collision::component::Collider;
+use crate::features::health::component::Health;
+use crate::features::materials::component::MaterialLibrary;
+use crate::features::movement::component::{ExternalForce, Velocity};
+use crate::features::ragdoll::component::RagdollOnDeath;
+
+pub mod component;
+pub mod spawner;
+
+use component::{
+    DUMMY_HALF_EXTENTS, DUMMY_MAX_HEALTH, Enemy, EnemyAssets, EnemySpawners, EnemySpawns,
+};
+
+/// Enemy feature.
+///
+/// Scope (current slice):
+/// - `Enemy` tag shared by combat / targeting / HUD features; enemies are
+///   `Faction::Monsters`
+/// - Startup: stationary training dummies from `EnemySpawns` (health, collider,
+///   ragdoll on death)
+/// - enemies are movers: knockback pushes (`ExternalForce`) move them through the
+///   shared `MovementPlugin` integration
+/// - `EnemySpawners` from `assets/data/spawners.ron`: timed spawns capped by
+///   `max_alive`, optionally only during some hours (`TimeOfDay`); enemies of
+///   light-sensitive spawners retreat once those hours end; a spawner may stop
+///   after a `total`, and `Dormant` ones are skipped (game modes switch them off)
+/// - difficulty (`GameplayTuning`): enemy health and spawner speed are scaled
+///
+/// - `AggroChanged` / `BossPhaseChanged`: the vocabulary enemy AI announces its
+///   state with (music, HUD)
+///
+/// AI and movement come later; anything tagged `Enemy` with `Health` participates in combat.
+pub struct EnemyPlugin;
+
+/// An enemy started (`Some`) or stopped (`None`) hunting a target.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AggroChanged {
+    pub enemy: Entity,
+    pub target: Option<Entity>,
+}
+
+/// A boss entered a new fight phase (`1` = first).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BossPhaseChanged {
+    pub boss: Entity,
+    pub phase: u32,
+}
+
+impl Plugin for EnemyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemySpawns>();
+        app.init_resource::<EnemySpawners>();
+        app.init_resource::<MaterialLibrary>();
+        app.add_message::<AggroChanged>();
+        app.add_message::<BossPhaseChanged>();
+        app.add_systems(
+            Startup,
+            (spawn_training_dummies, spawner::spawn_enemy_spawners),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (spawner::run_enemy_spawners, spawner::retreat_out_of_hours)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
+
+/// Startup: create the shared `EnemyAssets` and spawn a training dummy at each
+/// `EnemySpawns` ground point.
+pub fn spawn_training_dummies(
+    mut commands: Commands,
+    spawns: Res<EnemySpawns>,
+    tuning: Res<GameplayTuning>,
+    library: Res<MaterialLibrary>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<CharacterMaterial>>,
+) {
+    let assets = EnemyAssets {
+        mesh: meshes.add(Cuboid::from_size(DUMMY_HALF_EXTENTS * 2.0)),
+        material: materials.add(character_material(library.color("enemy"))),
+    };
+
+    for &position in &spawns.0 {
+        commands.spawn((enemy_bundle(&assets, position, &tuning), Name::new("Training Dummy")));
+    }
+    commands.insert_resource(assets);
+}
+
+/// Components of a dummy-style enemy standing on the ground point `position`, its
+/// health scaled by `GameplayTuning::enemy_health` (callers add a `Name`).
+pub fn enemy_bundle(assets: &EnemyAssets, position: Vec3, tuning: &GameplayTuning) -> impl Bundle {
+    (
+        Enemy,
+        Faction::Monsters,
+        Health::new(DUMMY_MAX_HEALTH * tuning.enemy_health),
+        Collider::cuboid(DUMMY_HALF_EXTENTS),
+        RagdollOnDeath::default(),
+        CharacterFx::default(),
+        ExternalForce::default(),
+        Velocity::default(),
+        Mesh3d(assets.mesh.clone()),
+        MeshMaterial3d(assets.material.clone()),
+        Transform::from_translation(position + Vec3::Y * DUMMY_HALF_EXTENTS.y),
+    )
+}
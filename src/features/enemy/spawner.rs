@@ -0,0 +1,164 @@
+// src/features/enemy/spawner.rs
+use bevy::prelude::*;
+
+use super::component::{
+    ActiveHours, Dormant, EnemyAssets, EnemySpawner, EnemySpawners, SpawnedBy,
+};
+use super::enemy_bundle;
+use crate::app::{DespawnQueue, GameplayTuning};
+use crate::features::enemy::component::Enemy;
+use crate::features::health::component::Health;
+use crate::features::time_of_day::TimeOfDay;
+
+/// Startup: place a spawner for each `EnemySpawners` entry.
+pub fn spawn_enemy_spawners(mut commands: Commands, spawners: Res<EnemySpawners>) {
+    for def in &spawners.0 {
+        commands.spawn((
+            Transform::from_translation(Vec3::from(def.position)),
+            EnemySpawner::new(def.clone()),
+            Name::new("Enemy Spawner"),
+        ));
+    }
+}
+
+/// FixedUpdate: spawners inside their hours add an enemy every `interval_secs`
+/// while fewer than `max_alive` of theirs are alive (`GameplayTuning::spawn_rate`
+/// speeds the cooldown up or down).
+///
+/// Outside their hours the cooldown resets, so the first enemy shows up as soon
+/// as the window opens. `Dormant` spawners and those that spawned their `total`
+/// are skipped.
+pub fn run_enemy_spawners(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    clock: Option<Res<TimeOfDay>>,
+    tuning: Res<GameplayTuning>,
+    assets: Res<EnemyAssets>,
+    mut q_spawners: Query<(Entity, &mut EnemySpawner), Without<Dormant>>,
+    q_spawned: Query<(&SpawnedBy, &Health), With<Enemy>>,
+) {
+    let dt = time.delta_secs() * tuning.spawn_rate;
+
+    for (entity, mut spawner) in &mut q_spawners {
+        if spawner.is_exhausted() {
+            continue;
+        }
+        let in_hours = match (spawner.def.hours, &clock) {
+            (Some(hours), Some(clock)) => hours.contains(clock),
+            _ => true,
+        };
+        if !in_hours {
+            spawner.cooldown_secs = 0.0;
+            continue;
+        }
+
+        let alive = q_spawned
+            .iter()
+            .filter(|(by, health)| by.0 == entity && !health.is_depleted())
+            .count() as u32;
+        if alive >= spawner.def.max_alive {
+            continue;
+        }
+        spawner.cooldown_secs -= dt;
+        if spawner.cooldown_secs > 0.0 {
+            continue;
+        }
+
+        let position = spawner.spawn_point(spawner.spawned);
+        spawner.spawned += 1;
+        spawner.cooldown_secs = spawner.def.interval_secs;
+
+        let mut enemy = commands.spawn((
+            enemy_bundle(&assets, position, &tuning),
+            SpawnedBy(entity),
+            Name::new("Spawned Enemy"),
+        ));
+        if let (Some(hours), true) = (spawner.def.hours, spawner.def.retreat_outside_hours) {
+            enemy.insert(ActiveHours(hours));
+        }
+    }
+}
+
+/// FixedUpdate: light-sensitive enemies leave once their hours are over.
+pub fn retreat_out_of_hours(
+    mut despawns: DespawnQueue,
+    clock: Option<Res<TimeOfDay>>,
+    q_enemies: Query<(Entity, &ActiveHours, &Health)>,
+) {
+    let Some(clock) = clock else {
+        return;
+    };
+    for (entity, hours, health) in &q_enemies {
+        // The dead ragdoll out on their own.
+        if !hours.0.contains(&clock) && !health.is_depleted() {
+            despawns.despawn(entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use crate::features::enemy::component::SpawnerDef;
+    use crate::features::time_of_day::HourRange;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world(hour: f32) -> (World, Entity) {
+        let mut world = World::new();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.insert_resource(TimeOfDay {
+            hour,
+            hours_per_sec: 0.0,
+        });
+        world.init_resource::<EnemyAssets>();
+        world.init_resource::<GameplayTuning>();
+        let spawner = world
+            .spawn(EnemySpawner::new(SpawnerDef {
+                position: (0.0, 0.0, 0.0),
+                radius: 2.0,
+                max_alive: 2,
+                interval_secs: 0.0,
+                hours: Some(HourRange { from: 20.0, to: 5.0 }),
+                retreat_outside_hours: true,
+                total: None,
+            }))
+            .id();
+        (world, spawner)
+    }
+
+    /// Enemies still in play (not on their way out).
+    fn enemy_count(world: &mut World) -> usize {
+        world
+            .query_filtered::<(), (With<Enemy>, Without<Despawning>)>()
+            .iter(world)
+            .count()
+    }
+
+    #[test]
+    fn night_spawner_is_idle_by_day_and_caps_alive_enemies() {
+        let (mut world, _) = setup_world(12.0);
+        let _ = world.run_system_once(run_enemy_spawners);
+        assert_eq!(enemy_count(&mut world), 0);
+
+        world.resource_mut::<TimeOfDay>().hour = 23.0;
+        for _ in 0..5 {
+            let _ = world.run_system_once(run_enemy_spawners);
+        }
+        assert_eq!(enemy_count(&mut world), 2);
+    }
+
+    #[test]
+    fn light_sensitive_enemies_retreat_at_daybreak() {
+        let (mut world, _) = setup_world(23.0);
+        let _ = world.run_system_once(run_enemy_spawners);
+        assert_eq!(enemy_count(&mut world), 1);
+
+        world.resource_mut::<TimeOfDay>().hour = 7.0;
+        let _ = world.run_system_once(retreat_out_of_hours);
+        assert_eq!(enemy_count(&mut world), 0);
+    }
+}
@@ -0,0 +1,105 @@
+// src/features/explosion/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Blast parameters (weapon data, or anything else that blows up).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ExplosionDef {
+    /// Reach in world units, measured to the nearest point of a target's collider.
+    pub radius: f32,
+    /// Damage at the center.
+    pub damage: f32,
+    /// Push speed at the center (`ExternalForce` impulse).
+    #[serde(default)]
+    pub knockback: f32,
+}
+
+impl ExplosionDef {
+    /// Share of full damage / knockback at `distance`: 1 at the center, fading
+    /// linearly to 0 at the edge.
+    pub fn falloff(&self, distance: f32) -> f32 {
+        if self.radius <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - distance / self.radius).clamp(0.0, 1.0)
+    }
+}
+
+/// Explosion presentation (Update-only).
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ExplosionEffects {
+    /// Blast sound (asset path); `None` stays silent.
+    pub sound: Option<String>,
+    /// How long the fireball takes to expand and vanish.
+    pub flash_secs: f32,
+    /// Fireball glow (linear, HDR).
+    pub glow: LinearRgba,
+}
+
+impl Default for ExplosionEffects {
+    fn default() -> Self {
+        Self {
+            sound: None,
+            flash_secs: 0.35,
+            glow: LinearRgba::rgb(8.0, 3.0, 0.6),
+        }
+    }
+}
+
+/// Shared fireball mesh (unit sphere) and material.
+#[derive(Resource, Debug, Clone)]
+pub struct ExplosionAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Expanding fireball; scaled from nothing up to `radius`, then despawned.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ExplosionFlash {
+    pub radius: f32,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl ExplosionFlash {
+    /// Current scale: quick ease-out growth to the full radius.
+    pub fn scale(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return self.radius;
+        }
+        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        self.radius * (1.0 - (1.0 - t).powi(3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falloff_is_linear_to_the_edge() {
+        let def = ExplosionDef {
+            radius: 4.0,
+            damage: 10.0,
+            knockback: 0.0,
+        };
+        assert_eq!(def.falloff(0.0), 1.0);
+        assert_eq!(def.falloff(1.0), 0.75);
+        assert_eq!(def.falloff(4.0), 0.0);
+        assert_eq!(def.falloff(9.0), 0.0);
+    }
+
+    #[test]
+    fn flash_grows_to_the_blast_radius() {
+        let mut flash = ExplosionFlash {
+            radius: 2.0,
+            elapsed: 0.0,
+            duration: 1.0,
+        };
+        assert_eq!(flash.scale(), 0.0);
+        flash.elapsed = 0.5;
+        assert!((flash.scale() - 1.75).abs() < 1e-5);
+        flash.elapsed = 1.0;
+        assert_eq!(flash.scale(), 2.0);
+    }
+}
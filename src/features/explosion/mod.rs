@@ -0,0 +1,56 @@
+// src/features/explosion/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::culling::effects::{EffectBudget, EffectStats};
+use crate::features::health::damage::{DamageEvent, apply_damage};
+use crate::features::meshes::component::MeshLibrary;
+
+pub mod component;
+pub mod systems;
+
+/// Explosions: area damage with falloff, knockback and occlusion.
+///
+/// Scope:
+/// - `Explosion` (position + `ExplosionDef`): colliders in reach take damage scaled
+///   by distance (`Health`) and a radial push (`ExternalForce`), unless a wall is in
+///   the way; each one caught gets an `ExplosionHit`
+/// - a fireball and the blast sound (`ExplosionEffects`) where it went off; its glow
+///   follows `EffectsIntensity`
+/// - weapons opt in per projectile (`WeaponDelivery::Projectile::explosion`)
+///
+/// Design constraints:
+/// - Targets come from `CollisionWorld` overlap queries, so a broadphase behind it
+///   speeds blasts up with no change here.
+/// - Resolved on the fixed timestep before damage; presentation is Update-only.
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::ExplosionEffects>();
+        app.init_resource::<MeshLibrary>();
+        app.init_resource::<EffectBudget>();
+        app.init_resource::<EffectStats>();
+
+        app.add_message::<systems::Explosion>();
+        app.add_message::<systems::ExplosionHit>();
+        app.add_message::<DamageEvent>();
+
+        app.add_systems(Startup, systems::setup_explosion_assets);
+        app.add_systems(
+            FixedUpdate,
+            systems::resolve_explosions
+                .before(apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::apply_explosion_glow,
+                systems::spawn_explosion_effects,
+                systems::update_explosion_flashes,
+            )
+                .after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,300 @@
+// src/features/explosion/systems.rs
+use bevy::prelude::*;
+
+use super::component::{ExplosionAssets, ExplosionDef, ExplosionEffects, ExplosionFlash};
+use crate::app::EffectsIntensity;
+use crate::features::audio::component::{AudioBus, DucksMusic};
+use crate::features::collision::query::CollisionWorld;
+use crate::features::culling::effects::{CosmeticEffect, EffectDetail, EffectSpawns};
+use crate::features::health::component::Health;
+use crate::features::health::damage::{DamageEvent, IncomingAttack};
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::movement::component::ExternalForce;
+
+/// Something blew up at `position`.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct Explosion {
+    /// Who caused it (credited for the damage); `None` for environmental blasts.
+    pub source: Option<Entity>,
+    pub position: Vec3,
+    pub def: ExplosionDef,
+}
+
+/// A target caught in a blast (hit reactions, achievements).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct ExplosionHit {
+    pub source: Option<Entity>,
+    pub target: Entity,
+    /// Share of the full blast that reached the target (see `ExplosionDef::falloff`).
+    pub falloff: f32,
+}
+
+/// FixedUpdate (before damage): every collider in reach with a clear line from the
+/// blast takes falloff damage (`Health`) and a radial push (`ExternalForce`).
+///
+/// - Distance is measured to the nearest point of the target's collider, so big
+///   targets aren't spared by their size.
+/// - Walls block: the line from the blast to the target's center must reach the
+///   target before any other collider.
+pub fn resolve_explosions(
+    collision: CollisionWorld,
+    mut explosions: MessageReader<Explosion>,
+    mut q_targets: Query<(Has<Health>, Option<&mut ExternalForce>)>,
+    mut damage: MessageWriter<DamageEvent>,
+    mut hits: MessageWriter<ExplosionHit>,
+) {
+    for explosion in explosions.read() {
+        let Explosion {
+            source,
+            position,
+            def,
+        } = *explosion;
+        let ignore: Vec<Entity> = source.into_iter().collect();
+
+        for overlap in collision.overlap_sphere(position, def.radius, &[]) {
+            let target = overlap.entity;
+            let falloff = def.falloff(overlap.distance);
+            let Ok((damageable, force)) = q_targets.get_mut(target) else {
+                continue;
+            };
+            if falloff <= 0.0
+                || (!damageable && force.is_none())
+                || !collision.line_of_sight(position, target, &ignore)
+            {
+                continue;
+            }
+
+            let direction = (overlap.point - position).try_normalize().unwrap_or(Vec3::Y);
+            if damageable && def.damage > 0.0 {
+                let amount = def.damage * falloff;
+                damage.write(match source {
+                    Some(source) => DamageEvent::from_attack(
+                        target,
+                        amount,
+                        IncomingAttack {
+                            source,
+                            direction,
+                            parryable: false,
+                        },
+                    ),
+                    None => DamageEvent::new(target, amount),
+                });
+            }
+            if let Some(mut force) = force {
+                force.apply_impulse(direction * def.knockback * falloff);
+            }
+            hits.write(ExplosionHit {
+                source,
+                target,
+                falloff,
+            });
+        }
+    }
+}
+
+/// Startup: fireball mesh and material.
+pub fn setup_explosion_assets(
+    mut commands: Commands,
+    effects: Res<ExplosionEffects>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(ExplosionAssets {
+        mesh: meshes.get(MeshPrimitive::Sphere),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.6, 0.2),
+            emissive: effects.glow,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Update: the fireball glow follows `EffectsIntensity::flash`.
+pub fn apply_explosion_glow(
+    effects: Res<ExplosionEffects>,
+    intensity: Res<EffectsIntensity>,
+    assets: Option<Res<ExplosionAssets>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    if !(intensity.is_changed() || assets.is_changed()) {
+        return;
+    }
+    if let Some(material) = materials.get_mut(&assets.material) {
+        material.emissive = effects.glow * intensity.flash;
+    }
+}
+
+/// Update: fireball and blast sound for every explosion.
+///
+/// The fireball is cosmetic: skipped off-screen or out of reach (`EffectSpawns`);
+/// the sound always plays.
+pub fn spawn_explosion_effects(
+    mut commands: Commands,
+    effects: Res<ExplosionEffects>,
+    assets: Option<Res<ExplosionAssets>>,
+    asset_server: Option<Res<AssetServer>>,
+    mut explosions: MessageReader<Explosion>,
+    mut spawns: EffectSpawns,
+) {
+    for explosion in explosions.read() {
+        if let Some(assets) = &assets
+            && spawns.detail(explosion.position, explosion.def.radius) != EffectDetail::Skipped
+        {
+            commands.spawn((
+                CosmeticEffect,
+                ExplosionFlash {
+                    radius: explosion.def.radius,
+                    elapsed: 0.0,
+                    duration: effects.flash_secs,
+                },
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::from_translation(explosion.position).with_scale(Vec3::ZERO),
+                Name::new("Explosion"),
+            ));
+        }
+        if let (Some(server), Some(path)) = (&asset_server, &effects.sound) {
+            commands.spawn((
+                AudioBus::Sfx,
+                AudioPlayer::new(server.load(path.clone())),
+                PlaybackSettings::DESPAWN,
+                Transform::from_translation(explosion.position),
+                DucksMusic,
+            ));
+        }
+    }
+}
+
+/// Update: fireballs expand and vanish.
+pub fn update_explosion_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_flashes: Query<(Entity, &mut ExplosionFlash, &mut Transform)>,
+) {
+    for (entity, mut flash, mut transform) in &mut q_flashes {
+        flash.elapsed += time.delta_secs();
+        if flash.elapsed >= flash.duration {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.scale = Vec3::splat(flash.scale());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::collision::component::Collider;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    const BLAST: ExplosionDef = ExplosionDef {
+        radius: 4.0,
+        damage: 40.0,
+        knockback: 10.0,
+    };
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<Explosion>>();
+        world.init_resource::<Messages<ExplosionHit>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world
+    }
+
+    /// Unit box with health and a push velocity, centered at `at`.
+    fn spawn_target(world: &mut World, at: Vec3) -> Entity {
+        world
+            .spawn((
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::from_translation(at),
+                Health::new(100.0),
+                ExternalForce::default(),
+            ))
+            .id()
+    }
+
+    fn explode(world: &mut World, source: Option<Entity>) {
+        world.write_message(Explosion {
+            source,
+            position: Vec3::ZERO,
+            def: BLAST,
+        });
+        let _ = world.run_system_once(resolve_explosions);
+    }
+
+    fn damage(world: &World) -> Vec<DamageEvent> {
+        world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn damage_and_knockback_fall_off_with_distance() {
+        let mut world = setup_world();
+        let shooter = world.spawn_empty().id();
+        // Nearest faces 1 and 3 units away; the last one is out of reach.
+        let near = spawn_target(&mut world, Vec3::X * 1.5);
+        let far = spawn_target(&mut world, Vec3::NEG_Z * 3.5);
+        let out = spawn_target(&mut world, Vec3::Y * 5.0);
+
+        explode(&mut world, Some(shooter));
+
+        let damage = damage(&world);
+        assert_eq!(damage.len(), 2);
+        assert_eq!((damage[0].target, damage[0].amount), (near, 30.0));
+        assert_eq!((damage[1].target, damage[1].amount), (far, 10.0));
+        let attack = damage[0].attack.unwrap();
+        assert_eq!((attack.source, attack.direction), (shooter, Vec3::X));
+        assert!(!attack.parryable);
+
+        assert_eq!(world.get::<ExternalForce>(near).unwrap().velocity, Vec3::X * 7.5);
+        assert_eq!(world.get::<ExternalForce>(far).unwrap().velocity, Vec3::NEG_Z * 2.5);
+        assert_eq!(world.get::<ExternalForce>(out).unwrap().velocity, Vec3::ZERO);
+        assert_eq!(world.resource::<Messages<ExplosionHit>>().len(), 2);
+    }
+
+    #[test]
+    fn walls_shield_targets_behind_them() {
+        let mut world = setup_world();
+        let hidden = spawn_target(&mut world, Vec3::X * 2.5);
+        world.spawn((
+            Collider::cuboid(Vec3::new(0.1, 2.0, 2.0)),
+            GlobalTransform::from_translation(Vec3::X * 1.0),
+        ));
+        let exposed = spawn_target(&mut world, Vec3::NEG_X * 2.5);
+
+        explode(&mut world, None);
+
+        let damage = damage(&world);
+        assert_eq!(damage.len(), 1);
+        assert_eq!(damage[0].target, exposed);
+        assert_eq!(damage[0].attack, None, "environmental blasts are sourceless");
+        assert_eq!(world.get::<ExternalForce>(hidden).unwrap().velocity, Vec3::ZERO);
+    }
+
+    #[test]
+    fn fireball_glow_follows_the_effects_intensity() {
+        let mut world = World::new();
+        world.init_resource::<ExplosionEffects>();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.insert_resource(EffectsIntensity {
+            flash: 0.5,
+            ..default()
+        });
+        let _ = world.run_system_once(setup_explosion_assets);
+
+        let _ = world.run_system_once(apply_explosion_glow);
+
+        let handle = world.resource::<ExplosionAssets>().material.clone();
+        let glow = world.resource::<Assets<StandardMaterial>>().get(&handle).unwrap().emissive;
+        assert_eq!(glow, ExplosionEffects::default().glow * 0.5);
+    }
+}
@@ -0,0 +1,53 @@
+// src/features/fall_damage/component.rs
+use bevy::prelude::*;
+
+use crate::app::FallTuning;
+
+/// Peak downward speed of the current fall (`airborne` while no ground is below).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct FallTracker {
+    pub airborne: bool,
+    pub peak_fall_speed: f32,
+}
+
+/// What a landing at `speed` does to the lander.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LandingImpact {
+    /// Hit points lost (`0.0` = harmless).
+    pub damage: f32,
+    pub stagger: bool,
+}
+
+impl LandingImpact {
+    pub fn at_speed(tuning: &FallTuning, speed: f32) -> Self {
+        Self {
+            damage: (speed - tuning.safe_speed).max(0.0) * tuning.damage_per_speed,
+            stagger: speed > tuning.stagger_speed,
+        }
+    }
+
+    pub fn is_hard(&self) -> bool {
+        self.damage > 0.0 || self.stagger
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impact_scales_past_the_thresholds() {
+        let tuning = FallTuning::default();
+
+        let soft = LandingImpact::at_speed(&tuning, tuning.stagger_speed);
+        assert!(!soft.is_hard());
+
+        let stagger = LandingImpact::at_speed(&tuning, tuning.safe_speed);
+        assert_eq!(stagger.damage, 0.0);
+        assert!(stagger.stagger);
+
+        let hurt = LandingImpact::at_speed(&tuning, tuning.safe_speed + 2.0);
+        assert_eq!(hurt.damage, 2.0 * tuning.damage_per_speed);
+        assert!(hurt.stagger);
+    }
+}
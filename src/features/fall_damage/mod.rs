@@ -0,0 +1,36 @@
+// src/features/fall_damage/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::health::damage::DamageEvent;
+use crate::features::status::systems::ApplyStatusEffect;
+
+pub mod component;
+pub mod systems;
+
+/// Fall damage and landing impacts.
+///
+/// Scope:
+/// - `FallTracker` (players get one): peak downward speed while no ground is below
+/// - landing past `GameplayTuning::fall` thresholds: damage (`DamageEvent`), a brief
+///   stagger (Slow status effect) and `HardLanding` for camera shake / particles
+///
+/// Design constraints:
+/// - Ground is whatever `CollisionWorld` finds under the body, the same probe the
+///   surface feature uses for footsteps; no separate grounded state to keep in sync.
+pub struct FallDamagePlugin;
+
+impl Plugin for FallDamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<systems::HardLanding>();
+        app.add_message::<DamageEvent>();
+        app.add_message::<ApplyStatusEffect>();
+
+        app.add_systems(
+            FixedUpdate,
+            (systems::grant_fall_trackers, systems::track_falls)
+                .chain()
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
@@ -0,0 +1,179 @@
+// src/features/fall_damage/systems.rs
+use bevy::prelude::*;
+
+use super::component::{FallTracker, LandingImpact};
+use crate::app::GameplayTuning;
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::health::damage::DamageEvent;
+use crate::features::movement::component::{ExternalForce, Velocity};
+use crate::features::player::component::Player;
+use crate::features::status::component::StatusEffect;
+use crate::features::status::systems::ApplyStatusEffect;
+use crate::features::surface::component::GROUND_PROBE_MARGIN;
+
+/// Half height assumed for movers without a `Collider` (the player box).
+const DEFAULT_HALF_HEIGHT: f32 = 0.5;
+
+/// A landing past the fall thresholds (camera shake, dust, grunts hook in here).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct HardLanding {
+    pub entity: Entity,
+    /// Peak downward speed of the fall.
+    pub speed: f32,
+    pub impact: LandingImpact,
+    pub position: Vec3,
+}
+
+/// FixedUpdate: players take fall damage.
+pub fn grant_fall_trackers(
+    mut commands: Commands,
+    q_players: Query<Entity, (Added<Player>, Without<FallTracker>)>,
+) {
+    for entity in &q_players {
+        commands.entity(entity).insert(FallTracker::default());
+    }
+}
+
+/// FixedUpdate (after integration): track the peak fall speed while nothing is below,
+/// and resolve the landing once ground is back under the body.
+///
+/// - Vertical speed is `Velocity` plus the `ExternalForce` push (knockback, gravity).
+/// - Damage goes out as sourceless `DamageEvent`, the stagger as a Slow status effect;
+///   both scale from `GameplayTuning::fall`.
+#[expect(clippy::type_complexity)]
+pub fn track_falls(
+    tuning: Res<GameplayTuning>,
+    collision: CollisionWorld,
+    mut q_bodies: Query<(
+        Entity,
+        &Transform,
+        Option<&Collider>,
+        Option<&Velocity>,
+        Option<&ExternalForce>,
+        &mut FallTracker,
+    )>,
+    mut damage: MessageWriter<DamageEvent>,
+    mut status: MessageWriter<ApplyStatusEffect>,
+    mut landings: MessageWriter<HardLanding>,
+) {
+    let fall = &tuning.fall;
+    for (entity, transform, collider, velocity, external, mut tracker) in &mut q_bodies {
+        let vertical =
+            velocity.map_or(0.0, |v| v.0.y) + external.map_or(0.0, |e| e.velocity.y);
+        let half_height = collider.map_or(DEFAULT_HALF_HEIGHT, |c| c.half_extents.y);
+        let grounded = collision
+            .ray_cast(
+                transform.translation,
+                Vec3::NEG_Y,
+                half_height + GROUND_PROBE_MARGIN,
+                &[entity],
+            )
+            .is_some();
+
+        if !grounded {
+            tracker.airborne = true;
+            tracker.peak_fall_speed = tracker.peak_fall_speed.max(-vertical);
+            continue;
+        }
+        if !tracker.airborne {
+            continue;
+        }
+
+        let speed = std::mem::take(&mut *tracker).peak_fall_speed;
+        let impact = LandingImpact::at_speed(fall, speed);
+        if !impact.is_hard() {
+            continue;
+        }
+        if impact.damage > 0.0 {
+            damage.write(DamageEvent::new(entity, impact.damage));
+        }
+        if impact.stagger {
+            status.write(ApplyStatusEffect {
+                target: entity,
+                effect: StatusEffect::slow(fall.stagger_slow, fall.stagger_secs),
+            });
+        }
+        landings.write(HardLanding {
+            entity,
+            speed,
+            impact,
+            position: transform.translation,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<GameplayTuning>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<ApplyStatusEffect>>();
+        world.init_resource::<Messages<HardLanding>>();
+        // Ground slab, top at y = 0.
+        world.spawn((
+            Collider::cuboid(Vec3::new(10.0, 0.5, 10.0)),
+            GlobalTransform::from_translation(Vec3::NEG_Y * 0.5),
+        ));
+        world
+    }
+
+    /// Fall from high up at `speed`, then land.
+    fn fall_and_land(world: &mut World, speed: f32) -> Entity {
+        let body = world
+            .spawn((
+                FallTracker::default(),
+                Transform::from_xyz(0.0, 10.0, 0.0),
+                ExternalForce {
+                    velocity: Vec3::NEG_Y * speed,
+                    ..default()
+                },
+            ))
+            .id();
+        let _ = world.run_system_once(track_falls);
+        assert!(world.get::<FallTracker>(body).unwrap().airborne);
+
+        world.get_mut::<Transform>(body).unwrap().translation.y = 0.5;
+        world.get_mut::<ExternalForce>(body).unwrap().velocity = Vec3::ZERO;
+        let _ = world.run_system_once(track_falls);
+        body
+    }
+
+    #[test]
+    fn short_drops_land_softly() {
+        let mut world = setup_world();
+        let body = fall_and_land(&mut world, 5.0);
+
+        assert_eq!(*world.get::<FallTracker>(body).unwrap(), FallTracker::default());
+        assert_eq!(world.resource::<Messages<HardLanding>>().len(), 0);
+        assert_eq!(world.resource::<Messages<DamageEvent>>().len(), 0);
+    }
+
+    #[test]
+    fn long_falls_hurt_and_stagger() {
+        let mut world = setup_world();
+        let fall = world.resource::<GameplayTuning>().fall;
+        let body = fall_and_land(&mut world, fall.safe_speed + 3.0);
+
+        let damage: Vec<_> = world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(damage, [DamageEvent::new(body, 3.0 * fall.damage_per_speed)]);
+        assert_eq!(world.resource::<Messages<ApplyStatusEffect>>().len(), 1);
+        let landing = world
+            .resource::<Messages<HardLanding>>()
+            .iter_current_update_messages()
+            .next()
+            .copied()
+            .unwrap();
+        assert_eq!(landing.entity, body);
+        assert_eq!(landing.speed, fall.safe_speed + 3.0);
+    }
+}
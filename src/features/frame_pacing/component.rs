@@ -0,0 +1,20 @@
+// src/features/frame_pacing/component.rs
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Frame rate cap in effect (from `UserSettings::performance`).
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameLimiter {
+    /// `None` = uncapped.
+    pub fps_cap: Option<u32>,
+}
+
+impl FrameLimiter {
+    /// Shortest time a frame may take.
+    pub fn frame_time(&self) -> Option<Duration> {
+        self.fps_cap
+            .filter(|fps| *fps > 0)
+            .map(|fps| Duration::from_secs_f64(1.0 / fps as f64))
+    }
+}
@@ -0,0 +1,28 @@
+// src/features/frame_pacing/mod.rs
+use bevy::prelude::*;
+
+pub mod component;
+pub mod systems;
+
+/// Frame rate cap and background power saving.
+///
+/// Scope:
+/// - optional FPS cap (`UserSettings::performance.fps_cap`): the frame sleeps out
+///   whatever is left of its time slice, windowed or headless (servers sharing a host)
+/// - power saving: while the window is unfocused or minimized the app updates at
+///   `background_fps` instead of continuously (`WinitSettings`)
+///
+/// Design constraints:
+/// - only the render / `Update` rate changes; `FixedUpdate` still runs every step the
+///   elapsed time calls for, and rates are clamped so no frame exceeds the virtual
+///   clock's `max_delta` (which would silently drop simulated time)
+pub struct FramePacingPlugin;
+
+impl Plugin for FramePacingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::FrameLimiter>();
+
+        app.add_systems(Update, systems::apply_performance_settings);
+        app.add_systems(Last, systems::limit_frame_rate);
+    }
+}
@@ -0,0 +1,113 @@
+// src/features/frame_pacing/systems.rs
+use std::time::Instant;
+
+use bevy::prelude::*;
+use bevy::winit::{UpdateMode, WinitSettings};
+
+use super::component::FrameLimiter;
+use crate::features::settings::component::UserSettings;
+
+/// Update: push the stored performance settings into the limiter and the window loop.
+///
+/// Power saving switches the unfocused (also minimized) mode to a low-power wait;
+/// otherwise the game keeps updating continuously in the background.
+pub fn apply_performance_settings(
+    settings: Res<UserSettings>,
+    mut limiter: ResMut<FrameLimiter>,
+    winit: Option<ResMut<WinitSettings>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let performance = settings.performance;
+    limiter.set_if_neq(FrameLimiter {
+        fps_cap: performance.fps_cap(),
+    });
+    if let Some(mut winit) = winit {
+        winit.focused_mode = UpdateMode::Continuous;
+        winit.unfocused_mode = match performance.background_wait() {
+            Some(wait) => UpdateMode::reactive_low_power(wait),
+            None => UpdateMode::Continuous,
+        };
+    }
+}
+
+/// Last: sleep out the rest of the frame when a cap is set.
+///
+/// Only rendering and `Update` slow down: `FixedUpdate` catches up from the elapsed
+/// time, and the settings keep frames short enough that none of it is dropped.
+pub fn limit_frame_rate(limiter: Res<FrameLimiter>, mut frame_start: Local<Option<Instant>>) {
+    if let (Some(frame_time), Some(start)) = (limiter.frame_time(), *frame_start) {
+        let elapsed = start.elapsed();
+        if elapsed < frame_time {
+            std::thread::sleep(frame_time - elapsed);
+        }
+    }
+    *frame_start = Some(Instant::now());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::settings::component::PerformanceSettings;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    #[test]
+    fn settings_drive_the_cap_and_background_mode() {
+        let mut world = World::new();
+        world.init_resource::<FrameLimiter>();
+        world.insert_resource(WinitSettings::game());
+        world.insert_resource(UserSettings {
+            performance: PerformanceSettings {
+                fps_cap: Some(60),
+                power_saving: true,
+                background_fps: 10,
+            },
+            ..default()
+        });
+
+        let _ = world.run_system_once(apply_performance_settings);
+
+        assert_eq!(world.resource::<FrameLimiter>().fps_cap, Some(60));
+        let winit = world.resource::<WinitSettings>();
+        assert!(matches!(winit.focused_mode, UpdateMode::Continuous));
+        assert!(matches!(
+            winit.unfocused_mode,
+            UpdateMode::Reactive { wait, .. } if wait == Duration::from_millis(100)
+        ));
+
+        world.resource_mut::<UserSettings>().performance.power_saving = false;
+        let _ = world.run_system_once(apply_performance_settings);
+        assert!(matches!(
+            world.resource::<WinitSettings>().unfocused_mode,
+            UpdateMode::Continuous
+        ));
+    }
+
+    #[test]
+    fn capped_frames_take_at_least_the_frame_time() {
+        let mut world = World::new();
+        world.insert_resource(FrameLimiter { fps_cap: Some(50) });
+        let mut schedule = Schedule::default();
+        schedule.add_systems(limit_frame_rate);
+
+        schedule.run(&mut world);
+        let start = Instant::now();
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn fixed_steps_keep_up_with_background_frames() {
+        // A 5 fps background frame still advances the simulation by all of its time.
+        let slowest = PerformanceSettings {
+            background_fps: 0,
+            ..default()
+        };
+        let frame = slowest.background_wait().unwrap();
+        assert!(frame < Time::<Virtual>::default().max_delta());
+    }
+}
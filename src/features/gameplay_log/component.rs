@@ -0,0 +1,142 @@
+// src/features/gameplay_log/component.rs
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{GameMode, StoragePaths};
+
+/// Current log format; the first record of every file (`SessionStarted`) names it.
+pub const LOG_FORMAT: u32 = 1;
+
+/// A world position in the log.
+pub type LogPosition = (f32, f32, f32);
+
+/// Gameplay event as written to the log. Entities are `Entity::to_bits` (only
+/// meaningful within one session).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum LoggedEvent {
+    /// First record of every session.
+    SessionStarted { format: u32, unix_secs: u64 },
+    RunStarted { mode: GameMode },
+    Damage {
+        target: u64,
+        amount: f32,
+        position: Option<LogPosition>,
+    },
+    Died {
+        entity: u64,
+        position: Option<LogPosition>,
+    },
+    PlayerSpawned {
+        entity: u64,
+        position: Option<LogPosition>,
+    },
+    PlayerDied {
+        entity: u64,
+        position: Option<LogPosition>,
+    },
+    PlayerRespawned { entity: u64 },
+    SceneLoaded { name: String },
+    SceneUnloaded { name: String },
+    PortalArrived {
+        entity: u64,
+        from: LogPosition,
+        to: LogPosition,
+    },
+}
+
+/// One line of the log: an event stamped with the `SimTick` it happened on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub tick: u64,
+    #[serde(flatten)]
+    pub event: LoggedEvent,
+}
+
+/// Whether (and where) to record this session.
+///
+/// Off unless `--record-gameplay` is passed or `TO_BE_FREE_RECORD_GAMEPLAY` is `1` /
+/// `true`; logs go to `<data dir>/logs`.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct GameplayLogSettings {
+    pub enabled: bool,
+    pub dir: PathBuf,
+}
+
+impl GameplayLogSettings {
+    /// Settings for these arguments and environment.
+    pub fn resolve(
+        args: &[String],
+        env: impl Fn(&str) -> Option<String>,
+        paths: &StoragePaths,
+    ) -> Self {
+        let enabled = args.iter().any(|a| a == "--record-gameplay")
+            || env("TO_BE_FREE_RECORD_GAMEPLAY").is_some_and(|v| v == "1" || v == "true");
+        Self {
+            enabled,
+            dir: paths.data_file("logs"),
+        }
+    }
+}
+
+impl FromWorld for GameplayLogSettings {
+    fn from_world(world: &mut World) -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        Self::resolve(&args, |name| std::env::var(name).ok(), &StoragePaths::of(world))
+    }
+}
+
+/// The open log of this session (only present while recording).
+#[derive(Resource, Debug)]
+pub struct GameplayLog {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    written: u64,
+}
+
+impl GameplayLog {
+    /// Create `session-<unix_secs>.jsonl` in `dir` and write its `SessionStarted`.
+    pub fn create(dir: &Path, unix_secs: u64) -> std::io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("session-{unix_secs}.jsonl"));
+        let writer = BufWriter::new(File::create(&path)?);
+        let mut log = Self {
+            path,
+            writer,
+            written: 0,
+        };
+        log.append(&LogRecord {
+            tick: 0,
+            event: LoggedEvent::SessionStarted {
+                format: LOG_FORMAT,
+                unix_secs,
+            },
+        })?;
+        Ok(log)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records written so far (including `SessionStarted`).
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Append one record (buffered until `flush`).
+    pub fn append(&mut self, record: &LogRecord) -> std::io::Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")?;
+        self.written += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
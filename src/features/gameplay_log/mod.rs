@@ -0,0 +1,56 @@
+// src/features/gameplay_log/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{RunStarted, SimTick};
+use crate::features::health::damage::{DamageEvent, Died};
+use crate::features::player::bundles::PlayerSpawned;
+use crate::features::player::death::{PlayerDied, PlayerRespawned};
+use crate::features::portal::systems::PortalArrived;
+use crate::features::scenes::systems::{SceneLoaded, SceneUnloaded};
+
+pub mod component;
+pub mod reader;
+pub mod systems;
+
+/// Gameplay event log (post-mortem debugging, analytics).
+///
+/// Scope:
+/// - opt-in recording (`--record-gameplay` / `TO_BE_FREE_RECORD_GAMEPLAY=1`,
+///   `GameplayLogSettings`): every session appends its gameplay events, stamped
+///   with `SimTick`, to `<data dir>/logs/session-<unix secs>.jsonl`, one JSON record
+///   per line
+/// - recorded: damage, deaths and player spawns / respawns with positions, run
+///   starts, scene loads / unloads, portal arrivals
+/// - `reader::SessionLog`: offline reading ("what happened around tick 4312?") and
+///   simple analytics (where players die, damage taken)
+///
+/// Design constraints:
+/// - Not recording costs nothing: without an open `GameplayLog` no system runs.
+/// - The file is flushed every frame; a crash loses at most the last frame and the
+///   reader skips a half-written last line.
+/// - A new event kind is a new `LoggedEvent` variant; changing an existing one bumps
+///   `LOG_FORMAT`.
+pub struct GameplayLogPlugin;
+
+impl Plugin for GameplayLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::GameplayLogSettings>();
+        app.init_resource::<SimTick>();
+
+        // Recorded messages; registering them here too is idempotent.
+        app.add_message::<DamageEvent>();
+        app.add_message::<Died>();
+        app.add_message::<PlayerSpawned>();
+        app.add_message::<PlayerDied>();
+        app.add_message::<PlayerRespawned>();
+        app.add_message::<RunStarted>();
+        app.add_message::<SceneLoaded>();
+        app.add_message::<SceneUnloaded>();
+        app.add_message::<PortalArrived>();
+
+        let recording = resource_exists::<component::GameplayLog>;
+        app.add_systems(Startup, systems::open_gameplay_log);
+        app.add_systems(FixedLast, systems::record_simulation_events.run_if(recording));
+        app.add_systems(Last, systems::record_frame_events.run_if(recording));
+    }
+}
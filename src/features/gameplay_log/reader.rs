@@ -0,0 +1,162 @@
+// src/features/gameplay_log/reader.rs
+//! Offline access to recorded gameplay logs (bug reports, analytics tools); nothing
+//! here needs a running app.
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use super::component::{LOG_FORMAT, LogPosition, LogRecord, LoggedEvent};
+
+/// Why a log couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogError {
+    Io(String),
+    /// Line `line` (1-based) isn't a record.
+    Malformed { line: usize, reason: String },
+    /// The first record isn't a `SessionStarted` this build understands.
+    UnknownFormat(Option<u32>),
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Malformed { line, reason } => write!(f, "line {line}: {reason}"),
+            Self::UnknownFormat(Some(format)) => {
+                write!(f, "log format {format} is not {LOG_FORMAT}")
+            }
+            Self::UnknownFormat(None) => write!(f, "log doesn't start with a session header"),
+        }
+    }
+}
+
+/// A recorded session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionLog {
+    /// Every record, `SessionStarted` first.
+    pub records: Vec<LogRecord>,
+}
+
+impl SessionLog {
+    /// Parse log text. A truncated last line (the game crashed mid-write) is dropped.
+    pub fn parse(source: &str) -> Result<Self, LogError> {
+        let lines: Vec<&str> = source.lines().filter(|line| !line.trim().is_empty()).collect();
+        let mut records = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            match serde_json::from_str::<LogRecord>(line) {
+                Ok(record) => records.push(record),
+                Err(err) if err.is_eof() && i + 1 == lines.len() => break,
+                Err(err) => {
+                    return Err(LogError::Malformed {
+                        line: i + 1,
+                        reason: err.to_string(),
+                    });
+                }
+            }
+        }
+        match records.first().map(|r| &r.event) {
+            Some(LoggedEvent::SessionStarted { format, .. }) if *format == LOG_FORMAT => {}
+            Some(LoggedEvent::SessionStarted { format, .. }) => {
+                return Err(LogError::UnknownFormat(Some(*format)));
+            }
+            _ => return Err(LogError::UnknownFormat(None)),
+        }
+        Ok(Self { records })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, LogError> {
+        let source = std::fs::read_to_string(path).map_err(|err| LogError::Io(err.to_string()))?;
+        Self::parse(&source)
+    }
+
+    /// Records on ticks `from..=to`.
+    pub fn between(&self, from: u64, to: u64) -> impl Iterator<Item = &LogRecord> {
+        self.records.iter().filter(move |r| (from..=to).contains(&r.tick))
+    }
+
+    /// Where players died, with the tick.
+    pub fn player_deaths(&self) -> impl Iterator<Item = (u64, LogPosition)> + '_ {
+        self.records.iter().filter_map(|r| match r.event {
+            LoggedEvent::PlayerDied {
+                position: Some(position),
+                ..
+            } => Some((r.tick, position)),
+            _ => None,
+        })
+    }
+
+    /// Player deaths per `cell`-sized square of the ground plane (X / Z), keyed by
+    /// cell coordinates.
+    pub fn death_heatmap(&self, cell: f32) -> HashMap<(i32, i32), u32> {
+        let mut cells = HashMap::new();
+        for (_, (x, _, z)) in self.player_deaths() {
+            let key = ((x / cell).floor() as i32, (z / cell).floor() as i32);
+            *cells.entry(key).or_insert(0) += 1;
+        }
+        cells
+    }
+
+    /// Total damage taken per target.
+    pub fn damage_by_target(&self) -> HashMap<u64, f32> {
+        let mut totals = HashMap::new();
+        for record in &self.records {
+            if let LoggedEvent::Damage { target, amount, .. } = record.event {
+                *totals.entry(target).or_insert(0.0) += amount;
+            }
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(lines: &[&str]) -> String {
+        let mut text = format!(
+            "{{\"tick\":0,\"type\":\"SessionStarted\",\"format\":{LOG_FORMAT},\"unix_secs\":1}}\n"
+        );
+        for line in lines {
+            text.push_str(line);
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn deaths_bucket_into_heatmap_cells() {
+        let log = SessionLog::parse(&session(&[
+            r#"{"tick":10,"type":"PlayerDied","entity":1,"position":[1.0,0.0,1.0]}"#,
+            r#"{"tick":20,"type":"PlayerDied","entity":1,"position":[3.0,0.0,2.0]}"#,
+            r#"{"tick":30,"type":"PlayerDied","entity":1,"position":[-1.0,0.0,12.0]}"#,
+            r#"{"tick":31,"type":"Damage","target":2,"amount":5.0,"position":null}"#,
+            r#"{"tick":32,"type":"Damage","target":2,"amount":2.5,"position":null}"#,
+        ]))
+        .unwrap();
+
+        let heatmap = log.death_heatmap(10.0);
+        assert_eq!(heatmap[&(0, 0)], 2);
+        assert_eq!(heatmap[&(-1, 1)], 1);
+        assert_eq!(log.damage_by_target()[&2], 7.5);
+        assert_eq!(log.between(20, 31).count(), 3);
+    }
+
+    #[test]
+    fn truncated_last_line_is_dropped_but_garbage_is_not() {
+        let log = SessionLog::parse(&session(&[
+            r#"{"tick":5,"type":"SceneLoaded","name":"hub"}"#,
+            r#"{"tick":6,"type":"SceneLoa"#,
+        ]))
+        .unwrap();
+        assert_eq!(log.records.len(), 2);
+
+        assert!(matches!(
+            SessionLog::parse(&session(&["nonsense", r#"{"tick":6,"type":"PlayerRespawned","entity":1}"#])),
+            Err(LogError::Malformed { line: 2, .. })
+        ));
+        assert_eq!(
+            SessionLog::parse(r#"{"tick":5,"type":"SceneLoaded","name":"hub"}"#),
+            Err(LogError::UnknownFormat(None))
+        );
+    }
+}
@@ -0,0 +1,203 @@
+// src/features/gameplay_log/systems.rs
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use super::component::{GameplayLog, GameplayLogSettings, LogPosition, LogRecord, LoggedEvent};
+use crate::app::{RunStarted, SimTick};
+use crate::features::health::damage::{DamageEvent, Died};
+use crate::features::player::bundles::PlayerSpawned;
+use crate::features::player::death::{PlayerDied, PlayerRespawned};
+use crate::features::portal::systems::PortalArrived;
+use crate::features::scenes::systems::{SceneLoaded, SceneUnloaded};
+
+/// Startup: open this session's log when recording is enabled.
+pub fn open_gameplay_log(mut commands: Commands, settings: Res<GameplayLogSettings>) {
+    if !settings.enabled {
+        return;
+    }
+    let unix_secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    match GameplayLog::create(&settings.dir, unix_secs) {
+        Ok(log) => {
+            info!("recording gameplay to {}", log.path().display());
+            commands.insert_resource(log);
+        }
+        Err(err) => warn!("could not open a gameplay log in {}: {err}", settings.dir.display()),
+    }
+}
+
+/// Append `events` stamped with `tick`; stop recording (drop the log) on a write
+/// error rather than warning every frame.
+fn append_all(commands: &mut Commands, log: &mut GameplayLog, tick: u64, events: Vec<LoggedEvent>) {
+    for event in events {
+        if let Err(err) = log.append(&LogRecord { tick, event }) {
+            warn!("gameplay log {} stopped: {err}", log.path().display());
+            commands.remove_resource::<GameplayLog>();
+            return;
+        }
+    }
+}
+
+/// FixedLast: log this step's simulation events (damage, deaths, spawns) with where
+/// they happened.
+#[expect(clippy::too_many_arguments)]
+pub fn record_simulation_events(
+    mut commands: Commands,
+    tick: Res<SimTick>,
+    mut log: ResMut<GameplayLog>,
+    q_transforms: Query<&Transform>,
+    mut damage: MessageReader<DamageEvent>,
+    mut died: MessageReader<Died>,
+    mut spawned: MessageReader<PlayerSpawned>,
+    mut player_died: MessageReader<PlayerDied>,
+    mut respawned: MessageReader<PlayerRespawned>,
+) {
+    let position = |entity: Entity| -> Option<LogPosition> {
+        q_transforms.get(entity).ok().map(|tr| tr.translation.into())
+    };
+    let mut events = Vec::new();
+    for ev in spawned.read() {
+        events.push(LoggedEvent::PlayerSpawned {
+            entity: ev.entity.to_bits(),
+            position: position(ev.entity),
+        });
+    }
+    for ev in damage.read() {
+        events.push(LoggedEvent::Damage {
+            target: ev.target.to_bits(),
+            amount: ev.amount,
+            position: position(ev.target),
+        });
+    }
+    for ev in died.read() {
+        events.push(LoggedEvent::Died {
+            entity: ev.entity.to_bits(),
+            position: position(ev.entity),
+        });
+    }
+    for ev in player_died.read() {
+        events.push(LoggedEvent::PlayerDied {
+            entity: ev.entity.to_bits(),
+            position: position(ev.entity),
+        });
+    }
+    for ev in respawned.read() {
+        events.push(LoggedEvent::PlayerRespawned {
+            entity: ev.entity.to_bits(),
+        });
+    }
+    append_all(&mut commands, &mut log, tick.0, events);
+}
+
+/// Last: log this frame's world events (runs, scenes, portals) with the latest
+/// tick, then flush so a crash loses at most one frame.
+pub fn record_frame_events(
+    mut commands: Commands,
+    tick: Res<SimTick>,
+    mut log: ResMut<GameplayLog>,
+    mut runs: MessageReader<RunStarted>,
+    mut loaded: MessageReader<SceneLoaded>,
+    mut unloaded: MessageReader<SceneUnloaded>,
+    mut arrived: MessageReader<PortalArrived>,
+) {
+    let mut events = Vec::new();
+    for ev in runs.read() {
+        events.push(LoggedEvent::RunStarted { mode: ev.mode });
+    }
+    for ev in loaded.read() {
+        events.push(LoggedEvent::SceneLoaded {
+            name: ev.name.clone(),
+        });
+    }
+    for ev in unloaded.read() {
+        events.push(LoggedEvent::SceneUnloaded {
+            name: ev.name.clone(),
+        });
+    }
+    for ev in arrived.read() {
+        events.push(LoggedEvent::PortalArrived {
+            entity: ev.player.to_bits(),
+            from: ev.from.into(),
+            to: ev.to.into(),
+        });
+    }
+    append_all(&mut commands, &mut log, tick.0, events);
+    if let Err(err) = log.flush() {
+        warn!("gameplay log {} stopped: {err}", log.path().display());
+        commands.remove_resource::<GameplayLog>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::gameplay_log::reader::SessionLog;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn recorded_sessions_read_back_with_ticks_and_positions() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_gameplay_log_{}", std::process::id()));
+        let mut world = World::new();
+        world.insert_resource(GameplayLogSettings {
+            enabled: true,
+            dir: dir.clone(),
+        });
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<PlayerSpawned>>();
+        world.init_resource::<Messages<PlayerDied>>();
+        world.init_resource::<Messages<PlayerRespawned>>();
+        world.init_resource::<Messages<RunStarted>>();
+        world.init_resource::<Messages<SceneLoaded>>();
+        world.init_resource::<Messages<SceneUnloaded>>();
+        world.init_resource::<Messages<PortalArrived>>();
+        let _ = world.run_system_once(open_gameplay_log);
+        let path = world.resource::<GameplayLog>().path().to_owned();
+
+        let player = world.spawn(Transform::from_xyz(3.0, 0.0, -4.0)).id();
+        world.insert_resource(SimTick(120));
+        world.write_message(DamageEvent::new(player, 25.0));
+        world.write_message(PlayerDied { entity: player });
+        world.write_message(SceneLoaded { name: "grove".into() });
+        let _ = world.run_system_once(record_simulation_events);
+        let _ = world.run_system_once(record_frame_events);
+
+        let log = SessionLog::load(&path).unwrap();
+        let events: Vec<_> = log.records.iter().map(|r| (r.tick, r.event.clone())).collect();
+        assert_eq!(events.len(), 4, "{events:?}");
+        assert_eq!(
+            events[1],
+            (
+                120,
+                LoggedEvent::Damage {
+                    target: player.to_bits(),
+                    amount: 25.0,
+                    position: Some((3.0, 0.0, -4.0)),
+                }
+            )
+        );
+        assert_eq!(log.player_deaths().collect::<Vec<_>>(), [(120, (3.0, 0.0, -4.0))]);
+        assert_eq!(events[3], (120, LoggedEvent::SceneLoaded { name: "grove".into() }));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn recording_is_opt_in() {
+        let mut world = World::new();
+        world.insert_resource(GameplayLogSettings {
+            enabled: false,
+            dir: std::env::temp_dir().join("to_be_free_gameplay_log_disabled"),
+        });
+        let _ = world.run_system_once(open_gameplay_log);
+        assert!(!world.contains_resource::<GameplayLog>());
+
+        let paths = crate::app::StoragePaths::in_dir("/data");
+        let args = ["--record-gameplay".to_string()];
+        assert!(GameplayLogSettings::resolve(&args, |_| None, &paths).enabled);
+        assert!(GameplayLogSettings::resolve(&[], |_| Some("1".into()), &paths).enabled);
+        assert!(!GameplayLogSettings::resolve(&[], |_| None, &paths).enabled);
+    }
+}
@@ -0,0 +1,99 @@
+// src/features/harvest/component.rs
+use bevy::prelude::*;
+
+/// Prefab kinds of harvestable node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceNodeKind {
+    Tree,
+    Rock,
+}
+
+/// Data describing a node prefab (what it yields, how tough it is).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceNodeDef {
+    /// Inventory item id granted per hit.
+    pub item: &'static str,
+    pub yield_per_hit: u32,
+    pub hit_points: u32,
+    pub respawn_secs: f32,
+    /// Collider-ish footprint for interaction / visuals (half extents).
+    pub half_extents: Vec3,
+    pub color: Color,
+}
+
+impl ResourceNodeKind {
+    pub fn def(self) -> ResourceNodeDef {
+        match self {
+            ResourceNodeKind::Tree => ResourceNodeDef {
+                item: "wood",
+                yield_per_hit: 2,
+                hit_points: 4,
+                respawn_secs: 30.0,
+                half_extents: Vec3::new(0.3, 1.5, 0.3),
+                color: Color::srgb_u8(110, 80, 50),
+            },
+            ResourceNodeKind::Rock => ResourceNodeDef {
+                item: "stone",
+                yield_per_hit: 1,
+                hit_points: 6,
+                respawn_secs: 45.0,
+                half_extents: Vec3::new(0.6, 0.4, 0.6),
+                color: Color::srgb_u8(130, 130, 135),
+            },
+        }
+    }
+}
+
+/// A harvestable node in the world (tree, rock).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ResourceNode {
+    pub kind: ResourceNodeKind,
+    pub hit_points: u32,
+}
+
+impl ResourceNode {
+    pub fn new(kind: ResourceNodeKind) -> Self {
+        Self {
+            kind,
+            hit_points: kind.def().hit_points,
+        }
+    }
+}
+
+/// Marker + countdown: the node has been harvested out and is waiting to regrow.
+///
+/// Depleted nodes are hidden and ignore hits.
+#[derive(Component, Debug, Clone)]
+pub struct Depleted(pub Timer);
+
+/// Query filter: nodes that can currently be harvested.
+pub type HarvestableNode = (With<ResourceNode>, Without<Depleted>);
+
+/// Scene data: which node prefabs to place where.
+///
+/// This is the authoring surface for nodes; swapping the resource (or loading it
+/// from a level file later) changes the layout without touching systems.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ResourceNodeLayout(pub Vec<(ResourceNodeKind, Vec3)>);
+
+impl Default for ResourceNodeLayout {
+    fn default() -> Self {
+        // Placed on the ground disc, away from the spawn point.
+        Self(vec![
+            (ResourceNodeKind::Tree, Vec3::new(-3.5, 0.0, -2.0)),
+            (ResourceNodeKind::Tree, Vec3::new(-4.0, 0.0, 1.5)),
+            (ResourceNodeKind::Rock, Vec3::new(3.0, 0.0, -3.0)),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_node_starts_with_prefab_hit_points() {
+        let node = ResourceNode::new(ResourceNodeKind::Rock);
+        assert_eq!(node.hit_points, ResourceNodeKind::Rock.def().hit_points);
+    }
+}
@@ -0,0 +1,43 @@
+// src/features/harvest/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::inventory::ItemsAdded;
+
+pub mod component;
+pub mod systems;
+
+/// Harvestable resource nodes (trees, rocks).
+///
+/// Scope:
+/// - Startup: node prefabs spawned from `ResourceNodeLayout` scene data
+/// - Update: gather key -> `HarvestHit` on the nearest node in reach
+/// - FixedUpdate: hits -> items into the harvester's `Inventory` -> depleted -> timed regrow
+///
+/// Other features (weapons, tools) can send `HarvestHit` directly.
+pub struct HarvestPlugin;
+
+impl Plugin for HarvestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::ResourceNodeLayout>();
+        app.init_resource::<systems::GatherKeybinding>();
+
+        app.add_message::<systems::HarvestHit>();
+        app.add_message::<systems::NodeDepleted>();
+        app.add_message::<ItemsAdded>();
+
+        app.add_systems(Startup, systems::spawn_resource_nodes);
+        app.add_systems(
+            Update,
+            systems::read_gather_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (systems::apply_harvest_hits, systems::respawn_depleted_nodes)
+                .chain()
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
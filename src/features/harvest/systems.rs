@@ -0,0 +1,258 @@
+// src/features/harvest/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Depleted, HarvestableNode, ResourceNode, ResourceNodeLayout};
+use crate::features::inventory::ItemsAdded;
+use crate::features::inventory::component::Inventory;
+use crate::features::player::death::AlivePlayer;
+
+/// Max distance (world units) between a harvester and a node's origin.
+pub const GATHER_REACH: f32 = 2.5;
+
+/// Key used for the gather interaction.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct GatherKeybinding(pub KeyCode);
+
+impl Default for GatherKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::KeyG)
+    }
+}
+
+/// One hit against a node (gather interaction, tool / weapon hit).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HarvestHit {
+    pub node: Entity,
+    pub harvester: Entity,
+}
+
+/// Emitted when a node runs out (UI / audio / VFX hook).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeDepleted {
+    pub node: Entity,
+}
+
+/// Startup: spawn node prefabs from the layout resource.
+pub fn spawn_resource_nodes(
+    mut commands: Commands,
+    layout: Res<ResourceNodeLayout>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for &(kind, position) in &layout.0 {
+        let def = kind.def();
+        commands.spawn((
+            ResourceNode::new(kind),
+            Mesh3d(meshes.add(Cuboid::from_size(def.half_extents * 2.0))),
+            MeshMaterial3d(materials.add(def.color)),
+            // Layout positions are ground points; lift so the node rests on them.
+            Transform::from_translation(position + Vec3::Y * def.half_extents.y),
+            Name::new(format!("{kind:?}")),
+        ));
+    }
+}
+
+/// Update: gather key -> `HarvestHit` on the nearest live node in reach.
+pub fn read_gather_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<GatherKeybinding>,
+    q_players: Query<(Entity, &Transform), AlivePlayer>,
+    q_nodes: Query<(Entity, &Transform), HarvestableNode>,
+    mut hits: MessageWriter<HarvestHit>,
+) {
+    if !keyboard.just_pressed(binding.0) {
+        return;
+    }
+
+    for (player, player_tr) in &q_players {
+        let nearest = q_nodes
+            .iter()
+            .map(|(e, tr)| (e, tr.translation.distance(player_tr.translation)))
+            .filter(|(_, d)| *d <= GATHER_REACH)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((node, _)) = nearest {
+            hits.write(HarvestHit {
+                node,
+                harvester: player,
+            });
+        }
+    }
+}
+
+/// FixedUpdate: apply hits -> yield items -> deplete at zero hit points.
+pub fn apply_harvest_hits(
+    mut commands: Commands,
+    mut hits: MessageReader<HarvestHit>,
+    mut q_nodes: Query<(&mut ResourceNode, &mut Visibility), Without<Depleted>>,
+    mut q_inventories: Query<&mut Inventory>,
+    mut added: MessageWriter<ItemsAdded>,
+    mut depleted: MessageWriter<NodeDepleted>,
+) {
+    for hit in hits.read() {
+        let Ok((mut node, mut visibility)) = q_nodes.get_mut(hit.node) else {
+            continue;
+        };
+        // Nodes already emptied earlier this tick are skipped.
+        if node.hit_points == 0 {
+            continue;
+        }
+
+        let def = node.kind.def();
+        node.hit_points -= 1;
+
+        if let Ok(mut inventory) = q_inventories.get_mut(hit.harvester) {
+            inventory.add(def.item, def.yield_per_hit);
+            added.write(ItemsAdded {
+                entity: hit.harvester,
+                item: def.item.to_string(),
+                amount: def.yield_per_hit,
+            });
+        }
+
+        if node.hit_points == 0 {
+            *visibility = Visibility::Hidden;
+            commands
+                .entity(hit.node)
+                .insert(Depleted(Timer::from_seconds(def.respawn_secs, TimerMode::Once)));
+            depleted.write(NodeDepleted { node: hit.node });
+        }
+    }
+}
+
+/// FixedUpdate: regrow depleted nodes when their timer expires.
+pub fn respawn_depleted_nodes(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q_depleted: Query<(Entity, &mut Depleted, &mut ResourceNode, &mut Visibility)>,
+) {
+    for (entity, mut depleted, mut node, mut visibility) in &mut q_depleted {
+        if !depleted.0.tick(time.delta()).is_finished() {
+            continue;
+        }
+
+        *node = ResourceNode::new(node.kind);
+        *visibility = Visibility::Inherited;
+        commands.entity(entity).remove::<Depleted>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::harvest::component::ResourceNodeKind;
+    use crate::features::player::component::Player;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<HarvestHit>>();
+        world.init_resource::<Messages<ItemsAdded>>();
+        world.init_resource::<Messages<NodeDepleted>>();
+        world
+    }
+
+    fn spawn_node(world: &mut World, kind: ResourceNodeKind, at: Vec3) -> Entity {
+        world
+            .spawn((
+                ResourceNode::new(kind),
+                Visibility::default(),
+                Transform::from_translation(at),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn hits_yield_items_until_depleted() {
+        let mut world = setup_world();
+        let node = spawn_node(&mut world, ResourceNodeKind::Tree, Vec3::ZERO);
+        let player = world.spawn(Inventory::default()).id();
+
+        let def = ResourceNodeKind::Tree.def();
+        for _ in 0..def.hit_points + 2 {
+            world.write_message(HarvestHit {
+                node,
+                harvester: player,
+            });
+        }
+        let _ = world.run_system_once(apply_harvest_hits);
+
+        assert_eq!(
+            world.get::<Inventory>(player).unwrap().count("wood"),
+            def.hit_points * def.yield_per_hit,
+            "extra hits on a depleted node yield nothing"
+        );
+        assert!(world.get::<Depleted>(node).is_some());
+        assert_eq!(*world.get::<Visibility>(node).unwrap(), Visibility::Hidden);
+        assert_eq!(world.resource::<Messages<NodeDepleted>>().len(), 1);
+    }
+
+    #[test]
+    fn depleted_node_respawns_after_timer() {
+        let mut world = setup_world();
+        let node = spawn_node(&mut world, ResourceNodeKind::Rock, Vec3::ZERO);
+        world.entity_mut(node).insert((
+            Depleted(Timer::from_seconds(1.0, TimerMode::Once)),
+            Visibility::Hidden,
+        ));
+        world.get_mut::<ResourceNode>(node).unwrap().hit_points = 0;
+
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(fixed);
+        let _ = world.run_system_once(respawn_depleted_nodes);
+
+        assert!(world.get::<Depleted>(node).is_none());
+        assert_eq!(
+            *world.get::<ResourceNode>(node).unwrap(),
+            ResourceNode::new(ResourceNodeKind::Rock)
+        );
+        assert_eq!(*world.get::<Visibility>(node).unwrap(), Visibility::Inherited);
+    }
+
+    #[test]
+    fn gather_input_targets_nearest_node_in_reach() {
+        let mut world = setup_world();
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(GatherKeybinding::default());
+
+        let player = world.spawn((Player, Transform::default())).id();
+        let near = spawn_node(&mut world, ResourceNodeKind::Tree, Vec3::new(1.0, 0.0, 0.0));
+        spawn_node(&mut world, ResourceNodeKind::Rock, Vec3::new(2.0, 0.0, 0.0));
+        spawn_node(&mut world, ResourceNodeKind::Rock, Vec3::new(10.0, 0.0, 0.0));
+
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyG);
+        let _ = world.run_system_once(read_gather_input);
+
+        let hits: Vec<_> = world
+            .resource::<Messages<HarvestHit>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(
+            hits,
+            vec![HarvestHit {
+                node: near,
+                harvester: player
+            }]
+        );
+    }
+
+    #[test]
+    fn spawn_resource_nodes_follows_layout() {
+        let mut world = World::new();
+        world.insert_resource(Assets::<Mesh>::default());
+        world.insert_resource(Assets::<StandardMaterial>::default());
+        world.insert_resource(ResourceNodeLayout(vec![
+            (ResourceNodeKind::Tree, Vec3::ZERO),
+            (ResourceNodeKind::Rock, Vec3::X),
+        ]));
+
+        let _ = world.run_system_once(spawn_resource_nodes);
+
+        let count = world.query::<&ResourceNode>().iter(&world).count();
+        assert_eq!(count, 2);
+    }
+}
@@ -0,0 +1,158 @@
+// src/features/hazard/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::features::health::damage::DamageKind;
+use crate::features::rotation::component::MotionDef;
+
+/// What a hazard volume does to players inside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum HazardKind {
+    /// Kills on contact.
+    Lava,
+    /// Hits and throws the body back out of the volume.
+    Spikes,
+    /// Poisons (damage over time) while inside, lingering a little after.
+    PoisonGas,
+}
+
+impl HazardKind {
+    pub fn damage_kind(self) -> DamageKind {
+        match self {
+            Self::Lava => DamageKind::Fire,
+            Self::Spikes => DamageKind::Piercing,
+            Self::PoisonGas => DamageKind::Poison,
+        }
+    }
+
+    /// Warning tint of the volume.
+    pub fn color(self) -> Color {
+        match self {
+            Self::Lava => Color::srgba(1.0, 0.35, 0.05, 0.9),
+            Self::Spikes => Color::srgba(0.55, 0.55, 0.6, 0.9),
+            Self::PoisonGas => Color::srgba(0.45, 0.85, 0.2, 0.35),
+        }
+    }
+
+    /// Warning glow at the peak of a pulse (linear, HDR).
+    pub fn glow(self) -> LinearRgba {
+        match self {
+            Self::Lava => LinearRgba::rgb(6.0, 1.5, 0.2),
+            Self::Spikes => LinearRgba::rgb(1.5, 0.2, 0.2),
+            Self::PoisonGas => LinearRgba::rgb(0.6, 2.0, 0.3),
+        }
+    }
+}
+
+/// Hazard tunables (damage per kind, spike knock-out, gas poison).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct HazardSettings {
+    pub spikes_damage: f32,
+    /// Push speed throwing a body out of spikes (mostly sideways, partly up).
+    pub spikes_knockback: f32,
+    /// A body still in the spikes after this long is hit again.
+    pub spikes_rehit_secs: f32,
+    pub gas_damage_per_tick: f32,
+    pub gas_tick_secs: f32,
+    /// Poison from the gas lasts this long; it's renewed as it runs out while inside.
+    pub gas_linger_secs: f32,
+    /// Warning glow pulses per second.
+    pub warning_pulse_hz: f32,
+}
+
+impl Default for HazardSettings {
+    fn default() -> Self {
+        Self {
+            spikes_damage: 25.0,
+            spikes_knockback: 9.0,
+            spikes_rehit_secs: 0.75,
+            gas_damage_per_tick: 4.0,
+            gas_tick_secs: 1.0,
+            gas_linger_secs: 3.0,
+            warning_pulse_hz: 0.8,
+        }
+    }
+}
+
+impl HazardSettings {
+    /// Seconds between repeated effects on a body that stays inside.
+    pub fn interval(&self, kind: HazardKind) -> f32 {
+        match kind {
+            HazardKind::Lava => 0.5,
+            HazardKind::Spikes => self.spikes_rehit_secs,
+            HazardKind::PoisonGas => self.gas_linger_secs,
+        }
+    }
+}
+
+/// One hazard volume, loaded from `assets/data/hazards.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HazardDef {
+    pub name: String,
+    pub kind: HazardKind,
+    pub position: (f32, f32, f32),
+    pub half_extents: (f32, f32, f32),
+    /// Spin / orbit (see `RotationPlugin`); the volume stays axis-aligned.
+    #[serde(default)]
+    pub motion: Option<MotionDef>,
+}
+
+/// Scene data: hazard volumes placed at startup.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct HazardSpawns(pub Vec<HazardDef>);
+
+const BUILTIN_HAZARDS: &str = include_str!("../../../assets/data/hazards.ron");
+
+impl HazardSpawns {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+}
+
+impl Default for HazardSpawns {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_HAZARDS).expect("assets/data/hazards.ron must parse")
+    }
+}
+
+/// Hazard on a `TriggerVolume`; tracks when each occupant is next affected.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Hazard {
+    pub kind: HazardKind,
+    /// `(occupant, seconds until it's affected again)`.
+    pub cooldowns: Vec<(Entity, f32)>,
+}
+
+impl Hazard {
+    pub fn new(kind: HazardKind) -> Self {
+        Self {
+            kind,
+            cooldowns: Vec::new(),
+        }
+    }
+}
+
+/// Per-kind warning materials, pulsed together.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HazardAssets {
+    pub materials: Vec<(HazardKind, Handle<StandardMaterial>)>,
+}
+
+impl HazardAssets {
+    pub fn material(&self, kind: HazardKind) -> Option<Handle<StandardMaterial>> {
+        self.materials.iter().find(|(k, _)| *k == kind).map(|(_, m)| m.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_hazards_parse_and_cover_every_kind() {
+        let spawns = HazardSpawns::default();
+        for kind in [HazardKind::Lava, HazardKind::Spikes, HazardKind::PoisonGas] {
+            assert!(spawns.0.iter().any(|def| def.kind == kind), "{kind:?}");
+        }
+    }
+}
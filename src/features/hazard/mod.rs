@@ -0,0 +1,50 @@
+// src/features/hazard/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::health::damage::{DamageEvent, apply_damage};
+use crate::features::status::systems::{ApplyStatusEffect, apply_status_effects};
+use crate::features::trigger::systems::detect_trigger_occupancy;
+
+pub mod component;
+pub mod systems;
+
+/// Hazard volumes.
+///
+/// Scope:
+/// - `HazardSpawns` from `assets/data/hazards.ron`: lava, spikes and poison gas
+///   boxes on `TriggerVolume`s
+/// - lava kills on contact (fire damage), spikes hit and throw the body back out
+///   (piercing damage + `ExternalForce`), gas poisons while inside (status effect)
+/// - bodies that stay inside are affected again on a per-kind interval
+/// - warnings: translucent, pulsing glow per kind; `HazardHit` for audio / HUD
+///
+/// Design constraints:
+/// - hazards never touch `Health` or effects directly: damage goes through
+///   `DamageEvent` (typed with `DamageKind`), poison through `ApplyStatusEffect`.
+pub struct HazardPlugin;
+
+impl Plugin for HazardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::HazardSettings>();
+        app.init_resource::<component::HazardSpawns>();
+
+        app.add_message::<systems::HazardHit>();
+        app.add_message::<DamageEvent>();
+        app.add_message::<ApplyStatusEffect>();
+
+        app.add_systems(
+            Startup,
+            (systems::setup_hazard_assets, systems::spawn_hazards).chain(),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::apply_hazards
+                .after(detect_trigger_occupancy)
+                .before(apply_damage)
+                .before(apply_status_effects)
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(Update, systems::pulse_hazard_warnings);
+    }
+}
@@ -0,0 +1,272 @@
+// src/features/hazard/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Hazard, HazardAssets, HazardKind, HazardSettings, HazardSpawns};
+use crate::app::EffectsIntensity;
+use crate::features::health::component::Health;
+use crate::features::health::damage::DamageEvent;
+use crate::features::movement::component::ExternalForce;
+use crate::features::player::death::Dead;
+use crate::features::status::component::StatusEffect;
+use crate::features::status::systems::ApplyStatusEffect;
+use crate::features::trigger::component::{TriggerOccupants, TriggerVolume};
+
+/// A hazard affected a body (hurt sounds, screen flashes).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct HazardHit {
+    pub hazard: Entity,
+    pub target: Entity,
+    pub kind: HazardKind,
+}
+
+/// Startup: one warning material per hazard kind.
+pub fn setup_hazard_assets(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let materials = [HazardKind::Lava, HazardKind::Spikes, HazardKind::PoisonGas]
+        .into_iter()
+        .map(|kind| {
+            let material = materials.add(StandardMaterial {
+                base_color: kind.color(),
+                emissive: kind.glow(),
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            });
+            (kind, material)
+        })
+        .collect();
+    commands.insert_resource(HazardAssets { materials });
+}
+
+/// Startup (after assets): spawn the `HazardSpawns` volumes.
+pub fn spawn_hazards(
+    mut commands: Commands,
+    spawns: Res<HazardSpawns>,
+    assets: Option<Res<HazardAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for def in &spawns.0 {
+        let half_extents = Vec3::from(def.half_extents);
+        let position = Vec3::from(def.position);
+        let mut entity = commands.spawn((
+            Hazard::new(def.kind),
+            TriggerVolume { half_extents },
+            Transform::from_translation(position),
+            Visibility::default(),
+            Name::new(def.name.clone()),
+        ));
+        if let Some(material) = assets.as_ref().and_then(|a| a.material(def.kind)) {
+            entity.insert((
+                Mesh3d(meshes.add(Cuboid::from_size(half_extents * 2.0))),
+                MeshMaterial3d(material),
+            ));
+        }
+        if let Some(motion) = &def.motion {
+            motion.insert(&mut entity, position);
+        }
+    }
+}
+
+/// FixedUpdate (after trigger occupancy): affect every living occupant on entry,
+/// then again every `HazardSettings::interval` while it stays.
+///
+/// - Lava deals the body's remaining health.
+/// - Spikes hit and push the body out sideways (away from the volume's center,
+///   backwards when dead center) and up.
+/// - Gas poisons; the poison outlasts the stay a little.
+#[expect(clippy::type_complexity)]
+pub fn apply_hazards(
+    settings: Res<HazardSettings>,
+    time: Res<Time<Fixed>>,
+    mut q_hazards: Query<(Entity, &mut Hazard, &TriggerOccupants, &Transform)>,
+    mut q_bodies: Query<
+        (&Transform, Option<&Health>, Option<&mut ExternalForce>),
+        (Without<Dead>, Without<Hazard>),
+    >,
+    mut damage: MessageWriter<DamageEvent>,
+    mut status: MessageWriter<ApplyStatusEffect>,
+    mut hits: MessageWriter<HazardHit>,
+) {
+    let dt = time.delta_secs();
+    for (hazard_entity, mut hazard, occupants, hazard_tr) in &mut q_hazards {
+        hazard.cooldowns.retain(|(entity, _)| occupants.0.contains(entity));
+
+        let kind = hazard.kind;
+        for &target in &occupants.0 {
+            match hazard.cooldowns.iter_mut().find(|(e, _)| *e == target) {
+                Some((_, remaining)) => {
+                    *remaining -= dt;
+                    if *remaining > 0.0 {
+                        continue;
+                    }
+                    *remaining += settings.interval(kind);
+                }
+                None => hazard.cooldowns.push((target, settings.interval(kind))),
+            }
+            let Ok((transform, health, force)) = q_bodies.get_mut(target) else {
+                continue;
+            };
+
+            match kind {
+                HazardKind::Lava => {
+                    let amount = health.map_or(0.0, |h| h.current);
+                    damage.write(DamageEvent::new(target, amount).with_kind(kind.damage_kind()));
+                }
+                HazardKind::Spikes => {
+                    damage.write(
+                        DamageEvent::new(target, settings.spikes_damage)
+                            .with_kind(kind.damage_kind()),
+                    );
+                    if let Some(mut force) = force {
+                        let away = (transform.translation - hazard_tr.translation)
+                            .with_y(0.0)
+                            .try_normalize()
+                            .unwrap_or(transform.rotation * Vec3::Z);
+                        let push = (away + Vec3::Y * 0.5).normalize();
+                        force.apply_impulse(push * settings.spikes_knockback);
+                    }
+                }
+                HazardKind::PoisonGas => {
+                    status.write(ApplyStatusEffect {
+                        target,
+                        effect: StatusEffect::poison(
+                            settings.gas_damage_per_tick,
+                            settings.gas_tick_secs,
+                            settings.gas_linger_secs,
+                        ),
+                    });
+                }
+            }
+            hits.write(HazardHit {
+                hazard: hazard_entity,
+                target,
+                kind,
+            });
+        }
+    }
+}
+
+/// Update: hazard warning glows pulse (as far as `EffectsIntensity::pulse` allows;
+/// steady at the mean glow without it).
+pub fn pulse_hazard_warnings(
+    time: Res<Time>,
+    settings: Res<HazardSettings>,
+    effects: Res<EffectsIntensity>,
+    assets: Option<Res<HazardAssets>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let phase = time.elapsed_secs() * settings.warning_pulse_hz * std::f32::consts::TAU;
+    let strength = 0.6 + 0.4 * effects.pulse * phase.sin();
+    for (kind, handle) in &assets.materials {
+        if let Some(material) = materials.get_mut(handle) {
+            material.emissive = kind.glow() * strength;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<HazardSettings>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<ApplyStatusEffect>>();
+        world.init_resource::<Messages<HazardHit>>();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(0.5));
+        world.insert_resource(time);
+        world
+    }
+
+    /// Hazard at the origin with `body` already inside.
+    fn spawn_hazard(world: &mut World, kind: HazardKind, body: Entity) -> Entity {
+        world
+            .spawn((
+                Hazard::new(kind),
+                TriggerOccupants(vec![body]),
+                Transform::default(),
+            ))
+            .id()
+    }
+
+    fn spawn_body(world: &mut World, at: Vec3) -> Entity {
+        world
+            .spawn((
+                Transform::from_translation(at),
+                Health::new(80.0),
+                ExternalForce::default(),
+            ))
+            .id()
+    }
+
+    fn damage(world: &World) -> Vec<DamageEvent> {
+        world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn lava_deals_all_remaining_health_as_fire() {
+        let mut world = setup_world();
+        let body = spawn_body(&mut world, Vec3::ZERO);
+        world.get_mut::<Health>(body).unwrap().current = 35.0;
+        spawn_hazard(&mut world, HazardKind::Lava, body);
+
+        let _ = world.run_system_once(apply_hazards);
+
+        let damage = damage(&world);
+        assert_eq!(damage.len(), 1);
+        assert_eq!(damage[0].amount, 35.0);
+        assert_eq!(damage[0].kind, HazardKind::Lava.damage_kind());
+    }
+
+    #[test]
+    fn spikes_throw_bodies_out_and_rehit_stragglers() {
+        let mut world = setup_world();
+        let body = spawn_body(&mut world, Vec3::X);
+        let spikes = spawn_hazard(&mut world, HazardKind::Spikes, body);
+
+        let _ = world.run_system_once(apply_hazards);
+        let push = world.get::<ExternalForce>(body).unwrap().velocity;
+        assert!(push.x > 0.0 && push.y > 0.0 && push.z == 0.0, "push {push}");
+        assert_eq!(damage(&world).len(), 1);
+
+        // 0.75 s between hits, 0.5 s per run.
+        let _ = world.run_system_once(apply_hazards);
+        assert_eq!(damage(&world).len(), 1);
+        let _ = world.run_system_once(apply_hazards);
+        assert_eq!(damage(&world).len(), 2);
+
+        world.get_mut::<TriggerOccupants>(spikes).unwrap().0.clear();
+        let _ = world.run_system_once(apply_hazards);
+        assert!(world.get::<Hazard>(spikes).unwrap().cooldowns.is_empty());
+    }
+
+    #[test]
+    fn gas_poisons_and_renews_as_the_poison_runs_out() {
+        let mut world = setup_world();
+        let body = spawn_body(&mut world, Vec3::ZERO);
+        spawn_hazard(&mut world, HazardKind::PoisonGas, body);
+
+        for _ in 0..7 {
+            let _ = world.run_system_once(apply_hazards);
+        }
+
+        // 3 s linger, 0.5 s per run: applied on entry and again on the seventh run.
+        let applied = world.resource::<Messages<ApplyStatusEffect>>().len();
+        assert_eq!(applied, 2);
+        assert!(damage(&world).is_empty(), "damage comes from the poison ticks");
+        assert_eq!(world.resource::<Messages<HazardHit>>().len(), 2);
+    }
+}
@@ -0,0 +1,69 @@
+// src/features/health/component.rs
+use bevy::prelude::*;
+
+/// Hit points of a damageable entity.
+///
+/// Contract:
+/// - `current` is always within `0.0..=max`.
+/// - Reaching `0.0` is what "dead" means to the rest of the game; the health
+///   feature announces it once via [`super::damage::Died`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Health {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Health {
+    /// Full health with the given maximum.
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// `true` once health has been depleted.
+    pub fn is_depleted(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Remaining health as a `0.0..=1.0` fraction (handy for bars).
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            0.0
+        } else {
+            (self.current / self.max).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Restore to full health (respawn, checkpoints, etc.).
+    pub fn reset(&mut self) {
+        self.current = self.max;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_starts_full() {
+        let h = Health::new(80.0);
+        assert_eq!(h.current, 80.0);
+        assert_eq!(h.max, 80.0);
+        assert!(!h.is_depleted());
+        assert_eq!(h.fraction(), 1.0);
+    }
+
+    #[test]
+    fn reset_restores_max() {
+        let mut h = Health { current: 0.0, max: 50.0 };
+        assert!(h.is_depleted());
+
+        h.reset();
+        assert_eq!(h, Health::new(50.0));
+    }
+
+    #[test]
+    fn fraction_handles_zero_max() {
+        let h = Health { current: 0.0, max: 0.0 };
+        assert_eq!(h.fraction(), 0.0);
+    }
+}
@@ -0,0 +1,227 @@
+// src/features/health/damage.rs
+use bevy::prelude::*;
+
+use super::component::Health;
+use crate::app::{Allegiance, GameplayTuning};
+use crate::features::enemy::component::Enemy;
+
+/// Request to remove hit points from an entity.
+///
+/// Any feature (hazards, enemies, fall damage, ...) writes this message instead of
+/// touching `Health` directly, so death detection lives in exactly one place.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    /// Who / where the hit came from. `None` for sourceless damage (poison,
+    /// hazards), which can't be blocked or parried.
+    pub attack: Option<IncomingAttack>,
+    /// What kind of harm it is (hit reactions, death messages, resistances).
+    pub kind: DamageKind,
+}
+
+impl DamageEvent {
+    /// Sourceless damage.
+    pub fn new(target: Entity, amount: f32) -> Self {
+        Self {
+            target,
+            amount,
+            attack: None,
+            kind: DamageKind::default(),
+        }
+    }
+
+    /// Damage from an attack with metadata (melee swing, projectile, ...).
+    pub fn from_attack(target: Entity, amount: f32, attack: IncomingAttack) -> Self {
+        Self {
+            target,
+            amount,
+            attack: Some(attack),
+            kind: DamageKind::default(),
+        }
+    }
+
+    pub fn with_kind(mut self, kind: DamageKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// Kind of harm a hit does.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageKind {
+    /// Blows, bullets, falls.
+    #[default]
+    Physical,
+    Fire,
+    Piercing,
+    Poison,
+}
+
+/// Metadata of the attack behind a hit; defensive features (block, parry) read it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IncomingAttack {
+    /// The attacker.
+    pub source: Entity,
+    /// World-space direction the attack travels (attacker -> target), normalized.
+    pub direction: Vec3,
+    /// Whether a well-timed guard can parry it (melee yes, bullets no).
+    pub parryable: bool,
+}
+
+/// Emitted exactly once when an entity's health crosses from alive to depleted.
+///
+/// Features react to this (player death state, enemy loot, UI, audio)
+/// rather than polling `Health` every tick.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Died {
+    pub entity: Entity,
+}
+
+/// FixedUpdate: apply queued damage and announce deaths.
+///
+/// - Reads: DamageEvent (already scaled by the target's guard, see the defense
+///   feature), `GameplayTuning::enemy_damage` (scales hits whose attacker is an `Enemy`),
+///   `Allegiance` (an attacker's hits on allies only land with friendly fire on)
+/// - Writes: Health, Died
+/// - Damage to already-depleted entities is ignored (no duplicate `Died`).
+/// - Negative amounts are ignored; healing is a separate concern.
+pub fn apply_damage(
+    mut damage: MessageReader<DamageEvent>,
+    tuning: Option<Res<GameplayTuning>>,
+    allegiance: Allegiance,
+    q_enemies: Query<(), With<Enemy>>,
+    mut q_health: Query<&mut Health>,
+    mut died: MessageWriter<Died>,
+) {
+    for ev in damage.read() {
+        let Ok(mut health) = q_health.get_mut(ev.target) else {
+            continue;
+        };
+
+        if health.is_depleted() || ev.amount <= 0.0 {
+            continue;
+        }
+        if ev
+            .attack
+            .is_some_and(|attack| !allegiance.can_hurt(attack.source, ev.target))
+        {
+            continue;
+        }
+
+        let from_enemy = ev.attack.is_some_and(|a| q_enemies.contains(a.source));
+        let amount = match &tuning {
+            Some(tuning) if from_enemy => ev.amount * tuning.enemy_damage,
+            _ => ev.amount,
+        };
+
+        health.current = (health.current - amount).max(0.0);
+
+        if health.is_depleted() {
+            died.write(Died { entity: ev.target });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{Faction, Tuning};
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<Died>>();
+        world
+    }
+
+    fn died_count(world: &World) -> usize {
+        world.resource::<Messages<Died>>().len()
+    }
+
+    #[test]
+    fn damage_reduces_health() {
+        let mut world = setup_world();
+        let e = world.spawn(Health::new(100.0)).id();
+
+        world.write_message(DamageEvent::new(e, 30.0));
+        let _ = world.run_system_once(apply_damage);
+
+        assert_eq!(world.get::<Health>(e).unwrap().current, 70.0);
+        assert_eq!(died_count(&world), 0);
+    }
+
+    #[test]
+    fn lethal_damage_clamps_to_zero_and_emits_died_once() {
+        let mut world = setup_world();
+        let e = world.spawn(Health::new(10.0)).id();
+
+        world.write_message(DamageEvent::new(e, 25.0));
+        world.write_message(DamageEvent::new(e, 5.0));
+        let _ = world.run_system_once(apply_damage);
+
+        assert_eq!(world.get::<Health>(e).unwrap().current, 0.0);
+        assert_eq!(died_count(&world), 1, "Died must fire exactly once");
+    }
+
+    #[test]
+    fn negative_damage_and_missing_targets_are_ignored() {
+        let mut world = setup_world();
+        let e = world.spawn(Health::new(10.0)).id();
+        let no_health = world.spawn_empty().id();
+
+        world.write_message(DamageEvent::new(e, -5.0));
+        world.write_message(DamageEvent::new(no_health, 5.0));
+        let _ = world.run_system_once(apply_damage);
+
+        assert_eq!(world.get::<Health>(e).unwrap().current, 10.0);
+        assert_eq!(died_count(&world), 0);
+    }
+
+    #[test]
+    fn enemy_hits_scale_with_difficulty() {
+        let mut world = setup_world();
+        world.insert_resource(GameplayTuning::for_difficulty(crate::app::Difficulty::Hard));
+        let enemy = world.spawn(Enemy).id();
+        let bystander = world.spawn_empty().id();
+        let e = world.spawn(Health::new(100.0)).id();
+        let attack = |source| IncomingAttack {
+            source,
+            direction: Vec3::Z,
+            parryable: false,
+        };
+
+        world.write_message(DamageEvent::from_attack(e, 10.0, attack(enemy)));
+        world.write_message(DamageEvent::from_attack(e, 10.0, attack(bystander)));
+        world.write_message(DamageEvent::new(e, 10.0));
+        let _ = world.run_system_once(apply_damage);
+
+        // 15 (scaled) + 10 + 10.
+        assert_eq!(world.get::<Health>(e).unwrap().current, 65.0);
+    }
+
+    #[test]
+    fn hits_on_allies_need_friendly_fire() {
+        let mut world = setup_world();
+        world.init_resource::<Tuning>();
+        let player = world.spawn(Faction::Players).id();
+        let partner = world.spawn((Faction::Players, Health::new(100.0))).id();
+        let attack = IncomingAttack {
+            source: player,
+            direction: Vec3::Z,
+            parryable: false,
+        };
+
+        world.write_message(DamageEvent::from_attack(partner, 10.0, attack));
+        let _ = world.run_system_once(apply_damage);
+        assert_eq!(world.get::<Health>(partner).unwrap().current, 100.0);
+
+        world.resource_mut::<Messages<DamageEvent>>().clear();
+        world.resource_mut::<Tuning>().factions.friendly_fire = true;
+        world.write_message(DamageEvent::from_attack(partner, 10.0, attack));
+        let _ = world.run_system_once(apply_damage);
+        assert_eq!(world.get::<Health>(partner).unwrap().current, 90.0);
+    }
+}
@@ -0,0 +1,35 @@
+// src/features/health/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod damage;
+
+/// Health feature plugin.
+///
+/// Scope:
+/// - `Health` component for anything that can take damage
+/// - `DamageEvent` in, `Died` out (FixedUpdate, deterministic with movement)
+/// - hits arrive already blocked / parried by the target's `Guard` (the defense
+///   feature rewrites `DamageEvent`s before `apply_damage`)
+/// - hits between allies (`Allegiance`) are dropped unless the tuning's
+///   `friendly_fire` is on
+///
+/// Design constraints:
+/// - Only this feature writes `Health` on damage; others send `DamageEvent`.
+/// - What "dying" means (respawn, loot, despawn) is decided by the feature that
+///   owns the entity, by reacting to `Died`.
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<damage::DamageEvent>();
+        app.add_message::<damage::Died>();
+
+        app.add_systems(
+            FixedUpdate,
+            damage::apply_damage.in_set(AppSet::FixedGameplay),
+        );
+    }
+}
@@ -0,0 +1,130 @@
+// src/features/hud/ammo.rs
+use bevy::prelude::*;
+
+use super::crosshair::CrosshairKick;
+use super::settings::HudSettings;
+use crate::features::player::component::Player;
+use crate::features::weapon::component::{Reloading, WeaponAmmo, WeaponLoadout};
+use crate::features::weapon::firing::WeaponFired;
+
+/// Bottom-right ammo counter ("magazine / reserve", or "Reloading...").
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct AmmoCounter;
+
+/// Counter text for the player's equipped weapon (`None` = nothing to show).
+pub fn ammo_text(
+    loadout: &WeaponLoadout,
+    ammo: &WeaponAmmo,
+    reloading: Option<&Reloading>,
+) -> Option<String> {
+    let id = loadout.equipped_id()?;
+    let state = ammo.get(id)?;
+    Some(if reloading.is_some() {
+        "Reloading...".to_string()
+    } else {
+        format!("{} / {}", state.magazine, state.reserve)
+    })
+}
+
+/// Startup: spawn the ammo counter.
+pub fn spawn_ammo_counter(mut commands: Commands) {
+    commands.spawn((
+        AmmoCounter,
+        Text::new(""),
+        TextFont {
+            font_size: 28.0,
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(24.0),
+            bottom: Val::Px(24.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        Name::new("AmmoCounter"),
+    ));
+}
+
+/// Update: mirror the player's magazine / reserve / reload state into the counter.
+pub fn update_ammo_counter(
+    q_player: Query<(&WeaponLoadout, &WeaponAmmo, Option<&Reloading>), With<Player>>,
+    mut q_counter: Query<(&mut Text, &mut Visibility), With<AmmoCounter>>,
+) {
+    let text = q_player
+        .iter()
+        .next()
+        .and_then(|(loadout, ammo, reloading)| ammo_text(loadout, ammo, reloading));
+
+    for (mut counter, mut vis) in &mut q_counter {
+        match &text {
+            Some(text) => {
+                if counter.0 != *text {
+                    counter.0.clone_from(text);
+                }
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
+/// Update: the player's shots bloom the crosshair.
+pub fn kick_crosshair_on_fire(
+    settings: Res<HudSettings>,
+    mut fired: MessageReader<WeaponFired>,
+    q_players: Query<(), With<Player>>,
+    mut kicks: MessageWriter<CrosshairKick>,
+) {
+    for ev in fired.read() {
+        if q_players.contains(ev.entity) {
+            kicks.write(CrosshairKick {
+                amount: settings.crosshair.fire_kick,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::weapon::component::AmmoState;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn counter_shows_equipped_ammo_then_reloading() {
+        let mut world = World::new();
+        let _ = world.run_system_once(spawn_ammo_counter);
+
+        let mut ammo = WeaponAmmo::default();
+        ammo.0.insert(
+            "pistol".into(),
+            AmmoState {
+                magazine: 7,
+                reserve: 24,
+            },
+        );
+        let player = world
+            .spawn((Player, WeaponLoadout::new(["pistol"]), ammo))
+            .id();
+
+        let _ = world.run_system_once(update_ammo_counter);
+        let (text, vis) = world
+            .query_filtered::<(&Text, &Visibility), With<AmmoCounter>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(text.0, "7 / 24");
+        assert_eq!(*vis, Visibility::Inherited);
+
+        world.entity_mut(player).insert(Reloading {
+            weapon_id: "pistol".into(),
+            timer: Timer::from_seconds(1.0, TimerMode::Once),
+        });
+        let _ = world.run_system_once(update_ammo_counter);
+        let text = world
+            .query_filtered::<&Text, With<AmmoCounter>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(text.0, "Reloading...");
+    }
+}
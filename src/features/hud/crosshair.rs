@@ -0,0 +1,238 @@
+// src/features/hud/crosshair.rs
+use bevy::prelude::*;
+
+use super::settings::{CrosshairSettings, CrosshairStyle, HudSettings};
+use crate::features::aim::component::AimState;
+use crate::features::enemy::component::Enemy;
+use crate::features::health::damage::DamageEvent;
+use crate::features::movement::component::Velocity;
+use crate::features::player::component::Player;
+
+/// Root node of the crosshair (a zero-size anchor at the screen center).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Crosshair;
+
+/// One crosshair line; `dir` is the screen direction it sits in (x right, y down).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CrosshairLine {
+    pub dir: Vec2,
+}
+
+/// Center dot of the crosshair.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct CrosshairDot;
+
+/// Request extra spread (weapon fire, recoil, landing).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct CrosshairKick {
+    /// Extra gap in logical pixels, decays over time.
+    pub amount: f32,
+}
+
+/// Transient crosshair state (bloom from kicks, hit flash timer).
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CrosshairState {
+    pub bloom: f32,
+    pub flash_remaining: f32,
+}
+
+/// Gap between the aim point and the lines, in logical pixels.
+///
+/// Grows with movement speed and bloom, shrinks with aim, never beyond `max_gap`.
+pub fn crosshair_gap(settings: &CrosshairSettings, speed: f32, bloom: f32, spread_scale: f32) -> f32 {
+    let gap = settings.base_gap + speed.max(0.0) * settings.gap_per_speed + bloom.max(0.0);
+    (gap * spread_scale).min(settings.max_gap)
+}
+
+/// Startup: spawn the crosshair nodes.
+pub fn spawn_crosshair(mut commands: Commands) {
+    commands
+        .spawn((
+            Crosshair,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Percent(50.0),
+                width: Val::Px(0.0),
+                height: Val::Px(0.0),
+                ..default()
+            },
+            Name::new("Crosshair"),
+        ))
+        .with_children(|root| {
+            for dir in [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y] {
+                root.spawn((
+                    CrosshairLine { dir },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE),
+                ));
+            }
+            root.spawn((
+                CrosshairDot,
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                BackgroundColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Update: collect kicks into bloom, hits on enemies into the flash, and decay both.
+pub fn track_crosshair_state(
+    time: Res<Time>,
+    settings: Res<HudSettings>,
+    mut state: ResMut<CrosshairState>,
+    mut kicks: MessageReader<CrosshairKick>,
+    mut damage: MessageReader<DamageEvent>,
+    q_enemies: Query<(), With<Enemy>>,
+) {
+    let s = &settings.crosshair;
+    let dt = time.delta_secs();
+
+    state.bloom *= (-s.bloom_recovery.max(0.0) * dt).exp();
+    state.flash_remaining = (state.flash_remaining - dt).max(0.0);
+
+    for kick in kicks.read() {
+        state.bloom += kick.amount.max(0.0);
+    }
+    if damage.read().any(|ev| ev.amount > 0.0 && q_enemies.contains(ev.target)) {
+        state.flash_remaining = s.hit_flash_secs;
+    }
+}
+
+/// Update: lay out and color the crosshair from the player's speed / aim and the state.
+pub fn update_crosshair(
+    settings: Res<HudSettings>,
+    state: Res<CrosshairState>,
+    q_player: Query<(Option<&Velocity>, Option<&AimState>), With<Player>>,
+    mut q_lines: Query<(&CrosshairLine, &mut Node, &mut BackgroundColor, &mut Visibility), Without<CrosshairDot>>,
+    mut q_dot: Query<(&mut Node, &mut BackgroundColor, &mut Visibility), With<CrosshairDot>>,
+) {
+    let s = &settings.crosshair;
+    let (speed, spread_scale) = q_player.iter().next().map_or((0.0, 1.0), |(v, aim)| {
+        (
+            v.map_or(0.0, |v| v.0.with_y(0.0).length()),
+            aim.map_or(1.0, AimState::spread_scale_now),
+        )
+    });
+    let gap = crosshair_gap(s, speed, state.bloom, spread_scale);
+    let color = if state.flash_remaining > 0.0 {
+        s.hit_color
+    } else {
+        s.color
+    };
+    let show_lines = matches!(s.style, CrosshairStyle::Cross | CrosshairStyle::CrossWithDot);
+    let show_dot = matches!(s.style, CrosshairStyle::Dot | CrosshairStyle::CrossWithDot);
+
+    for (line, mut node, mut bg, mut vis) in &mut q_lines {
+        let horizontal = line.dir.x != 0.0;
+        let (w, h) = if horizontal {
+            (s.line_length, s.thickness)
+        } else {
+            (s.thickness, s.line_length)
+        };
+        // Line center sits `gap + len/2` from the aim point; nodes are placed by top-left.
+        let center = line.dir * (gap + s.line_length * 0.5);
+        node.width = Val::Px(w);
+        node.height = Val::Px(h);
+        node.left = Val::Px(center.x - w * 0.5);
+        node.top = Val::Px(center.y - h * 0.5);
+        bg.0 = color;
+        *vis = visible(show_lines);
+    }
+
+    for (mut node, mut bg, mut vis) in &mut q_dot {
+        node.width = Val::Px(s.thickness);
+        node.height = Val::Px(s.thickness);
+        node.left = Val::Px(-s.thickness * 0.5);
+        node.top = Val::Px(-s.thickness * 0.5);
+        bg.0 = color;
+        *vis = visible(show_dot);
+    }
+}
+
+fn visible(show: bool) -> Visibility {
+    if show {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.insert_resource(HudSettings::default());
+        world.init_resource::<CrosshairState>();
+        world.init_resource::<Messages<CrosshairKick>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world
+    }
+
+    #[test]
+    fn gap_grows_with_speed_and_bloom_and_shrinks_when_aiming() {
+        let s = CrosshairSettings::default();
+        let rest = crosshair_gap(&s, 0.0, 0.0, 1.0);
+        assert_eq!(rest, s.base_gap);
+        assert!(crosshair_gap(&s, 5.0, 0.0, 1.0) > rest);
+        assert!(crosshair_gap(&s, 0.0, 10.0, 1.0) > rest);
+        assert!(crosshair_gap(&s, 0.0, 0.0, 0.3) < rest);
+        assert_eq!(crosshair_gap(&s, 1000.0, 0.0, 1.0), s.max_gap);
+    }
+
+    #[test]
+    fn damage_to_enemy_flashes_and_kicks_bloom() {
+        let mut world = setup_world();
+        let enemy = world.spawn(Enemy).id();
+        let bystander = world.spawn_empty().id();
+
+        world.write_message(DamageEvent::new(bystander, 5.0));
+        let _ = world.run_system_once(track_crosshair_state);
+        assert_eq!(world.resource::<CrosshairState>().flash_remaining, 0.0);
+
+        world.write_message(DamageEvent::new(enemy, 5.0));
+        world.write_message(CrosshairKick { amount: 6.0 });
+        let _ = world.run_system_once(track_crosshair_state);
+
+        let state = *world.resource::<CrosshairState>();
+        assert!(state.flash_remaining > 0.0);
+        assert_eq!(state.bloom, 6.0);
+    }
+
+    #[test]
+    fn layout_follows_style_and_flash_color() {
+        let mut world = setup_world();
+        let _ = world.run_system_once(spawn_crosshair);
+        world.resource_mut::<HudSettings>().crosshair.style = CrosshairStyle::Dot;
+        world.resource_mut::<CrosshairState>().flash_remaining = 0.1;
+
+        let _ = world.run_system_once(update_crosshair);
+
+        let hit = HudSettings::default().crosshair.hit_color;
+        for (_, vis, bg) in world
+            .query::<(&CrosshairLine, &Visibility, &BackgroundColor)>()
+            .iter(&world)
+        {
+            assert_eq!(*vis, Visibility::Hidden);
+            assert_eq!(bg.0, hit);
+        }
+        let (vis, _) = world
+            .query_filtered::<(&Visibility, &Node), With<CrosshairDot>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(*vis, Visibility::Inherited);
+    }
+}
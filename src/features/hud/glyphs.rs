@@ -0,0 +1,112 @@
+// src/features/hud/glyphs.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::features::ability::input::AbilityKeybindings;
+use crate::features::interaction::component::InteractKeybinding;
+use crate::features::player::input::PlayerKeybindings;
+
+/// Short on-screen label of a key ("W", "Space", "LShift", "1").
+pub fn key_glyph(key: KeyCode) -> String {
+    match key {
+        KeyCode::ShiftLeft => "LShift".into(),
+        KeyCode::ShiftRight => "RShift".into(),
+        KeyCode::ControlLeft => "LCtrl".into(),
+        KeyCode::ControlRight => "RCtrl".into(),
+        KeyCode::AltLeft => "LAlt".into(),
+        KeyCode::AltRight => "RAlt".into(),
+        KeyCode::Escape => "Esc".into(),
+        KeyCode::ArrowUp => "Up".into(),
+        KeyCode::ArrowDown => "Down".into(),
+        KeyCode::ArrowLeft => "Left".into(),
+        KeyCode::ArrowRight => "Right".into(),
+        other => {
+            let name = format!("{other:?}");
+            let short = name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name);
+            short.to_string()
+        }
+    }
+}
+
+/// Bracketed glyph used inline in prompts ("[H]").
+pub fn key_prompt(key: KeyCode) -> String {
+    format!("[{}]", key_glyph(key))
+}
+
+/// Replace `{action}` placeholders with key prompts; unknown actions are kept verbatim.
+pub fn fill_glyphs(template: &str, resolve: impl Fn(&str) -> Option<KeyCode>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let action = &rest[start + 1..start + len];
+        match resolve(action) {
+            Some(key) => out.push_str(&key_prompt(key)),
+            None => out.push_str(&rest[start..=start + len]),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Current bindings of named actions, for prompts written in data files.
+///
+/// Actions: `forward`, `back`, `left`, `right`, `up`, `down`, `interact`,
+/// `ability1`..`ability4`.
+#[derive(SystemParam)]
+pub struct ActionKeys<'w> {
+    player: Option<Res<'w, PlayerKeybindings>>,
+    interact: Option<Res<'w, InteractKeybinding>>,
+    abilities: Option<Res<'w, AbilityKeybindings>>,
+}
+
+impl ActionKeys<'_> {
+    pub fn key(&self, action: &str) -> Option<KeyCode> {
+        if let Some(slot) = action.strip_prefix("ability") {
+            let index = slot.parse::<usize>().ok()?.checked_sub(1)?;
+            return self.abilities.as_ref()?.slots.get(index).copied();
+        }
+        if action == "interact" {
+            return self.interact.as_ref().map(|b| b.key);
+        }
+        let player = self.player.as_ref()?;
+        match action {
+            "forward" => Some(player.forward),
+            "back" => Some(player.back),
+            "left" => Some(player.left),
+            "right" => Some(player.right),
+            "up" => Some(player.up),
+            "down" => Some(player.down),
+            _ => None,
+        }
+    }
+
+    /// `template` with every known `{action}` replaced by its key prompt.
+    pub fn fill(&self, template: &str) -> String {
+        fill_glyphs(template, |action| self.key(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyphs_are_short_and_templates_fill() {
+        assert_eq!(key_glyph(KeyCode::KeyW), "W");
+        assert_eq!(key_glyph(KeyCode::Digit1), "1");
+        assert_eq!(key_glyph(KeyCode::ShiftLeft), "LShift");
+
+        let filled = fill_glyphs("Press {up} to rise, {nope} stays", |a| {
+            (a == "up").then_some(KeyCode::Space)
+        });
+        assert_eq!(filled, "Press [Space] to rise, {nope} stays");
+    }
+}
@@ -0,0 +1,236 @@
+// src/features/hud/health_bars.rs
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::settings::{HealthBarSettings, HudSettings};
+use crate::app::{Allegiance, Faction, Relation};
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::collision::component::Collider;
+use crate::features::health::component::Health;
+use crate::features::health::damage::DamageEvent;
+use crate::features::player::component::Player;
+
+/// One pooled bar widget (background); its child is the `HealthBarFill`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthBarSlot {
+    pub index: usize,
+}
+
+/// Fill of a pooled bar; width follows the tracked character's health fraction.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct HealthBarFill;
+
+/// Characters with a visible bar -> seconds since they were last damaged.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct TrackedHealthBars(pub HashMap<Entity, f32>);
+
+/// Bar opacity `since_hit` seconds after the last hit (`0.0` = gone).
+pub fn health_bar_alpha(settings: &HealthBarSettings, since_hit: f32) -> f32 {
+    let fade_start = settings.visible_secs;
+    if since_hit <= fade_start {
+        1.0
+    } else if settings.fade_secs <= 0.0 {
+        0.0
+    } else {
+        (1.0 - (since_hit - fade_start) / settings.fade_secs).clamp(0.0, 1.0)
+    }
+}
+
+/// Startup: spawn the bar pool (hidden until assigned).
+pub fn spawn_health_bar_pool(mut commands: Commands, settings: Res<HudSettings>) {
+    let s = &settings.health_bars;
+    for index in 0..s.pool_size {
+        commands
+            .spawn((
+                HealthBarSlot { index },
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(s.width),
+                    height: Val::Px(s.height),
+                    ..default()
+                },
+                BackgroundColor(s.back_color),
+                Visibility::Hidden,
+                Name::new("HealthBar"),
+            ))
+            .with_children(|bar| {
+                bar.spawn((
+                    HealthBarFill,
+                    Node {
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(settings.relations.hostile),
+                ));
+            });
+    }
+}
+
+/// Update: start / refresh bars on damaged characters (anyone with a faction but
+/// the player), age them, drop expired or dead ones.
+pub fn track_health_bars(
+    time: Res<Time>,
+    settings: Res<HudSettings>,
+    allegiance: Allegiance,
+    mut damage: MessageReader<DamageEvent>,
+    q_targets: Query<&Health, Without<Player>>,
+    mut tracked: ResMut<TrackedHealthBars>,
+) {
+    let dt = time.delta_secs();
+    for since_hit in tracked.0.values_mut() {
+        *since_hit += dt;
+    }
+    for ev in damage.read() {
+        if ev.amount > 0.0
+            && q_targets.contains(ev.target)
+            && allegiance.faction(ev.target).is_some()
+        {
+            tracked.0.insert(ev.target, 0.0);
+        }
+    }
+
+    let s = &settings.health_bars;
+    tracked.0.retain(|target, since_hit| {
+        health_bar_alpha(s, *since_hit) > 0.0
+            && q_targets.get(*target).is_ok_and(|h| !h.is_depleted())
+    });
+}
+
+/// Update: project tracked characters to the screen and lay out pooled bars over
+/// them, filled in the color of how the players' faction regards them.
+///
+/// Bars are reused every frame; characters off-screen or beyond the pool size get none.
+#[expect(clippy::type_complexity)]
+pub fn update_health_bars(
+    settings: Res<HudSettings>,
+    tracked: Res<TrackedHealthBars>,
+    allegiance: Allegiance,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_targets: Query<(&Health, &GlobalTransform, Option<&Collider>)>,
+    mut q_slots: Query<
+        (&HealthBarSlot, &Children, &mut Node, &mut BackgroundColor, &mut Visibility),
+        Without<HealthBarFill>,
+    >,
+    mut q_fills: Query<(&mut Node, &mut BackgroundColor), With<HealthBarFill>>,
+) {
+    let s = &settings.health_bars;
+    let camera = q_camera.iter().find(|(camera, _)| camera.is_active);
+
+    // Stable order so bars don't hop between slots from frame to frame.
+    let mut bars: Vec<_> = tracked
+        .0
+        .iter()
+        .filter_map(|(&target, &since_hit)| {
+            let (camera, camera_tr) = camera?;
+            let (health, tr, collider) = q_targets.get(target).ok()?;
+            let half_height = collider.map_or(1.0, |c| c.half_extents.y);
+            let top = tr.translation() + Vec3::Y * (half_height + s.world_offset);
+            let screen = camera.world_to_viewport(camera_tr, top).ok()?;
+            let relation = allegiance
+                .faction(target)
+                .map_or(Relation::Neutral, |f| allegiance.table_relation(Faction::Players, f));
+            let fill = settings.relations.get(relation);
+            Some((target, screen, health.fraction(), fill, health_bar_alpha(s, since_hit)))
+        })
+        .collect();
+    bars.sort_by_key(|(target, ..)| *target);
+
+    for (slot, children, mut node, mut bg, mut vis) in &mut q_slots {
+        let Some(&(_, screen, fraction, fill, alpha)) = bars.get(slot.index) else {
+            if *vis != Visibility::Hidden {
+                *vis = Visibility::Hidden;
+            }
+            continue;
+        };
+
+        node.left = Val::Px(screen.x - s.width * 0.5);
+        node.top = Val::Px(screen.y - s.height);
+        bg.0 = s.back_color.with_alpha(s.back_color.alpha() * alpha);
+        *vis = Visibility::Inherited;
+
+        for child in children.iter() {
+            if let Ok((mut fill_node, mut fill_bg)) = q_fills.get_mut(child) {
+                fill_node.width = Val::Percent(fraction * 100.0);
+                fill_bg.0 = fill.with_alpha(fill.alpha() * alpha);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    #[test]
+    fn alpha_holds_then_fades_out() {
+        let s = HealthBarSettings::default();
+        assert_eq!(health_bar_alpha(&s, 0.0), 1.0);
+        assert_eq!(health_bar_alpha(&s, s.visible_secs), 1.0);
+        let half = health_bar_alpha(&s, s.visible_secs + s.fade_secs * 0.5);
+        assert!((half - 0.5).abs() < 1e-5);
+        assert_eq!(health_bar_alpha(&s, s.visible_secs + s.fade_secs), 0.0);
+    }
+
+    #[test]
+    fn damaged_characters_are_tracked_until_they_fade_or_die() {
+        let mut world = World::new();
+        world.insert_resource(HudSettings::default());
+        world.init_resource::<TrackedHealthBars>();
+        world.init_resource::<Messages<DamageEvent>>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+
+        let enemy = world.spawn((Faction::Monsters, Health::new(50.0))).id();
+        let dead = world
+            .spawn((
+                Faction::Monsters,
+                Health {
+                    current: 0.0,
+                    max: 50.0,
+                },
+            ))
+            .id();
+        let bystander = world.spawn(Health::new(50.0)).id();
+        let player = world.spawn((Player, Faction::Players, Health::new(50.0))).id();
+
+        for target in [enemy, dead, bystander, player] {
+            world.write_message(DamageEvent::new(target, 5.0));
+        }
+        let _ = world.run_system_once(track_health_bars);
+        let tracked: Vec<_> = world.resource::<TrackedHealthBars>().0.keys().copied().collect();
+        assert_eq!(tracked, vec![enemy]);
+
+        world.resource_mut::<Messages<DamageEvent>>().clear();
+        for _ in 0..4 {
+            let _ = world.run_system_once(track_health_bars);
+        }
+        assert!(world.resource::<TrackedHealthBars>().0.is_empty());
+    }
+
+    #[test]
+    fn unassigned_pool_slots_stay_hidden() {
+        let mut world = World::new();
+        world.insert_resource(HudSettings::default());
+        world.init_resource::<TrackedHealthBars>();
+        let _ = world.run_system_once(spawn_health_bar_pool);
+
+        let enemy = world
+            .spawn((Faction::Monsters, Health::new(10.0), GlobalTransform::default()))
+            .id();
+        world.resource_mut::<TrackedHealthBars>().0.insert(enemy, 0.0);
+        // No camera: nothing can be projected.
+        let _ = world.run_system_once(update_health_bars);
+
+        let slots = world
+            .query::<(&HealthBarSlot, &Visibility)>()
+            .iter(&world)
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>();
+        assert_eq!(slots.len(), HealthBarSettings::default().pool_size);
+        assert!(slots.iter().all(|v| *v == Visibility::Hidden));
+    }
+}
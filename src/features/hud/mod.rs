@@ -0,0 +1,94 @@
+// src/features/hud/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::health::damage::DamageEvent;
+use crate::features::race::component::Race;
+use crate::features::weapon::firing::WeaponFired;
+
+pub mod ammo;
+pub mod crosshair;
+pub mod glyphs;
+pub mod health_bars;
+pub mod name_tags;
+pub mod objectives;
+pub mod oxygen;
+pub mod settings;
+pub mod splits;
+pub mod stealth;
+pub mod wallet;
+
+/// Heads-up display feature.
+///
+/// Scope:
+/// - crosshair: spread from movement speed, aim and `CrosshairKick` bloom,
+///   hit-confirm flash on damage to enemies, style / colors in `HudSettings`
+/// - health bars: pooled widgets over recently damaged characters, projected
+///   from world to screen every frame, fading out after `HealthBarSettings::visible_secs`
+/// - name tags: `Name`s over characters near the camera (`NameTagSettings::enabled`)
+/// - bars and tags are colored by how the players' faction regards the character
+///   (`RelationColors`, matrix in `Tuning::factions`)
+/// - ammo counter: equipped weapon's magazine / reserve, reload indicator;
+///   the player's shots bloom the crosshair
+/// - objective markers: labels over `ActiveObjective`s, pinned to the screen edge
+///   with a distance readout when off-screen (`ObjectiveMarkerSettings::enabled`)
+/// - wallet counter: the player's coin balance
+/// - detection meter: how aware enemies are of the player (shown while crouched or
+///   noticed)
+/// - oxygen bar: the player's air while diving (shown until it's full again)
+/// - race splits: time at each checkpoint passed, +/- against the reference run
+/// - input glyphs: key labels and `{action}` prompt templates for other UI
+///
+/// Design constraints:
+/// - HUD only reads gameplay state and messages; it never writes gameplay components.
+/// - Widgets are built once at startup and updated in place (no per-frame spawning).
+/// - Updates run in `AppSet::UiSync`, so bars and markers sit on this frame's
+///   camera and targets.
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<settings::HudSettings>();
+        app.init_resource::<crosshair::CrosshairState>();
+        app.init_resource::<health_bars::TrackedHealthBars>();
+        app.init_resource::<Race>();
+
+        app.add_message::<crosshair::CrosshairKick>();
+        app.add_message::<DamageEvent>();
+        app.add_message::<WeaponFired>();
+
+        app.add_systems(
+            Startup,
+            (
+                crosshair::spawn_crosshair,
+                ammo::spawn_ammo_counter,
+                health_bars::spawn_health_bar_pool,
+                name_tags::spawn_name_tag_pool,
+                objectives::spawn_objective_marker_pool,
+                wallet::spawn_wallet_counter,
+                stealth::spawn_detection_meter,
+                oxygen::spawn_oxygen_bar,
+                splits::spawn_race_splits,
+            ),
+        );
+        app.add_systems(
+            PostUpdate,
+            (
+                ammo::kick_crosshair_on_fire,
+                crosshair::track_crosshair_state,
+                crosshair::update_crosshair,
+                ammo::update_ammo_counter,
+                health_bars::track_health_bars,
+                health_bars::update_health_bars,
+                name_tags::update_name_tags,
+                objectives::update_objective_markers,
+                wallet::update_wallet_counter,
+                stealth::update_detection_meter,
+                oxygen::update_oxygen_bar,
+                splits::update_race_splits,
+            )
+                .chain()
+                .in_set(AppSet::UiSync),
+        );
+    }
+}
@@ -0,0 +1,126 @@
+// src/features/hud/name_tags.rs
+use bevy::prelude::*;
+
+use super::settings::HudSettings;
+use crate::app::{Allegiance, Faction};
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::collision::component::Collider;
+use crate::features::health::component::Health;
+use crate::features::player::component::Player;
+
+/// One pooled name tag label.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NameTagSlot {
+    pub index: usize,
+}
+
+/// Startup: spawn the tag pool (hidden until assigned).
+pub fn spawn_name_tag_pool(mut commands: Commands, settings: Res<HudSettings>) {
+    let s = &settings.name_tags;
+    for index in 0..s.pool_size {
+        commands.spawn((
+            NameTagSlot { index },
+            Text::new(""),
+            TextFont {
+                font_size: s.font_size,
+                ..default()
+            },
+            TextColor(settings.relations.neutral),
+            TextLayout::new_with_justify(Justify::Center),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(s.width),
+                ..default()
+            },
+            Visibility::Hidden,
+            Name::new("NameTag"),
+        ));
+    }
+}
+
+/// Update: label living characters (anyone with a faction, or an owner who has
+/// one, but the player) within `NameTagSettings::range` of the camera, colored by
+/// how the players' faction regards them.
+///
+/// The nearest characters get the pooled tags; the rest get none.
+#[expect(clippy::type_complexity)]
+pub fn update_name_tags(
+    settings: Res<HudSettings>,
+    allegiance: Allegiance,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_named: Query<
+        (Entity, &Name, &GlobalTransform, Option<&Collider>, Option<&Health>),
+        Without<Player>,
+    >,
+    mut q_slots: Query<(&NameTagSlot, &mut Text, &mut TextColor, &mut Node, &mut Visibility)>,
+) {
+    let s = &settings.name_tags;
+    let camera = q_camera
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .filter(|_| s.enabled);
+
+    let mut tags: Vec<_> = q_named
+        .iter()
+        .filter_map(|(entity, name, tr, collider, health)| {
+            let (camera, camera_tr) = camera?;
+            if health.is_some_and(Health::is_depleted) {
+                return None;
+            }
+            let faction = allegiance.faction(entity)?;
+            let distance = tr.translation().distance(camera_tr.translation());
+            if distance > s.range {
+                return None;
+            }
+            let half_height = collider.map_or(1.0, |c| c.half_extents.y);
+            let top = tr.translation() + Vec3::Y * (half_height + s.world_offset);
+            let screen = camera.world_to_viewport(camera_tr, top).ok()?;
+            let color = settings
+                .relations
+                .get(allegiance.table_relation(Faction::Players, faction));
+            Some((distance, entity, name.as_str(), screen, color))
+        })
+        .collect();
+    // Nearest first (ties by entity so tags don't swap slots).
+    tags.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+    for (slot, mut text, mut color, mut node, mut vis) in &mut q_slots {
+        let Some(&(_, _, name, screen, tag_color)) = tags.get(slot.index) else {
+            if *vis != Visibility::Hidden {
+                *vis = Visibility::Hidden;
+            }
+            continue;
+        };
+
+        if text.0 != name {
+            text.0 = name.to_string();
+        }
+        color.0 = tag_color;
+        node.left = Val::Px(screen.x - s.width * 0.5);
+        node.top = Val::Px(screen.y - s.font_size * 1.5);
+        *vis = Visibility::Inherited;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn tags_stay_hidden_without_a_camera() {
+        let mut world = World::new();
+        world.insert_resource(HudSettings::default());
+        let _ = world.run_system_once(spawn_name_tag_pool);
+        world.spawn((Name::new("Guard"), Faction::Villagers, GlobalTransform::default()));
+        let _ = world.run_system_once(update_name_tags);
+
+        let slots: Vec<_> = world
+            .query_filtered::<&Visibility, With<NameTagSlot>>()
+            .iter(&world)
+            .copied()
+            .collect();
+        assert_eq!(slots.len(), HudSettings::default().name_tags.pool_size);
+        assert!(slots.iter().all(|v| *v == Visibility::Hidden));
+    }
+}
@@ -0,0 +1,208 @@
+// src/features/hud/objectives.rs
+use bevy::prelude::*;
+
+use super::settings::HudSettings;
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::objective::component::{ActiveObjective, Objective};
+use crate::features::player::component::Player;
+
+/// One pooled objective marker (label text, plus the distance when off-screen).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectiveMarkerSlot {
+    pub index: usize,
+}
+
+/// Where a marker goes on a `viewport`-sized screen, and whether the target is on it.
+///
+/// `projected` is the target's viewport position (if it could be projected) and
+/// `camera_local` its position in camera space (`-Z` forward). Targets outside the
+/// screen rect inset by `margin`, or behind the camera, are pinned to that rect's
+/// edge in their direction from the center.
+pub fn marker_position(
+    viewport: Vec2,
+    margin: f32,
+    projected: Option<Vec2>,
+    camera_local: Vec3,
+) -> (Vec2, bool) {
+    let center = viewport * 0.5;
+    let half = (center - Vec2::splat(margin)).max(Vec2::ONE);
+    let in_front = camera_local.z < 0.0;
+
+    if let Some(screen) = projected.filter(|_| in_front) {
+        let offset = screen - center;
+        if offset.x.abs() <= half.x && offset.y.abs() <= half.y {
+            return (screen, true);
+        }
+    }
+
+    // Behind the camera the projection is mirrored: use the camera-space direction
+    // instead (screen y points down).
+    let dir = match projected {
+        Some(screen) if in_front => screen - center,
+        _ => Vec2::new(camera_local.x, -camera_local.y),
+    };
+    // Straight behind: park the marker at the bottom edge.
+    let dir = if dir.length_squared() < 1e-6 { Vec2::Y } else { dir };
+    let scale = (half.x / dir.x.abs()).min(half.y / dir.y.abs());
+    (center + dir * scale, false)
+}
+
+/// Marker text: the label, plus the distance (whole meters) when off-screen.
+pub fn marker_text(label: &str, distance: f32, on_screen: bool) -> String {
+    if on_screen {
+        label.to_string()
+    } else {
+        format!("{label}\n{:.0} m", distance)
+    }
+}
+
+/// Startup: spawn the marker pool (hidden until assigned).
+pub fn spawn_objective_marker_pool(mut commands: Commands, settings: Res<HudSettings>) {
+    let s = &settings.objectives;
+    for index in 0..s.pool_size {
+        commands.spawn((
+            ObjectiveMarkerSlot { index },
+            Text::new(""),
+            TextFont {
+                font_size: s.font_size,
+                ..default()
+            },
+            TextColor(s.color),
+            TextLayout::new_with_justify(Justify::Center),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(s.width),
+                ..default()
+            },
+            Visibility::Hidden,
+            Name::new("ObjectiveMarker"),
+        ));
+    }
+}
+
+/// Update: project active objectives and lay out pooled markers over them, pinned to
+/// the screen edge with a distance readout when off-screen.
+#[expect(clippy::type_complexity)]
+pub fn update_objective_markers(
+    settings: Res<HudSettings>,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_players: Query<&GlobalTransform, With<Player>>,
+    q_objectives: Query<(Entity, &Objective, &GlobalTransform), With<ActiveObjective>>,
+    mut q_slots: Query<(&ObjectiveMarkerSlot, &mut Text, &mut Node, &mut Visibility)>,
+) {
+    let s = &settings.objectives;
+    let camera = q_camera
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .and_then(|(camera, tr)| Some((camera, tr, camera.logical_viewport_size()?)));
+
+    let mut markers: Vec<_> = match camera {
+        Some((camera, camera_tr, viewport)) if s.enabled => {
+            let from = q_players
+                .iter()
+                .next()
+                .unwrap_or(camera_tr)
+                .translation();
+            let to_local = camera_tr.affine().inverse();
+            q_objectives
+                .iter()
+                .map(|(entity, objective, tr)| {
+                    let target = tr.translation() + Vec3::Y * s.world_offset;
+                    let projected = camera.world_to_viewport(camera_tr, target).ok();
+                    let local = to_local.transform_point3(target);
+                    let (pos, on_screen) = marker_position(viewport, s.edge_margin, projected, local);
+                    let text = marker_text(&objective.label, from.distance(tr.translation()), on_screen);
+                    (entity, pos, text)
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+    markers.sort_by_key(|(entity, ..)| *entity);
+
+    for (slot, mut text, mut node, mut vis) in &mut q_slots {
+        let Some((_, pos, label)) = markers.get(slot.index) else {
+            if *vis != Visibility::Hidden {
+                *vis = Visibility::Hidden;
+            }
+            continue;
+        };
+
+        node.left = Val::Px(pos.x - s.width * 0.5);
+        node.top = Val::Px(pos.y - s.font_size * 0.5);
+        if text.0 != *label {
+            text.0.clone_from(label);
+        }
+        *vis = Visibility::Inherited;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    const VIEWPORT: Vec2 = Vec2::new(800.0, 600.0);
+
+    #[test]
+    fn on_screen_targets_stay_where_projected() {
+        let (pos, on_screen) =
+            marker_position(VIEWPORT, 40.0, Some(Vec2::new(500.0, 200.0)), Vec3::NEG_Z);
+        assert!(on_screen);
+        assert_eq!(pos, Vec2::new(500.0, 200.0));
+    }
+
+    #[test]
+    fn off_screen_targets_are_pinned_to_the_edge() {
+        // Far to the right, level with the center.
+        let (pos, on_screen) =
+            marker_position(VIEWPORT, 40.0, Some(Vec2::new(2000.0, 300.0)), Vec3::NEG_Z);
+        assert!(!on_screen);
+        assert_eq!(pos, Vec2::new(760.0, 300.0));
+
+        // Behind and to the left: the mirrored projection is ignored.
+        let (pos, on_screen) = marker_position(
+            VIEWPORT,
+            40.0,
+            Some(Vec2::new(700.0, 300.0)),
+            Vec3::new(-5.0, 0.0, 5.0),
+        );
+        assert!(!on_screen);
+        assert_eq!(pos, Vec2::new(40.0, 300.0));
+
+        // Dead behind: bottom edge.
+        let (pos, _) = marker_position(VIEWPORT, 40.0, None, Vec3::Z);
+        assert_eq!(pos, Vec2::new(400.0, 560.0));
+    }
+
+    #[test]
+    fn distance_is_shown_only_off_screen() {
+        assert_eq!(marker_text("Camp", 12.4, true), "Camp");
+        assert_eq!(marker_text("Camp", 12.4, false), "Camp\n12 m");
+    }
+
+    #[test]
+    fn markers_stay_hidden_without_a_camera() {
+        let mut world = World::new();
+        world.insert_resource(HudSettings::default());
+        let _ = world.run_system_once(spawn_objective_marker_pool);
+        world.spawn((
+            Objective {
+                label: "Camp".into(),
+                radius: 1.0,
+                order: 0,
+            },
+            ActiveObjective,
+            GlobalTransform::default(),
+        ));
+        let _ = world.run_system_once(update_objective_markers);
+
+        let slots: Vec<_> = world
+            .query_filtered::<&Visibility, With<ObjectiveMarkerSlot>>()
+            .iter(&world)
+            .copied()
+            .collect();
+        assert_eq!(slots.len(), HudSettings::default().objectives.pool_size);
+        assert!(slots.iter().all(|v| *v == Visibility::Hidden));
+    }
+}
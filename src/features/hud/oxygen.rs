@@ -0,0 +1,119 @@
+// src/features/hud/oxygen.rs
+use bevy::prelude::*;
+
+use crate::features::player::component::Player;
+use crate::features::water::component::Oxygen;
+
+/// Width of the oxygen bar (logical pixels).
+const BAR_WIDTH: f32 = 160.0;
+
+/// Oxygen below this share turns the bar red.
+const LOW_OXYGEN: f32 = 0.25;
+
+/// Bottom-center oxygen bar, shown while the player's air isn't full.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct OxygenBar;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct OxygenBarFill;
+
+/// Fill color: light blue, red when nearly out of air.
+pub fn oxygen_color(fraction: f32) -> Color {
+    if fraction < LOW_OXYGEN {
+        Color::srgb(0.95, 0.2, 0.15)
+    } else {
+        Color::srgb(0.55, 0.85, 1.0)
+    }
+}
+
+/// Startup: spawn the (hidden) oxygen bar.
+pub fn spawn_oxygen_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            OxygenBar,
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(72.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-BAR_WIDTH / 2.0)),
+                width: Val::Px(BAR_WIDTH),
+                height: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            Visibility::Hidden,
+            Name::new("OxygenBar"),
+        ))
+        .with_child((
+            OxygenBarFill,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(oxygen_color(1.0)),
+        ));
+}
+
+/// Update: shown while the player is short of air (diving or catching breath).
+pub fn update_oxygen_bar(
+    q_player: Query<&Oxygen, With<Player>>,
+    mut q_bar: Query<&mut Visibility, With<OxygenBar>>,
+    mut q_fill: Query<(&mut Node, &mut BackgroundColor), With<OxygenBarFill>>,
+) {
+    let shown = q_player
+        .iter()
+        .next()
+        .filter(|oxygen| !oxygen.is_full())
+        .map(Oxygen::fraction);
+
+    for mut vis in &mut q_bar {
+        let next = if shown.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *vis != next {
+            *vis = next;
+        }
+    }
+    let Some(fraction) = shown else {
+        return;
+    };
+    for (mut node, mut color) in &mut q_fill {
+        node.width = Val::Percent(fraction * 100.0);
+        color.0 = oxygen_color(fraction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn bar_shows_while_short_of_air() {
+        let mut world = World::new();
+        let _ = world.run_system_once(spawn_oxygen_bar);
+        let player = world.spawn((Player, Oxygen::new(20.0))).id();
+        let visibility = |world: &mut World| {
+            *world
+                .query_filtered::<&Visibility, With<OxygenBar>>()
+                .single(world)
+                .unwrap()
+        };
+
+        let _ = world.run_system_once(update_oxygen_bar);
+        assert_eq!(visibility(&mut world), Visibility::Hidden);
+
+        world.get_mut::<Oxygen>(player).unwrap().current = 4.0;
+        let _ = world.run_system_once(update_oxygen_bar);
+        assert_eq!(visibility(&mut world), Visibility::Inherited);
+        let (node, color) = world
+            .query_filtered::<(&Node, &BackgroundColor), With<OxygenBarFill>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(node.width, Val::Percent(20.0));
+        assert_eq!(color.0, oxygen_color(0.0));
+    }
+}
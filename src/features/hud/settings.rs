@@ -0,0 +1,185 @@
+// src/features/hud/settings.rs
+use bevy::prelude::*;
+
+use crate::app::Relation;
+
+/// Crosshair shape.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CrosshairStyle {
+    /// Four lines around the aim point.
+    #[default]
+    Cross,
+    /// Single center dot.
+    Dot,
+    /// Four lines plus a center dot.
+    CrossWithDot,
+}
+
+/// User-facing HUD configuration.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct HudSettings {
+    pub crosshair: CrosshairSettings,
+    pub health_bars: HealthBarSettings,
+    pub objectives: ObjectiveMarkerSettings,
+    pub name_tags: NameTagSettings,
+    pub relations: RelationColors,
+}
+
+/// Crosshair look and spread behaviour (all sizes in logical pixels).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrosshairSettings {
+    pub style: CrosshairStyle,
+    pub color: Color,
+    /// Color of the hit-confirm flash.
+    pub hit_color: Color,
+    pub line_length: f32,
+    pub thickness: f32,
+    /// Gap between the aim point and each line at rest.
+    pub base_gap: f32,
+    pub max_gap: f32,
+    /// Extra gap per world unit/sec of horizontal movement speed.
+    pub gap_per_speed: f32,
+    /// Bloom decay rate (1/sec, exponential).
+    pub bloom_recovery: f32,
+    /// Seconds the hit-confirm flash lasts.
+    pub hit_flash_secs: f32,
+    /// Bloom added per shot of the player's weapon.
+    pub fire_kick: f32,
+}
+
+impl Default for CrosshairSettings {
+    fn default() -> Self {
+        Self {
+            style: CrosshairStyle::Cross,
+            color: Color::WHITE,
+            hit_color: Color::srgb(1.0, 0.25, 0.2),
+            line_length: 8.0,
+            thickness: 2.0,
+            base_gap: 4.0,
+            max_gap: 40.0,
+            gap_per_speed: 2.0,
+            bloom_recovery: 8.0,
+            hit_flash_secs: 0.15,
+            fire_kick: 6.0,
+        }
+    }
+}
+
+/// Floating health bars over damaged characters (sizes in logical pixels); the
+/// fill takes the `RelationColors` of the character's faction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HealthBarSettings {
+    pub width: f32,
+    pub height: f32,
+    /// World units above the character's collider top where the bar is anchored.
+    pub world_offset: f32,
+    /// Seconds a bar stays fully visible after the last hit.
+    pub visible_secs: f32,
+    /// Seconds the bar then takes to fade out.
+    pub fade_secs: f32,
+    /// Number of pooled bar widgets (max bars on screen at once).
+    pub pool_size: usize,
+    pub back_color: Color,
+}
+
+impl Default for HealthBarSettings {
+    fn default() -> Self {
+        Self {
+            width: 48.0,
+            height: 5.0,
+            world_offset: 0.3,
+            visible_secs: 3.0,
+            fade_secs: 0.5,
+            pool_size: 16,
+            back_color: Color::srgba(0.0, 0.0, 0.0, 0.6),
+        }
+    }
+}
+
+/// Objective markers (sizes in logical pixels).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectiveMarkerSettings {
+    /// Show markers over active objectives.
+    pub enabled: bool,
+    /// Inset from the screen border where off-screen markers are pinned.
+    pub edge_margin: f32,
+    /// Width of the (centered) marker label.
+    pub width: f32,
+    pub font_size: f32,
+    /// World units above the objective where the marker is anchored.
+    pub world_offset: f32,
+    /// Number of pooled marker widgets (max markers on screen at once).
+    pub pool_size: usize,
+    pub color: Color,
+}
+
+impl Default for ObjectiveMarkerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            edge_margin: 48.0,
+            width: 160.0,
+            font_size: 18.0,
+            world_offset: 1.5,
+            pool_size: 4,
+            color: Color::srgb(1.0, 0.85, 0.3),
+        }
+    }
+}
+
+/// Name tags over nearby characters (sizes in logical pixels), colored by
+/// `RelationColors`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NameTagSettings {
+    pub enabled: bool,
+    /// World units from the camera within which characters get a tag.
+    pub range: f32,
+    /// Width of the (centered) tag label.
+    pub width: f32,
+    pub font_size: f32,
+    /// World units above the character's collider top where the tag is anchored.
+    pub world_offset: f32,
+    /// Number of pooled tag widgets (the nearest characters get them).
+    pub pool_size: usize,
+}
+
+impl Default for NameTagSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            range: 15.0,
+            width: 160.0,
+            font_size: 14.0,
+            world_offset: 0.6,
+            pool_size: 8,
+        }
+    }
+}
+
+/// UI colors by how the players' faction regards a character (`Tuning::factions`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelationColors {
+    pub ally: Color,
+    pub neutral: Color,
+    pub hostile: Color,
+}
+
+impl RelationColors {
+    pub fn get(&self, relation: Relation) -> Color {
+        match relation {
+            Relation::Ally => self.ally,
+            Relation::Neutral => self.neutral,
+            Relation::Hostile => self.hostile,
+        }
+    }
+}
+
+impl Default for RelationColors {
+    fn default() -> Self {
+        Self {
+            ally: Color::srgb(0.3, 0.8, 0.35),
+            neutral: Color::srgb(0.95, 0.8, 0.3),
+            hostile: Color::srgb(0.85, 0.15, 0.1),
+        }
+    }
+}
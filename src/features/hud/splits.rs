@@ -0,0 +1,114 @@
+// src/features/hud/splits.rs
+use bevy::prelude::*;
+
+use crate::app::TickRate;
+use crate::features::race::component::{Race, RaceStatus, ticks_to_secs};
+
+/// Right-side list of race split times, shown while a race is on.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct RaceSplits;
+
+/// Startup: spawn the (hidden) split list.
+pub fn spawn_race_splits(mut commands: Commands) {
+    commands.spawn((
+        RaceSplits,
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.95, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(24.0),
+            top: Val::Px(64.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        Name::new("RaceSplits"),
+    ));
+}
+
+/// One split line: "CP 2  12.40s  -0.35" (the delta only with a reference).
+pub fn split_line(race: &Race, index: usize, rate: TickRate) -> String {
+    let label = if index + 1 == race.checkpoints {
+        "Finish".to_string()
+    } else {
+        format!("CP {}", index + 1)
+    };
+    let secs = ticks_to_secs(race.splits[index], rate);
+    match race.delta_ticks(index) {
+        Some(delta) => {
+            let delta_secs = delta as f64 / rate.0;
+            format!("{label}  {secs:.2}s  {delta_secs:+.2}")
+        }
+        None => format!("{label}  {secs:.2}s"),
+    }
+}
+
+/// Update: list the splits passed so far (and whether the run was voided).
+pub fn update_race_splits(
+    race: Res<Race>,
+    rate: Res<TickRate>,
+    mut q_splits: Query<(&mut Text, &mut Visibility), With<RaceSplits>>,
+) {
+    let text = match race.status {
+        RaceStatus::Idle => None,
+        status => {
+            let mut lines: Vec<String> = (0..race.splits.len())
+                .map(|index| split_line(&race, index, *rate))
+                .collect();
+            if status == RaceStatus::Invalidated {
+                lines.push("Invalid run".to_string());
+            }
+            Some(lines.join("\n"))
+        }
+    };
+
+    for (mut splits, mut vis) in &mut q_splits {
+        match &text {
+            Some(text) => {
+                if splits.0 != *text {
+                    splits.0.clone_from(text);
+                }
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::SimTick;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn splits_show_times_and_deltas_against_the_reference() {
+        let mut world = World::new();
+        world.init_resource::<Race>();
+        world.insert_resource(TickRate(60.0));
+        let _ = world.run_system_once(spawn_race_splits);
+        let _ = world.run_system_once(update_race_splits);
+        let vis = *world
+            .query_filtered::<&Visibility, With<RaceSplits>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(vis, Visibility::Hidden, "no race");
+
+        let mut race = Race::start(SimTick(0), 2);
+        race.splits = vec![120, 300];
+        race.reference = vec![150, 270];
+        race.status = RaceStatus::Finished;
+        world.insert_resource(race);
+        let _ = world.run_system_once(update_race_splits);
+
+        let (text, vis) = world
+            .query_filtered::<(&Text, &Visibility), With<RaceSplits>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(text.0, "CP 1  2.00s  -0.50\nFinish  5.00s  +0.50");
+        assert_eq!(*vis, Visibility::Inherited);
+    }
+}
@@ -0,0 +1,155 @@
+// src/features/hud/stealth.rs
+use bevy::prelude::*;
+
+use crate::features::player::component::Player;
+use crate::features::stealth::component::{Crouching, DetectionLevel, Stealth};
+
+/// Width of the detection bar (logical pixels).
+const METER_WIDTH: f32 = 160.0;
+
+/// Top-center detection meter: label + bar filling as enemies notice the player.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DetectionMeter;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DetectionMeterFill;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DetectionMeterLabel;
+
+/// Fill color: amber while suspicious, red once detected.
+pub fn meter_color(detection: f32) -> Color {
+    match DetectionLevel::of(detection) {
+        DetectionLevel::Detected => Color::srgb(0.95, 0.2, 0.15),
+        _ => Color::srgb(1.0, 0.75, 0.2),
+    }
+}
+
+/// Startup: spawn the (hidden) detection meter.
+pub fn spawn_detection_meter(mut commands: Commands) {
+    commands
+        .spawn((
+            DetectionMeter,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(24.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-METER_WIDTH / 2.0)),
+                width: Val::Px(METER_WIDTH),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            Name::new("DetectionMeter"),
+        ))
+        .with_children(|meter| {
+            meter.spawn((
+                DetectionMeterLabel,
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+            ));
+            meter
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                ))
+                .with_child((
+                    DetectionMeterFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(meter_color(0.0)),
+                ));
+        });
+}
+
+/// Update: shown while the player crouches or is being noticed.
+pub fn update_detection_meter(
+    q_player: Query<(&Stealth, Has<Crouching>), With<Player>>,
+    mut q_meter: Query<&mut Visibility, With<DetectionMeter>>,
+    mut q_fill: Query<(&mut Node, &mut BackgroundColor), With<DetectionMeterFill>>,
+    mut q_label: Query<&mut Text, With<DetectionMeterLabel>>,
+) {
+    let shown = q_player
+        .iter()
+        .next()
+        .filter(|(stealth, crouching)| *crouching || stealth.detection > 0.0)
+        .map(|(stealth, _)| stealth.detection);
+
+    for mut vis in &mut q_meter {
+        let next = if shown.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *vis != next {
+            *vis = next;
+        }
+    }
+    let Some(detection) = shown else {
+        return;
+    };
+    for (mut node, mut color) in &mut q_fill {
+        node.width = Val::Percent(detection.clamp(0.0, 1.0) * 100.0);
+        color.0 = meter_color(detection);
+    }
+    let label = DetectionLevel::of(detection).label();
+    for mut text in &mut q_label {
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn meter_shows_while_crouched_or_noticed() {
+        let mut world = World::new();
+        let _ = world.run_system_once(spawn_detection_meter);
+        let player = world.spawn((Player, Stealth::default())).id();
+        let visibility = |world: &mut World| {
+            *world
+                .query_filtered::<&Visibility, With<DetectionMeter>>()
+                .single(world)
+                .unwrap()
+        };
+
+        let _ = world.run_system_once(update_detection_meter);
+        assert_eq!(visibility(&mut world), Visibility::Hidden);
+
+        world.get_mut::<Stealth>(player).unwrap().detection = 0.25;
+        let _ = world.run_system_once(update_detection_meter);
+        assert_eq!(visibility(&mut world), Visibility::Inherited);
+        let width = world
+            .query_filtered::<&Node, With<DetectionMeterFill>>()
+            .single(&world)
+            .unwrap()
+            .width;
+        assert_eq!(width, Val::Percent(25.0));
+        let label = world
+            .query_filtered::<&Text, With<DetectionMeterLabel>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(label.0, "Suspicious");
+
+        world.get_mut::<Stealth>(player).unwrap().detection = 0.0;
+        world.entity_mut(player).insert(Crouching { speed_scale: 0.5 });
+        let _ = world.run_system_once(update_detection_meter);
+        assert_eq!(visibility(&mut world), Visibility::Inherited, "crouched");
+    }
+}
@@ -0,0 +1,78 @@
+// src/features/hud/wallet.rs
+use bevy::prelude::*;
+
+use crate::features::player::component::Player;
+use crate::features::wallet::component::Wallet;
+
+/// Top-right coin counter.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct WalletCounter;
+
+/// Startup: spawn the coin counter.
+pub fn spawn_wallet_counter(mut commands: Commands) {
+    commands.spawn((
+        WalletCounter,
+        Text::new(""),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.85, 0.3)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(24.0),
+            top: Val::Px(24.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        Name::new("WalletCounter"),
+    ));
+}
+
+/// Update: mirror the player's balance into the counter.
+pub fn update_wallet_counter(
+    q_player: Query<&Wallet, With<Player>>,
+    mut q_counter: Query<(&mut Text, &mut Visibility), With<WalletCounter>>,
+) {
+    let text = q_player.iter().next().map(|w| format!("{} coins", w.coins));
+
+    for (mut counter, mut vis) in &mut q_counter {
+        match &text {
+            Some(text) => {
+                if counter.0 != *text {
+                    counter.0.clone_from(text);
+                }
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn counter_shows_the_player_balance() {
+        let mut world = World::new();
+        let _ = world.run_system_once(spawn_wallet_counter);
+        let _ = world.run_system_once(update_wallet_counter);
+        let vis = *world
+            .query_filtered::<&Visibility, With<WalletCounter>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(vis, Visibility::Hidden);
+
+        world.spawn((Player, Wallet::new(12)));
+        let _ = world.run_system_once(update_wallet_counter);
+
+        let (text, vis) = world
+            .query_filtered::<(&Text, &Visibility), With<WalletCounter>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(text.0, "12 coins");
+        assert_eq!(*vis, Visibility::Inherited);
+    }
+}
@@ -0,0 +1,46 @@
+// src/features/ik/component.rs
+use bevy::prelude::*;
+
+/// One leg chain, by joint entity (hip -> knee -> foot).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IkLeg {
+    pub hip: Entity,
+    pub knee: Entity,
+    pub foot: Entity,
+    /// Knee bend hint, in the character's local space (e.g. `Vec3::NEG_Z` = knees forward).
+    pub pole_local: Vec3,
+}
+
+/// Foot placement rig on a character root.
+///
+/// Applied after animation each frame:
+/// - lowers the pelvis so the lowest-grounded foot can reach its surface
+/// - two-bone IK lifts each foot onto the ground under it
+/// - feet are aligned to the ground normal
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct FootIk {
+    pub pelvis: Entity,
+    pub legs: Vec<IkLeg>,
+    /// Height of the foot joint above the sole.
+    pub foot_height: f32,
+    /// Max distance the pelvis may drop to reach lower ground.
+    pub max_pelvis_drop: f32,
+}
+
+/// Ground query used by IK: `(height, normal)` at a world XZ position.
+///
+/// Flat ground at `y = 0` until terrain exists; terrain replaces the sampler.
+#[derive(Resource)]
+pub struct IkGround(pub Box<dyn Fn(Vec2) -> (f32, Vec3) + Send + Sync>);
+
+impl Default for IkGround {
+    fn default() -> Self {
+        Self(Box::new(|_| (0.0, Vec3::Y)))
+    }
+}
+
+impl IkGround {
+    pub fn sample(&self, xz: Vec2) -> (f32, Vec3) {
+        (self.0)(xz)
+    }
+}
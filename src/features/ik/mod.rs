@@ -0,0 +1,31 @@
+// src/features/ik/mod.rs
+use bevy::app::AnimationSystems;
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+
+pub mod component;
+pub mod solver;
+pub mod systems;
+
+/// Foot IK feature plugin.
+///
+/// Scope:
+/// - `FootIk` rigs (pelvis + hip/knee/foot chains) on animated characters
+/// - PostUpdate, between animation and transform propagation: pelvis drop,
+///   two-bone leg IK onto `IkGround`, foot alignment to the ground normal
+///
+/// Ground sampling is a resource so terrain can plug in its heightfield later.
+pub struct IkPlugin;
+
+impl Plugin for IkPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::IkGround>();
+
+        app.add_systems(
+            PostUpdate,
+            systems::apply_foot_ik
+                .after(AnimationSystems)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// src/features/ik/solver.rs
+//
+// Pure two-bone IK math (no ECS), world-space positions in, positions out.
+use bevy::prelude::*;
+
+/// Solve a two-bone chain `root -> mid -> end` so `end` reaches `target`.
+///
+/// - Bone lengths are preserved (`|mid - root|`, `|end - mid|`).
+/// - The joint bends towards `pole` (a world-space point, e.g. in front of the knee).
+/// - Unreachable targets fully extend the chain towards the target.
+/// - Targets closer than `|l1 - l2|` fold the chain as far as possible.
+///
+/// Returns the new `(mid, end)` positions; `root` never moves.
+pub fn solve_two_bone(root: Vec3, mid: Vec3, end: Vec3, target: Vec3, pole: Vec3) -> (Vec3, Vec3) {
+    let l1 = root.distance(mid);
+    let l2 = mid.distance(end);
+
+    let to_target = target - root;
+    let Some(dir) = to_target.try_normalize() else {
+        return (mid, end);
+    };
+
+    // Clamp reach into the solvable range.
+    let min_reach = (l1 - l2).abs() + 1e-4;
+    let max_reach = (l1 + l2) - 1e-4;
+    let dist = to_target.length().clamp(min_reach, max_reach);
+
+    // Law of cosines: distance along `dir` to the mid joint's projection, and its offset.
+    let along = (l1 * l1 - l2 * l2 + dist * dist) / (2.0 * dist);
+    let offset = (l1 * l1 - along * along).max(0.0).sqrt();
+
+    // Bend direction: pole projected onto the plane perpendicular to `dir`.
+    let pole_dir = pole - root;
+    let bend = (pole_dir - dir * pole_dir.dot(dir))
+        .try_normalize()
+        .or_else(|| {
+            let current = mid - root;
+            (current - dir * current.dot(dir)).try_normalize()
+        })
+        .unwrap_or_else(|| dir.any_orthonormal_vector());
+
+    let new_mid = root + dir * along + bend * offset;
+    let new_end = root + dir * dist;
+
+    (new_mid, new_end)
+}
+
+/// Rotation that turns a bone pointing `from_dir` into one pointing `to_dir`.
+pub fn bone_swing(from_dir: Vec3, to_dir: Vec3) -> Quat {
+    match (from_dir.try_normalize(), to_dir.try_normalize()) {
+        (Some(a), Some(b)) => Quat::from_rotation_arc(a, b),
+        _ => Quat::IDENTITY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-3;
+
+    // Straight leg hanging down: hip at y=1, knee at 0.5, foot at 0.
+    fn leg() -> (Vec3, Vec3, Vec3) {
+        (
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.5, 0.0),
+            Vec3::ZERO,
+        )
+    }
+
+    #[test]
+    fn reachable_target_is_hit_and_lengths_preserved() {
+        let (root, mid, end) = leg();
+        let target = Vec3::new(0.0, 0.3, 0.0);
+        let pole = Vec3::new(0.0, 0.5, -1.0);
+
+        let (m, e) = solve_two_bone(root, mid, end, target, pole);
+
+        assert!((e - target).length() < EPS, "end {e:?}");
+        assert!((root.distance(m) - 0.5).abs() < EPS);
+        assert!((m.distance(e) - 0.5).abs() < EPS);
+        // Knee bends towards the pole (-Z).
+        assert!(m.z < -0.1, "knee {m:?}");
+    }
+
+    #[test]
+    fn unreachable_target_extends_towards_it() {
+        let (root, mid, end) = leg();
+        let target = Vec3::new(0.0, -5.0, 0.0);
+
+        let (_, e) = solve_two_bone(root, mid, end, target, Vec3::NEG_Z);
+
+        assert!((e - Vec3::ZERO).length() < EPS, "fully extended: {e:?}");
+    }
+
+    #[test]
+    fn degenerate_target_at_root_is_noop() {
+        let (root, mid, end) = leg();
+        assert_eq!(
+            solve_two_bone(root, mid, end, root, Vec3::NEG_Z),
+            (mid, end)
+        );
+    }
+
+    #[test]
+    fn bone_swing_maps_directions() {
+        let q = bone_swing(Vec3::NEG_Y, Vec3::X);
+        assert!((q * Vec3::NEG_Y - Vec3::X).length() < EPS);
+        assert_eq!(bone_swing(Vec3::ZERO, Vec3::X), Quat::IDENTITY);
+    }
+}
@@ -0,0 +1,211 @@
+// src/features/ik/systems.rs
+use bevy::prelude::*;
+
+use super::component::{FootIk, IkGround, IkLeg};
+use super::solver::{bone_swing, solve_two_bone};
+
+/// World transforms of one leg chain.
+struct LegPose {
+    hip: GlobalTransform,
+    knee: GlobalTransform,
+    foot: GlobalTransform,
+}
+
+fn leg_pose(
+    joints: &Query<&mut Transform>,
+    pelvis_global: &GlobalTransform,
+    leg: &IkLeg,
+) -> Option<LegPose> {
+    let hip = pelvis_global.mul_transform(*joints.get(leg.hip).ok()?);
+    let knee = hip.mul_transform(*joints.get(leg.knee).ok()?);
+    let foot = knee.mul_transform(*joints.get(leg.foot).ok()?);
+    Some(LegPose { hip, knee, foot })
+}
+
+/// PostUpdate (after animation, before transform propagation): foot IK.
+///
+/// Works on local `Transform`s, because animation has just written them and
+/// `GlobalTransform`s are only recomputed afterwards. The pelvis' *parent* global
+/// transform is from last frame, which is fine for a character root that moves
+/// smoothly.
+pub fn apply_foot_ik(
+    ground: Res<IkGround>,
+    q_rigs: Query<(&FootIk, &GlobalTransform)>,
+    q_parents: Query<&ChildOf>,
+    q_globals: Query<&GlobalTransform>,
+    mut q_joints: Query<&mut Transform>,
+) {
+    for (rig, root_global) in &q_rigs {
+        let Some(pelvis_parent) = q_parents
+            .get(rig.pelvis)
+            .ok()
+            .and_then(|c| q_globals.get(c.parent()).ok())
+        else {
+            continue;
+        };
+        let Ok(&pelvis_local) = q_joints.get(rig.pelvis) else {
+            continue;
+        };
+
+        // 1) Pelvis drop: how far the lowest-reaching foot needs to go down.
+        let pelvis_global = pelvis_parent.mul_transform(pelvis_local);
+        let mut drop: f32 = 0.0;
+        for leg in &rig.legs {
+            let Some(p) = leg_pose(&q_joints, &pelvis_global, leg) else {
+                continue;
+            };
+            let foot = p.foot.translation();
+            let (height, _) = ground.sample(foot.xz());
+            drop = drop.min(height + rig.foot_height - foot.y);
+        }
+        let drop = drop.max(-rig.max_pelvis_drop);
+
+        let mut new_pelvis_local = pelvis_local;
+        new_pelvis_local.translation += pelvis_parent.rotation().inverse() * (Vec3::Y * drop);
+        let pelvis_global = pelvis_parent.mul_transform(new_pelvis_local);
+        if let Ok(mut t) = q_joints.get_mut(rig.pelvis) {
+            *t = new_pelvis_local;
+        }
+
+        // 2) Per-leg IK onto the ground (never pulls a swinging foot down).
+        for leg in &rig.legs {
+            let Some(p) = leg_pose(&q_joints, &pelvis_global, leg) else {
+                continue;
+            };
+            let (hip, knee, foot) = (
+                p.hip.translation(),
+                p.knee.translation(),
+                p.foot.translation(),
+            );
+            let (height, normal) = ground.sample(foot.xz());
+            let target = Vec3::new(foot.x, (height + rig.foot_height).max(foot.y), foot.z);
+            let pole = knee + root_global.rotation() * leg.pole_local;
+
+            let (new_knee, new_foot) = solve_two_bone(hip, knee, foot, target, pole);
+
+            // Hip: swing the thigh onto the new knee position.
+            let hip_world_rot = bone_swing(knee - hip, new_knee - hip) * p.hip.rotation();
+            let hip_local_rot = pelvis_global.rotation().inverse() * hip_world_rot;
+
+            // Knee: recompute its world pose under the new hip, then swing the shin.
+            let local = |e: Entity| q_joints.get(e).copied().unwrap_or_default();
+            let mut hip_local = local(leg.hip);
+            hip_local.rotation = hip_local_rot;
+            let hip_global = pelvis_global.mul_transform(hip_local);
+            let knee_global = hip_global.mul_transform(local(leg.knee));
+            let shin_from = knee_global.mul_transform(local(leg.foot)).translation()
+                - knee_global.translation();
+            let knee_world_rot =
+                bone_swing(shin_from, new_foot - new_knee) * knee_global.rotation();
+            let knee_local_rot = hip_global.rotation().inverse() * knee_world_rot;
+
+            // Foot: keep its world orientation, tilted onto the ground normal.
+            let foot_world_rot = Quat::from_rotation_arc(Vec3::Y, normal) * p.foot.rotation();
+            let knee_rot_global = hip_global.rotation() * knee_local_rot;
+            let foot_local_rot = knee_rot_global.inverse() * foot_world_rot;
+
+            if let Ok(mut t) = q_joints.get_mut(leg.hip) {
+                t.rotation = hip_local_rot;
+            }
+            if let Ok(mut t) = q_joints.get_mut(leg.knee) {
+                t.rotation = knee_local_rot;
+            }
+            if let Ok(mut t) = q_joints.get_mut(leg.foot) {
+                t.rotation = foot_local_rot;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// Root at origin; pelvis at y=1; two legs (x = ±0.2), each 0.5 + 0.5 long, straight down.
+    fn spawn_rig(world: &mut World) -> (Entity, Entity, [Entity; 2]) {
+        let root = world
+            .spawn((Transform::default(), GlobalTransform::default()))
+            .id();
+        let pelvis = world
+            .spawn((Transform::from_xyz(0.0, 1.0, 0.0), ChildOf(root)))
+            .id();
+
+        let mut legs = Vec::new();
+        let mut feet = [Entity::PLACEHOLDER; 2];
+        for (i, x) in [-0.2_f32, 0.2].into_iter().enumerate() {
+            let hip = world
+                .spawn((Transform::from_xyz(x, 0.0, 0.0), ChildOf(pelvis)))
+                .id();
+            let knee = world
+                .spawn((Transform::from_xyz(0.0, -0.5, 0.0), ChildOf(hip)))
+                .id();
+            let foot = world
+                .spawn((Transform::from_xyz(0.0, -0.5, 0.0), ChildOf(knee)))
+                .id();
+            legs.push(IkLeg {
+                hip,
+                knee,
+                foot,
+                pole_local: Vec3::NEG_Z,
+            });
+            feet[i] = foot;
+        }
+
+        world.entity_mut(root).insert(FootIk {
+            pelvis,
+            legs,
+            foot_height: 0.0,
+            max_pelvis_drop: 0.5,
+        });
+        (root, pelvis, feet)
+    }
+
+    fn world_pos(world: &mut World, e: Entity) -> Vec3 {
+        // Walk up the hierarchy composing local transforms.
+        let mut acc = GlobalTransform::from(*world.get::<Transform>(e).unwrap());
+        let mut cur = e;
+        while let Some(parent) = world.get::<ChildOf>(cur).map(|c| c.parent()) {
+            acc = GlobalTransform::from(*world.get::<Transform>(parent).unwrap()) * acc;
+            cur = parent;
+        }
+        acc.translation()
+    }
+
+    #[test]
+    fn flat_ground_leaves_standing_pose_untouched() {
+        let mut world = World::new();
+        world.insert_resource(IkGround::default());
+        let (_, pelvis, feet) = spawn_rig(&mut world);
+
+        let _ = world.run_system_once(apply_foot_ik);
+
+        assert!((world.get::<Transform>(pelvis).unwrap().translation.y - 1.0).abs() < 1e-4);
+        for f in feet {
+            assert!(world_pos(&mut world, f).y.abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn step_lowers_pelvis_and_lifts_high_foot() {
+        let mut world = World::new();
+        // Left half (x < 0) is a 0.2 step up, right half is 0.1 lower than flat.
+        world.insert_resource(IkGround(Box::new(|xz: Vec2| {
+            (if xz.x < 0.0 { 0.2 } else { -0.1 }, Vec3::Y)
+        })));
+        let (_, pelvis, [left, right]) = spawn_rig(&mut world);
+
+        let _ = world.run_system_once(apply_foot_ik);
+
+        let pelvis_y = world.get::<Transform>(pelvis).unwrap().translation.y;
+        assert!(
+            (pelvis_y - 0.9).abs() < 1e-4,
+            "pelvis drops 0.1, got {pelvis_y}"
+        );
+
+        let l = world_pos(&mut world, left);
+        let r = world_pos(&mut world, right);
+        assert!((l.y - 0.2).abs() < 1e-3, "left foot on the step: {l:?}");
+        assert!((r.y + 0.1).abs() < 1e-3, "right foot on low ground: {r:?}");
+    }
+}
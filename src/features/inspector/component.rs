@@ -0,0 +1,49 @@
+// src/features/inspector/component.rs
+use bevy::ecs::entity::{EntityGeneration, EntityIndex};
+use bevy::prelude::*;
+
+/// Farthest a click picks from the camera (world units).
+pub const PICK_REACH: f32 = 200.0;
+
+/// Console target word for the inspector's selection.
+pub const SELECTED: &str = "selected";
+
+/// Dev-tools picking state.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Inspector {
+    /// Clicks in the viewport select; the panel, collider and label show.
+    pub enabled: bool,
+    /// Entity picked last; cleared once it despawns.
+    pub selected: Option<Entity>,
+}
+
+impl Inspector {
+    /// Entity a console argument names: `selected`, or an entity id as the panel
+    /// prints it (`42v1`; a bare index means the first generation).
+    pub fn resolve(&self, target: &str) -> Result<Entity, String> {
+        if target == SELECTED {
+            return self.selected.ok_or_else(|| "nothing selected".to_owned());
+        }
+        let (index, generation) = target.split_once('v').unwrap_or((target, "0"));
+        let invalid = || format!("not an entity: `{target}`");
+        let index = index.parse().ok().and_then(EntityIndex::from_raw_u32).ok_or_else(invalid)?;
+        let generation = generation.parse().map(EntityGeneration::from_bits).map_err(|_| invalid())?;
+        Ok(Entity::from_index_and_generation(index, generation))
+    }
+}
+
+/// Inspector keys: `toggle` switches picking mode, `select` picks under the cursor.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InspectorKeybinding {
+    pub toggle: KeyCode,
+    pub select: MouseButton,
+}
+
+impl Default for InspectorKeybinding {
+    fn default() -> Self {
+        Self {
+            toggle: KeyCode::F3,
+            select: MouseButton::Left,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// src/features/inspector/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, HOTKEYS, in_input_context};
+use crate::features::console::systems::{ConsoleCommand, register_console_command};
+use crate::features::health::damage::DamageEvent;
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Entity inspector (dev tool).
+///
+/// Scope:
+/// - picking mode toggled with `InspectorKeybinding` (F3); a click in the viewport
+///   selects the collider under the cursor (`Inspector::selected`)
+/// - panel with the selection's name, id, position and components; its collider
+///   outlined and a name label over it
+/// - console: `damage selected 10` (or an entity id) hurts through `DamageEvent`
+///
+/// Design constraints:
+/// - Read-only apart from console commands: the selection never changes gameplay.
+/// - Picks through `CollisionWorld`, so only entities with a `Collider` are clickable.
+pub struct InspectorPlugin;
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::Inspector>();
+        app.init_resource::<component::InspectorKeybinding>();
+        app.add_message::<ConsoleCommand>();
+        app.add_message::<DamageEvent>();
+        register_console_command(app, systems::DAMAGE_COMMAND, systems::DAMAGE_USAGE);
+
+        app.add_systems(Startup, ui::spawn_inspector_panel);
+        app.add_systems(
+            Update,
+            (
+                systems::toggle_inspector.run_if(in_input_context(HOTKEYS)),
+                systems::pick_entity
+                    .run_if(systems::inspector_enabled)
+                    .run_if(in_input_context(GAMEPLAY)),
+            )
+                .chain()
+                .in_set(AppSet::Input),
+        );
+        app.add_systems(
+            Update,
+            (systems::handle_damage_command, ui::update_inspector_panel)
+                .chain()
+                .after(AppSet::Input),
+        );
+        app.add_systems(PostUpdate, ui::update_inspector_label.in_set(AppSet::UiSync));
+        app.add_systems(
+            PostUpdate,
+            systems::draw_selection.run_if(systems::inspector_enabled),
+        );
+    }
+}
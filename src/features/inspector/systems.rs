@@ -0,0 +1,195 @@
+// src/features/inspector/systems.rs
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::component::{Inspector, InspectorKeybinding, PICK_REACH};
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::console::component::Console;
+use crate::features::console::systems::ConsoleCommand;
+use crate::features::health::damage::DamageEvent;
+
+pub const DAMAGE_COMMAND: &str = "damage";
+pub const DAMAGE_USAGE: &str = "damage <selected|entity> <amount>: hurt an entity (inspector, F3)";
+
+/// Update: the inspector key switches picking mode.
+pub fn toggle_inspector(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<InspectorKeybinding>,
+    mut inspector: ResMut<Inspector>,
+) {
+    if keyboard.just_pressed(binding.toggle) {
+        inspector.enabled = !inspector.enabled;
+        info!("inspector {}", if inspector.enabled { "on" } else { "off" });
+    }
+}
+
+/// Run condition: picking mode is on.
+pub fn inspector_enabled(inspector: Res<Inspector>) -> bool {
+    inspector.enabled
+}
+
+/// Update (inspector on): a click selects the nearest collider under the cursor;
+/// clicking empty space clears the selection.
+#[expect(clippy::type_complexity)]
+pub fn pick_entity(
+    mouse: Res<ButtonInput<MouseButton>>,
+    binding: Res<InspectorKeybinding>,
+    collision: CollisionWorld,
+    mut inspector: ResMut<Inspector>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+) {
+    if !mouse.just_pressed(binding.select) {
+        return;
+    }
+    let Some(cursor) = q_window.iter().next().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Some((camera, transform)) = q_camera.iter().find(|(camera, _)| camera.is_active) else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(transform, cursor) else {
+        return;
+    };
+    let picked = collision.ray_cast(ray.origin, *ray.direction, PICK_REACH, &[]).map(|hit| hit.entity);
+    if picked != inspector.selected {
+        inspector.selected = picked;
+    }
+}
+
+/// Name, id and component list of `entity`, one component per line (sorted).
+pub fn entity_report(world: &World, entity: Entity) -> Option<String> {
+    let mut components: Vec<String> = world
+        .inspect_entity(entity)
+        .ok()?
+        .map(|info| info.name().shortname().to_string())
+        .collect();
+    components.sort();
+
+    let mut report = match world.get::<Name>(entity) {
+        Some(name) => format!("{name} ({entity})"),
+        None => entity.to_string(),
+    };
+    if let Some(tr) = world.get::<Transform>(entity) {
+        let p = tr.translation;
+        report.push_str(&format!("\nat ({:.1}, {:.1}, {:.1})", p.x, p.y, p.z));
+    }
+    for component in components {
+        report.push_str("\n  ");
+        report.push_str(&component);
+    }
+    Some(report)
+}
+
+/// Update: `damage <target> <amount>` hurts the target through `DamageEvent`.
+pub fn handle_damage_command(
+    mut commands: MessageReader<ConsoleCommand>,
+    inspector: Res<Inspector>,
+    mut console: ResMut<Console>,
+    mut damage: MessageWriter<DamageEvent>,
+) {
+    for command in commands.read().filter(|c| c.name == DAMAGE_COMMAND) {
+        let [target, amount] = command.args.as_slice() else {
+            console.print(DAMAGE_USAGE);
+            continue;
+        };
+        let target = match inspector.resolve(target) {
+            Ok(target) => target,
+            Err(err) => {
+                console.print(err);
+                continue;
+            }
+        };
+        let Ok(amount) = amount.parse::<f32>() else {
+            console.print(format!("not an amount: `{amount}`"));
+            continue;
+        };
+        damage.write(DamageEvent::new(target, amount));
+        console.print(format!("{amount} damage to {target}"));
+    }
+}
+
+/// PostUpdate (inspector on): outline the selection's collider.
+pub fn draw_selection(
+    mut gizmos: Gizmos,
+    inspector: Res<Inspector>,
+    q_selected: Query<(&GlobalTransform, Option<&Collider>)>,
+) {
+    let Some((tr, collider)) = inspector.selected.and_then(|e| q_selected.get(e).ok()) else {
+        return;
+    };
+    let color = Color::srgb(0.3, 0.9, 1.0);
+    match collider {
+        Some(collider) => {
+            gizmos.cube(
+                Transform::from_translation(tr.translation()).with_scale(collider.half_extents * 2.0),
+                color,
+            );
+        }
+        None => {
+            gizmos.sphere(Isometry3d::from_translation(tr.translation()), 0.25, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Inspector>();
+        world.init_resource::<Console>();
+        world.init_resource::<Messages<ConsoleCommand>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world
+    }
+
+    fn run(world: &mut World, line: &str) -> Vec<DamageEvent> {
+        world.write_message(ConsoleCommand::parse(line).unwrap());
+        let _ = world.run_system_once(handle_damage_command);
+        // Each one-shot run has a fresh reader; don't let it see this command again.
+        world.resource_mut::<Messages<ConsoleCommand>>().clear();
+        world.resource_mut::<Messages<DamageEvent>>().drain().collect()
+    }
+
+    #[test]
+    fn damage_selected_hurts_the_selection() {
+        let mut world = setup_world();
+        assert!(run(&mut world, "damage selected 10").is_empty());
+        assert_eq!(world.resource::<Console>().lines.back().unwrap(), "nothing selected");
+
+        let target = world.spawn_empty().id();
+        world.resource_mut::<Inspector>().selected = Some(target);
+        assert_eq!(run(&mut world, "damage selected 10"), [DamageEvent::new(target, 10.0)]);
+        assert!(run(&mut world, "damage selected lots").is_empty());
+    }
+
+    #[test]
+    fn damage_takes_entity_ids_as_printed() {
+        let mut world = setup_world();
+        let target = world.spawn_empty().id();
+        let sent = run(&mut world, &format!("damage {target} 3"));
+        assert_eq!(sent, [DamageEvent::new(target, 3.0)]);
+        assert!(run(&mut world, "damage crate 3").is_empty());
+    }
+
+    #[test]
+    fn report_lists_name_and_components() {
+        let mut world = World::new();
+        let e = world
+            .spawn((Name::new("Crate"), Transform::from_xyz(1.0, 2.0, 3.0), Collider::cuboid(Vec3::ONE)))
+            .id();
+        let report = entity_report(&world, e).unwrap();
+        assert!(report.starts_with(&format!("Crate ({e})\nat (1.0, 2.0, 3.0)")));
+        assert!(report.contains("\n  Collider"));
+        assert!(report.contains("\n  Transform"));
+
+        world.despawn(e);
+        assert_eq!(entity_report(&world, e), None);
+    }
+}
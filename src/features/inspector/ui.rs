@@ -0,0 +1,113 @@
+// src/features/inspector/ui.rs
+use bevy::prelude::*;
+
+use super::component::Inspector;
+use super::systems::entity_report;
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::collision::component::Collider;
+
+/// Inspector panel: the selection's components (hidden while the inspector is off).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct InspectorPanel;
+
+/// Name label floating over the selection.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct InspectorLabel;
+
+/// Startup: spawn the panel in the top-right corner and the (hidden) label.
+pub fn spawn_inspector_panel(mut commands: Commands) {
+    commands.spawn((
+        InspectorPanel,
+        Text::new(""),
+        TextFont::from_font_size(13.0),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(12.0),
+            top: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.8)),
+        Pickable::IGNORE,
+        Visibility::Hidden,
+        Name::new("InspectorPanel"),
+    ));
+    commands.spawn((
+        InspectorLabel,
+        Text::new(""),
+        TextFont::from_font_size(14.0),
+        TextColor(Color::srgb(0.3, 0.9, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        Pickable::IGNORE,
+        Visibility::Hidden,
+        Name::new("InspectorLabel"),
+    ));
+}
+
+/// Update (exclusive): drop a despawned selection, then show the selection's
+/// report in the panel.
+pub fn update_inspector_panel(world: &mut World) {
+    let inspector = *world.resource::<Inspector>();
+    if let Some(selected) = inspector.selected
+        && world.get_entity(selected).is_err()
+    {
+        world.resource_mut::<Inspector>().selected = None;
+    }
+    let report = inspector.enabled.then(|| {
+        inspector
+            .selected
+            .and_then(|e| entity_report(world, e))
+            .unwrap_or_else(|| "Inspector: click an entity to select it".to_owned())
+    });
+
+    let mut q_panel = world.query_filtered::<(&mut Text, &mut Visibility), With<InspectorPanel>>();
+    for (mut text, mut vis) in q_panel.iter_mut(world) {
+        let shown = if report.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        vis.set_if_neq(shown);
+        if let Some(report) = &report
+            && text.0 != *report
+        {
+            text.0.clone_from(report);
+        }
+    }
+}
+
+/// Update (after the panel): keep the label over the selection's collider, showing
+/// its `Name` (or id).
+#[expect(clippy::type_complexity)]
+pub fn update_inspector_label(
+    inspector: Res<Inspector>,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_selected: Query<(&GlobalTransform, Option<&Collider>, Option<&Name>)>,
+    mut q_label: Query<(&mut Text, &mut Node, &mut Visibility), With<InspectorLabel>>,
+) {
+    let placed = inspector.selected.filter(|_| inspector.enabled).and_then(|e| {
+        let (camera, camera_tr) = q_camera.iter().find(|(camera, _)| camera.is_active)?;
+        let (tr, collider, name) = q_selected.get(e).ok()?;
+        let half_height = collider.map_or(0.5, |c| c.half_extents.y);
+        let top = tr.translation() + Vec3::Y * (half_height + 0.3);
+        let screen = camera.world_to_viewport(camera_tr, top).ok()?;
+        let label = name.map_or_else(|| e.to_string(), |name| format!("{name} ({e})"));
+        Some((screen, label))
+    });
+
+    for (mut text, mut node, mut vis) in &mut q_label {
+        let Some((screen, label)) = &placed else {
+            vis.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        if text.0 != *label {
+            text.0.clone_from(label);
+        }
+        node.left = Val::Px(screen.x);
+        node.top = Val::Px(screen.y - 18.0);
+        vis.set_if_neq(Visibility::Inherited);
+    }
+}
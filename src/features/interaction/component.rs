@@ -0,0 +1,48 @@
+// src/features/interaction/component.rs
+use bevy::prelude::*;
+
+/// Something the player can interact with (talk, open, use).
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Interactable {
+    /// Prompt verb + name shown while focused ("Talk to Mara").
+    pub prompt: String,
+    /// Max distance (world units) from the player.
+    pub reach: f32,
+}
+
+impl Interactable {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            reach: 2.5,
+        }
+    }
+}
+
+/// Marks an interactable that currently can't be used (busy NPC, locked door).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct InteractionDisabled;
+
+/// What the player would interact with if they pressed the key now.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InteractionFocus {
+    pub actor: Option<Entity>,
+    pub target: Option<Entity>,
+}
+
+/// Interaction key.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct InteractKeybinding {
+    pub key: KeyCode,
+    pub gamepad: GamepadButton,
+}
+
+impl Default for InteractKeybinding {
+    fn default() -> Self {
+        Self {
+            // `E` / `F` are ability slots, `G` is gather.
+            key: KeyCode::KeyH,
+            gamepad: GamepadButton::North,
+        }
+    }
+}
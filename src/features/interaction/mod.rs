@@ -0,0 +1,42 @@
+// src/features/interaction/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+
+pub mod component;
+pub mod systems;
+
+/// Generic "press to interact" plumbing.
+///
+/// Scope:
+/// - `Interactable` entities; the nearest enabled one in reach of the player is the
+///   `InteractionFocus` and gets an on-screen prompt
+/// - interact key -> `Interacted { actor, target }`
+///
+/// What an interaction *does* is up to the feature owning the target (dialogue,
+/// shops, doors), which reacts to `Interacted`.
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::InteractionFocus>();
+        app.init_resource::<component::InteractKeybinding>();
+
+        app.add_message::<systems::Interacted>();
+
+        app.add_systems(Startup, systems::spawn_interaction_prompt);
+        app.add_systems(
+            Update,
+            (
+                systems::update_interaction_focus,
+                systems::read_interact_input.run_if(in_input_context(GAMEPLAY)),
+            )
+                .chain()
+                .in_set(AppSet::Input),
+        );
+        app.add_systems(
+            Update,
+            systems::update_interaction_prompt.after(AppSet::Input),
+        );
+    }
+}
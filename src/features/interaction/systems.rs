@@ -0,0 +1,149 @@
+// src/features/interaction/systems.rs
+use bevy::prelude::*;
+
+use super::component::{InteractKeybinding, Interactable, InteractionDisabled, InteractionFocus};
+use crate::features::hud::glyphs::key_prompt;
+use crate::features::player::death::AlivePlayer;
+
+/// The actor used an interactable. Owning features react (dialogue, doors, shops).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interacted {
+    pub actor: Entity,
+    pub target: Entity,
+}
+
+/// Bottom-center "[H] Talk to Mara" prompt.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct InteractionPrompt;
+
+/// Update: focus the nearest enabled interactable in reach of the player.
+pub fn update_interaction_focus(
+    q_player: Query<(Entity, &Transform), AlivePlayer>,
+    q_targets: Query<(Entity, &Interactable, &GlobalTransform), Without<InteractionDisabled>>,
+    mut focus: ResMut<InteractionFocus>,
+) {
+    let next = q_player.iter().next().map_or_else(InteractionFocus::default, |(actor, tr)| {
+        let target = q_targets
+            .iter()
+            .map(|(e, i, gt)| (e, i, gt.translation().distance(tr.translation)))
+            .filter(|(_, i, d)| *d <= i.reach)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(e, ..)| e);
+        InteractionFocus {
+            actor: Some(actor),
+            target,
+        }
+    });
+    if *focus != next {
+        *focus = next;
+    }
+}
+
+/// Update: interact key -> `Interacted` on the focused target.
+pub fn read_interact_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<InteractKeybinding>,
+    q_gamepads: Query<&Gamepad>,
+    focus: Res<InteractionFocus>,
+    mut interacted: MessageWriter<Interacted>,
+) {
+    let pressed = keyboard.just_pressed(binding.key)
+        || q_gamepads.iter().any(|g| g.just_pressed(binding.gamepad));
+    if !pressed {
+        return;
+    }
+    if let (Some(actor), Some(target)) = (focus.actor, focus.target) {
+        interacted.write(Interacted { actor, target });
+    }
+}
+
+/// Startup: spawn the prompt label (hidden until something is focused).
+pub fn spawn_interaction_prompt(mut commands: Commands) {
+    commands.spawn((
+        InteractionPrompt,
+        Text::new(""),
+        TextFont::from_font_size(20.0),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(22.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Visibility::Hidden,
+        Name::new("InteractionPrompt"),
+    ));
+}
+
+/// Update: show the focused target's prompt.
+pub fn update_interaction_prompt(
+    focus: Res<InteractionFocus>,
+    binding: Res<InteractKeybinding>,
+    q_targets: Query<&Interactable>,
+    mut q_prompt: Query<(&mut Text, &mut Visibility), With<InteractionPrompt>>,
+) {
+    let prompt = focus.target.and_then(|t| q_targets.get(t).ok());
+    for (mut text, mut vis) in &mut q_prompt {
+        match prompt {
+            Some(interactable) => {
+                let label = format!("{} {}", key_prompt(binding.key), interactable.prompt);
+                if text.0 != label {
+                    text.0 = label;
+                }
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::player::component::Player;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn nearest_enabled_target_in_reach_is_focused_and_used() {
+        let mut world = World::new();
+        world.init_resource::<InteractionFocus>();
+        world.insert_resource(InteractKeybinding::default());
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.init_resource::<Messages<Interacted>>();
+
+        let player = world.spawn((Player, Transform::default())).id();
+        let spawn_target = |world: &mut World, x: f32| {
+            world
+                .spawn((
+                    Interactable::new("Use"),
+                    GlobalTransform::from_translation(Vec3::X * x),
+                ))
+                .id()
+        };
+        let near = spawn_target(&mut world, 1.0);
+        let disabled = spawn_target(&mut world, 0.5);
+        world.entity_mut(disabled).insert(InteractionDisabled);
+        spawn_target(&mut world, 2.0);
+        spawn_target(&mut world, 10.0);
+
+        let _ = world.run_system_once(update_interaction_focus);
+        assert_eq!(
+            *world.resource::<InteractionFocus>(),
+            InteractionFocus {
+                actor: Some(player),
+                target: Some(near)
+            }
+        );
+
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyH);
+        let _ = world.run_system_once(read_interact_input);
+        let sent: Vec<_> = world
+            .resource::<Messages<Interacted>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(sent, vec![Interacted { actor: player, target: near }]);
+    }
+}
@@ -0,0 +1,94 @@
+// src/features/inventory/component.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+/// Stackable item storage keyed by item id (e.g. `"wood"`, `"stone"`).
+///
+/// Notes:
+/// - `BTreeMap` keeps iteration order stable (UI lists, saves, tests).
+/// - Counts of zero are never stored.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    items: BTreeMap<String, u32>,
+}
+
+impl Inventory {
+    pub fn count(&self, item: &str) -> u32 {
+        self.items.get(item).copied().unwrap_or(0)
+    }
+
+    pub fn add(&mut self, item: &str, amount: u32) {
+        if amount == 0 {
+            return;
+        }
+        *self.items.entry(item.to_string()).or_insert(0) += amount;
+    }
+
+    /// Remove `amount` of `item`. All-or-nothing: returns `false` and changes nothing
+    /// if there isn't enough.
+    pub fn remove(&mut self, item: &str, amount: u32) -> bool {
+        self.remove_all(&[(item, amount)])
+    }
+
+    /// `true` if every `(item, amount)` requirement is covered.
+    pub fn has_all(&self, costs: &[(&str, u32)]) -> bool {
+        costs.iter().all(|(item, amount)| self.count(item) >= *amount)
+    }
+
+    /// Remove a whole cost list atomically (build costs, recipes, trades).
+    pub fn remove_all(&mut self, costs: &[(&str, u32)]) -> bool {
+        if !self.has_all(costs) {
+            return false;
+        }
+
+        for (item, amount) in costs {
+            if let Some(count) = self.items.get_mut(*item) {
+                *count -= amount;
+                if *count == 0 {
+                    self.items.remove(*item);
+                }
+            }
+        }
+        true
+    }
+
+    /// `(item, count)` pairs in stable order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.items.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_count() {
+        let mut inv = Inventory::default();
+        inv.add("wood", 3);
+        inv.add("wood", 2);
+        inv.add("stone", 0);
+
+        assert_eq!(inv.count("wood"), 5);
+        assert_eq!(inv.count("stone"), 0);
+        assert_eq!(inv.iter().collect::<Vec<_>>(), vec![("wood", 5)]);
+    }
+
+    #[test]
+    fn remove_is_all_or_nothing() {
+        let mut inv = Inventory::default();
+        inv.add("wood", 3);
+        inv.add("stone", 1);
+
+        assert!(!inv.remove_all(&[("wood", 2), ("stone", 2)]));
+        assert_eq!(inv.count("wood"), 3, "failed removal must not change counts");
+
+        assert!(inv.remove_all(&[("wood", 3), ("stone", 1)]));
+        assert!(inv.is_empty(), "zero counts are dropped");
+    }
+}
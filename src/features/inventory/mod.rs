@@ -0,0 +1,46 @@
+// src/features/inventory/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::player::component::Player;
+
+pub mod component;
+
+use component::Inventory;
+
+/// Emitted whenever items are granted to an inventory (pickup toasts, audio, quests).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ItemsAdded {
+    pub entity: Entity,
+    pub item: String,
+    pub amount: u32,
+}
+
+/// Inventory feature plugin.
+///
+/// Scope:
+/// - `Inventory` component (item id -> count)
+/// - every new `Player` gets an empty inventory
+/// - `ItemsAdded` message for UI / audio hooks (written by item sources)
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ItemsAdded>();
+
+        app.add_systems(
+            FixedUpdate,
+            grant_player_inventory.in_set(AppSet::FixedGameplay),
+        );
+    }
+}
+
+/// Every player carries an inventory.
+pub fn grant_player_inventory(
+    mut commands: Commands,
+    q_new_players: Query<Entity, (Added<Player>, Without<Inventory>)>,
+) {
+    for entity in &q_new_players {
+        commands.entity(entity).insert(Inventory::default());
+    }
+}
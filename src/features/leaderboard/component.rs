@@ -0,0 +1,198 @@
+// src/features/leaderboard/component.rs
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{Difficulty, StoragePaths};
+
+/// Game version recorded with every run.
+pub const GAME_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// One finished run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunResult {
+    /// Game mode the run was played in (e.g. `"survival"`).
+    pub mode: String,
+    pub score: u64,
+    /// Survival: last wave reached.
+    #[serde(default)]
+    pub wave_reached: Option<u32>,
+    /// Timed modes: seconds to completion.
+    #[serde(default)]
+    pub completion_secs: Option<f32>,
+    /// World seed, so the run can be replayed.
+    pub seed: u64,
+    /// Difficulty preset the run was played on (older entries: `Normal`).
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    pub version: String,
+    /// Unix seconds when the run ended.
+    pub finished_at: u64,
+}
+
+/// All recorded runs, in the order they were played.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub runs: Vec<RunResult>,
+}
+
+impl Leaderboard {
+    /// Runs of `mode`, best first: higher score, then faster completion, then earlier.
+    pub fn ranked(&self, mode: &str) -> Vec<&RunResult> {
+        let mut runs: Vec<_> = self.runs.iter().filter(|r| r.mode == mode).collect();
+        runs.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| {
+                    let a = a.completion_secs.unwrap_or(f32::INFINITY);
+                    let b = b.completion_secs.unwrap_or(f32::INFINITY);
+                    a.total_cmp(&b)
+                })
+                .then_with(|| a.finished_at.cmp(&b.finished_at))
+        });
+        runs
+    }
+
+    /// The `n` best runs of `mode` (results screen).
+    pub fn top(&self, mode: &str, n: usize) -> Vec<&RunResult> {
+        let mut runs = self.ranked(mode);
+        runs.truncate(n);
+        runs
+    }
+
+    /// 1-based rank `run` would have in its mode.
+    pub fn rank_of(&self, run: &RunResult) -> usize {
+        self.ranked(&run.mode)
+            .iter()
+            .position(|r| *r == run)
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Stored runs, or none yet. An unreadable file is moved aside (`<file>.bak`)
+    /// first, so the next recorded run doesn't overwrite the old results.
+    pub fn load(path: &Path) -> Self {
+        let Ok(source) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&source).unwrap_or_else(|err| {
+            let backup = backup_path(path);
+            match std::fs::rename(path, &backup) {
+                Ok(()) => warn!(
+                    "unreadable {}: {err}; moved it to {}",
+                    path.display(),
+                    backup.display()
+                ),
+                Err(move_err) => warn!(
+                    "unreadable {}: {err}; could not move it aside: {move_err}",
+                    path.display()
+                ),
+            }
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// `path` with `.bak` appended (`leaderboard.json.bak`).
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
+/// Where the leaderboard JSON lives.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardFile(pub PathBuf);
+
+impl FromWorld for LeaderboardFile {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).data_file("leaderboard.json"))
+    }
+}
+
+/// Seed of the current run (world generation, spawns); recorded with the result.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunSeed(pub u64);
+
+impl Default for RunSeed {
+    fn default() -> Self {
+        Self(unix_secs())
+    }
+}
+
+pub fn unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(mode: &str, score: u64, secs: Option<f32>, at: u64) -> RunResult {
+        RunResult {
+            mode: mode.into(),
+            score,
+            wave_reached: None,
+            completion_secs: secs,
+            seed: 1,
+            difficulty: Difficulty::Normal,
+            version: GAME_VERSION.into(),
+            finished_at: at,
+        }
+    }
+
+    #[test]
+    fn ranking_prefers_score_then_time_and_filters_mode() {
+        let board = Leaderboard {
+            runs: vec![
+                run("survival", 10, None, 1),
+                run("survival", 30, Some(90.0), 2),
+                run("survival", 30, Some(60.0), 3),
+                run("race", 99, None, 4),
+            ],
+        };
+        let ranked: Vec<_> = board.ranked("survival").iter().map(|r| r.finished_at).collect();
+        assert_eq!(ranked, [3, 2, 1]);
+        assert_eq!(board.top("survival", 1).len(), 1);
+        assert_eq!(board.rank_of(&board.runs[0]), 3);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_lb_{}", std::process::id()))
+            .join("leaderboard.json");
+        let board = Leaderboard {
+            runs: vec![run("survival", 5, Some(12.5), 7)],
+        };
+        board.save(&path).unwrap();
+        assert_eq!(Leaderboard::load(&path), board);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn unreadable_files_are_moved_aside() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_lb_bad_{}", std::process::id()));
+        let path = dir.join("leaderboard.json");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "{ not json").unwrap();
+
+        assert_eq!(Leaderboard::load(&path), Leaderboard::default());
+        assert!(!path.exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("leaderboard.json.bak")).unwrap(),
+            "{ not json"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,171 @@
+// src/features/leaderboard/ghost.rs
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::app::StoragePaths;
+
+/// The player's pose on one recorded tick.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GhostFrame {
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+}
+
+impl GhostFrame {
+    pub fn from_transform(transform: &Transform) -> Self {
+        Self {
+            translation: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+        }
+    }
+
+    pub fn to_transform(self) -> Transform {
+        Transform::from_translation(Vec3::from_array(self.translation))
+            .with_rotation(Quat::from_array(self.rotation))
+    }
+}
+
+/// A recorded run: one frame per simulation tick from the start.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GhostRun {
+    pub completion_secs: f32,
+    /// Tick rate the frames were recorded at.
+    pub tick_hz: f64,
+    /// Ticks from the start to each race checkpoint.
+    #[serde(default)]
+    pub splits: Vec<u64>,
+    pub frames: Vec<GhostFrame>,
+}
+
+impl GhostRun {
+    /// Pose `secs` into the run; held at the last frame once the run is over.
+    pub fn frame_at(&self, secs: f32) -> Option<GhostFrame> {
+        let index = (secs.max(0.0) as f64 * self.tick_hz).floor() as usize;
+        self.frames.get(index.min(self.frames.len().checked_sub(1)?)).copied()
+    }
+}
+
+/// Best recorded run per game mode (time trial ghosts).
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ghosts {
+    pub best: BTreeMap<String, GhostRun>,
+}
+
+impl Ghosts {
+    pub fn best(&self, mode: &str) -> Option<&GhostRun> {
+        self.best.get(mode)
+    }
+
+    /// Keep `run` if it beats `mode`'s best; returns whether it did.
+    pub fn offer(&mut self, mode: &str, run: GhostRun) -> bool {
+        if self
+            .best
+            .get(mode)
+            .is_some_and(|best| best.completion_secs <= run.completion_secs)
+        {
+            return false;
+        }
+        self.best.insert(mode.to_string(), run);
+        true
+    }
+
+    pub fn load(path: &Path) -> Self {
+        let Ok(source) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&source).unwrap_or_else(|err| {
+            warn!("ignoring unreadable {}: {err}", path.display());
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Where the ghosts JSON lives (next to the leaderboard).
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct GhostFile(pub PathBuf);
+
+impl FromWorld for GhostFile {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).data_file("ghosts.json"))
+    }
+}
+
+/// A run of `mode` finished with this recording (written by the game mode).
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct GhostRecorded {
+    pub mode: String,
+    pub run: GhostRun,
+}
+
+/// Startup: read the ghosts file.
+pub fn load_ghosts(file: Res<GhostFile>, mut ghosts: ResMut<Ghosts>) {
+    *ghosts = Ghosts::load(&file.0);
+}
+
+/// Update: keep recordings that beat their mode's best and save them.
+pub fn record_ghosts(
+    file: Res<GhostFile>,
+    mut ghosts: ResMut<Ghosts>,
+    mut recorded: MessageReader<GhostRecorded>,
+) {
+    let mut any = false;
+    for ghost in recorded.read() {
+        any |= ghosts.offer(&ghost.mode, ghost.run.clone());
+    }
+    if any && let Err(err) = ghosts.save(&file.0) {
+        warn!("could not save {}: {err}", file.0.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ghost(secs: f32, frames: usize) -> GhostRun {
+        GhostRun {
+            completion_secs: secs,
+            tick_hz: 60.0,
+            splits: Vec::new(),
+            frames: (0..frames)
+                .map(|i| GhostFrame::from_transform(&Transform::from_xyz(i as f32, 0.0, 0.0)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn only_faster_runs_replace_the_best_and_survive_a_reload() {
+        let mut ghosts = Ghosts::default();
+        assert!(ghosts.offer("time_trial", ghost(12.0, 3)));
+        assert!(!ghosts.offer("time_trial", ghost(13.0, 3)));
+        assert!(ghosts.offer("time_trial", ghost(11.5, 4)));
+        assert_eq!(ghosts.best("time_trial").unwrap().completion_secs, 11.5);
+
+        let path = std::env::temp_dir()
+            .join(format!("ghosts-test-{}", std::process::id()))
+            .join("ghosts.json");
+        ghosts.save(&path).unwrap();
+        assert_eq!(Ghosts::load(&path), ghosts);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn frames_are_sampled_by_time_and_held_at_the_end() {
+        let run = ghost(1.0, 4);
+        let x = |secs| run.frame_at(secs).unwrap().translation[0];
+        assert_eq!(x(0.0), 0.0);
+        assert_eq!(x(2.5 / 60.0), 2.0);
+        assert_eq!(x(10.0), 3.0);
+        assert_eq!(ghost(1.0, 0).frame_at(0.0), None);
+    }
+}
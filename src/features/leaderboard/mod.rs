@@ -0,0 +1,38 @@
+// src/features/leaderboard/mod.rs
+use bevy::prelude::*;
+
+pub mod component;
+pub mod ghost;
+pub mod systems;
+
+/// Local leaderboard.
+///
+/// Scope:
+/// - `RunFinished` in (game modes): score, wave reached / completion time
+/// - results are stamped with the `RunSeed`, difficulty and game version for reproducibility
+///   and appended to a JSON file (`LeaderboardFile`, `leaderboard.json` in the data directory)
+/// - read API for results screens: `Leaderboard::ranked` / `top` per mode;
+///   `RunRecorded` tells the new run's rank
+/// - ghosts: the best recorded run per mode (`GhostRecorded` in, faster ones kept),
+///   saved next to the leaderboard (`ghosts.json`) for modes to replay
+///
+/// Design constraints:
+/// - JSON (not RON) so the file is easy to consume outside the game.
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::Leaderboard>();
+        app.init_resource::<component::LeaderboardFile>();
+        app.init_resource::<component::RunSeed>();
+        app.init_resource::<ghost::Ghosts>();
+        app.init_resource::<ghost::GhostFile>();
+
+        app.add_message::<systems::RunFinished>();
+        app.add_message::<systems::RunRecorded>();
+        app.add_message::<ghost::GhostRecorded>();
+
+        app.add_systems(Startup, (systems::load_leaderboard, ghost::load_ghosts));
+        app.add_systems(Update, (systems::record_finished_runs, ghost::record_ghosts));
+    }
+}
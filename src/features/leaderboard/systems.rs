@@ -0,0 +1,99 @@
+// src/features/leaderboard/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    GAME_VERSION, Leaderboard, LeaderboardFile, RunResult, RunSeed, unix_secs,
+};
+use crate::app::GameplayTuning;
+
+/// A run ended (written by the game mode).
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct RunFinished {
+    pub mode: String,
+    pub score: u64,
+    pub wave_reached: Option<u32>,
+    pub completion_secs: Option<f32>,
+}
+
+/// The finished run was recorded; `rank` is 1-based within its mode.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct RunRecorded {
+    pub result: RunResult,
+    pub rank: usize,
+}
+
+/// Startup: read the leaderboard file.
+pub fn load_leaderboard(file: Res<LeaderboardFile>, mut board: ResMut<Leaderboard>) {
+    *board = Leaderboard::load(&file.0);
+}
+
+/// Update: stamp finished runs with seed / difficulty / version / time, append and save.
+pub fn record_finished_runs(
+    file: Res<LeaderboardFile>,
+    seed: Res<RunSeed>,
+    tuning: Res<GameplayTuning>,
+    mut board: ResMut<Leaderboard>,
+    mut finished: MessageReader<RunFinished>,
+    mut recorded: MessageWriter<RunRecorded>,
+) {
+    let mut any = false;
+    for run in finished.read() {
+        let result = RunResult {
+            mode: run.mode.clone(),
+            score: run.score,
+            wave_reached: run.wave_reached,
+            completion_secs: run.completion_secs,
+            seed: seed.0,
+            difficulty: tuning.difficulty,
+            version: GAME_VERSION.to_string(),
+            finished_at: unix_secs(),
+        };
+        board.runs.push(result.clone());
+        let rank = board.rank_of(&result);
+        recorded.write(RunRecorded { result, rank });
+        any = true;
+    }
+    if any && let Err(err) = board.save(&file.0) {
+        warn!("could not save {}: {err}", file.0.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn finished_runs_are_stamped_ranked_and_saved() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_runs_{}", std::process::id()));
+        let mut world = World::new();
+        world.insert_resource(LeaderboardFile(dir.join("leaderboard.json")));
+        world.insert_resource(RunSeed(42));
+        world.insert_resource(GameplayTuning::for_difficulty(crate::app::Difficulty::Hard));
+        world.init_resource::<Leaderboard>();
+        world.init_resource::<Messages<RunFinished>>();
+        world.init_resource::<Messages<RunRecorded>>();
+        for score in [10, 20] {
+            world.write_message(RunFinished {
+                mode: "survival".into(),
+                score,
+                wave_reached: Some(3),
+                completion_secs: None,
+            });
+        }
+
+        let _ = world.run_system_once(record_finished_runs);
+
+        let ranks: Vec<_> = world
+            .resource::<Messages<RunRecorded>>()
+            .iter_current_update_messages()
+            .map(|r| (r.result.seed, r.rank))
+            .collect();
+        assert_eq!(ranks, [(42, 1), (42, 1)]);
+        let saved = Leaderboard::load(&dir.join("leaderboard.json"));
+        assert_eq!(saved.runs.len(), 2);
+        assert!(saved.runs.iter().all(|r| r.difficulty == crate::app::Difficulty::Hard));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,108 @@
+// src/features/ledge/component.rs
+use bevy::prelude::*;
+
+/// Ledge grab tunables (world units / seconds).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct LedgeSettings {
+    /// How far past the body's front face the wall probe reaches.
+    pub reach: f32,
+    /// How far above the body's top a ledge can still be caught.
+    pub grab_above: f32,
+    /// Gap kept between the hanging body and the wall.
+    pub hang_gap: f32,
+    /// Duration of the mantle (pull-up) move.
+    pub mantle_secs: f32,
+    /// Intent needed to climb / drop (local `MoveInput` component).
+    pub input_threshold: f32,
+}
+
+impl Default for LedgeSettings {
+    fn default() -> Self {
+        Self {
+            reach: 0.4,
+            grab_above: 0.5,
+            hang_gap: 0.05,
+            mantle_secs: 0.45,
+            input_threshold: 0.5,
+        }
+    }
+}
+
+/// Can catch ledges while airborne (players get one).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LedgeGrabber;
+
+/// A caught ledge: the top edge point and the wall's outward normal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ledge {
+    pub point: Vec3,
+    pub normal: Vec3,
+}
+
+/// Hanging from `ledge` (movement locked until climbing up or dropping).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LedgeHang {
+    pub ledge: Ledge,
+    /// Movement intent went neutral since the grab: the key held while jumping at
+    /// the wall doesn't climb straight away.
+    pub released: bool,
+}
+
+/// Scripted pull-up from `from` to `to` (standing on the ledge).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Mantle {
+    pub from: Vec3,
+    pub to: Vec3,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl Mantle {
+    pub fn fraction(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Body position at the current progress: the first 60% pulls up, the rest
+    /// steps forward onto the ledge (so the body never clips the ledge's corner).
+    pub fn position(&self) -> Vec3 {
+        const LIFT: f32 = 0.6;
+        let t = self.fraction();
+        let up = (t / LIFT).min(1.0);
+        let forward = ((t - LIFT) / (1.0 - LIFT)).clamp(0.0, 1.0);
+        let horizontal = self.from.lerp(self.to, forward);
+        Vec3::new(horizontal.x, self.from.y + (self.to.y - self.from.y) * up, horizontal.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mantle_lifts_before_stepping_forward() {
+        let mut mantle = Mantle {
+            from: Vec3::ZERO,
+            to: Vec3::new(0.0, 2.0, -1.0),
+            elapsed: 0.0,
+            duration: 1.0,
+        };
+        assert_eq!(mantle.position(), Vec3::ZERO);
+
+        mantle.elapsed = 0.3;
+        let halfway_up = mantle.position();
+        assert!((halfway_up.y - 1.0).abs() < 1e-5);
+        assert_eq!(halfway_up.z, 0.0, "no forward step while lifting");
+
+        mantle.elapsed = 0.8;
+        let stepping = mantle.position();
+        assert_eq!(stepping.y, 2.0);
+        assert!((stepping.z + 0.5).abs() < 1e-5);
+
+        mantle.elapsed = 2.0;
+        assert_eq!(mantle.position(), mantle.to);
+    }
+}
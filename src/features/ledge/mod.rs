@@ -0,0 +1,47 @@
+// src/features/ledge/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::fall_damage::systems::track_falls;
+use crate::features::player::death::PlayerDied;
+
+pub mod component;
+pub mod systems;
+
+/// Ledge grab and mantle.
+///
+/// Scope:
+/// - `LedgeGrabber` bodies (players) that are airborne catch a ledge in front of
+///   them: a forward wall probe plus a downward probe onto the wall's top
+/// - hanging (`LedgeHang`) locks movement; forward / up intent pulls the body up
+///   (`Mantle`, a short scripted move), back / down lets go
+/// - `LedgeGrabbed` / `Mantled` for animation and audio
+///
+/// Design constraints:
+/// - "Airborne" is the fall tracker's view (`FallTracker`), so grabbing a ledge
+///   and landing can never disagree about whether there's ground below.
+pub struct LedgePlugin;
+
+impl Plugin for LedgePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::LedgeSettings>();
+
+        app.add_message::<systems::LedgeGrabbed>();
+        app.add_message::<systems::Mantled>();
+        app.add_message::<PlayerDied>();
+
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_ledge_grabbers,
+                systems::release_ledges_on_death,
+                systems::grab_ledges,
+                systems::hang_on_ledges,
+                systems::run_mantles,
+            )
+                .chain()
+                .after(track_falls)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
@@ -0,0 +1,299 @@
+// src/features/ledge/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Ledge, LedgeGrabber, LedgeHang, LedgeSettings, Mantle};
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::fall_damage::component::FallTracker;
+use crate::features::movement::component::{ExternalForce, MoveInput, MovementLocked, Velocity};
+use crate::features::player::component::Player;
+use crate::features::player::death::PlayerDied;
+
+/// Half extents assumed for movers without a `Collider` (the player box).
+const DEFAULT_HALF_EXTENTS: Vec3 = Vec3::splat(0.5);
+
+/// Walls are steeper than this (`|normal.y|` below it); ledge tops are flatter
+/// (`normal.y` above `1 - MAX_WALL_SLOPE`).
+const MAX_WALL_SLOPE: f32 = 0.3;
+
+/// A body caught a ledge and now hangs from it.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct LedgeGrabbed {
+    pub entity: Entity,
+    pub ledge: Ledge,
+}
+
+/// A body finished pulling itself up onto a ledge.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mantled {
+    pub entity: Entity,
+}
+
+/// Ledge in front of a body at `position` facing `forward`, if any.
+///
+/// Two probes: forward from the body's center must hit a wall within `reach`;
+/// then down from `grab_above` over the body's top, just behind that wall's face,
+/// must land on a flat top. A wall taller than that (probe starts inside it) or
+/// nothing to stand on means no ledge.
+pub fn find_ledge(
+    collision: &CollisionWorld,
+    entity: Entity,
+    position: Vec3,
+    forward: Vec3,
+    half_extents: Vec3,
+    settings: &LedgeSettings,
+) -> Option<Ledge> {
+    let forward = forward.with_y(0.0).try_normalize()?;
+    let depth = half_extents.x.max(half_extents.z);
+
+    let wall = collision.ray_cast(position, forward, depth + settings.reach, &[entity])?;
+    if wall.normal.y.abs() > MAX_WALL_SLOPE {
+        return None;
+    }
+    let face = position + forward * wall.distance;
+
+    let drop = half_extents.y + settings.grab_above;
+    let origin = face.with_y(position.y + drop) + forward * settings.hang_gap.max(0.01);
+    let top = collision.ray_cast(origin, Vec3::NEG_Y, drop, &[entity])?;
+    if top.normal.y < 1.0 - MAX_WALL_SLOPE {
+        return None;
+    }
+
+    Some(Ledge {
+        point: face.with_y(origin.y - top.distance),
+        normal: wall.normal,
+    })
+}
+
+/// FixedUpdate: players can grab ledges.
+pub fn grant_ledge_grabbers(
+    mut commands: Commands,
+    q_players: Query<Entity, (Added<Player>, Without<LedgeGrabber>)>,
+) {
+    for entity in &q_players {
+        commands.entity(entity).insert(LedgeGrabber);
+    }
+}
+
+/// FixedUpdate: airborne grabbers that meet a ledge catch it and hang below its edge.
+///
+/// Catching resets the fall (hanging is not a landing).
+#[expect(clippy::type_complexity)]
+pub fn grab_ledges(
+    mut commands: Commands,
+    settings: Res<LedgeSettings>,
+    collision: CollisionWorld,
+    mut q_bodies: Query<
+        (Entity, &mut Transform, Option<&Collider>, &mut FallTracker, Option<&mut Velocity>),
+        (
+            With<LedgeGrabber>,
+            Without<LedgeHang>,
+            Without<Mantle>,
+            Without<MovementLocked>,
+        ),
+    >,
+    mut grabbed: MessageWriter<LedgeGrabbed>,
+) {
+    for (entity, mut transform, collider, mut fall, velocity) in &mut q_bodies {
+        if !fall.airborne {
+            continue;
+        }
+        let half = collider.map_or(DEFAULT_HALF_EXTENTS, |c| c.half_extents);
+        let forward = transform.rotation * Vec3::NEG_Z;
+        let Some(ledge) =
+            find_ledge(&collision, entity, transform.translation, forward, half, &settings)
+        else {
+            continue;
+        };
+
+        let depth = half.x.max(half.z);
+        transform.translation =
+            ledge.point.with_y(ledge.point.y - half.y) + ledge.normal * (depth + settings.hang_gap);
+        *fall = FallTracker::default();
+        if let Some(mut velocity) = velocity {
+            velocity.0 = Vec3::ZERO;
+        }
+        commands.entity(entity).insert((
+            LedgeHang {
+                ledge,
+                released: false,
+            },
+            MovementLocked,
+            ExternalForce::default(),
+        ));
+        grabbed.write(LedgeGrabbed { entity, ledge });
+    }
+}
+
+/// FixedUpdate: hanging bodies climb (forward / up intent) or let go (back / down).
+pub fn hang_on_ledges(
+    mut commands: Commands,
+    settings: Res<LedgeSettings>,
+    mut q_hanging: Query<(Entity, &Transform, Option<&Collider>, &MoveInput, &mut LedgeHang)>,
+) {
+    let threshold = settings.input_threshold;
+    for (entity, transform, collider, input, mut hang) in &mut q_hanging {
+        let intent = input.0;
+        if !hang.released {
+            hang.released = intent.length_squared() < threshold * threshold;
+            continue;
+        }
+
+        // Local intent: -Z forward, +Y up.
+        if intent.z < -threshold || intent.y > threshold {
+            let half = collider.map_or(DEFAULT_HALF_EXTENTS, |c| c.half_extents);
+            let depth = half.x.max(half.z);
+            let ledge = hang.ledge;
+            let to = ledge.point.with_y(ledge.point.y + half.y)
+                - ledge.normal * (depth + settings.hang_gap);
+            commands.entity(entity).remove::<LedgeHang>().insert(Mantle {
+                from: transform.translation,
+                to,
+                elapsed: 0.0,
+                duration: settings.mantle_secs,
+            });
+        } else if intent.z > threshold || intent.y < -threshold {
+            commands.entity(entity).remove::<(LedgeHang, MovementLocked)>();
+        }
+    }
+}
+
+/// FixedUpdate: advance pull-ups; the body is released on top of the ledge.
+pub fn run_mantles(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q_mantling: Query<(Entity, &mut Transform, &mut Mantle)>,
+    mut mantled: MessageWriter<Mantled>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut transform, mut mantle) in &mut q_mantling {
+        mantle.elapsed += dt;
+        transform.translation = mantle.position();
+        if mantle.fraction() >= 1.0 {
+            commands.entity(entity).remove::<(Mantle, MovementLocked)>();
+            mantled.write(Mantled { entity });
+        }
+    }
+}
+
+/// FixedUpdate: dying lets go (death owns `MovementLocked` from here on).
+pub fn release_ledges_on_death(
+    mut commands: Commands,
+    mut died: MessageReader<PlayerDied>,
+) {
+    for ev in died.read() {
+        if let Ok(mut entity) = commands.get_entity(ev.entity) {
+            entity.remove::<(LedgeHang, Mantle)>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    /// A 2 m high block whose near face is at z = -1 (top at y = 2).
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<LedgeSettings>();
+        world.init_resource::<Messages<LedgeGrabbed>>();
+        world.init_resource::<Messages<Mantled>>();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(0.25));
+        world.insert_resource(time);
+        world.spawn((
+            Collider::cuboid(Vec3::new(2.0, 1.0, 1.0)),
+            GlobalTransform::from_translation(Vec3::new(0.0, 1.0, -2.0)),
+        ));
+        world
+    }
+
+    /// Airborne player facing the block (-Z) with its center at height `y`.
+    fn spawn_jumper(world: &mut World, y: f32) -> Entity {
+        world
+            .spawn((
+                LedgeGrabber,
+                Transform::from_xyz(0.0, y, -0.2),
+                MoveInput(Vec3::NEG_Z),
+                Velocity(Vec3::new(0.0, 3.0, -5.0)),
+                FallTracker {
+                    airborne: true,
+                    peak_fall_speed: 0.0,
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn jumping_at_a_ledge_hangs_below_its_edge() {
+        let mut world = setup_world();
+        let player = spawn_jumper(&mut world, 1.8);
+
+        let _ = world.run_system_once(grab_ledges);
+
+        let hang = world.get::<LedgeHang>(player).copied().unwrap();
+        assert!((hang.ledge.point - Vec3::new(0.0, 2.0, -1.0)).length() < 1e-4);
+        assert_eq!(hang.ledge.normal, Vec3::Z);
+        assert!(world.get::<MovementLocked>(player).is_some());
+        let position = world.get::<Transform>(player).unwrap().translation;
+        assert!((position.y - 1.5).abs() < 1e-4, "top of the body at the ledge");
+        assert!((position.z - (-1.0 + 0.5 + 0.05)).abs() < 1e-4);
+        assert_eq!(world.resource::<Messages<LedgeGrabbed>>().len(), 1);
+    }
+
+    #[test]
+    fn walls_too_tall_or_grounded_bodies_catch_nothing() {
+        let mut world = setup_world();
+        let low = spawn_jumper(&mut world, 0.6);
+        let grounded = spawn_jumper(&mut world, 1.8);
+        world.get_mut::<FallTracker>(grounded).unwrap().airborne = false;
+
+        let _ = world.run_system_once(grab_ledges);
+
+        assert!(world.get::<LedgeHang>(low).is_none(), "ledge out of reach");
+        assert!(world.get::<LedgeHang>(grounded).is_none());
+    }
+
+    #[test]
+    fn releasing_then_pushing_forward_mantles_onto_the_top() {
+        let mut world = setup_world();
+        let player = spawn_jumper(&mut world, 1.8);
+        let _ = world.run_system_once(grab_ledges);
+
+        // Still holding forward from the jump: nothing happens until released.
+        let _ = world.run_system_once(hang_on_ledges);
+        assert!(world.get::<LedgeHang>(player).is_some());
+        world.get_mut::<MoveInput>(player).unwrap().0 = Vec3::ZERO;
+        let _ = world.run_system_once(hang_on_ledges);
+        world.get_mut::<MoveInput>(player).unwrap().0 = Vec3::NEG_Z;
+        let _ = world.run_system_once(hang_on_ledges);
+        assert!(world.get::<Mantle>(player).is_some());
+
+        for _ in 0..2 {
+            let _ = world.run_system_once(run_mantles);
+        }
+        let position = world.get::<Transform>(player).unwrap().translation;
+        assert!((position - Vec3::new(0.0, 2.5, -1.55)).length() < 1e-4);
+        assert!(world.get::<Mantle>(player).is_none());
+        assert!(world.get::<MovementLocked>(player).is_none());
+        assert_eq!(world.resource::<Messages<Mantled>>().len(), 1);
+    }
+
+    #[test]
+    fn pulling_back_drops_off_the_ledge() {
+        let mut world = setup_world();
+        let player = spawn_jumper(&mut world, 1.8);
+        let _ = world.run_system_once(grab_ledges);
+        world.get_mut::<LedgeHang>(player).unwrap().released = true;
+        world.get_mut::<MoveInput>(player).unwrap().0 = Vec3::Z;
+
+        let _ = world.run_system_once(hang_on_ledges);
+
+        assert!(world.get::<LedgeHang>(player).is_none());
+        assert!(world.get::<Mantle>(player).is_none());
+        assert!(world.get::<MovementLocked>(player).is_none());
+    }
+}
@@ -0,0 +1,41 @@
+// src/features/lighting/component.rs
+use bevy::prelude::*;
+
+/// How many dynamic point lights may be on, and how many may cast shadows.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct LightBudget {
+    /// The nearest this many shadow-casting lights keep their shadows.
+    pub max_shadowed: usize,
+    /// The nearest this many lights stay on; the rest are hidden.
+    pub max_active: usize,
+    /// Lights farther than this from the camera are hidden regardless.
+    pub cull_distance: f32,
+}
+
+impl Default for LightBudget {
+    fn default() -> Self {
+        Self {
+            max_shadowed: 4,
+            max_active: 24,
+            cull_distance: 60.0,
+        }
+    }
+}
+
+/// A point light under the `LightBudget` (added to every `PointLight`).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManagedLight {
+    /// The light was authored with shadows (it gets them back when in budget).
+    pub wants_shadows: bool,
+    /// Hidden by the budget (the manager only writes `Visibility` on changes).
+    pub culled: bool,
+}
+
+/// Lights the budget let through last frame.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LightStats {
+    pub managed: usize,
+    pub shadowed: usize,
+    pub active: usize,
+    pub culled: usize,
+}
@@ -0,0 +1,38 @@
+// src/features/lighting/mod.rs
+use bevy::camera::visibility::VisibilitySystems;
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+
+pub mod component;
+pub mod systems;
+
+/// Dynamic light budget.
+///
+/// Scope:
+/// - every `PointLight` is managed (`ManagedLight`): torches, lamps, muzzle
+///   flashes and projectile lights alike, with no opt-in at the spawn site
+/// - each frame lights are ranked by distance to the main camera: the nearest
+///   `LightBudget::max_shadowed` shadow casters keep their shadows, further ones
+///   light without them, and lights past `max_active` / `cull_distance` are hidden
+/// - `LightStats` reports managed / shadowed / active / culled lights
+///
+/// Design constraints:
+/// - Rendering only: gameplay reading lights (stealth) sees them as authored.
+/// - `Visibility` is only written when a light crosses the budget, so other
+///   features hiding the same light keep control in between.
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::LightBudget>();
+        app.init_resource::<component::LightStats>();
+
+        app.add_systems(Update, systems::manage_new_lights);
+        app.add_systems(
+            PostUpdate,
+            systems::budget_lights
+                .after(TransformSystems::Propagate)
+                .before(VisibilitySystems::VisibilityPropagate),
+        );
+    }
+}
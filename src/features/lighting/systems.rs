@@ -0,0 +1,140 @@
+// src/features/lighting/systems.rs
+use bevy::prelude::*;
+
+use super::component::{LightBudget, LightStats, ManagedLight};
+use crate::features::camera::component::OffscreenCamera;
+
+/// Update: put new point lights under the budget, remembering whether they were
+/// authored with shadows.
+#[expect(clippy::type_complexity)]
+pub fn manage_new_lights(
+    mut commands: Commands,
+    q_new: Query<(Entity, &PointLight), (Added<PointLight>, Without<ManagedLight>)>,
+) {
+    for (entity, light) in &q_new {
+        commands.entity(entity).insert(ManagedLight {
+            wants_shadows: light.shadows_enabled,
+            culled: false,
+        });
+    }
+}
+
+/// PostUpdate (after transforms): rank managed lights by distance to the main
+/// camera; the nearest `max_shadowed` shadow casters keep their shadows, the rest
+/// drop them, and lights past `max_active` or `cull_distance` are hidden.
+pub fn budget_lights(
+    budget: Res<LightBudget>,
+    mut stats: ResMut<LightStats>,
+    q_camera: Query<&GlobalTransform, (With<Camera3d>, Without<OffscreenCamera>)>,
+    mut q_lights: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut ManagedLight,
+        &mut PointLight,
+        &mut Visibility,
+    )>,
+) {
+    let Some(viewer) = q_camera.iter().next().map(GlobalTransform::translation) else {
+        return;
+    };
+    let mut ranked: Vec<(f32, Entity)> = q_lights
+        .iter()
+        .map(|(entity, at, ..)| (at.translation().distance_squared(viewer), entity))
+        .collect();
+    ranked.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+    let cull_sq = budget.cull_distance * budget.cull_distance;
+    let mut frame = LightStats {
+        managed: ranked.len(),
+        ..default()
+    };
+    for (rank, (distance_sq, entity)) in ranked.into_iter().enumerate() {
+        let Ok((_, _, mut managed, mut light, mut visibility)) = q_lights.get_mut(entity) else {
+            continue;
+        };
+        let culled = rank >= budget.max_active || distance_sq > cull_sq;
+        let shadows = !culled && managed.wants_shadows && frame.shadowed < budget.max_shadowed;
+
+        if managed.culled != culled {
+            managed.culled = culled;
+            *visibility = if culled {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            };
+        }
+        if light.shadows_enabled != shadows {
+            light.shadows_enabled = shadows;
+        }
+        if culled {
+            frame.culled += 1;
+        } else {
+            frame.active += 1;
+        }
+        frame.shadowed += shadows as usize;
+    }
+    *stats = frame;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_light(world: &mut World, z: f32, shadows: bool) -> Entity {
+        world
+            .spawn((
+                PointLight {
+                    shadows_enabled: shadows,
+                    ..default()
+                },
+                GlobalTransform::from_xyz(0.0, 0.0, z),
+                Visibility::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn nearest_lights_keep_shadows_and_the_rest_fall_back_or_go_dark() {
+        let mut world = World::new();
+        world.insert_resource(LightBudget {
+            max_shadowed: 2,
+            max_active: 4,
+            cull_distance: 50.0,
+        });
+        world.init_resource::<LightStats>();
+        world.spawn((Camera3d::default(), GlobalTransform::IDENTITY));
+        // Muzzle flashes, torches, projectiles: all authored with shadows but one.
+        let lights: Vec<Entity> = [(1.0, true), (2.0, false), (3.0, true), (4.0, true), (5.0, true)]
+            .into_iter()
+            .map(|(z, shadows)| spawn_light(&mut world, z, shadows))
+            .collect();
+        let far = spawn_light(&mut world, 80.0, true);
+
+        let _ = world.run_system_once(manage_new_lights);
+        let _ = world.run_system_once(budget_lights);
+
+        let shadows = |world: &World, e| world.get::<PointLight>(e).unwrap().shadows_enabled;
+        let hidden = |world: &World, e| *world.get::<Visibility>(e).unwrap() == Visibility::Hidden;
+        assert!(shadows(&world, lights[0]) && shadows(&world, lights[2]));
+        assert!(!shadows(&world, lights[1]), "never wanted them");
+        assert!(!shadows(&world, lights[3]), "over the shadow budget");
+        assert!(hidden(&world, lights[4]), "over the active budget");
+        assert!(hidden(&world, far), "too far");
+        assert_eq!(
+            *world.resource::<LightStats>(),
+            LightStats {
+                managed: 6,
+                shadowed: 2,
+                active: 4,
+                culled: 2
+            }
+        );
+
+        // The nearest light goes away: the next caster gets its shadows back.
+        world.despawn(lights[0]);
+        let _ = world.run_system_once(budget_lights);
+        assert!(shadows(&world, lights[3]));
+        assert!(!hidden(&world, lights[4]));
+    }
+}
@@ -0,0 +1,47 @@
+// src/features/lock_on/component.rs
+use bevy::prelude::*;
+
+/// Present on a character while it is locked onto `0`.
+///
+/// While locked:
+/// - the character faces the target (so local `MoveInput` strafes / circles around it)
+/// - follow cameras targeting the character swing to keep the target in view
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockOnTarget(pub Entity);
+
+/// Lock-on tunables.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct LockOnSettings {
+    /// Max distance to acquire a target.
+    pub acquire_distance: f32,
+    /// Lock breaks beyond this distance (a bit larger than `acquire_distance`).
+    pub break_distance: f32,
+    /// Half-angle of the view cone candidates must be in (degrees).
+    pub view_half_angle_deg: f32,
+    /// Height above the character's origin used for line-of-sight checks.
+    pub eye_height: f32,
+    /// Facing turn rate towards the target (1/sec, exponential approach).
+    pub turn_rate: f32,
+}
+
+impl Default for LockOnSettings {
+    fn default() -> Self {
+        Self {
+            acquire_distance: 15.0,
+            break_distance: 20.0,
+            view_half_angle_deg: 45.0,
+            eye_height: 1.5,
+            turn_rate: 12.0,
+        }
+    }
+}
+
+/// Key that toggles lock-on.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct LockOnKeybinding(pub KeyCode);
+
+impl Default for LockOnKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::KeyT)
+    }
+}
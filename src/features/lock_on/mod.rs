@@ -0,0 +1,52 @@
+// src/features/lock_on/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::camera::systems as camera_systems;
+
+pub mod component;
+pub mod systems;
+
+/// Target lock-on feature.
+///
+/// Scope:
+/// - Update: lock key toggles a lock onto the nearest `Enemy` in range, in the camera's
+///   view cone and in line of sight; locks break on death, distance or lost sight
+/// - FixedUpdate: locked characters face the target (local movement becomes strafing)
+/// - Update: follow cameras of locked characters swing to keep the target in view
+///
+/// Other features (AI, abilities) can send `ToggleLockOn` or insert `LockOnTarget` directly.
+pub struct LockOnPlugin;
+
+impl Plugin for LockOnPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::LockOnSettings>();
+        app.init_resource::<component::LockOnKeybinding>();
+
+        app.add_message::<systems::ToggleLockOn>();
+        app.add_message::<systems::LockOnChanged>();
+
+        app.add_systems(
+            Update,
+            systems::read_lock_on_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::toggle_lock_on,
+                systems::validate_lock_on,
+                systems::track_lock_on_camera,
+            )
+                .chain()
+                .in_set(AppSet::CameraUpdate)
+                .after(camera_systems::recenter_orbit_camera)
+                .before(camera_systems::update_follow_camera),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::face_lock_on_target.in_set(AppSet::ReadIntent),
+        );
+    }
+}
@@ -0,0 +1,279 @@
+// src/features/lock_on/systems.rs
+use bevy::prelude::*;
+
+use super::component::{LockOnKeybinding, LockOnSettings, LockOnTarget};
+use crate::features::camera::component::{CameraSettings, FollowCamera};
+use crate::features::camera::rig::CameraRig;
+use crate::features::camera::systems::wrap_angle;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::enemy::component::Enemy;
+use crate::features::health::component::Health;
+use crate::features::player::death::AlivePlayer;
+
+/// Lock-on toggle requested for a character (acquire if free, release if locked).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToggleLockOn {
+    pub entity: Entity,
+}
+
+/// Lock acquired / released (HUD reticle, audio).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockOnChanged {
+    Acquired { entity: Entity, target: Entity },
+    Released { entity: Entity },
+}
+
+/// Update: lock-on key -> `ToggleLockOn` for living players.
+pub fn read_lock_on_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<LockOnKeybinding>,
+    q_players: Query<Entity, AlivePlayer>,
+    mut toggles: MessageWriter<ToggleLockOn>,
+) {
+    if !keyboard.just_pressed(binding.0) {
+        return;
+    }
+    for entity in &q_players {
+        toggles.write(ToggleLockOn { entity });
+    }
+}
+
+/// Yaw that makes a `-Z`-forward entity face along `dir` (XZ only).
+fn yaw_towards(dir: Vec3) -> Option<f32> {
+    let flat = Vec2::new(dir.x, dir.z).try_normalize()?;
+    Some(f32::atan2(-flat.x, -flat.y))
+}
+
+/// `true` if nothing but `ignore` lies between `from` and `to`.
+fn has_line_of_sight(collision: &CollisionWorld, from: Vec3, to: Vec3, ignore: &[Entity]) -> bool {
+    let delta = to - from;
+    let distance = delta.length();
+    distance <= f32::EPSILON || collision.ray_cast(from, delta, distance, ignore).is_none()
+}
+
+/// Update: handle toggles — release an existing lock, or pick the nearest enemy that is
+/// in range, inside the camera's view cone and in line of sight.
+#[expect(clippy::too_many_arguments)]
+pub fn toggle_lock_on(
+    mut commands: Commands,
+    settings: Res<LockOnSettings>,
+    collision: CollisionWorld,
+    mut toggles: MessageReader<ToggleLockOn>,
+    mut changed: MessageWriter<LockOnChanged>,
+    q_characters: Query<(&Transform, Option<&LockOnTarget>)>,
+    q_enemies: Query<(Entity, &Transform, Option<&Health>), With<Enemy>>,
+    q_cameras: Query<&Transform, With<CameraRig>>,
+) {
+    let cos_cone = settings.view_half_angle_deg.to_radians().cos();
+
+    for ev in toggles.read() {
+        let Ok((tr, locked)) = q_characters.get(ev.entity) else {
+            continue;
+        };
+
+        if locked.is_some() {
+            commands.entity(ev.entity).remove::<LockOnTarget>();
+            changed.write(LockOnChanged::Released { entity: ev.entity });
+            continue;
+        }
+
+        // "In view" means the camera's view when there is one, the character's otherwise.
+        let (view_origin, view_forward) = q_cameras
+            .iter()
+            .next()
+            .map_or((tr.translation, tr.forward().as_vec3()), |cam| {
+                (cam.translation, cam.forward().as_vec3())
+            });
+        let eye = tr.translation + Vec3::Y * settings.eye_height;
+
+        let best = q_enemies
+            .iter()
+            .filter(|(_, _, health)| health.is_none_or(|h| !h.is_depleted()))
+            .filter_map(|(enemy, enemy_tr, _)| {
+                let distance = enemy_tr.translation.distance(tr.translation);
+                let to_enemy = (enemy_tr.translation - view_origin).try_normalize()?;
+                (distance <= settings.acquire_distance && to_enemy.dot(view_forward) >= cos_cone)
+                    .then_some((enemy, enemy_tr.translation, distance))
+            })
+            .filter(|&(enemy, pos, _)| has_line_of_sight(&collision, eye, pos, &[ev.entity, enemy]))
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+
+        if let Some((target, _, _)) = best {
+            commands.entity(ev.entity).insert(LockOnTarget(target));
+            changed.write(LockOnChanged::Acquired {
+                entity: ev.entity,
+                target,
+            });
+        }
+    }
+}
+
+/// Update: release locks whose target died, despawned, got too far or went out of sight.
+pub fn validate_lock_on(
+    mut commands: Commands,
+    settings: Res<LockOnSettings>,
+    collision: CollisionWorld,
+    mut changed: MessageWriter<LockOnChanged>,
+    q_locked: Query<(Entity, &Transform, &LockOnTarget)>,
+    q_targets: Query<(&Transform, Option<&Health>)>,
+) {
+    for (entity, tr, lock) in &q_locked {
+        let keep = q_targets.get(lock.0).is_ok_and(|(target_tr, health)| {
+            let eye = tr.translation + Vec3::Y * settings.eye_height;
+            health.is_none_or(|h| !h.is_depleted())
+                && target_tr.translation.distance(tr.translation) <= settings.break_distance
+                && has_line_of_sight(&collision, eye, target_tr.translation, &[entity, lock.0])
+        });
+
+        if !keep {
+            commands.entity(entity).remove::<LockOnTarget>();
+            changed.write(LockOnChanged::Released { entity });
+        }
+    }
+}
+
+/// FixedUpdate (before velocity): turn locked characters to face their target.
+///
+/// Movement intent is local-space, so facing the target turns WASD into strafing.
+pub fn face_lock_on_target(
+    time: Res<Time<Fixed>>,
+    settings: Res<LockOnSettings>,
+    mut q_locked: Query<(&mut Transform, &LockOnTarget)>,
+    q_targets: Query<&Transform, Without<LockOnTarget>>,
+) {
+    let t = 1.0 - (-settings.turn_rate.max(0.0) * time.delta_secs()).exp();
+
+    for (mut tr, lock) in &mut q_locked {
+        let Some(yaw) = q_targets
+            .get(lock.0)
+            .ok()
+            .and_then(|target| yaw_towards(target.translation - tr.translation))
+        else {
+            continue;
+        };
+
+        let (current, _, _) = tr.rotation.to_euler(EulerRot::YXZ);
+        tr.rotation = Quat::from_rotation_y(current + wrap_angle(yaw - current) * t);
+    }
+}
+
+/// Update (before the boom is placed): swing follow cameras of locked characters so the
+/// target stays in view past the character's shoulder.
+pub fn track_lock_on_camera(
+    time: Res<Time>,
+    camera_settings: Res<CameraSettings>,
+    q_locked: Query<(&Transform, &LockOnTarget)>,
+    q_targets: Query<&Transform, Without<FollowCamera>>,
+    mut q_cameras: Query<&mut FollowCamera>,
+) {
+    let t = 1.0 - (-camera_settings.recenter_rate.max(0.0) * time.delta_secs()).exp();
+
+    for mut follow in &mut q_cameras {
+        let Some((tr, lock)) = follow.target.and_then(|e| q_locked.get(e).ok()) else {
+            continue;
+        };
+        let Some(yaw) = q_targets
+            .get(lock.0)
+            .ok()
+            .and_then(|target| yaw_towards(target.translation - tr.translation))
+        else {
+            continue;
+        };
+
+        // Boom points away from the target, i.e. the same yaw as facing it.
+        follow.yaw += wrap_angle(yaw - follow.yaw) * t;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::collision::component::Collider;
+    use crate::features::player::component::Player;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> (World, Entity) {
+        let mut world = World::new();
+        world.insert_resource(LockOnSettings::default());
+        world.init_resource::<Messages<ToggleLockOn>>();
+        world.init_resource::<Messages<LockOnChanged>>();
+        let player = world.spawn((Player, Transform::default())).id();
+        (world, player)
+    }
+
+    fn enemy(world: &mut World, pos: Vec3) -> Entity {
+        world.spawn((Enemy, Transform::from_translation(pos))).id()
+    }
+
+    fn toggle(world: &mut World, entity: Entity) {
+        world.write_message(ToggleLockOn { entity });
+        let _ = world.run_system_once(toggle_lock_on);
+        world.resource_mut::<Messages<ToggleLockOn>>().clear();
+    }
+
+    #[test]
+    fn acquires_nearest_enemy_in_view_and_toggles_off() {
+        let (mut world, player) = setup_world();
+        let _far = enemy(&mut world, Vec3::new(0.0, 0.0, -10.0));
+        let _behind = enemy(&mut world, Vec3::new(0.0, 0.0, 2.0));
+        let near = enemy(&mut world, Vec3::new(1.0, 0.0, -4.0));
+
+        toggle(&mut world, player);
+        assert_eq!(world.get::<LockOnTarget>(player), Some(&LockOnTarget(near)));
+
+        toggle(&mut world, player);
+        assert!(world.get::<LockOnTarget>(player).is_none());
+    }
+
+    #[test]
+    fn occluded_and_dead_enemies_are_skipped() {
+        let (mut world, player) = setup_world();
+        let _hidden = enemy(&mut world, Vec3::new(0.0, 0.0, -4.0));
+        world.spawn((
+            Collider::cuboid(Vec3::new(1.0, 3.0, 0.1)),
+            GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -2.0)),
+        ));
+        let dead = enemy(&mut world, Vec3::new(-3.0, 0.0, -6.0));
+        world.entity_mut(dead).insert(Health {
+            current: 0.0,
+            max: 10.0,
+        });
+        let visible = enemy(&mut world, Vec3::new(5.0, 0.0, -6.0));
+
+        toggle(&mut world, player);
+
+        assert_eq!(world.get::<LockOnTarget>(player), Some(&LockOnTarget(visible)));
+    }
+
+    #[test]
+    fn lock_breaks_when_target_moves_out_of_range() {
+        let (mut world, player) = setup_world();
+        let target = enemy(&mut world, Vec3::new(0.0, 0.0, -5.0));
+        world.entity_mut(player).insert(LockOnTarget(target));
+
+        let _ = world.run_system_once(validate_lock_on);
+        assert!(world.get::<LockOnTarget>(player).is_some());
+
+        world.get_mut::<Transform>(target).unwrap().translation.z = -50.0;
+        let _ = world.run_system_once(validate_lock_on);
+        assert!(world.get::<LockOnTarget>(player).is_none());
+        assert_eq!(world.resource::<Messages<LockOnChanged>>().len(), 1);
+    }
+
+    #[test]
+    fn locked_character_turns_to_face_target() {
+        let (mut world, player) = setup_world();
+        let target = enemy(&mut world, Vec3::new(5.0, 0.0, 0.0));
+        world.entity_mut(player).insert(LockOnTarget(target));
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs(1));
+        world.insert_resource(fixed);
+
+        let _ = world.run_system_once(face_lock_on_target);
+
+        let forward = world.get::<Transform>(player).unwrap().forward().as_vec3();
+        assert!((forward - Vec3::X).length() < 1e-3, "got {forward:?}");
+    }
+}
@@ -0,0 +1,145 @@
+// src/features/materials/component.rs
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Palette every other palette falls back to.
+pub const DEFAULT_PALETTE: &str = "default";
+
+/// Color of keys no palette defines (impossible to miss on screen).
+const MISSING_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+/// Palette key -> sRGB color.
+pub type Palette = BTreeMap<String, (f32, f32, f32)>;
+
+/// A shared `StandardMaterial`, loaded from `assets/data/materials.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MaterialDef {
+    /// Palette key of the base color.
+    pub color: String,
+    #[serde(default)]
+    pub roughness: Option<f32>,
+    #[serde(default)]
+    pub metallic: Option<f32>,
+    #[serde(default)]
+    pub unlit: bool,
+}
+
+/// Palettes and material definitions.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MaterialData {
+    pub palettes: BTreeMap<String, Palette>,
+    pub materials: BTreeMap<String, MaterialDef>,
+}
+
+const BUILTIN_MATERIALS: &str = include_str!("../../../assets/data/materials.ron");
+
+impl MaterialData {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+}
+
+impl Default for MaterialData {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_MATERIALS).expect("assets/data/materials.ron must parse")
+    }
+}
+
+/// The game's shared materials and its active color palette.
+///
+/// Features ask for materials by key (`get`) instead of adding their own, so each
+/// is one asset however often it's spawned; per-entity materials (characters)
+/// take their color from the palette (`color`). Switching palettes recolors the
+/// shared materials in place.
+#[derive(Resource, Debug, Clone)]
+pub struct MaterialLibrary {
+    pub data: MaterialData,
+    /// Name of the active palette.
+    pub palette: String,
+    handles: HashMap<String, Handle<StandardMaterial>>,
+    missing: Handle<StandardMaterial>,
+}
+
+impl Default for MaterialLibrary {
+    fn default() -> Self {
+        Self::new(MaterialData::default())
+    }
+}
+
+impl MaterialLibrary {
+    /// A library over `data` with the default palette and no assets yet (`build`).
+    pub fn new(data: MaterialData) -> Self {
+        Self {
+            data,
+            palette: DEFAULT_PALETTE.to_string(),
+            handles: HashMap::new(),
+            missing: Handle::default(),
+        }
+    }
+
+    /// Color of `key` in the active palette (then the default one).
+    pub fn color(&self, key: &str) -> Color {
+        [self.palette.as_str(), DEFAULT_PALETTE]
+            .iter()
+            .find_map(|palette| self.data.palettes.get(*palette)?.get(key))
+            .map_or(MISSING_COLOR, |&(r, g, b)| Color::srgb(r, g, b))
+    }
+
+    /// Shared material `key` (a magenta stand-in for unknown keys).
+    pub fn get(&self, key: &str) -> Handle<StandardMaterial> {
+        self.handles.get(key).cloned().unwrap_or_else(|| {
+            warn!("no material {key:?} in the material library");
+            self.missing.clone()
+        })
+    }
+
+    /// Number of shared materials created.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    fn standard_material(&self, def: &MaterialDef) -> StandardMaterial {
+        let defaults = StandardMaterial::default();
+        StandardMaterial {
+            base_color: self.color(&def.color),
+            perceptual_roughness: def.roughness.unwrap_or(defaults.perceptual_roughness),
+            metallic: def.metallic.unwrap_or(defaults.metallic),
+            unlit: def.unlit,
+            ..defaults
+        }
+    }
+
+    /// Create one asset per defined material.
+    pub fn build(&mut self, materials: &mut Assets<StandardMaterial>) {
+        self.missing = materials.add(MISSING_COLOR);
+        let handles = self
+            .data
+            .materials
+            .iter()
+            .map(|(key, def)| (key.clone(), materials.add(self.standard_material(def))))
+            .collect();
+        self.handles = handles;
+    }
+
+    /// Switch to `palette`, recoloring the shared materials in place; unknown
+    /// palettes are ignored (returns whether it switched).
+    pub fn set_palette(&mut self, palette: &str, materials: &mut Assets<StandardMaterial>) -> bool {
+        if !self.data.palettes.contains_key(palette) {
+            return false;
+        }
+        self.palette = palette.to_string();
+        for (key, def) in &self.data.materials {
+            let color = self.color(&def.color);
+            if let Some(material) = self.handles.get(key).and_then(|h| materials.get_mut(h)) {
+                material.base_color = color;
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,28 @@
+// src/features/materials/mod.rs
+use bevy::prelude::*;
+
+pub mod component;
+pub mod systems;
+
+/// Material library.
+///
+/// Scope:
+/// - `MaterialLibrary`: shared `StandardMaterial`s by key and named color palettes,
+///   from `assets/data/materials.ron`, created in `PreStartup`
+/// - per-entity materials (characters) take their colors from the palette
+/// - `SetPalette` switches palettes, recoloring the shared materials in place
+///
+/// Design constraints:
+/// - Features request materials by key instead of `materials.add`-ing their own,
+///   so repeated spawns share one asset.
+pub struct MaterialsPlugin;
+
+impl Plugin for MaterialsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::MaterialLibrary>();
+        app.add_message::<systems::SetPalette>();
+
+        app.add_systems(PreStartup, systems::build_material_library);
+        app.add_systems(Update, systems::apply_palette);
+    }
+}
@@ -0,0 +1,88 @@
+// src/features/materials/systems.rs
+use bevy::prelude::*;
+
+use super::component::MaterialLibrary;
+
+/// Switch the color palette (unknown names are ignored with a warning).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SetPalette(pub String);
+
+/// PreStartup: create the shared materials before anything spawns with them.
+pub fn build_material_library(
+    mut library: ResMut<MaterialLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    library.build(&mut materials);
+}
+
+/// Update: apply palette switches.
+pub fn apply_palette(
+    mut requests: MessageReader<SetPalette>,
+    mut library: ResMut<MaterialLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for SetPalette(palette) in requests.read() {
+        if !library.set_palette(palette, &mut materials) {
+            warn!("unknown palette {palette:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<MaterialLibrary>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Messages<SetPalette>>();
+        let _ = world.run_system_once(build_material_library);
+        world
+    }
+
+    #[test]
+    fn materials_are_shared_per_key() {
+        let world = setup_world();
+        let library = world.resource::<MaterialLibrary>();
+        assert_eq!(library.get("ground"), library.get("ground"));
+        assert_ne!(library.get("ground"), library.get("accent"));
+        // One asset per defined material (plus the missing stand-in), no matter how
+        // often they're requested.
+        let assets = world.resource::<Assets<StandardMaterial>>();
+        assert_eq!(assets.len(), library.len() + 1);
+    }
+
+    #[test]
+    fn palette_swaps_recolor_shared_materials_in_place() {
+        let mut world = setup_world();
+        let ground = world.resource::<MaterialLibrary>().get("ground");
+
+        world.write_message(SetPalette("dusk".into()));
+        world.write_message(SetPalette("nope".into()));
+        let _ = world.run_system_once(apply_palette);
+
+        let library = world.resource::<MaterialLibrary>();
+        assert_eq!(library.palette, "dusk");
+        assert_eq!(library.get("ground"), ground, "same asset");
+        let color = world.resource::<Assets<StandardMaterial>>().get(&ground).unwrap().base_color;
+        assert_eq!(color, Color::srgb(0.62, 0.58, 0.7));
+        assert_eq!(library.color("player"), Color::srgb(0.55, 0.85, 0.95));
+        assert_eq!(library.color("nothing"), Color::srgb(1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn every_palette_defines_every_key() {
+        let library = MaterialLibrary::default();
+        let default = &library.data.palettes["default"];
+        for (name, palette) in &library.data.palettes {
+            let keys: Vec<_> = palette.keys().collect();
+            assert_eq!(keys, default.keys().collect::<Vec<_>>(), "palette {name}");
+        }
+        for def in library.data.materials.values() {
+            assert!(default.contains_key(&def.color), "{}", def.color);
+        }
+    }
+}
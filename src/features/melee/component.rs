@@ -0,0 +1,183 @@
+// src/features/melee/component.rs
+use bevy::prelude::*;
+
+use crate::features::collision::component::Aabb;
+
+/// Shape of a melee hitbox, centered on the strike's offset point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HitboxShape {
+    Sphere { radius: f32 },
+    /// Oriented with the attacker; overlap uses its world-space bounding box.
+    Box { half_extents: Vec3 },
+}
+
+impl HitboxShape {
+    /// Overlap test against a world-space box.
+    pub fn overlaps(&self, center: Vec3, rotation: Quat, target: &Aabb) -> bool {
+        match *self {
+            HitboxShape::Sphere { radius } => {
+                let closest = center.clamp(target.min, target.max);
+                closest.distance_squared(center) <= radius * radius
+            }
+            HitboxShape::Box { half_extents } => {
+                // World-space half extents of the rotated box.
+                let m = Mat3::from_quat(rotation);
+                let world_half = m.x_axis.abs() * half_extents.x
+                    + m.y_axis.abs() * half_extents.y
+                    + m.z_axis.abs() * half_extents.z;
+                let min = center - world_half;
+                let max = center + world_half;
+                min.cmple(target.max).all() && max.cmpge(target.min).all()
+            }
+        }
+    }
+}
+
+/// One attack of a combo. Windows are counted in fixed ticks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeleeStrike {
+    pub damage: f32,
+    /// Impulse (units/sec) pushing hit targets away from the attacker.
+    pub knockback: f32,
+    /// Hitbox center in the attacker's local space (`-Z` is forward).
+    pub offset: Vec3,
+    pub shape: HitboxShape,
+    /// Whether a timed guard can parry this strike (otherwise it can only be blocked).
+    pub parryable: bool,
+    /// Wind-up before the hitbox turns on.
+    pub startup_ticks: u32,
+    /// Ticks the hitbox is live.
+    pub active_ticks: u32,
+    /// Ticks after the hitbox turns off before the attacker is free again.
+    pub recovery_ticks: u32,
+}
+
+impl MeleeStrike {
+    pub fn total_ticks(&self) -> u32 {
+        self.startup_ticks + self.active_ticks + self.recovery_ticks
+    }
+
+    /// Whether the hitbox is live on `tick` (0-based, counted from the swing start).
+    pub fn is_active(&self, tick: u32) -> bool {
+        tick >= self.startup_ticks && tick < self.startup_ticks + self.active_ticks
+    }
+}
+
+/// A character's melee combo: strikes in order, plus how long a finished strike
+/// still accepts a follow-up press.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct MeleeCombo {
+    pub strikes: Vec<MeleeStrike>,
+    pub chain_window_ticks: u32,
+}
+
+impl Default for MeleeCombo {
+    /// Two quick slashes and a heavier, unparryable finisher
+    /// (at 60 Hz: ~0.3s, ~0.3s, ~0.5s).
+    fn default() -> Self {
+        let slash = MeleeStrike {
+            damage: 10.0,
+            knockback: 3.0,
+            offset: Vec3::new(0.0, 0.0, -1.0),
+            shape: HitboxShape::Box {
+                half_extents: Vec3::new(0.8, 0.6, 0.6),
+            },
+            parryable: true,
+            startup_ticks: 5,
+            active_ticks: 6,
+            recovery_ticks: 8,
+        };
+        let finisher = MeleeStrike {
+            damage: 25.0,
+            knockback: 9.0,
+            offset: Vec3::new(0.0, 0.0, -1.2),
+            shape: HitboxShape::Sphere { radius: 1.0 },
+            parryable: false,
+            startup_ticks: 10,
+            active_ticks: 6,
+            recovery_ticks: 14,
+        };
+        Self {
+            strikes: vec![slash, slash, finisher],
+            chain_window_ticks: 15,
+        }
+    }
+}
+
+/// A swing in progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeleeSwing {
+    /// Index into `MeleeCombo::strikes`.
+    pub step: usize,
+    /// Ticks since the swing started.
+    pub tick: u32,
+    /// Targets already hit by this swing (one hit per target per swing).
+    pub hit: Vec<Entity>,
+    /// A follow-up press arrived during this swing.
+    pub queued: bool,
+}
+
+/// Runtime melee state of a character.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct MeleeState {
+    pub swing: Option<MeleeSwing>,
+    /// Combo step a press would start next.
+    pub next_step: usize,
+    /// Ticks left in which a press continues the combo instead of restarting it.
+    pub chain_ticks_left: u32,
+}
+
+impl MeleeState {
+    pub fn is_swinging(&self) -> bool {
+        self.swing.is_some()
+    }
+}
+
+/// Melee input bindings.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct MeleeBindings {
+    pub key: KeyCode,
+    pub gamepad: GamepadButton,
+}
+
+impl Default for MeleeBindings {
+    fn default() -> Self {
+        Self {
+            key: KeyCode::KeyC,
+            gamepad: GamepadButton::East,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strike_windows_partition_the_swing() {
+        let s = MeleeCombo::default().strikes[0];
+        assert!(!s.is_active(s.startup_ticks - 1));
+        assert!(s.is_active(s.startup_ticks));
+        assert!(!s.is_active(s.startup_ticks + s.active_ticks));
+        assert_eq!(s.total_ticks(), 19);
+    }
+
+    #[test]
+    fn hitbox_shapes_overlap_boxes() {
+        let target = Aabb {
+            min: Vec3::new(1.0, 0.0, -0.5),
+            max: Vec3::new(2.0, 1.0, 0.5),
+        };
+        let sphere = HitboxShape::Sphere { radius: 0.6 };
+        assert!(sphere.overlaps(Vec3::new(0.5, 0.5, 0.0), Quat::IDENTITY, &target));
+        assert!(!sphere.overlaps(Vec3::new(0.0, 0.5, 0.0), Quat::IDENTITY, &target));
+
+        // A long thin box only reaches the target once rotated towards it.
+        let blade = HitboxShape::Box {
+            half_extents: Vec3::new(0.1, 0.1, 1.0),
+        };
+        assert!(!blade.overlaps(Vec3::new(0.2, 0.5, 0.0), Quat::IDENTITY, &target));
+        let towards_x = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        assert!(blade.overlaps(Vec3::new(0.2, 0.5, 0.0), towards_x, &target));
+    }
+}
@@ -0,0 +1,53 @@
+// src/features/melee/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::health::damage::DamageEvent;
+
+pub mod component;
+pub mod systems;
+
+/// Melee combat feature.
+///
+/// Scope:
+/// - `MeleeCombo`: ordered strikes, each a hitbox shape + offset in front of the
+///   attacker with startup / active / recovery windows in fixed ticks
+/// - Update: melee button -> `MeleeAttackRequest`
+/// - FixedUpdate: start / chain strikes, overlap live hitboxes against enemy
+///   colliders -> `DamageEvent` + knockback (`ExternalForce`) + `MeleeHit`
+/// - pressing again mid-swing or within `chain_window_ticks` continues the combo
+///
+/// Design constraints:
+/// - Windows are tick counts, so hit timing is identical at any frame rate.
+/// - Melee shares the "hands busy" rules with weapons: no swings while reloading
+///   or while an ability casts / channels.
+pub struct MeleePlugin;
+
+impl Plugin for MeleePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::MeleeBindings>();
+
+        app.add_message::<systems::MeleeAttackRequest>();
+        app.add_message::<systems::MeleeSwingStarted>();
+        app.add_message::<systems::MeleeHit>();
+        app.add_message::<DamageEvent>();
+
+        app.add_systems(
+            Update,
+            systems::read_melee_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_player_melee,
+                systems::start_melee_attacks,
+                systems::advance_melee_attacks,
+            )
+                .chain()
+                .before(crate::features::health::damage::apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
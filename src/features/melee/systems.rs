@@ -0,0 +1,310 @@
+// src/features/melee/systems.rs
+use bevy::prelude::*;
+
+use super::component::{MeleeBindings, MeleeCombo, MeleeState, MeleeSwing};
+use crate::features::ability::component::AbilitySlots;
+use crate::features::collision::component::Collider;
+use crate::features::defense::component::Staggered;
+use crate::features::enemy::component::Enemy;
+use crate::features::health::damage::{DamageEvent, IncomingAttack};
+use crate::features::movement::component::ExternalForce;
+use crate::features::player::component::Player;
+use crate::features::player::death::AlivePlayer;
+use crate::features::weapon::component::Reloading;
+
+/// Request a melee attack (starts a combo, or chains the next strike).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeleeAttackRequest {
+    pub entity: Entity,
+}
+
+/// A strike started (animation / audio hook).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeleeSwingStarted {
+    pub entity: Entity,
+    pub step: usize,
+}
+
+/// A strike's hitbox overlapped a target (damage is sent separately as `DamageEvent`).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeleeHit {
+    pub attacker: Entity,
+    pub target: Entity,
+    pub step: usize,
+}
+
+/// Players get the default combo.
+pub fn grant_player_melee(
+    mut commands: Commands,
+    q_new: Query<Entity, (Added<Player>, Without<MeleeCombo>)>,
+) {
+    for entity in &q_new {
+        commands
+            .entity(entity)
+            .insert((MeleeCombo::default(), MeleeState::default()));
+    }
+}
+
+/// Update: melee button -> `MeleeAttackRequest`.
+pub fn read_melee_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<MeleeBindings>,
+    q_gamepads: Query<&Gamepad>,
+    q_players: Query<Entity, (AlivePlayer, With<MeleeCombo>)>,
+    mut requests: MessageWriter<MeleeAttackRequest>,
+) {
+    let pressed = keyboard.just_pressed(bindings.key)
+        || q_gamepads.iter().any(|g| g.just_pressed(bindings.gamepad));
+    if !pressed {
+        return;
+    }
+    for entity in &q_players {
+        requests.write(MeleeAttackRequest { entity });
+    }
+}
+
+/// FixedUpdate: start strikes or queue the next one of the combo.
+///
+/// - Idle: starts `next_step` while the chain window is open, else the first strike.
+/// - Mid-swing: queues the follow-up (played when the current strike ends).
+/// - Refused while reloading, staggered, or while an ability casts / channels.
+#[expect(clippy::type_complexity)]
+pub fn start_melee_attacks(
+    mut requests: MessageReader<MeleeAttackRequest>,
+    mut q_attackers: Query<(
+        &MeleeCombo,
+        &mut MeleeState,
+        Option<&AbilitySlots>,
+        Has<Reloading>,
+        Has<Staggered>,
+    )>,
+    mut started: MessageWriter<MeleeSwingStarted>,
+) {
+    for req in requests.read() {
+        let Ok((combo, mut state, abilities, reloading, staggered)) = q_attackers.get_mut(req.entity)
+        else {
+            continue;
+        };
+        if reloading
+            || staggered
+            || abilities.is_some_and(AbilitySlots::is_busy)
+            || combo.strikes.is_empty()
+        {
+            continue;
+        }
+
+        if let Some(swing) = state.swing.as_mut() {
+            swing.queued = swing.step + 1 < combo.strikes.len();
+            continue;
+        }
+
+        let step = if state.chain_ticks_left > 0 {
+            state.next_step % combo.strikes.len()
+        } else {
+            0
+        };
+        state.swing = Some(new_swing(step));
+        started.write(MeleeSwingStarted {
+            entity: req.entity,
+            step,
+        });
+    }
+}
+
+fn new_swing(step: usize) -> MeleeSwing {
+    MeleeSwing {
+        step,
+        tick: 0,
+        hit: Vec::new(),
+        queued: false,
+    }
+}
+
+/// FixedUpdate: advance swings one tick, hit enemies overlapping live hitboxes,
+/// and chain / close the combo when a strike ends.
+///
+/// Each target is hit at most once per strike; knockback pushes it horizontally
+/// away from the attacker through `ExternalForce`.
+pub fn advance_melee_attacks(
+    mut q_attackers: Query<(Entity, &MeleeCombo, &mut MeleeState, &Transform)>,
+    q_targets: Query<(Entity, &Collider, &GlobalTransform), With<Enemy>>,
+    mut q_forces: Query<&mut ExternalForce>,
+    mut damage: MessageWriter<DamageEvent>,
+    mut hits: MessageWriter<MeleeHit>,
+    mut started: MessageWriter<MeleeSwingStarted>,
+) {
+    for (attacker, combo, mut state, transform) in &mut q_attackers {
+        state.chain_ticks_left = state.chain_ticks_left.saturating_sub(1);
+        let Some(swing) = state.swing.as_mut() else {
+            continue;
+        };
+        let Some(strike) = combo.strikes.get(swing.step).copied() else {
+            state.swing = None;
+            continue;
+        };
+
+        if strike.is_active(swing.tick) {
+            let center = transform.translation + transform.rotation * strike.offset;
+            for (target, collider, target_tr) in &q_targets {
+                if target == attacker || swing.hit.contains(&target) {
+                    continue;
+                }
+                let target_pos = target_tr.translation();
+                if !strike
+                    .shape
+                    .overlaps(center, transform.rotation, &collider.aabb(target_pos))
+                {
+                    continue;
+                }
+
+                swing.hit.push(target);
+                let away = (target_pos - transform.translation).with_y(0.0);
+                let direction = away.try_normalize().unwrap_or(transform.rotation * Vec3::NEG_Z);
+                damage.write(DamageEvent::from_attack(
+                    target,
+                    strike.damage,
+                    IncomingAttack {
+                        source: attacker,
+                        direction,
+                        parryable: strike.parryable,
+                    },
+                ));
+                hits.write(MeleeHit {
+                    attacker,
+                    target,
+                    step: swing.step,
+                });
+                if let Ok(mut force) = q_forces.get_mut(target) {
+                    force.apply_impulse(direction * strike.knockback);
+                }
+            }
+        }
+
+        swing.tick += 1;
+        if swing.tick < strike.total_ticks() {
+            continue;
+        }
+
+        let (step, queued) = (swing.step, swing.queued);
+        if queued {
+            state.swing = Some(new_swing(step + 1));
+            started.write(MeleeSwingStarted {
+                entity: attacker,
+                step: step + 1,
+            });
+        } else {
+            state.swing = None;
+            state.next_step = (step + 1) % combo.strikes.len();
+            state.chain_ticks_left = combo.chain_window_ticks;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<MeleeAttackRequest>>();
+        world.init_resource::<Messages<MeleeSwingStarted>>();
+        world.init_resource::<Messages<MeleeHit>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world
+    }
+
+    fn spawn_attacker(world: &mut World) -> Entity {
+        world
+            .spawn((MeleeCombo::default(), MeleeState::default(), Transform::default()))
+            .id()
+    }
+
+    fn spawn_enemy(world: &mut World, at: Vec3) -> Entity {
+        world
+            .spawn((
+                Enemy,
+                Collider::cuboid(Vec3::splat(0.4)),
+                GlobalTransform::from_translation(at),
+                ExternalForce::default(),
+            ))
+            .id()
+    }
+
+    fn press(world: &mut World, entity: Entity) {
+        world.write_message(MeleeAttackRequest { entity });
+        let _ = world.run_system_once(start_melee_attacks);
+    }
+
+    fn run_ticks(world: &mut World, ticks: u32) {
+        for _ in 0..ticks {
+            let _ = world.run_system_once(advance_melee_attacks);
+        }
+    }
+
+    #[test]
+    fn strike_hits_enemies_in_front_once_with_knockback() {
+        let mut world = setup_world();
+        let attacker = spawn_attacker(&mut world);
+        let front = spawn_enemy(&mut world, Vec3::new(0.0, 0.0, -1.2));
+        let behind = spawn_enemy(&mut world, Vec3::new(0.0, 0.0, 2.0));
+
+        press(&mut world, attacker);
+        let strike = MeleeCombo::default().strikes[0];
+        run_ticks(&mut world, strike.total_ticks());
+
+        let damage: Vec<_> = world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(damage.len(), 1);
+        assert_eq!((damage[0].target, damage[0].amount), (front, strike.damage));
+        let attack = damage[0].attack.unwrap();
+        assert_eq!(attack.source, attacker);
+        assert!((attack.direction - Vec3::NEG_Z).length() < 1e-5);
+        assert!(world.get::<ExternalForce>(front).unwrap().velocity.z < 0.0);
+        assert_eq!(world.get::<ExternalForce>(behind).unwrap().velocity, Vec3::ZERO);
+        assert!(!world.get::<MeleeState>(attacker).unwrap().is_swinging());
+    }
+
+    #[test]
+    fn presses_chain_the_combo_and_reset_after_the_window() {
+        let mut world = setup_world();
+        let attacker = spawn_attacker(&mut world);
+        let combo = MeleeCombo::default();
+
+        // Press during the first swing: the second starts right after it.
+        press(&mut world, attacker);
+        run_ticks(&mut world, 2);
+        press(&mut world, attacker);
+        run_ticks(&mut world, combo.strikes[0].total_ticks() - 2);
+        let swing = world.get::<MeleeState>(attacker).unwrap().swing.clone().unwrap();
+        assert_eq!((swing.step, swing.tick), (1, 0));
+
+        // Finish it, then press within the chain window: finisher.
+        run_ticks(&mut world, combo.strikes[1].total_ticks());
+        press(&mut world, attacker);
+        assert_eq!(world.get::<MeleeState>(attacker).unwrap().swing.as_ref().unwrap().step, 2);
+
+        // Finish, wait out the window: back to the first strike.
+        run_ticks(&mut world, combo.strikes[2].total_ticks() + combo.chain_window_ticks);
+        press(&mut world, attacker);
+        assert_eq!(world.get::<MeleeState>(attacker).unwrap().swing.as_ref().unwrap().step, 0);
+    }
+
+    #[test]
+    fn reloading_blocks_melee() {
+        let mut world = setup_world();
+        let attacker = spawn_attacker(&mut world);
+        world.entity_mut(attacker).insert(Reloading {
+            weapon_id: "pistol".into(),
+            timer: Timer::from_seconds(1.0, TimerMode::Once),
+        });
+
+        press(&mut world, attacker);
+
+        assert!(!world.get::<MeleeState>(attacker).unwrap().is_swinging());
+    }
+}
@@ -0,0 +1,45 @@
+// src/features/menu/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GameState, HOTKEYS, RunStarted, in_input_context};
+use crate::features::dungeon::systems::GenerateDungeon;
+use crate::features::settings::systems::SaveSettings;
+
+pub mod ui;
+
+/// Main menu.
+///
+/// Scope:
+/// - `M` opens the menu from play (`GameState::Menu`) and closes it again
+/// - buttons: resume, play (`RunStarted` for the `GameMode` picked with the mode
+///   button, saved in `UserSettings`), "Daily seed" dungeon run (`GenerateDungeon` with today's seed),
+///   difficulty preset (saved in `UserSettings`, locked in when a run starts), UI scale,
+///   text size and flashes & shake (saved, applied live)
+/// - keyboard / gamepad navigation comes from the accessibility feature
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ui::MenuKeybinding>();
+        app.add_message::<GenerateDungeon>();
+        app.add_message::<RunStarted>();
+        app.add_message::<SaveSettings>();
+
+        app.add_systems(Startup, ui::spawn_main_menu);
+        app.add_systems(
+            Update,
+            ui::toggle_main_menu
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(
+            Update,
+            (ui::click_main_menu, ui::update_menu_labels)
+                .chain()
+                .after(AppSet::Input)
+                .run_if(in_state(GameState::Menu)),
+        );
+        app.add_systems(OnEnter(GameState::Menu), ui::show_main_menu);
+        app.add_systems(OnExit(GameState::Menu), ui::hide_main_menu);
+    }
+}
@@ -0,0 +1,354 @@
+// src/features/menu/ui.rs
+use bevy::prelude::*;
+
+use crate::app::{GameState, GameplayTuning, RunStarted};
+use crate::features::dungeon::component::daily_seed;
+use crate::features::dungeon::systems::GenerateDungeon;
+use crate::features::leaderboard::component::unix_secs;
+use crate::features::settings::component::{AccessibilitySettings, UserSettings};
+use crate::features::settings::systems::SaveSettings;
+
+/// Key that opens / closes the main menu while playing.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MenuKeybinding(pub KeyCode);
+
+impl Default for MenuKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::KeyM)
+    }
+}
+
+/// What a main menu button does.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuAction {
+    Resume,
+    /// Start a new run of the picked mode.
+    Play,
+    /// Cycle the mode "Play" starts (saved).
+    Mode,
+    /// Generate today's dungeon (same seed for everyone that day) and play it.
+    DailyRun,
+    /// Cycle the difficulty preset (saved; applies from the next run on).
+    Difficulty,
+    /// Cycle the UI scale (saved; applies immediately).
+    UiScale,
+    /// Cycle the text size (saved; applies immediately).
+    TextSize,
+    /// Cycle shake / flash strength (saved; applies immediately).
+    Effects,
+}
+
+impl MenuAction {
+    pub const ALL: [Self; 8] = [
+        Self::Resume,
+        Self::Play,
+        Self::Mode,
+        Self::DailyRun,
+        Self::Difficulty,
+        Self::UiScale,
+        Self::TextSize,
+        Self::Effects,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Resume => "Resume",
+            Self::Play => "Play",
+            Self::Mode => "Mode",
+            Self::DailyRun => "Daily seed",
+            Self::Difficulty => "Difficulty",
+            Self::UiScale => "UI scale",
+            Self::TextSize => "Text size",
+            Self::Effects => "Flashes & shake",
+        }
+    }
+
+    /// Button text; settings buttons show their current value ("Difficulty: Normal").
+    pub fn text(self, settings: &UserSettings) -> String {
+        let percent = |scale: f32| format!("{}: {:.0}%", self.label(), scale * 100.0);
+        match self {
+            Self::Mode => format!("{}: {}", self.label(), settings.game_mode.label()),
+            Self::Difficulty => format!("{}: {}", self.label(), settings.difficulty.label()),
+            Self::UiScale => percent(settings.accessibility.ui_scale()),
+            Self::TextSize => percent(settings.accessibility.font_scale()),
+            Self::Effects => format!("{}: {}", self.label(), settings.accessibility.effects.label()),
+            Self::Resume | Self::Play | Self::DailyRun => self.label().to_string(),
+        }
+    }
+}
+
+/// Text of a main menu button, kept in sync with the settings it shows.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MenuLabel(pub MenuAction);
+
+/// Full-screen main menu root (visible only in `GameState::Menu`).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct MainMenuScreen;
+
+/// Startup: spawn the (hidden) main menu.
+pub fn spawn_main_menu(mut commands: Commands) {
+    commands
+        .spawn((
+            MainMenuScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.85)),
+            GlobalZIndex(9),
+            Visibility::Hidden,
+            Name::new("MainMenu"),
+        ))
+        .with_children(|screen| {
+            screen.spawn((Text::new("To Be Free"), TextFont::from_font_size(32.0)));
+            for action in MenuAction::ALL {
+                screen
+                    .spawn((
+                        Button,
+                        action,
+                        Node {
+                            width: Val::Px(220.0),
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            MenuLabel(action),
+                            Text::new(action.label()),
+                            TextFont::from_font_size(18.0),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Update: the menu key opens the menu from play and closes it again.
+pub fn toggle_main_menu(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<MenuKeybinding>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if !keyboard.just_pressed(binding.0) {
+        return;
+    }
+    match state.get() {
+        GameState::Playing => next.set(GameState::Menu),
+        GameState::Menu => next.set(GameState::Playing),
+        GameState::Paused | GameState::Onboarding => {}
+    }
+}
+
+/// Update (menu): run the clicked button's action.
+///
+/// A new run (play, daily seed) locks in the chosen difficulty; resuming keeps the
+/// current run's tuning.
+pub fn click_main_menu(
+    q_buttons: Query<(&Interaction, &MenuAction), Changed<Interaction>>,
+    mut settings: ResMut<UserSettings>,
+    mut tuning: ResMut<GameplayTuning>,
+    mut next: ResMut<NextState<GameState>>,
+    mut generate: MessageWriter<GenerateDungeon>,
+    mut started: MessageWriter<RunStarted>,
+    mut save: MessageWriter<SaveSettings>,
+) {
+    for (interaction, action) in &q_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            MenuAction::Resume => next.set(GameState::Playing),
+            MenuAction::Play => {
+                *tuning = GameplayTuning::for_difficulty(settings.difficulty);
+                started.write(RunStarted {
+                    mode: settings.game_mode,
+                });
+                next.set(GameState::Playing);
+            }
+            MenuAction::Mode => {
+                settings.game_mode = settings.game_mode.next();
+                save.write(SaveSettings);
+            }
+            MenuAction::DailyRun => {
+                *tuning = GameplayTuning::for_difficulty(settings.difficulty);
+                generate.write(GenerateDungeon {
+                    seed: daily_seed(unix_secs()),
+                });
+                next.set(GameState::Playing);
+            }
+            MenuAction::Difficulty => {
+                settings.difficulty = settings.difficulty.next();
+                save.write(SaveSettings);
+            }
+            MenuAction::UiScale => {
+                let scale = &mut settings.accessibility.ui_scale;
+                *scale = AccessibilitySettings::next_scale(*scale);
+                save.write(SaveSettings);
+            }
+            MenuAction::TextSize => {
+                let scale = &mut settings.accessibility.font_scale;
+                *scale = AccessibilitySettings::next_scale(*scale);
+                save.write(SaveSettings);
+            }
+            MenuAction::Effects => {
+                settings.accessibility.effects = settings.accessibility.effects.next();
+                save.write(SaveSettings);
+            }
+        }
+    }
+}
+
+/// Update: keep the buttons' texts in sync with the settings.
+pub fn update_menu_labels(settings: Res<UserSettings>, mut q_labels: Query<(&mut Text, &MenuLabel)>) {
+    for (mut text, label) in &mut q_labels {
+        let current = label.0.text(&settings);
+        if text.0 != current {
+            text.0 = current;
+        }
+    }
+}
+
+/// OnEnter / OnExit(Menu): show or hide the menu.
+pub fn show_main_menu(mut q_screen: Query<&mut Visibility, With<MainMenuScreen>>) {
+    for mut vis in &mut q_screen {
+        *vis = Visibility::Inherited;
+    }
+}
+
+pub fn hide_main_menu(mut q_screen: Query<&mut Visibility, With<MainMenuScreen>>) {
+    for mut vis in &mut q_screen {
+        *vis = Visibility::Hidden;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn world_with_menu_resources() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<GenerateDungeon>>();
+        world.init_resource::<Messages<RunStarted>>();
+        world.init_resource::<Messages<SaveSettings>>();
+        world.init_resource::<NextState<GameState>>();
+        world.init_resource::<UserSettings>();
+        world.init_resource::<GameplayTuning>();
+        world
+    }
+
+    #[test]
+    fn daily_run_button_generates_todays_dungeon_and_resumes() {
+        let mut world = world_with_menu_resources();
+        world.resource_mut::<UserSettings>().difficulty = crate::app::Difficulty::Hard;
+        world.spawn((Button, MenuAction::DailyRun, Interaction::Pressed));
+
+        let _ = world.run_system_once(click_main_menu);
+
+        let generated: Vec<_> = world
+            .resource::<Messages<GenerateDungeon>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(generated, [GenerateDungeon { seed: daily_seed(unix_secs()) }]);
+        assert!(matches!(
+            *world.resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Playing)
+        ));
+        assert_eq!(
+            world.resource::<GameplayTuning>().difficulty,
+            crate::app::Difficulty::Hard
+        );
+    }
+
+    #[test]
+    fn play_button_starts_a_run_of_the_picked_mode() {
+        let mut world = world_with_menu_resources();
+        world.spawn((Button, MenuAction::Mode, Interaction::Pressed));
+        world.spawn((Text::new(""), MenuLabel(MenuAction::Mode)));
+        let _ = world.run_system_once(click_main_menu);
+        let _ = world.run_system_once(update_menu_labels);
+        let label = world.query::<&Text>().single(&world).unwrap();
+        assert_eq!(label.0, "Mode: Survival");
+
+        world.spawn((Button, MenuAction::Play, Interaction::Pressed));
+        let mut q_mode = world.query::<(&mut Interaction, &MenuAction)>();
+        for (mut interaction, action) in q_mode.iter_mut(&mut world) {
+            if *action == MenuAction::Mode {
+                *interaction = Interaction::None;
+            }
+        }
+        let _ = world.run_system_once(click_main_menu);
+
+        let started: Vec<_> = world
+            .resource::<Messages<RunStarted>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(started, [RunStarted { mode: crate::app::GameMode::Survival }]);
+        assert!(matches!(
+            *world.resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Playing)
+        ));
+    }
+
+    #[test]
+    fn difficulty_button_cycles_and_saves_without_touching_the_run() {
+        let mut world = world_with_menu_resources();
+        world.spawn((Button, MenuAction::Difficulty, Interaction::Pressed));
+        world.spawn((Text::new(""), MenuLabel(MenuAction::Difficulty)));
+
+        let _ = world.run_system_once(click_main_menu);
+        let _ = world.run_system_once(update_menu_labels);
+
+        assert_eq!(world.resource::<UserSettings>().difficulty, crate::app::Difficulty::Hard);
+        assert_eq!(world.resource::<Messages<SaveSettings>>().len(), 1);
+        assert_eq!(*world.resource::<GameplayTuning>(), GameplayTuning::default());
+        let label = world.query::<&Text>().single(&world).unwrap();
+        assert_eq!(label.0, "Difficulty: Hard");
+    }
+
+    #[test]
+    fn scale_buttons_step_the_accessibility_settings() {
+        let mut world = world_with_menu_resources();
+        world.spawn((Button, MenuAction::UiScale, Interaction::Pressed));
+        world.spawn((Button, MenuAction::TextSize, Interaction::Pressed));
+        world.spawn((Text::new(""), MenuLabel(MenuAction::TextSize)));
+
+        let _ = world.run_system_once(click_main_menu);
+        let _ = world.run_system_once(update_menu_labels);
+
+        let accessibility = world.resource::<UserSettings>().accessibility;
+        assert_eq!((accessibility.ui_scale, accessibility.font_scale), (1.25, 1.25));
+        assert_eq!(world.resource::<Messages<SaveSettings>>().len(), 2);
+        let label = world.query::<&Text>().single(&world).unwrap();
+        assert_eq!(label.0, "Text size: 125%");
+    }
+
+    #[test]
+    fn effects_button_cycles_the_photosensitivity_option() {
+        let mut world = world_with_menu_resources();
+        world.spawn((Button, MenuAction::Effects, Interaction::Pressed));
+        world.spawn((Text::new(""), MenuLabel(MenuAction::Effects)));
+
+        let _ = world.run_system_once(click_main_menu);
+        let _ = world.run_system_once(update_menu_labels);
+
+        assert_eq!(
+            world.resource::<UserSettings>().accessibility.effects,
+            crate::app::EffectsLevel::Reduced
+        );
+        let label = world.query::<&Text>().single(&world).unwrap();
+        assert_eq!(label.0, "Flashes & shake: Reduced");
+    }
+}
@@ -0,0 +1,62 @@
+// src/features/meshes/component.rs
+use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+/// Shapes the library shares; all unit sized, scaled per entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeshPrimitive {
+    /// 1 x 1 x 1 cube centered on the origin.
+    Cube,
+    /// Radius 1 disc lying flat in XZ, facing up.
+    Disc,
+    /// Radius 0.5 capsule, 2 tall, standing on Y.
+    Capsule,
+    /// Radius 1 sphere.
+    Sphere,
+}
+
+impl MeshPrimitive {
+    pub const ALL: [Self; 4] = [Self::Cube, Self::Disc, Self::Capsule, Self::Sphere];
+
+    /// Build the primitive's mesh.
+    pub fn mesh(self) -> Mesh {
+        match self {
+            Self::Cube => Cuboid::from_length(1.0).into(),
+            Self::Disc => Mesh::from(Circle::new(1.0)).rotated_by(Quat::from_rotation_x(-FRAC_PI_2)),
+            Self::Capsule => Capsule3d::new(0.5, 1.0).into(),
+            Self::Sphere => Sphere::new(1.0).into(),
+        }
+    }
+}
+
+/// Shared meshes for the common primitives.
+///
+/// Built once in `PreStartup`; until then (or in apps without `MeshesPlugin`)
+/// `get` hands out the default handle, which renders nothing.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MeshLibrary {
+    handles: HashMap<MeshPrimitive, Handle<Mesh>>,
+}
+
+impl MeshLibrary {
+    /// Add one asset per primitive (once; later calls keep the existing handles).
+    pub fn build(&mut self, meshes: &mut Assets<Mesh>) {
+        for kind in MeshPrimitive::ALL {
+            self.handles.entry(kind).or_insert_with(|| meshes.add(kind.mesh()));
+        }
+    }
+
+    pub fn get(&self, kind: MeshPrimitive) -> Handle<Mesh> {
+        self.handles.get(&kind).cloned().unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
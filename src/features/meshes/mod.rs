@@ -0,0 +1,26 @@
+// src/features/meshes/mod.rs
+use bevy::prelude::*;
+
+pub mod component;
+pub mod systems;
+
+/// Mesh library.
+///
+/// Scope:
+/// - `MeshLibrary`: one shared `Mesh` asset per common primitive (unit cube, ground
+///   disc, capsule, sphere), created in `PreStartup`
+/// - spawners size the shared primitive with their `Transform` scale
+///
+/// Design constraints:
+/// - Repeated spawns (projectiles, debris, placed structures) reuse the library's
+///   handles instead of `meshes.add`-ing one asset per entity.
+/// - Colliders ignore scale, so scaling a visual never changes gameplay.
+pub struct MeshesPlugin;
+
+impl Plugin for MeshesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::MeshLibrary>();
+
+        app.add_systems(PreStartup, systems::build_mesh_library);
+    }
+}
@@ -0,0 +1,34 @@
+// src/features/meshes/systems.rs
+use bevy::prelude::*;
+
+use super::component::MeshLibrary;
+
+/// PreStartup: create the shared meshes before anything spawns with them.
+pub fn build_mesh_library(mut library: ResMut<MeshLibrary>, mut meshes: ResMut<Assets<Mesh>>) {
+    library.build(&mut meshes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::meshes::component::MeshPrimitive;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn each_primitive_is_one_asset_however_often_it_is_built() {
+        let mut world = World::new();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Assets<Mesh>>();
+        let _ = world.run_system_once(build_mesh_library);
+        let _ = world.run_system_once(build_mesh_library);
+
+        let library = world.resource::<MeshLibrary>();
+        assert_eq!(library.len(), MeshPrimitive::ALL.len());
+        assert_eq!(world.resource::<Assets<Mesh>>().len(), MeshPrimitive::ALL.len());
+        let meshes = world.resource::<Assets<Mesh>>();
+        for kind in MeshPrimitive::ALL {
+            assert!(meshes.get(&library.get(kind)).is_some(), "{kind:?}");
+        }
+        assert_ne!(library.get(MeshPrimitive::Cube), library.get(MeshPrimitive::Sphere));
+    }
+}
@@ -0,0 +1,221 @@
+// src/features/minimap/component.rs
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::StoragePaths;
+use crate::features::save::migrate::{Migration, from_ron_with};
+
+/// Minimap layout and exploration tuning.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct MinimapSettings {
+    /// The map covers `-half_extent..half_extent` on X and Z (world units).
+    pub half_extent: f32,
+    /// Mask / texture cells per side.
+    pub cells: u32,
+    /// Ground revealed around the player (world units).
+    pub reveal_radius: f32,
+    /// Panel size (logical pixels).
+    pub panel_px: f32,
+    pub explored_color: Color,
+    pub unexplored_color: Color,
+    /// Seconds between saves while exploration keeps changing.
+    pub save_interval_secs: f32,
+}
+
+impl Default for MinimapSettings {
+    fn default() -> Self {
+        Self {
+            half_extent: 64.0,
+            cells: 128,
+            reveal_radius: 10.0,
+            panel_px: 160.0,
+            explored_color: Color::srgb(0.35, 0.5, 0.3),
+            unexplored_color: Color::srgb(0.04, 0.04, 0.06),
+            save_interval_secs: 5.0,
+        }
+    }
+}
+
+/// Current exploration file format; bump it (and register a migration) on every
+/// layout change.
+pub const EXPLORATION_VERSION: u32 = 1;
+
+/// Every exploration file migration, oldest first (see `save::migrate`).
+pub const EXPLORATION_MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    summary: "version header added",
+    apply: |_| Ok(()),
+}];
+
+/// Which minimap cells the player has seen (a bitset, row-major, +Z rows down).
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExplorationMask {
+    /// Format the file was written in (missing = 0, the unversioned layout).
+    #[serde(default)]
+    pub version: u32,
+    pub cells: u32,
+    pub words: Vec<u64>,
+}
+
+impl Default for ExplorationMask {
+    fn default() -> Self {
+        Self::new(MinimapSettings::default().cells)
+    }
+}
+
+impl ExplorationMask {
+    pub fn new(cells: u32) -> Self {
+        let bits = (cells * cells) as usize;
+        Self {
+            version: EXPLORATION_VERSION,
+            cells,
+            words: vec![0; bits.div_ceil(64)],
+        }
+    }
+
+    pub fn is_explored(&self, x: u32, y: u32) -> bool {
+        if x >= self.cells || y >= self.cells {
+            return false;
+        }
+        let bit = (y * self.cells + x) as usize;
+        self.words[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    fn mark(&mut self, x: u32, y: u32) -> bool {
+        let bit = (y * self.cells + x) as usize;
+        let (word, mask) = (&mut self.words[bit / 64], 1u64 << (bit % 64));
+        let fresh = *word & mask == 0;
+        *word |= mask;
+        fresh
+    }
+
+    /// Cell (fractional) of a world position on a map spanning `±half_extent`.
+    pub fn cell_of(&self, position: Vec3, half_extent: f32) -> Vec2 {
+        let size = half_extent * 2.0 / self.cells as f32;
+        Vec2::new(position.x + half_extent, position.z + half_extent) / size
+    }
+
+    /// Mark every cell within `radius` of `position`; `true` if any was new.
+    pub fn reveal(&mut self, position: Vec3, radius: f32, half_extent: f32) -> bool {
+        let center = self.cell_of(position, half_extent);
+        let r = radius / (half_extent * 2.0 / self.cells as f32);
+        let max = self.cells as f32 - 1.0;
+        let (x0, x1) = ((center.x - r).floor().max(0.0), (center.x + r).ceil().min(max));
+        let (y0, y1) = ((center.y - r).floor().max(0.0), (center.y + r).ceil().min(max));
+        if x0 > x1 || y0 > y1 {
+            return false;
+        }
+        let mut changed = false;
+        for y in y0 as u32..=y1 as u32 {
+            for x in x0 as u32..=x1 as u32 {
+                let cell_center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if cell_center.distance(center) <= r {
+                    changed |= self.mark(x, y);
+                }
+            }
+        }
+        changed
+    }
+
+    /// Share of the map explored (`0..1`).
+    pub fn explored_fraction(&self) -> f32 {
+        let explored: u32 = self.words.iter().map(|w| w.count_ones()).sum();
+        explored as f32 / (self.cells * self.cells) as f32
+    }
+
+    /// Stored mask (any known version), or an empty one (also when it was saved with
+    /// another size).
+    pub fn load(path: &Path, cells: u32) -> Self {
+        let Ok(source) = std::fs::read_to_string(path) else {
+            return Self::new(cells);
+        };
+        match from_ron_with::<Self>(&source, EXPLORATION_MIGRATIONS, EXPLORATION_VERSION) {
+            Ok(mask) if mask.cells == cells && mask.words.len() == Self::new(cells).words.len() => mask,
+            Ok(_) => Self::new(cells),
+            Err(err) => {
+                warn!("ignoring unreadable {}: {err}", path.display());
+                Self::new(cells)
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let current = Self {
+            version: EXPLORATION_VERSION,
+            ..self.clone()
+        };
+        let text = ron::to_string(&current).map_err(std::io::Error::other)?;
+        std::fs::write(path, text)
+    }
+}
+
+/// Where `ExplorationMask` is saved.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct ExplorationFile(pub PathBuf);
+
+impl FromWorld for ExplorationFile {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).data_file("exploration.ron"))
+    }
+}
+
+/// Unsaved exploration and the time since the last save.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExplorationSaveState {
+    pub dirty: bool,
+    pub since_save_secs: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_marks_a_disc_once() {
+        let mut mask = ExplorationMask::new(16);
+        // 16 cells over 32 units: 2 units per cell; origin is cell (8, 8).
+        assert!(mask.reveal(Vec3::ZERO, 3.0, 16.0));
+        assert!(mask.is_explored(8, 8));
+        assert!(mask.is_explored(7, 7));
+        assert!(!mask.is_explored(0, 0));
+        assert!(!mask.reveal(Vec3::ZERO, 3.0, 16.0), "already explored");
+        assert!(!mask.reveal(Vec3::new(500.0, 0.0, 0.0), 3.0, 16.0), "off the map");
+    }
+
+    #[test]
+    fn mask_round_trips_and_rejects_other_sizes() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_exploration_{}", std::process::id()))
+            .join("exploration.ron");
+        let mut mask = ExplorationMask::new(16);
+        mask.reveal(Vec3::new(4.0, 0.0, -4.0), 5.0, 16.0);
+        mask.save(&path).unwrap();
+
+        assert_eq!(ExplorationMask::load(&path, 16), mask);
+        assert_eq!(ExplorationMask::load(&path, 32), ExplorationMask::new(32));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn unversioned_mask_loads() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_exploration_v0_{}", std::process::id()))
+            .join("exploration.ron");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, include_str!("../../../tests/fixtures/saves/exploration_v0.ron")).unwrap();
+
+        let mask = ExplorationMask::load(&path, 8);
+
+        assert_eq!(mask.version, EXPLORATION_VERSION);
+        assert!(mask.is_explored(0, 0));
+        assert!(mask.is_explored(1, 0));
+        assert!(!mask.is_explored(2, 0));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
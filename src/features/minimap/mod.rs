@@ -0,0 +1,47 @@
+// src/features/minimap/mod.rs
+use bevy::prelude::*;
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Minimap with fog of war.
+///
+/// Scope:
+/// - `ExplorationMask`: a cell grid over the map area; cells within
+///   `reveal_radius` of the player become explored as they move
+/// - the minimap panel shows the mask as a texture (unexplored ground stays dark)
+///   plus the player's position
+/// - exploration is saved to the exploration file (throttled while it changes, and
+///   on exit) and restored at startup; the file is versioned like the game save
+///   (`EXPLORATION_VERSION`, `EXPLORATION_MIGRATIONS`)
+///
+/// Design constraints:
+/// - Presentation only: the mask is never used to gate gameplay.
+/// - The texture is repainted only when the mask changed.
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::MinimapSettings>();
+        app.init_resource::<component::ExplorationMask>();
+        app.init_resource::<component::ExplorationFile>();
+        app.init_resource::<component::ExplorationSaveState>();
+
+        app.add_systems(
+            Startup,
+            (systems::load_exploration, ui::spawn_minimap).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::reveal_around_player,
+                systems::save_exploration,
+                ui::redraw_minimap,
+                ui::update_minimap_marker,
+            )
+                .chain(),
+        );
+        app.add_systems(Last, systems::save_exploration_on_exit);
+    }
+}
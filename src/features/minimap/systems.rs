@@ -0,0 +1,99 @@
+// src/features/minimap/systems.rs
+use bevy::prelude::*;
+
+use super::component::{ExplorationFile, ExplorationMask, ExplorationSaveState, MinimapSettings};
+use crate::features::player::component::Player;
+
+/// Startup: restore the explored area.
+pub fn load_exploration(
+    file: Res<ExplorationFile>,
+    settings: Res<MinimapSettings>,
+    mut mask: ResMut<ExplorationMask>,
+) {
+    *mask = ExplorationMask::load(&file.0, settings.cells);
+}
+
+/// Update: reveal the ground around the player (the mask only counts as changed
+/// when new cells were uncovered).
+pub fn reveal_around_player(
+    settings: Res<MinimapSettings>,
+    mut mask: ResMut<ExplorationMask>,
+    mut save: ResMut<ExplorationSaveState>,
+    q_player: Query<&GlobalTransform, With<Player>>,
+) {
+    for transform in &q_player {
+        let position = transform.translation();
+        if mask
+            .bypass_change_detection()
+            .reveal(position, settings.reveal_radius, settings.half_extent)
+        {
+            mask.set_changed();
+            save.dirty = true;
+        }
+    }
+}
+
+/// Update: save new exploration at most every `save_interval_secs`.
+pub fn save_exploration(
+    time: Res<Time<Real>>,
+    settings: Res<MinimapSettings>,
+    file: Res<ExplorationFile>,
+    mask: Res<ExplorationMask>,
+    mut save: ResMut<ExplorationSaveState>,
+) {
+    save.since_save_secs += time.delta_secs();
+    if !save.dirty || save.since_save_secs < settings.save_interval_secs {
+        return;
+    }
+    if let Err(err) = mask.save(&file.0) {
+        warn!("could not save {}: {err}", file.0.display());
+    }
+    *save = ExplorationSaveState::default();
+}
+
+/// Last: keep exploration made since the last save.
+pub fn save_exploration_on_exit(
+    mut exit: MessageReader<AppExit>,
+    file: Res<ExplorationFile>,
+    mask: Res<ExplorationMask>,
+    save: Res<ExplorationSaveState>,
+) {
+    if exit.read().count() > 0
+        && save.dirty
+        && let Err(err) = mask.save(&file.0)
+    {
+        warn!("could not save {}: {err}", file.0.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn walking_reveals_and_saves_after_the_interval() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_minimap_{}", std::process::id()));
+        let mut world = World::new();
+        world.init_resource::<MinimapSettings>();
+        world.init_resource::<ExplorationMask>();
+        world.init_resource::<ExplorationSaveState>();
+        world.insert_resource(ExplorationFile(dir.join("exploration.ron")));
+        world.insert_resource(Time::<Real>::default());
+        world.spawn((Player, GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 5.0))));
+
+        let _ = world.run_system_once(reveal_around_player);
+        assert!(world.resource::<ExplorationMask>().explored_fraction() > 0.0);
+        assert!(world.resource::<ExplorationSaveState>().dirty);
+
+        let _ = world.run_system_once(save_exploration);
+        assert!(!dir.join("exploration.ron").exists(), "throttled");
+
+        world.resource_mut::<ExplorationSaveState>().since_save_secs = 10.0;
+        let _ = world.run_system_once(save_exploration);
+        let saved = ExplorationMask::load(&dir.join("exploration.ron"), 128);
+        assert_eq!(&saved, world.resource::<ExplorationMask>());
+        assert!(!world.resource::<ExplorationSaveState>().dirty);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
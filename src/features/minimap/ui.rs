@@ -0,0 +1,149 @@
+// src/features/minimap/ui.rs
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use super::component::{ExplorationMask, MinimapSettings};
+use crate::features::player::component::Player;
+
+/// Texture the minimap panel shows (one pixel per mask cell).
+#[derive(Resource, Debug, Clone)]
+pub struct MinimapImage(pub Handle<Image>);
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct MinimapPanel;
+
+/// Dot marking the player on the minimap.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct MinimapPlayerMarker;
+
+const MARKER_PX: f32 = 6.0;
+
+/// Startup: the map texture and the panel in the top-right corner (headless apps
+/// without image assets get the panel only).
+pub fn spawn_minimap(
+    mut commands: Commands,
+    settings: Res<MinimapSettings>,
+    images: Option<ResMut<Assets<Image>>>,
+) {
+    let image = images.map(|mut images| {
+        let handle = images.add(Image::new_fill(
+            Extent3d {
+                width: settings.cells,
+                height: settings.cells,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &settings.unexplored_color.to_srgba().to_u8_array(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+        commands.insert_resource(MinimapImage(handle.clone()));
+        handle
+    });
+
+    let mut panel = commands.spawn((
+        MinimapPanel,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            right: Val::Px(12.0),
+            width: Val::Px(settings.panel_px),
+            height: Val::Px(settings.panel_px),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+        Name::new("Minimap"),
+    ));
+    if let Some(image) = image {
+        panel.insert(ImageNode::new(image));
+    }
+    panel.with_child((
+        MinimapPlayerMarker,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(MARKER_PX),
+            height: Val::Px(MARKER_PX),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(1.0, 0.9, 0.3)),
+    ));
+}
+
+/// Update: repaint the texture when exploration changed (explored cells light,
+/// the rest dark).
+pub fn redraw_minimap(
+    settings: Res<MinimapSettings>,
+    mask: Res<ExplorationMask>,
+    image: Option<Res<MinimapImage>>,
+    images: Option<ResMut<Assets<Image>>>,
+) {
+    let (Some(image), Some(mut images)) = (image, images) else {
+        return;
+    };
+    if !mask.is_changed() && !image.is_added() {
+        return;
+    }
+    let Some(texture) = images.get_mut(&image.0) else {
+        return;
+    };
+    for y in 0..mask.cells {
+        for x in 0..mask.cells {
+            let color = if mask.is_explored(x, y) {
+                settings.explored_color
+            } else {
+                settings.unexplored_color
+            };
+            let _ = texture.set_color_at(x, y, color);
+        }
+    }
+}
+
+/// Update: place the player dot.
+pub fn update_minimap_marker(
+    settings: Res<MinimapSettings>,
+    mask: Res<ExplorationMask>,
+    q_player: Query<&GlobalTransform, With<Player>>,
+    mut q_marker: Query<&mut Node, With<MinimapPlayerMarker>>,
+) {
+    let Some(position) = q_player.iter().next().map(GlobalTransform::translation) else {
+        return;
+    };
+    let uv = (mask.cell_of(position, settings.half_extent) / mask.cells as f32).clamp(Vec2::ZERO, Vec2::ONE);
+    for mut node in &mut q_marker {
+        node.left = Val::Px(uv.x * settings.panel_px - MARKER_PX * 0.5);
+        node.top = Val::Px(uv.y * settings.panel_px - MARKER_PX * 0.5);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn explored_cells_are_painted_light() {
+        let mut world = World::new();
+        let settings = MinimapSettings {
+            cells: 8,
+            half_extent: 8.0,
+            ..default()
+        };
+        world.insert_resource(settings);
+        world.init_resource::<Assets<Image>>();
+        let mut mask = ExplorationMask::new(8);
+        mask.reveal(Vec3::ZERO, 3.0, 8.0);
+        world.insert_resource(mask);
+
+        let _ = world.run_system_once(spawn_minimap);
+        let _ = world.run_system_once(redraw_minimap);
+
+        let handle = world.resource::<MinimapImage>().0.clone();
+        let images = world.resource::<Assets<Image>>();
+        let texture = images.get(&handle).unwrap();
+        let explored = texture.get_color_at(4, 4).unwrap().to_srgba();
+        let dark = texture.get_color_at(0, 0).unwrap().to_srgba();
+        assert!(explored.green > dark.green + 0.2, "{explored:?} vs {dark:?}");
+    }
+}
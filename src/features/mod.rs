@@ -1,7 +1,79 @@
 // src/features/mod.rs
 use bevy::prelude::*;
 
+pub mod ability;
+pub mod accessibility;
+pub mod achievements;
+pub mod aim;
+pub mod animation;
+pub mod audio;
+pub mod biome;
+pub mod bug_report;
+pub mod building;
+pub mod camera;
+pub mod character_material;
+pub mod collision;
+pub mod companion;
+pub mod console;
+pub mod culling;
+pub mod defense;
+pub mod diagnostics;
+pub mod dialogue;
+pub mod dungeon;
+pub mod editor;
+pub mod enemy;
+pub mod explosion;
+pub mod fall_damage;
+pub mod frame_pacing;
+pub mod gameplay_log;
+pub mod harvest;
+pub mod hazard;
+pub mod health;
+pub mod hud;
+pub mod ik;
+pub mod inspector;
+pub mod interaction;
+pub mod inventory;
+pub mod leaderboard;
+pub mod lighting;
+pub mod ledge;
+pub mod lock_on;
+pub mod materials;
+pub mod melee;
+pub mod meshes;
+pub mod menu;
+pub mod minimap;
+pub mod movement;
+pub mod music;
+pub mod net;
+pub mod notifications;
+pub mod npc;
+pub mod objective;
+pub mod onboarding;
 pub mod player;
+pub mod portal;
+pub mod props;
+pub mod race;
+pub mod ragdoll;
+pub mod rotation;
+pub mod save;
+pub mod scenes;
+pub mod settings;
+pub mod shop;
+pub mod skills;
+pub mod status;
+pub mod stealth;
+pub mod surface;
+pub mod terrain;
+pub mod time_of_day;
+pub mod track;
+pub mod trigger;
+pub mod tutorial;
+pub mod wallet;
+pub mod water;
+pub mod weapon;
+pub mod weather;
+pub mod wind;
 
 /// Registers all gameplay feature plugins.
 ///
@@ -13,11 +85,121 @@ pub mod player;
 /// - toggle features for experiments
 /// - build different app modes (menu/gameplay/tests)
 /// - swap implementations (e.g. different player controllers)
+///
+/// Every feature plugin also runs on its own (tests, minimal apps): it initializes
+/// the resources and registers the messages it uses even when another feature owns
+/// them (the `MeshLibrary` built by `MeshesPlugin`, the app's `SimTick`, ...). Both
+/// are idempotent, so the owner's setup is unaffected.
 pub struct FeaturesPlugin;
 
 impl Plugin for FeaturesPlugin {
     fn build(&self, app: &mut App) {
-        // Minimal feature set for now: user-controlled player movement.
-        app.add_plugins(player::PlayerPlugin);
+        // Gameplay. Health/damage first: other features react to its messages.
+        app.add_plugins((
+            health::HealthPlugin,
+            player::PlayerPlugin,
+            movement::MovementPlugin,
+            fall_damage::FallDamagePlugin,
+            ledge::LedgePlugin,
+            status::StatusEffectsPlugin,
+            ability::AbilityPlugin,
+            inventory::InventoryPlugin,
+            harvest::HarvestPlugin,
+            building::BuildingPlugin,
+            ragdoll::RagdollPlugin,
+            enemy::EnemyPlugin,
+            objective::ObjectivePlugin,
+            companion::CompanionPlugin,
+            stealth::StealthPlugin,
+        ));
+
+        // Combat.
+        app.add_plugins((
+            weapon::WeaponPlugin,
+            explosion::ExplosionPlugin,
+            melee::MeleePlugin,
+            defense::DefensePlugin,
+            lock_on::LockOnPlugin,
+            aim::AimPlugin,
+        ));
+
+        // Progression: skills, currency, achievements, run results and game saves.
+        app.add_plugins((
+            skills::SkillsPlugin,
+            wallet::WalletPlugin,
+            achievements::AchievementsPlugin,
+            leaderboard::LeaderboardPlugin,
+            save::SavePlugin,
+        ));
+
+        // World: terrain, biomes, dungeons, clock, weather, wind, moving geometry.
+        app.add_plugins((
+            terrain::TerrainPlugin,
+            biome::BiomePlugin,
+            dungeon::DungeonPlugin,
+            time_of_day::TimeOfDayPlugin,
+            weather::WeatherPlugin,
+            wind::WindPlugin,
+            track::TrackPlugin,
+        ));
+
+        // World contents: additively loaded scenes, portals, props, spinning / orbiting props,
+        // water, surfaces, triggers, race checkpoints, hazards, interaction, NPCs,
+        // conversations, shops and tutorials.
+        app.add_plugins((
+            scenes::ScenesPlugin,
+            portal::PortalPlugin,
+            props::PropsPlugin,
+            rotation::RotationPlugin,
+            water::WaterPlugin,
+            surface::SurfacePlugin,
+            trigger::TriggerPlugin,
+            race::RacePlugin,
+            hazard::HazardPlugin,
+            interaction::InteractionPlugin,
+            dialogue::DialoguePlugin,
+            npc::NpcPlugin,
+            shop::ShopPlugin,
+            tutorial::TutorialPlugin,
+        ));
+
+        // Presentation: mesh and material libraries, character materials, animation, camera,
+        // culling, light budget, HUD, minimap, notifications, audio, collision debug (read
+        // gameplay state, never drive it).
+        app.add_plugins((
+            meshes::MeshesPlugin,
+            materials::MaterialsPlugin,
+            character_material::CharacterMaterialPlugin,
+            ik::IkPlugin,
+            animation::CharacterAnimationPlugin,
+            camera::CameraPlugin,
+            culling::CullingPlugin,
+            lighting::LightingPlugin,
+            hud::HudPlugin,
+            minimap::MinimapPlugin,
+            notifications::NotificationsPlugin,
+            music::MusicPlugin,
+            audio::AudioMixerPlugin,
+            collision::CollisionDebugPlugin,
+        ));
+
+        // Shell: user settings (loaded before Startup), UI scale and menu navigation, frame
+        // pacing, the first-run onboarding, the main menu, the in-game editor, the developer
+        // console, the entity inspector, ECS diagnostics, the gameplay log, bug reports and
+        // the network layer's dev controls.
+        app.add_plugins((
+            settings::SettingsPlugin,
+            accessibility::AccessibilityPlugin,
+            frame_pacing::FramePacingPlugin,
+            onboarding::OnboardingPlugin,
+            menu::MenuPlugin,
+            editor::EditorPlugin,
+            console::ConsolePlugin,
+            inspector::InspectorPlugin,
+            diagnostics::EcsDiagnosticsPlugin,
+            gameplay_log::GameplayLogPlugin,
+            bug_report::BugReportPlugin,
+            net::NetPlugin,
+        ));
     }
 }
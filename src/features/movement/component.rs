@@ -0,0 +1,298 @@
+// src/features/movement/component.rs
+use bevy::prelude::*;
+
+/// How fast a mover goes along each local axis, in world units per second.
+///
+/// Separate horizontal and vertical speeds let ground movement, swimming and
+/// debug flight be tuned independently; `strafe` / `backward` scale sideways and
+/// backwards movement relative to `horizontal` (`1.0` = as fast as forwards).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MoveConfig {
+    pub horizontal: f32,
+    pub vertical: f32,
+    pub strafe: f32,
+    pub backward: f32,
+}
+
+impl MoveConfig {
+    /// Same speed along every axis and direction.
+    pub fn uniform(speed: f32) -> Self {
+        Self {
+            horizontal: speed,
+            vertical: speed,
+            strafe: 1.0,
+            backward: 1.0,
+        }
+    }
+
+    /// Local-space velocity for a local `MoveInput` (`+X` right, `-Z` forward).
+    pub fn local_velocity(&self, input: Vec3) -> Vec3 {
+        let along = if input.z > 0.0 { self.backward } else { 1.0 };
+        Vec3::new(
+            input.x * self.horizontal * self.strafe,
+            input.y * self.vertical,
+            input.z * self.horizontal * along,
+        )
+    }
+
+    /// Scale both speeds (multipliers stay relative).
+    pub fn scale(&mut self, factor: f32) {
+        self.horizontal *= factor;
+        self.vertical *= factor;
+    }
+}
+
+/// Multiplier applied on top of `MoveConfig` (status effects, aiming, crouching).
+///
+/// Rebuilt every tick: `reset_speed_multipliers` starts it at `1.0` (unmodified),
+/// then each feature with a speed modifier multiplies its factor in during
+/// `AppSet::ApplyForces`, before `compute_velocity_from_input` reads it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SpeedMultiplier(pub f32);
+
+impl Default for SpeedMultiplier {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Where a character's displacement comes from each fixed tick.
+///
+/// - `Analytic`: `MoveInput` scaled by `MoveConfig` (the default velocity pipeline).
+/// - `RootMotion`: the animation feature's `RootMotion` track writes `Velocity` instead;
+///   the analytic velocity step leaves the entity alone.
+///
+/// Either way `integrate_velocity` (and later collision) consumes `Velocity`, so both
+/// modes are reconciled by the same fixed-step integration.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MovementMode {
+    #[default]
+    Analytic,
+    RootMotion,
+}
+
+/// Local-space movement intent produced by input.
+///
+/// Coordinate conventions (Bevy-style):
+/// - +X: right
+/// - +Y: up
+/// - -Z: forward
+///
+/// This is an *intent*, not a velocity:
+/// - Its length is at most 1: the direction says where to go, the length how hard
+///   (a half-deflected stick is 0.5, keyboard input is always 0 or 1).
+/// - A separate FixedUpdate system converts it into world-space velocity,
+///   proportionally to the length.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MoveInput(pub Vec3);
+
+/// World-space velocity (units per second).
+///
+/// For now we integrate this directly into `Transform.translation` in FixedUpdate.
+/// Later, a physics/collision engine will own integration and write the transform.
+///
+/// Written by `compute_velocity_from_input` for movers with `MoveInput`; movers
+/// without intent (props, knocked-back dummies) keep it at zero or steer it
+/// themselves (NPC routines).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Velocity(pub Vec3);
+
+/// Mover that must not move this tick (dead, stunned, cutscene): the velocity
+/// and integration steps skip it entirely, pending pushes included.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MovementLocked;
+
+/// Mover that collides with level geometry (any `Collider`).
+///
+/// Each tick's displacement is swept as a box instead of applied blindly, so fast
+/// movers (dashes, knockback) stop at thin walls rather than tunnelling through, and
+/// slide along whatever they hit. It is not a `Collider` itself: nothing collides
+/// *with* the mover through it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SweptCollider {
+    /// Half size of the mover's box, centered on its translation.
+    pub half_extents: Vec3,
+    /// Obstacles reaching at most this far above the bottom of the box are walked
+    /// over (ground slabs, curbs, low platforms) instead of blocking.
+    pub step_height: f32,
+}
+
+impl Default for SweptCollider {
+    /// Character-sized: a unit box that steps over half-unit ledges.
+    fn default() -> Self {
+        Self {
+            half_extents: Vec3::splat(0.5),
+            step_height: 0.55,
+        }
+    }
+}
+
+impl SweptCollider {
+    /// Offset from the mover's translation to the center of the box that is swept,
+    /// and its half size: the bottom `step_height` is left out.
+    pub fn swept_box(&self) -> (Vec3, Vec3) {
+        let step = self
+            .step_height
+            .min(self.half_extents.y * 2.0 - MIN_SWEPT_HEIGHT)
+            .max(0.0);
+        (
+            Vec3::Y * step * 0.5,
+            self.half_extents - Vec3::Y * step * 0.5,
+        )
+    }
+}
+
+/// Thinnest swept box, however tall the step allowance.
+const MIN_SWEPT_HEIGHT: f32 = 0.05;
+
+/// Downward pull on a mover (world units/sec²), applied as acceleration on its
+/// `ExternalForce` at the start of the pipeline.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Gravity(pub f32);
+
+impl Default for Gravity {
+    fn default() -> Self {
+        Self(9.81)
+    }
+}
+
+/// Externally applied motion (knockback, explosions, wind), kept separate from `Velocity`.
+///
+/// Why a separate component:
+/// - `Velocity` is recomputed from input every tick, so writing to it from other
+///   features would be overwritten (or fight the input).
+/// - Features *add* to this component; the integrator consumes and decays it.
+///
+/// Integration contract (see `systems::integrate_velocity`):
+/// - `acceleration` is accumulated during the tick, folded into `velocity`, then cleared.
+/// - `velocity` is added on top of `Velocity` and decays exponentially by `damping` (1/sec).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ExternalForce {
+    /// Push velocity in world units per second.
+    pub velocity: Vec3,
+    /// Continuous acceleration for the current tick (world units per second²).
+    pub acceleration: Vec3,
+    /// Exponential decay rate of `velocity`, per second.
+    pub damping: f32,
+}
+
+impl ExternalForce {
+    /// Default decay rate: a knockback mostly fades within ~0.5s.
+    pub const DEFAULT_DAMPING: f32 = 6.0;
+
+    /// Instant change in push velocity (knockback, explosion).
+    pub fn apply_impulse(&mut self, delta_velocity: Vec3) {
+        self.velocity += delta_velocity;
+    }
+
+    /// Continuous push for this tick (wind, currents). Call every tick it should apply.
+    pub fn apply_acceleration(&mut self, acceleration: Vec3) {
+        self.acceleration += acceleration;
+    }
+}
+
+impl Default for ExternalForce {
+    fn default() -> Self {
+        Self {
+            velocity: Vec3::ZERO,
+            acceleration: Vec3::ZERO,
+            damping: Self::DEFAULT_DAMPING,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Compile-time trait contracts ---
+    // These tests won't run anything meaningful; they just ensure trait bounds hold.
+    fn assert_copy<T: Copy>() {}
+    fn assert_default<T: Default>() {}
+    fn assert_partial_eq<T: PartialEq>() {}
+    fn assert_component<T: Component>() {}
+
+    #[test]
+    fn trait_contracts_hold() {
+        assert_component::<MoveConfig>();
+        assert_copy::<MoveConfig>();
+        assert_partial_eq::<MoveConfig>();
+
+        assert_component::<SpeedMultiplier>();
+        assert_copy::<SpeedMultiplier>();
+        assert_default::<SpeedMultiplier>();
+
+        assert_component::<MovementMode>();
+        assert_copy::<MovementMode>();
+        assert_default::<MovementMode>();
+
+        assert_component::<MoveInput>();
+        assert_copy::<MoveInput>();
+        assert_partial_eq::<MoveInput>();
+
+        assert_component::<Velocity>();
+        assert_copy::<Velocity>();
+        assert_default::<Velocity>();
+        assert_partial_eq::<Velocity>();
+
+        assert_component::<MovementLocked>();
+        assert_copy::<MovementLocked>();
+
+        assert_component::<Gravity>();
+        assert_copy::<Gravity>();
+        assert_default::<Gravity>();
+
+        assert_component::<ExternalForce>();
+        assert_copy::<ExternalForce>();
+        assert_default::<ExternalForce>();
+        assert_partial_eq::<ExternalForce>();
+    }
+
+    // --- Minimal ECS sanity ---
+    #[test]
+    fn can_spawn_and_query_mover_components() {
+        let mut world = World::new();
+
+        let e = world.spawn((
+            MoveConfig::uniform(3.5),
+            MoveInput(Vec3::new(1.0, 0.0, 0.0)),
+            Velocity(Vec3::new(0.0, 0.0, -2.0)),
+        )).id();
+
+        let (speed, input, vel) = world
+            .query::<(&MoveConfig, &MoveInput, &Velocity)>()
+            .get(&world, e)
+            .expect("entity should have speed/input/velocity");
+
+        assert_eq!(*speed, MoveConfig::uniform(3.5));
+        assert_eq!(*input, MoveInput(Vec3::new(1.0, 0.0, 0.0)));
+        assert_eq!(*vel, Velocity(Vec3::new(0.0, 0.0, -2.0)));
+    }
+
+    #[test]
+    fn move_config_scales_each_axis_and_direction() {
+        let config = MoveConfig {
+            horizontal: 4.0,
+            vertical: 2.0,
+            strafe: 0.5,
+            backward: 0.25,
+        };
+        assert_eq!(config.local_velocity(Vec3::NEG_Z), Vec3::NEG_Z * 4.0);
+        assert_eq!(config.local_velocity(Vec3::Z), Vec3::Z * 1.0);
+        assert_eq!(config.local_velocity(Vec3::X), Vec3::X * 2.0);
+        assert_eq!(config.local_velocity(Vec3::Y), Vec3::Y * 2.0);
+    }
+
+    #[test]
+    fn external_force_accumulates_impulses_and_acceleration() {
+        let mut f = ExternalForce::default();
+
+        f.apply_impulse(Vec3::X);
+        f.apply_impulse(Vec3::Y);
+        f.apply_acceleration(Vec3::Z * 2.0);
+
+        assert_eq!(f.velocity, Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(f.acceleration, Vec3::Z * 2.0);
+        assert_eq!(f.damping, ExternalForce::DEFAULT_DAMPING);
+    }
+}
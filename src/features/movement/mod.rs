@@ -0,0 +1,56 @@
+// src/features/movement/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GameState};
+
+pub mod component;
+pub mod systems;
+
+/// Shared mover pipeline for anything with a `Velocity` and a `Transform`.
+///
+/// Scope:
+/// - FixedUpdate: `Gravity` -> `ExternalForce` acceleration
+/// - FixedUpdate: `MoveInput` (local intent) -> world `Velocity` via `MoveConfig`,
+///   scaled by the tick's `SpeedMultiplier`
+/// - FixedUpdate: `Velocity` + `ExternalForce` pushes -> `Transform.translation`,
+///   swept against colliders for `SweptCollider` movers (stop + slide, no tunnelling)
+///
+/// Design constraints:
+/// - Features only contribute to the pipeline: players and AI write `MoveInput` (or
+///   steer `Velocity` directly), knockback / wind / water add to `ExternalForce`.
+///   Nobody else integrates positions.
+/// - Extra steps join a stage of `AppSet::FixedMovement`: intent in `ReadIntent`,
+///   forces / velocity overrides in `ApplyForces` (after `compute_velocity_from_input`
+///   if they replace its velocity), follow-ups of the move in `PostMovement`.
+/// - Speed modifiers (slows, aiming, crouching) multiply into `SpeedMultiplier` in
+///   `ApplyForces`, after `reset_speed_multipliers` and before
+///   `compute_velocity_from_input`; movement doesn't know where they come from.
+/// - `MovementLocked` movers are skipped by every step.
+pub struct MovementPlugin;
+
+impl Plugin for MovementPlugin {
+    fn build(&self, app: &mut App) {
+        // Multipliers restart every tick, paused or not, so modifiers multiplying in
+        // never compound.
+        app.add_systems(
+            FixedUpdate,
+            systems::reset_speed_multipliers
+                .in_set(AppSet::ApplyForces)
+                .before(systems::compute_velocity_from_input),
+        );
+        // Gravity first, so velocity overrides later in the stage see the whole tick.
+        app.add_systems(
+            FixedUpdate,
+            (systems::apply_gravity, systems::compute_velocity_from_input)
+                .chain()
+                .in_set(AppSet::ApplyForces)
+                .run_if(in_state(GameState::Playing)),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::integrate_velocity
+                .in_set(AppSet::Integrate)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
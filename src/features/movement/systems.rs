@@ -0,0 +1,747 @@
+// src/features/movement/systems.rs
+use bevy::ecs::batching::BatchingStrategy;
+use bevy::prelude::*;
+
+use super::component::{
+    ExternalForce, Gravity, MoveConfig, MoveInput, MovementLocked, MovementMode, SpeedMultiplier,
+    SweptCollider, Velocity,
+};
+use crate::app::SimMath;
+use crate::features::collision::query::CollisionWorld;
+
+/// Batching strategy shared by the hot FixedUpdate mover loops.
+///
+/// - Per-entity work here is a handful of multiply-adds, so tiny batches would
+///   spend more time scheduling tasks than doing math.
+/// - With a few movers everything stays in one batch (effectively single-threaded).
+/// - With thousands of movers the query is split across the `ComputeTaskPool`.
+///
+/// Future collision / AI loops over large entity counts should reuse this
+/// (or a tuned sibling constant) instead of picking ad-hoc batch sizes.
+pub const MOVER_BATCHING: BatchingStrategy = BatchingStrategy::new().min_batch_size(256);
+
+/// Start this tick's `SpeedMultiplier`s unmodified; speed modifiers multiply theirs
+/// in after this.
+pub fn reset_speed_multipliers(mut q_movers: Query<&mut SpeedMultiplier>) {
+    for mut multiplier in &mut q_movers {
+        multiplier.0 = 1.0;
+    }
+}
+
+/// Fold each mover's `Gravity` into its `ExternalForce` for this tick.
+pub fn apply_gravity(mut q_movers: Query<(&Gravity, &mut ExternalForce), Without<MovementLocked>>) {
+    for (gravity, mut external) in &mut q_movers {
+        external.apply_acceleration(Vec3::NEG_Y * gravity.0);
+    }
+}
+
+/// Convert local-space movement intent into world-space velocity.
+///
+/// Pipeline contract:
+/// - Reads: MoveInput (local), MoveConfig, SpeedMultiplier (optional), Transform.rotation
+/// - Writes: Velocity (world units/sec)
+/// - Runs in parallel batches (see [`MOVER_BATCHING`]); each entity is independent.
+/// - Skips `MovementLocked` movers.
+/// - Skips `MovementMode::RootMotion` movers (their `Velocity` comes from animation).
+/// - Quantizes the result in `SimMath::Quantized` mode (plain floats without a `SimMath`).
+#[expect(clippy::type_complexity)]
+pub fn compute_velocity_from_input(
+    math: Option<Res<SimMath>>,
+    mut q_movers: Query<
+        (
+            &MoveInput,
+            &MoveConfig,
+            Option<&SpeedMultiplier>,
+            Option<&MovementMode>,
+            &Transform,
+            &mut Velocity,
+        ),
+        Without<MovementLocked>,
+    >,
+) {
+    let math = math.map_or(SimMath::Float, |math| *math);
+    q_movers
+        .par_iter_mut()
+        .batching_strategy(MOVER_BATCHING)
+        .for_each(
+            |(move_input, config, multiplier, mode, transform, mut velocity)| {
+                if mode == Some(&MovementMode::RootMotion) {
+                    return;
+                }
+
+                // Local intent has length <= 1 (its magnitude scales the speed); clamp anyway
+                // so a misbehaving writer can't exceed full speed. Scale it per axis, then
+                // rotate it into world space using the mover's current orientation.
+                let local = config.local_velocity(move_input.0.clamp_length_max(1.0));
+
+                // Velocity is in world units per second.
+                let multiplier = multiplier.map_or(1.0, |m| m.0);
+                velocity.0 = math.quantize_vec3(transform.rotation * local * multiplier);
+            },
+        );
+}
+
+/// Integrate velocity into translation using the fixed timestep.
+///
+/// Temporary integration step:
+/// - Reads: Velocity, ExternalForce (optional), SweptCollider (optional), colliders
+/// - Writes: Transform.translation, ExternalForce (consumed + decayed)
+/// - Runs in parallel batches (see [`MOVER_BATCHING`]); each entity is independent.
+/// - Skips `MovementLocked` movers.
+/// - `SweptCollider` movers sweep the tick's displacement (see [`sweep_mover`]) and
+///   lose the push velocity that drove them into a wall; others move freely.
+/// - Quantizes translations and push velocities in `SimMath::Quantized` mode (plain
+///   floats without a `SimMath`).
+///
+/// Later, swap this out for physics engine integration.
+#[expect(clippy::type_complexity)]
+pub fn integrate_velocity(
+    time: Res<Time<Fixed>>,
+    math: Option<Res<SimMath>>,
+    collision: CollisionWorld,
+    mut q_movers: Query<
+        (
+            Entity,
+            &Velocity,
+            &mut Transform,
+            Option<&mut ExternalForce>,
+            Option<&SweptCollider>,
+        ),
+        Without<MovementLocked>,
+    >,
+) {
+    let dt = time.delta_secs();
+    let math = math.map_or(SimMath::Float, |math| *math);
+
+    q_movers
+        .par_iter_mut()
+        .batching_strategy(MOVER_BATCHING)
+        .for_each(|(entity, velocity, mut transform, mut external, swept)| {
+            let mut total = velocity.0;
+
+            if let Some(external) = external.as_mut() {
+                total += step_external_force_with(math, external, dt);
+            }
+
+            let displacement = total * dt;
+            let target = match swept {
+                Some(swept) => {
+                    let sweep = sweep_mover(
+                        &collision,
+                        entity,
+                        transform.translation,
+                        displacement,
+                        swept,
+                    );
+                    if let Some(external) = external.as_mut() {
+                        for normal in &sweep.normals {
+                            let into = external.velocity.dot(*normal);
+                            if into < 0.0 {
+                                external.velocity -= *normal * into;
+                            }
+                        }
+                    }
+                    sweep.position
+                }
+                None => transform.translation + displacement,
+            };
+            transform.translation = math.quantize_vec3(target);
+        });
+}
+
+/// Gap kept between a swept mover and what it stopped against, so the next tick's
+/// cast starts outside the surface (casts ignore boxes they start inside).
+pub const SWEEP_SKIN: f32 = 1e-3;
+
+/// Most surfaces a mover slides along in one tick (a corner takes two).
+const MAX_SLIDES: usize = 3;
+
+/// Where a swept move ended up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepResult {
+    pub position: Vec3,
+    /// Normals of the surfaces hit along the way, in order.
+    pub normals: Vec<Vec3>,
+}
+
+/// Move a `SweptCollider` box from `start` by `displacement`, stopping at the first
+/// collider in the way and sliding the rest of the move along its surface.
+///
+/// - The whole path is cast, however long, so no speed tunnels through a wall.
+/// - Colliders the box already overlaps are ignored, so a mover can always get out.
+/// - Leftover motion after [`MAX_SLIDES`] contacts is dropped.
+pub fn sweep_mover(
+    collision: &CollisionWorld,
+    entity: Entity,
+    start: Vec3,
+    displacement: Vec3,
+    swept: &SweptCollider,
+) -> SweepResult {
+    let (offset, half_extents) = swept.swept_box();
+    let mut position = start;
+    let mut remaining = displacement;
+    let mut normals = Vec::new();
+
+    for _ in 0..MAX_SLIDES {
+        let distance = remaining.length();
+        if distance <= f32::EPSILON {
+            break;
+        }
+        let dir = remaining / distance;
+        let Some(hit) =
+            collision.box_cast(position + offset, dir, distance, half_extents, &[entity])
+        else {
+            position += remaining;
+            break;
+        };
+
+        let travel = (hit.distance - SWEEP_SKIN).max(0.0);
+        position += dir * travel;
+        remaining -= dir * travel;
+        remaining -= hit.normal * remaining.dot(hit.normal);
+        normals.push(hit.normal);
+    }
+
+    SweepResult { position, normals }
+}
+
+/// Below this push speed (units/sec) the external velocity snaps to zero.
+const EXTERNAL_VELOCITY_EPSILON: f32 = 1e-3;
+
+/// Advance an `ExternalForce` by one tick and return the push velocity to integrate.
+///
+/// - Folds this tick's acceleration into the push velocity, then clears it.
+/// - The returned velocity is the *pre-decay* value, so a single impulse moves the
+///   entity on the tick it was applied.
+/// - Decay is exponential (`exp(-damping * dt)`), so it's framerate-independent.
+pub fn step_external_force(external: &mut ExternalForce, dt: f32) -> Vec3 {
+    step_external_force_with(SimMath::Float, external, dt)
+}
+
+/// `step_external_force` with the simulation's math (quantized push velocity and
+/// deterministic decay in `SimMath::Quantized` mode).
+pub fn step_external_force_with(math: SimMath, external: &mut ExternalForce, dt: f32) -> Vec3 {
+    external.velocity = math.quantize_vec3(external.velocity + external.acceleration * dt);
+    external.acceleration = Vec3::ZERO;
+
+    let push = external.velocity;
+
+    external.velocity =
+        math.quantize_vec3(external.velocity * math.exp(-external.damping.max(0.0) * dt));
+    if external.velocity.length_squared() < EXTERNAL_VELOCITY_EPSILON * EXTERNAL_VELOCITY_EPSILON {
+        external.velocity = Vec3::ZERO;
+    }
+
+    push
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::collision::component::Collider;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    /// `par_iter_mut` needs the compute pool; a bare `World` doesn't create it
+    /// (normally `TaskPoolPlugin` does).
+    fn init_task_pool() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+    }
+
+    #[test]
+    fn compute_velocity_from_input_rotates_and_scales() {
+        init_task_pool();
+        let mut world = World::new();
+
+        // Local intent along +X, rotate +90° around Y -> world direction should become -Z.
+        world.spawn((
+            MoveInput(Vec3::X),
+            MoveConfig::uniform(10.0),
+            Transform::from_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2)),
+            Velocity(Vec3::ZERO),
+        ));
+
+        let _ = world.run_system_once(compute_velocity_from_input);
+
+        let v = world.query::<&Velocity>().single(&world).unwrap().0;
+        let expected = Vec3::NEG_Z * 10.0;
+
+        assert!(
+            (v - expected).length() < 1e-5,
+            "Expected velocity {expected:?}, got {v:?}"
+        );
+    }
+
+    #[test]
+    fn compute_velocity_from_input_scales_with_analog_magnitude() {
+        init_task_pool();
+        let mut world = World::new();
+
+        let half = world
+            .spawn((
+                MoveInput(Vec3::NEG_Z * 0.5),
+                MoveConfig::uniform(10.0),
+                Transform::default(),
+                Velocity(Vec3::ZERO),
+            ))
+            .id();
+        let overlong = world
+            .spawn((
+                MoveInput(Vec3::X * 3.0),
+                MoveConfig::uniform(10.0),
+                Transform::default(),
+                Velocity(Vec3::ZERO),
+            ))
+            .id();
+
+        let _ = world.run_system_once(compute_velocity_from_input);
+
+        let v = |e| world.get::<Velocity>(e).unwrap().0;
+        assert!(
+            (v(half) - Vec3::NEG_Z * 5.0).length() < 1e-5,
+            "got {:?}",
+            v(half)
+        );
+        assert!(
+            (v(overlong) - Vec3::X * 10.0).length() < 1e-5,
+            "got {:?}",
+            v(overlong)
+        );
+    }
+
+    #[test]
+    fn compute_velocity_from_input_applies_speed_multiplier() {
+        init_task_pool();
+        let mut world = World::new();
+
+        world.spawn((
+            MoveInput(Vec3::NEG_Z),
+            MoveConfig::uniform(10.0),
+            SpeedMultiplier(0.5),
+            Transform::default(),
+            Velocity(Vec3::ZERO),
+        ));
+
+        let _ = world.run_system_once(compute_velocity_from_input);
+
+        let v = world.query::<&Velocity>().single(&world).unwrap().0;
+        assert!((v - Vec3::NEG_Z * 5.0).length() < 1e-5, "got {v:?}");
+    }
+
+    #[test]
+    fn speed_multipliers_restart_unmodified_each_tick() {
+        let mut world = World::new();
+        let mover = world.spawn(SpeedMultiplier(0.25)).id();
+
+        let _ = world.run_system_once(reset_speed_multipliers);
+
+        assert_eq!(
+            world.get::<SpeedMultiplier>(mover),
+            Some(&SpeedMultiplier(1.0))
+        );
+    }
+
+    #[test]
+    fn compute_velocity_from_input_leaves_root_motion_movers_alone() {
+        init_task_pool();
+        let mut world = World::new();
+
+        let e = world
+            .spawn((
+                MoveInput(Vec3::NEG_Z),
+                MoveConfig::uniform(10.0),
+                MovementMode::RootMotion,
+                Transform::default(),
+                Velocity(Vec3::X),
+            ))
+            .id();
+
+        let _ = world.run_system_once(compute_velocity_from_input);
+
+        assert_eq!(world.get::<Velocity>(e).unwrap().0, Vec3::X);
+    }
+
+    #[test]
+    fn integrate_velocity_moves_translation_by_fixed_dt() {
+        init_task_pool();
+        let mut world = World::new();
+
+        // Create a fixed clock and make its delta deterministic.
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+
+        world.spawn((
+            Velocity(Vec3::new(6.0, 0.0, 0.0)), // 6 units/sec
+            Transform::from_translation(Vec3::ZERO),
+        ));
+
+        let _ = world.run_system_once(integrate_velocity);
+
+        let pos = world
+            .query::<&Transform>()
+            .single(&world)
+            .unwrap()
+            .translation;
+        let expected = Vec3::new(0.1, 0.0, 0.0); // 6 * (1/60) = 0.1
+
+        assert!(
+            (pos - expected).length() < 1e-6,
+            "Expected translation {expected:?}, got {pos:?}"
+        );
+    }
+
+    #[test]
+    fn integrate_velocity_does_nothing_when_dt_is_zero() {
+        init_task_pool();
+        let mut world = World::new();
+
+        // Default Time<Fixed> has 0 delta until advanced.
+        world.insert_resource(Time::<Fixed>::from_hz(60.0));
+
+        world.spawn((
+            Velocity(Vec3::new(100.0, 0.0, 0.0)),
+            Transform::from_translation(Vec3::new(1.0, 2.0, 3.0)),
+        ));
+
+        let _ = world.run_system_once(integrate_velocity);
+
+        let pos = world
+            .query::<&Transform>()
+            .single(&world)
+            .unwrap()
+            .translation;
+        assert!(
+            (pos - Vec3::new(1.0, 2.0, 3.0)).length() < 1e-6,
+            "With dt=0, translation should not change"
+        );
+    }
+
+    #[test]
+    fn integrate_velocity_updates_every_mover_across_batches() {
+        init_task_pool();
+        let mut world = World::new();
+
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+
+        // Well above MOVER_BATCHING's minimum so multiple batches are produced.
+        for _ in 0..2_000 {
+            world.spawn((Velocity(Vec3::new(0.0, 0.0, -6.0)), Transform::default()));
+        }
+
+        let _ = world.run_system_once(integrate_velocity);
+
+        let expected = Vec3::new(0.0, 0.0, -0.1);
+        for tr in world.query::<&Transform>().iter(&world) {
+            assert!(
+                (tr.translation - expected).length() < 1e-6,
+                "every mover must be integrated exactly once, got {:?}",
+                tr.translation
+            );
+        }
+    }
+
+    #[test]
+    fn integrate_velocity_adds_and_decays_external_impulse() {
+        init_task_pool();
+        let mut world = World::new();
+
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+
+        let mut external = ExternalForce::default();
+        external.apply_impulse(Vec3::new(0.0, 0.0, 6.0));
+
+        let e = world
+            .spawn((
+                Velocity(Vec3::new(6.0, 0.0, 0.0)),
+                Transform::default(),
+                external,
+            ))
+            .id();
+
+        let _ = world.run_system_once(integrate_velocity);
+
+        // Both the input velocity and the push contribute this tick.
+        let pos = world.get::<Transform>(e).unwrap().translation;
+        assert!(
+            (pos - Vec3::new(0.1, 0.0, 0.1)).length() < 1e-6,
+            "got {pos:?}"
+        );
+
+        // The push decays, input velocity is untouched.
+        let ext = world.get::<ExternalForce>(e).unwrap();
+        assert!(ext.velocity.z < 6.0 && ext.velocity.z > 0.0);
+        assert_eq!(
+            world.get::<Velocity>(e).unwrap().0,
+            Vec3::new(6.0, 0.0, 0.0)
+        );
+    }
+
+    fn swept_world() -> World {
+        init_task_pool();
+        let mut world = World::new();
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+        // Ground slab, top at y = 0.
+        world.spawn((
+            Collider::cuboid(Vec3::new(50.0, 0.05, 50.0)),
+            GlobalTransform::from_xyz(0.0, -0.05, 0.0),
+        ));
+        world
+    }
+
+    /// A 5cm-thick wall facing the mover, its near face at z = -3.
+    fn thin_wall(world: &mut World) -> Entity {
+        world
+            .spawn((
+                Collider::cuboid(Vec3::new(10.0, 2.0, 0.025)),
+                GlobalTransform::from_xyz(0.0, 2.0, -3.025),
+            ))
+            .id()
+    }
+
+    fn dash(world: &mut World, velocity: Vec3) -> Entity {
+        let mut external = ExternalForce::default();
+        external.apply_impulse(velocity);
+        world
+            .spawn((
+                Velocity(Vec3::ZERO),
+                Transform::from_xyz(0.0, 0.5, 0.0),
+                external,
+                SweptCollider::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn dashes_at_extreme_speed_stop_at_thin_walls() {
+        for speed in [500.0, 5_000.0, 500_000.0] {
+            let mut world = swept_world();
+            thin_wall(&mut world);
+            let e = dash(&mut world, Vec3::NEG_Z * speed);
+
+            for _ in 0..3 {
+                let _ = world.run_system_once(integrate_velocity);
+            }
+
+            let pos = world.get::<Transform>(e).unwrap().translation;
+            assert!(
+                pos.z > -2.5 && pos.z < -2.49,
+                "{speed} u/s: stopped against the wall, got {pos:?}"
+            );
+            assert_eq!(pos.y, 0.5, "the ground never blocks walking");
+            let push = world.get::<ExternalForce>(e).unwrap().velocity;
+            assert!(push.z >= 0.0, "no push left into the wall: {push:?}");
+        }
+    }
+
+    #[test]
+    fn swept_movers_slide_along_walls_and_step_over_ledges() {
+        let mut world = swept_world();
+        thin_wall(&mut world);
+        // A 30cm curb in the way along X.
+        world.spawn((
+            Collider::cuboid(Vec3::new(0.25, 0.15, 2.0)),
+            GlobalTransform::from_xyz(4.0, 0.15, 0.0),
+        ));
+        let e = dash(&mut world, Vec3::new(600.0, 0.0, -600.0));
+
+        let _ = world.run_system_once(integrate_velocity);
+
+        let pos = world.get::<Transform>(e).unwrap().translation;
+        assert!(pos.z > -2.5, "blocked along Z: {pos:?}");
+        assert!(
+            (pos.x - 10.0).abs() < 0.01,
+            "kept sliding along X, over the curb: {pos:?}"
+        );
+        let push = world.get::<ExternalForce>(e).unwrap().velocity;
+        assert!(
+            push.x > 0.0 && push.z == 0.0,
+            "only the into-wall push is dropped: {push:?}"
+        );
+    }
+
+    #[test]
+    fn movers_without_a_swept_collider_ignore_walls() {
+        let mut world = swept_world();
+        thin_wall(&mut world);
+        let e = dash(&mut world, Vec3::NEG_Z * 600.0);
+        world.entity_mut(e).remove::<SweptCollider>();
+
+        let _ = world.run_system_once(integrate_velocity);
+
+        assert!(world.get::<Transform>(e).unwrap().translation.z < -9.0);
+    }
+
+    #[test]
+    fn locked_movers_neither_steer_nor_move() {
+        init_task_pool();
+        let mut world = World::new();
+
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+
+        let mut external = ExternalForce::default();
+        external.apply_impulse(Vec3::X);
+        let e = world
+            .spawn((
+                MovementLocked,
+                MoveInput(Vec3::NEG_Z),
+                MoveConfig::uniform(10.0),
+                Gravity::default(),
+                Transform::default(),
+                Velocity::default(),
+                external,
+            ))
+            .id();
+
+        let _ = world.run_system_once(apply_gravity);
+        let _ = world.run_system_once(compute_velocity_from_input);
+        let _ = world.run_system_once(integrate_velocity);
+
+        assert_eq!(world.get::<Velocity>(e).unwrap().0, Vec3::ZERO);
+        assert_eq!(world.get::<Transform>(e).unwrap().translation, Vec3::ZERO);
+        assert_eq!(
+            world.get::<ExternalForce>(e).unwrap().acceleration,
+            Vec3::ZERO
+        );
+    }
+
+    #[test]
+    fn gravity_pulls_movers_without_intent_down() {
+        init_task_pool();
+        let mut world = World::new();
+
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f32(0.5));
+        world.insert_resource(fixed_time);
+
+        let e = world
+            .spawn((
+                Gravity(10.0),
+                ExternalForce {
+                    damping: 0.0,
+                    ..default()
+                },
+                Transform::default(),
+                Velocity::default(),
+            ))
+            .id();
+
+        let _ = world.run_system_once(apply_gravity);
+        let _ = world.run_system_once(integrate_velocity);
+
+        // 10 * 0.5 = 5 units/sec after one tick, integrated over 0.5s.
+        assert_eq!(
+            world.get::<ExternalForce>(e).unwrap().velocity,
+            Vec3::NEG_Y * 5.0
+        );
+        assert_eq!(
+            world.get::<Transform>(e).unwrap().translation,
+            Vec3::NEG_Y * 2.5
+        );
+    }
+
+    #[test]
+    fn external_acceleration_is_consumed_each_tick() {
+        let mut external = ExternalForce {
+            damping: 0.0,
+            ..default()
+        };
+        external.apply_acceleration(Vec3::new(60.0, 0.0, 0.0));
+
+        let push = step_external_force(&mut external, 1.0 / 60.0);
+
+        assert!((push - Vec3::X).length() < 1e-6);
+        assert_eq!(external.acceleration, Vec3::ZERO);
+        // Without damping the accumulated push velocity persists.
+        assert!((external.velocity - Vec3::X).length() < 1e-6);
+    }
+
+    #[test]
+    fn external_velocity_eventually_settles_to_zero() {
+        let mut external = ExternalForce::default();
+        external.apply_impulse(Vec3::splat(10.0));
+
+        for _ in 0..600 {
+            step_external_force(&mut external, 1.0 / 60.0);
+        }
+
+        assert_eq!(external.velocity, Vec3::ZERO);
+    }
+
+    /// A mover walking in a turn while an impulse decays and gravity pulls, for
+    /// `ticks` fixed steps; returns its final translation.
+    fn simulate(math: SimMath, start: Vec3, ticks: u32) -> Vec3 {
+        init_task_pool();
+        let mut world = World::new();
+        world.insert_resource(math);
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f64(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+
+        let mut external = ExternalForce::default();
+        external.apply_impulse(Vec3::new(3.0, 8.0, -1.0));
+        let e = world
+            .spawn((
+                MoveInput(Vec3::new(0.6, 0.0, -0.8)),
+                MoveConfig::uniform(4.5),
+                Gravity(2.0),
+                Transform::from_translation(start),
+                Velocity::default(),
+                external,
+            ))
+            .id();
+
+        for tick in 0..ticks {
+            world.get_mut::<Transform>(e).unwrap().rotation =
+                Quat::from_rotation_y(tick as f32 * 0.01);
+            let _ = world.run_system_once(apply_gravity);
+            let _ = world.run_system_once(compute_velocity_from_input);
+            let _ = world.run_system_once(integrate_velocity);
+        }
+        world.get::<Transform>(e).unwrap().translation
+    }
+
+    #[test]
+    fn quantized_movement_cross_checks_against_the_float_path() {
+        let start = Vec3::new(10.0, 0.0, -4.0);
+        let float = simulate(SimMath::Float, start, 600);
+        let quantized = simulate(SimMath::Quantized, start, 600);
+
+        // Same motion, within the grid's rounding (<= 0.5 mm per value per step)
+        // accumulated over 10 seconds of steps.
+        assert!(
+            (float - quantized).length() < 0.1,
+            "{float:?} vs {quantized:?}"
+        );
+        assert_eq!(
+            SimMath::Quantized.quantize_vec3(quantized),
+            quantized,
+            "on the grid"
+        );
+    }
+
+    #[test]
+    fn quantized_movement_absorbs_last_bit_differences() {
+        // Two peers whose start positions differ in the last bit (as different
+        // platforms' rounding would leave them) end up bit-identical.
+        let start = Vec3::new(10.0, 0.0, -4.0);
+        let nudged = Vec3::new(f32::from_bits(10.0_f32.to_bits() + 1), 0.0, -4.0);
+        assert_ne!(start, nudged);
+
+        let a = simulate(SimMath::Quantized, start, 300);
+        let b = simulate(SimMath::Quantized, nudged, 300);
+        assert_eq!(
+            a.to_array().map(f32::to_bits),
+            b.to_array().map(f32::to_bits)
+        );
+    }
+}
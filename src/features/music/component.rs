@@ -0,0 +1,151 @@
+// src/features/music/component.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// A looping music layer; all layers play in sync and only their volumes change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+pub enum MusicLayer {
+    Exploration,
+    Combat,
+    /// Tension layer on top of the others while the player is badly hurt.
+    Danger,
+}
+
+/// One-shot musical cue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+pub enum Stinger {
+    BossPhase,
+    Victory,
+}
+
+/// Music configuration, loaded from `assets/data/music.ron`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct MusicData {
+    /// Looping track per layer (asset paths).
+    pub layers: BTreeMap<MusicLayer, String>,
+    /// One-shot track per stinger (asset paths).
+    pub stingers: BTreeMap<Stinger, String>,
+    pub crossfade_secs: f32,
+    pub combat_linger_secs: f32,
+    pub danger_health_fraction: f32,
+}
+
+const BUILTIN_MUSIC: &str = include_str!("../../../assets/data/music.ron");
+
+impl MusicData {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+}
+
+impl Default for MusicData {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_MUSIC).expect("assets/data/music.ron must parse")
+    }
+}
+
+/// What the music is reacting to, and how far each cross-fade has progressed.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct MusicDirector {
+    /// Enemies currently fighting the player.
+    pub engaged: Vec<Entity>,
+    /// Seconds of combat music left once nobody is engaged.
+    pub linger_secs: f32,
+    /// `0.0` = exploration, `1.0` = combat.
+    pub combat_blend: f32,
+    /// `0.0..=1.0` volume of the danger layer.
+    pub danger_blend: f32,
+}
+
+impl MusicDirector {
+    pub fn in_combat(&self) -> bool {
+        !self.engaged.is_empty() || self.linger_secs > 0.0
+    }
+
+    /// Mark `enemy` as fighting; returns `true` if it wasn't already.
+    pub fn engage(&mut self, enemy: Entity) -> bool {
+        if self.engaged.contains(&enemy) {
+            return false;
+        }
+        self.engaged.push(enemy);
+        true
+    }
+
+    /// Forget `enemy`; returns `true` if it was engaged.
+    pub fn disengage(&mut self, enemy: Entity) -> bool {
+        let before = self.engaged.len();
+        self.engaged.retain(|&e| e != enemy);
+        self.engaged.len() != before
+    }
+
+    /// Move both blends towards their targets; a full swap takes `crossfade_secs`.
+    pub fn advance(&mut self, dt: f32, crossfade_secs: f32, danger: bool) {
+        if self.engaged.is_empty() {
+            self.linger_secs = (self.linger_secs - dt).max(0.0);
+        }
+        let step = dt / crossfade_secs.max(f32::EPSILON);
+        let combat = if self.in_combat() { 1.0 } else { 0.0 };
+        let danger = if danger { 1.0 } else { 0.0 };
+        self.combat_blend = approach(self.combat_blend, combat, step);
+        self.danger_blend = approach(self.danger_blend, danger, step);
+    }
+
+    /// Volume (`0.0..=1.0`) of `layer`.
+    pub fn layer_volume(&self, layer: MusicLayer) -> f32 {
+        match layer {
+            MusicLayer::Exploration => 1.0 - self.combat_blend,
+            MusicLayer::Combat => self.combat_blend,
+            MusicLayer::Danger => self.danger_blend,
+        }
+    }
+}
+
+fn approach(current: f32, target: f32, step: f32) -> f32 {
+    if current < target {
+        (current + step).min(target)
+    } else {
+        (current - step).max(target)
+    }
+}
+
+/// Looping player of one music layer.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MusicLayerPlayer {
+    pub layer: MusicLayer,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_music_parses() {
+        let data = MusicData::default();
+        assert!(data.crossfade_secs > 0.0);
+        assert!((0.0..=1.0).contains(&data.danger_health_fraction));
+    }
+
+    #[test]
+    fn combat_crossfades_in_and_lingers_out() {
+        let mut director = MusicDirector::default();
+        let enemy = Entity::from_raw_u32(1).unwrap();
+        assert!(director.engage(enemy));
+        assert!(!director.engage(enemy));
+
+        director.advance(1.0, 2.0, false);
+        assert_eq!(director.layer_volume(MusicLayer::Combat), 0.5);
+        assert_eq!(director.layer_volume(MusicLayer::Exploration), 0.5);
+        director.advance(5.0, 2.0, false);
+        assert_eq!(director.layer_volume(MusicLayer::Combat), 1.0);
+
+        assert!(director.disengage(enemy));
+        director.linger_secs = 3.0;
+        director.advance(2.0, 2.0, false);
+        assert_eq!(director.layer_volume(MusicLayer::Combat), 1.0, "still lingering");
+        director.advance(2.0, 2.0, false);
+        assert!(!director.in_combat());
+        assert!(director.layer_volume(MusicLayer::Combat) < 1.0);
+    }
+}
@@ -0,0 +1,42 @@
+// src/features/music/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod systems;
+
+/// Adaptive music.
+///
+/// Scope:
+/// - `MusicDirector`: follows enemies fighting the player (`AggroChanged`, hits
+///   either way) and the player's health
+/// - looping layers (exploration, combat, danger) start together and cross-fade
+/// - stingers on boss phases and when the last engaged enemy dies (`PlayStinger`)
+/// - tracks and timings come from `assets/data/music.ron`
+///
+/// Design constraints:
+/// - reads gameplay messages only; nothing in gameplay depends on the music.
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::MusicData>();
+        app.init_resource::<component::MusicDirector>();
+
+        app.add_message::<systems::PlayStinger>();
+
+        app.add_systems(Startup, systems::spawn_music_layers);
+        app.add_systems(
+            Update,
+            (
+                systems::track_engagements,
+                systems::update_music_director,
+                systems::fade_music_layers,
+                systems::play_stingers,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
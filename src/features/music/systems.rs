@@ -0,0 +1,247 @@
+// src/features/music/systems.rs
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use super::component::{MusicData, MusicDirector, MusicLayerPlayer, Stinger};
+use crate::features::audio::component::{AudioBus, SoundVolume};
+use crate::features::enemy::component::Enemy;
+use crate::features::enemy::{AggroChanged, BossPhaseChanged};
+use crate::features::health::component::Health;
+use crate::features::health::damage::{DamageEvent, Died};
+use crate::features::player::component::Player;
+
+/// Request a one-shot musical cue.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayStinger {
+    pub stinger: Stinger,
+}
+
+/// Update: follow who is fighting the player.
+///
+/// - `AggroChanged` engages / disengages enemies (AI)
+/// - damage between the player and an enemy, either way, engages it too, so
+///   enemies without AI (training dummies) still count once hit
+/// - `BossPhaseChanged` -> boss stinger; the last engaged enemy dying -> victory
+/// - enemies that leave without dying (retreats, scene unloads) are dropped, with
+///   no victory
+#[expect(clippy::too_many_arguments)]
+pub fn track_engagements(
+    data: Res<MusicData>,
+    mut director: ResMut<MusicDirector>,
+    mut aggro: MessageReader<AggroChanged>,
+    mut damage: MessageReader<DamageEvent>,
+    mut died: MessageReader<Died>,
+    mut boss_phases: MessageReader<BossPhaseChanged>,
+    q_enemies: Query<(), With<Enemy>>,
+    q_players: Query<(), With<Player>>,
+    mut stingers: MessageWriter<PlayStinger>,
+) {
+    for ev in aggro.read() {
+        if ev.target.is_some_and(|t| q_players.contains(t)) {
+            director.engage(ev.enemy);
+        } else {
+            director.disengage(ev.enemy);
+        }
+    }
+
+    for ev in damage.read() {
+        let Some(source) = ev.attack.map(|a| a.source) else {
+            continue;
+        };
+        if q_enemies.contains(ev.target) && q_players.contains(source) {
+            director.engage(ev.target);
+        } else if q_players.contains(ev.target) && q_enemies.contains(source) {
+            director.engage(source);
+        }
+    }
+
+    let mut victory = false;
+    for ev in died.read() {
+        if director.disengage(ev.entity) && director.engaged.is_empty() {
+            victory = true;
+        }
+    }
+    director.engaged.retain(|&enemy| q_enemies.contains(enemy));
+    if !director.engaged.is_empty() {
+        director.linger_secs = data.combat_linger_secs;
+    }
+
+    for _ in boss_phases.read() {
+        stingers.write(PlayStinger {
+            stinger: Stinger::BossPhase,
+        });
+    }
+    if victory {
+        stingers.write(PlayStinger {
+            stinger: Stinger::Victory,
+        });
+    }
+}
+
+/// Update: cross-fade towards combat / danger.
+pub fn update_music_director(
+    time: Res<Time>,
+    data: Res<MusicData>,
+    mut director: ResMut<MusicDirector>,
+    q_player: Query<&Health, With<Player>>,
+) {
+    let danger = q_player
+        .iter()
+        .any(|h| !h.is_depleted() && h.fraction() < data.danger_health_fraction);
+    director.advance(time.delta_secs(), data.crossfade_secs, danger);
+}
+
+/// Startup: one silent looping player per configured layer (none without an
+/// `AssetServer`, e.g. headless).
+pub fn spawn_music_layers(
+    mut commands: Commands,
+    data: Res<MusicData>,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    for (&layer, path) in &data.layers {
+        commands.spawn((
+            MusicLayerPlayer { layer },
+            AudioBus::Music,
+            SoundVolume(0.0),
+            AudioPlayer::new(asset_server.load(path.clone())),
+            PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+            Name::new(format!("{layer:?} Music")),
+        ));
+    }
+}
+
+/// Update: layer volumes follow the director's blends.
+pub fn fade_music_layers(
+    director: Res<MusicDirector>,
+    mut q_layers: Query<(&MusicLayerPlayer, &mut SoundVolume)>,
+) {
+    for (player, mut volume) in &mut q_layers {
+        volume.0 = director.layer_volume(player.layer);
+    }
+}
+
+/// Update: play requested stingers that have a track.
+pub fn play_stingers(
+    mut commands: Commands,
+    data: Res<MusicData>,
+    asset_server: Option<Res<AssetServer>>,
+    mut requests: MessageReader<PlayStinger>,
+) {
+    for request in requests.read() {
+        let (Some(server), Some(path)) = (&asset_server, data.stingers.get(&request.stinger))
+        else {
+            continue;
+        };
+        commands.spawn((
+            AudioBus::Music,
+            AudioPlayer::new(server.load(path.clone())),
+            PlaybackSettings::DESPAWN,
+            Name::new(format!("{:?} Stinger", request.stinger)),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::health::damage::IncomingAttack;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+        world.init_resource::<MusicData>();
+        world.init_resource::<MusicDirector>();
+        world.init_resource::<Messages<AggroChanged>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<BossPhaseChanged>>();
+        world.init_resource::<Messages<PlayStinger>>();
+        world
+    }
+
+    fn stingers(world: &World) -> Vec<Stinger> {
+        world
+            .resource::<Messages<PlayStinger>>()
+            .iter_current_update_messages()
+            .map(|m| m.stinger)
+            .collect()
+    }
+
+    #[test]
+    fn hitting_an_enemy_engages_and_its_death_is_a_victory() {
+        let mut world = setup_world();
+        let player = world.spawn((Player, Health::new(100.0))).id();
+        let enemy = world.spawn(Enemy).id();
+        world.write_message(DamageEvent::from_attack(
+            enemy,
+            5.0,
+            IncomingAttack {
+                source: player,
+                direction: Vec3::NEG_Z,
+                parryable: true,
+            },
+        ));
+
+        let _ = world.run_system_once(track_engagements);
+        assert_eq!(world.resource::<MusicDirector>().engaged, vec![enemy]);
+
+        world.write_message(Died { entity: enemy });
+        world.write_message(BossPhaseChanged { boss: enemy, phase: 2 });
+        let _ = world.run_system_once(track_engagements);
+
+        assert_eq!(stingers(&world), vec![Stinger::BossPhase, Stinger::Victory]);
+        let director = world.resource::<MusicDirector>();
+        assert!(director.engaged.is_empty());
+        assert!(director.in_combat(), "combat music lingers after the fight");
+    }
+
+    #[test]
+    fn enemies_that_leave_without_dying_stop_the_fight_quietly() {
+        let mut world = setup_world();
+        let player = world.spawn((Player, Health::new(100.0))).id();
+        let enemy = world.spawn(Enemy).id();
+        world.write_message(AggroChanged {
+            enemy,
+            target: Some(player),
+        });
+        let _ = world.run_system_once(track_engagements);
+        assert_eq!(world.resource::<MusicDirector>().engaged, vec![enemy]);
+
+        world.resource_mut::<Messages<AggroChanged>>().clear();
+        world.despawn(enemy);
+        let _ = world.run_system_once(track_engagements);
+
+        assert!(world.resource::<MusicDirector>().engaged.is_empty());
+        assert!(stingers(&world).is_empty());
+    }
+
+    #[test]
+    fn aggro_on_the_player_fades_in_combat_and_low_health_adds_danger() {
+        let mut world = setup_world();
+        let mut health = Health::new(100.0);
+        health.current = 10.0;
+        let player = world.spawn((Player, health)).id();
+        let enemy = world.spawn(Enemy).id();
+        world.write_message(AggroChanged {
+            enemy,
+            target: Some(player),
+        });
+
+        let _ = world.run_system_once(track_engagements);
+        for _ in 0..5 {
+            let _ = world.run_system_once(update_music_director);
+        }
+
+        let director = world.resource::<MusicDirector>();
+        assert_eq!(director.combat_blend, 1.0);
+        assert_eq!(director.danger_blend, 1.0);
+    }
+}
@@ -0,0 +1,317 @@
+// src/features/net/conditioner.rs
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use super::transport::{Packet, Transport, TransportError, check_size};
+
+/// Reordered packets are held back at least this long.
+const MIN_REORDER_HOLD: Duration = Duration::from_millis(10);
+
+/// Simulated link quality, applied to every packet (one way).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LinkConditions {
+    /// One-way delay of every packet.
+    pub latency: Duration,
+    /// Extra delay, uniform in `0..=jitter` per packet (reorders packets sent close
+    /// together).
+    pub jitter: Duration,
+    /// Chance a packet is dropped (`0..=1`).
+    pub loss: f32,
+    /// Chance a packet is held back by an extra `latency + jitter` (at least 10 ms),
+    /// landing behind packets sent after it (`0..=1`).
+    pub reorder: f32,
+}
+
+impl LinkConditions {
+    pub fn is_perfect(&self) -> bool {
+        self.latency.is_zero() && self.jitter.is_zero() && self.loss <= 0.0 && self.reorder <= 0.0
+    }
+}
+
+/// Small seeded generator (splitmix64): the same seed loses / delays the same
+/// packets, so tests are reproducible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LinkRng(u64);
+
+impl LinkRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..1`.
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn chance(&mut self, probability: f32) -> bool {
+        probability > 0.0 && self.unit() < probability as f64
+    }
+}
+
+/// A packet on its way to `to`, due once the clock reaches `deliver_at` (ties in
+/// send order).
+#[derive(Debug)]
+struct InFlight {
+    deliver_at: Duration,
+    sequence: u64,
+    to: SocketAddr,
+    packet: Packet,
+}
+
+impl PartialEq for InFlight {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for InFlight {}
+
+impl PartialOrd for InFlight {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InFlight {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.deliver_at, self.sequence).cmp(&(other.deliver_at, other.sequence))
+    }
+}
+
+/// Packets delayed / dropped / reordered by `LinkConditions`, released by a clock
+/// the owner advances.
+#[derive(Debug)]
+pub struct LinkSimulator {
+    rng: LinkRng,
+    sequence: u64,
+    queue: BinaryHeap<Reverse<InFlight>>,
+}
+
+impl LinkSimulator {
+    /// Losses, jitter and reordering drawn from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: LinkRng(seed),
+            sequence: 0,
+            queue: BinaryHeap::new(),
+        }
+    }
+
+    /// Put `packet` (for `to`) on the link at `now`, unless the link loses it.
+    pub fn send(&mut self, now: Duration, conditions: &LinkConditions, to: SocketAddr, packet: Packet) {
+        if self.rng.chance(conditions.loss) {
+            return;
+        }
+        let mut delay = conditions.latency;
+        if !conditions.jitter.is_zero() {
+            delay += conditions.jitter.mul_f64(self.rng.unit());
+        }
+        if self.rng.chance(conditions.reorder) {
+            delay += (conditions.latency + conditions.jitter).max(MIN_REORDER_HOLD);
+        }
+        self.sequence += 1;
+        self.queue.push(Reverse(InFlight {
+            deliver_at: now + delay,
+            sequence: self.sequence,
+            to,
+            packet,
+        }));
+    }
+
+    /// Next packet due by `now`, with its destination.
+    pub fn pop_due(&mut self, now: Duration) -> Option<(SocketAddr, Packet)> {
+        if self.queue.peek()?.0.deliver_at > now {
+            return None;
+        }
+        self.queue.pop().map(|next| (next.0.to, next.0.packet))
+    }
+
+    /// Drop every packet for `to`.
+    pub fn forget(&mut self, to: SocketAddr) {
+        self.queue.retain(|in_flight| in_flight.0.to != to);
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+/// Whether (and how) conditioned transports degrade their traffic right now.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ConditionerSettings {
+    pub enabled: bool,
+    pub link: LinkConditions,
+}
+
+/// Live network conditioner settings, shared by the resource and every
+/// `ConditionedTransport` made from it (the `net_sim` console command edits them).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct NetworkConditions(Arc<Mutex<ConditionerSettings>>);
+
+impl NetworkConditions {
+    fn lock(&self) -> MutexGuard<'_, ConditionerSettings> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn get(&self) -> ConditionerSettings {
+        *self.lock()
+    }
+
+    pub fn set(&self, settings: ConditionerSettings) {
+        *self.lock() = settings;
+    }
+
+    /// Wrap `transport` so its traffic follows these settings.
+    pub fn wrap<T: Transport>(&self, transport: T, seed: u64) -> ConditionedTransport<T> {
+        ConditionedTransport {
+            inner: transport,
+            conditions: self.clone(),
+            outgoing: LinkSimulator::new(seed),
+            incoming: LinkSimulator::new(seed ^ 0x5DEE_CE66_D1CE_4E5B),
+            now: Duration::ZERO,
+        }
+    }
+}
+
+/// Any transport with simulated latency, jitter, loss and reordering on top, in both
+/// directions (each way gets the full one-way `LinkConditions`).
+///
+/// Its clock only moves with `advance`: the owner advances it by real time every
+/// frame, tests by whatever step they like. Packets already delayed keep their
+/// schedule when the conditioner is switched off.
+#[derive(Debug)]
+pub struct ConditionedTransport<T: Transport> {
+    inner: T,
+    conditions: NetworkConditions,
+    outgoing: LinkSimulator,
+    incoming: LinkSimulator,
+    now: Duration,
+}
+
+impl<T: Transport> ConditionedTransport<T> {
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Move the clock forward and hand due outgoing packets to the real transport.
+    pub fn advance(&mut self, dt: Duration) -> Result<(), TransportError> {
+        self.now += dt;
+        while let Some((to, packet)) = self.outgoing.pop_due(self.now) {
+            self.inner.send(to, &packet.payload)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Transport> Transport for ConditionedTransport<T> {
+    fn local_addr(&self) -> SocketAddr {
+        self.inner.local_addr()
+    }
+
+    fn send(&mut self, to: SocketAddr, payload: &[u8]) -> Result<(), TransportError> {
+        check_size(payload)?;
+        let settings = self.conditions.get();
+        if !settings.enabled || settings.link.is_perfect() {
+            return self.inner.send(to, payload);
+        }
+        let packet = Packet {
+            from: self.inner.local_addr(),
+            payload: payload.to_vec(),
+        };
+        self.outgoing.send(self.now, &settings.link, to, packet);
+        // Zero-delay packets go out right away.
+        self.advance(Duration::ZERO)
+    }
+
+    fn receive(&mut self) -> Result<Option<Packet>, TransportError> {
+        let settings = self.conditions.get();
+        let link = if settings.enabled {
+            settings.link
+        } else {
+            LinkConditions::default()
+        };
+        let local = self.inner.local_addr();
+        while let Some(packet) = self.inner.receive()? {
+            self.incoming.send(self.now, &link, local, packet);
+        }
+        Ok(self.incoming.pop_due(self.now).map(|(_, packet)| packet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::net::loopback::LoopbackNetwork;
+
+    fn conditioned(latency_ms: u64, reorder: f32) -> NetworkConditions {
+        let conditions = NetworkConditions::default();
+        conditions.set(ConditionerSettings {
+            enabled: true,
+            link: LinkConditions {
+                latency: Duration::from_millis(latency_ms),
+                reorder,
+                ..default()
+            },
+        });
+        conditions
+    }
+
+    #[test]
+    fn latency_applies_each_way_and_follows_the_toggle() {
+        let network = LoopbackNetwork::default();
+        let conditions = conditioned(30, 0.0);
+        let mut client = conditions.wrap(network.endpoint(), 1);
+        let mut server = network.endpoint();
+
+        client.send(server.local_addr(), &[1]).unwrap();
+        assert_eq!(server.receive(), Ok(None), "held on the way out");
+        client.advance(Duration::from_millis(30)).unwrap();
+        assert_eq!(server.receive().unwrap().unwrap().payload, vec![1]);
+
+        server.send(client.local_addr(), &[2]).unwrap();
+        assert_eq!(client.receive(), Ok(None), "held on the way in");
+        client.advance(Duration::from_millis(30)).unwrap();
+        assert_eq!(client.receive().unwrap().unwrap().payload, vec![2]);
+
+        conditions.set(ConditionerSettings {
+            enabled: false,
+            ..conditions.get()
+        });
+        client.send(server.local_addr(), &[3]).unwrap();
+        assert_eq!(server.receive().unwrap().unwrap().payload, vec![3], "off: straight through");
+    }
+
+    #[test]
+    fn reordering_lets_later_packets_overtake() {
+        let network = LoopbackNetwork::default();
+        let conditions = conditioned(5, 0.3);
+        let mut client = conditions.wrap(network.endpoint(), 9);
+        let mut server = network.endpoint();
+
+        for i in 0..40 {
+            client.send(server.local_addr(), &[i]).unwrap();
+            client.advance(Duration::from_millis(1)).unwrap();
+        }
+        client.advance(Duration::from_millis(100)).unwrap();
+
+        let received: Vec<u8> = server
+            .receive_all()
+            .unwrap()
+            .into_iter()
+            .map(|packet| packet.payload[0])
+            .collect();
+        let mut sorted = received.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..40).collect::<Vec<u8>>(), "nothing lost");
+        assert_ne!(received, sorted, "some packets overtaken");
+    }
+}
@@ -0,0 +1,156 @@
+// src/features/net/interpolation.rs
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::app::SERVER_TICK_HZ;
+
+/// Snapshots kept per entity (a couple of seconds at server rates).
+const MAX_SNAPSHOTS: usize = 64;
+
+/// An entity's server-side pose on one server tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Snapshot {
+    pub tick: u64,
+    pub translation: Vec3,
+    pub rotation: Quat,
+}
+
+/// How clients render server state that arrives at the server's tick rate.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotInterpolation {
+    /// The server's `TickRate` (snapshots per second).
+    pub server_tick_hz: f64,
+    /// How far behind the newest snapshot entities are shown, in server ticks; a
+    /// couple of ticks hides one late or lost snapshot.
+    pub delay_ticks: f64,
+}
+
+impl Default for SnapshotInterpolation {
+    fn default() -> Self {
+        Self {
+            server_tick_hz: SERVER_TICK_HZ,
+            delay_ticks: 2.0,
+        }
+    }
+}
+
+/// Received snapshots of a server-driven entity, oldest first; its `Transform` is
+/// interpolated between them every frame.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct SnapshotBuffer {
+    snapshots: VecDeque<Snapshot>,
+    /// Client time (seconds) the newest snapshot arrived.
+    newest_received_secs: f64,
+}
+
+impl SnapshotBuffer {
+    /// Store a snapshot received at `now_secs`; stale or duplicate ticks (reordered
+    /// packets) are ignored.
+    pub fn push(&mut self, snapshot: Snapshot, now_secs: f64) {
+        if self.snapshots.back().is_some_and(|newest| newest.tick >= snapshot.tick) {
+            return;
+        }
+        self.snapshots.push_back(snapshot);
+        self.newest_received_secs = now_secs;
+        while self.snapshots.len() > MAX_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+    }
+
+    pub fn newest(&self) -> Option<&Snapshot> {
+        self.snapshots.back()
+    }
+
+    /// Server tick to show at client time `now_secs`: the newest tick plus the
+    /// server time elapsed since it arrived, minus the interpolation delay.
+    pub fn render_tick(&self, now_secs: f64, settings: &SnapshotInterpolation) -> Option<f64> {
+        let newest = self.newest()?;
+        let elapsed = (now_secs - self.newest_received_secs).max(0.0) * settings.server_tick_hz;
+        Some(newest.tick as f64 + elapsed - settings.delay_ticks)
+    }
+
+    /// Pose at (fractional) server `tick`: interpolated between the snapshots
+    /// around it, held at the ends (never extrapolated).
+    pub fn sample(&self, tick: f64) -> Option<(Vec3, Quat)> {
+        let first = self.snapshots.front()?;
+        if tick <= first.tick as f64 {
+            return Some((first.translation, first.rotation));
+        }
+        let after = self.snapshots.iter().position(|s| s.tick as f64 >= tick);
+        let Some(after) = after else {
+            let newest = self.newest()?;
+            return Some((newest.translation, newest.rotation));
+        };
+        let (a, b) = (self.snapshots[after - 1], self.snapshots[after]);
+        let t = ((tick - a.tick as f64) / (b.tick - a.tick) as f64) as f32;
+        Some((a.translation.lerp(b.translation, t), a.rotation.slerp(b.rotation, t)))
+    }
+}
+
+/// Update: show every snapshot-driven entity at the interpolated server pose, so
+/// server ticks (e.g. 30 Hz) play back smoothly at the client's frame rate.
+pub fn interpolate_snapshots(
+    time: Res<Time<Real>>,
+    settings: Res<SnapshotInterpolation>,
+    mut q_entities: Query<(&SnapshotBuffer, &mut Transform)>,
+) {
+    let now = time.elapsed_secs_f64();
+    for (buffer, mut transform) in &mut q_entities {
+        let Some((translation, rotation)) = buffer
+            .render_tick(now, &settings)
+            .and_then(|tick| buffer.sample(tick))
+        else {
+            continue;
+        };
+        transform.translation = translation;
+        transform.rotation = rotation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(tick: u64, x: f32) -> Snapshot {
+        Snapshot {
+            tick,
+            translation: Vec3::X * x,
+            rotation: Quat::IDENTITY,
+        }
+    }
+
+    #[test]
+    fn samples_between_ticks_and_holds_at_the_ends() {
+        let mut buffer = SnapshotBuffer::default();
+        buffer.push(snapshot(10, 0.0), 0.0);
+        buffer.push(snapshot(12, 4.0), 0.0);
+        buffer.push(snapshot(11, 9.0), 0.0); // arrived late: ignored
+
+        assert_eq!(buffer.sample(11.5).unwrap().0, Vec3::X * 3.0);
+        assert_eq!(buffer.sample(5.0).unwrap().0, Vec3::ZERO);
+        assert_eq!(buffer.sample(20.0).unwrap().0, Vec3::X * 4.0, "no extrapolation");
+    }
+
+    #[test]
+    fn client_frames_between_server_ticks_move_smoothly() {
+        // 30 Hz server, rendered at 60 fps two ticks behind.
+        let settings = SnapshotInterpolation::default();
+        let mut buffer = SnapshotBuffer::default();
+        for tick in 0..=10 {
+            buffer.push(snapshot(tick, tick as f32), tick as f64 / 30.0);
+        }
+
+        let now = 10.0 / 30.0;
+        let frames: Vec<f32> = (0..4)
+            .map(|frame| {
+                let tick = buffer.render_tick(now + frame as f64 / 60.0, &settings).unwrap();
+                buffer.sample(tick).unwrap().0.x
+            })
+            .collect();
+        // Half a server tick per client frame, up to the newest snapshot.
+        for (got, want) in frames.iter().zip([8.0, 8.5, 9.0, 9.5]) {
+            assert!((got - want).abs() < 1e-4, "{frames:?}");
+        }
+    }
+}
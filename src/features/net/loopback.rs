@@ -0,0 +1,249 @@
+// src/features/net/loopback.rs
+use std::collections::{HashMap, VecDeque};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use super::conditioner::{LinkConditions, LinkSimulator};
+use super::transport::{Packet, Transport, TransportError, check_size};
+
+#[derive(Debug)]
+struct Hub {
+    now: Duration,
+    conditions: LinkConditions,
+    link: LinkSimulator,
+    next_port: u16,
+    /// Delivered packets per bound address.
+    inboxes: HashMap<SocketAddr, VecDeque<Packet>>,
+}
+
+/// In-process network for tests: endpoints made by one `LoopbackNetwork` reach each
+/// other through shared memory, with simulated latency, jitter, loss and reordering.
+///
+/// The network has its own clock, moved by `advance`, so a test decides exactly
+/// when packets arrive (run both endpoints, advance by a tick, repeat). Cloning
+/// shares the network.
+#[derive(Debug, Clone)]
+pub struct LoopbackNetwork {
+    hub: Arc<Mutex<Hub>>,
+}
+
+impl Default for LoopbackNetwork {
+    fn default() -> Self {
+        Self::new(LinkConditions::default(), 0)
+    }
+}
+
+impl LoopbackNetwork {
+    /// A network whose losses, jitter and reordering are drawn from `seed`.
+    pub fn new(conditions: LinkConditions, seed: u64) -> Self {
+        Self {
+            hub: Arc::new(Mutex::new(Hub {
+                now: Duration::ZERO,
+                conditions,
+                link: LinkSimulator::new(seed),
+                next_port: 1,
+                inboxes: HashMap::new(),
+            })),
+        }
+    }
+
+    fn hub(&self) -> MutexGuard<'_, Hub> {
+        // A test that panicked mid-send can't leave the queues half-written.
+        self.hub.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// A new endpoint on its own address (`127.0.0.1:<n>`).
+    pub fn endpoint(&self) -> LoopbackTransport {
+        let mut hub = self.hub();
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, hub.next_port));
+        hub.next_port = hub.next_port.wrapping_add(1).max(1);
+        hub.inboxes.insert(addr, VecDeque::new());
+        LoopbackTransport {
+            addr,
+            network: self.clone(),
+        }
+    }
+
+    /// Move the network clock forward; packets due by then become receivable.
+    pub fn advance(&self, dt: Duration) {
+        self.hub().now += dt;
+    }
+
+    pub fn now(&self) -> Duration {
+        self.hub().now
+    }
+
+    pub fn conditions(&self) -> LinkConditions {
+        self.hub().conditions
+    }
+
+    /// New conditions for packets sent from now on (those in flight keep theirs).
+    pub fn set_conditions(&self, conditions: LinkConditions) {
+        self.hub().conditions = conditions;
+    }
+}
+
+/// One endpoint of a `LoopbackNetwork`; dropping it unbinds its address.
+#[derive(Debug)]
+pub struct LoopbackTransport {
+    addr: SocketAddr,
+    network: LoopbackNetwork,
+}
+
+impl Transport for LoopbackTransport {
+    fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    fn send(&mut self, to: SocketAddr, payload: &[u8]) -> Result<(), TransportError> {
+        check_size(payload)?;
+        let mut hub = self.network.hub();
+        if !hub.inboxes.contains_key(&to) {
+            return Ok(());
+        }
+        let (now, conditions) = (hub.now, hub.conditions);
+        let packet = Packet {
+            from: self.addr,
+            payload: payload.to_vec(),
+        };
+        hub.link.send(now, &conditions, to, packet);
+        Ok(())
+    }
+
+    fn receive(&mut self) -> Result<Option<Packet>, TransportError> {
+        let mut hub = self.network.hub();
+        let hub = &mut *hub;
+        while let Some((to, packet)) = hub.link.pop_due(hub.now) {
+            if let Some(inbox) = hub.inboxes.get_mut(&to) {
+                inbox.push_back(packet);
+            }
+        }
+        Ok(hub.inboxes.get_mut(&self.addr).and_then(VecDeque::pop_front))
+    }
+}
+
+impl Drop for LoopbackTransport {
+    fn drop(&mut self) {
+        let mut hub = self.network.hub();
+        hub.inboxes.remove(&self.addr);
+        hub.link.forget(self.addr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payloads(transport: &mut LoopbackTransport) -> Vec<u8> {
+        transport
+            .receive_all()
+            .unwrap()
+            .into_iter()
+            .map(|packet| packet.payload[0])
+            .collect()
+    }
+
+    #[test]
+    fn perfect_links_deliver_immediately_and_in_order() {
+        let network = LoopbackNetwork::default();
+        let mut client = network.endpoint();
+        let mut server = network.endpoint();
+        assert_ne!(client.local_addr(), server.local_addr());
+
+        for i in 0..3 {
+            client.send(server.local_addr(), &[i]).unwrap();
+        }
+        let packet = server.receive().unwrap().unwrap();
+        assert_eq!(packet.from, client.local_addr());
+        assert_eq!(packet.payload, vec![0]);
+        assert_eq!(payloads(&mut server), vec![1, 2]);
+
+        server.send(client.local_addr(), &[9]).unwrap();
+        assert_eq!(payloads(&mut client), vec![9]);
+    }
+
+    #[test]
+    fn latency_holds_packets_until_the_clock_reaches_them() {
+        let network = LoopbackNetwork::new(
+            LinkConditions {
+                latency: Duration::from_millis(100),
+                ..Default::default()
+            },
+            1,
+        );
+        let mut client = network.endpoint();
+        let mut server = network.endpoint();
+
+        client.send(server.local_addr(), &[1]).unwrap();
+        network.advance(Duration::from_millis(99));
+        assert_eq!(server.receive(), Ok(None));
+        network.advance(Duration::from_millis(1));
+        assert_eq!(payloads(&mut server), vec![1]);
+    }
+
+    #[test]
+    fn jitter_reorders_but_delivers_everything_within_its_window() {
+        let network = LoopbackNetwork::new(
+            LinkConditions {
+                latency: Duration::from_millis(50),
+                jitter: Duration::from_millis(40),
+                ..Default::default()
+            },
+            7,
+        );
+        let mut client = network.endpoint();
+        let mut server = network.endpoint();
+
+        for i in 0..50 {
+            client.send(server.local_addr(), &[i]).unwrap();
+        }
+        network.advance(Duration::from_millis(49));
+        assert_eq!(server.receive(), Ok(None));
+        network.advance(Duration::from_millis(41));
+
+        let received = payloads(&mut server);
+        let mut sorted = received.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..50).collect::<Vec<u8>>(), "nothing lost");
+        assert_ne!(received, sorted, "reordered");
+    }
+
+    #[test]
+    fn loss_drops_about_its_share_reproducibly() {
+        let run = |seed| {
+            let network = LoopbackNetwork::new(
+                LinkConditions {
+                    loss: 0.25,
+                    ..Default::default()
+                },
+                seed,
+            );
+            let mut client = network.endpoint();
+            let mut server = network.endpoint();
+            for i in 0..1000u32 {
+                client.send(server.local_addr(), &i.to_le_bytes()).unwrap();
+            }
+            server.receive_all().unwrap()
+        };
+
+        let delivered = run(3);
+        assert!((700..800).contains(&delivered.len()), "{}", delivered.len());
+        assert_eq!(run(3), delivered, "same seed, same losses");
+    }
+
+    #[test]
+    fn packets_to_unbound_addresses_are_lost() {
+        let network = LoopbackNetwork::default();
+        let mut client = network.endpoint();
+        let server = network.endpoint();
+        let gone = server.local_addr();
+        drop(server);
+
+        assert_eq!(client.send(gone, &[1]), Ok(()));
+        assert!(matches!(
+            client.send(client.local_addr(), &[0; 2000]),
+            Err(TransportError::TooLarge { size: 2000 })
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+// src/features/net/mod.rs
+use bevy::prelude::*;
+
+use crate::AppMode;
+use crate::app::{AppSet, GameState};
+use crate::features::console::systems::{ConsoleCommand, register_console_command};
+use crate::features::movement::systems::integrate_velocity;
+
+pub mod conditioner;
+pub mod interpolation;
+pub mod loopback;
+pub mod protocol;
+pub mod systems;
+pub mod transport;
+pub mod udp;
+pub mod validation;
+
+pub use conditioner::{ConditionedTransport, ConditionerSettings, LinkConditions, NetworkConditions};
+pub use interpolation::{Snapshot, SnapshotBuffer, SnapshotInterpolation};
+pub use loopback::{LoopbackNetwork, LoopbackTransport};
+pub use protocol::{
+    ClientHandshake, HandshakeState, NetMessage, PROTOCOL_VERSION, ProtocolFeatures, RejectReason,
+    ServerHandshake, ServerInfo,
+};
+pub use transport::{MAX_PACKET_SIZE, Packet, Transport, TransportError};
+pub use udp::UdpTransport;
+
+/// Networking transport layer.
+///
+/// Scope:
+/// - `Transport`: unreliable datagrams between addressed endpoints (UDP semantics)
+/// - `UdpTransport`: a non-blocking UDP socket, for real play
+/// - `LoopbackNetwork` / `LoopbackTransport`: endpoints in one process with
+///   simulated latency, jitter, loss and reordering on a manually advanced clock,
+///   so client / server integration tests run both sides deterministically
+/// - `ConditionedTransport`: the same degradation on top of any transport, driven
+///   by the shared `NetworkConditions` (console: `net_sim`), to exercise
+///   prediction / interpolation locally
+/// - `protocol`: the versioned wire format (`NetMessage`, `PROTOCOL_VERSION`) and
+///   the handshake that opens every session: the client's `Hello` names its
+///   protocol version and features, the server answers `Welcome` (agreed features)
+///   or `Reject` with the reason, so mismatched builds stop before playing
+/// - snapshot interpolation: `SnapshotBuffer` entities are shown between the
+///   server ticks around "now minus a short delay", so a server ticking slower
+///   than the client's frame rate (`SnapshotInterpolation::server_tick_hz`) still
+///   moves smoothly
+/// - server-side movement validation (`validation`, dedicated servers only):
+///   client-claimed inputs drive the regular movement pipeline, claimed positions
+///   that are too fast, teleport or cross colliders are flagged
+///   (`MovementViolation`) and mismatches corrected (`MovementCorrection`)
+///
+/// Design constraints:
+/// - Protocol code only talks to `Transport`, never to sockets, so tests exercise
+///   the same code paths real play does.
+/// - Transports don't sequence, ack or resend; the protocol on top does.
+/// - `Hello` and `Reject` keep their layout in every protocol version (any build can
+///   tell any other why they can't play); released layouts are frozen by tests.
+pub struct NetPlugin;
+
+impl Plugin for NetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NetworkConditions>();
+        app.init_resource::<SnapshotInterpolation>();
+        app.add_message::<ConsoleCommand>();
+        register_console_command(app, systems::NET_SIM_COMMAND, systems::NET_SIM_USAGE);
+        app.add_systems(Update, systems::handle_net_sim_command.after(AppSet::Input));
+        app.add_systems(Update, interpolation::interpolate_snapshots.after(AppSet::Input));
+
+        app.init_resource::<validation::MovementValidation>();
+        app.add_message::<validation::MovementViolation>();
+        app.add_message::<validation::MovementCorrection>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                validation::apply_client_inputs.in_set(AppSet::ReadIntent),
+                validation::record_client_pushes
+                    .in_set(AppSet::Integrate)
+                    .before(integrate_velocity),
+                validation::validate_client_positions.in_set(AppSet::PostMovement),
+            )
+                .run_if(in_state(GameState::Playing))
+                .run_if(resource_exists_and_equals(AppMode::DedicatedServer)),
+        );
+    }
+}
@@ -0,0 +1,699 @@
+// src/features/net/protocol.rs
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use super::transport::{Transport, TransportError};
+use crate::app::SimMath;
+
+/// First bytes of every packet; anything else on the port is ignored.
+pub const PROTOCOL_MAGIC: [u8; 4] = *b"TBFP";
+
+/// Wire format version. Bump it on any change to an existing message's layout (or
+/// its meaning) and freeze the old bytes in the tests below.
+pub const PROTOCOL_VERSION: u16 = 1;
+
+/// Build that speaks this protocol (shown in mismatch messages).
+pub const BUILD: &str = env!("CARGO_PKG_VERSION");
+
+/// Optional protocol capabilities, agreed on during the handshake.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProtocolFeatures(pub u32);
+
+impl ProtocolFeatures {
+    pub const NONE: Self = Self(0);
+    /// Simulation runs on quantized math (`SimMath::Quantized`); both sides must agree.
+    pub const QUANTIZED_MATH: Self = Self(1 << 0);
+    /// The server sends snapshots for `SnapshotBuffer` interpolation.
+    pub const SNAPSHOT_INTERPOLATION: Self = Self(1 << 1);
+    /// The server validates claimed movement (`MovementCorrection`s may arrive).
+    pub const MOVEMENT_VALIDATION: Self = Self(1 << 2);
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Flags in `self` but not in `other`.
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// What this build supports, and what its peer must share given `math`.
+    pub fn local(math: SimMath) -> (Self, Self) {
+        let supported = Self::SNAPSHOT_INTERPOLATION.union(Self::MOVEMENT_VALIDATION);
+        if math.is_quantized() {
+            let quantized = Self::QUANTIZED_MATH;
+            (supported.union(quantized), quantized)
+        } else {
+            (supported, Self::NONE)
+        }
+    }
+}
+
+/// Client -> server, first packet of a session.
+///
+/// Layout frozen across protocol versions (like `Reject`): any build can read it
+/// and answer a mismatched peer with a clear `Reject`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hello {
+    pub protocol: u16,
+    pub build: String,
+    pub features: ProtocolFeatures,
+    /// Features the server must also have.
+    pub required: ProtocolFeatures,
+}
+
+/// Server -> client: accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Welcome {
+    /// Features both sides have (use nothing else).
+    pub features: ProtocolFeatures,
+    pub server_tick_hz: u16,
+}
+
+/// Why a server turned a client away.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectReason {
+    VersionMismatch { server: u16, client: u16 },
+    /// The side that lacks them is named by `server_lacks`.
+    MissingFeatures {
+        features: ProtocolFeatures,
+        server_lacks: bool,
+    },
+    ServerFull,
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionMismatch { server, client } => write!(
+                f,
+                "the server speaks protocol {server}, this game {client}: update {}",
+                if server > client { "the game" } else { "the server" }
+            ),
+            Self::MissingFeatures {
+                features,
+                server_lacks: true,
+            } => write!(f, "the server lacks required features {:#x}", features.0),
+            Self::MissingFeatures { features, .. } => {
+                write!(f, "the server requires features {:#x} this game lacks", features.0)
+            }
+            Self::ServerFull => write!(f, "the server is full"),
+        }
+    }
+}
+
+/// Everything that goes over the wire.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetMessage {
+    Hello(Hello),
+    Welcome(Welcome),
+    Reject(RejectReason),
+    /// Client -> server: input and predicted position for `tick`.
+    MoveClaim { tick: u64, input: Vec3, position: Vec3 },
+    /// Server -> client: where the server put the player on `tick`.
+    MoveCorrection { tick: u64, position: Vec3 },
+}
+
+const KIND_HELLO: u8 = 1;
+const KIND_WELCOME: u8 = 2;
+const KIND_REJECT: u8 = 3;
+const KIND_MOVE_CLAIM: u8 = 4;
+const KIND_MOVE_CORRECTION: u8 = 5;
+
+const REJECT_VERSION: u8 = 1;
+const REJECT_FEATURES: u8 = 2;
+const REJECT_FULL: u8 = 3;
+
+/// Why bytes couldn't be read as a `NetMessage`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Not a packet of this game (wrong magic).
+    Foreign,
+    /// A packet of another protocol version (other than `Hello` / `Reject`).
+    VersionMismatch { ours: u16, theirs: u16 },
+    UnknownKind(u8),
+    /// Ends early, has trailing bytes or holds invalid values.
+    Malformed(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Foreign => write!(f, "not a game packet"),
+            Self::VersionMismatch { ours, theirs } => {
+                write!(f, "packet of protocol {theirs}, expected {ours}")
+            }
+            Self::UnknownKind(kind) => write!(f, "unknown message kind {kind}"),
+            Self::Malformed(what) => write!(f, "malformed packet: {what}"),
+        }
+    }
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+    fn u16(&mut self, v: u16) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+    fn u32(&mut self, v: u32) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+    fn u64(&mut self, v: u64) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+    fn vec3(&mut self, v: Vec3) {
+        for c in v.to_array() {
+            self.0.extend_from_slice(&c.to_le_bytes());
+        }
+    }
+    /// Up to 255 bytes (longer strings are cut at a char boundary).
+    fn string(&mut self, s: &str) {
+        let mut end = s.len().min(u8::MAX as usize);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.u8(end as u8);
+        self.0.extend_from_slice(&s.as_bytes()[..end]);
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn bytes(&mut self, n: usize) -> Result<&[u8], DecodeError> {
+        if self.0.len() < n {
+            return Err(DecodeError::Malformed("truncated"));
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.bytes(1)?[0])
+    }
+    fn u16(&mut self) -> Result<u16, DecodeError> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+    fn f32(&mut self) -> Result<f32, DecodeError> {
+        let v = f32::from_le_bytes(self.bytes(4)?.try_into().unwrap());
+        if v.is_finite() { Ok(v) } else { Err(DecodeError::Malformed("non-finite number")) }
+    }
+    fn vec3(&mut self) -> Result<Vec3, DecodeError> {
+        Ok(Vec3::new(self.f32()?, self.f32()?, self.f32()?))
+    }
+    fn string(&mut self) -> Result<String, DecodeError> {
+        let len = self.u8()? as usize;
+        String::from_utf8(self.bytes(len)?.to_vec()).map_err(|_| DecodeError::Malformed("bad utf-8"))
+    }
+}
+
+impl NetMessage {
+    /// Wire bytes: magic, `PROTOCOL_VERSION` (u16), kind (u8), body; little endian.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut w = Writer(Vec::with_capacity(32));
+        w.0.extend_from_slice(&PROTOCOL_MAGIC);
+        w.u16(PROTOCOL_VERSION);
+        match self {
+            Self::Hello(hello) => {
+                w.u8(KIND_HELLO);
+                w.u16(hello.protocol);
+                w.u32(hello.features.0);
+                w.u32(hello.required.0);
+                w.string(&hello.build);
+            }
+            Self::Welcome(welcome) => {
+                w.u8(KIND_WELCOME);
+                w.u32(welcome.features.0);
+                w.u16(welcome.server_tick_hz);
+            }
+            Self::Reject(reason) => {
+                w.u8(KIND_REJECT);
+                match reason {
+                    RejectReason::VersionMismatch { server, client } => {
+                        w.u8(REJECT_VERSION);
+                        w.u16(*server);
+                        w.u16(*client);
+                    }
+                    RejectReason::MissingFeatures {
+                        features,
+                        server_lacks,
+                    } => {
+                        w.u8(REJECT_FEATURES);
+                        w.u32(features.0);
+                        w.u8(u8::from(*server_lacks));
+                    }
+                    RejectReason::ServerFull => w.u8(REJECT_FULL),
+                }
+            }
+            Self::MoveClaim {
+                tick,
+                input,
+                position,
+            } => {
+                w.u8(KIND_MOVE_CLAIM);
+                w.u64(*tick);
+                w.vec3(*input);
+                w.vec3(*position);
+            }
+            Self::MoveCorrection { tick, position } => {
+                w.u8(KIND_MOVE_CORRECTION);
+                w.u64(*tick);
+                w.vec3(*position);
+            }
+        }
+        w.0
+    }
+
+    /// Read a packet. `Hello` / `Reject` are read whatever version sent them; any
+    /// other message of another version is a `VersionMismatch`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = Reader(bytes);
+        if r.bytes(4).map_err(|_| DecodeError::Foreign)? != PROTOCOL_MAGIC {
+            return Err(DecodeError::Foreign);
+        }
+        let version = r.u16()?;
+        let kind = r.u8()?;
+        if version != PROTOCOL_VERSION && kind != KIND_HELLO && kind != KIND_REJECT {
+            return Err(DecodeError::VersionMismatch {
+                ours: PROTOCOL_VERSION,
+                theirs: version,
+            });
+        }
+        let message = match kind {
+            KIND_HELLO => Self::Hello(Hello {
+                protocol: r.u16()?,
+                features: ProtocolFeatures(r.u32()?),
+                required: ProtocolFeatures(r.u32()?),
+                build: r.string()?,
+            }),
+            KIND_WELCOME => Self::Welcome(Welcome {
+                features: ProtocolFeatures(r.u32()?),
+                server_tick_hz: r.u16()?,
+            }),
+            KIND_REJECT => Self::Reject(match r.u8()? {
+                REJECT_VERSION => RejectReason::VersionMismatch {
+                    server: r.u16()?,
+                    client: r.u16()?,
+                },
+                REJECT_FEATURES => RejectReason::MissingFeatures {
+                    features: ProtocolFeatures(r.u32()?),
+                    server_lacks: r.u8()? != 0,
+                },
+                REJECT_FULL => RejectReason::ServerFull,
+                _ => return Err(DecodeError::Malformed("unknown reject reason")),
+            }),
+            KIND_MOVE_CLAIM => Self::MoveClaim {
+                tick: r.u64()?,
+                input: r.vec3()?,
+                position: r.vec3()?,
+            },
+            KIND_MOVE_CORRECTION => Self::MoveCorrection {
+                tick: r.u64()?,
+                position: r.vec3()?,
+            },
+            other => return Err(DecodeError::UnknownKind(other)),
+        };
+        if !r.0.is_empty() {
+            return Err(DecodeError::Malformed("trailing bytes"));
+        }
+        Ok(message)
+    }
+}
+
+/// What a server accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerInfo {
+    pub features: ProtocolFeatures,
+    /// Features every client must have.
+    pub required: ProtocolFeatures,
+    pub tick_hz: u16,
+    pub max_clients: usize,
+}
+
+impl ServerInfo {
+    /// Accept `hello`, or say why not. Versions must match exactly and each side's
+    /// required features must be in the other's.
+    pub fn answer(&self, hello: &Hello) -> Result<Welcome, RejectReason> {
+        if hello.protocol != PROTOCOL_VERSION {
+            return Err(RejectReason::VersionMismatch {
+                server: PROTOCOL_VERSION,
+                client: hello.protocol,
+            });
+        }
+        let client_lacks = self.required.difference(hello.features);
+        if client_lacks != ProtocolFeatures::NONE {
+            return Err(RejectReason::MissingFeatures {
+                features: client_lacks,
+                server_lacks: false,
+            });
+        }
+        let server_lacks = hello.required.difference(self.features);
+        if server_lacks != ProtocolFeatures::NONE {
+            return Err(RejectReason::MissingFeatures {
+                features: server_lacks,
+                server_lacks: true,
+            });
+        }
+        Ok(Welcome {
+            features: self.features.intersection(hello.features),
+            server_tick_hz: self.tick_hz,
+        })
+    }
+}
+
+/// Server side of the handshake: answers `Hello`s and only lets accepted peers'
+/// messages through.
+#[derive(Debug, Clone)]
+pub struct ServerHandshake {
+    pub info: ServerInfo,
+    /// Accepted peers and their agreed features.
+    peers: HashMap<SocketAddr, ProtocolFeatures>,
+}
+
+impl ServerHandshake {
+    pub fn new(info: ServerInfo) -> Self {
+        Self {
+            info,
+            peers: HashMap::new(),
+        }
+    }
+
+    pub fn features_of(&self, peer: SocketAddr) -> Option<ProtocolFeatures> {
+        self.peers.get(&peer).copied()
+    }
+
+    /// Drain `transport`: answer hellos, drop foreign / mismatched packets and
+    /// anything from peers that haven't shaken hands, return the rest.
+    pub fn receive(
+        &mut self,
+        transport: &mut impl Transport,
+    ) -> Result<Vec<(SocketAddr, NetMessage)>, TransportError> {
+        let mut messages = Vec::new();
+        for packet in transport.receive_all()? {
+            match NetMessage::decode(&packet.payload) {
+                Ok(NetMessage::Hello(hello)) => {
+                    let full = !self.peers.contains_key(&packet.from)
+                        && self.peers.len() >= self.info.max_clients;
+                    let answer = if full {
+                        Err(RejectReason::ServerFull)
+                    } else {
+                        self.info.answer(&hello)
+                    };
+                    let reply = match answer {
+                        Ok(welcome) => {
+                            self.peers.insert(packet.from, welcome.features);
+                            NetMessage::Welcome(welcome)
+                        }
+                        Err(reason) => {
+                            info!("rejected {} (build {}): {reason}", packet.from, hello.build);
+                            NetMessage::Reject(reason)
+                        }
+                    };
+                    transport.send(packet.from, &reply.encode())?;
+                }
+                Ok(message) if self.peers.contains_key(&packet.from) => {
+                    messages.push((packet.from, message));
+                }
+                Ok(_) => {}
+                Err(err) => debug!("dropped packet from {}: {err}", packet.from),
+            }
+        }
+        Ok(messages)
+    }
+}
+
+/// Where a client's handshake stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandshakeState {
+    Connecting { since_hello: Duration, waited: Duration },
+    Connected(Welcome),
+    Rejected(RejectReason),
+    /// The server answered in another protocol version (it couldn't even reject us).
+    VersionMismatch { ours: u16, theirs: u16 },
+    TimedOut,
+}
+
+/// Client side of the handshake.
+#[derive(Debug, Clone)]
+pub struct ClientHandshake {
+    pub server: SocketAddr,
+    pub hello: Hello,
+    pub resend_every: Duration,
+    pub timeout: Duration,
+    pub state: HandshakeState,
+}
+
+impl ClientHandshake {
+    pub fn new(server: SocketAddr, math: SimMath) -> Self {
+        let (features, required) = ProtocolFeatures::local(math);
+        Self {
+            server,
+            hello: Hello {
+                protocol: PROTOCOL_VERSION,
+                build: BUILD.into(),
+                features,
+                required,
+            },
+            resend_every: Duration::from_millis(500),
+            timeout: Duration::from_secs(5),
+            state: HandshakeState::Connecting {
+                since_hello: Duration::MAX,
+                waited: Duration::ZERO,
+            },
+        }
+    }
+
+    /// Advance by `dt`: (re)send the hello, read the server's answer. Once
+    /// connected, other packets are left on the transport.
+    pub fn poll(
+        &mut self,
+        transport: &mut impl Transport,
+        dt: Duration,
+    ) -> Result<&HandshakeState, TransportError> {
+        let HandshakeState::Connecting { since_hello, waited } = self.state else {
+            return Ok(&self.state);
+        };
+        for packet in transport.receive_all()? {
+            if packet.from != self.server {
+                continue;
+            }
+            match NetMessage::decode(&packet.payload) {
+                Ok(NetMessage::Welcome(welcome)) => self.state = HandshakeState::Connected(welcome),
+                Ok(NetMessage::Reject(reason)) => self.state = HandshakeState::Rejected(reason),
+                Err(DecodeError::VersionMismatch { ours, theirs }) => {
+                    self.state = HandshakeState::VersionMismatch { ours, theirs };
+                }
+                _ => continue,
+            }
+            return Ok(&self.state);
+        }
+
+        let waited = waited + dt;
+        if waited >= self.timeout {
+            self.state = HandshakeState::TimedOut;
+            return Ok(&self.state);
+        }
+        let since_hello = since_hello.saturating_add(dt);
+        let since_hello = if since_hello >= self.resend_every {
+            transport.send(self.server, &NetMessage::Hello(self.hello.clone()).encode())?;
+            Duration::ZERO
+        } else {
+            since_hello
+        };
+        self.state = HandshakeState::Connecting { since_hello, waited };
+        Ok(&self.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::net::loopback::LoopbackNetwork;
+
+    // Released wire formats. Never edit these: a change that breaks them needs a new
+    // `PROTOCOL_VERSION` (and new constants next to these).
+    const V1_HELLO: &[u8] = &[
+        b'T', b'B', b'F', b'P', 1, 0, 1, // header: version 1, hello
+        1, 0, // protocol 1
+        6, 0, 0, 0, // features
+        0, 0, 0, 0, // required
+        5, b'0', b'.', b'1', b'.', b'0', // build
+    ];
+    const V1_WELCOME: &[u8] = &[b'T', b'B', b'F', b'P', 1, 0, 2, 6, 0, 0, 0, 30, 0];
+    const V1_REJECT_VERSION: &[u8] = &[b'T', b'B', b'F', b'P', 1, 0, 3, 1, 1, 0, 2, 0];
+    const V1_MOVE_CLAIM: &[u8] = &[
+        b'T', b'B', b'F', b'P', 1, 0, 4, // header
+        42, 0, 0, 0, 0, 0, 0, 0, // tick
+        0, 0, 128, 63, 0, 0, 0, 0, 0, 0, 0, 0, // input (1, 0, 0)
+        0, 0, 0, 64, 0, 0, 0, 63, 0, 0, 128, 191, // position (2, 0.5, -1)
+    ];
+    const V1_MOVE_CORRECTION: &[u8] = &[
+        b'T', b'B', b'F', b'P', 1, 0, 5, // header
+        7, 0, 0, 0, 0, 0, 0, 0, // tick
+        0, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 0, // position (0, 1, 0)
+    ];
+
+    fn v1_messages() -> [(&'static [u8], NetMessage); 5] {
+        [
+            (
+                V1_HELLO,
+                NetMessage::Hello(Hello {
+                    protocol: 1,
+                    build: "0.1.0".into(),
+                    features: ProtocolFeatures(6),
+                    required: ProtocolFeatures::NONE,
+                }),
+            ),
+            (
+                V1_WELCOME,
+                NetMessage::Welcome(Welcome {
+                    features: ProtocolFeatures(6),
+                    server_tick_hz: 30,
+                }),
+            ),
+            (
+                V1_REJECT_VERSION,
+                NetMessage::Reject(RejectReason::VersionMismatch { server: 1, client: 2 }),
+            ),
+            (
+                V1_MOVE_CLAIM,
+                NetMessage::MoveClaim {
+                    tick: 42,
+                    input: Vec3::X,
+                    position: Vec3::new(2.0, 0.5, -1.0),
+                },
+            ),
+            (
+                V1_MOVE_CORRECTION,
+                NetMessage::MoveCorrection {
+                    tick: 7,
+                    position: Vec3::Y,
+                },
+            ),
+        ]
+    }
+
+    #[test]
+    fn version_1_wire_format_is_frozen() {
+        assert_eq!(PROTOCOL_VERSION, 1, "released a new version? freeze its bytes too");
+        for (bytes, message) in v1_messages() {
+            assert_eq!(message.encode(), bytes, "{message:?}");
+            assert_eq!(NetMessage::decode(bytes), Ok(message));
+        }
+    }
+
+    #[test]
+    fn other_versions_still_read_hello_and_reject_but_nothing_else() {
+        let from_v2 = |bytes: &[u8]| {
+            let mut bytes = bytes.to_vec();
+            bytes[4] = 2;
+            NetMessage::decode(&bytes)
+        };
+        assert!(matches!(from_v2(V1_HELLO), Ok(NetMessage::Hello(_))));
+        assert!(matches!(from_v2(V1_REJECT_VERSION), Ok(NetMessage::Reject(_))));
+        assert_eq!(
+            from_v2(V1_MOVE_CLAIM),
+            Err(DecodeError::VersionMismatch { ours: 1, theirs: 2 })
+        );
+        assert_eq!(NetMessage::decode(b"GET / HTTP/1.1"), Err(DecodeError::Foreign));
+        assert_eq!(NetMessage::decode(&V1_WELCOME[..9]), Err(DecodeError::Malformed("truncated")));
+    }
+
+    fn server_info(required: ProtocolFeatures) -> ServerInfo {
+        let (features, _) = ProtocolFeatures::local(SimMath::Quantized);
+        ServerInfo {
+            features,
+            required,
+            tick_hz: 30,
+            max_clients: 8,
+        }
+    }
+
+    /// Run client and server against each other until the client settles.
+    fn shake(server_info: ServerInfo, client_hello: impl FnOnce(&mut Hello)) -> HandshakeState {
+        let network = LoopbackNetwork::default();
+        let mut server_transport = network.endpoint();
+        let mut client_transport = network.endpoint();
+        let mut server = ServerHandshake::new(server_info);
+        let mut client = ClientHandshake::new(server_transport.local_addr(), SimMath::Float);
+        client_hello(&mut client.hello);
+
+        let dt = Duration::from_millis(50);
+        for _ in 0..200 {
+            let state = client.poll(&mut client_transport, dt).unwrap().clone();
+            if !matches!(state, HandshakeState::Connecting { .. }) {
+                return state;
+            }
+            server.receive(&mut server_transport).unwrap();
+            network.advance(dt);
+        }
+        client.state
+    }
+
+    #[test]
+    fn matching_builds_connect_with_the_common_features() {
+        let state = shake(server_info(ProtocolFeatures::NONE), |_| {});
+        let HandshakeState::Connected(welcome) = state else {
+            panic!("{state:?}");
+        };
+        assert_eq!(welcome.server_tick_hz, 30);
+        assert!(welcome.features.contains(ProtocolFeatures::MOVEMENT_VALIDATION));
+        assert!(!welcome.features.contains(ProtocolFeatures::QUANTIZED_MATH), "client runs floats");
+    }
+
+    #[test]
+    fn mismatched_builds_fail_fast_with_a_reason() {
+        let state = shake(server_info(ProtocolFeatures::NONE), |hello| hello.protocol = 2);
+        assert_eq!(
+            state,
+            HandshakeState::Rejected(RejectReason::VersionMismatch { server: 1, client: 2 })
+        );
+        let HandshakeState::Rejected(reason) = state else { unreachable!() };
+        assert_eq!(reason.to_string(), "the server speaks protocol 1, this game 2: update the server");
+
+        let state = shake(server_info(ProtocolFeatures::QUANTIZED_MATH), |_| {});
+        assert_eq!(
+            state,
+            HandshakeState::Rejected(RejectReason::MissingFeatures {
+                features: ProtocolFeatures::QUANTIZED_MATH,
+                server_lacks: false,
+            })
+        );
+    }
+
+    #[test]
+    fn unanswered_hellos_time_out() {
+        let network = LoopbackNetwork::default();
+        let mut client_transport = network.endpoint();
+        let silent = network.endpoint();
+        let mut client = ClientHandshake::new(silent.local_addr(), SimMath::Float);
+        let mut sent = 0;
+        while matches!(client.state, HandshakeState::Connecting { .. }) {
+            client.poll(&mut client_transport, Duration::from_millis(100)).unwrap();
+            sent += 1;
+        }
+        assert_eq!(client.state, HandshakeState::TimedOut);
+        assert_eq!(sent, 50);
+    }
+}
@@ -0,0 +1,100 @@
+// src/features/net/systems.rs
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use super::conditioner::{ConditionerSettings, NetworkConditions};
+use crate::features::console::component::Console;
+use crate::features::console::systems::ConsoleCommand;
+
+pub const NET_SIM_COMMAND: &str = "net_sim";
+pub const NET_SIM_USAGE: &str =
+    "net_sim [on|off|latency <ms>|jitter <ms>|loss <%>|reorder <%>]: network conditioner";
+
+/// Apply one `net_sim` argument pair to `settings`.
+pub fn apply_net_sim_args(settings: &mut ConditionerSettings, args: &[String]) -> Result<(), String> {
+    let number = |value: Option<&String>| -> Result<f32, String> {
+        let value = value.ok_or("missing value")?;
+        value
+            .parse::<f32>()
+            .ok()
+            .filter(|v| v.is_finite() && *v >= 0.0)
+            .ok_or_else(|| format!("`{value}` is not a non-negative number"))
+    };
+    let millis = |ms: f32| Duration::from_micros((ms * 1000.0).round() as u64);
+    let percent = |p: f32| (p / 100.0).min(1.0);
+    match args.first().map(String::as_str) {
+        None => {}
+        Some("on") => settings.enabled = true,
+        Some("off") => settings.enabled = false,
+        Some("latency") => settings.link.latency = millis(number(args.get(1))?),
+        Some("jitter") => settings.link.jitter = millis(number(args.get(1))?),
+        Some("loss") => settings.link.loss = percent(number(args.get(1))?),
+        Some("reorder") => settings.link.reorder = percent(number(args.get(1))?),
+        Some(other) => return Err(format!("unknown option `{other}`")),
+    }
+    Ok(())
+}
+
+fn describe(settings: &ConditionerSettings) -> String {
+    format!(
+        "net_sim {}: latency {} ms, jitter {} ms, loss {:.0}%, reorder {:.0}%",
+        if settings.enabled { "on" } else { "off" },
+        settings.link.latency.as_millis(),
+        settings.link.jitter.as_millis(),
+        settings.link.loss * 100.0,
+        settings.link.reorder * 100.0,
+    )
+}
+
+/// Update: `net_sim` console commands edit the shared `NetworkConditions`.
+pub fn handle_net_sim_command(
+    mut commands: MessageReader<ConsoleCommand>,
+    conditions: Res<NetworkConditions>,
+    mut console: ResMut<Console>,
+) {
+    for command in commands.read().filter(|c| c.name == NET_SIM_COMMAND) {
+        let mut settings = conditions.get();
+        match apply_net_sim_args(&mut settings, &command.args) {
+            Ok(()) => {
+                conditions.set(settings);
+                console.print(describe(&settings));
+            }
+            Err(err) => console.print(format!("{err}\n{NET_SIM_USAGE}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn run(world: &mut World, line: &str) {
+        world.write_message(ConsoleCommand::parse(line).unwrap());
+        let _ = world.run_system_once(handle_net_sim_command);
+    }
+
+    #[test]
+    fn console_commands_configure_the_shared_conditions() {
+        let mut world = World::new();
+        world.init_resource::<Messages<ConsoleCommand>>();
+        world.init_resource::<Console>();
+        let conditions = NetworkConditions::default();
+        world.insert_resource(conditions.clone());
+
+        run(&mut world, "net_sim latency 80");
+        run(&mut world, "net_sim loss 5");
+        run(&mut world, "net_sim on");
+        let settings = conditions.get();
+        assert!(settings.enabled);
+        assert_eq!(settings.link.latency, Duration::from_millis(80));
+        assert!((settings.link.loss - 0.05).abs() < 1e-6);
+        assert!(world.resource::<Console>().lines.back().unwrap().starts_with("net_sim on"));
+
+        run(&mut world, "net_sim jitter fast");
+        assert_eq!(conditions.get(), settings, "bad input changes nothing");
+        assert!(world.resource::<Console>().lines.back().unwrap().contains("not a non-negative number"));
+    }
+}
@@ -0,0 +1,75 @@
+// src/features/net/transport.rs
+use std::fmt;
+use std::net::SocketAddr;
+
+/// Largest payload a transport accepts: fits one UDP datagram on any sane path MTU
+/// without IP fragmentation.
+pub const MAX_PACKET_SIZE: usize = 1200;
+
+/// Why a transport couldn't send or receive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransportError {
+    Io(String),
+    /// Payload over `MAX_PACKET_SIZE`.
+    TooLarge { size: usize },
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::TooLarge { size } => {
+                write!(f, "packet of {size} bytes is over the {MAX_PACKET_SIZE} byte limit")
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for TransportError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err.to_string())
+    }
+}
+
+/// One datagram taken off a transport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub from: SocketAddr,
+    pub payload: Vec<u8>,
+}
+
+/// Unreliable, unordered datagrams between addressed endpoints (UDP semantics).
+///
+/// Packets may be lost, duplicated or reordered; anything above (sequencing,
+/// acks, sessions) is the protocol's job, so it behaves the same over every
+/// implementation.
+pub trait Transport: Send + Sync + 'static {
+    /// Address peers send to to reach this endpoint.
+    fn local_addr(&self) -> SocketAddr;
+
+    /// Queue `payload` for `to`. Unreachable peers are not an error (the packet is
+    /// just lost), oversized payloads are.
+    fn send(&mut self, to: SocketAddr, payload: &[u8]) -> Result<(), TransportError>;
+
+    /// Next packet that has arrived, without blocking (`Ok(None)` when there is none).
+    fn receive(&mut self) -> Result<Option<Packet>, TransportError>;
+
+    /// Every packet that has arrived, without blocking.
+    fn receive_all(&mut self) -> Result<Vec<Packet>, TransportError> {
+        let mut packets = Vec::new();
+        while let Some(packet) = self.receive()? {
+            packets.push(packet);
+        }
+        Ok(packets)
+    }
+}
+
+/// Reject payloads no transport may carry.
+pub fn check_size(payload: &[u8]) -> Result<(), TransportError> {
+    if payload.len() > MAX_PACKET_SIZE {
+        return Err(TransportError::TooLarge {
+            size: payload.len(),
+        });
+    }
+    Ok(())
+}
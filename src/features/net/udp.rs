@@ -0,0 +1,101 @@
+// src/features/net/udp.rs
+use std::io::ErrorKind;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use super::transport::{MAX_PACKET_SIZE, Packet, Transport, TransportError, check_size};
+
+/// `Transport` over a non-blocking UDP socket (real play).
+#[derive(Debug)]
+pub struct UdpTransport {
+    socket: UdpSocket,
+    local_addr: SocketAddr,
+    buffer: Vec<u8>,
+}
+
+impl UdpTransport {
+    /// Bind to `addr` (port `0` picks a free one).
+    pub fn bind(addr: impl ToSocketAddrs) -> Result<Self, TransportError> {
+        let socket = UdpSocket::bind(addr)?;
+        socket.set_nonblocking(true)?;
+        let local_addr = socket.local_addr()?;
+        Ok(Self {
+            socket,
+            local_addr,
+            // One spare byte tells a truncated (oversized) datagram apart.
+            buffer: vec![0; MAX_PACKET_SIZE + 1],
+        })
+    }
+}
+
+impl Transport for UdpTransport {
+    fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    fn send(&mut self, to: SocketAddr, payload: &[u8]) -> Result<(), TransportError> {
+        check_size(payload)?;
+        match self.socket.send_to(payload, to) {
+            Ok(_) => Ok(()),
+            // A full socket buffer drops the packet, like the network would.
+            Err(err) if err.kind() == ErrorKind::WouldBlock => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn receive(&mut self) -> Result<Option<Packet>, TransportError> {
+        loop {
+            match self.socket.recv_from(&mut self.buffer) {
+                Ok((len, _)) if len > MAX_PACKET_SIZE => continue,
+                Ok((len, from)) => {
+                    return Ok(Some(Packet {
+                        from,
+                        payload: self.buffer[..len].to_vec(),
+                    }));
+                }
+                Err(err) if err.kind() == ErrorKind::WouldBlock => return Ok(None),
+                // Windows reports an earlier send to a closed port here; that's a lost
+                // packet, not a broken socket.
+                Err(err) if err.kind() == ErrorKind::ConnectionReset => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn datagrams_round_trip_over_localhost() {
+        let mut server = UdpTransport::bind("127.0.0.1:0").unwrap();
+        let mut client = UdpTransport::bind("127.0.0.1:0").unwrap();
+        assert_eq!(server.receive(), Ok(None), "non-blocking");
+
+        client.send(server.local_addr(), b"hello").unwrap();
+        let mut packet = None;
+        for _ in 0..500 {
+            packet = server.receive().unwrap();
+            if packet.is_some() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        assert_eq!(
+            packet,
+            Some(Packet {
+                from: client.local_addr(),
+                payload: b"hello".to_vec(),
+            })
+        );
+
+        let oversized = vec![0; MAX_PACKET_SIZE + 1];
+        assert_eq!(
+            client.send(server.local_addr(), &oversized),
+            Err(TransportError::TooLarge {
+                size: MAX_PACKET_SIZE + 1
+            })
+        );
+    }
+}
@@ -0,0 +1,332 @@
+// src/features/net/validation.rs
+use bevy::prelude::*;
+
+use crate::features::collision::query::CollisionWorld;
+use crate::features::movement::component::{ExternalForce, MoveInput, Velocity};
+
+/// Limits the server holds client movement to.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct MovementValidation {
+    /// Headroom on the simulated speed before a step counts as too fast (float
+    /// noise, a tick of input latency).
+    pub speed_slack: f32,
+    /// Claimed positions this close to the server's are accepted as-is.
+    pub position_tolerance: f32,
+    /// A single step longer than this is a teleport, whatever the speed.
+    pub teleport_distance: f32,
+    /// Violations before a client is flagged for review / kicking.
+    pub flag_after: u32,
+}
+
+impl Default for MovementValidation {
+    fn default() -> Self {
+        Self {
+            speed_slack: 1.15,
+            position_tolerance: 0.05,
+            teleport_distance: 4.0,
+            flag_after: 5,
+        }
+    }
+}
+
+/// What a client submitted for one tick: its input and where its prediction ended
+/// up after applying it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveClaim {
+    pub tick: u64,
+    pub input: Vec3,
+    pub position: Vec3,
+}
+
+/// Server side of a client-controlled mover.
+///
+/// The receive path stores the client's latest `claim`; the server simulates the
+/// claimed input with the regular movement pipeline and checks the claimed position
+/// against the result.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ClientMovement {
+    pub claim: Option<MoveClaim>,
+    /// Where the server had the mover after the last validated tick (the next
+    /// claimed step starts there, whether or not the last claim was accepted).
+    pub last_server_position: Option<Vec3>,
+    /// This tick's push speed (`ExternalForce`) before integration decayed it.
+    pub push_speed: f32,
+    pub violations: u32,
+    /// Reached `MovementValidation::flag_after` violations.
+    pub flagged: bool,
+}
+
+impl ClientMovement {
+    /// Count a violation, flagging the client once it reaches `flag_after`.
+    pub fn record_violation(&mut self, flag_after: u32) {
+        self.violations += 1;
+        self.flagged |= self.violations >= flag_after;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// Input longer than full stick / all keys.
+    InputOutOfRange,
+    /// Moved further in a tick than its speed allows.
+    TooFast,
+    /// Moved further in a tick than anyone could.
+    Teleport,
+    /// Moved through a collider.
+    ThroughWall,
+}
+
+/// A client broke the movement rules.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct MovementViolation {
+    pub entity: Entity,
+    pub tick: u64,
+    pub kind: ViolationKind,
+}
+
+/// The client's predicted position is wrong: it must snap to `position` (and replay
+/// its later inputs).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct MovementCorrection {
+    pub entity: Entity,
+    pub tick: u64,
+    pub position: Vec3,
+}
+
+/// FixedUpdate (server, before `compute_velocity_from_input`): feed claimed inputs
+/// to the movement pipeline, clamped to full stick.
+///
+/// The server's velocity then comes from the exact same `compute_velocity_from_input`
+/// the client predicted with, so honest clients never disagree with it.
+pub fn apply_client_inputs(
+    settings: Res<MovementValidation>,
+    mut violations: MessageWriter<MovementViolation>,
+    mut q_clients: Query<(Entity, &mut ClientMovement, &mut MoveInput)>,
+) {
+    for (entity, mut client, mut move_input) in &mut q_clients {
+        let Some(claim) = client.claim else {
+            continue;
+        };
+        if !claim.input.is_finite() || claim.input.length() > 1.0 + 1e-3 {
+            violations.write(MovementViolation {
+                entity,
+                tick: claim.tick,
+                kind: ViolationKind::InputOutOfRange,
+            });
+            client.record_violation(settings.flag_after);
+        }
+        let input = if claim.input.is_finite() {
+            claim.input.clamp_length_max(1.0)
+        } else {
+            Vec3::ZERO
+        };
+        move_input.0 = input;
+    }
+}
+
+/// FixedUpdate (server, before `integrate_velocity`): note the push speed each
+/// client moves with this tick, before integration decays it.
+pub fn record_client_pushes(
+    time: Res<Time<Fixed>>,
+    mut q_clients: Query<(&mut ClientMovement, &ExternalForce)>,
+) {
+    let dt = time.delta_secs();
+    for (mut client, external) in &mut q_clients {
+        client.push_speed = (external.velocity + external.acceleration * dt).length();
+    }
+}
+
+/// FixedUpdate (server, after `integrate_velocity`): check each claimed position
+/// against the rules and the server's own result.
+///
+/// - a step longer than `teleport_distance`, faster than the simulated velocity
+///   (plus pushes) allows, or crossing a collider is a violation
+/// - any violation, or a claim further than `position_tolerance` from the server,
+///   sends a `MovementCorrection` to the server position (the server never adopts
+///   a claimed position)
+pub fn validate_client_positions(
+    time: Res<Time<Fixed>>,
+    settings: Res<MovementValidation>,
+    collision: CollisionWorld,
+    mut violations: MessageWriter<MovementViolation>,
+    mut corrections: MessageWriter<MovementCorrection>,
+    mut q_clients: Query<(Entity, &mut ClientMovement, &Transform, &Velocity)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut client, transform, velocity) in &mut q_clients {
+        let Some(claim) = client.claim.take() else {
+            continue;
+        };
+        let server = transform.translation;
+        let from = client.last_server_position.unwrap_or(server);
+        let step = claim.position - from;
+        let distance = step.length();
+
+        // Pushes moved the mover too, at their speed before integration decayed them.
+        let max_step = (velocity.0.length() + client.push_speed) * dt * settings.speed_slack
+            + settings.position_tolerance;
+        let kind = if !claim.position.is_finite() || distance > settings.teleport_distance {
+            Some(ViolationKind::Teleport)
+        } else if distance > max_step {
+            Some(ViolationKind::TooFast)
+        } else if distance > 0.0
+            && collision
+                .ray_cast(from, step, distance, &[entity])
+                .is_some()
+        {
+            Some(ViolationKind::ThroughWall)
+        } else {
+            None
+        };
+
+        if let Some(kind) = kind {
+            violations.write(MovementViolation {
+                entity,
+                tick: claim.tick,
+                kind,
+            });
+            client.record_violation(settings.flag_after);
+        }
+        if kind.is_some() || claim.position.distance(server) > settings.position_tolerance {
+            corrections.write(MovementCorrection {
+                entity,
+                tick: claim.tick,
+                position: server,
+            });
+        }
+        client.last_server_position = Some(server);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::SimMath;
+    use crate::features::collision::component::Collider;
+    use crate::features::movement::component::MoveConfig;
+    use crate::features::movement::systems::{compute_velocity_from_input, integrate_velocity};
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    fn setup_world() -> (World, Entity) {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<SimMath>();
+        world.init_resource::<MovementValidation>();
+        world.init_resource::<Messages<MovementViolation>>();
+        world.init_resource::<Messages<MovementCorrection>>();
+        let mut fixed_time = Time::<Fixed>::from_hz(60.0);
+        fixed_time.advance_by(Duration::from_secs_f64(1.0 / 60.0));
+        world.insert_resource(fixed_time);
+        let client = world
+            .spawn((
+                ClientMovement::default(),
+                MoveInput(Vec3::ZERO),
+                MoveConfig::uniform(6.0),
+                Transform::default(),
+                Velocity::default(),
+            ))
+            .id();
+        (world, client)
+    }
+
+    /// One server tick for a client claiming `input` got it to `position`.
+    fn tick(world: &mut World, client: Entity, tick: u64, input: Vec3, position: Vec3) {
+        world.get_mut::<ClientMovement>(client).unwrap().claim = Some(MoveClaim {
+            tick,
+            input,
+            position,
+        });
+        let _ = world.run_system_once(apply_client_inputs);
+        let _ = world.run_system_once(compute_velocity_from_input);
+        let _ = world.run_system_once(record_client_pushes);
+        let _ = world.run_system_once(integrate_velocity);
+        let _ = world.run_system_once(validate_client_positions);
+    }
+
+    fn drain<T: Message>(world: &mut World) -> Vec<T> {
+        world.resource_mut::<Messages<T>>().drain().collect()
+    }
+
+    #[test]
+    fn honest_clients_predicting_with_the_same_pipeline_pass() {
+        let (mut world, client) = setup_world();
+        // The client runs the same math: 6 u/s forward for 1/60 s per tick.
+        let mut predicted = Vec3::ZERO;
+        for t in 1..=30 {
+            predicted += Vec3::NEG_Z * 6.0 / 60.0;
+            tick(&mut world, client, t, Vec3::NEG_Z, predicted);
+        }
+        assert!(drain::<MovementViolation>(&mut world).is_empty());
+        assert!(drain::<MovementCorrection>(&mut world).is_empty());
+    }
+
+    #[test]
+    fn speed_hacks_and_teleports_are_flagged_and_corrected() {
+        let (mut world, client) = setup_world();
+        tick(&mut world, client, 1, Vec3::NEG_Z, Vec3::NEG_Z * 0.5);
+        tick(&mut world, client, 2, Vec3::NEG_Z, Vec3::NEG_Z * 20.0);
+        tick(&mut world, client, 3, Vec3::NEG_Z * 3.0, Vec3::NEG_Z * 0.3);
+
+        let kinds: Vec<ViolationKind> = drain::<MovementViolation>(&mut world)
+            .into_iter()
+            .map(|v| v.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ViolationKind::TooFast,
+                ViolationKind::Teleport,
+                ViolationKind::InputOutOfRange
+            ]
+        );
+        // Cheating ticks snap back to the server; the clamped tick matched it.
+        let corrections = drain::<MovementCorrection>(&mut world);
+        let ticks: Vec<u64> = corrections.iter().map(|c| c.tick).collect();
+        assert_eq!(ticks, vec![1, 2]);
+        assert!((corrections[1].position - Vec3::NEG_Z * 0.2).length() < 1e-4);
+        let server = world.get::<Transform>(client).unwrap().translation;
+        assert!((server - Vec3::NEG_Z * 0.3).length() < 1e-4, "clamped input, real speed");
+        assert_eq!(world.get::<ClientMovement>(client).unwrap().violations, 3);
+    }
+
+    #[test]
+    fn moving_through_colliders_is_flagged() {
+        let (mut world, client) = setup_world();
+        world.insert_resource(MovementValidation {
+            speed_slack: 100.0,
+            ..default()
+        });
+        world.spawn((
+            Collider::cuboid(Vec3::new(2.0, 2.0, 0.05)),
+            GlobalTransform::from_xyz(0.0, 0.0, -0.5),
+        ));
+
+        tick(&mut world, client, 1, Vec3::NEG_Z, Vec3::NEG_Z);
+
+        let violations = drain::<MovementViolation>(&mut world);
+        assert_eq!(violations[0].kind, ViolationKind::ThroughWall);
+    }
+
+    #[test]
+    fn knockback_allows_its_undecayed_speed() {
+        let (mut world, client) = setup_world();
+        // Decays to about a third within the tick.
+        let mut push = ExternalForce {
+            damping: 60.0,
+            ..default()
+        };
+        push.apply_impulse(Vec3::X * 30.0);
+        world.entity_mut(client).insert(push);
+
+        // The whole impulse moves the mover this tick (decay starts after it).
+        tick(&mut world, client, 1, Vec3::ZERO, Vec3::X * 0.5);
+
+        assert!(drain::<MovementViolation>(&mut world).is_empty());
+        assert!(drain::<MovementCorrection>(&mut world).is_empty());
+        let client = world.get::<ClientMovement>(client).unwrap();
+        assert_eq!(client.push_speed, 30.0);
+    }
+}
@@ -0,0 +1,149 @@
+// src/features/notifications/component.rs
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+/// What a notification is about (toast accent color, log filtering).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotifyKind {
+    #[default]
+    Info,
+    Pickup,
+    Quest,
+    Achievement,
+    Connection,
+    Warning,
+}
+
+impl NotifyKind {
+    pub fn color(self) -> Color {
+        match self {
+            Self::Info => Color::srgb(0.85, 0.85, 0.85),
+            Self::Pickup => Color::srgb(0.55, 0.9, 0.5),
+            Self::Quest => Color::srgb(1.0, 0.85, 0.3),
+            Self::Achievement => Color::srgb(0.75, 0.55, 1.0),
+            Self::Connection => Color::srgb(0.45, 0.75, 1.0),
+            Self::Warning => Color::srgb(1.0, 0.45, 0.35),
+        }
+    }
+}
+
+/// A toast currently on screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShownToast {
+    pub text: String,
+    pub kind: NotifyKind,
+    pub remaining_secs: f32,
+}
+
+/// Toasts waiting for a free slot and the ones on screen (oldest first).
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct Toasts {
+    pub queue: VecDeque<(NotifyKind, String)>,
+    pub shown: Vec<ShownToast>,
+}
+
+impl Toasts {
+    /// Age shown toasts, drop expired ones and fill free slots from the queue.
+    pub fn advance(&mut self, dt: f32, settings: &NotificationSettings) {
+        for toast in &mut self.shown {
+            toast.remaining_secs -= dt;
+        }
+        self.shown.retain(|t| t.remaining_secs > 0.0);
+        while self.shown.len() < settings.max_visible {
+            let Some((kind, text)) = self.queue.pop_front() else {
+                break;
+            };
+            self.shown.push(ShownToast {
+                text,
+                kind,
+                remaining_secs: settings.toast_secs,
+            });
+        }
+    }
+}
+
+/// One line of the event log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub text: String,
+    pub kind: NotifyKind,
+}
+
+/// Every notification, newest last, capped at `NotificationSettings::log_capacity`.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct EventLog {
+    pub entries: VecDeque<LogEntry>,
+    pub open: bool,
+}
+
+impl EventLog {
+    pub fn push(&mut self, kind: NotifyKind, text: String, capacity: usize) {
+        self.entries.push_back(LogEntry { text, kind });
+        while self.entries.len() > capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct NotificationSettings {
+    /// Toasts stacked on screen at once; the rest wait in the queue.
+    pub max_visible: usize,
+    pub toast_secs: f32,
+    /// Final seconds of a toast spent fading out.
+    pub fade_secs: f32,
+    pub log_capacity: usize,
+    pub log_toggle: KeyCode,
+    /// Logical pixels scrolled per mouse wheel line in the event log.
+    pub log_scroll_speed: f32,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            max_visible: 4,
+            toast_secs: 4.0,
+            fade_secs: 0.5,
+            log_capacity: 50,
+            log_toggle: KeyCode::KeyL,
+            log_scroll_speed: 24.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toasts_queue_beyond_the_visible_limit_and_expire() {
+        let settings = NotificationSettings {
+            max_visible: 2,
+            toast_secs: 1.0,
+            ..default()
+        };
+        let mut toasts = Toasts::default();
+        for n in 0..3 {
+            toasts.queue.push_back((NotifyKind::Info, format!("n{n}")));
+        }
+
+        toasts.advance(0.0, &settings);
+        assert_eq!(toasts.shown.len(), 2);
+        assert_eq!(toasts.queue.len(), 1);
+
+        toasts.advance(1.0, &settings);
+        assert_eq!(toasts.shown.len(), 1);
+        assert_eq!(toasts.shown[0].text, "n2");
+    }
+
+    #[test]
+    fn log_keeps_the_newest_entries() {
+        let mut log = EventLog::default();
+        for n in 0..5 {
+            log.push(NotifyKind::Info, format!("n{n}"), 3);
+        }
+        let texts: Vec<_> = log.entries.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["n2", "n3", "n4"]);
+    }
+}
@@ -0,0 +1,53 @@
+// src/features/notifications/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Toasts and the event log.
+///
+/// Scope:
+/// - `Notify` message in: any feature (pickups, quests, achievements, connection
+///   status) announces something to the player
+/// - toasts queue up and stack on screen (`NotificationSettings::max_visible`),
+///   each timed and fading out; they run on real time, so they keep going while paused
+/// - every notification also lands in the event log (`L` toggles it, mouse wheel scrolls)
+/// - built-in sources: items granted to the player (`ItemsAdded`)
+///
+/// Design constraints:
+/// - widgets are pooled at startup and updated in place.
+pub struct NotificationsPlugin;
+
+impl Plugin for NotificationsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::NotificationSettings>();
+        app.init_resource::<component::Toasts>();
+        app.init_resource::<component::EventLog>();
+
+        app.add_message::<systems::Notify>();
+        app.add_message::<crate::features::inventory::ItemsAdded>();
+
+        app.add_systems(Startup, ui::spawn_notification_ui);
+        app.add_systems(
+            Update,
+            systems::toggle_event_log
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::notify_pickups,
+                systems::queue_notifications,
+                systems::advance_toasts,
+                ui::update_toast_slots,
+                ui::update_event_log,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
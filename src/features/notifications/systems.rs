@@ -0,0 +1,111 @@
+// src/features/notifications/systems.rs
+use bevy::prelude::*;
+
+use super::component::{EventLog, NotificationSettings, NotifyKind, Toasts};
+use crate::features::inventory::ItemsAdded;
+use crate::features::player::component::Player;
+
+/// Show a toast and record it in the event log.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct Notify {
+    pub kind: NotifyKind,
+    pub text: String,
+}
+
+impl Notify {
+    pub fn new(kind: NotifyKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+        }
+    }
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(NotifyKind::Info, text)
+    }
+}
+
+/// Update: queue every `Notify` as a toast and log it.
+pub fn queue_notifications(
+    settings: Res<NotificationSettings>,
+    mut toasts: ResMut<Toasts>,
+    mut log: ResMut<EventLog>,
+    mut notifications: MessageReader<Notify>,
+) {
+    for notify in notifications.read() {
+        toasts.queue.push_back((notify.kind, notify.text.clone()));
+        log.push(notify.kind, notify.text.clone(), settings.log_capacity);
+    }
+}
+
+/// Update: age toasts on real time, so they keep moving while the game is paused.
+pub fn advance_toasts(
+    time: Res<Time<Real>>,
+    settings: Res<NotificationSettings>,
+    mut toasts: ResMut<Toasts>,
+) {
+    if toasts.queue.is_empty() && toasts.shown.is_empty() {
+        return;
+    }
+    toasts.advance(time.delta_secs(), &settings);
+}
+
+/// Update: items granted to a player -> pickup toast.
+pub fn notify_pickups(
+    mut added: MessageReader<ItemsAdded>,
+    q_players: Query<(), With<Player>>,
+    mut notify: MessageWriter<Notify>,
+) {
+    for ev in added.read() {
+        if q_players.contains(ev.entity) {
+            notify.write(Notify::new(
+                NotifyKind::Pickup,
+                format!("+{} {}", ev.amount, ev.item),
+            ));
+        }
+    }
+}
+
+/// Update: open / close the event log.
+pub fn toggle_event_log(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<NotificationSettings>,
+    mut log: ResMut<EventLog>,
+) {
+    if keys.just_pressed(settings.log_toggle) {
+        log.open = !log.open;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn player_pickups_become_logged_toasts() {
+        let mut world = World::new();
+        world.init_resource::<NotificationSettings>();
+        world.init_resource::<Toasts>();
+        world.init_resource::<EventLog>();
+        world.init_resource::<Messages<ItemsAdded>>();
+        world.init_resource::<Messages<Notify>>();
+        let player = world.spawn(Player).id();
+        let chest = world.spawn_empty().id();
+        for entity in [player, chest] {
+            world.write_message(ItemsAdded {
+                entity,
+                item: "wood".into(),
+                amount: 3,
+            });
+        }
+
+        let _ = world.run_system_once(notify_pickups);
+        let _ = world.run_system_once(queue_notifications);
+
+        let toasts = world.resource::<Toasts>();
+        assert_eq!(toasts.queue, [(NotifyKind::Pickup, "+3 wood".to_string())]);
+        assert_eq!(world.resource::<EventLog>().entries.len(), 1);
+    }
+}
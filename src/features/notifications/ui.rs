@@ -0,0 +1,173 @@
+// src/features/notifications/ui.rs
+use bevy::input::mouse::AccumulatedMouseScroll;
+use bevy::prelude::*;
+
+use super::component::{EventLog, NotificationSettings, Toasts};
+
+/// Column the toasts stack in (top center).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ToastStack;
+
+/// Pooled toast widget showing `Toasts::shown[index]`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToastSlot {
+    pub index: usize,
+}
+
+/// Scrollable event log panel (hidden until toggled).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct EventLogPanel;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct EventLogText;
+
+/// Startup: toast slots and the event log panel.
+pub fn spawn_notification_ui(mut commands: Commands, settings: Res<NotificationSettings>) {
+    commands
+        .spawn((
+            ToastStack,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(24.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            Pickable::IGNORE,
+            Name::new("ToastStack"),
+        ))
+        .with_children(|stack| {
+            for index in 0..settings.max_visible {
+                stack.spawn((
+                    ToastSlot { index },
+                    Text::new(""),
+                    TextFont::from_font_size(16.0),
+                    TextColor(Color::WHITE),
+                    Node {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.85)),
+                    Visibility::Hidden,
+                ));
+            }
+        });
+
+    commands
+        .spawn((
+            EventLogPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(24.0),
+                bottom: Val::Px(24.0),
+                width: Val::Px(360.0),
+                max_height: Val::Px(240.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            ScrollPosition::DEFAULT,
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.8)),
+            Visibility::Hidden,
+            Name::new("EventLog"),
+        ))
+        .with_child((EventLogText, Text::new(""), TextFont::from_font_size(14.0)));
+}
+
+/// Update: fill the toast slots; toasts fade during their last `fade_secs`.
+pub fn update_toast_slots(
+    toasts: Res<Toasts>,
+    settings: Res<NotificationSettings>,
+    mut q_slots: Query<(
+        &ToastSlot,
+        &mut Text,
+        &mut TextColor,
+        &mut BackgroundColor,
+        &mut Visibility,
+    )>,
+) {
+    for (slot, mut text, mut color, mut background, mut vis) in &mut q_slots {
+        let Some(toast) = toasts.shown.get(slot.index) else {
+            *vis = Visibility::Hidden;
+            continue;
+        };
+        if text.0 != toast.text {
+            text.0.clone_from(&toast.text);
+        }
+        let alpha = (toast.remaining_secs / settings.fade_secs.max(f32::EPSILON)).min(1.0);
+        color.0 = toast.kind.color().with_alpha(alpha);
+        background.0 = background.0.with_alpha(0.85 * alpha);
+        *vis = Visibility::Inherited;
+    }
+}
+
+/// Update: event log visibility, contents (newest first) and wheel scrolling.
+pub fn update_event_log(
+    log: Res<EventLog>,
+    settings: Res<NotificationSettings>,
+    scroll: Option<Res<AccumulatedMouseScroll>>,
+    mut q_panel: Query<(&mut Visibility, &mut ScrollPosition), With<EventLogPanel>>,
+    mut q_text: Query<&mut Text, With<EventLogText>>,
+) {
+    for (mut vis, mut position) in &mut q_panel {
+        *vis = if log.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if log.open
+            && let Some(scroll) = &scroll
+        {
+            position.0.y = (position.0.y - scroll.delta.y * settings.log_scroll_speed).max(0.0);
+        }
+    }
+
+    if !log.is_changed() {
+        return;
+    }
+    let contents = log
+        .entries
+        .iter()
+        .rev()
+        .map(|e| e.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    for mut text in &mut q_text {
+        text.0.clone_from(&contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::notifications::component::{NotifyKind, ShownToast};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn slots_mirror_shown_toasts() {
+        let mut world = World::new();
+        world.init_resource::<NotificationSettings>();
+        world.init_resource::<EventLog>();
+        world.insert_resource(Toasts {
+            shown: vec![ShownToast {
+                text: "+3 wood".into(),
+                kind: NotifyKind::Pickup,
+                remaining_secs: 2.0,
+            }],
+            ..default()
+        });
+        let _ = world.run_system_once(spawn_notification_ui);
+        let _ = world.run_system_once(update_toast_slots);
+
+        let mut visible: Vec<_> = world
+            .query::<(&ToastSlot, &Text, &Visibility)>()
+            .iter(&world)
+            .filter(|(_, _, vis)| **vis == Visibility::Inherited)
+            .map(|(slot, text, _)| (slot.index, text.0.clone()))
+            .collect();
+        visible.sort();
+        assert_eq!(visible, [(0, "+3 wood".to_string())]);
+    }
+}
@@ -0,0 +1,135 @@
+// src/features/npc/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// One stop of a daily schedule: be around `at` between the two hours.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ScheduleEntry {
+    pub from_hour: f32,
+    pub to_hour: f32,
+    pub at: (f32, f32, f32),
+    /// Wander radius around `at` once there (`0.0` = stand still).
+    pub radius: f32,
+}
+
+/// How an NPC moves around when nobody is talking to it.
+#[derive(Component, Debug, Clone, PartialEq, Deserialize)]
+pub enum NpcRoutine {
+    /// Stroll to random points around the spawn position.
+    Wander(f32),
+    /// Walk the waypoints in order, looping.
+    Patrol(Vec<(f32, f32, f32)>),
+    /// Follow the time of day (`TimeOfDay`); the first matching entry wins.
+    Schedule(Vec<ScheduleEntry>),
+}
+
+/// One NPC definition, loaded from `assets/data/npcs.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NpcDef {
+    pub name: String,
+    pub position: (f32, f32, f32),
+    pub dialogue: String,
+    /// Walking speed (world units/sec).
+    pub speed: f32,
+    pub routine: NpcRoutine,
+    /// `assets/data/shops.ron` id if the NPC is a vendor.
+    #[serde(default)]
+    pub shop: Option<String>,
+}
+
+/// NPCs placed in the world at startup.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct NpcSpawns(pub Vec<NpcDef>);
+
+const BUILTIN_NPCS: &str = include_str!("../../../assets/data/npcs.ron");
+
+impl NpcSpawns {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+}
+
+impl Default for NpcSpawns {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_NPCS).expect("assets/data/npcs.ron must parse")
+    }
+}
+
+/// Friendly, non-combat character.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Npc {
+    pub name: String,
+}
+
+/// Walking state of an NPC.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct NpcMotion {
+    pub speed: f32,
+    /// Spawn point (center of `Wander`).
+    pub home: Vec3,
+    pub destination: Option<Vec3>,
+    /// Seconds to idle before picking the next destination.
+    pub wait_secs: f32,
+    pub patrol_index: usize,
+    /// Per-NPC random state (wander targets, idle times).
+    pub seed: u32,
+}
+
+impl NpcMotion {
+    pub fn new(home: Vec3, speed: f32, seed: u32) -> Self {
+        Self {
+            speed,
+            home,
+            destination: None,
+            wait_secs: 0.0,
+            patrol_index: 0,
+            // xorshift must not start at zero.
+            seed: seed.max(1),
+        }
+    }
+
+    /// Next pseudo-random value in `0.0..1.0` (xorshift32).
+    pub fn next_random(&mut self) -> f32 {
+        let mut x = self.seed;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.seed = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Random ground point within `radius` of `center`.
+    pub fn random_point_around(&mut self, center: Vec3, radius: f32) -> Vec3 {
+        let angle = self.next_random() * std::f32::consts::TAU;
+        let dist = radius * self.next_random().sqrt();
+        center + Vec3::new(angle.cos(), 0.0, angle.sin()) * dist
+    }
+}
+
+/// Distance at which an NPC counts as arrived.
+pub const NPC_ARRIVE_DISTANCE: f32 = 0.1;
+
+/// Capsule dimensions of the placeholder NPC body.
+pub const NPC_RADIUS: f32 = 0.3;
+pub const NPC_HALF_HEIGHT: f32 = 0.9;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_npcs_parse() {
+        let spawns = NpcSpawns::default();
+        assert!(spawns.0.iter().any(|n| matches!(n.routine, NpcRoutine::Schedule(_))));
+        assert!(spawns.0.iter().any(|n| matches!(n.routine, NpcRoutine::Patrol(_))));
+    }
+
+    #[test]
+    fn random_points_stay_within_radius() {
+        let mut motion = NpcMotion::new(Vec3::ZERO, 1.0, 7);
+        for _ in 0..100 {
+            let p = motion.random_point_around(Vec3::new(1.0, 0.0, 1.0), 2.0);
+            assert!(p.distance(Vec3::new(1.0, 0.0, 1.0)) <= 2.0 + 1e-4);
+        }
+    }
+}
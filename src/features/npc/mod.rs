@@ -0,0 +1,36 @@
+// src/features/npc/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::meshes::component::MeshLibrary;
+
+pub mod component;
+pub mod systems;
+
+/// Friendly NPCs.
+///
+/// Scope:
+/// - `NpcSpawns`: NPCs from `assets/data/npcs.ron` (name, dialogue id, speed, routine)
+/// - routines: wander around home, patrol waypoints, or follow a daily schedule
+///   driven by `TimeOfDay`
+/// - NPCs are `Interactable` + `Talkable`: the interaction key starts their dialogue,
+///   and while `InConversation` they stop walking and face the player
+/// - NPCs with a `shop` id are `Vendor`s (see `features::shop`)
+/// - NPCs are `Faction::Villagers`
+/// - routines steer `Velocity`; the shared `MovementPlugin` pipeline moves them
+pub struct NpcPlugin;
+
+impl Plugin for NpcPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::NpcSpawns>();
+        app.init_resource::<MeshLibrary>();
+
+        app.add_systems(Startup, systems::spawn_npcs);
+        app.add_systems(
+            FixedUpdate,
+            (systems::plan_npc_routes, systems::steer_npcs)
+                .chain()
+                .in_set(AppSet::ReadIntent),
+        );
+    }
+}
@@ -0,0 +1,262 @@
+// src/features/npc/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    NPC_ARRIVE_DISTANCE, NPC_HALF_HEIGHT, NPC_RADIUS, Npc, NpcMotion, NpcRoutine, NpcSpawns,
+};
+use crate::app::Faction;
+use crate::features::character_material::toon::Stylized;
+use crate::features::collision::component::Collider;
+use crate::features::dialogue::component::{InConversation, Talkable};
+use crate::features::interaction::component::Interactable;
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::movement::component::Velocity;
+use crate::features::shop::component::Vendor;
+use crate::features::time_of_day::TimeOfDay;
+
+/// Turn rate used when NPCs face where they walk / who they talk to (1/sec).
+const NPC_TURN_RATE: f32 = 8.0;
+
+/// Startup: spawn NPCs from `NpcSpawns`.
+pub fn spawn_npcs(
+    mut commands: Commands,
+    spawns: Res<NpcSpawns>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.get(MeshPrimitive::Capsule);
+    // The shared capsule is 1 wide and 2 tall.
+    let scale = Vec3::new(NPC_RADIUS * 2.0, NPC_HALF_HEIGHT, NPC_RADIUS * 2.0);
+    let material = materials.add(Color::srgb_u8(90, 170, 110));
+
+    for (i, def) in spawns.0.iter().enumerate() {
+        let ground = Vec3::from(def.position);
+        let position = ground + Vec3::Y * NPC_HALF_HEIGHT;
+        let mut npc = commands.spawn((
+            Npc {
+                name: def.name.clone(),
+            },
+            Faction::Villagers,
+            def.routine.clone(),
+            NpcMotion::new(position, def.speed, 0x9E37_79B9 ^ (i as u32 + 1)),
+            Interactable::new(format!("Talk to {}", def.name)),
+            Talkable {
+                dialogue: def.dialogue.clone(),
+            },
+            Collider::cuboid(Vec3::new(NPC_RADIUS, NPC_HALF_HEIGHT, NPC_RADIUS)),
+            Velocity::default(),
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Stylized,
+            Transform::from_translation(position).with_scale(scale),
+            Name::new(def.name.clone()),
+        ));
+        if let Some(shop) = &def.shop {
+            npc.insert(Vendor::new(shop.clone()));
+        }
+    }
+}
+
+/// FixedUpdate: pick the next destination for idle NPCs from their routine.
+///
+/// NPCs in a conversation keep their plan but don't advance it.
+pub fn plan_npc_routes(
+    time: Res<Time<Fixed>>,
+    clock: Option<Res<TimeOfDay>>,
+    mut q_npcs: Query<(&NpcRoutine, &mut NpcMotion, &Transform), Without<InConversation>>,
+) {
+    let dt = time.delta_secs();
+
+    for (routine, mut motion, transform) in &mut q_npcs {
+        if motion.destination.is_some() {
+            continue;
+        }
+        motion.wait_secs -= dt;
+        if motion.wait_secs > 0.0 {
+            continue;
+        }
+
+        let y = transform.translation.y;
+        let next = match routine {
+            NpcRoutine::Wander(radius) => {
+                let home = motion.home;
+                Some(motion.random_point_around(home, *radius))
+            }
+            NpcRoutine::Patrol(points) if !points.is_empty() => {
+                let index = motion.patrol_index % points.len();
+                motion.patrol_index = index + 1;
+                Some(Vec3::from(points[index]))
+            }
+            NpcRoutine::Patrol(_) => None,
+            NpcRoutine::Schedule(entries) => clock
+                .as_ref()
+                .and_then(|clock| entries.iter().find(|e| clock.is_between(e.from_hour, e.to_hour)))
+                .map(|entry| {
+                    let at = Vec3::from(entry.at).with_y(y);
+                    if transform.translation.distance(at) > entry.radius + NPC_ARRIVE_DISTANCE {
+                        at
+                    } else {
+                        motion.random_point_around(at, entry.radius)
+                    }
+                }),
+        };
+
+        // Idle a little before setting off again.
+        motion.wait_secs = 1.0 + motion.next_random() * 2.0;
+        motion.destination = next
+            .map(|p| p.with_y(y))
+            .filter(|p| p.distance(transform.translation) > NPC_ARRIVE_DISTANCE);
+    }
+}
+
+/// FixedUpdate (before integration): steer `Velocity` towards the destination,
+/// arriving exactly on it; in conversation, stand still and face the other party
+/// instead.
+pub fn steer_npcs(
+    time: Res<Time<Fixed>>,
+    mut q_npcs: Query<
+        (&mut NpcMotion, &mut Transform, &mut Velocity, Option<&InConversation>),
+        With<Npc>,
+    >,
+    q_others: Query<&GlobalTransform>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut motion, mut transform, mut velocity, conversation) in &mut q_npcs {
+        velocity.0 = Vec3::ZERO;
+        let facing = if let Some(conversation) = conversation {
+            q_others
+                .get(conversation.with)
+                .map(|other| other.translation() - transform.translation)
+                .unwrap_or(Vec3::ZERO)
+        } else if let Some(destination) = motion.destination {
+            let to = destination - transform.translation;
+            let step = motion.speed * dt;
+            if to.length() <= step.max(NPC_ARRIVE_DISTANCE) {
+                // Cover the rest of the way this tick.
+                if dt > 0.0 {
+                    velocity.0 = to / dt;
+                }
+                motion.destination = None;
+            } else {
+                velocity.0 = to.normalize() * motion.speed;
+            }
+            to
+        } else {
+            Vec3::ZERO
+        };
+
+        let facing = facing.with_y(0.0);
+        if facing.length_squared() > 1e-6 {
+            let target = Transform::IDENTITY.looking_to(facing, Vec3::Y).rotation;
+            let t = 1.0 - (-NPC_TURN_RATE * dt).exp();
+            transform.rotation = transform.rotation.slerp(target, t);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::movement::systems::integrate_velocity;
+    use crate::features::npc::component::ScheduleEntry;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    fn setup_world(dt: f32) -> World {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(dt));
+        world.insert_resource(fixed);
+        world
+    }
+
+    fn spawn_npc(world: &mut World, routine: NpcRoutine) -> Entity {
+        world
+            .spawn((
+                Npc { name: "Test".into() },
+                routine,
+                NpcMotion::new(Vec3::ZERO, 2.0, 1),
+                Transform::default(),
+                Velocity::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn patrol_walks_waypoints_in_order() {
+        let mut world = setup_world(0.5);
+        let npc = spawn_npc(
+            &mut world,
+            NpcRoutine::Patrol(vec![(1.0, 0.0, 0.0), (1.0, 0.0, 1.0)]),
+        );
+
+        let _ = world.run_system_once(plan_npc_routes);
+        assert_eq!(world.get::<NpcMotion>(npc).unwrap().destination, Some(Vec3::X));
+
+        // 2 units/sec * 0.5s = 1 unit: arrives.
+        let _ = world.run_system_once(steer_npcs);
+        let _ = world.run_system_once(integrate_velocity);
+        assert_eq!(world.get::<Transform>(npc).unwrap().translation, Vec3::X);
+        assert_eq!(world.get::<NpcMotion>(npc).unwrap().destination, None);
+
+        world.get_mut::<NpcMotion>(npc).unwrap().wait_secs = 0.0;
+        let _ = world.run_system_once(plan_npc_routes);
+        assert_eq!(
+            world.get::<NpcMotion>(npc).unwrap().destination,
+            Some(Vec3::new(1.0, 0.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn schedule_follows_the_clock() {
+        let mut world = setup_world(0.1);
+        world.insert_resource(TimeOfDay {
+            hour: 20.0,
+            hours_per_sec: 0.0,
+        });
+        let npc = spawn_npc(
+            &mut world,
+            NpcRoutine::Schedule(vec![
+                ScheduleEntry {
+                    from_hour: 6.0,
+                    to_hour: 18.0,
+                    at: (5.0, 0.0, 0.0),
+                    radius: 0.0,
+                },
+                ScheduleEntry {
+                    from_hour: 18.0,
+                    to_hour: 6.0,
+                    at: (0.0, 0.0, -5.0),
+                    radius: 0.0,
+                },
+            ]),
+        );
+
+        let _ = world.run_system_once(plan_npc_routes);
+
+        assert_eq!(
+            world.get::<NpcMotion>(npc).unwrap().destination,
+            Some(Vec3::new(0.0, 0.0, -5.0))
+        );
+    }
+
+    #[test]
+    fn npcs_in_conversation_stop_walking() {
+        let mut world = setup_world(0.5);
+        let talker = world.spawn(GlobalTransform::from_translation(Vec3::X)).id();
+        let npc = spawn_npc(&mut world, NpcRoutine::Wander(3.0));
+        world.entity_mut(npc).insert(InConversation { with: talker });
+        world.get_mut::<NpcMotion>(npc).unwrap().destination = Some(Vec3::NEG_Z * 3.0);
+
+        let _ = world.run_system_once(steer_npcs);
+        let _ = world.run_system_once(integrate_velocity);
+
+        let tr = world.get::<Transform>(npc).unwrap();
+        assert_eq!(tr.translation, Vec3::ZERO);
+        // Turning towards the talker (+X).
+        assert!((tr.rotation * Vec3::NEG_Z).x > 0.0);
+    }
+}
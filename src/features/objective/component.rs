@@ -0,0 +1,57 @@
+// src/features/objective/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// One waypoint objective, loaded from `assets/data/objectives.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectiveDef {
+    /// Shown on the HUD marker and in the completion toast.
+    pub label: String,
+    /// World-space point to reach.
+    pub position: (f32, f32, f32),
+    /// Completion distance (world units).
+    pub radius: f32,
+}
+
+/// Objectives of the current run, in completion order.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ObjectiveSpawns(pub Vec<ObjectiveDef>);
+
+const BUILTIN_OBJECTIVES: &str = include_str!("../../../assets/data/objectives.ron");
+
+impl ObjectiveSpawns {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+}
+
+impl Default for ObjectiveSpawns {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_OBJECTIVES).expect("assets/data/objectives.ron must parse")
+    }
+}
+
+/// A place the player has been asked to go; the entity's `Transform` is the spot.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Objective {
+    pub label: String,
+    pub radius: f32,
+    /// Position in the objective chain (lowest goes first).
+    pub order: usize,
+}
+
+/// Marks the objective currently being pursued (HUD markers show these).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveObjective;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_objectives_parse() {
+        let spawns = ObjectiveSpawns::default();
+        assert!(!spawns.0.is_empty());
+        assert!(spawns.0.iter().all(|o| o.radius > 0.0 && !o.label.is_empty()));
+    }
+}
@@ -0,0 +1,36 @@
+// src/features/objective/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::notifications::systems::Notify;
+
+pub mod component;
+pub mod systems;
+
+/// Waypoint objectives.
+///
+/// Scope:
+/// - `ObjectiveSpawns`: a chain of waypoints from `assets/data/objectives.ron`
+/// - the first remaining objective carries `ActiveObjective`; a living player within
+///   its radius completes it (`ObjectiveCompleted` + toast) and activates the next
+///
+/// Design constraints:
+/// - objectives are plain entities (`Objective` + `Transform`): anything that hands
+///   out goals (dialogue hooks, scripted events) spawns them the same way, and the
+///   HUD markers follow whatever is active.
+pub struct ObjectivePlugin;
+
+impl Plugin for ObjectivePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::ObjectiveSpawns>();
+
+        app.add_message::<systems::ObjectiveCompleted>();
+        app.add_message::<Notify>();
+
+        app.add_systems(Startup, systems::spawn_objectives);
+        app.add_systems(
+            FixedUpdate,
+            systems::complete_reached_objectives.in_set(AppSet::FixedGameplay),
+        );
+    }
+}
@@ -0,0 +1,117 @@
+// src/features/objective/systems.rs
+use bevy::prelude::*;
+
+use super::component::{ActiveObjective, Objective, ObjectiveSpawns};
+use crate::features::notifications::systems::Notify;
+use crate::features::player::death::AlivePlayer;
+
+/// An objective was reached (quest logic, audio, achievements).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ObjectiveCompleted {
+    pub label: String,
+}
+
+/// Startup: spawn the `ObjectiveSpawns` chain; the first one starts active.
+pub fn spawn_objectives(mut commands: Commands, spawns: Res<ObjectiveSpawns>) {
+    for (order, def) in spawns.0.iter().enumerate() {
+        let mut objective = commands.spawn((
+            Objective {
+                label: def.label.clone(),
+                radius: def.radius,
+                order,
+            },
+            Transform::from_translation(Vec3::from(def.position)),
+            Name::new(format!("Objective: {}", def.label)),
+        ));
+        if order == 0 {
+            objective.insert(ActiveObjective);
+        }
+    }
+}
+
+/// FixedUpdate: a living player within reach completes the active objective; the
+/// next one in order becomes active.
+pub fn complete_reached_objectives(
+    mut commands: Commands,
+    q_players: Query<&Transform, AlivePlayer>,
+    q_active: Query<(Entity, &Objective, &Transform), With<ActiveObjective>>,
+    q_pending: Query<(Entity, &Objective), Without<ActiveObjective>>,
+    mut completed: MessageWriter<ObjectiveCompleted>,
+    mut notify: MessageWriter<Notify>,
+) {
+    for (entity, objective, transform) in &q_active {
+        let reached = q_players.iter().any(|player| {
+            player.translation.with_y(0.0).distance(transform.translation.with_y(0.0))
+                <= objective.radius
+        });
+        if !reached {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        completed.write(ObjectiveCompleted {
+            label: objective.label.clone(),
+        });
+        notify.write(Notify::info(format!("Objective complete: {}", objective.label)));
+
+        if let Some((next, _)) = q_pending.iter().min_by_key(|(_, o)| o.order) {
+            commands.entity(next).insert(ActiveObjective);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::objective::component::ObjectiveDef;
+    use crate::features::player::component::Player;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<ObjectiveCompleted>>();
+        world.init_resource::<Messages<Notify>>();
+        world.insert_resource(ObjectiveSpawns(vec![
+            ObjectiveDef {
+                label: "First".into(),
+                position: (0.0, 0.0, -10.0),
+                radius: 2.0,
+            },
+            ObjectiveDef {
+                label: "Second".into(),
+                position: (10.0, 0.0, 0.0),
+                radius: 2.0,
+            },
+        ]));
+        let _ = world.run_system_once(spawn_objectives);
+        world
+    }
+
+    fn active_label(world: &mut World) -> Option<String> {
+        world
+            .query_filtered::<&Objective, With<ActiveObjective>>()
+            .iter(world)
+            .next()
+            .map(|o| o.label.clone())
+    }
+
+    #[test]
+    fn reaching_the_active_objective_advances_the_chain() {
+        let mut world = setup_world();
+        assert_eq!(active_label(&mut world).as_deref(), Some("First"));
+        let player = world.spawn((Player, Transform::default())).id();
+
+        let _ = world.run_system_once(complete_reached_objectives);
+        assert_eq!(world.resource::<Messages<ObjectiveCompleted>>().len(), 0, "too far");
+
+        // Height doesn't matter, only the ground distance.
+        world.get_mut::<Transform>(player).unwrap().translation = Vec3::new(0.0, 3.0, -8.5);
+        let _ = world.run_system_once(complete_reached_objectives);
+
+        assert_eq!(world.resource::<Messages<ObjectiveCompleted>>().len(), 1);
+        assert_eq!(world.resource::<Messages<Notify>>().len(), 1);
+        assert_eq!(active_label(&mut world).as_deref(), Some("Second"));
+        assert_eq!(world.query::<&Objective>().iter(&world).count(), 1);
+    }
+}
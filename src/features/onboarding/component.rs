@@ -0,0 +1,123 @@
+// src/features/onboarding/component.rs
+use bevy::prelude::*;
+
+use crate::features::settings::component::{ControlScheme, Language, UserSettings};
+
+/// Volume change per `-` / `+` click.
+pub const VOLUME_STEP: f32 = 0.1;
+
+/// Pages of the first-run flow, in order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnboardingStep {
+    #[default]
+    Language,
+    Volume,
+    Controls,
+}
+
+impl OnboardingStep {
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Language => "Choose your language",
+            Self::Volume => "Set the volume",
+            Self::Controls => "How do you play?",
+        }
+    }
+
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Self::Language => Some(Self::Volume),
+            Self::Volume => Some(Self::Controls),
+            Self::Controls => None,
+        }
+    }
+
+    pub fn previous(self) -> Option<Self> {
+        match self {
+            Self::Language => None,
+            Self::Volume => Some(Self::Language),
+            Self::Controls => Some(Self::Volume),
+        }
+    }
+}
+
+/// Current page of the flow.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Onboarding {
+    pub step: OnboardingStep,
+}
+
+/// What an onboarding button does.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingAction {
+    SetLanguage(Language),
+    /// Master volume up (`+1`) or down (`-1`) by `VOLUME_STEP`.
+    AdjustVolume(i8),
+    SetControls(ControlScheme),
+    Back,
+    /// Next page, or finish on the last one.
+    Next,
+}
+
+/// Result of applying an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingOutcome {
+    Continue,
+    Finished,
+}
+
+impl Onboarding {
+    /// Apply `action` to the page and the settings being chosen.
+    pub fn apply(&mut self, action: OnboardingAction, settings: &mut UserSettings) -> OnboardingOutcome {
+        match action {
+            OnboardingAction::SetLanguage(language) => settings.language = language,
+            OnboardingAction::AdjustVolume(direction) => {
+                let master = settings.volume.master + f32::from(direction) * VOLUME_STEP;
+                // Snap to whole steps so repeated clicks don't drift.
+                settings.volume.master = ((master / VOLUME_STEP).round() * VOLUME_STEP).clamp(0.0, 1.0);
+            }
+            OnboardingAction::SetControls(controls) => settings.controls = controls,
+            OnboardingAction::Back => {
+                if let Some(step) = self.step.previous() {
+                    self.step = step;
+                }
+            }
+            OnboardingAction::Next => match self.step.next() {
+                Some(step) => self.step = step,
+                None => {
+                    settings.onboarded = true;
+                    return OnboardingOutcome::Finished;
+                }
+            },
+        }
+        OnboardingOutcome::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walking_all_pages_finishes_with_the_choices() {
+        let mut flow = Onboarding::default();
+        let mut settings = UserSettings::default();
+        settings.volume.master = 1.0;
+
+        flow.apply(OnboardingAction::SetLanguage(Language::French), &mut settings);
+        flow.apply(OnboardingAction::Next, &mut settings);
+        flow.apply(OnboardingAction::AdjustVolume(-1), &mut settings);
+        flow.apply(OnboardingAction::AdjustVolume(-1), &mut settings);
+        flow.apply(OnboardingAction::Next, &mut settings);
+        flow.apply(OnboardingAction::SetControls(ControlScheme::Gamepad), &mut settings);
+        assert!(!settings.onboarded);
+
+        let outcome = flow.apply(OnboardingAction::Next, &mut settings);
+
+        assert_eq!(outcome, OnboardingOutcome::Finished);
+        assert!(settings.onboarded);
+        assert_eq!(settings.language, Language::French);
+        assert!((settings.volume.master - 0.8).abs() < 1e-6);
+        assert_eq!(settings.controls, ControlScheme::Gamepad);
+    }
+}
@@ -0,0 +1,39 @@
+// src/features/onboarding/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GameState};
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// First-run onboarding.
+///
+/// Scope:
+/// - on a launch whose settings never finished onboarding, the game starts in
+///   `GameState::Onboarding`: language, master volume and control scheme pages
+/// - choices apply live (`UserSettings`); finishing saves the settings file and
+///   enters `Playing`, so later launches skip the flow
+///
+/// Design constraints:
+/// - the flow only edits `UserSettings`; applying them is the settings feature's job.
+pub struct OnboardingPlugin;
+
+impl Plugin for OnboardingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::Onboarding>();
+
+        app.add_systems(
+            Startup,
+            (ui::spawn_onboarding_screen, systems::start_onboarding_on_first_run),
+        );
+        app.add_systems(
+            Update,
+            (systems::click_onboarding_buttons, ui::update_onboarding_screen)
+                .chain()
+                .after(AppSet::Input)
+                .run_if(in_state(GameState::Onboarding)),
+        );
+        app.add_systems(OnExit(GameState::Onboarding), ui::hide_onboarding_screen);
+    }
+}
@@ -0,0 +1,65 @@
+// src/features/onboarding/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Onboarding, OnboardingAction, OnboardingOutcome};
+use crate::app::GameState;
+use crate::features::settings::component::UserSettings;
+use crate::features::settings::systems::SaveSettings;
+
+/// Startup: first launch (settings never completed onboarding) -> run the flow.
+pub fn start_onboarding_on_first_run(
+    settings: Res<UserSettings>,
+    mut onboarding: ResMut<Onboarding>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if !settings.onboarded {
+        *onboarding = Onboarding::default();
+        next.set(GameState::Onboarding);
+    }
+}
+
+/// Update (onboarding): button clicks edit the settings; finishing saves them and
+/// starts the game.
+pub fn click_onboarding_buttons(
+    q_buttons: Query<(&Interaction, &OnboardingAction), Changed<Interaction>>,
+    mut onboarding: ResMut<Onboarding>,
+    mut settings: ResMut<UserSettings>,
+    mut save: MessageWriter<SaveSettings>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    for (interaction, &action) in &q_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if onboarding.apply(action, &mut settings) == OnboardingOutcome::Finished {
+            save.write(SaveSettings);
+            next.set(GameState::Playing);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    #[test]
+    fn only_first_launch_enters_onboarding() {
+        for (onboarded, expected) in [(false, GameState::Onboarding), (true, GameState::Playing)] {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin);
+            app.init_state::<GameState>();
+            app.init_resource::<Onboarding>();
+            app.insert_resource(UserSettings {
+                onboarded,
+                ..default()
+            });
+
+            let _ = app.world_mut().run_system_once(start_onboarding_on_first_run);
+            app.update();
+
+            assert_eq!(*app.world().resource::<State<GameState>>().get(), expected);
+        }
+    }
+}
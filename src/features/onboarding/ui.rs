@@ -0,0 +1,179 @@
+// src/features/onboarding/ui.rs
+use bevy::prelude::*;
+
+use super::component::{Onboarding, OnboardingAction, OnboardingStep};
+use crate::app::GameState;
+use crate::features::settings::component::{ControlScheme, Language, UserSettings};
+
+const OPTION_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.08);
+const SELECTED_COLOR: Color = Color::srgb(0.2, 0.35, 0.65);
+
+/// Full-screen onboarding root (hidden outside `GameState::Onboarding`).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct OnboardingScreen;
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct OnboardingTitle;
+
+/// Container the current page's controls are rebuilt into.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct OnboardingPage;
+
+/// Startup: spawn the (hidden) onboarding screen.
+pub fn spawn_onboarding_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            OnboardingScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.04, 0.04, 0.06)),
+            GlobalZIndex(10),
+            Visibility::Hidden,
+            Name::new("OnboardingScreen"),
+        ))
+        .with_children(|screen| {
+            screen.spawn((OnboardingTitle, Text::new(""), TextFont::from_font_size(28.0)));
+            screen.spawn((
+                OnboardingPage,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Update: show the screen while onboarding; the page is rebuilt when the step or
+/// a choice changes.
+pub fn update_onboarding_screen(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    onboarding: Res<Onboarding>,
+    settings: Res<UserSettings>,
+    mut q_screen: Query<&mut Visibility, With<OnboardingScreen>>,
+    mut q_title: Query<&mut Text, With<OnboardingTitle>>,
+    q_page: Query<Entity, With<OnboardingPage>>,
+) {
+    let active = *state.get() == GameState::Onboarding;
+    for mut vis in &mut q_screen {
+        *vis = if active {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    if !active || !(state.is_changed() || onboarding.is_changed() || settings.is_changed()) {
+        return;
+    }
+
+    for mut title in &mut q_title {
+        title.0 = onboarding.step.title().to_string();
+    }
+    for page in &q_page {
+        commands.entity(page).despawn_related::<Children>();
+        commands.entity(page).with_children(|page| {
+            page.spawn(Node {
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|row| match onboarding.step {
+                OnboardingStep::Language => {
+                    for language in Language::ALL {
+                        let selected = settings.language == language;
+                        option_button(row, OnboardingAction::SetLanguage(language), language.label(), selected);
+                    }
+                }
+                OnboardingStep::Volume => {
+                    option_button(row, OnboardingAction::AdjustVolume(-1), "-", false);
+                    row.spawn((
+                        Text::new(format!("{:.0}%", settings.volume.master * 100.0)),
+                        TextFont::from_font_size(20.0),
+                    ));
+                    option_button(row, OnboardingAction::AdjustVolume(1), "+", false);
+                }
+                OnboardingStep::Controls => {
+                    for controls in ControlScheme::ALL {
+                        let selected = settings.controls == controls;
+                        option_button(row, OnboardingAction::SetControls(controls), controls.label(), selected);
+                    }
+                }
+            });
+
+            page.spawn(Node {
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|row| {
+                if onboarding.step.previous().is_some() {
+                    option_button(row, OnboardingAction::Back, "Back", false);
+                }
+                let next = if onboarding.step.next().is_some() {
+                    "Next"
+                } else {
+                    "Start"
+                };
+                option_button(row, OnboardingAction::Next, next, true);
+            });
+        });
+    }
+}
+
+/// OnExit(Onboarding): hide the screen.
+pub fn hide_onboarding_screen(mut q_screen: Query<&mut Visibility, With<OnboardingScreen>>) {
+    for mut vis in &mut q_screen {
+        *vis = Visibility::Hidden;
+    }
+}
+
+fn option_button(parent: &mut ChildSpawnerCommands, action: OnboardingAction, label: &str, selected: bool) {
+    parent
+        .spawn((
+            Button,
+            action,
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(if selected { SELECTED_COLOR } else { OPTION_COLOR }),
+        ))
+        .with_child((Text::new(label), TextFont::from_font_size(18.0)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn language_page_offers_every_language() {
+        let mut world = World::new();
+        world.insert_resource(State::new(GameState::Onboarding));
+        world.init_resource::<Onboarding>();
+        world.init_resource::<UserSettings>();
+        let _ = world.run_system_once(spawn_onboarding_screen);
+        let _ = world.run_system_once(update_onboarding_screen);
+
+        let languages = world
+            .query::<&OnboardingAction>()
+            .iter(&world)
+            .filter(|a| matches!(a, OnboardingAction::SetLanguage(_)))
+            .count();
+        assert_eq!(languages, Language::ALL.len());
+        let vis = *world
+            .query_filtered::<&Visibility, With<OnboardingScreen>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(vis, Visibility::Inherited);
+    }
+}
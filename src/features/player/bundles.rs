@@ -1,60 +1,172 @@
 // src/features/player/bundles.rs
 use bevy::prelude::*;
 
-use super::component::{MoveInput, MoveSpeed, Player, Velocity};
+use super::component::{Player, PlayerVisual};
+use crate::app::{Faction, Tuning};
+use crate::features::character_material::component::{
+    CharacterFx, CharacterMaterial, character_material,
+};
+use crate::features::health::component::Health;
+use crate::features::materials::component::MaterialLibrary;
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::movement::component::{
+    ExternalForce, MoveConfig, MoveInput, SpeedMultiplier, SweptCollider, Velocity,
+};
+
+/// Starting (and respawn) hit points for the player.
+pub const PLAYER_MAX_HEALTH: f32 = 100.0;
+
+/// Where a default `SpawnPlayer` puts the player: slightly above ground so it
+/// "rests" visually on the ground plane (y=0).
+pub const PLAYER_SPAWN_POINT: Vec3 = Vec3::new(0.0, 0.5, 0.0);
+
+/// Request one more player (menus, network join, drop-in co-op, tests).
+///
+/// The player feature is the only place that spawns players; callers decide when
+/// and how many.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SpawnPlayer {
+    pub translation: Vec3,
+}
+
+impl Default for SpawnPlayer {
+    fn default() -> Self {
+        Self {
+            translation: PLAYER_SPAWN_POINT,
+        }
+    }
+}
+
+/// Emitted for each player spawned from a `SpawnPlayer` request.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerSpawned {
+    pub entity: Entity,
+}
+
+/// Compatibility switch for the single-player case: request one player at startup.
+///
+/// Turn off when something else (menu, netcode, a test) owns player spawning.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerSpawnSettings {
+    pub auto_spawn: bool,
+}
+
+impl Default for PlayerSpawnSettings {
+    fn default() -> Self {
+        Self { auto_spawn: true }
+    }
+}
 
 /// Convenience bundle for spawning a player with all required movement components.
 ///
 /// Notes:
 /// - We include `MoveInput` (local intent) and `Velocity` (world velocity) from day 1,
 ///   so swapping integration for collision/physics later is painless.
-/// - We intentionally do *not* attach any physics/collision components yet.
+/// - `ExternalForce` is present so other features can push the player without
+///   inserting components first.
+/// - `SweptCollider` stops the player at level geometry (a dash can't carry it
+///   through a wall); it is not a `Collider`, so nothing collides with the player.
+/// - This is the logic root only; visuals go on a `PlayerVisual` child (see `spawn_player`).
 #[derive(Bundle)]
 pub struct PlayerBundle {
     pub player: Player,
-    pub speed: MoveSpeed,
+    pub faction: Faction,
+    pub move_config: MoveConfig,
+    pub speed_multiplier: SpeedMultiplier,
     pub input: MoveInput,
     pub velocity: Velocity,
+    pub external_force: ExternalForce,
+    pub swept_collider: SweptCollider,
+    pub health: Health,
     pub transform: Transform,
+    pub visibility: Visibility,
 }
 
 impl PlayerBundle {
     pub fn new(spawn_translation: Vec3, speed_units_per_sec: f32) -> Self {
         Self {
             player: Player,
-            speed: MoveSpeed(speed_units_per_sec),
+            faction: Faction::Players,
+            move_config: MoveConfig::uniform(speed_units_per_sec),
+            speed_multiplier: SpeedMultiplier::default(),
             input: MoveInput(Vec3::ZERO),
             velocity: Velocity(Vec3::ZERO),
+            external_force: ExternalForce::default(),
+            swept_collider: SweptCollider::default(),
+            health: Health::new(PLAYER_MAX_HEALTH),
             transform: Transform::from_translation(spawn_translation),
+            visibility: Visibility::default(),
         }
     }
 }
 
-/// Spawns the player entity (Option A: the player feature owns the player).
+/// Startup: fire the single default `SpawnPlayer` if auto-spawn is on.
+pub fn request_initial_player(
+    settings: Res<PlayerSpawnSettings>,
+    mut requests: MessageWriter<SpawnPlayer>,
+) {
+    if settings.auto_spawn {
+        requests.write(SpawnPlayer::default());
+    }
+}
+
+/// Update (before input): spawn one player per `SpawnPlayer` request (Option A:
+/// the player feature owns the player).
 ///
-/// Minimal visuals: a lit cube so we can see motion immediately.
+/// - Logic root: `PlayerBundle` at `Tuning::player_speed`, plus `CharacterFx` (effects
+///   follow the root's health).
+/// - Visual child (`PlayerVisual`): a lit cube so we can see motion immediately.
 pub fn spawn_player(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut requests: MessageReader<SpawnPlayer>,
+    tuning: Res<Tuning>,
+    library: Res<MaterialLibrary>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<CharacterMaterial>>,
+    mut spawned: MessageWriter<PlayerSpawned>,
 ) {
-    // Spawn slightly above ground so it "rests" visually on the ground plane (y=0).
-    let spawn_pos = Vec3::new(0.0, 0.5, 0.0);
-
-    commands.spawn((
-        PlayerBundle::new(spawn_pos, 5.0),
-        // Visuals (PBR)
-        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
-        MeshMaterial3d(materials.add(Color::srgb_u8(240, 220, 120))),
-    ));
+    for request in requests.read() {
+        let entity = commands
+            .spawn((PlayerBundle::new(request.translation, tuning.player_speed), CharacterFx::default()))
+            .with_child((
+                PlayerVisual,
+                // Visuals (PBR)
+                Mesh3d(meshes.get(MeshPrimitive::Cube)),
+                MeshMaterial3d(materials.add(character_material(library.color("player")))),
+                Transform::default(),
+                Name::new("PlayerVisual"),
+            ))
+            .id();
+        spawned.write(PlayerSpawned { entity });
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::meshes::systems::build_mesh_library;
+    use bevy::ecs::message::Messages;
     use bevy::ecs::system::RunSystemOnce;
 
+    /// World with the spawn system's resources and `requests` pending `SpawnPlayer`s.
+    fn spawn_world(requests: usize) -> World {
+        let mut world = World::new();
+        // The system requires these resources. Assets<T> has Default, so we can insert it directly.
+        world.insert_resource(Assets::<Mesh>::default());
+        world.insert_resource(Assets::<CharacterMaterial>::default());
+        world.init_resource::<MaterialLibrary>();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Tuning>();
+        let _ = world.run_system_once(build_mesh_library);
+        world.init_resource::<Messages<SpawnPlayer>>();
+        world.init_resource::<Messages<PlayerSpawned>>();
+        for _ in 0..requests {
+            world.write_message(SpawnPlayer::default());
+        }
+        world
+    }
+
     // -------- PlayerBundle::new tests (pure constructor contract) --------
 
     #[test]
@@ -65,9 +177,13 @@ mod tests {
         let b = PlayerBundle::new(spawn, speed);
 
         assert_eq!(b.player, Player);
-        assert_eq!(b.speed, MoveSpeed(speed));
+        assert_eq!(b.faction, Faction::Players);
+        assert_eq!(b.move_config, MoveConfig::uniform(speed));
+        assert_eq!(b.speed_multiplier, SpeedMultiplier(1.0));
         assert_eq!(b.input, MoveInput(Vec3::ZERO));
         assert_eq!(b.velocity, Velocity(Vec3::ZERO));
+        assert_eq!(b.external_force, ExternalForce::default());
+        assert_eq!(b.health, Health::new(PLAYER_MAX_HEALTH));
         assert_eq!(b.transform.translation, spawn);
     }
 
@@ -75,33 +191,40 @@ mod tests {
 
     #[test]
     fn spawn_player_spawns_entity_with_required_components_and_assets() {
-        let mut world = World::new();
-
-        // The system requires these resources. Assets<T> has Default, so we can insert it directly.
-        world.insert_resource(Assets::<Mesh>::default());
-        world.insert_resource(Assets::<StandardMaterial>::default());
+        let mut world = spawn_world(1);
 
         // Run the spawn system once. This should apply Commands and actually spawn the entity.
         let _ = world.run_system_once(spawn_player);
 
-        // Find the spawned player entity.
+        // Find the spawned logic root.
         let mut q = world.query::<(
+            Entity,
             &Player,
-            &MoveSpeed,
+            &MoveConfig,
             &MoveInput,
             &Velocity,
             &Transform,
-            &Mesh3d,
-            &MeshMaterial3d<StandardMaterial>,
+            &Children,
         )>();
 
-        let ( _player, speed, input, vel, tr, mesh3d, mat3d) = q
+        let (root, _player, speed, input, vel, tr, children) = q
             .iter(&world)
             .next()
-            .expect("spawn_player should spawn exactly one entity with player + visuals");
+            .expect("spawn_player should spawn exactly one player root");
+        let children: Vec<Entity> = children.iter().collect();
+
+        // Visuals live on exactly one child, never on the root.
+        assert!(world.get::<Mesh3d>(root).is_none());
+        assert_eq!(children.len(), 1);
+        let visual = world.entity(children[0]);
+        assert!(visual.contains::<PlayerVisual>());
+        assert_eq!(visual.get::<ChildOf>().unwrap().parent(), root);
+        assert_eq!(*visual.get::<Transform>().unwrap(), Transform::IDENTITY);
+        let mesh3d = visual.get::<Mesh3d>().unwrap();
+        let mat3d = visual.get::<MeshMaterial3d<CharacterMaterial>>().unwrap();
 
         // Check gameplay components
-        assert_eq!(*speed, MoveSpeed(5.0));
+        assert_eq!(*speed, MoveConfig::uniform(5.0));
         assert_eq!(*input, MoveInput(Vec3::ZERO));
         assert_eq!(*vel, Velocity(Vec3::ZERO));
 
@@ -110,7 +233,7 @@ mod tests {
 
         // Check visuals: handles must exist in their asset storages
         let meshes = world.resource::<Assets<Mesh>>();
-        let materials = world.resource::<Assets<StandardMaterial>>();
+        let materials = world.resource::<Assets<CharacterMaterial>>();
 
         assert!(
             meshes.get(&mesh3d.0).is_some(),
@@ -118,17 +241,61 @@ mod tests {
         );
         assert!(
             materials.get(&mat3d.0).is_some(),
-            "Material handle must exist in Assets<StandardMaterial>"
+            "Material handle must exist in Assets<CharacterMaterial>"
         );
     }
 
     #[test]
-    fn spawn_player_is_idempotent_per_call_spawns_one_more_player() {
-        let mut world = World::new();
-        world.insert_resource(Assets::<Mesh>::default());
-        world.insert_resource(Assets::<StandardMaterial>::default());
+    fn spawn_player_spawns_exactly_one_player_per_request() {
+        let mut world = spawn_world(0);
+        let mut system = IntoSystem::into_system(spawn_player);
+        system.initialize(&mut world);
 
+        let _ = system.run((), &mut world);
+        world.flush();
+        assert_eq!(world.query::<&Player>().iter(&world).count(), 0, "no request, no player");
+
+        world.write_message(SpawnPlayer::default());
+        world.write_message(SpawnPlayer {
+            translation: Vec3::new(4.0, 0.5, 0.0),
+        });
+        let _ = system.run((), &mut world);
+        let _ = system.run((), &mut world);
+        world.flush();
+
+        let players = world.query::<&Player>().iter(&world).count();
+        let visuals = world.query::<&PlayerVisual>().iter(&world).count();
+        assert_eq!((players, visuals), (2, 2), "requests are handled once");
+        assert_eq!(world.resource::<Messages<PlayerSpawned>>().len(), 2);
+        assert!(
+            world
+                .query_filtered::<&Transform, With<Player>>()
+                .iter(&world)
+                .any(|tr| tr.translation == Vec3::new(4.0, 0.5, 0.0))
+        );
+    }
+
+    #[test]
+    fn auto_spawn_requests_one_player_unless_disabled() {
+        let mut world = spawn_world(0);
+        world.init_resource::<PlayerSpawnSettings>();
+        let _ = world.run_system_once(request_initial_player);
+        assert_eq!(world.resource::<Messages<SpawnPlayer>>().len(), 1);
+
+        let mut world = spawn_world(0);
+        world.insert_resource(PlayerSpawnSettings { auto_spawn: false });
+        let _ = world.run_system_once(request_initial_player);
+        assert_eq!(world.resource::<Messages<SpawnPlayer>>().len(), 0);
+    }
+
+    #[test]
+    fn despawning_the_root_takes_the_visual_with_it() {
+        let mut world = spawn_world(1);
         let _ = world.run_system_once(spawn_player);
-        let _ = world.run_system_once(spawn_player);
+
+        let root = world.query_filtered::<Entity, With<Player>>().single(&world).unwrap();
+        world.entity_mut(root).despawn();
+
+        assert_eq!(world.query::<&PlayerVisual>().iter(&world).count(), 0);
     }
 }    
\ No newline at end of file
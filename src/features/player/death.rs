@@ -0,0 +1,249 @@
+// src/features/player/death.rs
+use bevy::prelude::*;
+
+use super::component::Player;
+use crate::features::health::component::Health;
+use crate::features::health::damage::Died;
+use crate::features::movement::component::{ExternalForce, MoveInput, MovementLocked, Velocity};
+
+/// Marker: the player is dead and waiting to respawn.
+///
+/// While present:
+/// - input is not read into `MoveInput`
+/// - `MovementLocked` is set too, so the movement pipeline skips the entity
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Dead;
+
+/// Query filter: player entities that are alive (not in the `Dead` state).
+pub type AlivePlayer = (With<Player>, Without<Dead>);
+
+/// Query filter: player entities currently waiting to respawn.
+pub type DeadPlayer = (With<Player>, With<Dead>);
+
+/// Countdown until a dead player respawns.
+///
+/// Exposed as a component so UI can show "Respawning in N..." via `remaining_secs`.
+#[derive(Component, Debug, Clone)]
+pub struct RespawnTimer(pub Timer);
+
+impl RespawnTimer {
+    pub fn new(delay_secs: f32) -> Self {
+        Self(Timer::from_seconds(delay_secs, TimerMode::Once))
+    }
+
+    pub fn remaining_secs(&self) -> f32 {
+        self.0.remaining_secs()
+    }
+}
+
+/// Where and how quickly the player comes back after dying.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct RespawnSettings {
+    /// World-space translation the player is restored to.
+    pub point: Vec3,
+    /// Seconds between death and respawn.
+    pub delay_secs: f32,
+}
+
+impl Default for RespawnSettings {
+    fn default() -> Self {
+        Self {
+            point: Vec3::new(0.0, 0.5, 0.0),
+            delay_secs: 3.0,
+        }
+    }
+}
+
+/// A place dead players come back at (scene files' `spawn_points`); the one
+/// nearest to where the player died wins over `RespawnSettings::point`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SpawnPoint;
+
+/// Emitted when a player enters the `Dead` state (UI / audio hook).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerDied {
+    pub entity: Entity,
+}
+
+/// Emitted when a dead player has been restored (UI / audio hook).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerRespawned {
+    pub entity: Entity,
+}
+
+/// FixedUpdate: turn `Died` for player entities into the `Dead` state.
+///
+/// - Zeroes intent, velocity and any pending push so the player stops immediately.
+/// - Starts the respawn countdown.
+/// - Non-player deaths are ignored (their owning features handle them).
+pub fn enter_dead_state(
+    mut commands: Commands,
+    settings: Res<RespawnSettings>,
+    mut died: MessageReader<Died>,
+    mut q_player: Query<(&mut MoveInput, &mut Velocity, Option<&mut ExternalForce>), AlivePlayer>,
+    mut player_died: MessageWriter<PlayerDied>,
+) {
+    for ev in died.read() {
+        let Ok((mut move_input, mut velocity, external)) = q_player.get_mut(ev.entity) else {
+            continue;
+        };
+
+        move_input.0 = Vec3::ZERO;
+        velocity.0 = Vec3::ZERO;
+        if let Some(mut external) = external {
+            *external = ExternalForce::default();
+        }
+
+        commands
+            .entity(ev.entity)
+            .insert((Dead, MovementLocked, RespawnTimer::new(settings.delay_secs)));
+
+        player_died.write(PlayerDied { entity: ev.entity });
+    }
+}
+
+/// FixedUpdate: count down dead players and restore them when the timer expires.
+///
+/// On respawn:
+/// - Transform is reset to the nearest `SpawnPoint`, else the respawn point
+///   (identity rotation)
+/// - Health is reset to max
+/// - `Dead` / `MovementLocked` / `RespawnTimer` are removed
+pub fn tick_respawn(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    settings: Res<RespawnSettings>,
+    mut q_dead: Query<(Entity, &mut RespawnTimer, &mut Transform, &mut Health), DeadPlayer>,
+    q_points: Query<&Transform, (With<SpawnPoint>, Without<Player>)>,
+    mut respawned: MessageWriter<PlayerRespawned>,
+) {
+    for (entity, mut timer, mut transform, mut health) in &mut q_dead {
+        timer.0.tick(time.delta());
+
+        if !timer.0.is_finished() {
+            continue;
+        }
+
+        let died_at = transform.translation;
+        transform.translation = q_points
+            .iter()
+            .map(|point| point.translation)
+            .min_by(|a, b| a.distance_squared(died_at).total_cmp(&b.distance_squared(died_at)))
+            .unwrap_or(settings.point);
+        transform.rotation = Quat::IDENTITY;
+        health.reset();
+
+        commands.entity(entity).remove::<(Dead, MovementLocked, RespawnTimer)>();
+        respawned.write(PlayerRespawned { entity });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(RespawnSettings {
+            point: Vec3::new(1.0, 0.5, 2.0),
+            delay_secs: 2.0,
+        });
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<PlayerDied>>();
+        world.init_resource::<Messages<PlayerRespawned>>();
+        world
+    }
+
+    fn spawn_player(world: &mut World) -> Entity {
+        world
+            .spawn((
+                Player,
+                MoveInput(Vec3::NEG_Z),
+                Velocity(Vec3::new(0.0, 0.0, -5.0)),
+                Transform::from_xyz(9.0, 0.5, 9.0),
+                Health { current: 0.0, max: 100.0 },
+            ))
+            .id()
+    }
+
+    fn advance_fixed(world: &mut World, secs: f32) {
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(secs));
+        world.insert_resource(fixed);
+    }
+
+    #[test]
+    fn died_player_enters_dead_state_and_stops() {
+        let mut world = setup_world();
+        let e = spawn_player(&mut world);
+
+        world.write_message(Died { entity: e });
+        let _ = world.run_system_once(enter_dead_state);
+
+        assert!(world.get::<Dead>(e).is_some());
+        assert!(world.get::<MovementLocked>(e).is_some());
+        assert_eq!(world.get::<RespawnTimer>(e).unwrap().remaining_secs(), 2.0);
+        assert_eq!(world.get::<MoveInput>(e).unwrap().0, Vec3::ZERO);
+        assert_eq!(world.get::<Velocity>(e).unwrap().0, Vec3::ZERO);
+        assert_eq!(world.resource::<Messages<PlayerDied>>().len(), 1);
+    }
+
+    #[test]
+    fn non_player_deaths_are_ignored() {
+        let mut world = setup_world();
+        let other = world.spawn(Health { current: 0.0, max: 10.0 }).id();
+
+        world.write_message(Died { entity: other });
+        let _ = world.run_system_once(enter_dead_state);
+
+        assert!(world.get::<Dead>(other).is_none());
+        assert_eq!(world.resource::<Messages<PlayerDied>>().len(), 0);
+    }
+
+    #[test]
+    fn respawn_waits_for_timer_then_restores_player() {
+        let mut world = setup_world();
+        let e = spawn_player(&mut world);
+        world.entity_mut(e).insert((Dead, MovementLocked, RespawnTimer::new(2.0)));
+
+        // Not yet.
+        advance_fixed(&mut world, 1.0);
+        let _ = world.run_system_once(tick_respawn);
+        assert!(world.get::<Dead>(e).is_some());
+        assert_eq!(world.resource::<Messages<PlayerRespawned>>().len(), 0);
+
+        // Timer expires.
+        advance_fixed(&mut world, 1.5);
+        let _ = world.run_system_once(tick_respawn);
+
+        assert!(world.get::<Dead>(e).is_none());
+        assert!(world.get::<MovementLocked>(e).is_none());
+        assert!(world.get::<RespawnTimer>(e).is_none());
+        assert_eq!(
+            world.get::<Transform>(e).unwrap().translation,
+            Vec3::new(1.0, 0.5, 2.0)
+        );
+        assert_eq!(*world.get::<Health>(e).unwrap(), Health::new(100.0));
+        assert_eq!(world.resource::<Messages<PlayerRespawned>>().len(), 1);
+    }
+
+    #[test]
+    fn respawn_prefers_the_nearest_spawn_point() {
+        let mut world = setup_world();
+        let e = spawn_player(&mut world);
+        world.entity_mut(e).insert((Dead, MovementLocked, RespawnTimer::new(2.0)));
+        world.spawn((SpawnPoint, Transform::from_xyz(10.0, 0.5, 6.0)));
+        world.spawn((SpawnPoint, Transform::from_xyz(-20.0, 0.5, 0.0)));
+
+        advance_fixed(&mut world, 2.5);
+        let _ = world.run_system_once(tick_respawn);
+
+        assert_eq!(
+            world.get::<Transform>(e).unwrap().translation,
+            Vec3::new(10.0, 0.5, 6.0)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use super::component::{MoveInput, Player};
+use super::death::AlivePlayer;
+use crate::features::movement::component::MoveInput;
 
 /// Keybindings for player movement input.
 ///
@@ -34,12 +35,14 @@ impl Default for PlayerKeybindings {
 /// Update: read keyboard input and write local-space movement intent.
 ///
 /// - Uses match-based dispatch (clean Rust, fewer branches)
-/// - Produces normalized local intent
+/// - Produces unit-length local intent (keyboard is digital: full speed or none;
+///   analog sources may write shorter `MoveInput`s)
 /// - Does NOT touch Transform (collision-ready)
+/// - Skips `Dead` players (their intent stays zeroed until respawn)
 pub fn read_player_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     bindings: Option<Res<PlayerKeybindings>>,
-    mut q_player_input: Query<&mut MoveInput, With<Player>>,
+    mut q_player_input: Query<&mut MoveInput, AlivePlayer>,
 ) {
 
     let Some(bindings) = bindings else {
@@ -67,7 +70,8 @@ pub fn read_player_input(
         }
     }
 
-    // Normalize safely (zero stays zero, no diagonal speed boost)
+    // Normalize safely (zero stays zero, no diagonal speed boost): a keypress is
+    // always full deflection.
     dir = dir.normalize_or_zero();
 
     // Apply intent to all player entities (exactly one for now)
@@ -78,8 +82,12 @@ pub fn read_player_input(
 
 
 #[cfg(test)]
+// Baseline tests, kept as written; they predate these two lints.
+#[expect(clippy::drop_non_drop, clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
+    use crate::features::player::component::Player;
+    use crate::features::player::death::Dead;
     use bevy::ecs::system::RunSystemOnce;
 
     fn setup_world_with_player() -> (World, Entity) {
@@ -198,4 +206,20 @@ mod tests {
         assert_eq!(a, Vec3::new(1.0, 0.0, 0.0));
         assert_eq!(b, Vec3::new(1.0, 0.0, 0.0));
     }
+
+    #[test]
+    fn dead_players_are_not_driven() {
+        let (mut world, e) = setup_world_with_player();
+        world.insert_resource(PlayerKeybindings::default());
+        world.entity_mut(e).insert(Dead);
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyW);
+
+        let _ = world.run_system_once(read_player_input);
+
+        let got = world.entity(e).get::<MoveInput>().unwrap().0;
+        assert_eq!(got, Vec3::new(9.0, 9.0, 9.0));
+    }
 }
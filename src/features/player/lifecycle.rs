@@ -0,0 +1,83 @@
+// src/features/player/lifecycle.rs
+use bevy::prelude::*;
+
+use super::component::Player;
+use crate::app::{DespawnQueue, GameState};
+use crate::features::movement::component::MoveInput;
+
+/// Entity that only exists for a player while playing (reticles, transient
+/// effects): despawned, with its children, at the end of the frame the game leaves
+/// `Playing`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerOwned;
+
+/// Run condition: the game is `Playing` and at least one `Player` exists.
+///
+/// Player input skips entirely otherwise (menus, onboarding, before
+/// the first `SpawnPlayer` has been handled).
+pub fn player_active(
+    state: Option<Res<State<GameState>>>,
+    q_players: Query<(), With<Player>>,
+) -> bool {
+    state.is_some_and(|state| state.is_playing()) && !q_players.is_empty()
+}
+
+/// OnExit(Playing), and while input is in a non-gameplay context: zero movement
+/// intent, so nobody walks on with the keys that were held when play lost the keyboard.
+pub fn clear_player_intent(mut q_input: Query<&mut MoveInput, With<Player>>) {
+    for mut input in &mut q_input {
+        if input.0 != Vec3::ZERO {
+            input.0 = Vec3::ZERO;
+        }
+    }
+}
+
+/// OnExit(Playing): despawn every `PlayerOwned` entity.
+pub fn despawn_player_owned(mut despawns: DespawnQueue, q_owned: Query<Entity, With<PlayerOwned>>) {
+    for entity in &q_owned {
+        despawns.despawn(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn world_in(state: GameState) -> World {
+        let mut world = World::new();
+        world.insert_resource(State::new(state));
+        world
+    }
+
+    #[test]
+    fn player_is_active_only_while_playing_with_a_player() {
+        let mut world = world_in(GameState::Playing);
+        assert!(!world.run_system_once(player_active).unwrap(), "no player yet");
+
+        world.spawn(Player);
+        assert!(world.run_system_once(player_active).unwrap());
+
+        world.insert_resource(State::new(GameState::Menu));
+        assert!(!world.run_system_once(player_active).unwrap(), "menu");
+
+        world.remove_resource::<State<GameState>>();
+        assert!(!world.run_system_once(player_active).unwrap(), "no state at all");
+    }
+
+    #[test]
+    fn leaving_play_clears_intent_and_player_owned_entities() {
+        let mut world = world_in(GameState::Playing);
+        let player = world.spawn((Player, MoveInput(Vec3::NEG_Z))).id();
+        let owned = world.spawn(PlayerOwned).id();
+        let other = world.spawn(Transform::default()).id();
+
+        let _ = world.run_system_once(clear_player_intent);
+        let _ = world.run_system_once(despawn_player_owned);
+
+        assert_eq!(world.get::<MoveInput>(player), Some(&MoveInput(Vec3::ZERO)));
+        assert!(world.entity(owned).contains::<Despawning>());
+        assert!(!world.entity(other).contains::<Despawning>());
+    }
+}
@@ -1,45 +1,92 @@
 // src/features/player/mod.rs
 use bevy::prelude::*;
 
-use crate::app::AppSet;
+use crate::app::{AppSet, GAMEPLAY, GameState, Tuning, in_input_context};
+use crate::features::health::damage::{self, Died};
+use crate::features::materials::component::MaterialLibrary;
+use crate::features::meshes::component::MeshLibrary;
 
 pub mod bundles;
 pub mod component;
+pub mod death;
 pub mod input;
-pub mod movement;
+pub mod lifecycle;
 
 /// Player feature plugin.
 ///
 /// Scope (current slice):
-/// - Spawns a single player entity at startup (Option A: player feature owns player entity)
-/// - Update: reads keyboard input and writes local-space `MoveInput` intent
-/// - FixedUpdate: converts local intent -> world velocity -> integrates position (temporary)
+/// - Spawns players on `SpawnPlayer` requests (Option A: player feature owns player
+///   entities): a logic root with the mesh on a `PlayerVisual` child
+/// - `PlayerSpawnSettings::auto_spawn` (on by default) requests one player at startup
+/// - Update: reads keyboard input and writes local-space `MoveInput` intent; the
+///   shared `MovementPlugin` pipeline turns it into motion
+/// - FixedUpdate: `Died` -> `Dead` state (`MovementLocked`) -> respawn countdown ->
+///   restored at the respawn point
+/// - Input only runs while `Playing` with a player present; leaving `Playing` zeroes
+///   intent and despawns `PlayerOwned` entities
 ///
 /// Design constraints:
 /// - Input systems never write `Transform`.
-/// - FixedUpdate owns movement stepping (collision/physics-ready pipeline).
+/// - Simulation reads and writes the logic root only; the visual child is presentation.
+/// - The player feature only contributes input; it never integrates movement itself.
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         // Add default keybindings (can be overridden later by inserting your own resource).
         app.insert_resource(input::PlayerKeybindings::default());
+        app.init_resource::<death::RespawnSettings>();
+        app.init_resource::<bundles::PlayerSpawnSettings>();
+        // Owned by the materials / meshes features (and the app's `Tuning`); the player
+        // only needs its palette color, the shared cube and its run speed.
+        app.init_resource::<MaterialLibrary>();
+        app.init_resource::<MeshLibrary>();
+        app.init_resource::<Tuning>();
 
-        // Spawn the player entity (feature owns player).
-        app.add_systems(Startup, bundles::spawn_player);
+        // Messages this feature produces / consumes.
+        // `Died` is owned by the health feature; registering it here too is idempotent
+        // and keeps PlayerPlugin usable on its own (tests, minimal apps).
+        app.add_message::<Died>();
+        app.add_message::<death::PlayerDied>();
+        app.add_message::<death::PlayerRespawned>();
+        app.add_message::<bundles::SpawnPlayer>();
+        app.add_message::<bundles::PlayerSpawned>();
+
+        // Spawn players on request (feature owns players); by default one is requested
+        // at startup and spawned in the first frame.
+        app.add_systems(Startup, bundles::request_initial_player);
+        app.add_systems(Update, bundles::spawn_player.before(AppSet::Input));
 
         // Input (variable timestep): keyboard -> MoveInput (local-space intent).
-        app.add_systems(Update, input::read_player_input.in_set(AppSet::Input));
+        app.add_systems(
+            Update,
+            input::read_player_input
+                .in_set(AppSet::Input)
+                .run_if(lifecycle::player_active)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        // Keys held when another context took the keyboard (menu, console, text
+        // field) don't keep the player walking.
+        app.add_systems(
+            Update,
+            lifecycle::clear_player_intent
+                .in_set(AppSet::Input)
+                .run_if(not(in_input_context(GAMEPLAY))),
+        );
+
+        // Nothing player-driven carries over out of play.
+        app.add_systems(
+            OnExit(GameState::Playing),
+            (lifecycle::clear_player_intent, lifecycle::despawn_player_owned),
+        );
 
-        // Movement (fixed timestep): intent -> velocity -> integration.
-        //
-        // We explicitly chain the movement pipeline to guarantee ordering.
-        // This is robust and minimizes plugin cross-coupling.
+        // Death / respawn (fixed timestep, after damage has been applied).
         app.add_systems(
             FixedUpdate,
-            (movement::compute_velocity_from_input, movement::integrate_velocity)
+            (death::enter_dead_state, death::tick_respawn)
                 .chain()
-                .in_set(AppSet::FixedMovement),
+                .in_set(AppSet::FixedGameplay)
+                .after(damage::apply_damage),
         );
     }
 }
@@ -0,0 +1,90 @@
+// src/features/portal/component.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Where a portal sends the player.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PortalDestination {
+    /// Scene that must be loaded before the player arrives (`None`: somewhere
+    /// always loaded).
+    #[serde(default)]
+    pub scene: Option<String>,
+    /// Arrival point (keep it outside the destination's own portals).
+    pub position: (f32, f32, f32),
+    /// Scenes left behind, unloaded once the player has arrived.
+    #[serde(default)]
+    pub unload: Vec<String>,
+}
+
+/// Sends players walking into it (its `TriggerVolume` sits alongside) to
+/// `destination`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Portal {
+    pub destination: PortalDestination,
+}
+
+/// Transition timing.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct PortalSettings {
+    pub fade_out_secs: f32,
+    pub fade_in_secs: f32,
+    /// Frames to hold the black screen once the destination is ready, so spawned
+    /// entities and transforms settle before the player is shown.
+    pub settle_frames: u32,
+    /// Give up waiting for the destination after this long (fade back in where
+    /// the player was).
+    pub load_timeout_secs: f32,
+}
+
+impl Default for PortalSettings {
+    fn default() -> Self {
+        Self {
+            fade_out_secs: 0.35,
+            fade_in_secs: 0.35,
+            settle_frames: 2,
+            load_timeout_secs: 10.0,
+        }
+    }
+}
+
+/// Portal transition state machine: `Idle` -> `FadingOut` -> `Loading` ->
+/// `FadingIn` -> `Idle`.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub enum PortalTransition {
+    #[default]
+    Idle,
+    /// Screen going black; the player no longer takes input.
+    FadingOut {
+        player: Entity,
+        destination: PortalDestination,
+        elapsed: f32,
+    },
+    /// Black: waiting for the destination scene and pending terrain chunks.
+    Loading {
+        player: Entity,
+        destination: PortalDestination,
+        elapsed: f32,
+        ready_frames: u32,
+    },
+    /// Player placed; screen clearing.
+    FadingIn { elapsed: f32 },
+}
+
+impl PortalTransition {
+    pub fn is_active(&self) -> bool {
+        *self != Self::Idle
+    }
+
+    /// Opacity of the black overlay (`0.0` = clear).
+    pub fn fade(&self, settings: &PortalSettings) -> f32 {
+        let progress = |elapsed: f32, secs: f32| {
+            if secs <= 0.0 { 1.0 } else { (elapsed / secs).clamp(0.0, 1.0) }
+        };
+        match self {
+            Self::Idle => 0.0,
+            Self::FadingOut { elapsed, .. } => progress(*elapsed, settings.fade_out_secs),
+            Self::Loading { .. } => 1.0,
+            Self::FadingIn { elapsed } => 1.0 - progress(*elapsed, settings.fade_in_secs),
+        }
+    }
+}
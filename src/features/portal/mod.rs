@@ -0,0 +1,56 @@
+// src/features/portal/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, InputContextStack};
+use crate::features::scenes::component::LoadedScenes;
+use crate::features::scenes::systems::{LoadScene, UnloadScene};
+use crate::features::trigger::systems::TriggerEntered;
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Portals: walk in, fade to black, arrive somewhere else.
+///
+/// Scope:
+/// - `Portal` trigger volumes (spawned from scene files' `portals`) send the player
+///   to a `PortalDestination`: a position, optionally inside a scene to load first
+///   and with scenes to unload once there
+/// - the `PortalTransition` state machine: fade out, wait for the destination
+///   scene and pending terrain chunks, move the player and its camera, fade in
+///   (`PortalArrived` on arrival)
+/// - the full-screen fade overlay
+///
+/// Design constraints:
+/// - Player input is suspended for the whole transition (`InputContext::Transition`).
+/// - Loading goes through the scenes feature's `LoadScene` / `UnloadScene`; the
+///   player only moves once `LoadedScenes` has the destination, so it never lands
+///   in unloaded space. A destination that never loads times out and the player
+///   stays put.
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::PortalSettings>();
+        app.init_resource::<component::PortalTransition>();
+        app.init_resource::<InputContextStack>();
+        app.init_resource::<LoadedScenes>();
+
+        app.add_message::<systems::PortalArrived>();
+        app.add_message::<TriggerEntered>();
+        app.add_message::<LoadScene>();
+        app.add_message::<UnloadScene>();
+
+        app.add_systems(Startup, ui::spawn_portal_fade);
+        app.add_systems(
+            Update,
+            (
+                systems::enter_portals,
+                systems::advance_portal_transition,
+                ui::update_portal_fade,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
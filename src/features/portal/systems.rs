@@ -0,0 +1,254 @@
+// src/features/portal/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Portal, PortalSettings, PortalTransition};
+use crate::app::{InputContext, InputContextStack};
+use crate::features::camera::component::FollowCamera;
+use crate::features::camera::rig::CameraRig;
+use crate::features::player::component::Player;
+use crate::features::scenes::component::LoadedScenes;
+use crate::features::scenes::systems::{LoadScene, UnloadScene};
+use crate::features::terrain::component::ChunkMeshTask;
+use crate::features::trigger::systems::TriggerEntered;
+
+/// The player arrived through a portal (it's been moved; the screen is fading in).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PortalArrived {
+    pub player: Entity,
+    pub from: Vec3,
+    pub to: Vec3,
+}
+
+/// Update: a player walking into a portal starts a transition (one at a time) and
+/// loses input until it ends.
+pub fn enter_portals(
+    mut entered: MessageReader<TriggerEntered>,
+    q_portals: Query<&Portal>,
+    q_players: Query<(), With<Player>>,
+    mut transition: ResMut<PortalTransition>,
+    mut stack: ResMut<InputContextStack>,
+) {
+    for ev in entered.read() {
+        let Ok(portal) = q_portals.get(ev.volume) else {
+            continue;
+        };
+        if transition.is_active() || !q_players.contains(ev.entity) {
+            continue;
+        }
+        *transition = PortalTransition::FadingOut {
+            player: ev.entity,
+            destination: portal.destination.clone(),
+            elapsed: 0.0,
+        };
+        stack.push(InputContext::Transition);
+    }
+}
+
+/// Update: run the transition.
+///
+/// - Fading out: once black, ask for the destination scene.
+/// - Loading: wait until the scene is loaded and no terrain chunk is still being
+///   meshed, plus `settle_frames`; then move the player (cameras following it keep
+///   their offset) and unload the scenes left behind.
+/// - Fading in: hand input back once clear.
+#[expect(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn advance_portal_transition(
+    time: Res<Time>,
+    settings: Res<PortalSettings>,
+    loaded: Res<LoadedScenes>,
+    mut transition: ResMut<PortalTransition>,
+    mut stack: ResMut<InputContextStack>,
+    q_pending_chunks: Query<(), With<ChunkMeshTask>>,
+    mut q_player: Query<&mut Transform, With<Player>>,
+    mut q_cameras: Query<(&mut Transform, &mut CameraRig), (With<FollowCamera>, Without<Player>)>,
+    mut loads: MessageWriter<LoadScene>,
+    mut unloads: MessageWriter<UnloadScene>,
+    mut arrived: MessageWriter<PortalArrived>,
+) {
+    if !transition.is_active() {
+        return;
+    }
+    let dt = time.delta_secs();
+    let next = match std::mem::take(&mut *transition) {
+        PortalTransition::Idle => PortalTransition::Idle,
+        PortalTransition::FadingOut {
+            player,
+            destination,
+            elapsed,
+        } => {
+            let elapsed = elapsed + dt;
+            if elapsed < settings.fade_out_secs {
+                PortalTransition::FadingOut {
+                    player,
+                    destination,
+                    elapsed,
+                }
+            } else {
+                if let Some(scene) = &destination.scene {
+                    loads.write(LoadScene { name: scene.clone() });
+                }
+                PortalTransition::Loading {
+                    player,
+                    destination,
+                    elapsed: 0.0,
+                    ready_frames: 0,
+                }
+            }
+        }
+        PortalTransition::Loading {
+            player,
+            destination,
+            elapsed,
+            ready_frames,
+        } => {
+            let elapsed = elapsed + dt;
+            let ready = destination.scene.as_ref().is_none_or(|scene| loaded.contains(scene))
+                && q_pending_chunks.is_empty();
+            let ready_frames = if ready { ready_frames + 1 } else { 0 };
+            if ready && ready_frames > settings.settle_frames {
+                if let Ok(mut transform) = q_player.get_mut(player) {
+                    let from = transform.translation;
+                    let to = Vec3::from(destination.position);
+                    transform.translation = to;
+                    for (mut camera, mut rig) in &mut q_cameras {
+                        camera.translation += to - from;
+                        // No blending across the cut.
+                        rig.blend = None;
+                    }
+                    arrived.write(PortalArrived { player, from, to });
+                }
+                for scene in &destination.unload {
+                    unloads.write(UnloadScene { name: scene.clone() });
+                }
+                PortalTransition::FadingIn { elapsed: 0.0 }
+            } else if elapsed >= settings.load_timeout_secs {
+                warn!("portal destination {:?} never became ready", destination.scene);
+                PortalTransition::FadingIn { elapsed: 0.0 }
+            } else {
+                PortalTransition::Loading {
+                    player,
+                    destination,
+                    elapsed,
+                    ready_frames,
+                }
+            }
+        }
+        PortalTransition::FadingIn { elapsed } => {
+            let elapsed = elapsed + dt;
+            if elapsed < settings.fade_in_secs {
+                PortalTransition::FadingIn { elapsed }
+            } else {
+                stack.pop(InputContext::Transition);
+                PortalTransition::Idle
+            }
+        }
+    };
+    *transition = next;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::portal::component::PortalDestination;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(PortalSettings {
+            fade_out_secs: 0.2,
+            fade_in_secs: 0.2,
+            settle_frames: 1,
+            load_timeout_secs: 5.0,
+        });
+        world.init_resource::<PortalTransition>();
+        world.init_resource::<LoadedScenes>();
+        world.init_resource::<InputContextStack>();
+        world.init_resource::<Messages<TriggerEntered>>();
+        world.init_resource::<Messages<LoadScene>>();
+        world.init_resource::<Messages<UnloadScene>>();
+        world.init_resource::<Messages<PortalArrived>>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(0.1));
+        world.insert_resource(time);
+        world
+    }
+
+    fn step(world: &mut World) {
+        let _ = world.run_system_once(advance_portal_transition);
+    }
+
+    #[test]
+    fn portals_fade_out_wait_for_the_scene_move_the_player_and_fade_in() {
+        let mut world = setup_world();
+        let player = world.spawn((Player, Transform::from_xyz(1.0, 0.0, 0.0))).id();
+        let camera = world.spawn((FollowCamera::default(), Transform::from_xyz(1.0, 4.0, 8.0))).id();
+        let portal = world
+            .spawn(Portal {
+                destination: PortalDestination {
+                    scene: Some("ruins".into()),
+                    position: (100.0, 0.0, 50.0),
+                    unload: vec!["grove".into()],
+                },
+            })
+            .id();
+        world.write_message(TriggerEntered {
+            volume: portal,
+            entity: player,
+        });
+        let _ = world.run_system_once(enter_portals);
+        assert_eq!(world.resource::<InputContextStack>().top(), InputContext::Transition);
+
+        step(&mut world);
+        step(&mut world);
+        assert!(matches!(*world.resource::<PortalTransition>(), PortalTransition::Loading { .. }));
+        let loads: Vec<_> = world.resource_mut::<Messages<LoadScene>>().drain().collect();
+        assert_eq!(loads, [LoadScene { name: "ruins".into() }]);
+
+        // Gated on the scene.
+        for _ in 0..5 {
+            step(&mut world);
+        }
+        assert_eq!(world.get::<Transform>(player).unwrap().translation.x, 1.0);
+
+        world.resource_mut::<LoadedScenes>().0.push("ruins".into());
+        step(&mut world);
+        step(&mut world);
+        assert_eq!(world.get::<Transform>(player).unwrap().translation, Vec3::new(100.0, 0.0, 50.0));
+        assert_eq!(world.get::<Transform>(camera).unwrap().translation, Vec3::new(100.0, 4.0, 58.0));
+        let unloads: Vec<_> = world.resource_mut::<Messages<UnloadScene>>().drain().collect();
+        assert_eq!(unloads, [UnloadScene { name: "grove".into() }]);
+
+        step(&mut world);
+        step(&mut world);
+        assert_eq!(*world.resource::<PortalTransition>(), PortalTransition::Idle);
+        assert_eq!(world.resource::<InputContextStack>().top(), InputContext::Gameplay);
+    }
+
+    #[test]
+    fn fade_ramps_out_holds_black_and_ramps_in() {
+        let settings = PortalSettings::default();
+        let destination = PortalDestination {
+            scene: None,
+            position: (0.0, 0.0, 0.0),
+            unload: Vec::new(),
+        };
+        let player = Entity::PLACEHOLDER;
+        let half_out = PortalTransition::FadingOut {
+            player,
+            destination: destination.clone(),
+            elapsed: settings.fade_out_secs * 0.5,
+        };
+        let loading = PortalTransition::Loading {
+            player,
+            destination,
+            elapsed: 3.0,
+            ready_frames: 0,
+        };
+        assert_eq!(PortalTransition::Idle.fade(&settings), 0.0);
+        assert!((half_out.fade(&settings) - 0.5).abs() < 1e-5);
+        assert_eq!(loading.fade(&settings), 1.0);
+        assert_eq!(PortalTransition::FadingIn { elapsed: settings.fade_in_secs }.fade(&settings), 0.0);
+    }
+}
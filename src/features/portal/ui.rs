@@ -0,0 +1,40 @@
+// src/features/portal/ui.rs
+use bevy::prelude::*;
+
+use super::component::{PortalSettings, PortalTransition};
+
+/// Full-screen black overlay faded in and out around portal transitions.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct PortalFade;
+
+/// Startup: spawn the (clear) overlay above the HUD.
+pub fn spawn_portal_fade(mut commands: Commands) {
+    commands.spawn((
+        PortalFade,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(50),
+        Pickable::IGNORE,
+        Name::new("PortalFade"),
+    ));
+}
+
+/// Update: overlay opacity follows the transition.
+pub fn update_portal_fade(
+    settings: Res<PortalSettings>,
+    transition: Res<PortalTransition>,
+    mut q_fade: Query<&mut BackgroundColor, With<PortalFade>>,
+) {
+    if !transition.is_changed() {
+        return;
+    }
+    let alpha = transition.fade(&settings);
+    for mut color in &mut q_fade {
+        color.0 = Color::BLACK.with_alpha(alpha);
+    }
+}
@@ -0,0 +1,51 @@
+// src/features/props/component.rs
+use bevy::prelude::*;
+
+use crate::features::collision::component::Collider;
+use crate::features::movement::component::{ExternalForce, Gravity, Velocity};
+
+/// Half-size of the player's box, used when shoving props.
+pub const PLAYER_PUSH_HALF_EXTENTS: Vec3 = Vec3::splat(0.5);
+
+/// Share of the player's horizontal speed a shoved prop takes on.
+pub const PUSH_SPEED_TRANSFER: f32 = 0.8;
+
+/// Loose physics object (crates, barrels): falls, rests on the ground plane,
+/// can be shoved by players and pushed by anything writing its `ExternalForce`.
+///
+/// All motion lives in `ExternalForce::velocity` (props are movers without intent,
+/// so `Velocity` stays zero); `Collider` gives the size. Their gravity and ground
+/// friction come from `Tuning`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[require(
+    Collider = Collider::cuboid(Vec3::splat(0.5)),
+    ExternalForce = Prop::external_force(),
+    Gravity,
+    Velocity
+)]
+pub struct Prop;
+
+impl Prop {
+    /// Props keep their momentum in the air; friction / water drag slow them instead.
+    pub fn external_force() -> ExternalForce {
+        ExternalForce {
+            damping: 0.1,
+            ..default()
+        }
+    }
+}
+
+/// Props placed at startup: `(ground point, half extents)`.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PropSpawns(pub Vec<(Vec3, Vec3)>);
+
+impl Default for PropSpawns {
+    fn default() -> Self {
+        Self(vec![
+            (Vec3::new(2.0, 0.0, 1.5), Vec3::splat(0.4)),
+            // Dropped into the pond (see `WaterSpawns`) so it bobs on load.
+            (Vec3::new(0.5, 2.5, 9.0), Vec3::splat(0.4)),
+            (Vec3::new(-0.8, 2.0, 8.5), Vec3::new(0.5, 0.3, 0.3)),
+        ])
+    }
+}
@@ -0,0 +1,42 @@
+// src/features/props/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, Tuning};
+use crate::features::movement::systems::{apply_gravity, compute_velocity_from_input};
+
+pub mod component;
+pub mod systems;
+
+/// Loose props (crates).
+///
+/// Scope:
+/// - `PropSpawns`: crates placed at startup
+/// - props are movers with `Gravity`: the shared `MovementPlugin` pipeline integrates
+///   them; players shove them before integration, and afterwards they rest on the
+///   ground plane with friction (gravity and friction from `Tuning`)
+///
+/// Design constraints:
+/// - all prop motion goes through `ExternalForce`, so other features push props the
+///   same way they push players (impulses / accelerations before `integrate_velocity`).
+pub struct PropsPlugin;
+
+impl Plugin for PropsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::PropSpawns>();
+        app.init_resource::<Tuning>();
+
+        app.add_systems(Startup, systems::spawn_props);
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::retune_props
+                    .before(apply_gravity)
+                    .in_set(AppSet::ApplyForces),
+                systems::push_props
+                    .after(compute_velocity_from_input)
+                    .in_set(AppSet::ApplyForces),
+                systems::settle_props.in_set(AppSet::ResolveCollisions),
+            ),
+        );
+    }
+}
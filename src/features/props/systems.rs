@@ -0,0 +1,166 @@
+// src/features/props/systems.rs
+use bevy::prelude::*;
+
+use super::component::{PLAYER_PUSH_HALF_EXTENTS, PUSH_SPEED_TRANSFER, Prop, PropSpawns};
+use crate::app::Tuning;
+use crate::features::character_material::toon::Stylized;
+use crate::features::collision::component::Collider;
+use crate::features::culling::component::Cullable;
+use crate::features::movement::component::{ExternalForce, Gravity, Velocity};
+use crate::features::player::component::Player;
+use crate::features::surface::component::SurfaceMaterial;
+
+/// Startup: spawn a crate for each `PropSpawns` entry.
+pub fn spawn_props(
+    mut commands: Commands,
+    spawns: Res<PropSpawns>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let material = materials.add(Color::srgb_u8(150, 105, 60));
+    for &(ground, half_extents) in &spawns.0 {
+        commands.spawn((
+            Prop,
+            Collider::cuboid(half_extents),
+            SurfaceMaterial::Wood,
+            Mesh3d(meshes.add(Cuboid::from_size(half_extents * 2.0))),
+            MeshMaterial3d(material.clone()),
+            Stylized,
+            Cullable::default(),
+            Transform::from_translation(ground + Vec3::Y * half_extents.y),
+            Name::new("Crate"),
+        ));
+    }
+}
+
+/// FixedUpdate: players walking into a prop shove it along their movement.
+#[expect(clippy::type_complexity)]
+pub fn push_props(
+    q_players: Query<(&Transform, &Velocity), With<Player>>,
+    mut q_props: Query<(&Transform, &Collider, &mut ExternalForce), (With<Prop>, Without<Player>)>,
+) {
+    for (player_tr, velocity) in &q_players {
+        let push = velocity.0.with_y(0.0) * PUSH_SPEED_TRANSFER;
+        if push == Vec3::ZERO {
+            continue;
+        }
+        for (prop_tr, collider, mut external) in &mut q_props {
+            let reach = collider.half_extents + PLAYER_PUSH_HALF_EXTENTS;
+            let offset = prop_tr.translation - player_tr.translation;
+            let touching = offset.abs().cmple(reach).all();
+            // Only shove props in front of the player, never pull them along.
+            if !touching || offset.dot(push) <= 0.0 {
+                continue;
+            }
+            let current = external.velocity.with_y(0.0);
+            if current.dot(push.normalize()) < push.length() {
+                external.velocity = push.with_y(external.velocity.y);
+            }
+        }
+    }
+}
+
+/// FixedUpdate (before gravity): props fall at `Tuning::gravity`; new props from
+/// their first tick, all of them again whenever the tuning changes.
+pub fn retune_props(tuning: Res<Tuning>, mut q_props: Query<(Ref<Prop>, &mut Gravity)>) {
+    for (prop, mut gravity) in &mut q_props {
+        if tuning.is_changed() || prop.is_added() {
+            gravity.set_if_neq(Gravity(tuning.gravity));
+        }
+    }
+}
+
+/// FixedUpdate (after integration): rest props on the ground plane (`y = 0`),
+/// with ground friction (`Tuning::ground_friction`) while resting.
+pub fn settle_props(
+    time: Res<Time<Fixed>>,
+    tuning: Res<Tuning>,
+    mut q_props: Query<(&Collider, &mut Transform, &mut ExternalForce), With<Prop>>,
+) {
+    let dt = time.delta_secs();
+    let friction = (-tuning.ground_friction * dt).exp();
+
+    for (collider, mut transform, mut external) in &mut q_props {
+        let bottom = transform.translation.y - collider.half_extents.y;
+        if bottom <= 0.0 {
+            transform.translation.y = collider.half_extents.y;
+            external.velocity.y = external.velocity.y.max(0.0);
+            external.velocity.x *= friction;
+            external.velocity.z *= friction;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::movement::systems::{apply_gravity, integrate_velocity};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<Tuning>();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world
+    }
+
+    #[test]
+    fn dropped_prop_lands_on_the_ground() {
+        let mut world = setup_world();
+        let prop = world
+            .spawn((Prop, Transform::from_xyz(0.0, 3.0, 0.0)))
+            .id();
+
+        for _ in 0..180 {
+            let _ = world.run_system_once(apply_gravity);
+            let _ = world.run_system_once(integrate_velocity);
+            let _ = world.run_system_once(settle_props);
+        }
+
+        let y = world.get::<Transform>(prop).unwrap().translation.y;
+        assert!((y - 0.5).abs() < 1e-5, "y {y}");
+        assert_eq!(world.get::<ExternalForce>(prop).unwrap().velocity.y, 0.0);
+    }
+
+    #[test]
+    fn props_follow_the_tuned_gravity() {
+        let mut world = setup_world();
+        world.resource_mut::<Tuning>().gravity = 4.0;
+        let retune = world.register_system(retune_props);
+        let prop = world.spawn(Prop).id();
+
+        world.run_system(retune).unwrap();
+        assert_eq!(world.get::<Gravity>(prop), Some(&Gravity(4.0)), "from the first tick");
+
+        // Left alone until the tuning changes (a reload), then all props follow.
+        world.get_mut::<Gravity>(prop).unwrap().0 = 1.0;
+        world.run_system(retune).unwrap();
+        assert_eq!(world.get::<Gravity>(prop), Some(&Gravity(1.0)));
+        world.resource_mut::<Tuning>().gravity = 20.0;
+        world.run_system(retune).unwrap();
+        assert_eq!(world.get::<Gravity>(prop), Some(&Gravity(20.0)));
+    }
+
+    #[test]
+    fn walking_into_a_prop_shoves_it_forward_only() {
+        let mut world = setup_world();
+        world.spawn((Player, Transform::default(), Velocity(Vec3::X * 5.0)));
+        let ahead = world
+            .spawn((Prop, Transform::from_xyz(0.9, 0.0, 0.0)))
+            .id();
+        let behind = world
+            .spawn((Prop, Transform::from_xyz(-0.9, 0.0, 0.0)))
+            .id();
+
+        let _ = world.run_system_once(push_props);
+
+        let v = |world: &World, e| world.get::<ExternalForce>(e).unwrap().velocity;
+        assert_eq!(v(&world, ahead), Vec3::X * 5.0 * PUSH_SPEED_TRANSFER);
+        assert_eq!(v(&world, behind), Vec3::ZERO);
+    }
+}
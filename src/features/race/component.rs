@@ -0,0 +1,85 @@
+// src/features/race/component.rs
+use bevy::prelude::*;
+
+use crate::app::{SimTick, TickRate};
+
+/// A checkpoint of the race course (with a `TriggerVolume`); they must be passed in
+/// `index` order, the last one is the finish.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceCheckpoint {
+    pub index: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RaceStatus {
+    /// No race (or it was reset).
+    #[default]
+    Idle,
+    Running,
+    /// Crossed the finish; `splits` are final.
+    Finished,
+    /// A checkpoint was missed (or the run was abandoned); no result counts.
+    Invalidated,
+}
+
+/// The race in progress: start tick, next checkpoint and split times.
+///
+/// Times are counted in fixed ticks (`SimTick`), so they are exact and don't
+/// depend on frame rate.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Race {
+    pub status: RaceStatus,
+    pub started_tick: u64,
+    /// Checkpoints on the course, the finish included.
+    pub checkpoints: usize,
+    /// Ticks from the start to each checkpoint passed so far.
+    pub splits: Vec<u64>,
+    /// Splits to compare against (e.g. the best run so far); empty for none.
+    pub reference: Vec<u64>,
+}
+
+impl Race {
+    /// Start a race over `checkpoints` checkpoints (finish included) on `tick`.
+    pub fn start(tick: SimTick, checkpoints: usize) -> Self {
+        Self {
+            status: RaceStatus::Running,
+            started_tick: tick.0,
+            checkpoints,
+            splits: Vec::new(),
+            reference: Vec::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == RaceStatus::Running
+    }
+
+    /// Index of the checkpoint to pass next.
+    pub fn next_checkpoint(&self) -> usize {
+        self.splits.len()
+    }
+
+    /// Ticks on the clock at `tick` (stopped at the finish).
+    pub fn elapsed_ticks(&self, tick: SimTick) -> u64 {
+        match (self.status, self.splits.last()) {
+            (RaceStatus::Finished, Some(&last)) => last,
+            _ => tick.0.saturating_sub(self.started_tick),
+        }
+    }
+
+    /// Ticks split `index` was ahead (negative) or behind the reference.
+    pub fn delta_ticks(&self, index: usize) -> Option<i64> {
+        let split = *self.splits.get(index)?;
+        let reference = *self.reference.get(index)?;
+        Some(split as i64 - reference as i64)
+    }
+
+    /// Seconds on the clock at `tick`.
+    pub fn elapsed_secs(&self, tick: SimTick, rate: TickRate) -> f32 {
+        ticks_to_secs(self.elapsed_ticks(tick), rate)
+    }
+}
+
+pub fn ticks_to_secs(ticks: u64, rate: TickRate) -> f32 {
+    (ticks as f64 / rate.0) as f32
+}
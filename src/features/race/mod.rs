@@ -0,0 +1,40 @@
+// src/features/race/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::trigger::systems::{TriggerEntered, detect_trigger_occupancy};
+
+pub mod component;
+pub mod systems;
+
+/// Race timing.
+///
+/// Scope:
+/// - ordered `RaceCheckpoint` trigger volumes, the last one being the finish
+/// - `Race`: the clock (fixed ticks since the start) and a split per checkpoint
+/// - `CheckpointPassed` / `RaceFinished` as checkpoints are passed in order;
+///   reaching a later checkpoint first invalidates the race (`RaceInvalidated`)
+///
+/// Design constraints:
+/// - game modes lay out the checkpoints, start the `Race` and turn results into
+///   leaderboard entries; this feature only times
+/// - splits are stamped with `SimTick` on the fixed step the trigger fires, so
+///   times are exact and independent of frame rate.
+pub struct RacePlugin;
+
+impl Plugin for RacePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::Race>();
+        app.add_message::<TriggerEntered>();
+        app.add_message::<systems::CheckpointPassed>();
+        app.add_message::<systems::RaceFinished>();
+        app.add_message::<systems::RaceInvalidated>();
+
+        app.add_systems(
+            FixedUpdate,
+            systems::pass_race_checkpoints
+                .in_set(AppSet::FixedGameplay)
+                .after(detect_trigger_occupancy),
+        );
+    }
+}
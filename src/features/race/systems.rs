@@ -0,0 +1,152 @@
+// src/features/race/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Race, RaceCheckpoint, RaceStatus};
+use crate::app::SimTick;
+use crate::features::player::component::Player;
+use crate::features::trigger::systems::TriggerEntered;
+
+/// The racer passed checkpoint `index`, `split_ticks` after the start.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointPassed {
+    pub index: usize,
+    pub split_ticks: u64,
+}
+
+/// The racer crossed the finish; `splits` are ticks from the start to each
+/// checkpoint (the last one is the total).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct RaceFinished {
+    pub splits: Vec<u64>,
+}
+
+/// The racer reached checkpoint `reached` while `expected` was still to pass.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceInvalidated {
+    pub expected: usize,
+    pub reached: usize,
+}
+
+/// FixedUpdate (after trigger detection): time players passing checkpoints.
+///
+/// - the next checkpoint records a split on this tick; the last one finishes
+/// - reaching a later checkpoint means one was skipped: the race is invalidated
+/// - re-entering checkpoints already passed does nothing
+#[expect(clippy::too_many_arguments)]
+pub fn pass_race_checkpoints(
+    tick: Res<SimTick>,
+    mut race: ResMut<Race>,
+    mut entered: MessageReader<TriggerEntered>,
+    q_checkpoints: Query<&RaceCheckpoint>,
+    q_players: Query<(), With<Player>>,
+    mut passed: MessageWriter<CheckpointPassed>,
+    mut finished: MessageWriter<RaceFinished>,
+    mut invalidated: MessageWriter<RaceInvalidated>,
+) {
+    for ev in entered.read() {
+        let Ok(checkpoint) = q_checkpoints.get(ev.volume) else {
+            continue;
+        };
+        if !race.is_running() || !q_players.contains(ev.entity) {
+            continue;
+        }
+        let expected = race.next_checkpoint();
+        if checkpoint.index < expected {
+            continue;
+        }
+        if checkpoint.index > expected {
+            race.status = RaceStatus::Invalidated;
+            invalidated.write(RaceInvalidated {
+                expected,
+                reached: checkpoint.index,
+            });
+            continue;
+        }
+
+        let split_ticks = tick.0.saturating_sub(race.started_tick);
+        race.splits.push(split_ticks);
+        passed.write(CheckpointPassed {
+            index: checkpoint.index,
+            split_ticks,
+        });
+        if race.splits.len() >= race.checkpoints {
+            race.status = RaceStatus::Finished;
+            finished.write(RaceFinished {
+                splits: race.splits.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::trigger::component::TriggerVolume;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> (World, Entity, Vec<Entity>) {
+        let mut world = World::new();
+        world.insert_resource(SimTick(10));
+        world.insert_resource(Race::start(SimTick(10), 3));
+        world.init_resource::<Messages<TriggerEntered>>();
+        world.init_resource::<Messages<CheckpointPassed>>();
+        world.init_resource::<Messages<RaceFinished>>();
+        world.init_resource::<Messages<RaceInvalidated>>();
+        let player = world.spawn(Player).id();
+        let checkpoints = (0..3)
+            .map(|index| {
+                world
+                    .spawn((
+                        RaceCheckpoint { index },
+                        TriggerVolume {
+                            half_extents: Vec3::ONE,
+                        },
+                    ))
+                    .id()
+            })
+            .collect();
+        (world, player, checkpoints)
+    }
+
+    fn enter(world: &mut World, tick: u64, volume: Entity, entity: Entity) {
+        world.resource_mut::<SimTick>().0 = tick;
+        world.write_message(TriggerEntered { volume, entity });
+        let _ = world.run_system_once(pass_race_checkpoints);
+    }
+
+    #[test]
+    fn checkpoints_in_order_record_tick_exact_splits_and_finish() {
+        let (mut world, player, checkpoints) = setup_world();
+        enter(&mut world, 70, checkpoints[0], player);
+        enter(&mut world, 80, checkpoints[0], player); // re-entered: ignored
+        enter(&mut world, 130, checkpoints[1], player);
+        enter(&mut world, 200, checkpoints[2], player);
+
+        let race = world.resource::<Race>().clone();
+        assert_eq!(race.status, RaceStatus::Finished);
+        assert_eq!(race.splits, vec![60, 120, 190]);
+        assert_eq!(race.elapsed_ticks(SimTick(999)), 190, "clock stopped");
+        assert_eq!(world.resource::<Messages<CheckpointPassed>>().len(), 3);
+        let finished: Vec<_> = world.resource_mut::<Messages<RaceFinished>>().drain().collect();
+        assert_eq!(finished, [RaceFinished { splits: vec![60, 120, 190] }]);
+    }
+
+    #[test]
+    fn skipping_a_checkpoint_invalidates_the_race() {
+        let (mut world, player, checkpoints) = setup_world();
+        enter(&mut world, 70, checkpoints[0], player);
+        enter(&mut world, 200, checkpoints[2], player);
+
+        assert_eq!(world.resource::<Race>().status, RaceStatus::Invalidated);
+        let invalidated: Vec<_> = world.resource_mut::<Messages<RaceInvalidated>>().drain().collect();
+        assert_eq!(
+            invalidated,
+            [RaceInvalidated {
+                expected: 1,
+                reached: 2
+            }]
+        );
+        assert!(world.resource::<Messages<RaceFinished>>().is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+// src/features/ragdoll/component.rs
+use bevy::prelude::*;
+
+/// Opt-in: when this entity dies, collapse it as a ragdoll, then fade and despawn it.
+///
+/// Players don't carry this (they respawn); enemies / NPCs / props do.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct RagdollOnDeath {
+    /// Seconds the body lies still before fading.
+    pub linger_secs: f32,
+    /// Seconds the fade-out (shrink) takes before despawn.
+    pub fade_secs: f32,
+}
+
+impl Default for RagdollOnDeath {
+    fn default() -> Self {
+        Self {
+            linger_secs: 3.0,
+            fade_secs: 1.0,
+        }
+    }
+}
+
+/// Active ragdoll state.
+///
+/// Without a skeleton or physics backend this is a single-hinge approximation:
+/// the body pivots about its base around `axis` under gravity until it lies flat,
+/// with a small damped bounce, then fades.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Ragdoll {
+    /// Hinge axis in world space (horizontal).
+    pub axis: Vec3,
+    /// Current tilt from upright, radians (`0` upright, `PI/2` lying flat).
+    pub angle: f32,
+    /// Angular velocity around `axis`, radians/sec.
+    pub angular_velocity: f32,
+    /// Distance from the pivot (feet) to the body's center of mass.
+    pub pivot_height: f32,
+    /// Rotation at the moment of death (tilt is applied on top of it).
+    pub base_rotation: Quat,
+    /// Translation at the moment of death (the body's origin / center of mass).
+    pub base_translation: Vec3,
+    /// Scale at the moment of death (fade shrinks from this).
+    pub base_scale: Vec3,
+    /// Seconds since death.
+    pub elapsed: f32,
+    pub settings: RagdollOnDeath,
+}
+
+impl Ragdoll {
+    /// Lying flat.
+    pub const REST_ANGLE: f32 = std::f32::consts::FRAC_PI_2;
+
+    /// Fraction of angular speed kept when bouncing off the ground.
+    pub const BOUNCE: f32 = 0.25;
+
+    pub fn total_lifetime_secs(&self) -> f32 {
+        self.settings.linger_secs + self.settings.fade_secs
+    }
+
+    /// `1.0` until the fade starts, then down to `0.0` at despawn time.
+    pub fn fade_factor(&self) -> f32 {
+        let into_fade = self.elapsed - self.settings.linger_secs;
+        if into_fade <= 0.0 {
+            1.0
+        } else if self.settings.fade_secs <= 0.0 {
+            0.0
+        } else {
+            (1.0 - into_fade / self.settings.fade_secs).clamp(0.0, 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ragdoll(linger: f32, fade: f32) -> Ragdoll {
+        Ragdoll {
+            axis: Vec3::X,
+            angle: 0.0,
+            angular_velocity: 0.0,
+            pivot_height: 0.5,
+            base_rotation: Quat::IDENTITY,
+            base_translation: Vec3::ZERO,
+            base_scale: Vec3::ONE,
+            elapsed: 0.0,
+            settings: RagdollOnDeath {
+                linger_secs: linger,
+                fade_secs: fade,
+            },
+        }
+    }
+
+    #[test]
+    fn fade_factor_ramps_after_linger() {
+        let mut r = ragdoll(2.0, 1.0);
+        assert_eq!(r.fade_factor(), 1.0);
+
+        r.elapsed = 2.5;
+        assert!((r.fade_factor() - 0.5).abs() < 1e-6);
+
+        r.elapsed = 10.0;
+        assert_eq!(r.fade_factor(), 0.0);
+        assert_eq!(r.total_lifetime_secs(), 3.0);
+    }
+}
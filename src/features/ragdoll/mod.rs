@@ -0,0 +1,33 @@
+// src/features/ragdoll/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::health::damage::{self, Died};
+
+pub mod component;
+pub mod systems;
+
+/// Ragdoll-on-death feature.
+///
+/// Scope:
+/// - entities with `RagdollOnDeath` collapse when the health feature reports `Died`
+/// - single-hinge topple approximation (no skeleton / physics backend yet)
+/// - linger, shrink-fade, despawn
+///
+/// When rigged models and a physics backend exist, a per-joint ragdoll can replace
+/// `step_ragdoll` while keeping the same opt-in component and lifecycle.
+pub struct RagdollPlugin;
+
+impl Plugin for RagdollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Died>();
+
+        app.add_systems(
+            FixedUpdate,
+            (systems::start_ragdoll, systems::step_ragdoll)
+                .chain()
+                .in_set(AppSet::FixedGameplay)
+                .after(damage::apply_damage),
+        );
+    }
+}
@@ -0,0 +1,185 @@
+// src/features/ragdoll/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Ragdoll, RagdollOnDeath};
+use crate::app::DespawnQueue;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::health::damage::Died;
+use crate::features::movement::component::Velocity;
+
+/// Gravity used by the topple approximation (world units/sec²).
+const GRAVITY: f32 = 9.81;
+
+/// Small initial push so a perfectly upright body still starts falling.
+const INITIAL_TIP_SPEED: f32 = 0.6;
+
+/// How far below a body the ground under it is looked for.
+const GROUND_PROBE_DEPTH: f32 = 50.0;
+
+/// FixedUpdate: start ragdolls for entities that opted in and just died.
+///
+/// - The hinge axis is perpendicular to the horizontal velocity, so bodies fall the way
+///   they were moving; idle bodies fall backwards (+Z).
+/// - The hinge sits on the ground below the body (a ray down through the colliders;
+///   y = 0 when there is none).
+/// - `Velocity` is removed so no movement system keeps driving the corpse.
+pub fn start_ragdoll(
+    mut commands: Commands,
+    mut died: MessageReader<Died>,
+    collision: CollisionWorld,
+    q_candidates: Query<(&RagdollOnDeath, &Transform, Option<&Velocity>), Without<Ragdoll>>,
+) {
+    for ev in died.read() {
+        let Ok((settings, transform, velocity)) = q_candidates.get(ev.entity) else {
+            continue;
+        };
+
+        let horizontal = velocity
+            .map(|v| Vec3::new(v.0.x, 0.0, v.0.z))
+            .unwrap_or(Vec3::ZERO);
+        let fall_dir = horizontal.try_normalize().unwrap_or(Vec3::Z);
+        // Rotating +Y towards `fall_dir` happens around Y x fall_dir.
+        let axis = Vec3::Y.cross(fall_dir).normalize();
+        let origin = transform.translation;
+        let ground_distance = collision
+            .ray_cast(origin, Vec3::NEG_Y, GROUND_PROBE_DEPTH, &[ev.entity])
+            .map_or(origin.y, |hit| hit.distance);
+
+        commands.entity(ev.entity).remove::<Velocity>().insert(Ragdoll {
+            axis,
+            angle: 0.0,
+            angular_velocity: INITIAL_TIP_SPEED + horizontal.length() * 0.5,
+            pivot_height: ground_distance.max(0.1),
+            base_rotation: transform.rotation,
+            base_translation: transform.translation,
+            base_scale: transform.scale,
+            elapsed: 0.0,
+            settings: *settings,
+        });
+    }
+}
+
+/// FixedUpdate: topple around the hinge, settle flat, then shrink out and despawn.
+pub fn step_ragdoll(
+    mut despawns: DespawnQueue,
+    time: Res<Time<Fixed>>,
+    mut q_ragdolls: Query<(Entity, &mut Ragdoll, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut ragdoll, mut transform) in &mut q_ragdolls {
+        ragdoll.elapsed += dt;
+
+        if ragdoll.elapsed >= ragdoll.total_lifetime_secs() {
+            despawns.despawn(entity);
+            continue;
+        }
+
+        // Inverted pendulum: angular acceleration grows as the body tips over.
+        let accel = GRAVITY / ragdoll.pivot_height * ragdoll.angle.sin().max(0.05);
+        ragdoll.angular_velocity += accel * dt;
+        ragdoll.angle += ragdoll.angular_velocity * dt;
+
+        if ragdoll.angle >= Ragdoll::REST_ANGLE {
+            ragdoll.angle = Ragdoll::REST_ANGLE;
+            ragdoll.angular_velocity = -ragdoll.angular_velocity * Ragdoll::BOUNCE;
+            if ragdoll.angular_velocity.abs() < 0.2 {
+                ragdoll.angular_velocity = 0.0;
+            }
+        }
+
+        // Rotate about the pivot (base of the body), not the body's origin.
+        let tilt = Quat::from_axis_angle(ragdoll.axis, ragdoll.angle);
+        let pivot = ragdoll.base_translation - Vec3::Y * ragdoll.pivot_height;
+        transform.translation = pivot + tilt * (Vec3::Y * ragdoll.pivot_height);
+        transform.rotation = tilt * ragdoll.base_rotation;
+        transform.scale = ragdoll.base_scale * ragdoll.fade_factor();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use crate::features::collision::component::Collider;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world(dt: f32) -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<Died>>();
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(dt));
+        world.insert_resource(fixed);
+        world
+    }
+
+    #[test]
+    fn only_opted_in_entities_ragdoll() {
+        let mut world = setup_world(0.0);
+        let body = world
+            .spawn((
+                RagdollOnDeath::default(),
+                Transform::from_xyz(0.0, 1.0, 0.0),
+                Velocity(Vec3::new(2.0, 0.0, 0.0)),
+            ))
+            .id();
+        let other = world.spawn(Transform::default()).id();
+
+        world.write_message(Died { entity: body });
+        world.write_message(Died { entity: other });
+        let _ = world.run_system_once(start_ragdoll);
+
+        let ragdoll = world.get::<Ragdoll>(body).expect("body should ragdoll");
+        assert!(world.get::<Velocity>(body).is_none());
+        // Moving +X -> falls towards +X -> hinge around -Z.
+        assert!((ragdoll.axis - Vec3::NEG_Z).length() < 1e-6, "{:?}", ragdoll.axis);
+        assert!(world.get::<Ragdoll>(other).is_none());
+    }
+
+    #[test]
+    fn body_settles_flat_then_despawns() {
+        let mut world = setup_world(1.0 / 60.0);
+        let body = world
+            .spawn((RagdollOnDeath::default(), Transform::from_xyz(0.0, 1.0, 0.0)))
+            .id();
+        world.write_message(Died { entity: body });
+        let _ = world.run_system_once(start_ragdoll);
+
+        // 2 seconds: fallen over and resting, not yet fading.
+        for _ in 0..120 {
+            let _ = world.run_system_once(step_ragdoll);
+        }
+        let ragdoll = *world.get::<Ragdoll>(body).unwrap();
+        assert_eq!(ragdoll.angle, Ragdoll::REST_ANGLE);
+        let tr = world.get::<Transform>(body).unwrap();
+        assert!(tr.translation.y.abs() < 1e-4, "center drops to pivot height: {tr:?}");
+        assert_eq!(tr.scale, Vec3::ONE);
+
+        // Past linger + fade: on its way out.
+        for _ in 0..180 {
+            let _ = world.run_system_once(step_ragdoll);
+        }
+        assert!(world.entity(body).contains::<Despawning>());
+    }
+
+    #[test]
+    fn hinge_sits_on_the_floor_below() {
+        let mut world = setup_world(0.0);
+        // Floor top at y = 4.
+        world.spawn((
+            Collider::cuboid(Vec3::new(5.0, 0.5, 5.0)),
+            GlobalTransform::from_xyz(0.0, 3.5, 0.0),
+        ));
+        let body = world
+            .spawn((RagdollOnDeath::default(), Transform::from_xyz(0.0, 5.0, 0.0)))
+            .id();
+
+        world.write_message(Died { entity: body });
+        let _ = world.run_system_once(start_ragdoll);
+
+        let ragdoll = world.get::<Ragdoll>(body).unwrap();
+        assert!((ragdoll.pivot_height - 1.0).abs() < 1e-5, "{}", ragdoll.pivot_height);
+    }
+}
@@ -0,0 +1,86 @@
+// src/features/rotation/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Turns in place around `axis` (local to the world, through the entity's origin).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Spin {
+    pub axis: Dir3,
+    /// Radians per second (negative turns the other way).
+    pub speed: f32,
+}
+
+/// Circles `center` around `axis`, at the distance it was spawned at.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Orbit {
+    pub center: Vec3,
+    pub axis: Dir3,
+    /// Radians per second (negative turns the other way).
+    pub speed: f32,
+    /// Offset from `center` at angle 0.
+    pub offset: Vec3,
+    /// Current angle (radians, wrapped to a turn).
+    pub angle: f32,
+}
+
+impl Orbit {
+    /// Orbit that starts at `position`.
+    pub fn around(center: Vec3, axis: Dir3, speed: f32, position: Vec3) -> Self {
+        Self {
+            center,
+            axis,
+            speed,
+            offset: position - center,
+            angle: 0.0,
+        }
+    }
+
+    pub fn position(&self) -> Vec3 {
+        self.center + Quat::from_axis_angle(self.axis.as_vec3(), self.angle) * self.offset
+    }
+}
+
+/// Scene data form of `Spin` / `Orbit` (axes needn't be normalized; a zero axis
+/// falls back to up).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum MotionDef {
+    Spin {
+        axis: (f32, f32, f32),
+        degrees_per_sec: f32,
+    },
+    Orbit {
+        center: (f32, f32, f32),
+        axis: (f32, f32, f32),
+        degrees_per_sec: f32,
+    },
+}
+
+impl MotionDef {
+    /// Give an entity spawned at `position` this motion.
+    pub fn insert(&self, entity: &mut EntityCommands, position: Vec3) {
+        let axis = |axis: (f32, f32, f32)| Dir3::new(Vec3::from(axis)).unwrap_or(Dir3::Y);
+        match *self {
+            Self::Spin {
+                axis: spin_axis,
+                degrees_per_sec,
+            } => {
+                entity.insert(Spin {
+                    axis: axis(spin_axis),
+                    speed: degrees_per_sec.to_radians(),
+                });
+            }
+            Self::Orbit {
+                center,
+                axis: orbit_axis,
+                degrees_per_sec,
+            } => {
+                entity.insert(Orbit::around(
+                    Vec3::from(center),
+                    axis(orbit_axis),
+                    degrees_per_sec.to_radians(),
+                    position,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// src/features/rotation/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod systems;
+
+/// Spinning and orbiting scene props.
+///
+/// Scope:
+/// - `Spin`: turn in place around an axis
+/// - `Orbit`: circle a fixed point, keeping the spawn distance
+/// - `MotionDef`: the data form (e.g. a hazard's `motion` in `hazards.ron`)
+///
+/// Design constraints:
+/// - motion is kinematic and steps in `FixedUpdate` with the other movers, so
+///   orbiting trigger volumes (hazards) are where the gameplay checks expect them.
+pub struct RotationPlugin;
+
+impl Plugin for RotationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (systems::spin, systems::orbit).in_set(AppSet::Integrate),
+        );
+    }
+}
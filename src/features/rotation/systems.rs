@@ -0,0 +1,94 @@
+// src/features/rotation/systems.rs
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::component::{Orbit, Spin};
+
+/// FixedUpdate: turn spinning entities.
+pub fn spin(time: Res<Time<Fixed>>, mut q_spinning: Query<(&Spin, &mut Transform)>) {
+    let dt = time.delta_secs();
+    for (spin, mut transform) in &mut q_spinning {
+        transform.rotate_axis(spin.axis, spin.speed * dt);
+    }
+}
+
+/// FixedUpdate: move orbiting entities along their circle.
+pub fn orbit(time: Res<Time<Fixed>>, mut q_orbiting: Query<(&mut Orbit, &mut Transform)>) {
+    let dt = time.delta_secs();
+    for (mut orbit, mut transform) in &mut q_orbiting {
+        orbit.angle = (orbit.angle + orbit.speed * dt).rem_euclid(TAU);
+        transform.translation = orbit.position();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::rotation::component::MotionDef;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(0.25));
+        world.insert_resource(time);
+        world
+    }
+
+    fn spawn_with(world: &mut World, motion: MotionDef, position: Vec3) -> Entity {
+        let entity = world.spawn(Transform::from_translation(position)).id();
+        let _ = world.run_system_once(move |mut commands: Commands| {
+            motion.insert(&mut commands.entity(entity), position);
+        });
+        entity
+    }
+
+    #[test]
+    fn spinning_props_turn_in_place() {
+        let mut world = setup_world();
+        let prop = spawn_with(
+            &mut world,
+            MotionDef::Spin {
+                axis: (0.0, 2.0, 0.0),
+                degrees_per_sec: 360.0,
+            },
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+
+        // A quarter second at a turn per second: a quarter turn.
+        let _ = world.run_system_once(spin);
+
+        let transform = world.get::<Transform>(prop).unwrap();
+        assert_eq!(transform.translation, Vec3::new(1.0, 0.0, 0.0));
+        let forward = transform.forward().as_vec3();
+        assert!(forward.abs_diff_eq(Vec3::NEG_X, 1e-5), "{forward}");
+    }
+
+    #[test]
+    fn orbiting_props_circle_their_center_at_spawn_distance() {
+        let mut world = setup_world();
+        let prop = spawn_with(
+            &mut world,
+            MotionDef::Orbit {
+                center: (0.0, 1.0, 0.0),
+                axis: (0.0, 0.0, 0.0),
+                degrees_per_sec: 360.0,
+            },
+            Vec3::new(2.0, 1.0, 0.0),
+        );
+
+        let _ = world.run_system_once(orbit);
+        let quarter = world.get::<Transform>(prop).unwrap().translation;
+        assert!(quarter.abs_diff_eq(Vec3::new(0.0, 1.0, -2.0), 1e-5), "{quarter}");
+
+        // Three more quarters: back where it started, angle wrapped.
+        for _ in 0..3 {
+            let _ = world.run_system_once(orbit);
+        }
+        let full = world.get::<Transform>(prop).unwrap().translation;
+        assert!(full.abs_diff_eq(Vec3::new(2.0, 1.0, 0.0), 1e-4), "{full}");
+        assert!(world.get::<Orbit>(prop).unwrap().angle < TAU);
+    }
+}
@@ -0,0 +1,214 @@
+// src/features/save/component.rs
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::migrate::{MIGRATIONS, from_ron_with};
+use crate::app::{Difficulty, StoragePaths};
+use crate::features::building::component::StructureKind;
+use crate::features::skills::component::SkillTreeSave;
+use crate::features::wallet::component::Wallet;
+
+/// Current save format; bump it (and register a migration) on every layout change.
+pub const SAVE_VERSION: u32 = 4;
+
+/// The game save file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameSave {
+    /// Format the file was written in (missing = 0, the unversioned layout).
+    pub version: u32,
+    /// Difficulty the run is played on (saves before version 4: `Normal`).
+    #[serde(default, with = "difficulty_label")]
+    pub difficulty: Difficulty,
+    pub player: PlayerSave,
+    #[serde(default)]
+    pub world: WorldSave,
+}
+
+/// `Difficulty` by label (`"Hard"`): saves pass through untyped data on their way
+/// in, where bare enum variants don't survive.
+mod difficulty_label {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    use crate::app::Difficulty;
+
+    pub fn serialize<S: Serializer>(
+        difficulty: &Difficulty,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(difficulty.label())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Difficulty, D::Error> {
+        let label = String::deserialize(deserializer)?;
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.label() == label)
+            .ok_or_else(|| de::Error::custom(format!("unknown difficulty {label:?}")))
+    }
+}
+
+/// The player's persisted progress.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSave {
+    #[serde(default)]
+    pub position: Option<(f32, f32, f32)>,
+    #[serde(default)]
+    pub wallet: Wallet,
+    #[serde(default)]
+    pub skills: SkillTreeSave,
+    /// `(item, count)` pairs.
+    #[serde(default)]
+    pub inventory: Vec<(String, u32)>,
+}
+
+/// The world around the player.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorldSave {
+    /// Scenes loaded when saving (the player may stand in one reached by portal).
+    #[serde(default)]
+    pub scenes: Vec<String>,
+    /// Player-built structures: `(kind, translation)` pairs.
+    #[serde(default)]
+    pub structures: Vec<(StructureKind, (f32, f32, f32))>,
+}
+
+/// Why a save couldn't be read or written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveError {
+    Io(String),
+    /// Not valid save data (syntax, or fields a migration couldn't make sense of).
+    Malformed(String),
+    /// Written by a newer build than this one (in a format it doesn't know).
+    TooNew { version: u32 },
+    /// A registered migration rejected the data.
+    Migration { from: u32, reason: String },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Malformed(err) => write!(f, "malformed save: {err}"),
+            Self::TooNew { version } => {
+                write!(f, "version {version} was written by a newer build")
+            }
+            Self::Migration { from, reason } => {
+                write!(f, "migrating from version {from} failed: {reason}")
+            }
+        }
+    }
+}
+
+impl GameSave {
+    /// Parse save text of any known version, migrating it to the current layout.
+    pub fn from_ron(source: &str) -> Result<Self, SaveError> {
+        from_ron_with(source, MIGRATIONS, SAVE_VERSION)
+    }
+
+    /// Stored save, or `Ok(None)` when there is no file yet.
+    pub fn load(path: &Path) -> Result<Option<Self>, SaveError> {
+        match std::fs::read_to_string(path) {
+            Ok(source) => Self::from_ron(&source).map(Some),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(SaveError::Io(err.to_string())),
+        }
+    }
+
+    /// Write in the current format (whatever `version` says).
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let current = Self {
+            version: SAVE_VERSION,
+            ..self.clone()
+        };
+        let text = ron::ser::to_string_pretty(&current, ron::ser::PrettyConfig::default())
+            .map_err(std::io::Error::other)?;
+        std::fs::write(path, text)
+    }
+}
+
+/// Where the game is saved.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct SaveFile(pub PathBuf);
+
+impl FromWorld for SaveFile {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).data_file("game.ron"))
+    }
+}
+
+/// Quick save / quick load keys.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveKeybindings {
+    pub quick_save: KeyCode,
+    pub quick_load: KeyCode,
+}
+
+impl Default for SaveKeybindings {
+    fn default() -> Self {
+        Self {
+            quick_save: KeyCode::F5,
+            quick_load: KeyCode::F9,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_round_trip_in_the_current_version() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_save_{}", std::process::id()))
+            .join("game.ron");
+        assert_eq!(GameSave::load(&path), Ok(None));
+
+        let save = GameSave {
+            version: 0,
+            difficulty: Difficulty::Hard,
+            player: PlayerSave {
+                position: Some((1.0, 2.0, 3.0)),
+                wallet: Wallet::new(7),
+                skills: SkillTreeSave {
+                    points: 1,
+                    unlocked: vec!["blink".into()],
+                },
+                inventory: vec![("wood".into(), 4)],
+            },
+            world: WorldSave {
+                scenes: vec!["hub".into(), "ruins".into()],
+                structures: vec![(StructureKind::Crate, (2.0, 0.4, -3.0))],
+            },
+        };
+        save.save(&path).unwrap();
+
+        let loaded = GameSave::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.version, SAVE_VERSION, "written in the current format");
+        assert_eq!(loaded.difficulty, Difficulty::Hard);
+        assert_eq!(loaded.player, save.player);
+        assert_eq!(loaded.world, save.world);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn save_file_lives_in_the_data_directory() {
+        let mut world = World::new();
+        world.insert_resource(StoragePaths {
+            data_dir: PathBuf::from("/data"),
+            config_dir: PathBuf::from("/config"),
+            portable: false,
+        });
+        assert_eq!(SaveFile::from_world(&mut world).0, Path::new("/data/game.ron"));
+    }
+
+    #[test]
+    fn garbage_is_malformed() {
+        assert!(matches!(GameSave::from_ron("(player: 3"), Err(SaveError::Malformed(_))));
+    }
+}
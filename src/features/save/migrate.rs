@@ -0,0 +1,253 @@
+// src/features/save/migrate.rs
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use super::component::{SAVE_VERSION, SaveError};
+use crate::app::Difficulty;
+
+/// One step of the save format: turns `from` data into `from + 1` data.
+///
+/// Migrations work on untyped data so old layouts never need Rust types of their
+/// own; the final result is deserialized into the current `GameSave`.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub from: u32,
+    /// What changed (logs).
+    pub summary: &'static str,
+    pub apply: fn(&mut Map<String, Value>) -> Result<(), String>,
+}
+
+/// Every migration, oldest first; `MIGRATIONS[n].from == n`.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 0,
+        summary: "player progress moved under `player`",
+        apply: nest_player_progress,
+    },
+    Migration {
+        from: 1,
+        summary: "loaded scenes recorded under `world`",
+        apply: add_world,
+    },
+    Migration {
+        from: 2,
+        summary: "player-built structures recorded under `world`",
+        apply: add_structures,
+    },
+    Migration {
+        from: 3,
+        summary: "the run's difficulty recorded",
+        apply: add_difficulty,
+    },
+];
+
+/// Bring raw save data of any version up to `SAVE_VERSION`.
+pub fn migrate(raw: Value) -> Result<Value, SaveError> {
+    migrate_with(raw, MIGRATIONS, SAVE_VERSION)
+}
+
+/// Parse RON of any version `migrations` know, bring it up to `target` and read it
+/// as `T`. The game save goes through here, and so do the side files kept next to
+/// it (exploration, tutorials), each with its own registry.
+pub fn from_ron_with<T: DeserializeOwned>(
+    source: &str,
+    migrations: &[Migration],
+    target: u32,
+) -> Result<T, SaveError> {
+    let raw: Value = ron::from_str(source).map_err(|err| SaveError::Malformed(err.to_string()))?;
+    let current = migrate_with(raw, migrations, target)?;
+    serde_json::from_value(current).map_err(|err| SaveError::Malformed(err.to_string()))
+}
+
+/// `migrate` against an explicit registry (tests, tools, side files).
+pub fn migrate_with(
+    raw: Value,
+    migrations: &[Migration],
+    target: u32,
+) -> Result<Value, SaveError> {
+    let Value::Object(mut data) = raw else {
+        return Err(SaveError::Malformed("expected a struct at the top level".into()));
+    };
+    let mut version = match data.get("version") {
+        None => 0,
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| SaveError::Malformed(format!("bad version {value}")))?,
+    };
+    if version > target {
+        return Err(SaveError::TooNew { version });
+    }
+
+    while version < target {
+        let migration = migrations
+            .iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| SaveError::Migration {
+                from: version,
+                reason: "no migration registered".into(),
+            })?;
+        (migration.apply)(&mut data).map_err(|reason| SaveError::Migration {
+            from: version,
+            reason,
+        })?;
+        version += 1;
+        data.insert("version".into(), Value::from(version));
+    }
+    Ok(Value::Object(data))
+}
+
+/// 0 -> 1: the unversioned layout stored `wallet` and `skills` at the top level.
+fn nest_player_progress(data: &mut Map<String, Value>) -> Result<(), String> {
+    let mut player = Map::new();
+    for key in ["wallet", "skills"] {
+        if let Some(value) = data.remove(key) {
+            player.insert(key.into(), value);
+        }
+    }
+    data.insert("player".into(), Value::Object(player));
+    Ok(())
+}
+
+/// 1 -> 2: scenes weren't recorded; start from none (the startup scenes load anyway).
+fn add_world(data: &mut Map<String, Value>) -> Result<(), String> {
+    let mut world = Map::new();
+    world.insert("scenes".into(), Value::Array(Vec::new()));
+    data.insert("world".into(), Value::Object(world));
+    Ok(())
+}
+
+/// 2 -> 3: structures weren't recorded; the world had none as far as the save knows.
+fn add_structures(data: &mut Map<String, Value>) -> Result<(), String> {
+    let world = data
+        .entry("world")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or("`world` is not a struct")?;
+    world.insert("structures".into(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// 3 -> 4: difficulty wasn't recorded; runs back then played on the default preset.
+fn add_difficulty(data: &mut Map<String, Value>) -> Result<(), String> {
+    data.insert("difficulty".into(), Value::from(Difficulty::default().label()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::building::component::StructureKind;
+    use crate::features::save::component::GameSave;
+
+    const V0: &str = include_str!("../../../tests/fixtures/saves/v0.ron");
+    const V1: &str = include_str!("../../../tests/fixtures/saves/v1.ron");
+    const V2: &str = include_str!("../../../tests/fixtures/saves/v2.ron");
+    const V3: &str = include_str!("../../../tests/fixtures/saves/v3.ron");
+    const V4: &str = include_str!("../../../tests/fixtures/saves/v4.ron");
+
+    #[test]
+    fn registry_covers_every_version() {
+        for (n, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.from, n as u32, "{}", migration.summary);
+        }
+        assert_eq!(MIGRATIONS.len() as u32, SAVE_VERSION);
+    }
+
+    #[test]
+    fn unversioned_fixture_loads() {
+        let save = GameSave::from_ron(V0).unwrap();
+
+        assert_eq!(save.version, SAVE_VERSION);
+        assert_eq!(save.player.wallet.coins, 42);
+        assert_eq!(save.player.skills.points, 1);
+        assert_eq!(save.player.skills.unlocked, vec!["blink".to_string()]);
+        assert_eq!(save.player.position, None);
+        assert!(save.player.inventory.is_empty());
+    }
+
+    #[test]
+    fn version_1_fixture_loads() {
+        let save = GameSave::from_ron(V1).unwrap();
+
+        assert_eq!(save.player.position, Some((4.0, 0.5, -2.0)));
+        assert_eq!(save.player.wallet.coins, 15);
+        assert_eq!(save.player.inventory, vec![("stone".to_string(), 3), ("wood".to_string(), 12)]);
+        assert!(save.world.scenes.is_empty());
+    }
+
+    #[test]
+    fn version_2_fixture_loads() {
+        let save = GameSave::from_ron(V2).unwrap();
+
+        assert_eq!(save.player.position, Some((400.0, 0.5, 396.0)));
+        assert_eq!(save.world.scenes, vec!["hub".to_string(), "ruins".to_string()]);
+        assert!(save.world.structures.is_empty());
+    }
+
+    #[test]
+    fn version_3_fixture_loads() {
+        let save = GameSave::from_ron(V3).unwrap();
+
+        assert_eq!(save.world.scenes, vec!["hub".to_string()]);
+        assert_eq!(
+            save.world.structures,
+            vec![
+                (StructureKind::Floor, (2.0, 0.1, -3.0)),
+                (StructureKind::Crate, (2.0, 0.6, -3.0)),
+            ]
+        );
+        assert_eq!(save.difficulty, Difficulty::Normal);
+    }
+
+    #[test]
+    fn version_4_fixture_loads() {
+        let save = GameSave::from_ron(V4).unwrap();
+
+        assert_eq!(save.difficulty, Difficulty::Hard);
+        assert_eq!(save.player.wallet.coins, 8);
+        assert_eq!(save.world.structures, vec![(StructureKind::Wall, (0.0, 1.0, 4.0))]);
+    }
+
+    #[test]
+    fn unknown_difficulty_is_malformed() {
+        assert!(matches!(
+            GameSave::from_ron("(version: 4, difficulty: \"Nightmare\", player: ())"),
+            Err(SaveError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn newer_saves_are_refused() {
+        assert_eq!(
+            GameSave::from_ron("(version: 99, player: ())"),
+            Err(SaveError::TooNew { version: 99 })
+        );
+    }
+
+    #[test]
+    fn later_migrations_chain_onto_earlier_ones() {
+        fn rename_coins(data: &mut Map<String, Value>) -> Result<(), String> {
+            let wallet = data
+                .get_mut("player")
+                .and_then(|p| p.get_mut("wallet"))
+                .and_then(Value::as_object_mut)
+                .ok_or("no wallet")?;
+            let coins = wallet.remove("coins").ok_or("no coins")?;
+            wallet.insert("gold".into(), coins);
+            Ok(())
+        }
+        let mut registry = MIGRATIONS.to_vec();
+        registry.push(Migration {
+            from: 4,
+            summary: "coins renamed to gold",
+            apply: rename_coins,
+        });
+
+        let raw: Value = ron::from_str(V0).unwrap();
+        let migrated = migrate_with(raw, &registry, 5).unwrap();
+
+        assert_eq!(migrated["version"], 5);
+        assert_eq!(migrated["player"]["wallet"]["gold"], 42);
+    }
+}
@@ -0,0 +1,58 @@
+// src/features/save/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::building::systems::RestoreStructures;
+use crate::features::scenes::systems::{LoadScene, UnloadScene};
+use crate::features::skills::systems::RestoreSkillTree;
+use crate::features::wallet::systems::RestoreWallet;
+
+pub mod component;
+pub mod migrate;
+pub mod systems;
+
+/// Game saves.
+///
+/// Scope:
+/// - `GameSave`: the run's difficulty, the player's position, wallet, skill tree and
+///   inventory, the loaded scenes and the player-built structures, in the save file
+///   (`SaveFile`), written with `SaveGame`, read back with `LoadGame`
+/// - quick save / quick load keys (`SaveKeybindings`, F5 / F9)
+/// - versioning: every file records `SAVE_VERSION`; older files are upgraded step
+///   by step through the `migrate::MIGRATIONS` registry before they're read, newer
+///   ones are refused
+/// - the same machinery (`migrate::from_ron_with`) for side files other features keep
+///   in the data directory (exploration, tutorial progress), each with its own registry
+///
+/// Design constraints:
+/// - a layout change bumps `SAVE_VERSION`, registers a migration from the previous
+///   version and adds a fixture under `tests/fixtures/saves` that must keep loading.
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::SaveFile>();
+        app.init_resource::<component::SaveKeybindings>();
+
+        app.add_message::<systems::SaveGame>();
+        app.add_message::<systems::LoadGame>();
+        app.add_message::<RestoreWallet>();
+        app.add_message::<RestoreSkillTree>();
+        app.add_message::<RestoreStructures>();
+        app.add_message::<LoadScene>();
+        app.add_message::<UnloadScene>();
+
+        app.add_systems(
+            Update,
+            systems::read_save_hotkeys
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            Update,
+            (systems::save_game, systems::load_game)
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,243 @@
+// src/features/save/systems.rs
+use bevy::prelude::*;
+
+use super::component::{GameSave, PlayerSave, SaveFile, SaveKeybindings, WorldSave};
+use crate::app::GameplayTuning;
+use crate::features::building::component::PlacedStructure;
+use crate::features::building::systems::RestoreStructures;
+use crate::features::inventory::component::Inventory;
+use crate::features::player::component::Player;
+use crate::features::scenes::component::LoadedScenes;
+use crate::features::scenes::systems::{LoadScene, UnloadScene};
+use crate::features::skills::component::SkillTree;
+use crate::features::skills::systems::RestoreSkillTree;
+use crate::features::wallet::component::Wallet;
+use crate::features::wallet::systems::RestoreWallet;
+
+/// Write the player's progress to the save file.
+#[derive(Message, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SaveGame;
+
+/// Read the save file (any known version) back onto the player.
+#[derive(Message, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LoadGame;
+
+/// Update: quick save / quick load keys.
+pub fn read_save_hotkeys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<SaveKeybindings>,
+    mut save: MessageWriter<SaveGame>,
+    mut load: MessageWriter<LoadGame>,
+) {
+    if keyboard.just_pressed(bindings.quick_save) {
+        save.write(SaveGame);
+    }
+    if keyboard.just_pressed(bindings.quick_load) {
+        load.write(LoadGame);
+    }
+}
+
+/// Update: persist the player's progress (plus the run's difficulty, the loaded
+/// scenes and placed structures) on request.
+#[expect(clippy::type_complexity)]
+pub fn save_game(
+    file: Res<SaveFile>,
+    tuning: Res<GameplayTuning>,
+    loaded: Option<Res<LoadedScenes>>,
+    mut requests: MessageReader<SaveGame>,
+    q_player: Query<
+        (&Transform, Option<&Wallet>, Option<&SkillTree>, Option<&Inventory>),
+        With<Player>,
+    >,
+    q_structures: Query<(&PlacedStructure, &Transform)>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    let Some((transform, wallet, skills, inventory)) = q_player.iter().next() else {
+        return;
+    };
+    let save = GameSave {
+        difficulty: tuning.difficulty,
+        player: PlayerSave {
+            position: Some(transform.translation.into()),
+            wallet: wallet.copied().unwrap_or_default(),
+            skills: skills.map(SkillTree::to_save).unwrap_or_default(),
+            inventory: inventory
+                .map(|inv| inv.iter().map(|(item, n)| (item.to_string(), n)).collect())
+                .unwrap_or_default(),
+        },
+        world: WorldSave {
+            scenes: loaded.map(|loaded| loaded.0.clone()).unwrap_or_default(),
+            structures: q_structures
+                .iter()
+                .map(|(s, tr)| (s.kind, tr.translation.into()))
+                .collect(),
+        },
+        ..default()
+    };
+    match save.save(&file.0) {
+        Ok(()) => info!("saved to {}", file.0.display()),
+        Err(err) => warn!("could not save {}: {err}", file.0.display()),
+    }
+}
+
+/// Update: load on request. Wallet, skills and placed structures go through their
+/// restore messages; inventory, position and the run's difficulty are replaced in
+/// place. The saved scenes are loaded and the others unloaded, so the player doesn't
+/// land in unloaded space.
+#[expect(clippy::too_many_arguments)]
+pub fn load_game(
+    file: Res<SaveFile>,
+    mut tuning: ResMut<GameplayTuning>,
+    loaded: Option<Res<LoadedScenes>>,
+    mut requests: MessageReader<LoadGame>,
+    mut q_player: Query<(Entity, &mut Transform, Option<&mut Inventory>), With<Player>>,
+    mut wallets: MessageWriter<RestoreWallet>,
+    mut skills: MessageWriter<RestoreSkillTree>,
+    mut structures: MessageWriter<RestoreStructures>,
+    mut scene_loads: MessageWriter<LoadScene>,
+    mut scene_unloads: MessageWriter<UnloadScene>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    let save = match GameSave::load(&file.0) {
+        Ok(Some(save)) => save,
+        Ok(None) => return,
+        Err(err) => {
+            warn!("could not load {}: {err}", file.0.display());
+            return;
+        }
+    };
+    let Some((entity, mut transform, inventory)) = q_player.iter_mut().next() else {
+        return;
+    };
+
+    *tuning = GameplayTuning::for_difficulty(save.difficulty);
+
+    let scenes = save.world.scenes;
+    if !scenes.is_empty() {
+        for name in loaded.iter().flat_map(|loaded| &loaded.0) {
+            if !scenes.contains(name) {
+                scene_unloads.write(UnloadScene { name: name.clone() });
+            }
+        }
+        for name in scenes {
+            scene_loads.write(LoadScene { name });
+        }
+    }
+
+    structures.write(RestoreStructures {
+        structures: save
+            .world
+            .structures
+            .into_iter()
+            .map(|(kind, translation)| (kind, translation.into()))
+            .collect(),
+    });
+
+    let player = save.player;
+    if let Some(position) = player.position {
+        transform.translation = position.into();
+    }
+    if let Some(mut inventory) = inventory {
+        *inventory = Inventory::default();
+        for (item, count) in &player.inventory {
+            inventory.add(item, *count);
+        }
+    }
+    wallets.write(RestoreWallet {
+        entity,
+        wallet: player.wallet,
+    });
+    skills.write(RestoreSkillTree {
+        entity,
+        save: player.skills,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Difficulty;
+    use crate::features::building::component::StructureKind;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn saved_progress_loads_back_onto_the_player() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_game_save_{}", std::process::id()));
+        let mut world = World::new();
+        world.insert_resource(SaveFile(dir.join("game.ron")));
+        world.insert_resource(GameplayTuning::for_difficulty(Difficulty::Hard));
+        world.init_resource::<Messages<SaveGame>>();
+        world.init_resource::<Messages<LoadGame>>();
+        world.init_resource::<Messages<RestoreWallet>>();
+        world.init_resource::<Messages<RestoreSkillTree>>();
+        world.init_resource::<Messages<RestoreStructures>>();
+        world.init_resource::<Messages<LoadScene>>();
+        world.init_resource::<Messages<UnloadScene>>();
+        world.insert_resource(LoadedScenes(vec!["hub".into(), "ruins".into()]));
+        let mut inventory = Inventory::default();
+        inventory.add("wood", 3);
+        let player = world
+            .spawn((
+                Player,
+                Transform::from_xyz(2.0, 0.5, 1.0),
+                Wallet::new(30),
+                inventory,
+            ))
+            .id();
+        world.spawn((
+            PlacedStructure {
+                kind: StructureKind::Crate,
+            },
+            Transform::from_xyz(2.0, 0.4, -3.0),
+        ));
+
+        world.write_message(SaveGame);
+        let _ = world.run_system_once(save_game);
+
+        world.entity_mut(player).insert((Transform::default(), Inventory::default()));
+        world.insert_resource(LoadedScenes(vec!["hub".into(), "grove".into()]));
+        world.insert_resource(GameplayTuning::default());
+        world.write_message(LoadGame);
+        let _ = world.run_system_once(load_game);
+
+        assert_eq!(
+            world.get::<Transform>(player).unwrap().translation,
+            Vec3::new(2.0, 0.5, 1.0)
+        );
+        assert_eq!(world.get::<Inventory>(player).unwrap().count("wood"), 3);
+        assert_eq!(world.resource::<GameplayTuning>().difficulty, Difficulty::Hard);
+        let restored: Vec<RestoreWallet> = world
+            .resource::<Messages<RestoreWallet>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(
+            restored,
+            vec![RestoreWallet {
+                entity: player,
+                wallet: Wallet::new(30)
+            }]
+        );
+        assert_eq!(world.resource::<Messages<RestoreSkillTree>>().len(), 1);
+        let structures: Vec<_> = world.resource_mut::<Messages<RestoreStructures>>().drain().collect();
+        assert_eq!(
+            structures,
+            [RestoreStructures {
+                structures: vec![(StructureKind::Crate, Vec3::new(2.0, 0.4, -3.0))]
+            }]
+        );
+        let loads: Vec<_> = world.resource_mut::<Messages<LoadScene>>().drain().collect();
+        let unloads: Vec<_> = world.resource_mut::<Messages<UnloadScene>>().drain().collect();
+        assert_eq!(
+            loads,
+            [LoadScene { name: "hub".into() }, LoadScene { name: "ruins".into() }]
+        );
+        assert_eq!(unloads, [UnloadScene { name: "grove".into() }]);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,125 @@
+// src/features/scenes/component.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::features::enemy::component::SpawnerDef;
+use crate::features::portal::component::PortalDestination;
+use crate::features::surface::component::SurfaceMaterial;
+
+/// A box of level geometry.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScenePiece {
+    pub position: (f32, f32, f32),
+    pub half_extents: (f32, f32, f32),
+    /// `MaterialLibrary` key.
+    pub material: String,
+    #[serde(default)]
+    pub surface: SurfaceMaterial,
+}
+
+/// A trigger box that loads / unloads scenes when a player walks in.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SceneDoor {
+    pub position: (f32, f32, f32),
+    pub half_extents: (f32, f32, f32),
+    #[serde(default)]
+    pub load: Vec<String>,
+    #[serde(default)]
+    pub unload: Vec<String>,
+}
+
+/// A trigger box that sends a player walking in to `to` (see the portal feature).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScenePortal {
+    pub position: (f32, f32, f32),
+    pub half_extents: (f32, f32, f32),
+    pub to: PortalDestination,
+}
+
+/// One scene file (`assets/scenes/<name>.ron`).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct SceneDef {
+    /// Never unloaded once loaded (the hub).
+    #[serde(default)]
+    pub persistent: bool,
+    #[serde(default)]
+    pub pieces: Vec<ScenePiece>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub doors: Vec<SceneDoor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub portals: Vec<ScenePortal>,
+    /// Enemy spawners, active while the scene is loaded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawners: Vec<SpawnerDef>,
+    /// Where dead players come back (`SpawnPoint`s).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawn_points: Vec<(f32, f32, f32)>,
+}
+
+impl SceneDef {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+const BUILTIN_SCENES: [(&str, &str); 3] = [
+    ("hub", include_str!("../../../assets/scenes/hub.ron")),
+    ("grove", include_str!("../../../assets/scenes/grove.ron")),
+    ("ruins", include_str!("../../../assets/scenes/ruins.ron")),
+];
+
+/// Scene files by name.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SceneLibrary(pub HashMap<String, SceneDef>);
+
+impl Default for SceneLibrary {
+    fn default() -> Self {
+        Self(
+            BUILTIN_SCENES
+                .iter()
+                .map(|(name, source)| {
+                    let def = SceneDef::from_ron(source)
+                        .unwrap_or_else(|err| panic!("assets/scenes/{name}.ron must parse: {err}"));
+                    ((*name).to_owned(), def)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Scenes loaded right after startup.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct StartupScenes(pub Vec<String>);
+
+impl Default for StartupScenes {
+    fn default() -> Self {
+        Self(vec!["hub".into()])
+    }
+}
+
+/// Scenes currently loaded, in load order.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadedScenes(pub Vec<String>);
+
+impl LoadedScenes {
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|loaded| loaded == name)
+    }
+}
+
+/// Spawned by the named scene; unloading it despawns exactly these entities.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SceneMember(pub String);
+
+/// Door spawned from a `SceneDoor` (its `TriggerVolume` sits alongside).
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SceneDoorway {
+    pub load: Vec<String>,
+    pub unload: Vec<String>,
+}
@@ -0,0 +1,177 @@
+// src/features/scenes/generate.rs
+use bevy::prelude::*;
+
+use super::component::{SceneDef, ScenePiece};
+use crate::features::biome::component::{BiomeLibrary, ScatterKind};
+use crate::features::biome::scatter::{ScatterInstance, scatter_chunk, slope_deg};
+use crate::features::biome::systems::{biome_spawners, shape_terrain};
+use crate::features::surface::component::SurfaceMaterial;
+use crate::features::terrain::component::{TERRAIN_CHUNK_CELLS, TerrainData};
+
+/// World units between terrain vertices of a generated scene.
+const SPACING: f32 = 1.0;
+
+/// Terrain cells per side of one ground column.
+const GROUND_TILE_CELLS: u32 = 4;
+
+/// Ground columns reach this far below their lowest corner (no gaps between steps).
+const GROUND_DEPTH: f32 = 1.0;
+
+/// Steepest ground a spawn point is put on (degrees).
+const SPAWN_POINT_MAX_SLOPE_DEG: f32 = 20.0;
+
+/// What `generate_scene` builds (`cargo xtask gen-scene --size N --seed S`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerateOptions {
+    /// World units per side, rounded up to whole terrain chunks; centered on the origin.
+    pub size: u32,
+    /// Replaces the biome library's seed.
+    pub seed: u32,
+}
+
+/// A scene generated from `library`'s biomes: terrain shaped by `shape_terrain` and
+/// laid out as ground columns, scatter props as boxes, the biomes' spawners and
+/// spawn points on flat ground. The same options and library give the same scene.
+pub fn generate_scene(options: GenerateOptions, library: &BiomeLibrary) -> SceneDef {
+    let library = BiomeLibrary {
+        seed: options.seed,
+        ..library.clone()
+    };
+    let cells = options.size.max(1).div_ceil(TERRAIN_CHUNK_CELLS) * TERRAIN_CHUNK_CELLS;
+    let half = cells as f32 * SPACING * 0.5;
+    let size = cells + 1;
+    let mut terrain = TerrainData {
+        origin: (-half, -half),
+        size,
+        spacing: SPACING,
+        heights: vec![0.0; (size * size) as usize],
+        paint: vec![0; (size * size) as usize],
+        needs_generation: true,
+    };
+    shape_terrain(&library, &mut terrain);
+
+    let mut pieces = ground_columns(&library, &terrain);
+    for z in 0..terrain.chunks() {
+        for x in 0..terrain.chunks() {
+            let props = scatter_chunk(&library, &terrain, UVec2::new(x, z));
+            pieces.extend(props.iter().filter_map(prop_piece));
+        }
+    }
+    SceneDef {
+        pieces,
+        spawners: biome_spawners(&library, &terrain)
+            .into_iter()
+            .map(|(_, def)| def)
+            .collect(),
+        spawn_points: spawn_points(&terrain),
+        ..default()
+    }
+}
+
+/// One box per `GROUND_TILE_CELLS` square, its top at the terrain height in the
+/// middle and its surface from the biome there.
+fn ground_columns(library: &BiomeLibrary, terrain: &TerrainData) -> Vec<ScenePiece> {
+    let tiles = (terrain.size - 1) / GROUND_TILE_CELLS;
+    let width = GROUND_TILE_CELLS as f32 * terrain.spacing;
+    let mut pieces = Vec::with_capacity((tiles * tiles) as usize);
+    for tz in 0..tiles {
+        for tx in 0..tiles {
+            let (x0, z0) = (tx * GROUND_TILE_CELLS, tz * GROUND_TILE_CELLS);
+            let (x1, z1) = (x0 + GROUND_TILE_CELLS, z0 + GROUND_TILE_CELLS);
+            let center = (terrain.vertex_xz(x0, z0) + terrain.vertex_xz(x1, z1)) * 0.5;
+            let top = terrain.height_at(center).unwrap_or(0.0);
+            let lowest = [(x0, z0), (x1, z0), (x0, z1), (x1, z1)]
+                .into_iter()
+                .map(|(x, z)| terrain.height(x, z))
+                .fold(top, f32::min);
+            let bottom = lowest - GROUND_DEPTH;
+            pieces.push(ScenePiece {
+                position: (center.x, (top + bottom) * 0.5, center.y),
+                half_extents: (width * 0.5, (top - bottom) * 0.5, width * 0.5),
+                material: "ground".into(),
+                surface: library.at(center).surface,
+            });
+        }
+    }
+    pieces
+}
+
+/// Trees and rocks as boxes standing on the ground (grass is left out).
+fn prop_piece(instance: &ScatterInstance) -> Option<ScenePiece> {
+    let (half_extents, surface) = match instance.kind {
+        ScatterKind::Tree => (Vec3::new(0.3, 1.5, 0.3), SurfaceMaterial::Wood),
+        ScatterKind::Rock => (Vec3::new(0.5, 0.35, 0.5), SurfaceMaterial::Stone),
+        ScatterKind::Grass => return None,
+    };
+    let half_extents = half_extents * instance.scale;
+    let position = instance.position + Vec3::Y * half_extents.y;
+    Some(ScenePiece {
+        position: position.into(),
+        half_extents: half_extents.into(),
+        material: "accent".into(),
+        surface,
+    })
+}
+
+/// The middle and four points halfway to the corners, where the ground is flat
+/// enough; the middle regardless if none is.
+fn spawn_points(terrain: &TerrainData) -> Vec<(f32, f32, f32)> {
+    let last = terrain.size - 1;
+    let (min, max) = (terrain.vertex_xz(0, 0), terrain.vertex_xz(last, last));
+    let (center, quarter) = ((min + max) * 0.5, (max - min) * 0.25);
+    let at = |xz: Vec2| (xz.x, terrain.height_at(xz).unwrap_or(0.0) + 0.5, xz.y);
+    let points: Vec<_> = [
+        Vec2::ZERO,
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(-1.0, 1.0),
+        Vec2::new(1.0, 1.0),
+    ]
+    .into_iter()
+    .map(|offset| center + offset * quarter)
+    .filter(|&xz| slope_deg(terrain, xz) <= SPAWN_POINT_MAX_SLOPE_DEG)
+    .map(at)
+    .collect();
+    if points.is_empty() {
+        vec![at(center)]
+    } else {
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(seed: u32) -> GenerateOptions {
+        GenerateOptions { size: 64, seed }
+    }
+
+    #[test]
+    fn the_same_seed_generates_the_same_scene() {
+        let library = BiomeLibrary::default();
+        let scene = generate_scene(options(7), &library);
+        assert_eq!(scene, generate_scene(options(7), &library));
+        assert_ne!(scene, generate_scene(options(8), &library));
+    }
+
+    #[test]
+    fn generated_scenes_cover_the_area_and_have_spawn_points() {
+        let scene = generate_scene(GenerateOptions { size: 50, seed: 3 }, &BiomeLibrary::default());
+        // Rounded up to 64 units: 16 x 16 ground columns of 4 units.
+        let ground = scene.pieces.iter().filter(|p| p.material == "ground").count();
+        assert_eq!(ground, 16 * 16);
+        assert!(!scene.spawn_points.is_empty());
+        for &(x, _, z) in &scene.spawn_points {
+            assert!(x.abs() < 32.0 && z.abs() < 32.0, "({x}, {z}) outside the scene");
+        }
+        assert!(!scene.persistent);
+    }
+
+    #[test]
+    fn generated_scenes_round_trip_through_ron() {
+        let scene = generate_scene(options(11), &BiomeLibrary::default());
+        let text = scene.to_ron().unwrap();
+        assert_eq!(SceneDef::from_ron(&text).unwrap(), scene);
+    }
+}
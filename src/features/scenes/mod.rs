@@ -0,0 +1,53 @@
+// src/features/scenes/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::materials::component::MaterialLibrary;
+use crate::features::meshes::component::MeshLibrary;
+use crate::features::trigger::systems::TriggerEntered;
+
+pub mod component;
+pub mod generate;
+pub mod systems;
+
+/// Additive scene loading.
+///
+/// Scope:
+/// - scene files (`assets/scenes/*.ron`, `SceneLibrary`): level boxes, doors,
+///   portals, enemy spawners and spawn points
+/// - `LoadScene` / `UnloadScene` add a scene next to those already loaded or take
+///   it out again (`LoadedScenes`); `SceneLoaded` / `SceneUnloaded` report it
+/// - the persistent hub loads at startup (`StartupScenes`) and never unloads;
+///   sub-areas stream in and out through doors (`SceneDoorway` trigger volumes a
+///   player walks into)
+/// - `generate::generate_scene`: seeded placeholder scenes from the biomes (terrain
+///   columns, props, spawners, spawn points), written by `cargo xtask gen-scene`
+///
+/// Design constraints:
+/// - Every entity a scene spawns carries `SceneMember`, so unloading despawns exactly
+///   that scene's entities (through the end-of-tick `DespawnQueue`).
+pub struct ScenesPlugin;
+
+impl Plugin for ScenesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::SceneLibrary>();
+        app.init_resource::<component::StartupScenes>();
+        app.init_resource::<component::LoadedScenes>();
+        app.init_resource::<MeshLibrary>();
+        app.init_resource::<MaterialLibrary>();
+
+        app.add_message::<systems::LoadScene>();
+        app.add_message::<systems::UnloadScene>();
+        app.add_message::<systems::SceneLoaded>();
+        app.add_message::<systems::SceneUnloaded>();
+        app.add_message::<TriggerEntered>();
+
+        app.add_systems(Startup, systems::request_startup_scenes);
+        app.add_systems(
+            Update,
+            (systems::open_scene_doors, systems::apply_scene_requests)
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
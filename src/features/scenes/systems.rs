@@ -0,0 +1,334 @@
+// src/features/scenes/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    LoadedScenes, SceneDef, SceneDoorway, SceneLibrary, SceneMember, StartupScenes,
+};
+use crate::app::DespawnQueue;
+use crate::features::collision::component::Collider;
+use crate::features::culling::component::Cullable;
+use crate::features::enemy::component::EnemySpawner;
+use crate::features::materials::component::MaterialLibrary;
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::player::component::Player;
+use crate::features::player::death::SpawnPoint;
+use crate::features::portal::component::Portal;
+use crate::features::trigger::component::TriggerVolume;
+use crate::features::trigger::systems::TriggerEntered;
+
+/// Add the named scene to the world (ignored when it is already loaded).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct LoadScene {
+    pub name: String,
+}
+
+/// Despawn everything the named scene spawned (persistent scenes stay).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct UnloadScene {
+    pub name: String,
+}
+
+/// The named scene's entities were spawned this frame.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SceneLoaded {
+    pub name: String,
+}
+
+/// The named scene's entities were queued for despawning this frame.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SceneUnloaded {
+    pub name: String,
+}
+
+/// Startup: ask for the startup scenes (the hub).
+pub fn request_startup_scenes(startup: Res<StartupScenes>, mut loads: MessageWriter<LoadScene>) {
+    for name in &startup.0 {
+        loads.write(LoadScene { name: name.clone() });
+    }
+}
+
+/// Update: a player walking into a door loads / unloads its scenes.
+pub fn open_scene_doors(
+    mut entered: MessageReader<TriggerEntered>,
+    q_doors: Query<&SceneDoorway>,
+    q_players: Query<(), With<Player>>,
+    mut loads: MessageWriter<LoadScene>,
+    mut unloads: MessageWriter<UnloadScene>,
+) {
+    for ev in entered.read() {
+        let Ok(door) = q_doors.get(ev.volume) else {
+            continue;
+        };
+        if !q_players.contains(ev.entity) {
+            continue;
+        }
+        for name in &door.unload {
+            unloads.write(UnloadScene { name: name.clone() });
+        }
+        for name in &door.load {
+            loads.write(LoadScene { name: name.clone() });
+        }
+    }
+}
+
+/// Spawn `def`'s entities, each tagged `SceneMember(name)`.
+fn spawn_scene(
+    commands: &mut Commands,
+    name: &str,
+    def: &SceneDef,
+    meshes: &MeshLibrary,
+    materials: &MaterialLibrary,
+) {
+    let member = SceneMember(name.to_owned());
+    for piece in &def.pieces {
+        let half_extents = Vec3::from(piece.half_extents);
+        commands.spawn((
+            member.clone(),
+            Mesh3d(meshes.get(MeshPrimitive::Cube)),
+            MeshMaterial3d(materials.get(&piece.material)),
+            Transform::from_translation(piece.position.into()).with_scale(half_extents * 2.0),
+            Collider::cuboid(half_extents),
+            piece.surface,
+            Cullable::default(),
+        ));
+    }
+    for door in &def.doors {
+        commands.spawn((
+            member.clone(),
+            SceneDoorway {
+                load: door.load.clone(),
+                unload: door.unload.clone(),
+            },
+            TriggerVolume {
+                half_extents: door.half_extents.into(),
+            },
+            Transform::from_translation(door.position.into()),
+            Name::new(format!("Door ({name})")),
+        ));
+    }
+    for portal in &def.portals {
+        commands.spawn((
+            member.clone(),
+            Portal {
+                destination: portal.to.clone(),
+            },
+            TriggerVolume {
+                half_extents: portal.half_extents.into(),
+            },
+            Transform::from_translation(portal.position.into()),
+            Name::new(format!("Portal ({name})")),
+        ));
+    }
+    for spawner in &def.spawners {
+        commands.spawn((
+            member.clone(),
+            EnemySpawner::new(spawner.clone()),
+            Transform::from_translation(spawner.position.into()),
+            Name::new(format!("Spawner ({name})")),
+        ));
+    }
+    for &point in &def.spawn_points {
+        commands.spawn((
+            member.clone(),
+            SpawnPoint,
+            Transform::from_translation(point.into()),
+            Name::new(format!("Spawn Point ({name})")),
+        ));
+    }
+}
+
+/// Update: unload, then load the requested scenes.
+///
+/// - Loading spawns the scene's pieces, doors, portals, spawners and spawn points
+///   next to whatever is already loaded (additive); unknown or already loaded scenes
+///   are skipped.
+/// - Unloading despawns exactly the scene's `SceneMember`s; persistent scenes stay.
+#[expect(clippy::too_many_arguments)]
+pub fn apply_scene_requests(
+    mut commands: Commands,
+    mut despawns: DespawnQueue,
+    library: Res<SceneLibrary>,
+    meshes: Res<MeshLibrary>,
+    materials: Res<MaterialLibrary>,
+    mut loaded: ResMut<LoadedScenes>,
+    mut loads: MessageReader<LoadScene>,
+    mut unloads: MessageReader<UnloadScene>,
+    q_members: Query<(Entity, &SceneMember)>,
+    mut loaded_out: MessageWriter<SceneLoaded>,
+    mut unloaded_out: MessageWriter<SceneUnloaded>,
+) {
+    for UnloadScene { name } in unloads.read() {
+        if !loaded.contains(name) {
+            continue;
+        }
+        if library.0.get(name).is_some_and(|def| def.persistent) {
+            warn!("scene {name:?} is persistent; not unloading it");
+            continue;
+        }
+        loaded.0.retain(|loaded| loaded != name);
+        for (entity, member) in &q_members {
+            if member.0 == *name {
+                despawns.despawn(entity);
+            }
+        }
+        unloaded_out.write(SceneUnloaded { name: name.clone() });
+    }
+
+    for LoadScene { name } in loads.read() {
+        if loaded.contains(name) {
+            continue;
+        }
+        let Some(def) = library.0.get(name) else {
+            warn!("no scene {name:?} in the scene library");
+            continue;
+        };
+        spawn_scene(&mut commands, name, def, &meshes, &materials);
+        loaded.0.push(name.clone());
+        loaded_out.write(SceneLoaded { name: name.clone() });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use crate::features::biome::component::BiomeLibrary;
+    use crate::features::scenes::generate::{GenerateOptions, generate_scene};
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<SceneLibrary>();
+        world.init_resource::<LoadedScenes>();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<MaterialLibrary>();
+        world.init_resource::<Messages<LoadScene>>();
+        world.init_resource::<Messages<UnloadScene>>();
+        world.init_resource::<Messages<SceneLoaded>>();
+        world.init_resource::<Messages<SceneUnloaded>>();
+        world.init_resource::<Messages<TriggerEntered>>();
+        world
+    }
+
+    fn members(world: &mut World, scene: &str) -> Vec<Entity> {
+        world
+            .query_filtered::<(Entity, &SceneMember), Without<Despawning>>()
+            .iter(world)
+            .filter(|(_, member)| member.0 == scene)
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+
+    /// Handle the pending requests, then drop them (`run_system_once` readers would
+    /// see them again next time).
+    fn run_requests(world: &mut World) {
+        let _ = world.run_system_once(apply_scene_requests);
+        world.resource_mut::<Messages<LoadScene>>().clear();
+        world.resource_mut::<Messages<UnloadScene>>().clear();
+    }
+
+    fn load(world: &mut World, name: &str) {
+        world.write_message(LoadScene { name: name.into() });
+        run_requests(world);
+    }
+
+    fn unload(world: &mut World, name: &str) {
+        world.write_message(UnloadScene { name: name.into() });
+        run_requests(world);
+    }
+
+    #[test]
+    fn builtin_scenes_parse_and_the_hub_is_persistent() {
+        let library = SceneLibrary::default();
+        assert!(library.0["hub"].persistent);
+        assert!(!library.0["grove"].persistent);
+        assert!(library.0["hub"].doors.iter().any(|d| d.load.contains(&"grove".to_owned())));
+        let to_ruins = &library.0["grove"].portals[0].to;
+        assert_eq!(to_ruins.scene.as_deref(), Some("ruins"));
+        assert!(library.0.contains_key("ruins"));
+        assert!(library.0["ruins"].portals.iter().all(|p| p.to.unload == ["ruins"]));
+    }
+
+    #[test]
+    fn scenes_load_additively_and_unload_only_their_own_entities() {
+        let mut world = setup_world();
+        load(&mut world, "hub");
+        load(&mut world, "grove");
+        load(&mut world, "grove");
+
+        let library = world.resource::<SceneLibrary>().clone();
+        let hub = members(&mut world, "hub");
+        let grove = members(&mut world, "grove");
+        let spawned = |def: &SceneDef| {
+            def.pieces.len()
+                + def.doors.len()
+                + def.portals.len()
+                + def.spawners.len()
+                + def.spawn_points.len()
+        };
+        assert_eq!(hub.len(), spawned(&library.0["hub"]));
+        assert_eq!(grove.len(), spawned(&library.0["grove"]), "loading twice spawns once");
+        assert_eq!(world.resource::<LoadedScenes>().0, ["hub", "grove"]);
+
+        unload(&mut world, "grove");
+        assert!(members(&mut world, "grove").is_empty());
+        assert_eq!(members(&mut world, "hub"), hub);
+        assert!(grove.iter().all(|&e| world.entity(e).contains::<Despawning>()));
+
+        unload(&mut world, "hub");
+        assert_eq!(members(&mut world, "hub"), hub, "persistent");
+        assert_eq!(world.resource::<LoadedScenes>().0, ["hub"]);
+    }
+
+    #[test]
+    fn generated_scenes_bring_their_spawners_and_spawn_points() {
+        let mut world = setup_world();
+        let options = GenerateOptions { size: 32, seed: 5 };
+        let def = generate_scene(options, &BiomeLibrary::default());
+        world.resource_mut::<SceneLibrary>().0.insert("gen".into(), def.clone());
+        load(&mut world, "gen");
+
+        let points = world
+            .query_filtered::<&SceneMember, With<SpawnPoint>>()
+            .iter(&world)
+            .count();
+        let spawners = world
+            .query_filtered::<&SceneMember, With<EnemySpawner>>()
+            .iter(&world)
+            .count();
+        assert_eq!(points, def.spawn_points.len());
+        assert_eq!(spawners, def.spawners.len());
+
+        unload(&mut world, "gen");
+        assert!(members(&mut world, "gen").is_empty());
+    }
+
+    #[test]
+    fn players_walking_into_doors_stream_scenes() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+        let door = world
+            .spawn(SceneDoorway {
+                load: vec!["grove".into()],
+                unload: vec!["cave".into()],
+            })
+            .id();
+        let stranger = world.spawn_empty().id();
+        world.write_message(TriggerEntered {
+            volume: door,
+            entity: stranger,
+        });
+        world.write_message(TriggerEntered {
+            volume: door,
+            entity: player,
+        });
+
+        let _ = world.run_system_once(open_scene_doors);
+
+        let loads: Vec<_> = world.resource_mut::<Messages<LoadScene>>().drain().collect();
+        let unloads: Vec<_> = world.resource_mut::<Messages<UnloadScene>>().drain().collect();
+        assert_eq!(loads, [LoadScene { name: "grove".into() }]);
+        assert_eq!(unloads, [UnloadScene { name: "cave".into() }]);
+    }
+}
@@ -0,0 +1,317 @@
+// src/features/settings/component.rs
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{Difficulty, EffectsLevel, GameMode, StoragePaths};
+use crate::features::audio::component::AudioSettings;
+
+/// UI language.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Self; 4] = [Self::English, Self::German, Self::French, Self::Spanish];
+
+    /// Name in the language itself (what the picker shows).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+            Self::French => "Français",
+            Self::Spanish => "Español",
+        }
+    }
+}
+
+/// Preferred input device (prompt glyphs, default bindings).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ControlScheme {
+    #[default]
+    KeyboardMouse,
+    Gamepad,
+}
+
+impl ControlScheme {
+    pub const ALL: [Self; 2] = [Self::KeyboardMouse, Self::Gamepad];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::KeyboardMouse => "Keyboard & Mouse",
+            Self::Gamepad => "Gamepad",
+        }
+    }
+}
+
+/// How materials are lit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShadingStyle {
+    #[default]
+    Standard,
+    /// Banded (cel) lighting on characters and props.
+    Toon,
+}
+
+impl ShadingStyle {
+    pub const ALL: [Self; 2] = [Self::Standard, Self::Toon];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Toon => "Toon",
+        }
+    }
+}
+
+/// Graphics options as stored in the settings file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphicsSettings {
+    pub shading: ShadingStyle,
+}
+
+/// Readability options as stored in the settings file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// Multiplier on every UI size (layout, images and text).
+    pub ui_scale: f32,
+    /// Extra multiplier on text, on top of `ui_scale`.
+    pub font_scale: f32,
+    /// Screen shake, flashes and pulsing lights (photosensitivity).
+    pub effects: EffectsLevel,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            ui_scale: 1.0,
+            font_scale: 1.0,
+            effects: EffectsLevel::Full,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Steps the menu cycles through.
+    pub const SCALES: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+    /// Hand-edited files are held to this range so the UI stays usable.
+    const LIMITS: (f32, f32) = (0.5, 3.0);
+
+    /// The step after `current`, wrapping back to the smallest.
+    pub fn next_scale(current: f32) -> f32 {
+        Self::SCALES
+            .into_iter()
+            .find(|scale| *scale > current + 0.01)
+            .unwrap_or(Self::SCALES[0])
+    }
+
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale.clamp(Self::LIMITS.0, Self::LIMITS.1)
+    }
+
+    pub fn font_scale(&self) -> f32 {
+        self.font_scale.clamp(Self::LIMITS.0, Self::LIMITS.1)
+    }
+}
+
+/// Frame rate and power use as stored in the settings file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PerformanceSettings {
+    /// Most frames per second to render (`None` = as fast as vsync allows).
+    pub fps_cap: Option<u32>,
+    /// Update at `background_fps` while the window is unfocused or minimized.
+    pub power_saving: bool,
+    pub background_fps: u32,
+}
+
+impl Default for PerformanceSettings {
+    fn default() -> Self {
+        Self {
+            fps_cap: None,
+            power_saving: true,
+            background_fps: 10,
+        }
+    }
+}
+
+impl PerformanceSettings {
+    /// Caps the menu cycles through.
+    pub const CAPS: [Option<u32>; 5] = [None, Some(30), Some(60), Some(120), Some(144)];
+    /// Slowest allowed frame rate: frames stay under `Time<Virtual>`'s 250 ms
+    /// `max_delta`, so the fixed simulation never drops time.
+    pub const MIN_FPS: u32 = 5;
+
+    /// The cap after `current`, wrapping back to uncapped.
+    pub fn next_cap(current: Option<u32>) -> Option<u32> {
+        let index = Self::CAPS.iter().position(|c| *c == current).unwrap_or(0);
+        Self::CAPS[(index + 1) % Self::CAPS.len()]
+    }
+
+    pub fn fps_cap(&self) -> Option<u32> {
+        self.fps_cap.map(|fps| fps.max(Self::MIN_FPS))
+    }
+
+    /// Time between updates while unfocused, `None` when power saving is off.
+    pub fn background_wait(&self) -> Option<Duration> {
+        self.power_saving
+            .then(|| Duration::from_secs_f64(1.0 / self.background_fps.max(Self::MIN_FPS) as f64))
+    }
+}
+
+/// Bus volumes as stored in the settings file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub ui: f32,
+}
+
+impl Default for VolumeSettings {
+    fn default() -> Self {
+        let audio = AudioSettings::default();
+        Self {
+            master: audio.master,
+            music: audio.music,
+            sfx: audio.sfx,
+            ui: audio.ui,
+        }
+    }
+}
+
+/// The player's settings, persisted in the settings file.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserSettings {
+    pub language: Language,
+    pub volume: VolumeSettings,
+    pub controls: ControlScheme,
+    pub graphics: GraphicsSettings,
+    pub accessibility: AccessibilitySettings,
+    pub performance: PerformanceSettings,
+    /// Preset applied when a run starts (`GameplayTuning`).
+    pub difficulty: Difficulty,
+    /// Mode the main menu's "Play" button starts.
+    pub game_mode: GameMode,
+    /// First-run onboarding has been completed.
+    pub onboarded: bool,
+}
+
+impl UserSettings {
+    /// Stored settings, or `None` when there is no (readable) file yet.
+    pub fn load(path: &Path) -> Option<Self> {
+        let source = std::fs::read_to_string(path).ok()?;
+        ron::from_str(&source)
+            .inspect_err(|err| warn!("ignoring unreadable {}: {err}", path.display()))
+            .ok()
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(std::io::Error::other)?;
+        std::fs::write(path, text)
+    }
+
+    /// Copy the stored volumes onto the mixer settings.
+    pub fn apply_audio(&self, audio: &mut AudioSettings) {
+        audio.master = self.volume.master;
+        audio.music = self.volume.music;
+        audio.sfx = self.volume.sfx;
+        audio.ui = self.volume.ui;
+    }
+}
+
+/// Where `UserSettings` is saved.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct SettingsFile(pub PathBuf);
+
+impl FromWorld for SettingsFile {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).config_file("settings.ron"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_is_none_and_saved_settings_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_settings_{}", std::process::id()))
+            .join("settings.ron");
+        assert_eq!(UserSettings::load(&path), None);
+
+        let settings = UserSettings {
+            language: Language::German,
+            controls: ControlScheme::Gamepad,
+            graphics: GraphicsSettings {
+                shading: ShadingStyle::Toon,
+            },
+            accessibility: AccessibilitySettings {
+                ui_scale: 1.25,
+                font_scale: 1.5,
+                effects: EffectsLevel::Reduced,
+            },
+            performance: PerformanceSettings {
+                fps_cap: Some(60),
+                power_saving: false,
+                background_fps: 5,
+            },
+            difficulty: Difficulty::Hard,
+            game_mode: GameMode::Survival,
+            onboarded: true,
+            ..default()
+        };
+        settings.save(&path).unwrap();
+        assert_eq!(UserSettings::load(&path), Some(settings));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn scales_cycle_and_stay_in_range() {
+        assert_eq!(AccessibilitySettings::next_scale(1.0), 1.25);
+        assert_eq!(AccessibilitySettings::next_scale(2.0), 0.75);
+        assert_eq!(AccessibilitySettings::next_scale(1.1), 1.25, "off-step values rejoin");
+
+        let extreme = AccessibilitySettings {
+            ui_scale: 10.0,
+            font_scale: 0.0,
+            ..default()
+        };
+        assert_eq!(extreme.ui_scale(), 3.0);
+        assert_eq!(extreme.font_scale(), 0.5);
+    }
+
+    #[test]
+    fn frame_rates_stay_above_the_simulation_minimum() {
+        let slow = PerformanceSettings {
+            fps_cap: Some(1),
+            power_saving: true,
+            background_fps: 0,
+        };
+        assert_eq!(slow.fps_cap(), Some(PerformanceSettings::MIN_FPS));
+        assert_eq!(slow.background_wait(), Some(Duration::from_millis(200)));
+
+        let always_on = PerformanceSettings {
+            power_saving: false,
+            ..default()
+        };
+        assert_eq!(always_on.background_wait(), None);
+        assert_eq!(PerformanceSettings::next_cap(Some(144)), None);
+        assert_eq!(PerformanceSettings::next_cap(None), Some(30));
+    }
+}
@@ -0,0 +1,33 @@
+// src/features/settings/mod.rs
+use bevy::prelude::*;
+
+pub mod component;
+pub mod systems;
+
+/// Persistent user settings.
+///
+/// Scope:
+/// - `UserSettings` (language, bus volumes, control scheme, graphics, accessibility,
+///   frame rate / power saving, difficulty, onboarding done) loaded from the settings file before anything else starts
+/// - the stored difficulty tunes the first run (`GameplayTuning`)
+/// - changes are applied live (volumes -> `AudioSettings`)
+/// - `SaveSettings` writes the file
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::SettingsFile>();
+        app.init_resource::<component::UserSettings>();
+
+        app.add_message::<systems::SaveSettings>();
+
+        app.add_systems(
+            PreStartup,
+            (systems::load_user_settings, systems::start_run_tuning).chain(),
+        );
+        app.add_systems(
+            Update,
+            (systems::apply_user_settings, systems::save_user_settings),
+        );
+    }
+}
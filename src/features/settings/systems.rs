@@ -0,0 +1,82 @@
+// src/features/settings/systems.rs
+use bevy::prelude::*;
+
+use super::component::{SettingsFile, UserSettings};
+use crate::app::GameplayTuning;
+use crate::features::audio::component::AudioSettings;
+
+/// Write the current `UserSettings` to the settings file.
+#[derive(Message, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SaveSettings;
+
+/// PreStartup: read the settings file (defaults on first launch).
+pub fn load_user_settings(file: Res<SettingsFile>, mut settings: ResMut<UserSettings>) {
+    if let Some(stored) = UserSettings::load(&file.0) {
+        *settings = stored;
+    }
+}
+
+/// Update: push changed settings into the systems that use them.
+pub fn apply_user_settings(settings: Res<UserSettings>, mut audio: ResMut<AudioSettings>) {
+    if settings.is_changed() {
+        settings.apply_audio(&mut audio);
+    }
+}
+
+/// PreStartup (after loading): the first run plays on the stored difficulty preset.
+///
+/// Later runs pick the preset up when they start (see the main menu); changing it
+/// mid-run only affects the next one.
+pub fn start_run_tuning(settings: Res<UserSettings>, mut tuning: ResMut<GameplayTuning>) {
+    *tuning = GameplayTuning::for_difficulty(settings.difficulty);
+}
+
+/// Update: persist on request.
+pub fn save_user_settings(
+    file: Res<SettingsFile>,
+    settings: Res<UserSettings>,
+    mut requests: MessageReader<SaveSettings>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    if let Err(err) = settings.save(&file.0) {
+        warn!("could not save {}: {err}", file.0.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn settings_volumes_drive_the_mixer() {
+        let mut world = World::new();
+        world.init_resource::<AudioSettings>();
+        let mut settings = UserSettings::default();
+        settings.volume.music = 0.25;
+        world.insert_resource(settings);
+
+        let _ = world.run_system_once(apply_user_settings);
+
+        assert_eq!(world.resource::<AudioSettings>().music, 0.25);
+    }
+
+    #[test]
+    fn stored_difficulty_tunes_the_first_run() {
+        let mut world = World::new();
+        world.init_resource::<GameplayTuning>();
+        world.insert_resource(UserSettings {
+            difficulty: crate::app::Difficulty::Story,
+            ..default()
+        });
+
+        let _ = world.run_system_once(start_run_tuning);
+
+        assert_eq!(
+            *world.resource::<GameplayTuning>(),
+            GameplayTuning::for_difficulty(crate::app::Difficulty::Story)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+// src/features/shop/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::features::inventory::component::Inventory;
+use crate::features::time_of_day::{HourRange, TimeOfDay};
+use crate::features::wallet::component::Wallet;
+
+/// One tradable item of a shop.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ShopItem {
+    pub item: String,
+    /// Coins the customer pays per unit.
+    pub buy_price: u32,
+    /// Coins the vendor pays per unit bought back.
+    pub sell_price: u32,
+    /// Vendor stock after a restock.
+    pub restock_to: u32,
+}
+
+/// A vendor's catalog, loaded from `assets/data/shops.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ShopDef {
+    pub id: String,
+    pub items: Vec<ShopItem>,
+    /// Vendor purse after a restock.
+    pub coins: u32,
+    /// Hour of the day at which the vendor restocks.
+    pub restock_hour: f32,
+    /// Opening hours (`None` = always open).
+    #[serde(default)]
+    pub open: Option<HourRange>,
+}
+
+impl ShopDef {
+    /// Whether the shop trades at this time (no clock = open).
+    pub fn is_open(&self, clock: Option<&TimeOfDay>) -> bool {
+        match (self.open, clock) {
+            (Some(hours), Some(clock)) => hours.contains(clock),
+            _ => true,
+        }
+    }
+
+    pub fn item(&self, item: &str) -> Option<&ShopItem> {
+        self.items.iter().find(|i| i.item == item)
+    }
+
+    /// Refill the vendor's stock and purse (anything above the targets is kept).
+    pub fn restock(&self, stock: &mut Inventory, purse: &mut Wallet) {
+        for entry in &self.items {
+            let missing = entry.restock_to.saturating_sub(stock.count(&entry.item));
+            stock.add(&entry.item, missing);
+        }
+        purse.coins = purse.coins.max(self.coins);
+    }
+}
+
+/// All shops.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ShopLibrary(pub Vec<ShopDef>);
+
+const BUILTIN_SHOPS: &str = include_str!("../../../assets/data/shops.ron");
+
+impl ShopLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ShopDef> {
+        self.0.iter().find(|d| d.id == id)
+    }
+}
+
+impl Default for ShopLibrary {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_SHOPS).expect("assets/data/shops.ron must parse")
+    }
+}
+
+/// A character that runs a shop. Its own `Inventory` / `Wallet` hold the stock and purse.
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(Inventory, Wallet)]
+pub struct Vendor {
+    /// `ShopLibrary` id.
+    pub shop: String,
+    /// Clock hour seen on the previous tick (restock detection); `None` until first seen.
+    pub last_hour: Option<f32>,
+}
+
+impl Vendor {
+    pub fn new(shop: impl Into<String>) -> Self {
+        Self {
+            shop: shop.into(),
+            last_hour: None,
+        }
+    }
+}
+
+/// On the customer while a shop screen is open.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShopSession {
+    pub vendor: Entity,
+}
+
+/// Which way goods move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeKind {
+    /// Customer buys from the vendor.
+    Buy,
+    /// Customer sells to the vendor.
+    Sell,
+}
+
+/// Why a trade failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeError {
+    /// The shop doesn't deal in this item.
+    NotTraded,
+    /// Seller doesn't have enough of the item.
+    OutOfStock,
+    /// Buyer can't pay.
+    InsufficientCoins,
+    /// Outside the shop's opening hours.
+    Closed,
+}
+
+/// One side of a trade: where goods and coins come from / go to.
+pub struct TradeParty<'a> {
+    pub inventory: &'a mut Inventory,
+    pub wallet: &'a mut Wallet,
+}
+
+/// Move `amount` of `item` and the matching coins between customer and vendor.
+/// All-or-nothing; returns the coins paid.
+pub fn trade(
+    shop: &ShopDef,
+    kind: TradeKind,
+    item: &str,
+    amount: u32,
+    customer: TradeParty,
+    vendor: TradeParty,
+) -> Result<u32, TradeError> {
+    let entry = shop.item(item).ok_or(TradeError::NotTraded)?;
+    let (seller, buyer, unit_price) = match kind {
+        TradeKind::Buy => (vendor, customer, entry.buy_price),
+        TradeKind::Sell => (customer, vendor, entry.sell_price),
+    };
+    let price = unit_price * amount;
+
+    if seller.inventory.count(item) < amount {
+        return Err(TradeError::OutOfStock);
+    }
+    if !buyer.wallet.try_spend(price) {
+        return Err(TradeError::InsufficientCoins);
+    }
+    seller.inventory.remove(item, amount);
+    buyer.inventory.add(item, amount);
+    seller.wallet.earn(price);
+    Ok(price)
+}
+
+/// Key that closes the shop screen.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ShopCloseKeybinding(pub KeyCode);
+
+impl Default for ShopCloseKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::Escape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shop() -> ShopDef {
+        ShopLibrary::default().get("mara").unwrap().clone()
+    }
+
+    #[test]
+    fn buying_and_selling_move_goods_and_coins() {
+        let shop = shop();
+        let (mut stock, mut purse) = (Inventory::default(), Wallet::default());
+        shop.restock(&mut stock, &mut purse);
+        let (mut items, mut wallet) = (Inventory::default(), Wallet::new(10));
+        items.add("stone", 2);
+
+        let mut run = |kind, item, amount| {
+            trade(
+                &shop,
+                kind,
+                item,
+                amount,
+                TradeParty {
+                    inventory: &mut items,
+                    wallet: &mut wallet,
+                },
+                TradeParty {
+                    inventory: &mut stock,
+                    wallet: &mut purse,
+                },
+            )
+        };
+
+        assert_eq!(run(TradeKind::Buy, "wood", 3), Ok(9));
+        assert_eq!(run(TradeKind::Buy, "wood", 1), Err(TradeError::InsufficientCoins));
+        assert_eq!(run(TradeKind::Sell, "stone", 2), Ok(4));
+        assert_eq!(run(TradeKind::Sell, "stone", 1), Err(TradeError::OutOfStock));
+        assert_eq!(run(TradeKind::Sell, "iron", 1), Err(TradeError::NotTraded));
+
+        assert_eq!(items.count("wood"), 3);
+        assert_eq!(wallet.coins, 5);
+        assert_eq!(stock.count("wood"), 17);
+        assert_eq!(stock.count("stone"), 17);
+        assert_eq!(purse.coins, shop.coins + 9 - 4);
+    }
+
+    #[test]
+    fn opening_hours_follow_the_clock() {
+        let shop = shop();
+        let at = |hour| TimeOfDay {
+            hour,
+            ..default()
+        };
+        assert!(shop.is_open(Some(&at(12.0))));
+        assert!(!shop.is_open(Some(&at(22.0))));
+        assert!(shop.is_open(None));
+    }
+}
@@ -0,0 +1,58 @@
+// src/features/shop/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::dialogue::systems::DialogueHook;
+use crate::features::wallet::systems::WalletChanged;
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Vendors and trading.
+///
+/// Scope:
+/// - `ShopLibrary`: stock, prices, vendor purse and restock hour from `assets/data/shops.ron`
+/// - `Vendor` NPCs keep their stock in their own `Inventory` and their purse in a `Wallet`
+/// - the `open_shop` dialogue hook opens a `ShopSession`; the shop screen turns clicks
+///   into `TradeRequest`s, answered with `TradeCompleted` / `TradeRejected`
+/// - vendors restock once a day at their shop's hour and only trade during their
+///   opening hours (`TimeOfDay`); open screens close at closing time
+///
+/// Design constraints:
+/// - coins live in `Wallet`s (`features::wallet`); trades announce balance changes
+///   with `WalletChanged` like any other earn / spend
+/// - trades are all-or-nothing (`component::trade`)
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::ShopLibrary>();
+        app.init_resource::<component::ShopCloseKeybinding>();
+
+        app.add_message::<DialogueHook>();
+        app.add_message::<systems::TradeRequest>();
+        app.add_message::<systems::TradeCompleted>();
+        app.add_message::<systems::TradeRejected>();
+        app.add_message::<systems::CloseShop>();
+        app.add_message::<WalletChanged>();
+
+        app.add_systems(Startup, ui::spawn_shop_screen);
+        app.add_systems(Update, ui::click_shop_buttons.in_set(AppSet::Input));
+        app.add_systems(
+            Update,
+            (
+                systems::open_shops_from_dialogue,
+                systems::process_trades,
+                systems::close_shops,
+                ui::update_shop_screen,
+            )
+                .chain()
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::restock_vendors.in_set(AppSet::FixedGameplay),
+        );
+    }
+}
@@ -0,0 +1,280 @@
+// src/features/shop/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    ShopLibrary, ShopSession, TradeError, TradeKind, TradeParty, Vendor, trade,
+};
+use crate::features::dialogue::component::CONVERSATION_BREAK_DISTANCE;
+use crate::features::dialogue::systems::DialogueHook;
+use crate::features::inventory::component::Inventory;
+use crate::features::time_of_day::{TimeOfDay, crossed_hour};
+use crate::features::wallet::component::Wallet;
+use crate::features::wallet::systems::WalletChanged;
+
+/// Dialogue hook that opens the speaker's shop.
+pub const OPEN_SHOP_HOOK: &str = "open_shop";
+
+/// Buy from / sell to the vendor of the customer's open `ShopSession` (UI buttons, scripts).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct TradeRequest {
+    pub customer: Entity,
+    pub kind: TradeKind,
+    pub item: String,
+    pub amount: u32,
+}
+
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct TradeCompleted {
+    pub customer: Entity,
+    pub vendor: Entity,
+    pub kind: TradeKind,
+    pub item: String,
+    pub amount: u32,
+    /// Coins that changed hands.
+    pub price: u32,
+}
+
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct TradeRejected {
+    pub customer: Entity,
+    pub item: String,
+    pub reason: TradeError,
+}
+
+/// Close the customer's shop screen.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloseShop {
+    pub customer: Entity,
+}
+
+/// Update: the `open_shop` dialogue hook opens the speaker's shop for the actor
+/// (ignored outside opening hours).
+pub fn open_shops_from_dialogue(
+    mut commands: Commands,
+    library: Res<ShopLibrary>,
+    clock: Option<Res<TimeOfDay>>,
+    mut hooks: MessageReader<DialogueHook>,
+    q_vendors: Query<&Vendor>,
+) {
+    for ev in hooks.read() {
+        if ev.hook != OPEN_SHOP_HOOK {
+            continue;
+        }
+        let open = q_vendors
+            .get(ev.speaker)
+            .ok()
+            .and_then(|vendor| library.get(&vendor.shop))
+            .is_some_and(|shop| shop.is_open(clock.as_deref()));
+        if open {
+            commands
+                .entity(ev.actor)
+                .insert(ShopSession { vendor: ev.speaker });
+        }
+    }
+}
+
+/// Update: execute trade requests against the session's vendor.
+#[expect(clippy::too_many_arguments)]
+pub fn process_trades(
+    library: Res<ShopLibrary>,
+    clock: Option<Res<TimeOfDay>>,
+    mut requests: MessageReader<TradeRequest>,
+    q_sessions: Query<&ShopSession>,
+    mut q_vendors: Query<(&Vendor, &mut Inventory, &mut Wallet)>,
+    mut q_customers: Query<(&mut Inventory, &mut Wallet), Without<Vendor>>,
+    mut completed: MessageWriter<TradeCompleted>,
+    mut rejected: MessageWriter<TradeRejected>,
+    mut wallet_changed: MessageWriter<WalletChanged>,
+) {
+    for req in requests.read() {
+        let Ok(session) = q_sessions.get(req.customer) else {
+            continue;
+        };
+        let Ok((vendor, mut stock, mut purse)) = q_vendors.get_mut(session.vendor) else {
+            continue;
+        };
+        let Some(shop) = library.get(&vendor.shop) else {
+            continue;
+        };
+        let Ok((mut items, mut wallet)) = q_customers.get_mut(req.customer) else {
+            continue;
+        };
+        if !shop.is_open(clock.as_deref()) {
+            rejected.write(TradeRejected {
+                customer: req.customer,
+                item: req.item.clone(),
+                reason: TradeError::Closed,
+            });
+            continue;
+        }
+
+        let before = (wallet.coins, purse.coins);
+        let customer = TradeParty {
+            inventory: &mut items,
+            wallet: &mut wallet,
+        };
+        let vendor = TradeParty {
+            inventory: &mut stock,
+            wallet: &mut purse,
+        };
+        match trade(shop, req.kind, &req.item, req.amount, customer, vendor) {
+            Ok(price) => {
+                wallet_changed.write(WalletChanged::new(req.customer, before.0, wallet.coins));
+                wallet_changed.write(WalletChanged::new(session.vendor, before.1, purse.coins));
+                completed.write(TradeCompleted {
+                    customer: req.customer,
+                    vendor: session.vendor,
+                    kind: req.kind,
+                    item: req.item.clone(),
+                    amount: req.amount,
+                    price,
+                });
+            }
+            Err(reason) => {
+                rejected.write(TradeRejected {
+                    customer: req.customer,
+                    item: req.item.clone(),
+                    reason,
+                });
+            }
+        }
+    }
+}
+
+/// Update: close sessions on request, at closing time, when the customer walks away
+/// or the vendor is gone.
+pub fn close_shops(
+    mut commands: Commands,
+    library: Res<ShopLibrary>,
+    clock: Option<Res<TimeOfDay>>,
+    mut requests: MessageReader<CloseShop>,
+    q_sessions: Query<(Entity, &ShopSession, &GlobalTransform)>,
+    q_vendors: Query<(&Vendor, &GlobalTransform)>,
+) {
+    for req in requests.read() {
+        if q_sessions.contains(req.customer) {
+            commands.entity(req.customer).remove::<ShopSession>();
+        }
+    }
+    for (customer, session, customer_tr) in &q_sessions {
+        let still_open = q_vendors.get(session.vendor).is_ok_and(|(vendor, vendor_tr)| {
+            vendor_tr.translation().distance(customer_tr.translation()) <= CONVERSATION_BREAK_DISTANCE
+                && library
+                    .get(&vendor.shop)
+                    .is_some_and(|shop| shop.is_open(clock.as_deref()))
+        });
+        if !still_open {
+            commands.entity(customer).remove::<ShopSession>();
+        }
+    }
+}
+
+/// FixedUpdate: vendors restock once a day at their shop's `restock_hour`
+/// (and when first seen, so they open with full shelves).
+pub fn restock_vendors(
+    library: Res<ShopLibrary>,
+    clock: Option<Res<TimeOfDay>>,
+    mut q_vendors: Query<(&mut Vendor, &mut Inventory, &mut Wallet)>,
+) {
+    let hour = clock.map_or(0.0, |c| c.hour);
+
+    for (mut vendor, mut stock, mut purse) in &mut q_vendors {
+        let Some(shop) = library.get(&vendor.shop) else {
+            continue;
+        };
+        let due = vendor
+            .last_hour
+            .is_none_or(|last| crossed_hour(last, hour, shop.restock_hour));
+        if due {
+            shop.restock(&mut stock, &mut purse);
+        }
+        vendor.last_hour = Some(hour);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(ShopLibrary::default());
+        world.init_resource::<Messages<TradeRequest>>();
+        world.init_resource::<Messages<TradeCompleted>>();
+        world.init_resource::<Messages<TradeRejected>>();
+        world.init_resource::<Messages<WalletChanged>>();
+        world
+    }
+
+    #[test]
+    fn vendor_restocks_on_first_tick_and_at_restock_hour() {
+        let mut world = setup_world();
+        world.insert_resource(TimeOfDay {
+            hour: 12.0,
+            ..default()
+        });
+        let vendor = world.spawn(Vendor::new("mara")).id();
+
+        let _ = world.run_system_once(restock_vendors);
+        assert_eq!(world.get::<Inventory>(vendor).unwrap().count("wood"), 20);
+
+        world.get_mut::<Inventory>(vendor).unwrap().remove("wood", 15);
+        world.resource_mut::<TimeOfDay>().hour = 23.0;
+        let _ = world.run_system_once(restock_vendors);
+        assert_eq!(world.get::<Inventory>(vendor).unwrap().count("wood"), 5);
+
+        world.resource_mut::<TimeOfDay>().hour = 6.5;
+        let _ = world.run_system_once(restock_vendors);
+        assert_eq!(world.get::<Inventory>(vendor).unwrap().count("wood"), 20);
+    }
+
+    #[test]
+    fn trade_requests_need_an_open_session() {
+        let mut world = setup_world();
+        let (mut stock, mut purse) = (Inventory::default(), Wallet::default());
+        ShopLibrary::default().get("mara").unwrap().restock(&mut stock, &mut purse);
+        let vendor = world.spawn((Vendor::new("mara"), stock, purse)).id();
+        let customer = world.spawn((Inventory::default(), Wallet::new(10))).id();
+
+        let buy = TradeRequest {
+            customer,
+            kind: TradeKind::Buy,
+            item: "stone".into(),
+            amount: 2,
+        };
+        world.write_message(buy.clone());
+        let _ = world.run_system_once(process_trades);
+        assert_eq!(world.get::<Inventory>(customer).unwrap().count("stone"), 0);
+
+        world.entity_mut(customer).insert(ShopSession { vendor });
+        world.resource_mut::<Messages<TradeRequest>>().clear();
+        world.write_message(buy);
+        let _ = world.run_system_once(process_trades);
+
+        assert_eq!(world.get::<Inventory>(customer).unwrap().count("stone"), 2);
+        assert_eq!(world.get::<Wallet>(customer).unwrap().coins, 2);
+        assert_eq!(world.resource::<Messages<TradeCompleted>>().len(), 1);
+        assert_eq!(world.resource::<Messages<WalletChanged>>().len(), 2);
+
+        world.insert_resource(TimeOfDay {
+            hour: 22.0,
+            ..default()
+        });
+        world.resource_mut::<Messages<TradeRequest>>().clear();
+        world.write_message(TradeRequest {
+            customer,
+            kind: TradeKind::Buy,
+            item: "stone".into(),
+            amount: 1,
+        });
+        let _ = world.run_system_once(process_trades);
+        let rejected: Vec<_> = world
+            .resource::<Messages<TradeRejected>>()
+            .iter_current_update_messages()
+            .map(|ev| ev.reason)
+            .collect();
+        assert_eq!(rejected, vec![TradeError::Closed]);
+    }
+}
@@ -0,0 +1,237 @@
+// src/features/shop/ui.rs
+use bevy::prelude::*;
+
+use super::component::{ShopCloseKeybinding, ShopLibrary, ShopSession, TradeKind, Vendor};
+use super::systems::{CloseShop, TradeRequest};
+use crate::features::inventory::component::Inventory;
+use crate::features::npc::component::Npc;
+use crate::features::player::component::Player;
+use crate::features::wallet::component::Wallet;
+
+const BUY_COLOR: Color = Color::srgb(0.2, 0.35, 0.65);
+const SELL_COLOR: Color = Color::srgb(0.55, 0.4, 0.15);
+
+/// Root of the shop screen (hidden while no shop is open).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ShopScreen;
+
+/// "Vendor - your coins / vendor coins" header.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ShopHeaderText;
+
+/// Container the item rows are rebuilt into when either inventory changes.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ShopItemList;
+
+/// Buy / sell one unit of `item`.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ShopTradeButton {
+    pub kind: TradeKind,
+    pub item: String,
+}
+
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ShopCloseButton;
+
+/// Startup: spawn the (empty, hidden) shop screen.
+pub fn spawn_shop_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            ShopScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(30.0),
+                right: Val::Percent(30.0),
+                top: Val::Percent(15.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.9)),
+            Visibility::Hidden,
+            Name::new("ShopScreen"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((ShopHeaderText, Text::new(""), TextFont::from_font_size(18.0)));
+            panel.spawn((
+                ShopItemList,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+            ));
+            panel
+                .spawn((
+                    Button,
+                    ShopCloseButton,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        align_self: AlignSelf::End,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+                ))
+                .with_child((Text::new("Close"), TextFont::from_font_size(15.0)));
+        });
+}
+
+/// Update: show the player's open shop; rows are rebuilt only when the session or
+/// either inventory changes.
+#[expect(clippy::type_complexity)]
+pub fn update_shop_screen(
+    mut commands: Commands,
+    library: Res<ShopLibrary>,
+    q_player: Query<(Ref<ShopSession>, Ref<Inventory>, Ref<Wallet>), With<Player>>,
+    q_vendors: Query<(&Vendor, Ref<Inventory>, Ref<Wallet>, Option<&Npc>), Without<Player>>,
+    mut q_screen: Query<&mut Visibility, With<ShopScreen>>,
+    mut q_header: Query<&mut Text, With<ShopHeaderText>>,
+    q_list: Query<Entity, With<ShopItemList>>,
+) {
+    let open = q_player.iter().next().and_then(|customer| {
+        let (vendor, stock, purse, npc) = q_vendors.get(customer.0.vendor).ok()?;
+        let shop = library.get(&vendor.shop)?;
+        Some((customer, shop, stock, purse, npc))
+    });
+
+    for mut vis in &mut q_screen {
+        *vis = if open.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    let Some(((session, inventory, wallet), shop, stock, purse, npc)) = open else {
+        return;
+    };
+    let changed = session.is_changed()
+        || inventory.is_changed()
+        || wallet.is_changed()
+        || stock.is_changed()
+        || purse.is_changed();
+    if !changed {
+        return;
+    }
+
+    let name = npc.map_or("Shop", |n| n.name.as_str());
+    for mut text in &mut q_header {
+        text.0 = format!(
+            "{name}  -  your coins: {}  |  vendor coins: {}",
+            wallet.coins,
+            purse.coins
+        );
+    }
+
+    for list in &q_list {
+        commands.entity(list).despawn_related::<Children>();
+        commands.entity(list).with_children(|list| {
+            for entry in &shop.items {
+                list.spawn(Node {
+                    column_gap: Val::Px(8.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(format!(
+                            "{}  (stock {}, owned {})",
+                            entry.item,
+                            stock.count(&entry.item),
+                            inventory.count(&entry.item)
+                        )),
+                        TextFont::from_font_size(15.0),
+                        Node {
+                            flex_grow: 1.0,
+                            ..default()
+                        },
+                    ));
+                    for (kind, price, color) in [
+                        (TradeKind::Buy, entry.buy_price, BUY_COLOR),
+                        (TradeKind::Sell, entry.sell_price, SELL_COLOR),
+                    ] {
+                        let label = match kind {
+                            TradeKind::Buy => "Buy",
+                            TradeKind::Sell => "Sell",
+                        };
+                        row.spawn((
+                            Button,
+                            ShopTradeButton {
+                                kind,
+                                item: entry.item.clone(),
+                            },
+                            Node {
+                                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                        ))
+                        .with_child((Text::new(format!("{label} {price}")), TextFont::from_font_size(15.0)));
+                    }
+                });
+            }
+        });
+    }
+}
+
+/// Update: trade buttons -> `TradeRequest`; close button / key -> `CloseShop`.
+pub fn click_shop_buttons(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<ShopCloseKeybinding>,
+    q_trade: Query<(&Interaction, &ShopTradeButton), Changed<Interaction>>,
+    q_close: Query<&Interaction, (Changed<Interaction>, With<ShopCloseButton>)>,
+    q_player: Query<Entity, (With<Player>, With<ShopSession>)>,
+    mut trades: MessageWriter<TradeRequest>,
+    mut close: MessageWriter<CloseShop>,
+) {
+    let Some(customer) = q_player.iter().next() else {
+        return;
+    };
+    for (interaction, button) in &q_trade {
+        if *interaction == Interaction::Pressed {
+            trades.write(TradeRequest {
+                customer,
+                kind: button.kind,
+                item: button.item.clone(),
+                amount: 1,
+            });
+        }
+    }
+    let clicked_close = q_close.iter().any(|i| *i == Interaction::Pressed);
+    if clicked_close || keyboard.just_pressed(binding.0) {
+        close.write(CloseShop { customer });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn screen_lists_buy_and_sell_per_shop_item() {
+        let mut world = World::new();
+        world.insert_resource(ShopLibrary::default());
+        let _ = world.run_system_once(spawn_shop_screen);
+        let (mut stock, mut purse) = (Inventory::default(), Wallet::default());
+        ShopLibrary::default().get("mara").unwrap().restock(&mut stock, &mut purse);
+        let vendor = world.spawn((Vendor::new("mara"), stock, purse)).id();
+        world.spawn((Player, Inventory::default(), Wallet::new(7), ShopSession { vendor }));
+
+        let _ = world.run_system_once(update_shop_screen);
+
+        let items = ShopLibrary::default().get("mara").unwrap().items.len();
+        let buttons = world.query::<&ShopTradeButton>().iter(&world).count();
+        assert_eq!(buttons, items * 2);
+        let header = world
+            .query_filtered::<&Text, With<ShopHeaderText>>()
+            .single(&world)
+            .unwrap();
+        assert!(header.0.contains("your coins: 7"), "{}", header.0);
+        let vis = world
+            .query_filtered::<&Visibility, With<ShopScreen>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(*vis, Visibility::Inherited);
+    }
+}
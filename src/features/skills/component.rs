@@ -0,0 +1,238 @@
+// src/features/skills/component.rs
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What unlocking a skill does. Applied once, when the skill unlocks.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum SkillEffect {
+    MaxHealth(f32),
+    MaxStamina(f32),
+    /// Extra stamina per second.
+    StaminaRegen(f32),
+    /// Multiplies base move speed.
+    MoveSpeedScale(f32),
+    /// Adds a built-in ability (`AbilityDef::named`) to the next free slot.
+    Ability(String),
+}
+
+/// One node of the tree, loaded from `assets/data/skills.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SkillDef {
+    /// Stable key used by prerequisites and saves.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub cost: u32,
+    pub requires: Vec<String>,
+    pub effects: Vec<SkillEffect>,
+    /// (column, row) in the tree screen.
+    pub position: (u32, u32),
+}
+
+/// Problems found in a skill definition file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillLibraryError {
+    DuplicateId(String),
+    UnknownPrerequisite { skill: String, requires: String },
+    /// Prerequisites loop back to this skill.
+    Cycle(String),
+}
+
+/// All skill definitions.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SkillLibrary(pub Vec<SkillDef>);
+
+/// The tree shipped with the game (compiled in, so it can't go missing).
+const BUILTIN_SKILLS: &str = include_str!("../../../assets/data/skills.ron");
+
+impl SkillLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&SkillDef> {
+        self.0.iter().find(|d| d.id == id)
+    }
+
+    /// Check ids are unique and prerequisites exist and don't form cycles.
+    pub fn validate(&self) -> Result<(), SkillLibraryError> {
+        let mut seen = BTreeSet::new();
+        for def in &self.0 {
+            if !seen.insert(def.id.as_str()) {
+                return Err(SkillLibraryError::DuplicateId(def.id.clone()));
+            }
+        }
+        for def in &self.0 {
+            if let Some(missing) = def.requires.iter().find(|r| self.get(r).is_none()) {
+                return Err(SkillLibraryError::UnknownPrerequisite {
+                    skill: def.id.clone(),
+                    requires: missing.clone(),
+                });
+            }
+        }
+        for def in &self.0 {
+            // Depth-first walk of the prerequisites; coming back to `def` is a cycle.
+            let mut stack: Vec<&str> = def.requires.iter().map(String::as_str).collect();
+            let mut visited = BTreeSet::new();
+            while let Some(id) = stack.pop() {
+                if id == def.id {
+                    return Err(SkillLibraryError::Cycle(def.id.clone()));
+                }
+                if visited.insert(id)
+                    && let Some(next) = self.get(id)
+                {
+                    stack.extend(next.requires.iter().map(String::as_str));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for SkillLibrary {
+    fn default() -> Self {
+        let library = Self::from_ron(BUILTIN_SKILLS).expect("assets/data/skills.ron must parse");
+        if let Err(err) = library.validate() {
+            panic!("assets/data/skills.ron is invalid: {err:?}");
+        }
+        library
+    }
+}
+
+/// Why a skill can't be unlocked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillUnlockError {
+    UnknownSkill,
+    AlreadyUnlocked,
+    MissingPrerequisite(String),
+    NotEnoughPoints { needed: u32, available: u32 },
+}
+
+/// A character's skill progress: unspent points and unlocked skill ids.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillTree {
+    pub points: u32,
+    pub unlocked: BTreeSet<String>,
+}
+
+impl SkillTree {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(id)
+    }
+
+    /// Whether `id` can be unlocked right now (known, prerequisites met, affordable).
+    pub fn check_unlock(&self, library: &SkillLibrary, id: &str) -> Result<(), SkillUnlockError> {
+        let def = library.get(id).ok_or(SkillUnlockError::UnknownSkill)?;
+        if self.is_unlocked(id) {
+            return Err(SkillUnlockError::AlreadyUnlocked);
+        }
+        if let Some(missing) = def.requires.iter().find(|r| !self.is_unlocked(r)) {
+            return Err(SkillUnlockError::MissingPrerequisite(missing.clone()));
+        }
+        if self.points < def.cost {
+            return Err(SkillUnlockError::NotEnoughPoints {
+                needed: def.cost,
+                available: self.points,
+            });
+        }
+        Ok(())
+    }
+
+    /// Spend the points and mark `id` unlocked (after [`Self::check_unlock`]).
+    pub fn unlock(&mut self, library: &SkillLibrary, id: &str) -> Result<(), SkillUnlockError> {
+        self.check_unlock(library, id)?;
+        self.points -= library.get(id).map_or(0, |d| d.cost);
+        self.unlocked.insert(id.to_string());
+        Ok(())
+    }
+
+    pub fn to_save(&self) -> SkillTreeSave {
+        SkillTreeSave {
+            points: self.points,
+            unlocked: self.unlocked.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Persisted form of a `SkillTree` (what the save layer writes / reads).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillTreeSave {
+    pub points: u32,
+    pub unlocked: Vec<String>,
+}
+
+/// Skill points a new player starts with.
+pub const STARTING_SKILL_POINTS: u32 = 2;
+
+/// Skill points awarded to players for each enemy killed.
+pub const SKILL_POINTS_PER_KILL: u32 = 1;
+
+/// Key that opens / closes the skill tree screen.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SkillTreeKeybinding(pub KeyCode);
+
+impl Default for SkillTreeKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::KeyK)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_tree_parses_and_validates() {
+        let lib = SkillLibrary::default();
+        assert!(lib.get("blink").is_some());
+        assert_eq!(lib.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validation_catches_unknown_prerequisites_and_cycles() {
+        let skill = |id: &str, requires: &[&str]| SkillDef {
+            id: id.into(),
+            name: id.into(),
+            description: String::new(),
+            cost: 1,
+            requires: requires.iter().map(|r| r.to_string()).collect(),
+            effects: Vec::new(),
+            position: (0, 0),
+        };
+
+        let unknown = SkillLibrary(vec![skill("a", &["missing"])]);
+        assert!(matches!(
+            unknown.validate(),
+            Err(SkillLibraryError::UnknownPrerequisite { .. })
+        ));
+
+        let cycle = SkillLibrary(vec![skill("a", &["b"]), skill("b", &["a"])]);
+        assert!(matches!(cycle.validate(), Err(SkillLibraryError::Cycle(_))));
+    }
+
+    #[test]
+    fn unlock_requires_prerequisites_and_points() {
+        let lib = SkillLibrary::default();
+        let mut tree = SkillTree {
+            points: 1,
+            ..default()
+        };
+
+        assert_eq!(
+            tree.unlock(&lib, "fleet_foot"),
+            Err(SkillUnlockError::MissingPrerequisite("conditioning".into()))
+        );
+        assert_eq!(tree.unlock(&lib, "conditioning"), Ok(()));
+        assert_eq!(tree.points, 0);
+        assert_eq!(
+            tree.unlock(&lib, "conditioning"),
+            Err(SkillUnlockError::AlreadyUnlocked)
+        );
+        assert!(matches!(
+            tree.unlock(&lib, "fleet_foot"),
+            Err(SkillUnlockError::NotEnoughPoints { .. })
+        ));
+    }
+}
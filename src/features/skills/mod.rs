@@ -0,0 +1,66 @@
+// src/features/skills/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+use crate::features::ability::systems::grant_default_player_abilities;
+use crate::features::health::damage::{Died, apply_damage};
+
+pub mod component;
+pub mod systems;
+pub mod ui;
+
+/// Skill tree feature.
+///
+/// Scope:
+/// - `SkillLibrary`: tree definition from `assets/data/skills.ron`
+///   (cost, prerequisites, passive modifiers, granted abilities), validated on load
+/// - `SkillTree` on players: unspent points + unlocked ids; points come from
+///   `GrantSkillPoints` (enemy kills award some)
+/// - `UnlockSkill` -> prerequisites / points check -> `SkillUnlocked` -> effects applied once
+/// - skill tree screen (`K`): click a node to unlock it
+///
+/// Persistence: the save layer stores `SkillTree::to_save()` and loads it back with
+/// `RestoreSkillTree`, which replays the unlocks so effects are re-applied.
+pub struct SkillsPlugin;
+
+impl Plugin for SkillsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::SkillLibrary>();
+        app.init_resource::<component::SkillTreeKeybinding>();
+
+        app.add_message::<systems::UnlockSkill>();
+        app.add_message::<systems::SkillUnlocked>();
+        app.add_message::<systems::SkillUnlockRejected>();
+        app.add_message::<systems::GrantSkillPoints>();
+        app.add_message::<systems::RestoreSkillTree>();
+        app.add_message::<Died>();
+
+        app.add_systems(Startup, ui::spawn_skill_tree_screen);
+        app.add_systems(
+            Update,
+            (
+                ui::toggle_skill_tree_screen.run_if(in_input_context(HOTKEYS)),
+                ui::click_skill_buttons,
+            )
+                .in_set(AppSet::Input),
+        );
+        app.add_systems(Update, ui::update_skill_tree_screen.after(AppSet::Input));
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_player_skill_tree,
+                systems::award_kill_points,
+                systems::grant_skill_points,
+                systems::restore_skill_trees,
+                systems::process_unlock_requests,
+                systems::apply_skill_effects,
+            )
+                .chain()
+                // Effects extend the default abilities / pools, and kills are known
+                // once damage has been applied.
+                .after(grant_default_player_abilities)
+                .after(apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
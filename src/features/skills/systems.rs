@@ -0,0 +1,321 @@
+// src/features/skills/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    SKILL_POINTS_PER_KILL, STARTING_SKILL_POINTS, SkillEffect, SkillLibrary, SkillTree,
+    SkillTreeSave, SkillUnlockError,
+};
+use crate::features::ability::component::{AbilityDef, AbilitySlot, AbilitySlots, Stamina};
+use crate::features::enemy::component::Enemy;
+use crate::features::health::component::Health;
+use crate::features::health::damage::Died;
+use crate::features::movement::component::MoveConfig;
+use crate::features::player::component::Player;
+
+/// Request to unlock a skill (tree screen, scripts).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct UnlockSkill {
+    pub entity: Entity,
+    pub skill: String,
+}
+
+/// A skill was unlocked; its effects are applied by `apply_skill_effects`.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SkillUnlocked {
+    pub entity: Entity,
+    pub skill: String,
+}
+
+/// An `UnlockSkill` request was refused (UI feedback).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SkillUnlockRejected {
+    pub entity: Entity,
+    pub skill: String,
+    pub reason: SkillUnlockError,
+}
+
+/// Give skill points (level ups, quest rewards, kills).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrantSkillPoints {
+    pub entity: Entity,
+    pub amount: u32,
+}
+
+/// Load saved progress onto a character whose tree is still fresh.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct RestoreSkillTree {
+    pub entity: Entity,
+    pub save: SkillTreeSave,
+}
+
+/// Players start with a few points.
+pub fn grant_player_skill_tree(
+    mut commands: Commands,
+    q_new: Query<Entity, (Added<Player>, Without<SkillTree>)>,
+) {
+    for entity in &q_new {
+        commands.entity(entity).insert(SkillTree {
+            points: STARTING_SKILL_POINTS,
+            ..default()
+        });
+    }
+}
+
+/// FixedUpdate: every enemy death awards players skill points.
+pub fn award_kill_points(
+    mut died: MessageReader<Died>,
+    q_enemies: Query<(), With<Enemy>>,
+    q_players: Query<Entity, (With<Player>, With<SkillTree>)>,
+    mut grants: MessageWriter<GrantSkillPoints>,
+) {
+    let kills = died.read().filter(|ev| q_enemies.contains(ev.entity)).count() as u32;
+    if kills == 0 {
+        return;
+    }
+    for entity in &q_players {
+        grants.write(GrantSkillPoints {
+            entity,
+            amount: kills * SKILL_POINTS_PER_KILL,
+        });
+    }
+}
+
+/// FixedUpdate: add granted points.
+pub fn grant_skill_points(
+    mut grants: MessageReader<GrantSkillPoints>,
+    mut q_trees: Query<&mut SkillTree>,
+) {
+    for grant in grants.read() {
+        if let Ok(mut tree) = q_trees.get_mut(grant.entity) {
+            tree.points += grant.amount;
+        }
+    }
+}
+
+/// FixedUpdate: validate and apply unlock requests.
+pub fn process_unlock_requests(
+    library: Res<SkillLibrary>,
+    mut requests: MessageReader<UnlockSkill>,
+    mut q_trees: Query<&mut SkillTree>,
+    mut unlocked: MessageWriter<SkillUnlocked>,
+    mut rejected: MessageWriter<SkillUnlockRejected>,
+) {
+    for req in requests.read() {
+        let Ok(mut tree) = q_trees.get_mut(req.entity) else {
+            continue;
+        };
+        match tree.unlock(&library, &req.skill) {
+            Ok(()) => {
+                unlocked.write(SkillUnlocked {
+                    entity: req.entity,
+                    skill: req.skill.clone(),
+                });
+            }
+            Err(reason) => {
+                rejected.write(SkillUnlockRejected {
+                    entity: req.entity,
+                    skill: req.skill.clone(),
+                    reason,
+                });
+            }
+        }
+    }
+}
+
+/// FixedUpdate: replay saved unlocks (no point cost) so their effects apply again.
+///
+/// Unknown ids (skills removed from the data file) are dropped; unlocks are replayed
+/// in prerequisite order regardless of the order they were saved in.
+pub fn restore_skill_trees(
+    library: Res<SkillLibrary>,
+    mut restores: MessageReader<RestoreSkillTree>,
+    mut q_trees: Query<&mut SkillTree>,
+    mut unlocked: MessageWriter<SkillUnlocked>,
+) {
+    for restore in restores.read() {
+        let Ok(mut tree) = q_trees.get_mut(restore.entity) else {
+            continue;
+        };
+
+        let mut pending: Vec<&str> = restore.save.unlocked.iter().map(String::as_str).collect();
+        loop {
+            let before = pending.len();
+            pending.retain(|id| {
+                let Some(def) = library.get(id) else {
+                    return false;
+                };
+                if tree.is_unlocked(id) {
+                    return false;
+                }
+                if !def.requires.iter().all(|r| tree.is_unlocked(r)) {
+                    return true;
+                }
+                tree.unlocked.insert(id.to_string());
+                unlocked.write(SkillUnlocked {
+                    entity: restore.entity,
+                    skill: id.to_string(),
+                });
+                false
+            });
+            if pending.is_empty() || pending.len() == before {
+                break;
+            }
+        }
+        tree.points = restore.save.points;
+    }
+}
+
+/// FixedUpdate: apply the effects of newly unlocked skills to the character.
+#[expect(clippy::type_complexity)]
+pub fn apply_skill_effects(
+    library: Res<SkillLibrary>,
+    mut unlocked: MessageReader<SkillUnlocked>,
+    mut q_targets: Query<(
+        Option<&mut Health>,
+        Option<&mut Stamina>,
+        Option<&mut MoveConfig>,
+        Option<&mut AbilitySlots>,
+    )>,
+) {
+    for ev in unlocked.read() {
+        let (Some(def), Ok((mut health, mut stamina, mut speed, mut abilities))) =
+            (library.get(&ev.skill), q_targets.get_mut(ev.entity))
+        else {
+            continue;
+        };
+
+        for effect in &def.effects {
+            match effect {
+                SkillEffect::MaxHealth(bonus) => {
+                    if let Some(health) = health.as_mut() {
+                        health.max += bonus;
+                        health.current += bonus;
+                    }
+                }
+                SkillEffect::MaxStamina(bonus) => {
+                    if let Some(stamina) = stamina.as_mut() {
+                        stamina.0.max += bonus;
+                        stamina.0.current += bonus;
+                    }
+                }
+                SkillEffect::StaminaRegen(bonus) => {
+                    if let Some(stamina) = stamina.as_mut() {
+                        stamina.0.regen_per_sec += bonus;
+                    }
+                }
+                SkillEffect::MoveSpeedScale(factor) => {
+                    if let Some(speed) = speed.as_mut() {
+                        speed.scale(*factor);
+                    }
+                }
+                SkillEffect::Ability(name) => {
+                    if let (Some(abilities), Some(ability)) =
+                        (abilities.as_mut(), AbilityDef::named(name))
+                        && !abilities.0.iter().any(|slot| slot.def.name == ability.name)
+                    {
+                        abilities.0.push(AbilitySlot::new(ability));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::ability::component::Pool;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(SkillLibrary::default());
+        world.init_resource::<Messages<UnlockSkill>>();
+        world.init_resource::<Messages<SkillUnlocked>>();
+        world.init_resource::<Messages<SkillUnlockRejected>>();
+        world.init_resource::<Messages<RestoreSkillTree>>();
+        world
+    }
+
+    fn spawn_character(world: &mut World, points: u32) -> Entity {
+        world
+            .spawn((
+                SkillTree {
+                    points,
+                    ..default()
+                },
+                Health::new(100.0),
+                Stamina(Pool::new(100.0, 20.0)),
+                MoveConfig::uniform(10.0),
+                AbilitySlots(vec![AbilitySlot::new(AbilityDef::dash())]),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn unlocking_spends_points_and_applies_effects() {
+        let mut world = setup_world();
+        let e = spawn_character(&mut world, 4);
+
+        for skill in ["conditioning", "fleet_foot", "blink", "toughness"] {
+            world.write_message(UnlockSkill {
+                entity: e,
+                skill: skill.into(),
+            });
+        }
+        let _ = world.run_system_once(process_unlock_requests);
+        let _ = world.run_system_once(apply_skill_effects);
+
+        let tree = world.get::<SkillTree>(e).unwrap();
+        assert_eq!(tree.points, 0);
+        assert!(tree.is_unlocked("blink"));
+        assert!(!tree.is_unlocked("toughness"), "out of points");
+        assert_eq!(world.get::<Stamina>(e).unwrap().0.max, 125.0);
+        assert!((world.get::<MoveConfig>(e).unwrap().horizontal - 11.0).abs() < 1e-5);
+        let names: Vec<_> = world.get::<AbilitySlots>(e).unwrap().0.iter().map(|s| s.def.name).collect();
+        assert_eq!(names, vec!["dash", "blink"]);
+        assert_eq!(world.resource::<Messages<SkillUnlockRejected>>().len(), 1);
+    }
+
+    #[test]
+    fn restore_replays_unlocks_in_prerequisite_order() {
+        let mut world = setup_world();
+        let e = spawn_character(&mut world, 0);
+
+        world.write_message(RestoreSkillTree {
+            entity: e,
+            save: SkillTreeSave {
+                points: 3,
+                unlocked: vec!["fleet_foot".into(), "removed_skill".into(), "conditioning".into()],
+            },
+        });
+        let _ = world.run_system_once(restore_skill_trees);
+        let _ = world.run_system_once(apply_skill_effects);
+
+        let tree = world.get::<SkillTree>(e).unwrap().clone();
+        assert_eq!(tree.points, 3);
+        assert_eq!(
+            tree.to_save().unlocked,
+            vec!["conditioning".to_string(), "fleet_foot".to_string()]
+        );
+        assert_eq!(world.get::<Stamina>(e).unwrap().0.max, 125.0);
+    }
+
+    #[test]
+    fn enemy_deaths_award_points_to_players() {
+        let mut world = setup_world();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<GrantSkillPoints>>();
+        let player = world.spawn((Player, SkillTree::default())).id();
+        let enemy = world.spawn(Enemy).id();
+        let other = world.spawn_empty().id();
+
+        world.write_message(Died { entity: enemy });
+        world.write_message(Died { entity: other });
+        let _ = world.run_system_once(award_kill_points);
+        let _ = world.run_system_once(grant_skill_points);
+
+        assert_eq!(world.get::<SkillTree>(player).unwrap().points, SKILL_POINTS_PER_KILL);
+    }
+}
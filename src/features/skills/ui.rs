@@ -0,0 +1,191 @@
+// src/features/skills/ui.rs
+use bevy::prelude::*;
+
+use super::component::{SkillLibrary, SkillTree, SkillTreeKeybinding};
+use super::systems::UnlockSkill;
+use crate::features::player::component::Player;
+
+const NODE_WIDTH: f32 = 180.0;
+const UNLOCKED_COLOR: Color = Color::srgb(0.2, 0.55, 0.25);
+const AVAILABLE_COLOR: Color = Color::srgb(0.2, 0.35, 0.65);
+const LOCKED_COLOR: Color = Color::srgb(0.25, 0.25, 0.28);
+
+/// Root of the skill tree screen (hidden until toggled).
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SkillTreeScreen;
+
+/// "Skill points: N" label.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SkillPointsLabel;
+
+/// Clickable tree node for one skill.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SkillButton {
+    pub skill: String,
+}
+
+/// Startup: build the screen from the library (one button per skill, laid out on
+/// a grid by `SkillDef::position`).
+pub fn spawn_skill_tree_screen(mut commands: Commands, library: Res<SkillLibrary>) {
+    let columns = library.0.iter().map(|d| d.position.0 + 1).max().unwrap_or(1) as usize;
+
+    commands
+        .spawn((
+            SkillTreeScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            Visibility::Hidden,
+            Name::new("SkillTreeScreen"),
+        ))
+        .with_children(|screen| {
+            screen.spawn((Text::new("Skills"), TextFont::from_font_size(32.0)));
+            screen.spawn((SkillPointsLabel, Text::new(""), TextFont::from_font_size(20.0)));
+            screen
+                .spawn(Node {
+                    display: Display::Grid,
+                    grid_template_columns: vec![GridTrack::px(NODE_WIDTH); columns],
+                    column_gap: Val::Px(16.0),
+                    row_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for def in &library.0 {
+                        grid.spawn((
+                            Button,
+                            SkillButton {
+                                skill: def.id.clone(),
+                            },
+                            Node {
+                                grid_column: GridPlacement::start(def.position.0 as i16 + 1),
+                                grid_row: GridPlacement::start(def.position.1 as i16 + 1),
+                                flex_direction: FlexDirection::Column,
+                                padding: UiRect::all(Val::Px(8.0)),
+                                ..default()
+                            },
+                            BackgroundColor(LOCKED_COLOR),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new(format!("{} ({})", def.name, def.cost)),
+                                TextFont::from_font_size(18.0),
+                            ));
+                            button.spawn((
+                                Text::new(def.description.clone()),
+                                TextFont::from_font_size(13.0),
+                            ));
+                        });
+                    }
+                });
+        });
+}
+
+/// Update: toggle the screen.
+pub fn toggle_skill_tree_screen(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<SkillTreeKeybinding>,
+    mut q_screen: Query<&mut Visibility, With<SkillTreeScreen>>,
+) {
+    if !keyboard.just_pressed(binding.0) {
+        return;
+    }
+    for mut vis in &mut q_screen {
+        *vis = match *vis {
+            Visibility::Hidden => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+/// Update: clicking a skill requests the unlock for the player.
+pub fn click_skill_buttons(
+    q_buttons: Query<(&Interaction, &SkillButton), Changed<Interaction>>,
+    q_player: Query<Entity, (With<Player>, With<SkillTree>)>,
+    mut requests: MessageWriter<UnlockSkill>,
+) {
+    let Some(player) = q_player.iter().next() else {
+        return;
+    };
+    for (interaction, button) in &q_buttons {
+        if *interaction == Interaction::Pressed {
+            requests.write(UnlockSkill {
+                entity: player,
+                skill: button.skill.clone(),
+            });
+        }
+    }
+}
+
+/// Update: color nodes by state (unlocked / available / locked) and show the points.
+pub fn update_skill_tree_screen(
+    library: Res<SkillLibrary>,
+    q_player: Query<&SkillTree, With<Player>>,
+    mut q_buttons: Query<(&SkillButton, &mut BackgroundColor)>,
+    mut q_label: Query<&mut Text, With<SkillPointsLabel>>,
+) {
+    let Some(tree) = q_player.iter().next() else {
+        return;
+    };
+
+    for (button, mut bg) in &mut q_buttons {
+        bg.0 = if tree.is_unlocked(&button.skill) {
+            UNLOCKED_COLOR
+        } else if tree.check_unlock(&library, &button.skill).is_ok() {
+            AVAILABLE_COLOR
+        } else {
+            LOCKED_COLOR
+        };
+    }
+    for mut label in &mut q_label {
+        let text = format!("Skill points: {}", tree.points);
+        if label.0 != text {
+            label.0 = text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn screen_has_a_node_per_skill_colored_by_state() {
+        let mut world = World::new();
+        world.insert_resource(SkillLibrary::default());
+        let _ = world.run_system_once(spawn_skill_tree_screen);
+
+        let mut tree = SkillTree {
+            points: 1,
+            ..default()
+        };
+        tree.unlocked.insert("conditioning".into());
+        world.spawn((Player, tree));
+
+        let _ = world.run_system_once(update_skill_tree_screen);
+
+        let colors: std::collections::BTreeMap<_, _> = world
+            .query::<(&SkillButton, &BackgroundColor)>()
+            .iter(&world)
+            .map(|(b, bg)| (b.skill.clone(), bg.0))
+            .collect();
+        assert_eq!(colors.len(), SkillLibrary::default().0.len());
+        assert_eq!(colors["conditioning"], UNLOCKED_COLOR);
+        assert_eq!(colors["fleet_foot"], AVAILABLE_COLOR);
+        assert_eq!(colors["blink"], LOCKED_COLOR);
+
+        let label = world
+            .query_filtered::<&Text, With<SkillPointsLabel>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(label.0, "Skill points: 1");
+    }
+}
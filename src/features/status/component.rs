@@ -0,0 +1,170 @@
+// src/features/status/component.rs
+use bevy::prelude::*;
+
+use crate::features::movement::component::SpeedMultiplier;
+
+/// The kinds of status effect the game knows about.
+///
+/// Magnitude meaning depends on the kind (see [`StatusEffect::magnitude`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusEffectKind {
+    /// Reduces move speed by `magnitude` (fraction, `0.3` = -30%).
+    Slow,
+    /// Increases move speed by `magnitude` (fraction, `0.5` = +50%).
+    Haste,
+    /// Deals `magnitude` damage every `tick_interval_secs`.
+    Poison,
+}
+
+impl StatusEffectKind {
+    /// Asset path of the HUD icon for this effect.
+    pub fn icon_path(self) -> &'static str {
+        match self {
+            StatusEffectKind::Slow => "icons/status/slow.png",
+            StatusEffectKind::Haste => "icons/status/haste.png",
+            StatusEffectKind::Poison => "icons/status/poison.png",
+        }
+    }
+
+    /// Multiplicative contribution of one instance of this effect to move speed.
+    pub fn speed_factor(self, magnitude: f32) -> f32 {
+        match self {
+            StatusEffectKind::Slow => 1.0 - magnitude.clamp(0.0, 1.0),
+            StatusEffectKind::Haste => 1.0 + magnitude.max(0.0),
+            StatusEffectKind::Poison => 1.0,
+        }
+    }
+}
+
+/// One active instance of a status effect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    /// Kind-specific strength (fraction for speed effects, damage per tick for poison).
+    pub magnitude: f32,
+    /// Remaining lifetime.
+    pub duration: Timer,
+    /// Periodic tick timer (damage-over-time). `None` for purely passive effects.
+    pub tick: Option<Timer>,
+}
+
+impl StatusEffect {
+    pub fn slow(fraction: f32, duration_secs: f32) -> Self {
+        Self::passive(StatusEffectKind::Slow, fraction, duration_secs)
+    }
+
+    pub fn haste(fraction: f32, duration_secs: f32) -> Self {
+        Self::passive(StatusEffectKind::Haste, fraction, duration_secs)
+    }
+
+    pub fn poison(damage_per_tick: f32, tick_interval_secs: f32, duration_secs: f32) -> Self {
+        Self {
+            kind: StatusEffectKind::Poison,
+            magnitude: damage_per_tick,
+            duration: Timer::from_seconds(duration_secs, TimerMode::Once),
+            tick: Some(Timer::from_seconds(tick_interval_secs, TimerMode::Repeating)),
+        }
+    }
+
+    fn passive(kind: StatusEffectKind, magnitude: f32, duration_secs: f32) -> Self {
+        Self {
+            kind,
+            magnitude,
+            duration: Timer::from_seconds(duration_secs, TimerMode::Once),
+            tick: None,
+        }
+    }
+}
+
+/// HUD-facing view of one active effect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusIcon {
+    pub kind: StatusEffectKind,
+    pub icon: &'static str,
+    pub remaining_secs: f32,
+}
+
+/// All active status effects on an entity.
+///
+/// Stacking rules:
+/// - Every application is its own instance (two slows = two entries).
+/// - Speed effects stack multiplicatively: two 50% slows -> 0.25x speed.
+/// - The resolved product is clamped to [`StatusEffects::SPEED_MULTIPLIER_RANGE`].
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+#[require(SpeedMultiplier)]
+pub struct StatusEffects(pub Vec<StatusEffect>);
+
+impl StatusEffects {
+    /// Bounds for the resolved move-speed multiplier (never frozen, never absurd).
+    pub const SPEED_MULTIPLIER_RANGE: (f32, f32) = (0.1, 3.0);
+
+    pub fn add(&mut self, effect: StatusEffect) {
+        self.0.push(effect);
+    }
+
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.0.iter().any(|e| e.kind == kind)
+    }
+
+    /// Resolve all speed-affecting effects into a single multiplier.
+    pub fn speed_multiplier(&self) -> f32 {
+        let (min, max) = Self::SPEED_MULTIPLIER_RANGE;
+        self.0
+            .iter()
+            .map(|e| e.kind.speed_factor(e.magnitude))
+            .product::<f32>()
+            .clamp(min, max)
+    }
+
+    /// One icon entry per active effect instance, for the HUD.
+    pub fn icons(&self) -> impl Iterator<Item = StatusIcon> + '_ {
+        self.0.iter().map(|e| StatusIcon {
+            kind: e.kind,
+            icon: e.kind.icon_path(),
+            remaining_secs: e.duration.remaining_secs(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_list_has_neutral_speed() {
+        assert_eq!(StatusEffects::default().speed_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn speed_effects_stack_multiplicatively() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::slow(0.5, 5.0));
+        effects.add(StatusEffect::slow(0.5, 5.0));
+        assert!((effects.speed_multiplier() - 0.25).abs() < 1e-6);
+
+        effects.add(StatusEffect::haste(1.0, 5.0));
+        assert!((effects.speed_multiplier() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn speed_multiplier_is_clamped() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..10 {
+            effects.add(StatusEffect::slow(0.9, 5.0));
+        }
+        assert_eq!(effects.speed_multiplier(), StatusEffects::SPEED_MULTIPLIER_RANGE.0);
+    }
+
+    #[test]
+    fn poison_does_not_affect_speed_and_exposes_icon() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::poison(2.0, 1.0, 4.0));
+
+        assert_eq!(effects.speed_multiplier(), 1.0);
+
+        let icons: Vec<_> = effects.icons().collect();
+        assert_eq!(icons.len(), 1);
+        assert_eq!(icons[0].icon, "icons/status/poison.png");
+        assert_eq!(icons[0].remaining_secs, 4.0);
+    }
+}
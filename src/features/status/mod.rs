@@ -0,0 +1,50 @@
+// src/features/status/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::health::damage::{self, DamageEvent, Died};
+use crate::features::movement::systems::{compute_velocity_from_input, reset_speed_multipliers};
+
+pub mod component;
+pub mod systems;
+
+/// Status effects feature plugin (slow, haste, poison).
+///
+/// Scope:
+/// - `ApplyStatusEffect` message -> `StatusEffects` list on the target
+/// - FixedUpdate: durations + periodic ticks (poison -> `DamageEvent`)
+/// - Speed effects multiplied into the movement `SpeedMultiplier` every tick
+/// - HUD reads `StatusEffects::icons()`
+///
+/// Design constraints:
+/// - Never writes `Health` or `MoveConfig` directly; goes through damage messages and
+///   the movement multiplier so other features keep a single source of truth.
+pub struct StatusEffectsPlugin;
+
+impl Plugin for StatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<systems::ApplyStatusEffect>();
+        app.add_message::<DamageEvent>();
+        app.add_message::<Died>();
+
+        // Effects tick before damage is applied so poison lands on the same tick;
+        // death clearing runs after damage so a lethal tick also clears the list.
+        app.add_systems(
+            FixedUpdate,
+            (
+                (systems::apply_status_effects, systems::tick_status_effects)
+                    .chain()
+                    .before(damage::apply_damage),
+                systems::clear_effects_on_death.after(damage::apply_damage),
+            )
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::resolve_speed_modifiers
+                .in_set(AppSet::ApplyForces)
+                .after(reset_speed_multipliers)
+                .before(compute_velocity_from_input),
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// src/features/status/systems.rs
+use bevy::prelude::*;
+
+use super::component::{StatusEffect, StatusEffectKind, StatusEffects};
+use crate::features::health::damage::{DamageEvent, DamageKind, Died};
+use crate::features::movement::component::SpeedMultiplier;
+
+/// Request to put a status effect on an entity.
+///
+/// Sources (hazards, abilities, weapons) send this instead of editing
+/// `StatusEffects` directly, so entities without the component still work.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ApplyStatusEffect {
+    pub target: Entity,
+    pub effect: StatusEffect,
+}
+
+/// FixedUpdate: add requested effects, inserting `StatusEffects` where missing.
+pub fn apply_status_effects(
+    mut commands: Commands,
+    mut requests: MessageReader<ApplyStatusEffect>,
+    mut q_effects: Query<&mut StatusEffects>,
+) {
+    for req in requests.read() {
+        if let Ok(mut effects) = q_effects.get_mut(req.target) {
+            effects.add(req.effect.clone());
+        } else if let Ok(mut entity) = commands.get_entity(req.target) {
+            entity.insert(StatusEffects(vec![req.effect.clone()]));
+        }
+    }
+}
+
+/// FixedUpdate: advance durations, fire periodic ticks, drop expired effects.
+///
+/// - Poison ticks are applied through `DamageEvent` (health owns `Health`).
+/// - An effect that expires on the same tick as its periodic timer still ticks.
+pub fn tick_status_effects(
+    time: Res<Time<Fixed>>,
+    mut q_effects: Query<(Entity, &mut StatusEffects)>,
+    mut damage: MessageWriter<DamageEvent>,
+) {
+    let dt = time.delta();
+
+    for (entity, mut effects) in &mut q_effects {
+        for effect in effects.0.iter_mut() {
+            effect.duration.tick(dt);
+
+            let Some(tick) = effect.tick.as_mut() else {
+                continue;
+            };
+            tick.tick(dt);
+
+            let ticks = tick.times_finished_this_tick();
+            if ticks > 0 && effect.kind == StatusEffectKind::Poison {
+                damage.write(
+                    DamageEvent::new(entity, effect.magnitude * ticks as f32)
+                        .with_kind(DamageKind::Poison),
+                );
+            }
+        }
+
+        effects.0.retain(|e| !e.duration.is_finished());
+    }
+}
+
+/// FixedUpdate: multiply stacked speed effects into this tick's movement
+/// `SpeedMultiplier`.
+pub fn resolve_speed_modifiers(mut q_effects: Query<(&StatusEffects, &mut SpeedMultiplier)>) {
+    for (effects, mut multiplier) in &mut q_effects {
+        multiplier.0 *= effects.speed_multiplier();
+    }
+}
+
+/// FixedUpdate: death clears every effect (no poison ticking into a respawn).
+pub fn clear_effects_on_death(
+    mut died: MessageReader<Died>,
+    mut q_effects: Query<&mut StatusEffects>,
+) {
+    for ev in died.read() {
+        if let Ok(mut effects) = q_effects.get_mut(ev.entity) {
+            effects.0.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::movement::systems::reset_speed_multipliers;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<ApplyStatusEffect>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<Died>>();
+        world
+    }
+
+    fn advance_fixed(world: &mut World, secs: f32) {
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(secs));
+        world.insert_resource(fixed);
+    }
+
+    #[test]
+    fn apply_inserts_component_with_required_multiplier() {
+        let mut world = setup_world();
+        let e = world.spawn_empty().id();
+
+        world.write_message(ApplyStatusEffect {
+            target: e,
+            effect: StatusEffect::slow(0.5, 2.0),
+        });
+        let _ = world.run_system_once(apply_status_effects);
+
+        assert!(world.get::<StatusEffects>(e).unwrap().has(StatusEffectKind::Slow));
+        assert_eq!(*world.get::<SpeedMultiplier>(e).unwrap(), SpeedMultiplier(1.0));
+    }
+
+    #[test]
+    fn apply_appends_to_existing_list() {
+        let mut world = setup_world();
+        let e = world.spawn(StatusEffects(vec![StatusEffect::haste(0.2, 2.0)])).id();
+
+        world.write_message(ApplyStatusEffect {
+            target: e,
+            effect: StatusEffect::slow(0.5, 2.0),
+        });
+        let _ = world.run_system_once(apply_status_effects);
+
+        assert_eq!(world.get::<StatusEffects>(e).unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn poison_ticks_emit_damage_and_effect_expires() {
+        let mut world = setup_world();
+        let e = world
+            .spawn(StatusEffects(vec![StatusEffect::poison(3.0, 1.0, 2.0)]))
+            .id();
+
+        advance_fixed(&mut world, 1.0);
+        let _ = world.run_system_once(tick_status_effects);
+
+        let damage: Vec<_> = world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(damage, vec![DamageEvent::new(e, 3.0).with_kind(DamageKind::Poison)]);
+        assert_eq!(world.get::<StatusEffects>(e).unwrap().0.len(), 1);
+
+        advance_fixed(&mut world, 1.0);
+        let _ = world.run_system_once(tick_status_effects);
+
+        assert_eq!(world.resource::<Messages<DamageEvent>>().len(), 2);
+        assert!(world.get::<StatusEffects>(e).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn speed_multiplier_follows_effects() {
+        let mut world = setup_world();
+        let e = world
+            .spawn(StatusEffects(vec![StatusEffect::slow(0.5, 2.0)]))
+            .id();
+
+        let _ = world.run_system_once(reset_speed_multipliers);
+        let _ = world.run_system_once(resolve_speed_modifiers);
+        assert_eq!(world.get::<SpeedMultiplier>(e).unwrap().0, 0.5);
+
+        world.get_mut::<StatusEffects>(e).unwrap().0.clear();
+        let _ = world.run_system_once(reset_speed_multipliers);
+        let _ = world.run_system_once(resolve_speed_modifiers);
+        assert_eq!(world.get::<SpeedMultiplier>(e).unwrap().0, 1.0);
+    }
+
+    #[test]
+    fn death_clears_effects() {
+        let mut world = setup_world();
+        let e = world
+            .spawn(StatusEffects(vec![StatusEffect::poison(1.0, 1.0, 10.0)]))
+            .id();
+
+        world.write_message(Died { entity: e });
+        let _ = world.run_system_once(clear_effects_on_death);
+
+        assert!(world.get::<StatusEffects>(e).unwrap().0.is_empty());
+    }
+}
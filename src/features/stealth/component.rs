@@ -0,0 +1,158 @@
+// src/features/stealth/component.rs
+use bevy::prelude::*;
+
+use crate::features::movement::component::SpeedMultiplier;
+
+/// Crouched posture (toggled by the crouch key): moves at `speed_scale`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(SpeedMultiplier)]
+pub struct Crouching {
+    pub speed_scale: f32,
+}
+
+/// Stealth tunables: how visible bodies are and how fast enemies notice them.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct StealthSettings {
+    /// Toggles crouching.
+    pub crouch_key: KeyCode,
+    pub crouch_gamepad: GamepadButton,
+    /// Movement speed while crouched (multiplier).
+    pub crouch_speed_scale: f32,
+    /// Visibility multiplier while crouched.
+    pub crouch_visibility: f32,
+    /// Visibility multiplier standing still; moving at `run_speed` or faster is 1.
+    pub still_visibility: f32,
+    pub run_speed: f32,
+    /// Visibility multiplier in complete darkness; full light is 1.
+    pub dark_visibility: f32,
+    /// Height above an enemy's center it looks from.
+    pub eye_height: f32,
+    /// Seconds for a fully visible body at point blank to be detected.
+    pub notice_secs: f32,
+    /// Seconds for full awareness to fade once nothing is seen.
+    pub forget_secs: f32,
+}
+
+impl Default for StealthSettings {
+    fn default() -> Self {
+        Self {
+            crouch_key: KeyCode::ControlLeft,
+            crouch_gamepad: GamepadButton::LeftThumb,
+            crouch_speed_scale: 0.5,
+            crouch_visibility: 0.5,
+            still_visibility: 0.35,
+            run_speed: 5.0,
+            dark_visibility: 0.2,
+            eye_height: 0.4,
+            notice_secs: 1.5,
+            forget_secs: 4.0,
+        }
+    }
+}
+
+impl StealthSettings {
+    /// Visibility (`0.0..=1.0`) of a body: posture x movement x light.
+    pub fn visibility(&self, crouching: bool, speed: f32, light: f32) -> f32 {
+        let posture = if crouching { self.crouch_visibility } else { 1.0 };
+        let moving = if self.run_speed > 0.0 {
+            (speed / self.run_speed).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let motion = self.still_visibility + (1.0 - self.still_visibility) * moving;
+        let lit = self.dark_visibility + (1.0 - self.dark_visibility) * light.clamp(0.0, 1.0);
+        (posture * motion * lit).clamp(0.0, 1.0)
+    }
+}
+
+/// Light level (`0.0..=1.0`) at `at`: daylight, or the brightest lamp reaching it
+/// (full next to the lamp, fading out at its range).
+pub fn light_level(daylight: f32, at: Vec3, lamps: impl IntoIterator<Item = (Vec3, f32)>) -> f32 {
+    lamps
+        .into_iter()
+        .filter(|(_, range)| *range > 0.0)
+        .map(|(position, range)| 1.0 - position.distance(at) / range)
+        .fold(daylight, f32::max)
+        .clamp(0.0, 1.0)
+}
+
+/// How hidden a body is (players get one).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stealth {
+    /// Current visibility, `0.0..=1.0` (see `StealthSettings::visibility`).
+    pub visibility: f32,
+    /// Light level where the body stands.
+    pub light: f32,
+    /// Highest awareness any enemy has of this body, `0.0..=1.0` (the HUD meter).
+    pub detection: f32,
+}
+
+/// Coarse reading of a detection value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionLevel {
+    Hidden,
+    Suspicious,
+    Detected,
+}
+
+impl DetectionLevel {
+    pub fn of(detection: f32) -> Self {
+        if detection >= 1.0 {
+            Self::Detected
+        } else if detection > 0.0 {
+            Self::Suspicious
+        } else {
+            Self::Hidden
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Hidden => "Hidden",
+            Self::Suspicious => "Suspicious",
+            Self::Detected => "Detected",
+        }
+    }
+}
+
+/// What an enemy has noticed (enemies get one).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Perception {
+    /// `0.0..=1.0`; reaching 1 alerts the enemy.
+    pub awareness: f32,
+    /// Body it's most aware of.
+    pub target: Option<Entity>,
+    /// Announced as aggro (`AggroChanged`) until awareness fades out.
+    pub alerted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crouching_still_in_the_dark_is_nearly_invisible() {
+        let settings = StealthSettings::default();
+        let running_in_daylight = settings.visibility(false, 8.0, 1.0);
+        let sneaking_at_night = settings.visibility(true, 0.0, 0.0);
+
+        assert_eq!(running_in_daylight, 1.0);
+        assert!((sneaking_at_night - 0.5 * 0.35 * 0.2).abs() < 1e-6);
+        assert!(settings.visibility(true, 2.5, 1.0) < settings.visibility(false, 2.5, 1.0));
+    }
+
+    #[test]
+    fn lamps_light_up_the_night_near_them() {
+        let lamps = [(Vec3::ZERO, 10.0)];
+        assert_eq!(light_level(0.0, Vec3::X * 5.0, lamps), 0.5);
+        assert_eq!(light_level(0.0, Vec3::X * 20.0, lamps), 0.0);
+        assert_eq!(light_level(0.8, Vec3::X * 5.0, lamps), 0.8, "daylight wins");
+    }
+
+    #[test]
+    fn detection_levels() {
+        assert_eq!(DetectionLevel::of(0.0), DetectionLevel::Hidden);
+        assert_eq!(DetectionLevel::of(0.4), DetectionLevel::Suspicious);
+        assert_eq!(DetectionLevel::of(1.0).label(), "Detected");
+    }
+}
@@ -0,0 +1,60 @@
+// src/features/stealth/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, Tuning, in_input_context};
+use crate::features::enemy::AggroChanged;
+use crate::features::movement::systems::{compute_velocity_from_input, reset_speed_multipliers};
+
+pub mod component;
+pub mod systems;
+
+/// Stealth: how visible players are and how enemies notice them.
+///
+/// Scope:
+/// - crouch key toggles `Crouching` (lower profile; slower movement through the
+///   movement `SpeedMultiplier`)
+/// - `Stealth` on players: visibility from posture, speed and light level (daylight
+///   from `TimeOfDay`, point lights nearby)
+/// - `Perception` on enemies: awareness fills while they see a body they are
+///   hostile to (`Allegiance`; in range, no collider in between), faster the more
+///   visible and closer it is; full awareness alerts them (`AggroChanged`), losing
+///   sight lets it fade
+/// - `Stealth::detection` (highest awareness of the player) drives the HUD meter
+///
+/// Design constraints:
+/// - Perception only announces aggro; what alerted enemies do is up to enemy AI.
+pub struct StealthPlugin;
+
+impl Plugin for StealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::StealthSettings>();
+        app.init_resource::<Tuning>();
+
+        app.add_message::<AggroChanged>();
+
+        app.add_systems(
+            Update,
+            systems::read_crouch_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_stealth,
+                systems::grant_perception,
+                systems::update_visibility,
+                systems::perceive_bodies,
+            )
+                .chain()
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::apply_crouch_speed
+                .in_set(AppSet::ApplyForces)
+                .after(reset_speed_multipliers)
+                .before(compute_velocity_from_input),
+        );
+    }
+}
@@ -0,0 +1,328 @@
+// src/features/stealth/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Crouching, Perception, Stealth, StealthSettings, light_level};
+use crate::app::{Allegiance, Tuning};
+use crate::features::collision::query::CollisionWorld;
+use crate::features::enemy::AggroChanged;
+use crate::features::enemy::component::Enemy;
+use crate::features::movement::component::{SpeedMultiplier, Velocity};
+use crate::features::player::component::Player;
+use crate::features::player::death::{AlivePlayer, Dead};
+use crate::features::time_of_day::TimeOfDay;
+
+/// Update: the crouch key toggles crouching.
+pub fn read_crouch_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<StealthSettings>,
+    q_gamepads: Query<&Gamepad>,
+    q_players: Query<(Entity, Has<Crouching>), AlivePlayer>,
+) {
+    let pressed = keyboard.just_pressed(settings.crouch_key)
+        || q_gamepads.iter().any(|g| g.just_pressed(settings.crouch_gamepad));
+    if !pressed {
+        return;
+    }
+    for (entity, crouching) in &q_players {
+        if crouching {
+            commands.entity(entity).remove::<Crouching>();
+        } else {
+            commands.entity(entity).insert(Crouching {
+                speed_scale: settings.crouch_speed_scale,
+            });
+        }
+    }
+}
+
+/// FixedUpdate: crouched bodies move slower.
+pub fn apply_crouch_speed(mut q_crouched: Query<(&Crouching, &mut SpeedMultiplier)>) {
+    for (crouch, mut multiplier) in &mut q_crouched {
+        multiplier.0 *= crouch.speed_scale;
+    }
+}
+
+/// FixedUpdate: players can hide.
+pub fn grant_stealth(
+    mut commands: Commands,
+    q_players: Query<Entity, (Added<Player>, Without<Stealth>)>,
+) {
+    for entity in &q_players {
+        commands.entity(entity).insert(Stealth::default());
+    }
+}
+
+/// FixedUpdate: enemies can notice them.
+pub fn grant_perception(
+    mut commands: Commands,
+    q_enemies: Query<Entity, (Added<Enemy>, Without<Perception>)>,
+) {
+    for entity in &q_enemies {
+        commands.entity(entity).insert(Perception::default());
+    }
+}
+
+/// FixedUpdate: visibility from posture, speed and light (daylight from
+/// `TimeOfDay`, full without a clock; point lights brighten their surroundings).
+pub fn update_visibility(
+    settings: Res<StealthSettings>,
+    clock: Option<Res<TimeOfDay>>,
+    q_lamps: Query<(&PointLight, &GlobalTransform)>,
+    mut q_bodies: Query<(&mut Stealth, &Transform, Option<&Velocity>, Has<Crouching>)>,
+) {
+    let daylight = clock.map_or(1.0, |clock| clock.daylight());
+    for (mut stealth, transform, velocity, crouching) in &mut q_bodies {
+        let light = light_level(
+            daylight,
+            transform.translation,
+            q_lamps.iter().map(|(lamp, at)| (at.translation(), lamp.range)),
+        );
+        let speed = velocity.map_or(0.0, |v| v.0.with_y(0.0).length());
+        stealth.light = light;
+        stealth.visibility = settings.visibility(crouching, speed, light);
+    }
+}
+
+/// FixedUpdate: enemies grow aware of the most visible body they can see (in
+/// `Tuning::aggro_range`, nothing in between, and of a faction they are hostile
+/// to) and forget once none is.
+///
+/// - Gain scales with the body's visibility and fades with distance; full
+///   awareness alerts the enemy (`AggroChanged` to the body), fading out to zero
+///   calls it off (`AggroChanged` to `None`).
+/// - Each body's `Stealth::detection` is the highest awareness aimed at it.
+#[expect(clippy::too_many_arguments)]
+pub fn perceive_bodies(
+    time: Res<Time<Fixed>>,
+    settings: Res<StealthSettings>,
+    tuning: Res<Tuning>,
+    collision: CollisionWorld,
+    allegiance: Allegiance,
+    mut q_enemies: Query<(Entity, &GlobalTransform, &mut Perception)>,
+    mut q_bodies: Query<(Entity, &Transform, &mut Stealth, Has<Dead>)>,
+    mut aggro: MessageWriter<AggroChanged>,
+) {
+    let dt = time.delta_secs();
+    let bodies: Vec<(Entity, Vec3, f32)> = q_bodies
+        .iter()
+        .filter(|(.., dead)| !dead)
+        .map(|(entity, transform, stealth, _)| (entity, transform.translation, stealth.visibility))
+        .collect();
+
+    for (enemy, global, mut perception) in &mut q_enemies {
+        let eye = global.translation() + Vec3::Y * settings.eye_height;
+        let seen = bodies
+            .iter()
+            .filter(|&&(body, ..)| allegiance.is_hostile(enemy, body))
+            .filter_map(|&(body, position, visibility)| {
+                let to = position - eye;
+                let distance = to.length();
+                if distance > tuning.aggro_range {
+                    return None;
+                }
+                let blocked = collision.ray_cast(eye, to, distance, &[enemy, body]).is_some();
+                let gain = visibility * (1.0 - distance / tuning.aggro_range);
+                (!blocked && gain > 0.0).then_some((body, gain))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match seen {
+            Some((body, gain)) => {
+                perception.target = Some(body);
+                perception.awareness =
+                    (perception.awareness + gain * dt / settings.notice_secs.max(1e-3)).min(1.0);
+            }
+            None => {
+                perception.awareness =
+                    (perception.awareness - dt / settings.forget_secs.max(1e-3)).max(0.0);
+            }
+        }
+
+        if perception.awareness >= 1.0 && !perception.alerted {
+            perception.alerted = true;
+            aggro.write(AggroChanged {
+                enemy,
+                target: perception.target,
+            });
+        } else if perception.awareness <= 0.0 {
+            if perception.alerted {
+                perception.alerted = false;
+                aggro.write(AggroChanged {
+                    enemy,
+                    target: None,
+                });
+            }
+            perception.target = None;
+        }
+    }
+
+    for (body, _, mut stealth, _) in &mut q_bodies {
+        let detection = q_enemies
+            .iter()
+            .filter(|(.., perception)| perception.target == Some(body))
+            .map(|(.., perception)| perception.awareness)
+            .fold(0.0, f32::max);
+        if stealth.detection != detection {
+            stealth.detection = detection;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Faction;
+    use crate::features::collision::component::Collider;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<StealthSettings>();
+        world.init_resource::<Tuning>();
+        world.init_resource::<Messages<AggroChanged>>();
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(0.5));
+        world.insert_resource(fixed);
+        world
+    }
+
+    fn spawn_body(world: &mut World, at: Vec3, speed: f32) -> Entity {
+        world
+            .spawn((
+                Player,
+                Faction::Players,
+                Stealth::default(),
+                Transform::from_translation(at),
+                Velocity(Vec3::X * speed),
+            ))
+            .id()
+    }
+
+    fn spawn_enemy(world: &mut World) -> Entity {
+        world
+            .spawn((
+                Enemy,
+                Faction::Monsters,
+                Perception::default(),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    fn tick(world: &mut World) {
+        let _ = world.run_system_once(update_visibility);
+        let _ = world.run_system_once(perceive_bodies);
+    }
+
+    fn aggro(world: &World) -> Vec<AggroChanged> {
+        world
+            .resource::<Messages<AggroChanged>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn crouching_at_night_away_from_lamps_hides() {
+        let mut world = setup_world();
+        world.insert_resource(TimeOfDay {
+            hour: 0.0,
+            ..default()
+        });
+        world.spawn((
+            PointLight {
+                range: 4.0,
+                ..default()
+            },
+            GlobalTransform::from_translation(Vec3::new(0.0, 0.0, 10.0)),
+        ));
+        let lit = spawn_body(&mut world, Vec3::new(0.0, 0.0, 9.0), 5.0);
+        let hidden = spawn_body(&mut world, Vec3::ZERO, 0.0);
+        world.entity_mut(hidden).insert(Crouching { speed_scale: 0.5 });
+
+        let _ = world.run_system_once(update_visibility);
+
+        let stealth = |e| *world.get::<Stealth>(e).unwrap();
+        assert_eq!(stealth(lit).light, 0.75);
+        assert_eq!(stealth(hidden).light, 0.0);
+        assert!(stealth(hidden).visibility < 0.05);
+        assert!(stealth(lit).visibility > 0.75);
+    }
+
+    #[test]
+    fn seen_bodies_fill_awareness_until_the_enemy_is_alerted() {
+        let mut world = setup_world();
+        let enemy = spawn_enemy(&mut world);
+        // Half sight range away at eye height, running in daylight: gain 0.5 / notice_secs
+        // per sec.
+        let body = spawn_body(&mut world, Vec3::new(7.0, 0.4, 0.0), 5.0);
+
+        tick(&mut world);
+        let detection = world.get::<Stealth>(body).unwrap().detection;
+        assert!((detection - 0.5 * 0.5 / 1.5).abs() < 1e-5);
+        assert!(aggro(&world).is_empty());
+
+        for _ in 0..6 {
+            tick(&mut world);
+        }
+        assert_eq!(world.get::<Stealth>(body).unwrap().detection, 1.0);
+        assert_eq!(
+            aggro(&world),
+            [AggroChanged {
+                enemy,
+                target: Some(body)
+            }]
+        );
+
+        // Out of sight: awareness fades and the aggro is called off.
+        world.get_mut::<Transform>(body).unwrap().translation.x = 50.0;
+        for _ in 0..10 {
+            tick(&mut world);
+        }
+        assert_eq!(world.get::<Stealth>(body).unwrap().detection, 0.0);
+        assert_eq!(aggro(&world).last().unwrap().target, None);
+    }
+
+    #[test]
+    fn walls_block_sight() {
+        let mut world = setup_world();
+        let enemy = spawn_enemy(&mut world);
+        let body = spawn_body(&mut world, Vec3::X * 4.0, 5.0);
+        world.spawn((
+            Collider::cuboid(Vec3::new(0.2, 3.0, 3.0)),
+            GlobalTransform::from_translation(Vec3::X * 2.0),
+        ));
+
+        tick(&mut world);
+
+        assert_eq!(world.get::<Perception>(enemy).unwrap().awareness, 0.0);
+        assert_eq!(world.get::<Stealth>(body).unwrap().detection, 0.0);
+    }
+
+    #[test]
+    fn enemies_ignore_bodies_they_are_not_hostile_to() {
+        let mut world = setup_world();
+        let enemy = spawn_enemy(&mut world);
+        let body = spawn_body(&mut world, Vec3::X * 4.0, 5.0);
+        world.entity_mut(body).insert(Faction::Wildlife);
+
+        tick(&mut world);
+
+        assert_eq!(world.get::<Perception>(enemy).unwrap().awareness, 0.0);
+        assert_eq!(world.get::<Stealth>(body).unwrap().detection, 0.0);
+    }
+
+    #[test]
+    fn crouching_slows_movement() {
+        let mut world = World::new();
+        let body = world
+            .spawn((Crouching { speed_scale: 0.4 }, SpeedMultiplier(0.5)))
+            .id();
+
+        let _ = world.run_system_once(apply_crouch_speed);
+
+        assert_eq!(world.get::<SpeedMultiplier>(body).unwrap().0, 0.2);
+    }
+}
@@ -0,0 +1,134 @@
+// src/features/surface/component.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What a collider is made of (footstep sounds, dust, impact sparks).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum SurfaceMaterial {
+    Grass,
+    #[default]
+    Stone,
+    Metal,
+    Wood,
+}
+
+impl SurfaceMaterial {
+    /// How hard projectiles find it to punch through, per world unit of thickness.
+    pub fn penetration_resistance(self) -> f32 {
+        match self {
+            Self::Grass => 0.25,
+            Self::Wood => 1.0,
+            Self::Stone => 4.0,
+            Self::Metal => 8.0,
+        }
+    }
+}
+
+/// Per-surface sound and particle variants.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SurfaceEffectDef {
+    /// Footstep sounds (asset paths), cycled step by step.
+    #[serde(default)]
+    pub footstep_sounds: Vec<String>,
+    #[serde(default)]
+    pub landing_sounds: Vec<String>,
+    #[serde(default)]
+    pub impact_sounds: Vec<String>,
+    /// Particle color (sRGB 0..1).
+    pub particle_color: (f32, f32, f32),
+    /// Particles per footstep; landings and impacts scale this up.
+    pub particle_count: u32,
+}
+
+/// Surface effects, loaded from `assets/data/surfaces.ron`.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SurfaceEffects(pub BTreeMap<SurfaceMaterial, SurfaceEffectDef>);
+
+const BUILTIN_SURFACES: &str = include_str!("../../../assets/data/surfaces.ron");
+
+impl SurfaceEffects {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+
+    /// Effects for `surface`, falling back to the default surface's.
+    pub fn get(&self, surface: SurfaceMaterial) -> Option<&SurfaceEffectDef> {
+        self.0
+            .get(&surface)
+            .or_else(|| self.0.get(&SurfaceMaterial::default()))
+    }
+}
+
+impl Default for SurfaceEffects {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_SURFACES).expect("assets/data/surfaces.ron must parse")
+    }
+}
+
+/// Emits a footstep every `stride` world units walked on the ground.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct FootstepTracker {
+    pub stride: f32,
+    pub travelled: f32,
+    pub last_position: Option<Vec3>,
+    /// Steps taken (picks the sound variant).
+    pub steps: u32,
+}
+
+impl Default for FootstepTracker {
+    fn default() -> Self {
+        Self {
+            stride: 0.8,
+            travelled: 0.0,
+            last_position: None,
+            steps: 0,
+        }
+    }
+}
+
+/// Remembers how fast an `ExternalForce`-driven body was falling, to spot landings.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct LandingTracker {
+    pub fall_speed: f32,
+}
+
+/// Falls slower than this (units/sec) land silently.
+pub const MIN_LANDING_SPEED: f32 = 2.0;
+
+/// How far below a body's bottom the ground may be and still count as standing on it.
+pub const GROUND_PROBE_MARGIN: f32 = 0.15;
+
+/// Short-lived dust / spark particle.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceParticle {
+    pub velocity: Vec3,
+    pub remaining_secs: f32,
+    pub lifetime_secs: f32,
+}
+
+/// Shared particle mesh and one material per surface.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SurfaceEffectAssets {
+    pub mesh: Handle<Mesh>,
+    pub materials: BTreeMap<SurfaceMaterial, Handle<StandardMaterial>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_effects_cover_every_surface() {
+        let effects = SurfaceEffects::default();
+        for surface in [
+            SurfaceMaterial::Grass,
+            SurfaceMaterial::Stone,
+            SurfaceMaterial::Metal,
+            SurfaceMaterial::Wood,
+        ] {
+            assert!(effects.0.contains_key(&surface), "{surface:?}");
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// src/features/surface/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::culling::effects::{EffectBudget, EffectStats};
+
+pub mod component;
+pub mod systems;
+
+/// Surface materials and contact effects.
+///
+/// Scope:
+/// - `SurfaceMaterial` on colliders (ground, props); colliders without one count as stone
+/// - contacts resolved to the material below / hit: footsteps every stride walked,
+///   landings of falling props, and `SurfaceHit` impacts (for projectiles / hitscan)
+/// - `SurfaceContact` carries the material; effects pick sound / particle variants
+///   from `assets/data/surfaces.ron`
+///
+/// Design constraints:
+/// - contacts are detected on the fixed timestep; particles and sounds are Update-only.
+/// - particles are cosmetic: budgeted by camera frustum and distance (`EffectSpawns`).
+pub struct SurfacePlugin;
+
+impl Plugin for SurfacePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::SurfaceEffects>();
+        app.init_resource::<EffectBudget>();
+        app.init_resource::<EffectStats>();
+
+        app.add_message::<systems::SurfaceHit>();
+        app.add_message::<systems::SurfaceContact>();
+
+        app.add_systems(Startup, systems::setup_surface_effect_assets);
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_surface_trackers,
+                systems::emit_footsteps,
+                systems::detect_landings,
+                systems::resolve_surface_hits,
+            )
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            Update,
+            (systems::spawn_surface_effects, systems::update_surface_particles)
+                .after(AppSet::Input),
+        );
+    }
+}
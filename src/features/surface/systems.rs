@@ -0,0 +1,371 @@
+// src/features/surface/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    FootstepTracker, GROUND_PROBE_MARGIN, LandingTracker, MIN_LANDING_SPEED, SurfaceEffectAssets,
+    SurfaceEffects, SurfaceMaterial, SurfaceParticle,
+};
+use crate::features::audio::component::{AudioBus, DucksMusic};
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::culling::effects::{CosmeticEffect, EffectSpawns};
+use crate::features::movement::component::ExternalForce;
+use crate::features::player::component::Player;
+use crate::features::player::death::AlivePlayer;
+use crate::features::props::component::Prop;
+
+/// Half height assumed for movers without a `Collider` (the player box).
+const DEFAULT_HALF_HEIGHT: f32 = 0.5;
+
+/// Something (bullet, projectile, thrown object) struck `target` at `position`.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceHit {
+    pub source: Entity,
+    pub target: Entity,
+    pub position: Vec3,
+    pub normal: Vec3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContactKind {
+    Footstep,
+    Landing { speed: f32 },
+    Impact,
+}
+
+/// A contact with a known surface; effects pick their variant from it.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceContact {
+    pub source: Entity,
+    pub kind: ContactKind,
+    pub surface: SurfaceMaterial,
+    pub position: Vec3,
+    pub normal: Vec3,
+    /// Running counter for cycling sound variants.
+    pub variant: u32,
+}
+
+/// Ground point and material under a body of `half_height` centered at `position`.
+fn ground_below(
+    collision: &CollisionWorld,
+    q_surfaces: &Query<&SurfaceMaterial>,
+    entity: Entity,
+    position: Vec3,
+    half_height: f32,
+) -> Option<(Vec3, Vec3, SurfaceMaterial)> {
+    let hit = collision.ray_cast(
+        position,
+        Vec3::NEG_Y,
+        half_height + GROUND_PROBE_MARGIN,
+        &[entity],
+    )?;
+    let surface = q_surfaces.get(hit.entity).copied().unwrap_or_default();
+    Some((position + Vec3::NEG_Y * hit.distance, hit.normal, surface))
+}
+
+/// FixedUpdate: players leave footsteps; props report landings.
+pub fn grant_surface_trackers(
+    mut commands: Commands,
+    q_players: Query<Entity, (Added<Player>, Without<FootstepTracker>)>,
+    q_props: Query<Entity, (Added<Prop>, Without<LandingTracker>)>,
+) {
+    for entity in &q_players {
+        commands.entity(entity).insert(FootstepTracker::default());
+    }
+    for entity in &q_props {
+        commands.entity(entity).insert(LandingTracker::default());
+    }
+}
+
+/// FixedUpdate: every `stride` walked on solid ground -> `SurfaceContact::Footstep`.
+///
+/// Only horizontal distance counts; moving without ground below (flying, swimming)
+/// resets the stride.
+pub fn emit_footsteps(
+    collision: CollisionWorld,
+    q_surfaces: Query<&SurfaceMaterial>,
+    mut q_walkers: Query<(Entity, &Transform, Option<&Collider>, &mut FootstepTracker), AlivePlayer>,
+    mut contacts: MessageWriter<SurfaceContact>,
+) {
+    for (entity, transform, collider, mut tracker) in &mut q_walkers {
+        let position = transform.translation;
+        let last = tracker.last_position.replace(position);
+        let Some(last) = last else {
+            continue;
+        };
+
+        let half_height = collider.map_or(DEFAULT_HALF_HEIGHT, |c| c.half_extents.y);
+        let Some((point, normal, surface)) =
+            ground_below(&collision, &q_surfaces, entity, position, half_height)
+        else {
+            tracker.travelled = 0.0;
+            continue;
+        };
+
+        tracker.travelled += (position - last).with_y(0.0).length();
+        if tracker.travelled < tracker.stride {
+            continue;
+        }
+        tracker.travelled -= tracker.stride;
+        tracker.steps = tracker.steps.wrapping_add(1);
+        contacts.write(SurfaceContact {
+            source: entity,
+            kind: ContactKind::Footstep,
+            surface,
+            position: point,
+            normal,
+            variant: tracker.steps,
+        });
+    }
+}
+
+/// FixedUpdate: a fall that stops on solid ground -> `SurfaceContact::Landing`.
+pub fn detect_landings(
+    collision: CollisionWorld,
+    q_surfaces: Query<&SurfaceMaterial>,
+    mut q_bodies: Query<(Entity, &Transform, &Collider, &ExternalForce, &mut LandingTracker)>,
+    mut contacts: MessageWriter<SurfaceContact>,
+) {
+    for (entity, transform, collider, external, mut tracker) in &mut q_bodies {
+        let falling = (-external.velocity.y).max(0.0);
+        let was_falling = std::mem::replace(&mut tracker.fall_speed, falling);
+        if falling > 0.0 || was_falling < MIN_LANDING_SPEED {
+            continue;
+        }
+        let half_height = collider.half_extents.y;
+        if let Some((point, normal, surface)) =
+            ground_below(&collision, &q_surfaces, entity, transform.translation, half_height)
+        {
+            contacts.write(SurfaceContact {
+                source: entity,
+                kind: ContactKind::Landing { speed: was_falling },
+                surface,
+                position: point,
+                normal,
+                variant: entity.index_u32(),
+            });
+        }
+    }
+}
+
+/// FixedUpdate: `SurfaceHit` -> `SurfaceContact::Impact` with the target's material.
+pub fn resolve_surface_hits(
+    mut hits: MessageReader<SurfaceHit>,
+    q_surfaces: Query<&SurfaceMaterial>,
+    mut contacts: MessageWriter<SurfaceContact>,
+    mut count: Local<u32>,
+) {
+    for hit in hits.read() {
+        *count = count.wrapping_add(1);
+        contacts.write(SurfaceContact {
+            source: hit.source,
+            kind: ContactKind::Impact,
+            surface: q_surfaces.get(hit.target).copied().unwrap_or_default(),
+            position: hit.position,
+            normal: hit.normal,
+            variant: *count,
+        });
+    }
+}
+
+/// Startup: particle mesh and per-surface materials.
+pub fn setup_surface_effect_assets(
+    mut commands: Commands,
+    effects: Res<SurfaceEffects>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let materials = effects
+        .0
+        .iter()
+        .map(|(&surface, def)| {
+            let (r, g, b) = def.particle_color;
+            (surface, materials.add(Color::srgb(r, g, b)))
+        })
+        .collect();
+    commands.insert_resource(SurfaceEffectAssets {
+        mesh: meshes.add(Cuboid::from_size(Vec3::splat(0.06))),
+        materials,
+    });
+}
+
+/// Seconds surface particles live.
+const PARTICLE_LIFETIME_SECS: f32 = 0.5;
+
+/// Update: dust / sparks and the surface's sound variant for every contact.
+///
+/// Particles follow the `EffectSpawns` budget (fewer far away, none off-screen);
+/// sounds always play.
+pub fn spawn_surface_effects(
+    mut commands: Commands,
+    effects: Res<SurfaceEffects>,
+    assets: Option<Res<SurfaceEffectAssets>>,
+    asset_server: Option<Res<AssetServer>>,
+    mut contacts: MessageReader<SurfaceContact>,
+    mut spawns: EffectSpawns,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    for contact in contacts.read() {
+        let Some(def) = effects.get(contact.surface) else {
+            continue;
+        };
+        let (sounds, scale) = match contact.kind {
+            ContactKind::Footstep => (&def.footstep_sounds, 1),
+            ContactKind::Landing { .. } => (&def.landing_sounds, 3),
+            ContactKind::Impact => (&def.impact_sounds, 2),
+        };
+
+        if let (Some(server), false) = (&asset_server, sounds.is_empty()) {
+            let path = &sounds[contact.variant as usize % sounds.len()];
+            let mut sound = commands.spawn((
+                AudioBus::Sfx,
+                AudioPlayer::new(server.load(path.clone())),
+                PlaybackSettings::DESPAWN,
+                Transform::from_translation(contact.position),
+            ));
+            if matches!(contact.kind, ContactKind::Landing { .. }) {
+                sound.insert(DucksMusic);
+            }
+        }
+
+        let Some(material) = assets.materials.get(&contact.surface) else {
+            continue;
+        };
+        let detail = spawns.detail(contact.position, 0.5);
+        let count = spawns.count(detail, def.particle_count * scale);
+        let normal = contact.normal.try_normalize().unwrap_or(Vec3::Y);
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        for i in 0..count {
+            let angle = (i as f32 + contact.variant as f32 * 0.37) * std::f32::consts::TAU
+                / count.max(1) as f32;
+            let spread = tangent * angle.cos() + bitangent * angle.sin();
+            commands.spawn((
+                CosmeticEffect,
+                SurfaceParticle {
+                    velocity: (normal * 1.5 + spread) * 1.2,
+                    remaining_secs: PARTICLE_LIFETIME_SECS,
+                    lifetime_secs: PARTICLE_LIFETIME_SECS,
+                },
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(contact.position),
+            ));
+        }
+    }
+}
+
+/// Update: particles fly, fall, shrink and despawn.
+pub fn update_surface_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_particles: Query<(Entity, &mut SurfaceParticle, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut q_particles {
+        particle.remaining_secs -= dt;
+        if particle.remaining_secs <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        particle.velocity.y -= 9.81 * dt;
+        transform.translation += particle.velocity * dt;
+        transform.scale = Vec3::splat(particle.remaining_secs / particle.lifetime_secs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<SurfaceContact>>();
+        world.init_resource::<Messages<SurfaceHit>>();
+        world.spawn((
+            SurfaceMaterial::Grass,
+            Collider::cuboid(Vec3::new(10.0, 0.05, 10.0)),
+            GlobalTransform::default(),
+        ));
+        world
+    }
+
+    fn contacts(world: &World) -> Vec<SurfaceContact> {
+        world
+            .resource::<Messages<SurfaceContact>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn walking_a_stride_on_grass_steps_on_grass() {
+        let mut world = setup_world();
+        let player = world
+            .spawn((Player, FootstepTracker::default(), Transform::from_xyz(0.0, 0.5, 0.0)))
+            .id();
+
+        let _ = world.run_system_once(emit_footsteps);
+        world.get_mut::<Transform>(player).unwrap().translation.x = 0.5;
+        let _ = world.run_system_once(emit_footsteps);
+        assert!(contacts(&world).is_empty());
+
+        world.get_mut::<Transform>(player).unwrap().translation.x = 1.0;
+        let _ = world.run_system_once(emit_footsteps);
+
+        let steps = contacts(&world);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].kind, ContactKind::Footstep);
+        assert_eq!(steps[0].surface, SurfaceMaterial::Grass);
+        assert!((steps[0].position.y - 0.05).abs() < 1e-5);
+    }
+
+    #[test]
+    fn hard_fall_onto_ground_is_a_landing() {
+        let mut world = setup_world();
+        let prop = world
+            .spawn((
+                Collider::cuboid(Vec3::splat(0.5)),
+                Transform::from_xyz(0.0, 0.55, 0.0),
+                ExternalForce {
+                    velocity: Vec3::NEG_Y * 6.0,
+                    ..default()
+                },
+                LandingTracker::default(),
+            ))
+            .id();
+
+        let _ = world.run_system_once(detect_landings);
+        assert!(contacts(&world).is_empty());
+
+        world.get_mut::<ExternalForce>(prop).unwrap().velocity = Vec3::ZERO;
+        let _ = world.run_system_once(detect_landings);
+
+        let landed = contacts(&world);
+        assert_eq!(landed.len(), 1);
+        assert_eq!(landed[0].kind, ContactKind::Landing { speed: 6.0 });
+    }
+
+    #[test]
+    fn hits_take_the_target_material_or_the_default() {
+        let mut world = setup_world();
+        let metal = world.spawn(SurfaceMaterial::Metal).id();
+        let bare = world.spawn_empty().id();
+        for target in [metal, bare] {
+            world.write_message(SurfaceHit {
+                source: Entity::PLACEHOLDER,
+                target,
+                position: Vec3::ZERO,
+                normal: Vec3::Z,
+            });
+        }
+
+        let _ = world.run_system_once(resolve_surface_hits);
+
+        let surfaces: Vec<_> = contacts(&world).iter().map(|c| c.surface).collect();
+        assert_eq!(surfaces, vec![SurfaceMaterial::Metal, SurfaceMaterial::Stone]);
+    }
+}
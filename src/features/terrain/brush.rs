@@ -0,0 +1,73 @@
+// src/features/terrain/brush.rs
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::component::{BrushTool, DirtyTerrainChunks, TerrainBrush, TerrainData, TerrainFile};
+use crate::features::camera::component::OffscreenCamera;
+
+/// Farthest the brush reaches from the camera (world units).
+const BRUSH_REACH: f32 = 200.0;
+
+/// Update (editor): tool cycling, radius, and Ctrl+S saving.
+pub fn edit_terrain_brush(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    terrain: Res<TerrainData>,
+    file: Res<TerrainFile>,
+    mut brush: ResMut<TerrainBrush>,
+) {
+    if keyboard.just_pressed(brush.next_tool) {
+        let i = BrushTool::ALL.iter().position(|t| *t == brush.tool).unwrap_or(0);
+        brush.tool = BrushTool::ALL[(i + 1) % BrushTool::ALL.len()];
+        info!("terrain brush: {:?}", brush.tool);
+    }
+    if keyboard.just_pressed(brush.smaller) {
+        brush.radius = (brush.radius - 0.5).max(0.5);
+    }
+    if keyboard.just_pressed(brush.larger) {
+        brush.radius = (brush.radius + 0.5).min(20.0);
+    }
+    if keyboard.pressed(KeyCode::ControlLeft) && keyboard.just_pressed(brush.save) {
+        match terrain.save(&file.0) {
+            Ok(()) => info!("saved terrain to {}", file.0.display()),
+            Err(err) => warn!("could not save {}: {err}", file.0.display()),
+        }
+    }
+}
+
+/// Update (editor): while the apply button is held, brush the terrain under the
+/// cursor and mark the touched chunks for rebuilding.
+#[expect(clippy::type_complexity)]
+pub fn apply_terrain_brush(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut brush: ResMut<TerrainBrush>,
+    mut terrain: ResMut<TerrainData>,
+    mut dirty: ResMut<DirtyTerrainChunks>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+) {
+    if !mouse.pressed(brush.apply) {
+        brush.stroke_height = None;
+        return;
+    }
+    let Some(cursor) = q_window.iter().next().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Some((camera, transform)) = q_camera.iter().next() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(transform, cursor) else {
+        return;
+    };
+    let Some(center) = terrain.raycast(ray, BRUSH_REACH) else {
+        return;
+    };
+    if brush.stroke_height.is_none() {
+        brush.stroke_height = Some(center.y);
+    }
+    if let Some((min, max)) = brush.apply_to(&mut terrain, center, time.delta_secs()) {
+        for chunk in terrain.chunks_touching(min, max) {
+            dirty.mark(chunk);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+// src/features/terrain/component.rs
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use bevy::tasks::Task;
+use serde::{Deserialize, Serialize};
+
+/// Cells per side of one terrain mesh chunk.
+pub const TERRAIN_CHUNK_CELLS: u32 = 16;
+
+/// Colors of the paint layers (index = `TerrainData::paint` value).
+pub const TERRAIN_LAYERS: [Color; 4] = [
+    Color::srgb(0.32, 0.5, 0.24),
+    Color::srgb(0.45, 0.36, 0.24),
+    Color::srgb(0.55, 0.55, 0.55),
+    Color::srgb(0.85, 0.8, 0.6),
+];
+
+/// Heightmap terrain patch, loaded from (and saved to) the terrain data file.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TerrainData {
+    pub origin: (f32, f32),
+    pub size: u32,
+    pub spacing: f32,
+    #[serde(default)]
+    pub heights: Vec<f32>,
+    #[serde(default)]
+    pub paint: Vec<u8>,
+    /// The file had no heights: shape and paint are generated at startup (biomes).
+    #[serde(skip)]
+    pub needs_generation: bool,
+}
+
+const BUILTIN_TERRAIN: &str = include_str!("../../../assets/data/terrain.ron");
+
+impl Default for TerrainData {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_TERRAIN).expect("assets/data/terrain.ron must parse")
+    }
+}
+
+impl TerrainData {
+    /// Parse, filling empty (or mis-sized) height / paint arrays with flat ground.
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        let mut data: Self = ron::from_str(source)?;
+        let count = (data.size * data.size) as usize;
+        if data.heights.len() != count {
+            data.heights = vec![0.0; count];
+            data.needs_generation = true;
+        }
+        if data.paint.len() != count {
+            data.paint = vec![0; count];
+        }
+        Ok(data)
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default().compact_arrays(true))
+            .map_err(std::io::Error::other)?;
+        std::fs::write(path, text)
+    }
+
+    /// World XZ of the vertex `(x, z)`.
+    pub fn vertex_xz(&self, x: u32, z: u32) -> Vec2 {
+        Vec2::new(self.origin.0, self.origin.1) + Vec2::new(x as f32, z as f32) * self.spacing
+    }
+
+    /// World XZ of the center of `chunk`.
+    pub fn chunk_center(&self, chunk: UVec2) -> Vec2 {
+        let cells = (chunk * TERRAIN_CHUNK_CELLS).as_vec2() + Vec2::splat(TERRAIN_CHUNK_CELLS as f32 * 0.5);
+        Vec2::new(self.origin.0, self.origin.1) + cells * self.spacing
+    }
+
+    /// Chunks per side.
+    pub fn chunks(&self) -> u32 {
+        (self.size - 1).div_ceil(TERRAIN_CHUNK_CELLS)
+    }
+
+    pub fn index(&self, x: u32, z: u32) -> usize {
+        (z * self.size + x) as usize
+    }
+
+    pub fn height(&self, x: u32, z: u32) -> f32 {
+        self.heights[self.index(x, z)]
+    }
+
+    pub fn vertex_position(&self, x: u32, z: u32) -> Vec3 {
+        Vec3::new(
+            self.origin.0 + x as f32 * self.spacing,
+            self.height(x, z),
+            self.origin.1 + z as f32 * self.spacing,
+        )
+    }
+
+    /// Bilinear height at a world XZ position (`None` off the patch).
+    pub fn height_at(&self, xz: Vec2) -> Option<f32> {
+        let local = (xz - Vec2::new(self.origin.0, self.origin.1)) / self.spacing;
+        let max = (self.size - 1) as f32;
+        if local.x < 0.0 || local.y < 0.0 || local.x > max || local.y > max {
+            return None;
+        }
+        let (x0, z0) = (local.x.floor().min(max - 1.0) as u32, local.y.floor().min(max - 1.0) as u32);
+        let (fx, fz) = (local.x - x0 as f32, local.y - z0 as f32);
+        let top = self.height(x0, z0).lerp(self.height(x0 + 1, z0), fx);
+        let bottom = self.height(x0, z0 + 1).lerp(self.height(x0 + 1, z0 + 1), fx);
+        Some(top.lerp(bottom, fz))
+    }
+
+    /// Surface normal at a world XZ position (`None` off the patch): central
+    /// differences half a cell apart, one-sided at the border.
+    pub fn normal_at(&self, xz: Vec2) -> Option<Vec3> {
+        let center = self.height_at(xz)?;
+        let e = self.spacing * 0.5;
+        let gradient = |axis: Vec2| {
+            let ahead = self.height_at(xz + axis * e).map(|h| (h - center) / e);
+            let behind = self.height_at(xz - axis * e).map(|h| (center - h) / e);
+            match (ahead, behind) {
+                (Some(a), Some(b)) => (a + b) * 0.5,
+                (Some(slope), None) | (None, Some(slope)) => slope,
+                (None, None) => 0.0,
+            }
+        };
+        Some(Vec3::new(-gradient(Vec2::X), 1.0, -gradient(Vec2::Y)).normalize())
+    }
+
+    /// First point where `ray` hits the surface (ray-marched), within `max_distance`.
+    pub fn raycast(&self, ray: Ray3d, max_distance: f32) -> Option<Vec3> {
+        let step = self.spacing * 0.25;
+        let mut t = 0.0;
+        while t <= max_distance {
+            let p = ray.get_point(t);
+            if let Some(h) = self.height_at(Vec2::new(p.x, p.z))
+                && p.y <= h
+            {
+                return Some(Vec3::new(p.x, h, p.z));
+            }
+            t += step;
+        }
+        None
+    }
+
+    /// Chunks containing any vertex in the inclusive vertex range (shared border
+    /// vertices dirty both neighbours).
+    pub fn chunks_touching(&self, min: UVec2, max: UVec2) -> impl Iterator<Item = UVec2> + use<> {
+        let last = self.chunks() - 1;
+        let lo = |v: u32| (v.saturating_sub(1) / TERRAIN_CHUNK_CELLS).min(last);
+        let hi = |v: u32| (v / TERRAIN_CHUNK_CELLS).min(last);
+        let (x0, x1, z0, z1) = (lo(min.x), hi(max.x), lo(min.y), hi(max.y));
+        (z0..=z1).flat_map(move |z| (x0..=x1).map(move |x| UVec2::new(x, z)))
+    }
+}
+
+/// Where `TerrainData` is saved by the editor.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct TerrainFile(pub PathBuf);
+
+impl Default for TerrainFile {
+    fn default() -> Self {
+        Self(PathBuf::from("assets/data/terrain.ron"))
+    }
+}
+
+/// One mesh chunk of the terrain.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerrainChunk {
+    pub coord: UVec2,
+}
+
+/// A chunk's mesh being built on the async compute pool; replacing it cancels the
+/// older build.
+#[derive(Component, Debug)]
+pub struct ChunkMeshTask(pub Task<Mesh>);
+
+/// Chunks whose mesh must be rebuilt.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirtyTerrainChunks(pub Vec<UVec2>);
+
+impl DirtyTerrainChunks {
+    pub fn mark(&mut self, chunk: UVec2) {
+        if !self.0.contains(&chunk) {
+            self.0.push(chunk);
+        }
+    }
+}
+
+/// What the terrain brush does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrushTool {
+    Raise,
+    Lower,
+    /// Level towards the height under the brush when the stroke started.
+    Flatten,
+    /// Paint a `TERRAIN_LAYERS` index.
+    Paint(u8),
+}
+
+impl BrushTool {
+    /// Tools in cycling order.
+    pub const ALL: [Self; 6] = [
+        Self::Raise,
+        Self::Lower,
+        Self::Flatten,
+        Self::Paint(1),
+        Self::Paint(2),
+        Self::Paint(0),
+    ];
+}
+
+/// Editor brush state and bindings.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TerrainBrush {
+    pub tool: BrushTool,
+    /// World units.
+    pub radius: f32,
+    /// Height change per second at the center (raise / lower), or blend rate (flatten).
+    pub strength: f32,
+    /// Flatten target of the current stroke.
+    pub stroke_height: Option<f32>,
+    pub apply: MouseButton,
+    pub next_tool: KeyCode,
+    pub smaller: KeyCode,
+    pub larger: KeyCode,
+    /// With `ControlLeft`: save the terrain file.
+    pub save: KeyCode,
+}
+
+impl Default for TerrainBrush {
+    fn default() -> Self {
+        Self {
+            tool: BrushTool::Raise,
+            radius: 3.0,
+            strength: 2.0,
+            stroke_height: None,
+            apply: MouseButton::Left,
+            next_tool: KeyCode::Period,
+            smaller: KeyCode::BracketLeft,
+            larger: KeyCode::BracketRight,
+            save: KeyCode::KeyS,
+        }
+    }
+}
+
+impl TerrainBrush {
+    /// Apply one brush step of `dt` seconds at `center`; returns the inclusive
+    /// vertex range touched (`None` if the brush missed the patch).
+    pub fn apply_to(&self, terrain: &mut TerrainData, center: Vec3, dt: f32) -> Option<(UVec2, UVec2)> {
+        let origin = Vec2::new(terrain.origin.0, terrain.origin.1);
+        let local = (Vec2::new(center.x, center.z) - origin) / terrain.spacing;
+        let r = self.radius / terrain.spacing;
+        let max = (terrain.size - 1) as f32;
+        let (x0, x1) = ((local.x - r).ceil().max(0.0), (local.x + r).floor().min(max));
+        let (z0, z1) = ((local.y - r).ceil().max(0.0), (local.y + r).floor().min(max));
+        if x0 > x1 || z0 > z1 {
+            return None;
+        }
+        for z in z0 as u32..=z1 as u32 {
+            for x in x0 as u32..=x1 as u32 {
+                let d = Vec2::new(x as f32, z as f32).distance(local) / r;
+                if d > 1.0 {
+                    continue;
+                }
+                let falloff = 1.0 - d * d;
+                let i = terrain.index(x, z);
+                match self.tool {
+                    BrushTool::Raise => terrain.heights[i] += self.strength * falloff * dt,
+                    BrushTool::Lower => terrain.heights[i] -= self.strength * falloff * dt,
+                    BrushTool::Flatten => {
+                        let target = self.stroke_height.unwrap_or(center.y);
+                        let blend = (self.strength * falloff * dt).min(1.0);
+                        terrain.heights[i] = terrain.heights[i].lerp(target, blend);
+                    }
+                    BrushTool::Paint(layer) => {
+                        if falloff >= 0.25 {
+                            terrain.paint[i] = layer;
+                        }
+                    }
+                }
+            }
+        }
+        Some((UVec2::new(x0 as u32, z0 as u32), UVec2::new(x1 as u32, z1 as u32)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flat(size: u32) -> TerrainData {
+        TerrainData::from_ron(&format!("(origin: (0.0, 0.0), size: {size}, spacing: 1.0)")).unwrap()
+    }
+
+    #[test]
+    fn builtin_terrain_parses_flat() {
+        let terrain = TerrainData::default();
+        assert_eq!(terrain.heights.len(), (terrain.size * terrain.size) as usize);
+        assert_eq!(terrain.chunks(), 2);
+    }
+
+    #[test]
+    fn raise_brush_lifts_the_center_most() {
+        let mut terrain = flat(33);
+        let brush = TerrainBrush::default();
+        let (min, max) = brush.apply_to(&mut terrain, Vec3::new(16.0, 0.0, 16.0), 1.0).unwrap();
+        assert_eq!((min, max), (UVec2::splat(13), UVec2::splat(19)));
+        assert_eq!(terrain.height(16, 16), 2.0);
+        assert!(terrain.height(17, 16) > 0.0 && terrain.height(17, 16) < 2.0);
+        assert_eq!(terrain.height(0, 0), 0.0);
+        assert_eq!(terrain.height_at(Vec2::new(16.0, 16.0)), Some(2.0));
+    }
+
+    #[test]
+    fn flatten_levels_to_the_stroke_height() {
+        let mut terrain = flat(17);
+        terrain.heights.iter_mut().for_each(|h| *h = 4.0);
+        let brush = TerrainBrush {
+            tool: BrushTool::Flatten,
+            strength: 100.0,
+            stroke_height: Some(1.0),
+            ..default()
+        };
+        brush.apply_to(&mut terrain, Vec3::new(8.0, 4.0, 8.0), 1.0);
+        assert_eq!(terrain.height(8, 8), 1.0);
+    }
+
+    #[test]
+    fn edits_dirty_only_the_touched_chunks() {
+        let terrain = flat(33);
+        let inside: Vec<_> = terrain.chunks_touching(UVec2::new(2, 2), UVec2::new(5, 5)).collect();
+        assert_eq!(inside, vec![UVec2::ZERO]);
+        let border: Vec<_> = terrain.chunks_touching(UVec2::new(16, 2), UVec2::new(16, 2)).collect();
+        assert_eq!(border, vec![UVec2::new(0, 0), UVec2::new(1, 0)]);
+    }
+
+    #[test]
+    fn raycast_hits_the_surface() {
+        let terrain = flat(17);
+        let ray = Ray3d::new(Vec3::new(4.0, 10.0, 4.0), Dir3::NEG_Y);
+        let hit = terrain.raycast(ray, 50.0).unwrap();
+        assert!(hit.distance(Vec3::new(4.0, 0.0, 4.0)) < 1e-4);
+    }
+
+    #[test]
+    fn normals_lean_away_from_rising_ground() {
+        let mut terrain = flat(9);
+        // Rises 1 unit per unit along +X: a 45 degree slope.
+        for z in 0..9 {
+            for x in 0..9 {
+                let i = terrain.index(x, z);
+                terrain.heights[i] = x as f32;
+            }
+        }
+        let normal = terrain.normal_at(Vec2::new(4.0, 4.0)).unwrap();
+        assert!((normal.angle_between(Vec3::Y).to_degrees() - 45.0).abs() < 1e-3);
+        assert!(normal.x < 0.0 && normal.z.abs() < 1e-6);
+        assert_eq!(flat(9).normal_at(Vec2::new(0.0, 8.0)), Some(Vec3::Y));
+        assert_eq!(terrain.normal_at(Vec2::new(-1.0, 4.0)), None);
+    }
+}
@@ -0,0 +1,50 @@
+// src/features/terrain/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::editor::editor_active;
+
+pub mod brush;
+pub mod component;
+pub mod systems;
+
+/// Heightmap terrain with editor brushes.
+///
+/// Scope:
+/// - `TerrainData` (`assets/data/terrain.ron`): a heightmap patch with a paint
+///   layer per vertex, meshed in `TERRAIN_CHUNK_CELLS` chunks
+/// - editor mode: raise / lower / flatten / paint under the cursor (`.` cycles
+///   tools, `[` / `]` resize, left mouse applies); only touched chunks are rebuilt
+/// - Ctrl+S (editor) writes the edits back to the terrain file
+///
+/// Design constraints:
+/// - Chunk meshes are built on the `AsyncComputeTaskPool` (at startup and after
+///   edits) and attached when ready, so meshing never stalls a frame.
+/// - Meshes are rebuilt in place (same handles), so chunk entities never respawn.
+/// - No collision yet: the terrain is visual plus `height_at` queries.
+pub struct TerrainPlugin;
+
+impl Plugin for TerrainPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::TerrainData>();
+        app.init_resource::<component::TerrainFile>();
+        app.init_resource::<component::TerrainBrush>();
+        app.init_resource::<component::DirtyTerrainChunks>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+
+        app.add_systems(Startup, systems::spawn_terrain);
+        app.add_systems(
+            Update,
+            (brush::edit_terrain_brush, brush::apply_terrain_brush)
+                .chain()
+                .after(AppSet::Input)
+                .run_if(editor_active),
+        );
+        app.add_systems(
+            Update,
+            (systems::rebuild_dirty_chunks, systems::apply_chunk_meshes)
+                .chain()
+                .after(brush::apply_terrain_brush),
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// src/features/terrain/systems.rs
+use std::sync::Arc;
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::tasks::futures::check_ready;
+
+use super::component::{
+    ChunkMeshTask, DirtyTerrainChunks, TERRAIN_CHUNK_CELLS, TERRAIN_LAYERS, TerrainChunk,
+    TerrainData,
+};
+use crate::features::surface::component::SurfaceMaterial;
+
+/// Mesh of one chunk: world-space vertices, central-difference normals and the
+/// paint layer as vertex color.
+pub fn build_chunk_mesh(terrain: &TerrainData, coord: UVec2) -> Mesh {
+    let last = terrain.size - 1;
+    let (x0, z0) = (coord.x * TERRAIN_CHUNK_CELLS, coord.y * TERRAIN_CHUNK_CELLS);
+    let (x1, z1) = ((x0 + TERRAIN_CHUNK_CELLS).min(last), (z0 + TERRAIN_CHUNK_CELLS).min(last));
+    let width = x1 - x0 + 1;
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut colors = Vec::new();
+    for z in z0..=z1 {
+        for x in x0..=x1 {
+            positions.push(terrain.vertex_position(x, z).to_array());
+            let dx = terrain.height(x.saturating_sub(1), z) - terrain.height((x + 1).min(last), z);
+            let dz = terrain.height(x, z.saturating_sub(1)) - terrain.height(x, (z + 1).min(last));
+            normals.push(Vec3::new(dx, 2.0 * terrain.spacing, dz).normalize().to_array());
+            let layer = terrain.paint[terrain.index(x, z)] as usize;
+            let color = TERRAIN_LAYERS[layer.min(TERRAIN_LAYERS.len() - 1)].to_linear();
+            colors.push(color.to_f32_array());
+        }
+    }
+    let mut indices = Vec::new();
+    for z in 0..z1 - z0 {
+        for x in 0..x1 - x0 {
+            let i = z * width + x;
+            indices.extend_from_slice(&[i, i + width, i + 1, i + 1, i + width, i + width + 1]);
+        }
+    }
+
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Build `coord`'s mesh off the main thread from a snapshot of the terrain.
+fn chunk_mesh_task(terrain: Arc<TerrainData>, coord: UVec2) -> ChunkMeshTask {
+    ChunkMeshTask(AsyncComputeTaskPool::get().spawn(async move { build_chunk_mesh(&terrain, coord) }))
+}
+
+/// Startup: one entity per chunk; meshes are built in the background and attached
+/// by `apply_chunk_meshes`.
+pub fn spawn_terrain(
+    mut commands: Commands,
+    terrain: Res<TerrainData>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let material = materials.add(StandardMaterial {
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+    let snapshot = Arc::new(terrain.clone());
+    for z in 0..terrain.chunks() {
+        for x in 0..terrain.chunks() {
+            let coord = UVec2::new(x, z);
+            commands.spawn((
+                TerrainChunk { coord },
+                chunk_mesh_task(snapshot.clone(), coord),
+                MeshMaterial3d(material.clone()),
+                SurfaceMaterial::Grass,
+                Transform::default(),
+                Visibility::default(),
+                Name::new(format!("Terrain Chunk {x},{z}")),
+            ));
+        }
+    }
+}
+
+/// Update: start rebuilding the meshes of edited chunks (one terrain snapshot shared
+/// by every chunk dirtied this frame).
+pub fn rebuild_dirty_chunks(
+    mut commands: Commands,
+    terrain: Res<TerrainData>,
+    mut dirty: ResMut<DirtyTerrainChunks>,
+    q_chunks: Query<(Entity, &TerrainChunk)>,
+) {
+    if dirty.0.is_empty() {
+        return;
+    }
+    let snapshot = Arc::new(terrain.clone());
+    for (entity, chunk) in &q_chunks {
+        if dirty.0.contains(&chunk.coord) {
+            commands
+                .entity(entity)
+                .insert(chunk_mesh_task(snapshot.clone(), chunk.coord));
+        }
+    }
+    dirty.0.clear();
+}
+
+/// Update: attach finished chunk meshes; rebuilt chunks keep their mesh handle.
+pub fn apply_chunk_meshes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut q_tasks: Query<(Entity, &mut ChunkMeshTask, Option<&Mesh3d>)>,
+) {
+    for (entity, mut task, current) in &mut q_tasks {
+        let Some(mesh) = check_ready(&mut task.0) else {
+            continue;
+        };
+        let mut chunk = commands.entity(entity);
+        chunk.remove::<ChunkMeshTask>();
+        match current {
+            Some(handle) => {
+                let _ = meshes.insert(&handle.0, mesh);
+            }
+            None => {
+                chunk.insert(Mesh3d(meshes.add(mesh)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::TaskPool;
+
+    /// Run `apply_chunk_meshes` until every pending chunk mesh has landed.
+    fn finish_chunk_meshes(world: &mut World) {
+        for _ in 0..1000 {
+            let _ = world.run_system_once(apply_chunk_meshes);
+            if world.query::<&ChunkMeshTask>().iter(world).next().is_none() {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("chunk meshes never finished");
+    }
+
+    #[test]
+    fn chunk_mesh_covers_its_cells() {
+        let terrain = TerrainData::default();
+        let mesh = build_chunk_mesh(&terrain, UVec2::ZERO);
+        let side = (TERRAIN_CHUNK_CELLS + 1) as usize;
+        assert_eq!(mesh.count_vertices(), side * side);
+        assert_eq!(mesh.indices().unwrap().len(), (side - 1) * (side - 1) * 6);
+    }
+
+    #[test]
+    fn only_dirty_chunks_are_rebuilt() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<TerrainData>();
+        world.init_resource::<DirtyTerrainChunks>();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        let _ = world.run_system_once(spawn_terrain);
+        finish_chunk_meshes(&mut world);
+        let mut handles: Vec<(Entity, AssetId<Mesh>)> =
+            world.query::<(Entity, &Mesh3d)>().iter(&world).map(|(e, m)| (e, m.id())).collect();
+        handles.sort();
+        assert_eq!(handles.len(), (world.resource::<TerrainData>().chunks() as usize).pow(2));
+
+        world.resource_mut::<TerrainData>().heights[0] = 5.0;
+        world.resource_mut::<DirtyTerrainChunks>().mark(UVec2::ZERO);
+        let _ = world.run_system_once(rebuild_dirty_chunks);
+        assert_eq!(world.query::<&ChunkMeshTask>().iter(&world).count(), 1);
+        finish_chunk_meshes(&mut world);
+
+        let top_y = |world: &mut World, coord: UVec2| {
+            let handle = world
+                .query::<(&TerrainChunk, &Mesh3d)>()
+                .iter(world)
+                .find(|(c, _)| c.coord == coord)
+                .map(|(_, m)| m.0.clone())
+                .unwrap();
+            let meshes = world.resource::<Assets<Mesh>>();
+            let positions = meshes.get(&handle).unwrap().attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+            positions.as_float3().unwrap()[0][1]
+        };
+        assert_eq!(top_y(&mut world, UVec2::ZERO), 5.0);
+        assert!(world.resource::<DirtyTerrainChunks>().0.is_empty());
+        let mut after: Vec<(Entity, AssetId<Mesh>)> =
+            world.query::<(Entity, &Mesh3d)>().iter(&world).map(|(e, m)| (e, m.id())).collect();
+        after.sort();
+        assert_eq!(after, handles, "rebuilt in place");
+    }
+}
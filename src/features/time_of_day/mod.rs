@@ -0,0 +1,197 @@
+// src/features/time_of_day/mod.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppSet;
+
+/// In-game clock (hours, `0.0..24.0`).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfDay {
+    pub hour: f32,
+    /// In-game hours per real second (`0.0` freezes the clock).
+    pub hours_per_sec: f32,
+}
+
+impl Default for TimeOfDay {
+    /// 08:00, one in-game day per 20 real minutes.
+    fn default() -> Self {
+        Self {
+            hour: 8.0,
+            hours_per_sec: 24.0 / (20.0 * 60.0),
+        }
+    }
+}
+
+impl TimeOfDay {
+    pub fn advance(&mut self, dt: f32) {
+        self.hour = (self.hour + self.hours_per_sec * dt).rem_euclid(24.0);
+    }
+
+    /// Whether the clock is in `[from, to)`; ranges may wrap midnight (`22.0..6.0`).
+    pub fn is_between(&self, from: f32, to: f32) -> bool {
+        if from <= to {
+            self.hour >= from && self.hour < to
+        } else {
+            self.hour >= from || self.hour < to
+        }
+    }
+
+    pub fn phase(&self) -> DayPhase {
+        DayPhase::at(self.hour)
+    }
+
+    /// Ambient daylight, `0.0` (night) ..= `1.0` (day), ramping linearly through
+    /// dawn and dusk. Light-sensitive behaviour and lighting read this.
+    pub fn daylight(&self) -> f32 {
+        let h = self.hour;
+        if (DAWN_HOUR..DAY_HOUR).contains(&h) {
+            (h - DAWN_HOUR) / (DAY_HOUR - DAWN_HOUR)
+        } else if (DAY_HOUR..DUSK_HOUR).contains(&h) {
+            1.0
+        } else if (DUSK_HOUR..NIGHT_HOUR).contains(&h) {
+            1.0 - (h - DUSK_HOUR) / (NIGHT_HOUR - DUSK_HOUR)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Whether a clock going from `previous` to `current` (hours, wrapping at 24) passed `hour`.
+pub fn crossed_hour(previous: f32, current: f32, hour: f32) -> bool {
+    if previous <= current {
+        previous < hour && hour <= current
+    } else {
+        hour > previous || hour <= current
+    }
+}
+
+pub const DAWN_HOUR: f32 = 5.0;
+pub const DAY_HOUR: f32 = 7.0;
+pub const DUSK_HOUR: f32 = 18.0;
+pub const NIGHT_HOUR: f32 = 20.0;
+
+/// Coarse part of the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum DayPhase {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl DayPhase {
+    pub fn at(hour: f32) -> Self {
+        if (DAWN_HOUR..DAY_HOUR).contains(&hour) {
+            Self::Dawn
+        } else if (DAY_HOUR..DUSK_HOUR).contains(&hour) {
+            Self::Day
+        } else if (DUSK_HOUR..NIGHT_HOUR).contains(&hour) {
+            Self::Dusk
+        } else {
+            Self::Night
+        }
+    }
+}
+
+/// Data-file opening hours: `(from: 20.0, to: 5.0)`; may wrap midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct HourRange {
+    pub from: f32,
+    pub to: f32,
+}
+
+impl HourRange {
+    pub fn contains(&self, clock: &TimeOfDay) -> bool {
+        clock.is_between(self.from, self.to)
+    }
+}
+
+/// The clock moved into a new `DayPhase`.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayPhaseChanged {
+    pub from: DayPhase,
+    pub to: DayPhase,
+}
+
+/// Time-of-day feature.
+///
+/// Scope:
+/// - `TimeOfDay` clock advanced on the fixed timestep (schedules, shops, lighting read it)
+/// - `DayPhase` / `daylight()` for coarse queries, `DayPhaseChanged` when the phase flips
+/// - `HourRange`: hours in data files (shop opening hours, spawner activity)
+pub struct TimeOfDayPlugin;
+
+impl Plugin for TimeOfDayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeOfDay>();
+        app.add_message::<DayPhaseChanged>();
+        app.add_systems(
+            FixedUpdate,
+            advance_time_of_day.in_set(AppSet::FixedGameplay),
+        );
+    }
+}
+
+/// FixedUpdate: advance the clock.
+pub fn advance_time_of_day(
+    time: Res<Time<Fixed>>,
+    mut clock: ResMut<TimeOfDay>,
+    mut phase_changed: MessageWriter<DayPhaseChanged>,
+) {
+    let from = clock.phase();
+    clock.advance(time.delta_secs());
+    let to = clock.phase();
+    if from != to {
+        phase_changed.write(DayPhaseChanged { from, to });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_wraps_and_ranges_may_cross_midnight() {
+        let mut clock = TimeOfDay {
+            hour: 23.0,
+            hours_per_sec: 1.0,
+        };
+        clock.advance(2.5);
+        assert!((clock.hour - 1.5).abs() < 1e-5);
+
+        assert!(clock.is_between(22.0, 6.0));
+        assert!(!clock.is_between(8.0, 18.0));
+        assert!(clock.is_between(1.0, 2.0));
+    }
+
+    #[test]
+    fn phases_and_daylight_follow_the_hour() {
+        let at = |hour| TimeOfDay {
+            hour,
+            ..default()
+        };
+        assert_eq!(at(2.0).phase(), DayPhase::Night);
+        assert_eq!(at(6.0).phase(), DayPhase::Dawn);
+        assert_eq!(at(12.0).phase(), DayPhase::Day);
+        assert_eq!(at(19.0).phase(), DayPhase::Dusk);
+        assert_eq!(at(23.0).phase(), DayPhase::Night);
+
+        assert_eq!(at(2.0).daylight(), 0.0);
+        assert!((at(6.0).daylight() - 0.5).abs() < 1e-5);
+        assert_eq!(at(12.0).daylight(), 1.0);
+        assert!((at(19.0).daylight() - 0.5).abs() < 1e-5);
+
+        let night = HourRange { from: 20.0, to: 5.0 };
+        assert!(night.contains(&at(23.0)));
+        assert!(!night.contains(&at(12.0)));
+    }
+
+    #[test]
+    fn hour_crossing_handles_midnight() {
+        assert!(crossed_hour(5.9, 6.1, 6.0));
+        assert!(!crossed_hour(6.1, 6.2, 6.0));
+        assert!(crossed_hour(23.9, 0.1, 0.0));
+        assert!(crossed_hour(23.0, 6.5, 6.0));
+        assert!(!crossed_hour(23.0, 5.0, 6.0));
+    }
+}
@@ -0,0 +1,243 @@
+// src/features/track/component.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// One pose on a track, relative to the object's placement.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Keyframe {
+    /// Seconds from the start of the track.
+    pub time: f32,
+    /// Offset from the object's position.
+    #[serde(default)]
+    pub position: (f32, f32, f32),
+    /// Euler rotation in degrees (pitch, yaw, roll).
+    #[serde(default)]
+    pub rotation: (f32, f32, f32),
+}
+
+impl Keyframe {
+    pub fn offset(&self) -> Vec3 {
+        Vec3::from(self.position)
+    }
+
+    pub fn orientation(&self) -> Quat {
+        let (x, y, z) = self.rotation;
+        Quat::from_euler(EulerRot::YXZ, y.to_radians(), x.to_radians(), z.to_radians())
+    }
+}
+
+/// What a track does past its last key.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum LoopMode {
+    /// Stop on the last key.
+    Once,
+    /// Jump back to the first key.
+    #[default]
+    Loop,
+    /// Play backwards to the first key, then forwards again.
+    PingPong,
+}
+
+/// Keyframed motion: poses linearly interpolated (rotations slerped) between keys.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct KeyframeTrack {
+    /// Sorted by `time`.
+    pub keys: Vec<Keyframe>,
+    #[serde(default)]
+    pub mode: LoopMode,
+    /// Where on the track the object starts (phase offset between copies).
+    #[serde(default)]
+    pub start_secs: f32,
+}
+
+impl KeyframeTrack {
+    /// Time of the last key.
+    pub fn duration(&self) -> f32 {
+        self.keys.last().map_or(0.0, |k| k.time)
+    }
+
+    /// Track time after `elapsed` seconds of playback, per the loop mode.
+    pub fn local_time(&self, elapsed: f32) -> f32 {
+        let duration = self.duration();
+        if duration <= 0.0 {
+            return 0.0;
+        }
+        let t = elapsed + self.start_secs;
+        match self.mode {
+            LoopMode::Once => t.clamp(0.0, duration),
+            LoopMode::Loop => t.rem_euclid(duration),
+            LoopMode::PingPong => {
+                let t = t.rem_euclid(duration * 2.0);
+                if t > duration { duration * 2.0 - t } else { t }
+            }
+        }
+    }
+
+    /// Offset and rotation at track time `time`.
+    pub fn sample(&self, time: f32) -> (Vec3, Quat) {
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return (Vec3::ZERO, Quat::IDENTITY);
+        };
+        if time <= first.time {
+            return (first.offset(), first.orientation());
+        }
+        if time >= last.time {
+            return (last.offset(), last.orientation());
+        }
+        let next = self.keys.iter().position(|k| k.time > time).unwrap_or(self.keys.len() - 1);
+        let (a, b) = (self.keys[next - 1], self.keys[next]);
+        let span = b.time - a.time;
+        let f = if span > 0.0 { (time - a.time) / span } else { 1.0 };
+        (
+            a.offset().lerp(b.offset(), f),
+            a.orientation().slerp(b.orientation(), f),
+        )
+    }
+}
+
+/// One piece of moving geometry, loaded from `assets/data/tracks.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TrackedObjectDef {
+    pub name: String,
+    /// Placement; keyframe offsets are relative to it.
+    pub position: (f32, f32, f32),
+    /// Box size (half extents); solid unless `solid: false`.
+    pub half_extents: (f32, f32, f32),
+    #[serde(default = "default_solid")]
+    pub solid: bool,
+    pub track: KeyframeTrack,
+}
+
+fn default_solid() -> bool {
+    true
+}
+
+/// Scene data: platforms, doors and hazards that move along tracks.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TrackedGeometry(pub Vec<TrackedObjectDef>);
+
+const BUILTIN_TRACKS: &str = include_str!("../../../assets/data/tracks.ron");
+
+impl TrackedGeometry {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+}
+
+impl Default for TrackedGeometry {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_TRACKS).expect("assets/data/tracks.ron must parse")
+    }
+}
+
+/// Plays a track on the entity's `Transform` (fixed timestep).
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(TrackDelta)]
+pub struct TrackPlayer {
+    pub track: KeyframeTrack,
+    /// Pose the keyframes are relative to.
+    pub origin: Vec3,
+    pub base_rotation: Quat,
+    pub elapsed: f32,
+}
+
+impl TrackPlayer {
+    pub fn new(track: KeyframeTrack, origin: Transform) -> Self {
+        Self {
+            track,
+            origin: origin.translation,
+            base_rotation: origin.rotation,
+            elapsed: 0.0,
+        }
+    }
+
+    /// World pose after `elapsed` seconds of playback.
+    pub fn pose(&self) -> (Vec3, Quat) {
+        let (offset, rotation) = self.track.sample(self.track.local_time(self.elapsed));
+        (self.origin + offset, rotation * self.base_rotation)
+    }
+}
+
+/// How far a tracked object moved during the last fixed tick (what riders follow).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TrackDelta {
+    pub translation: Vec3,
+    /// Rotation about the object's center.
+    pub rotation: Quat,
+}
+
+impl Default for TrackDelta {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+        }
+    }
+}
+
+impl TrackDelta {
+    /// Where a point at `point` ends up when carried along (object now at `center`).
+    pub fn carry(&self, center: Vec3, point: Vec3) -> Vec3 {
+        let before = center - self.translation;
+        center + self.rotation * (point - before)
+    }
+}
+
+/// Gets carried by tracked objects it stands on (players get one).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Rider;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(time: f32, x: f32, yaw: f32) -> Keyframe {
+        Keyframe {
+            time,
+            position: (x, 0.0, 0.0),
+            rotation: (0.0, yaw, 0.0),
+        }
+    }
+
+    fn track(mode: LoopMode) -> KeyframeTrack {
+        KeyframeTrack {
+            keys: vec![key(0.0, 0.0, 0.0), key(2.0, 4.0, 90.0)],
+            mode,
+            start_secs: 0.0,
+        }
+    }
+
+    #[test]
+    fn loop_modes_wrap_time_differently() {
+        assert_eq!(track(LoopMode::Once).local_time(3.0), 2.0);
+        assert_eq!(track(LoopMode::Loop).local_time(3.0), 1.0);
+        assert_eq!(track(LoopMode::PingPong).local_time(3.0), 1.0);
+        assert_eq!(track(LoopMode::PingPong).local_time(3.5), 0.5);
+        assert_eq!(track(LoopMode::PingPong).local_time(4.5), 0.5);
+    }
+
+    #[test]
+    fn poses_interpolate_between_keys() {
+        let (offset, rotation) = track(LoopMode::Loop).sample(1.0);
+        assert_eq!(offset, Vec3::X * 2.0);
+        let expected = Quat::from_rotation_y(45f32.to_radians());
+        assert!(rotation.angle_between(expected) < 1e-4);
+        assert_eq!(track(LoopMode::Loop).sample(9.0).0, Vec3::X * 4.0);
+    }
+
+    #[test]
+    fn carried_points_follow_translation_and_spin() {
+        let delta = TrackDelta {
+            translation: Vec3::Y,
+            rotation: Quat::from_rotation_y(90f32.to_radians()),
+        };
+        // Object moved from the origin to y = 1 while turning a quarter.
+        let carried = delta.carry(Vec3::Y, Vec3::new(1.0, 0.5, 0.0));
+        assert!((carried - Vec3::new(0.0, 1.5, -1.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn builtin_tracks_parse() {
+        assert!(!TrackedGeometry::default().0.is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+// src/features/track/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod systems;
+
+/// Keyframed moving geometry.
+///
+/// Scope:
+/// - `KeyframeTrack`: position / rotation keys with Once / Loop / PingPong playback
+/// - `TrackedGeometry` from `assets/data/tracks.ron`: lifts, ferries, doors and
+///   hazards fully described by data (box, placement, track)
+/// - `TrackPlayer` drives an entity's `Transform` and records the tick's motion in
+///   `TrackDelta`; `Rider` bodies (players) standing on tracked colliders are
+///   carried by it
+///
+/// Design constraints:
+/// - tracks advance on the fixed timestep, after the shared movement integration,
+///   so riders add the platform's motion on top of their own.
+pub struct TrackPlugin;
+
+impl Plugin for TrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::TrackedGeometry>();
+
+        app.add_systems(Startup, systems::spawn_tracked_geometry);
+        app.add_systems(
+            FixedUpdate,
+            systems::grant_riders.in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (systems::advance_tracks, systems::carry_riders)
+                .chain()
+                .in_set(AppSet::PostMovement),
+        );
+    }
+}
@@ -0,0 +1,220 @@
+// src/features/track/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Rider, TrackDelta, TrackPlayer, TrackedGeometry};
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::ray_aabb;
+use crate::features::player::component::Player;
+use crate::features::surface::component::GROUND_PROBE_MARGIN;
+
+/// Half height assumed for riders without a `Collider` (the player box).
+const DEFAULT_HALF_HEIGHT: f32 = 0.5;
+
+/// Startup: spawn the `TrackedGeometry` objects at the start of their tracks.
+pub fn spawn_tracked_geometry(
+    mut commands: Commands,
+    geometry: Res<TrackedGeometry>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.45, 0.42, 0.5),
+        perceptual_roughness: 0.7,
+        ..default()
+    });
+    for def in &geometry.0 {
+        let half_extents = Vec3::from(def.half_extents);
+        let player = TrackPlayer::new(
+            def.track.clone(),
+            Transform::from_translation(Vec3::from(def.position)),
+        );
+        let (translation, rotation) = player.pose();
+        let mut entity = commands.spawn((
+            player,
+            Mesh3d(meshes.add(Cuboid::from_size(half_extents * 2.0))),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(translation).with_rotation(rotation),
+            Name::new(def.name.clone()),
+        ));
+        if def.solid {
+            entity.insert(Collider::cuboid(half_extents));
+        }
+    }
+}
+
+/// FixedUpdate: players ride moving geometry.
+pub fn grant_riders(
+    mut commands: Commands,
+    q_players: Query<Entity, (Added<Player>, Without<Rider>)>,
+) {
+    for entity in &q_players {
+        commands.entity(entity).insert(Rider);
+    }
+}
+
+/// FixedUpdate: advance every track and record how far its object moved this tick.
+pub fn advance_tracks(
+    time: Res<Time<Fixed>>,
+    mut q_tracks: Query<(&mut TrackPlayer, &mut Transform, &mut TrackDelta)>,
+) {
+    let dt = time.delta_secs();
+    for (mut player, mut transform, mut delta) in &mut q_tracks {
+        player.elapsed += dt;
+        let (translation, rotation) = player.pose();
+        *delta = TrackDelta {
+            translation: translation - transform.translation,
+            rotation: rotation * transform.rotation.inverse(),
+        };
+        transform.translation = translation;
+        transform.rotation = rotation;
+    }
+}
+
+/// FixedUpdate (after tracks advanced): riders standing on a tracked collider move
+/// with it; the object's spin turns them too (yaw only, so they stay upright).
+///
+/// Standing is tested against where the object was *before* this tick's move, with
+/// the same ground probe as landings.
+pub fn carry_riders(
+    q_tracks: Query<(Entity, &Transform, &Collider, &TrackDelta), Without<Rider>>,
+    mut q_riders: Query<(Entity, &mut Transform, Option<&Collider>), With<Rider>>,
+) {
+    for (rider, mut transform, collider) in &mut q_riders {
+        let half_height = collider.map_or(DEFAULT_HALF_HEIGHT, |c| c.half_extents.y);
+        let reach = half_height + GROUND_PROBE_MARGIN;
+        let support = q_tracks
+            .iter()
+            .filter(|(entity, ..)| *entity != rider)
+            .filter_map(|(_, track_tr, track_collider, delta)| {
+                let before = track_collider.aabb(track_tr.translation - delta.translation);
+                ray_aabb(transform.translation, Vec3::NEG_Y, reach, &before)
+                    .map(|(distance, _)| (distance, track_tr.translation, *delta))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        let Some((_, center, delta)) = support else {
+            continue;
+        };
+
+        transform.translation = delta.carry(center, transform.translation);
+        let (yaw, _, _) = delta.rotation.to_euler(EulerRot::YXZ);
+        if yaw != 0.0 {
+            transform.rotation = Quat::from_rotation_y(yaw) * transform.rotation;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::track::component::{Keyframe, KeyframeTrack, LoopMode};
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(0.5));
+        world.insert_resource(time);
+        world
+    }
+
+    /// 4x4 platform whose top starts at y = 0, moving `to` over 1 s.
+    fn spawn_platform(world: &mut World, to: Keyframe) -> Entity {
+        let track = KeyframeTrack {
+            keys: vec![
+                Keyframe {
+                    time: 0.0,
+                    position: (0.0, 0.0, 0.0),
+                    rotation: (0.0, 0.0, 0.0),
+                },
+                Keyframe { time: 1.0, ..to },
+            ],
+            mode: LoopMode::Once,
+            start_secs: 0.0,
+        };
+        let origin = Transform::from_xyz(0.0, -0.25, 0.0);
+        world
+            .spawn((
+                TrackPlayer::new(track, origin),
+                origin,
+                Collider::cuboid(Vec3::new(2.0, 0.25, 2.0)),
+            ))
+            .id()
+    }
+
+    fn spawn_rider(world: &mut World, at: Vec3) -> Entity {
+        world.spawn((Rider, Transform::from_translation(at))).id()
+    }
+
+    fn tick(world: &mut World) {
+        let _ = world.run_system_once(advance_tracks);
+        let _ = world.run_system_once(carry_riders);
+    }
+
+    #[test]
+    fn tracks_record_their_per_tick_delta() {
+        let mut world = setup_world();
+        let platform = spawn_platform(
+            &mut world,
+            Keyframe {
+                time: 1.0,
+                position: (4.0, 0.0, 0.0),
+                rotation: (0.0, 0.0, 0.0),
+            },
+        );
+
+        let _ = world.run_system_once(advance_tracks);
+        let _ = world.run_system_once(advance_tracks);
+        let _ = world.run_system_once(advance_tracks);
+
+        assert_eq!(world.get::<Transform>(platform).unwrap().translation.x, 4.0);
+        assert_eq!(world.get::<TrackDelta>(platform).unwrap().translation, Vec3::ZERO, "done");
+        let elapsed = world.get::<TrackPlayer>(platform).unwrap().elapsed;
+        assert_eq!(elapsed, 1.5);
+    }
+
+    #[test]
+    fn riders_move_with_the_platform_under_them() {
+        let mut world = setup_world();
+        spawn_platform(
+            &mut world,
+            Keyframe {
+                time: 1.0,
+                position: (4.0, 2.0, 0.0),
+                rotation: (0.0, 0.0, 0.0),
+            },
+        );
+        let rider = spawn_rider(&mut world, Vec3::new(1.0, 0.5, 0.0));
+        let bystander = spawn_rider(&mut world, Vec3::new(6.0, 0.5, 0.0));
+
+        tick(&mut world);
+        tick(&mut world);
+
+        let position = world.get::<Transform>(rider).unwrap().translation;
+        assert!((position - Vec3::new(5.0, 2.5, 0.0)).length() < 1e-5, "at {position}");
+        let position = world.get::<Transform>(bystander).unwrap().translation;
+        assert_eq!(position, Vec3::new(6.0, 0.5, 0.0), "not standing on it");
+    }
+
+    #[test]
+    fn spinning_platforms_turn_their_riders() {
+        let mut world = setup_world();
+        spawn_platform(
+            &mut world,
+            Keyframe {
+                time: 1.0,
+                position: (0.0, 0.0, 0.0),
+                rotation: (0.0, 90.0, 0.0),
+            },
+        );
+        let rider = spawn_rider(&mut world, Vec3::new(1.0, 0.5, 0.0));
+
+        tick(&mut world);
+        tick(&mut world);
+
+        let transform = *world.get::<Transform>(rider).unwrap();
+        assert!((transform.translation - Vec3::new(0.0, 0.5, -1.0)).length() < 1e-4);
+        let facing = transform.rotation * Vec3::NEG_Z;
+        assert!((facing - Vec3::NEG_X).length() < 1e-4, "facing {facing}");
+    }
+}
@@ -0,0 +1,20 @@
+// src/features/trigger/component.rs
+use bevy::prelude::*;
+
+/// Axis-aligned box (around the entity's `Transform`) that reports players
+/// entering and leaving it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(TriggerOccupants)]
+pub struct TriggerVolume {
+    pub half_extents: Vec3,
+}
+
+impl TriggerVolume {
+    pub fn contains(&self, center: Vec3, point: Vec3) -> bool {
+        ((point - center).abs() - self.half_extents).max_element() <= 0.0
+    }
+}
+
+/// Entities currently inside a trigger volume.
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TriggerOccupants(pub Vec<Entity>);
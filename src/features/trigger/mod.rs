@@ -0,0 +1,30 @@
+// src/features/trigger/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod systems;
+
+/// Trigger volumes.
+///
+/// Scope:
+/// - `TriggerVolume` boxes track which players are inside (`TriggerOccupants`)
+/// - `TriggerEntered` / `TriggerExited` on changes; owning features (tutorials,
+///   hazards, checkpoints) react to the volumes they spawned
+///
+/// Design constraints:
+/// - checked on the fixed timestep after movement, against `Transform` positions.
+pub struct TriggerPlugin;
+
+impl Plugin for TriggerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<systems::TriggerEntered>();
+        app.add_message::<systems::TriggerExited>();
+
+        app.add_systems(
+            FixedUpdate,
+            systems::detect_trigger_occupancy.in_set(AppSet::FixedGameplay),
+        );
+    }
+}
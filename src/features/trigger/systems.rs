@@ -0,0 +1,82 @@
+// src/features/trigger/systems.rs
+use bevy::prelude::*;
+
+use super::component::{TriggerOccupants, TriggerVolume};
+use crate::features::player::component::Player;
+
+/// `entity` moved into `volume`.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerEntered {
+    pub volume: Entity,
+    pub entity: Entity,
+}
+
+/// `entity` left `volume` (or despawned while inside).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerExited {
+    pub volume: Entity,
+    pub entity: Entity,
+}
+
+/// FixedUpdate: diff the players inside every volume against last tick.
+pub fn detect_trigger_occupancy(
+    mut q_volumes: Query<(Entity, &TriggerVolume, &Transform, &mut TriggerOccupants)>,
+    q_players: Query<(Entity, &Transform), With<Player>>,
+    mut entered: MessageWriter<TriggerEntered>,
+    mut exited: MessageWriter<TriggerExited>,
+) {
+    for (volume, trigger, transform, mut occupants) in &mut q_volumes {
+        let inside: Vec<Entity> = q_players
+            .iter()
+            .filter(|(_, t)| trigger.contains(transform.translation, t.translation))
+            .map(|(entity, _)| entity)
+            .collect();
+        if inside == occupants.0 {
+            continue;
+        }
+        for &entity in inside.iter().filter(|e| !occupants.0.contains(e)) {
+            entered.write(TriggerEntered { volume, entity });
+        }
+        for &entity in occupants.0.iter().filter(|e| !inside.contains(e)) {
+            exited.write(TriggerExited { volume, entity });
+        }
+        occupants.0 = inside;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn entering_and_leaving_are_reported_once() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TriggerEntered>>();
+        world.init_resource::<Messages<TriggerExited>>();
+        let volume = world
+            .spawn((
+                TriggerVolume {
+                    half_extents: Vec3::splat(1.0),
+                },
+                Transform::default(),
+            ))
+            .id();
+        let player = world.spawn((Player, Transform::from_xyz(0.5, 0.0, 0.0))).id();
+
+        let _ = world.run_system_once(detect_trigger_occupancy);
+        let _ = world.run_system_once(detect_trigger_occupancy);
+        assert_eq!(world.resource::<Messages<TriggerEntered>>().len(), 1);
+
+        world.get_mut::<Transform>(player).unwrap().translation.x = 3.0;
+        let _ = world.run_system_once(detect_trigger_occupancy);
+
+        let exits: Vec<_> = world
+            .resource::<Messages<TriggerExited>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(exits, [TriggerExited { volume, entity: player }]);
+    }
+}
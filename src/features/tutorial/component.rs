@@ -0,0 +1,140 @@
+// src/features/tutorial/component.rs
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::StoragePaths;
+use crate::features::save::migrate::{Migration, from_ron_with};
+
+/// One tutorial zone, loaded from `assets/data/tutorials.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TutorialDef {
+    pub id: String,
+    pub position: (f32, f32, f32),
+    pub half_extents: (f32, f32, f32),
+    /// Prompt text with `{action}` glyph placeholders.
+    pub prompt: String,
+}
+
+/// Tutorial zones placed at startup.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TutorialSpawns(pub Vec<TutorialDef>);
+
+const BUILTIN_TUTORIALS: &str = include_str!("../../../assets/data/tutorials.ron");
+
+impl TutorialSpawns {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+}
+
+impl Default for TutorialSpawns {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_TUTORIALS).expect("assets/data/tutorials.ron must parse")
+    }
+}
+
+/// A tutorial zone's trigger volume.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct TutorialZone {
+    pub id: String,
+    pub prompt: String,
+}
+
+/// Current tutorial progress file format; bump it (and register a migration) on
+/// every layout change.
+pub const TUTORIALS_VERSION: u32 = 1;
+
+/// Every tutorial progress file migration, oldest first (see `save::migrate`).
+pub const TUTORIALS_MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    summary: "version header added",
+    apply: |_| Ok(()),
+}];
+
+/// Tutorials already shown (persisted, so they never repeat).
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TutorialProgress {
+    /// Format the file was written in (missing = 0, the unversioned layout).
+    #[serde(default)]
+    pub version: u32,
+    pub seen: BTreeSet<String>,
+}
+
+impl TutorialProgress {
+    /// Stored progress (any known version), or none seen yet.
+    pub fn load(path: &Path) -> Self {
+        let Ok(source) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        from_ron_with(&source, TUTORIALS_MIGRATIONS, TUTORIALS_VERSION).unwrap_or_else(|err| {
+            warn!("ignoring unreadable {}: {err}", path.display());
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let current = Self {
+            version: TUTORIALS_VERSION,
+            ..self.clone()
+        };
+        let text = ron::ser::to_string_pretty(&current, ron::ser::PrettyConfig::default())
+            .map_err(std::io::Error::other)?;
+        std::fs::write(path, text)
+    }
+}
+
+/// Where `TutorialProgress` is saved.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct TutorialFile(pub PathBuf);
+
+impl FromWorld for TutorialFile {
+    fn from_world(world: &mut World) -> Self {
+        Self(StoragePaths::of(world).data_file("tutorials.ron"))
+    }
+}
+
+/// Seconds a prompt stays up at most (it also hides when the player leaves the zone).
+pub const TUTORIAL_PROMPT_SECS: f32 = 8.0;
+
+/// The prompt on screen.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct ActiveTutorial {
+    /// Zone that raised the prompt (`None` = nothing shown).
+    pub zone: Option<Entity>,
+    pub text: String,
+    pub remaining_secs: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_tutorials_parse_with_unique_ids() {
+        let spawns = TutorialSpawns::default();
+        let ids: BTreeSet<_> = spawns.0.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids.len(), spawns.0.len());
+    }
+
+    #[test]
+    fn unversioned_progress_loads() {
+        let path = std::env::temp_dir()
+            .join(format!("to_be_free_tutorials_v0_{}", std::process::id()))
+            .join("tutorials.ron");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, include_str!("../../../tests/fixtures/saves/tutorials_v0.ron")).unwrap();
+
+        let progress = TutorialProgress::load(&path);
+
+        assert_eq!(progress.version, TUTORIALS_VERSION);
+        assert_eq!(progress.seen, BTreeSet::from(["fly".to_string(), "jump".to_string()]));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
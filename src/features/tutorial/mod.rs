@@ -0,0 +1,41 @@
+// src/features/tutorial/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+
+pub mod component;
+pub mod systems;
+
+/// Zone-triggered tutorial prompts.
+///
+/// Scope:
+/// - `TutorialZone` trigger volumes from `assets/data/tutorials.ron`
+/// - the first time the player enters one, its prompt shows with the current
+///   key glyphs filled in ("Press [Space] to rise"); leaving the zone or
+///   `TUTORIAL_PROMPT_SECS` hides it
+/// - seen zones are saved (`tutorials.ron` in the data directory) and never spawned again;
+///   the file is versioned like the game save (`TUTORIALS_VERSION`, `TUTORIALS_MIGRATIONS`)
+///
+/// Design constraints:
+/// - builds on `features::trigger` volumes and `hud::glyphs`; no per-zone logic.
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::TutorialSpawns>();
+        app.init_resource::<component::TutorialFile>();
+        app.init_resource::<component::TutorialProgress>();
+        app.init_resource::<component::ActiveTutorial>();
+
+        app.add_systems(
+            Startup,
+            (systems::spawn_tutorial_zones, systems::spawn_tutorial_prompt),
+        );
+        app.add_systems(
+            Update,
+            (systems::show_tutorial_prompts, systems::update_tutorial_prompt)
+                .chain()
+                .after(AppSet::Input),
+        );
+    }
+}
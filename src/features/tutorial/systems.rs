@@ -0,0 +1,171 @@
+// src/features/tutorial/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    ActiveTutorial, TUTORIAL_PROMPT_SECS, TutorialFile, TutorialProgress, TutorialSpawns,
+    TutorialZone,
+};
+use crate::features::hud::glyphs::ActionKeys;
+use crate::features::trigger::component::TriggerVolume;
+use crate::features::trigger::systems::{TriggerEntered, TriggerExited};
+
+/// Bottom-center tutorial prompt label.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct TutorialPrompt;
+
+/// Startup: load what was already seen and place the zones not seen yet.
+pub fn spawn_tutorial_zones(
+    mut commands: Commands,
+    spawns: Res<TutorialSpawns>,
+    file: Res<TutorialFile>,
+    mut progress: ResMut<TutorialProgress>,
+) {
+    *progress = TutorialProgress::load(&file.0);
+    for def in spawns.0.iter().filter(|d| !progress.seen.contains(&d.id)) {
+        commands.spawn((
+            TutorialZone {
+                id: def.id.clone(),
+                prompt: def.prompt.clone(),
+            },
+            TriggerVolume {
+                half_extents: Vec3::from(def.half_extents),
+            },
+            Transform::from_translation(Vec3::from(def.position)),
+            Name::new(format!("Tutorial {}", def.id)),
+        ));
+    }
+}
+
+/// Update: first entry into a zone shows its prompt (keys filled in from the
+/// current bindings) and marks it seen; leaving the zone hides it.
+#[expect(clippy::too_many_arguments)]
+pub fn show_tutorial_prompts(
+    mut commands: Commands,
+    file: Res<TutorialFile>,
+    keys: ActionKeys,
+    mut progress: ResMut<TutorialProgress>,
+    mut active: ResMut<ActiveTutorial>,
+    mut entered: MessageReader<TriggerEntered>,
+    mut exited: MessageReader<TriggerExited>,
+    q_zones: Query<&TutorialZone>,
+) {
+    for ev in exited.read() {
+        if active.zone == Some(ev.volume) {
+            active.remaining_secs = active.remaining_secs.min(0.0);
+        }
+    }
+
+    let mut seen_new = false;
+    for ev in entered.read() {
+        let Ok(zone) = q_zones.get(ev.volume) else {
+            continue;
+        };
+        if !progress.seen.insert(zone.id.clone()) {
+            continue;
+        }
+        *active = ActiveTutorial {
+            zone: Some(ev.volume),
+            text: keys.fill(&zone.prompt),
+            remaining_secs: TUTORIAL_PROMPT_SECS,
+        };
+        seen_new = true;
+    }
+    if seen_new && let Err(err) = progress.save(&file.0) {
+        warn!("could not save {}: {err}", file.0.display());
+    }
+
+    // Once its prompt is over a zone has no further use.
+    if active.remaining_secs <= 0.0
+        && let Some(zone) = active.zone.take()
+    {
+        commands.entity(zone).try_despawn();
+    }
+}
+
+/// Update: count the prompt down (real time) and mirror it into the label.
+pub fn update_tutorial_prompt(
+    time: Res<Time<Real>>,
+    mut active: ResMut<ActiveTutorial>,
+    mut q_prompt: Query<(&mut Text, &mut Visibility), With<TutorialPrompt>>,
+) {
+    if active.zone.is_some() {
+        active.remaining_secs -= time.delta_secs();
+    }
+    for (mut text, mut vis) in &mut q_prompt {
+        if active.zone.is_some() && active.remaining_secs > 0.0 {
+            if text.0 != active.text {
+                text.0.clone_from(&active.text);
+            }
+            *vis = Visibility::Inherited;
+        } else {
+            *vis = Visibility::Hidden;
+        }
+    }
+}
+
+/// Startup: spawn the prompt label (hidden until a zone is entered).
+pub fn spawn_tutorial_prompt(mut commands: Commands) {
+    commands.spawn((
+        TutorialPrompt,
+        Text::new(""),
+        TextFont::from_font_size(22.0),
+        TextColor(Color::srgb(1.0, 0.95, 0.7)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(30.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Visibility::Hidden,
+        Name::new("TutorialPrompt"),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::player::input::PlayerKeybindings;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn first_entry_shows_filled_prompt_and_is_saved() {
+        let dir = std::env::temp_dir().join(format!("to_be_free_tut_{}", std::process::id()));
+        let mut world = World::new();
+        world.insert_resource(TutorialFile(dir.join("tutorials.ron")));
+        world.insert_resource(PlayerKeybindings::default());
+        world.init_resource::<TutorialProgress>();
+        world.init_resource::<ActiveTutorial>();
+        world.init_resource::<Messages<TriggerEntered>>();
+        world.init_resource::<Messages<TriggerExited>>();
+        let zone = world
+            .spawn(TutorialZone {
+                id: "fly".into(),
+                prompt: "Press {up} to rise".into(),
+            })
+            .id();
+        let player = world.spawn_empty().id();
+        let entry = TriggerEntered {
+            volume: zone,
+            entity: player,
+        };
+
+        world.write_message(entry);
+        let _ = world.run_system_once(show_tutorial_prompts);
+        assert_eq!(world.resource::<ActiveTutorial>().text, "Press [Space] to rise");
+
+        world.resource_mut::<ActiveTutorial>().text.clear();
+        world.write_message(entry);
+        let _ = world.run_system_once(show_tutorial_prompts);
+        assert!(
+            world.resource::<ActiveTutorial>().text.is_empty(),
+            "seen tutorials don't repeat"
+        );
+
+        let saved = TutorialProgress::load(&dir.join("tutorials.ron"));
+        assert!(saved.seen.contains("fly"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
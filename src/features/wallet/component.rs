@@ -0,0 +1,59 @@
+// src/features/wallet/component.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Coins every new player starts with.
+pub const STARTING_COINS: u32 = 10;
+
+/// Coins players earn per enemy killed.
+pub const COINS_PER_KILL: u32 = 5;
+
+/// Money carried by a character (players, vendors).
+///
+/// Serializable as-is: the save layer stores the component and loads it back
+/// with `RestoreWallet`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wallet {
+    pub coins: u32,
+}
+
+impl Wallet {
+    pub fn new(coins: u32) -> Self {
+        Self { coins }
+    }
+
+    pub fn earn(&mut self, amount: u32) {
+        self.coins = self.coins.saturating_add(amount);
+    }
+
+    pub fn can_afford(&self, amount: u32) -> bool {
+        self.coins >= amount
+    }
+
+    /// Spend `amount` if affordable. Returns `false` (and spends nothing) otherwise.
+    pub fn try_spend(&mut self, amount: u32) -> bool {
+        if !self.can_afford(amount) {
+            return false;
+        }
+        self.coins -= amount;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spending_is_all_or_nothing() {
+        let mut wallet = Wallet::new(10);
+
+        assert!(wallet.try_spend(4));
+        assert_eq!(wallet.coins, 6);
+        assert!(!wallet.try_spend(7), "unaffordable spend must not spend");
+        assert_eq!(wallet.coins, 6);
+
+        wallet.earn(u32::MAX);
+        assert_eq!(wallet.coins, u32::MAX, "earning saturates");
+    }
+}
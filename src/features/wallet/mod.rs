@@ -0,0 +1,45 @@
+// src/features/wallet/mod.rs
+use bevy::prelude::*;
+
+use crate::app::AppSet;
+use crate::features::health::damage::{Died, apply_damage};
+
+pub mod component;
+pub mod systems;
+
+/// Currency.
+///
+/// Scope:
+/// - `Wallet` on players (starting coins) and vendors
+/// - `EarnCoins` / `SpendCoins` requests from rewards and sinks (enemy kills pay
+///   a bounty); every balance change is announced with `WalletChanged`,
+///   unaffordable spends with `SpendRejected`
+/// - the HUD shows the player's balance, shops trade against it
+///
+/// Persistence: the save layer stores the `Wallet` component and loads it back
+/// with `RestoreWallet`.
+pub struct WalletPlugin;
+
+impl Plugin for WalletPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<systems::EarnCoins>();
+        app.add_message::<systems::SpendCoins>();
+        app.add_message::<systems::WalletChanged>();
+        app.add_message::<systems::SpendRejected>();
+        app.add_message::<systems::RestoreWallet>();
+        app.add_message::<Died>();
+
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_player_wallet,
+                systems::reward_kills,
+                systems::restore_wallets,
+                systems::apply_wallet_requests,
+            )
+                .chain()
+                .after(apply_damage)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
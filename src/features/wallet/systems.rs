@@ -0,0 +1,197 @@
+// src/features/wallet/systems.rs
+use bevy::prelude::*;
+
+use super::component::{COINS_PER_KILL, STARTING_COINS, Wallet};
+use crate::features::enemy::component::Enemy;
+use crate::features::health::damage::Died;
+use crate::features::player::component::Player;
+
+/// Add coins to a wallet (loot, objectives, quest rewards).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EarnCoins {
+    pub entity: Entity,
+    pub amount: u32,
+}
+
+/// Take coins from a wallet (fees, repairs). Rejected as a whole if unaffordable.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendCoins {
+    pub entity: Entity,
+    pub amount: u32,
+}
+
+/// A wallet's balance changed (HUD, toasts, audio).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalletChanged {
+    pub entity: Entity,
+    /// Signed change (negative = spent).
+    pub delta: i64,
+    pub balance: u32,
+    /// Loaded from a save (`RestoreWallet`) rather than earned or spent.
+    pub restored: bool,
+}
+
+impl WalletChanged {
+    pub fn new(entity: Entity, before: u32, after: u32) -> Self {
+        Self {
+            entity,
+            delta: i64::from(after) - i64::from(before),
+            balance: after,
+            restored: false,
+        }
+    }
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendRejected {
+    pub entity: Entity,
+    pub amount: u32,
+    pub balance: u32,
+}
+
+/// Replace a wallet with saved contents (save layer).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestoreWallet {
+    pub entity: Entity,
+    pub wallet: Wallet,
+}
+
+/// Players start with a few coins.
+pub fn grant_player_wallet(
+    mut commands: Commands,
+    q_new: Query<Entity, (Added<Player>, Without<Wallet>)>,
+) {
+    for entity in &q_new {
+        commands.entity(entity).insert(Wallet::new(STARTING_COINS));
+    }
+}
+
+/// FixedUpdate: every enemy death pays players a bounty.
+pub fn reward_kills(
+    mut died: MessageReader<Died>,
+    q_enemies: Query<(), With<Enemy>>,
+    q_players: Query<Entity, (With<Player>, With<Wallet>)>,
+    mut earn: MessageWriter<EarnCoins>,
+) {
+    let kills = died.read().filter(|ev| q_enemies.contains(ev.entity)).count() as u32;
+    if kills == 0 {
+        return;
+    }
+    for entity in &q_players {
+        earn.write(EarnCoins {
+            entity,
+            amount: kills * COINS_PER_KILL,
+        });
+    }
+}
+
+/// FixedUpdate: apply earn, then spend requests.
+pub fn apply_wallet_requests(
+    mut earn: MessageReader<EarnCoins>,
+    mut spend: MessageReader<SpendCoins>,
+    mut q_wallets: Query<&mut Wallet>,
+    mut changed: MessageWriter<WalletChanged>,
+    mut rejected: MessageWriter<SpendRejected>,
+) {
+    for req in earn.read() {
+        let Ok(mut wallet) = q_wallets.get_mut(req.entity) else {
+            continue;
+        };
+        if req.amount == 0 {
+            continue;
+        }
+        let before = wallet.coins;
+        wallet.earn(req.amount);
+        changed.write(WalletChanged::new(req.entity, before, wallet.coins));
+    }
+    for req in spend.read() {
+        let Ok(mut wallet) = q_wallets.get_mut(req.entity) else {
+            continue;
+        };
+        let before = wallet.coins;
+        if wallet.try_spend(req.amount) {
+            changed.write(WalletChanged::new(req.entity, before, wallet.coins));
+        } else {
+            rejected.write(SpendRejected {
+                entity: req.entity,
+                amount: req.amount,
+                balance: before,
+            });
+        }
+    }
+}
+
+/// FixedUpdate: load saved wallets.
+pub fn restore_wallets(
+    mut restores: MessageReader<RestoreWallet>,
+    mut q_wallets: Query<&mut Wallet>,
+    mut changed: MessageWriter<WalletChanged>,
+) {
+    for restore in restores.read() {
+        let Ok(mut wallet) = q_wallets.get_mut(restore.entity) else {
+            continue;
+        };
+        let before = wallet.coins;
+        *wallet = restore.wallet;
+        changed.write(WalletChanged {
+            restored: true,
+            ..WalletChanged::new(restore.entity, before, wallet.coins)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<EarnCoins>>();
+        world.init_resource::<Messages<SpendCoins>>();
+        world.init_resource::<Messages<WalletChanged>>();
+        world.init_resource::<Messages<SpendRejected>>();
+        world.init_resource::<Messages<RestoreWallet>>();
+        world
+    }
+
+    #[test]
+    fn enemy_kills_pay_a_bounty() {
+        let mut world = setup_world();
+        let player = world.spawn((Player, Wallet::new(0))).id();
+        let enemy = world.spawn(Enemy).id();
+
+        world.write_message(Died { entity: enemy });
+        world.write_message(Died { entity: player });
+        let _ = world.run_system_once(reward_kills);
+        let _ = world.run_system_once(apply_wallet_requests);
+
+        assert_eq!(world.get::<Wallet>(player).unwrap().coins, COINS_PER_KILL);
+        let changed: Vec<_> = world
+            .resource::<Messages<WalletChanged>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(changed, vec![WalletChanged::new(player, 0, COINS_PER_KILL)]);
+    }
+
+    #[test]
+    fn unaffordable_spend_is_rejected_and_restore_overwrites() {
+        let mut world = setup_world();
+        let e = world.spawn(Wallet::new(3)).id();
+
+        world.write_message(SpendCoins { entity: e, amount: 5 });
+        let _ = world.run_system_once(apply_wallet_requests);
+        assert_eq!(world.get::<Wallet>(e).unwrap().coins, 3);
+        assert_eq!(world.resource::<Messages<SpendRejected>>().len(), 1);
+
+        world.write_message(RestoreWallet {
+            entity: e,
+            wallet: Wallet::new(42),
+        });
+        let _ = world.run_system_once(restore_wallets);
+        assert_eq!(world.get::<Wallet>(e).unwrap().coins, 42);
+    }
+}
@@ -0,0 +1,182 @@
+// src/features/water/component.rs
+use bevy::prelude::*;
+
+/// Axis-aligned body of water around the entity's translation; the surface is
+/// the top face.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct WaterVolume {
+    pub half_extents: Vec3,
+}
+
+impl WaterVolume {
+    /// Submerged fraction (`0.0..=1.0`) of a box of `half_extents` at `center`,
+    /// for water centered at `water_center`. Boxes outside the volume's footprint
+    /// aren't submerged at all.
+    pub fn submerged_fraction(&self, water_center: Vec3, center: Vec3, half_extents: Vec3) -> f32 {
+        let offset = (center - water_center).abs();
+        let inside_footprint =
+            offset.x <= self.half_extents.x && offset.z <= self.half_extents.z;
+        if !inside_footprint || half_extents.y <= 0.0 {
+            return 0.0;
+        }
+        let surface = water_center.y + self.half_extents.y;
+        let floor = water_center.y - self.half_extents.y;
+        let bottom = (center.y - half_extents.y).max(floor);
+        let top = (center.y + half_extents.y).min(surface);
+        ((top - bottom) / (half_extents.y * 2.0)).clamp(0.0, 1.0)
+    }
+
+    /// How far below the surface `point` is (negative above it), or `None` outside
+    /// the volume's footprint or below its floor.
+    pub fn depth_at(&self, water_center: Vec3, point: Vec3) -> Option<f32> {
+        let offset = point - water_center;
+        let inside = offset.x.abs() <= self.half_extents.x
+            && offset.z.abs() <= self.half_extents.z
+            && offset.y >= -self.half_extents.y;
+        inside.then_some(self.half_extents.y - offset.y)
+    }
+}
+
+/// Diving and breathing tunables (world units / seconds).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct DiveSettings {
+    /// Seconds of air a full breath holds.
+    pub oxygen_secs: f32,
+    /// Seconds of air regained per second above water.
+    pub refill_per_sec: f32,
+    /// Damage per second once out of air, dealt in one hit per second.
+    pub drown_damage_per_sec: f32,
+    /// Height of the head above a diver's center (what has to stay above water).
+    pub head_height: f32,
+    /// Speed idle divers float back up at.
+    pub rise_speed: f32,
+    /// Vertical intent below this counts as idle (not diving or climbing).
+    pub input_threshold: f32,
+}
+
+impl Default for DiveSettings {
+    fn default() -> Self {
+        Self {
+            oxygen_secs: 20.0,
+            refill_per_sec: 5.0,
+            drown_damage_per_sec: 10.0,
+            head_height: 0.4,
+            rise_speed: 1.2,
+            input_threshold: 0.1,
+        }
+    }
+}
+
+/// Swims in `WaterVolume`s (players get one); vertical intent dives and surfaces.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Diver {
+    /// Inside a water volume at all (body touching the water).
+    pub in_water: bool,
+    /// Depth of the head below the surface; positive means submerged.
+    pub head_depth: f32,
+}
+
+impl Diver {
+    pub fn submerged(&self) -> bool {
+        self.in_water && self.head_depth > 0.0
+    }
+}
+
+/// Air left, in seconds.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Oxygen {
+    pub current: f32,
+    pub max: f32,
+    /// Time out of air since the last drowning hit.
+    pub drowning_secs: f32,
+}
+
+impl Oxygen {
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            drowning_secs: 0.0,
+        }
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            0.0
+        } else {
+            (self.current / self.max).clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.current >= self.max
+    }
+}
+
+/// Floats in `WaterVolume`s.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Buoyant {
+    /// Lift at full submersion, in multiples of gravity (`> 1.0` floats; a crate
+    /// with `2.0` settles half under water).
+    pub buoyancy: f32,
+    /// Velocity damping at full submersion (1/sec).
+    pub drag: f32,
+}
+
+impl Default for Buoyant {
+    fn default() -> Self {
+        Self {
+            buoyancy: 2.0,
+            drag: 1.5,
+        }
+    }
+}
+
+impl Buoyant {
+    /// Acceleration on a body under `gravity` moving at `velocity` with `fraction`
+    /// submerged.
+    pub fn acceleration(&self, gravity: f32, fraction: f32, velocity: Vec3) -> Vec3 {
+        Vec3::Y * gravity * self.buoyancy * fraction - velocity * self.drag * fraction
+    }
+}
+
+/// Water placed at startup: `(center, half_extents)`.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WaterSpawns(pub Vec<(Vec3, Vec3)>);
+
+impl Default for WaterSpawns {
+    fn default() -> Self {
+        // A raised pond behind the training ground (surface at y = 1.2).
+        Self(vec![(Vec3::new(0.0, 0.6, 9.0), Vec3::new(2.5, 0.6, 2.0))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submerged_fraction_tracks_depth_below_the_surface() {
+        let water = WaterVolume {
+            half_extents: Vec3::new(2.0, 1.0, 2.0),
+        };
+        let half = Vec3::splat(0.5);
+
+        assert_eq!(water.submerged_fraction(Vec3::ZERO, Vec3::ZERO, half), 1.0);
+        assert_eq!(water.submerged_fraction(Vec3::ZERO, Vec3::Y * 1.0, half), 0.5);
+        assert_eq!(water.submerged_fraction(Vec3::ZERO, Vec3::Y * 3.0, half), 0.0);
+        assert_eq!(water.submerged_fraction(Vec3::ZERO, Vec3::X * 5.0, half), 0.0);
+    }
+
+    #[test]
+    fn depth_is_measured_from_the_surface_inside_the_volume() {
+        let water = WaterVolume {
+            half_extents: Vec3::new(2.0, 1.0, 2.0),
+        };
+
+        assert_eq!(water.depth_at(Vec3::ZERO, Vec3::ZERO), Some(1.0));
+        assert_eq!(water.depth_at(Vec3::ZERO, Vec3::Y * 1.5), Some(-0.5));
+        assert_eq!(water.depth_at(Vec3::ZERO, Vec3::NEG_Y * 2.0), None, "below the floor");
+        assert_eq!(water.depth_at(Vec3::ZERO, Vec3::X * 3.0), None);
+    }
+}
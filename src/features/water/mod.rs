@@ -0,0 +1,67 @@
+// src/features/water/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, Tuning};
+use crate::features::health::damage::{DamageEvent, apply_damage};
+use crate::features::movement::systems::{apply_gravity, compute_velocity_from_input};
+
+pub mod component;
+pub mod systems;
+
+/// Water feature.
+///
+/// Scope:
+/// - `WaterVolume` boxes from `WaterSpawns` (surface = top face)
+/// - `Buoyant` bodies (props by default) get lift proportional to how deep they are
+///   submerged plus water drag, so crates sink in, bob and settle
+/// - diving: `Diver` bodies (players) swim down with "down" intent and float back
+///   up when idle; `Submerged` when the head goes under or comes up
+/// - `Oxygen` drains underwater and refills above it; out of air, drowning deals
+///   sourceless damage once a second
+/// - hooks: the HUD oxygen bar reads `Oxygen`; world sounds are muffled
+///   (`AudioMixer::muffle`) while the player is under
+///
+/// Design constraints:
+/// - buoyancy is an acceleration on `ExternalForce`, applied between the gravity and
+///   integration steps of the shared movement pipeline.
+/// - diving adjusts the input velocity, so it runs between input and integration.
+pub struct WaterPlugin;
+
+impl Plugin for WaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::WaterSpawns>();
+        app.init_resource::<component::DiveSettings>();
+        app.init_resource::<Tuning>();
+
+        app.add_message::<systems::Submerged>();
+        app.add_message::<DamageEvent>();
+
+        app.add_systems(Startup, systems::spawn_water);
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_prop_buoyancy,
+                systems::grant_divers,
+                systems::breathe.before(apply_damage),
+            )
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (systems::track_divers, systems::float_divers)
+                .chain()
+                .after(compute_velocity_from_input)
+                .in_set(AppSet::ApplyForces),
+        );
+        app.add_systems(
+            Update,
+            systems::muffle_audio_underwater.after(AppSet::Input),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::apply_buoyancy
+                .after(apply_gravity)
+                .in_set(AppSet::ApplyForces),
+        );
+    }
+}
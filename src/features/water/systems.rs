@@ -0,0 +1,353 @@
+// src/features/water/systems.rs
+use bevy::prelude::*;
+
+use super::component::{Buoyant, DiveSettings, Diver, Oxygen, WaterSpawns, WaterVolume};
+use crate::app::Tuning;
+use crate::features::audio::component::{AudioMixer, AudioSettings};
+use crate::features::collision::component::Collider;
+use crate::features::health::damage::DamageEvent;
+use crate::features::movement::component::{ExternalForce, Gravity, MoveInput, Velocity};
+use crate::features::player::component::Player;
+use crate::features::player::death::Dead;
+use crate::features::props::component::Prop;
+
+/// A diver's head went under (`underwater`) or came back up.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Submerged {
+    pub entity: Entity,
+    pub underwater: bool,
+}
+
+/// Startup: spawn the `WaterSpawns` volumes (translucent boxes).
+pub fn spawn_water(
+    mut commands: Commands,
+    spawns: Res<WaterSpawns>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.15, 0.35, 0.6, 0.6),
+        alpha_mode: AlphaMode::Blend,
+        perceptual_roughness: 0.1,
+        ..default()
+    });
+    for &(center, half_extents) in &spawns.0 {
+        commands.spawn((
+            WaterVolume { half_extents },
+            Mesh3d(meshes.add(Cuboid::from_size(half_extents * 2.0))),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(center),
+            Name::new("Water"),
+        ));
+    }
+}
+
+/// FixedUpdate: props float.
+pub fn grant_prop_buoyancy(
+    mut commands: Commands,
+    q_new: Query<Entity, (Added<Prop>, Without<Buoyant>)>,
+) {
+    for entity in &q_new {
+        commands.entity(entity).insert(Buoyant::default());
+    }
+}
+
+/// FixedUpdate: lift proportional to submerged depth (against the body's own
+/// `Gravity`, `Tuning::gravity` without one), plus water drag, as acceleration on
+/// `ExternalForce`.
+///
+/// Overlapping volumes don't stack: the deepest one wins.
+pub fn apply_buoyancy(
+    tuning: Res<Tuning>,
+    q_water: Query<(&WaterVolume, &GlobalTransform)>,
+    mut q_bodies: Query<(&Buoyant, &Collider, &Transform, Option<&Gravity>, &mut ExternalForce)>,
+) {
+    for (buoyant, collider, transform, gravity, mut external) in &mut q_bodies {
+        let fraction = q_water
+            .iter()
+            .map(|(water, water_tr)| {
+                water.submerged_fraction(
+                    water_tr.translation(),
+                    transform.translation,
+                    collider.half_extents,
+                )
+            })
+            .fold(0.0_f32, f32::max);
+        if fraction > 0.0 {
+            let gravity = gravity.map_or(tuning.gravity, |g| g.0);
+            let accel = buoyant.acceleration(gravity, fraction, external.velocity);
+            external.apply_acceleration(accel);
+        }
+    }
+}
+
+/// FixedUpdate: players swim and breathe.
+pub fn grant_divers(
+    mut commands: Commands,
+    settings: Res<DiveSettings>,
+    q_players: Query<Entity, (Added<Player>, Without<Diver>)>,
+) {
+    for entity in &q_players {
+        commands
+            .entity(entity)
+            .insert((Diver::default(), Oxygen::new(settings.oxygen_secs)));
+    }
+}
+
+/// FixedUpdate: where each diver is relative to the water; `Submerged` when the
+/// head goes under or comes up.
+///
+/// A diver is in the water once its center is below a surface; overlapping
+/// volumes don't stack (the deepest one wins).
+pub fn track_divers(
+    settings: Res<DiveSettings>,
+    q_water: Query<(&WaterVolume, &GlobalTransform)>,
+    mut q_divers: Query<(Entity, &Transform, &mut Diver)>,
+    mut submerged: MessageWriter<Submerged>,
+) {
+    for (entity, transform, mut diver) in &mut q_divers {
+        let depth = q_water
+            .iter()
+            .filter_map(|(water, water_tr)| {
+                water.depth_at(water_tr.translation(), transform.translation)
+            })
+            .fold(None, |deepest: Option<f32>, depth| {
+                Some(deepest.map_or(depth, |d| d.max(depth)))
+            });
+
+        let was_under = diver.submerged();
+        let next = match depth {
+            Some(depth) if depth >= 0.0 => Diver {
+                in_water: true,
+                head_depth: depth - settings.head_height,
+            },
+            _ => Diver::default(),
+        };
+        if *diver != next {
+            *diver = next;
+        }
+        if diver.submerged() != was_under {
+            submerged.write(Submerged {
+                entity,
+                underwater: diver.submerged(),
+            });
+        }
+    }
+}
+
+/// FixedUpdate (after input velocity): divers without vertical intent float back
+/// up until their head breaks the surface; diving is holding "down".
+pub fn float_divers(
+    settings: Res<DiveSettings>,
+    time: Res<Time<Fixed>>,
+    mut q_divers: Query<(&Diver, &MoveInput, &mut Velocity)>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    for (diver, input, mut velocity) in &mut q_divers {
+        if !diver.submerged() || input.0.y.abs() > settings.input_threshold {
+            continue;
+        }
+        let rise = settings.rise_speed.min(diver.head_depth / dt);
+        velocity.0.y = velocity.0.y.max(rise);
+    }
+}
+
+/// FixedUpdate: air runs out underwater and refills above it; out of air, the
+/// diver takes sourceless drowning damage once a second.
+pub fn breathe(
+    settings: Res<DiveSettings>,
+    time: Res<Time<Fixed>>,
+    mut q_divers: Query<(Entity, &Diver, &mut Oxygen), Without<Dead>>,
+    mut damage: MessageWriter<DamageEvent>,
+) {
+    let dt = time.delta_secs();
+    for (entity, diver, mut oxygen) in &mut q_divers {
+        if !diver.submerged() {
+            if !oxygen.is_full() || oxygen.drowning_secs > 0.0 {
+                oxygen.current = (oxygen.current + settings.refill_per_sec * dt).min(oxygen.max);
+                oxygen.drowning_secs = 0.0;
+            }
+            continue;
+        }
+
+        oxygen.current = (oxygen.current - dt).max(0.0);
+        if oxygen.current > 0.0 {
+            continue;
+        }
+        oxygen.drowning_secs += dt;
+        if oxygen.drowning_secs >= 1.0 {
+            oxygen.drowning_secs -= 1.0;
+            damage.write(DamageEvent::new(entity, settings.drown_damage_per_sec));
+        }
+    }
+}
+
+/// Update: world sounds are muffled while the player's head is underwater.
+pub fn muffle_audio_underwater(
+    settings: Res<AudioSettings>,
+    mut mixer: ResMut<AudioMixer>,
+    mut submerged: MessageReader<Submerged>,
+    q_players: Query<(), With<Player>>,
+) {
+    let Some(underwater) = submerged
+        .read()
+        .filter(|ev| q_players.contains(ev.entity))
+        .last()
+        .map(|ev| ev.underwater)
+    else {
+        return;
+    };
+    mixer.muffle = if underwater {
+        settings.underwater_muffle
+    } else {
+        0.0
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::movement::systems::{apply_gravity, integrate_velocity};
+    use crate::features::props::systems::settle_props;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    #[test]
+    fn crate_dropped_into_water_bobs_then_floats_half_submerged() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<Tuning>();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.spawn((
+            WaterVolume {
+                half_extents: Vec3::new(3.0, 1.0, 3.0),
+            },
+            GlobalTransform::from_translation(Vec3::Y),
+        ));
+        let prop = world
+            .spawn((
+                Prop,
+                Buoyant::default(),
+                Transform::from_xyz(0.0, 4.0, 0.0),
+            ))
+            .id();
+
+        let mut lowest = f32::MAX;
+        for _ in 0..1200 {
+            let _ = world.run_system_once(apply_gravity);
+            let _ = world.run_system_once(apply_buoyancy);
+            let _ = world.run_system_once(integrate_velocity);
+            let _ = world.run_system_once(settle_props);
+            lowest = lowest.min(world.get::<Transform>(prop).unwrap().translation.y);
+        }
+
+        // Surface at y = 2; half-submerged crate of half height 0.5 sits centered on it.
+        let y = world.get::<Transform>(prop).unwrap().translation.y;
+        assert!((y - 2.0).abs() < 0.05, "settled at {y}");
+        assert!(lowest < 1.9, "should dip below its rest height (bob), lowest {lowest}");
+    }
+
+    /// Pond with its surface at y = 2 and floor at y = -2, plus a diver at `y`.
+    fn setup_dive(y: f32) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<DiveSettings>();
+        world.init_resource::<Messages<Submerged>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(0.5));
+        world.insert_resource(time);
+        world.spawn((
+            WaterVolume {
+                half_extents: Vec3::new(3.0, 2.0, 3.0),
+            },
+            GlobalTransform::IDENTITY,
+        ));
+        let diver = world
+            .spawn((
+                Diver::default(),
+                Oxygen::new(1.0),
+                MoveInput(Vec3::ZERO),
+                Velocity::default(),
+                Transform::from_xyz(0.0, y, 0.0),
+            ))
+            .id();
+        (world, diver)
+    }
+
+    #[test]
+    fn heads_going_under_are_announced_once() {
+        let (mut world, diver) = setup_dive(0.0);
+
+        let _ = world.run_system_once(track_divers);
+        let _ = world.run_system_once(track_divers);
+
+        let state = *world.get::<Diver>(diver).unwrap();
+        assert!(state.in_water);
+        assert!((state.head_depth - 1.6).abs() < 1e-5);
+        let messages: Vec<Submerged> = world
+            .resource::<Messages<Submerged>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(
+            messages,
+            vec![Submerged {
+                entity: diver,
+                underwater: true
+            }]
+        );
+
+        // Treading water at the surface: head above it.
+        world.get_mut::<Transform>(diver).unwrap().translation.y = 1.8;
+        let _ = world.run_system_once(track_divers);
+        let state = *world.get::<Diver>(diver).unwrap();
+        assert!(state.in_water && !state.submerged());
+        assert_eq!(world.resource::<Messages<Submerged>>().len(), 2);
+    }
+
+    #[test]
+    fn idle_divers_float_up_but_diving_holds_depth() {
+        let (mut world, diver) = setup_dive(0.0);
+        let _ = world.run_system_once(track_divers);
+
+        let _ = world.run_system_once(float_divers);
+        assert_eq!(world.get::<Velocity>(diver).unwrap().0.y, 1.2);
+
+        world.get_mut::<Velocity>(diver).unwrap().0 = Vec3::NEG_Y * 2.0;
+        world.get_mut::<MoveInput>(diver).unwrap().0 = Vec3::NEG_Y;
+        let _ = world.run_system_once(float_divers);
+        assert_eq!(world.get::<Velocity>(diver).unwrap().0.y, -2.0);
+    }
+
+    #[test]
+    fn running_out_of_air_drowns_until_surfacing() {
+        let (mut world, diver) = setup_dive(0.0);
+        let _ = world.run_system_once(track_divers);
+
+        // 1 s of air, 0.5 s per run: empty after two, first hit two runs later.
+        for _ in 0..4 {
+            let _ = world.run_system_once(breathe);
+        }
+        assert_eq!(world.get::<Oxygen>(diver).unwrap().current, 0.0);
+        let damage: Vec<DamageEvent> = world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(damage, vec![DamageEvent::new(diver, 10.0)]);
+
+        world.get_mut::<Transform>(diver).unwrap().translation.y = 2.5;
+        let _ = world.run_system_once(track_divers);
+        let _ = world.run_system_once(breathe);
+        let oxygen = *world.get::<Oxygen>(diver).unwrap();
+        assert_eq!(oxygen.current, 1.0, "refilled");
+        assert_eq!(oxygen.drowning_secs, 0.0);
+    }
+}
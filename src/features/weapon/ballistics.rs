@@ -0,0 +1,672 @@
+// src/features/weapon/ballistics.rs
+use bevy::prelude::*;
+
+use super::component::{Projectile, Tracer, WeaponDelivery, WeaponLibrary};
+use super::firing::WeaponFired;
+use crate::features::collision::component::Collider;
+use crate::features::collision::query::{CollisionWorld, ray_aabb_exit};
+use crate::features::explosion::systems::Explosion;
+use crate::features::health::component::Health;
+use crate::features::health::damage::{DamageEvent, IncomingAttack};
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::movement::component::Velocity;
+use crate::features::surface::component::SurfaceMaterial;
+use crate::features::surface::systems::SurfaceHit;
+
+/// Height above the shooter's origin that shots leave from.
+pub const MUZZLE_HEIGHT: f32 = 0.2;
+
+/// Radius swept by projectiles when looking for hits.
+pub const PROJECTILE_RADIUS: f32 = 0.1;
+
+/// How long a hitscan tracer stays visible (seconds).
+pub const TRACER_SECS: f32 = 0.08;
+
+/// Most contacts (ricochets + penetrations) one projectile resolves per tick.
+const MAX_CONTACTS_PER_TICK: usize = 4;
+
+/// A shot connected, whichever way it travelled (hitscan ray or projectile).
+///
+/// The single place both deliveries meet: `apply_weapon_hits` turns it into damage
+/// and a surface impact.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct WeaponHit {
+    pub source: Entity,
+    pub target: Entity,
+    pub damage: f32,
+    pub position: Vec3,
+    pub normal: Vec3,
+    /// Direction the shot travelled (normalized).
+    pub direction: Vec3,
+}
+
+/// Shared visuals for projectiles in flight.
+///
+/// The mesh is the library's unit sphere; projectiles scale it to `PROJECTILE_RADIUS`.
+#[derive(Resource, Debug, Clone)]
+pub struct ProjectileAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Startup: projectile mesh and material.
+pub fn setup_projectile_assets(
+    mut commands: Commands,
+    library: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(ProjectileAssets {
+        mesh: library.get(MeshPrimitive::Sphere),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.7, 0.3),
+            emissive: LinearRgba::rgb(4.0, 2.0, 0.5),
+            ..default()
+        }),
+    });
+}
+
+/// Muzzle position and shot direction for a shooter: straight ahead of its body.
+fn muzzle(transform: &Transform) -> (Vec3, Vec3) {
+    (
+        transform.translation + Vec3::Y * MUZZLE_HEIGHT,
+        transform.forward().as_vec3(),
+    )
+}
+
+/// Launch position and velocity of a projectile: from the muzzle, `pitch_deg`
+/// above the shooter's facing.
+pub fn projectile_launch(transform: &Transform, speed: f32, pitch_deg: f32) -> (Vec3, Vec3) {
+    let (origin, direction) = muzzle(transform);
+    let pitch = Quat::from_axis_angle(transform.right().as_vec3(), pitch_deg.to_radians());
+    (origin, pitch * direction * speed)
+}
+
+/// A projectile's velocity after one tick of gravity.
+///
+/// Applied before the tick's sweep and integration (the flight and its preview
+/// both step through this).
+pub fn fall(velocity: Vec3, gravity: f32, dt: f32) -> Vec3 {
+    velocity - Vec3::Y * gravity * dt
+}
+
+/// FixedUpdate (after firing): resolve each `WeaponFired` by its weapon's delivery.
+///
+/// - Hitscan: instant ray cast; a hit becomes a `WeaponHit`, and tracer weapons
+///   leave a `Tracer` from muzzle to impact (or full range on a miss).
+/// - Projectile: spawns a `Projectile` mover; `sweep_projectiles` resolves it.
+pub fn launch_shots(
+    mut commands: Commands,
+    library: Res<WeaponLibrary>,
+    assets: Option<Res<ProjectileAssets>>,
+    mut fired: MessageReader<WeaponFired>,
+    q_shooters: Query<&Transform>,
+    collision: CollisionWorld,
+    mut hits: MessageWriter<WeaponHit>,
+) {
+    for ev in fired.read() {
+        let (Some(def), Ok(transform)) = (library.get(&ev.weapon_id), q_shooters.get(ev.entity))
+        else {
+            continue;
+        };
+        let (origin, direction) = muzzle(transform);
+
+        match def.delivery {
+            WeaponDelivery::Hitscan { range, tracer } => {
+                let hit = collision.ray_cast(origin, direction, range, &[ev.entity]);
+                let end = origin + direction * hit.map_or(range, |hit| hit.distance);
+                if let Some(hit) = hit {
+                    hits.write(WeaponHit {
+                        source: ev.entity,
+                        target: hit.entity,
+                        damage: def.damage,
+                        position: end,
+                        normal: hit.normal,
+                        direction,
+                    });
+                }
+                if tracer {
+                    commands.spawn((
+                        Tracer {
+                            from: origin,
+                            to: end,
+                            remaining: TRACER_SECS,
+                        },
+                        Name::new("Tracer"),
+                    ));
+                }
+            }
+            WeaponDelivery::Projectile {
+                speed,
+                lifetime_secs,
+                bounces,
+                bounce_damping,
+                penetration,
+                explosion,
+                gravity,
+                pitch_deg,
+            } => {
+                let (origin, velocity) = projectile_launch(transform, speed, pitch_deg);
+                let mut projectile = commands.spawn((
+                    Projectile {
+                        bounces_left: bounces,
+                        bounce_damping,
+                        penetration,
+                        explosion,
+                        gravity,
+                        ..Projectile::new(ev.entity, def.damage, lifetime_secs)
+                    },
+                    Velocity(velocity),
+                    Transform::from_translation(origin).with_scale(Vec3::splat(PROJECTILE_RADIUS)),
+                    Name::new("Projectile"),
+                ));
+                if let Some(assets) = &assets {
+                    projectile.insert((
+                        Mesh3d(assets.mesh.clone()),
+                        MeshMaterial3d(assets.material.clone()),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// What a projectile does at a contact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ContactResponse {
+    /// Punch through `thickness` of material, spending `cost` penetration.
+    Penetrate { thickness: f32, cost: f32 },
+    Ricochet,
+    Stop,
+}
+
+/// Penetrate if the material along the path (`thickness` x resistance) fits the
+/// remaining budget, else ricochet while bounces are left; damageable targets
+/// (`Health`) always stop the shot.
+fn contact_response(
+    projectile: &Projectile,
+    thickness: f32,
+    material: SurfaceMaterial,
+    damageable: bool,
+) -> ContactResponse {
+    if damageable {
+        return ContactResponse::Stop;
+    }
+    let cost = thickness * material.penetration_resistance();
+    if projectile.penetration > 0.0 && cost <= projectile.penetration {
+        ContactResponse::Penetrate { thickness, cost }
+    } else if projectile.bounces_left > 0 {
+        ContactResponse::Ricochet
+    } else {
+        ContactResponse::Stop
+    }
+}
+
+/// FixedUpdate (before integration): sweep each projectile along this tick's path.
+///
+/// - Gravity bends the velocity first (`fall`); the sweep follows the bent path.
+/// - Every contact becomes a `WeaponHit`; the projectile then penetrates, ricochets
+///   (velocity reflected about the hit normal and scaled by `bounce_damping`, as is
+///   the rest of the tick's travel) or ends (see `contact_response`).
+/// - Colliders without a `SurfaceMaterial` count as the default (stone).
+/// - After a contact the projectile is placed so integration lands it exactly where
+///   the sweep ended; paths without contacts are left to integration untouched.
+/// - Running out of lifetime ends it too; explosive projectiles blow up where they end.
+pub fn sweep_projectiles(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    collision: CollisionWorld,
+    q_solids: Query<(&Collider, &GlobalTransform, Option<&SurfaceMaterial>, Has<Health>)>,
+    mut q_projectiles: Query<(Entity, &mut Projectile, &mut Transform, &mut Velocity)>,
+    mut hits: MessageWriter<WeaponHit>,
+    mut explosions: MessageWriter<Explosion>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut projectile, mut transform, mut velocity) in &mut q_projectiles {
+        projectile.lifetime -= dt;
+
+        let mut position = transform.translation;
+        let mut vel = fall(velocity.0, projectile.gravity, dt);
+        let mut remaining = vel.length() * dt;
+        let mut ignore = vec![entity, projectile.source];
+        let mut contacts = 0;
+        let mut stopped = false;
+
+        while !stopped {
+            let direction = vel.normalize_or_zero();
+            let Some(hit) =
+                collision.sphere_cast(position, direction, remaining, PROJECTILE_RADIUS, &ignore)
+            else {
+                break;
+            };
+            contacts += 1;
+            position += direction * hit.distance;
+            remaining -= hit.distance;
+            hits.write(WeaponHit {
+                source: projectile.source,
+                target: hit.entity,
+                damage: projectile.damage,
+                position,
+                normal: hit.normal,
+                direction,
+            });
+
+            let response = q_solids.get(hit.entity).map_or(
+                ContactResponse::Stop,
+                |(collider, global, material, damageable)| {
+                    let bounds = collider.aabb(global.translation()).expanded(PROJECTILE_RADIUS);
+                    contact_response(
+                        &projectile,
+                        ray_aabb_exit(position, direction, &bounds),
+                        material.copied().unwrap_or_default(),
+                        damageable,
+                    )
+                },
+            );
+            match response {
+                ContactResponse::Penetrate { thickness, cost } => {
+                    projectile.penetration -= cost;
+                    position += direction * thickness;
+                    remaining = (remaining - thickness).max(0.0);
+                }
+                ContactResponse::Ricochet => {
+                    projectile.bounces_left -= 1;
+                    vel = (vel - 2.0 * vel.dot(hit.normal) * hit.normal) * projectile.bounce_damping;
+                    remaining *= projectile.bounce_damping;
+                }
+                ContactResponse::Stop => stopped = true,
+            }
+            ignore.push(hit.entity);
+            stopped |= contacts >= MAX_CONTACTS_PER_TICK;
+        }
+
+        if stopped || projectile.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            if let Some(def) = projectile.explosion {
+                explosions.write(Explosion {
+                    source: Some(projectile.source),
+                    position,
+                    def,
+                });
+            }
+        } else {
+            if contacts > 0 {
+                position += vel.normalize_or_zero() * remaining;
+                transform.translation = position - vel * dt;
+            }
+            if velocity.0 != vel {
+                velocity.0 = vel;
+            }
+        }
+    }
+}
+
+/// FixedUpdate: `WeaponHit` -> `DamageEvent` (bullets can be blocked, not parried)
+/// and a `SurfaceHit` impact.
+pub fn apply_weapon_hits(
+    mut hits: MessageReader<WeaponHit>,
+    mut damage: MessageWriter<DamageEvent>,
+    mut impacts: MessageWriter<SurfaceHit>,
+) {
+    for hit in hits.read() {
+        damage.write(DamageEvent::from_attack(
+            hit.target,
+            hit.damage,
+            IncomingAttack {
+                source: hit.source,
+                direction: hit.direction,
+                parryable: false,
+            },
+        ));
+        impacts.write(SurfaceHit {
+            source: hit.source,
+            target: hit.target,
+            position: hit.position,
+            normal: hit.normal,
+        });
+    }
+}
+
+/// Update: draw tracers and remove them once they've faded.
+pub fn draw_tracers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut q_tracers: Query<(Entity, &mut Tracer)>,
+) {
+    for (entity, mut tracer) in &mut q_tracers {
+        let alpha = (tracer.remaining / TRACER_SECS).clamp(0.0, 1.0);
+        gizmos.line(tracer.from, tracer.to, Color::srgba(1.0, 0.9, 0.6, alpha));
+        tracer.remaining -= time.delta_secs();
+        if tracer.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::explosion::component::ExplosionDef;
+    use crate::features::meshes::systems::build_mesh_library;
+    use crate::features::movement::systems::integrate_velocity;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.insert_resource(WeaponLibrary::default());
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(0.1));
+        world.insert_resource(fixed);
+        world.init_resource::<Messages<WeaponFired>>();
+        world.init_resource::<Messages<WeaponHit>>();
+        world.init_resource::<Messages<Explosion>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<SurfaceHit>>();
+        world
+    }
+
+    /// Shooter at the origin facing -Z, and a box 5 units ahead at muzzle height.
+    fn shooter_and_target(world: &mut World) -> (Entity, Entity) {
+        let shooter = world
+            .spawn((
+                Transform::default(),
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::default(),
+            ))
+            .id();
+        let target = world
+            .spawn((
+                Collider::cuboid(Vec3::splat(0.5)),
+                GlobalTransform::from_translation(Vec3::new(0.0, MUZZLE_HEIGHT, -5.0)),
+                Health::new(100.0),
+            ))
+            .id();
+        (shooter, target)
+    }
+
+    /// Axis-aligned slab with its center at `z` and `half_depth` along Z.
+    fn spawn_slab(world: &mut World, z: f32, half_depth: f32, material: SurfaceMaterial) -> Entity {
+        world
+            .spawn((
+                Collider::cuboid(Vec3::new(2.0, 2.0, half_depth)),
+                GlobalTransform::from_translation(Vec3::Z * z),
+                material,
+            ))
+            .id()
+    }
+
+    /// Projectile at the origin flying -Z at `speed`.
+    fn spawn_projectile(world: &mut World, speed: f32, configure: impl FnOnce(&mut Projectile)) -> Entity {
+        let mut projectile = Projectile::new(Entity::PLACEHOLDER, 5.0, 10.0);
+        configure(&mut projectile);
+        world
+            .spawn((projectile, Velocity(Vec3::NEG_Z * speed), Transform::default()))
+            .id()
+    }
+
+    /// One fixed tick of projectile flight: sweep, then integrate.
+    fn step(world: &mut World) {
+        let _ = world.run_system_once(sweep_projectiles);
+        let _ = world.run_system_once(integrate_velocity);
+    }
+
+    fn z_of(world: &World, entity: Entity) -> f32 {
+        world.get::<Transform>(entity).unwrap().translation.z
+    }
+
+    fn fire(world: &mut World, entity: Entity, weapon_ids: &[&str]) {
+        for id in weapon_ids {
+            world.write_message(WeaponFired {
+                entity,
+                weapon_id: id.to_string(),
+            });
+        }
+        let _ = world.run_system_once(launch_shots);
+    }
+
+    fn hits(world: &World) -> Vec<WeaponHit> {
+        world
+            .resource::<Messages<WeaponHit>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn hitscan_hits_instantly_and_tracer_weapons_leave_a_tracer() {
+        let mut world = setup_world();
+        let (shooter, target) = shooter_and_target(&mut world);
+
+        fire(&mut world, shooter, &["pistol", "rifle"]);
+
+        let hits = hits(&world);
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[0].source, hits[0].target), (shooter, target));
+        assert_eq!(hits[0].position, Vec3::new(0.0, MUZZLE_HEIGHT, -4.5));
+        assert_eq!(hits[0].normal, Vec3::Z);
+        assert_eq!(world.query::<&Projectile>().iter(&world).count(), 0);
+
+        // Only the rifle draws a tracer; it ends on the impact.
+        let tracers: Vec<Tracer> = world.query::<&Tracer>().iter(&world).copied().collect();
+        assert_eq!(tracers.len(), 1);
+        assert_eq!(tracers[0].to, hits[1].position);
+    }
+
+    #[test]
+    fn projectiles_travel_and_hit_later() {
+        let mut world = setup_world();
+        let (shooter, target) = shooter_and_target(&mut world);
+
+        fire(&mut world, shooter, &["launcher"]);
+        assert!(hits(&world).is_empty(), "no instant hit");
+        let projectile = world.query_filtered::<Entity, With<Projectile>>().single(&world).unwrap();
+        assert_eq!(world.get::<Velocity>(projectile).unwrap().0, Vec3::NEG_Z * 25.0);
+
+        // 25 units/sec * 0.1s = 2.5 units per tick: out of reach from the muzzle...
+        let _ = world.run_system_once(sweep_projectiles);
+        assert!(hits(&world).is_empty());
+        // ...but in reach 2.5 units further on.
+        world.get_mut::<Transform>(projectile).unwrap().translation.z = -2.5;
+        let _ = world.run_system_once(sweep_projectiles);
+
+        let hits = hits(&world);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].source, hits[0].target), (shooter, target));
+        assert!(world.get_entity(projectile).is_err(), "a hit ends the projectile");
+    }
+
+    #[test]
+    fn projectile_spawns_reuse_the_shared_mesh() {
+        let mut world = setup_world();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        let _ = world.run_system_once(build_mesh_library);
+        let _ = world.run_system_once(setup_projectile_assets);
+        let (shooter, _) = shooter_and_target(&mut world);
+        let before = world.resource::<Assets<Mesh>>().len();
+
+        let shots = vec!["launcher"; 1000];
+        fire(&mut world, shooter, &shots);
+
+        let sphere = world.resource::<MeshLibrary>().get(MeshPrimitive::Sphere);
+        let meshes: Vec<Handle<Mesh>> = world
+            .query_filtered::<&Mesh3d, With<Projectile>>()
+            .iter(&world)
+            .map(|mesh| mesh.0.clone())
+            .collect();
+        assert_eq!(meshes.len(), 1000);
+        assert!(meshes.iter().all(|mesh| *mesh == sphere));
+        assert_eq!(world.resource::<Assets<Mesh>>().len(), before, "no new mesh assets");
+    }
+
+    #[test]
+    fn projectiles_expire_after_their_lifetime() {
+        let mut world = setup_world();
+        let projectile = world
+            .spawn((
+                Projectile::new(Entity::PLACEHOLDER, 1.0, 0.05),
+                Velocity(Vec3::NEG_Z),
+                Transform::default(),
+            ))
+            .id();
+
+        let _ = world.run_system_once(sweep_projectiles);
+
+        assert!(world.get_entity(projectile).is_err());
+    }
+
+    #[test]
+    fn projectiles_at_extreme_speed_still_hit_paper_thin_walls() {
+        for speed in [1_000.0, 100_000.0, 10_000_000.0] {
+            let mut world = setup_world();
+            // 2mm thick, several ticks out at the slowest speed.
+            let wall = spawn_slab(&mut world, -250.0, 0.001, SurfaceMaterial::Stone);
+            let projectile = spawn_projectile(&mut world, speed, |_| {});
+
+            for _ in 0..5 {
+                if world.get_entity(projectile).is_err() {
+                    break;
+                }
+                step(&mut world);
+            }
+
+            assert!(world.get_entity(projectile).is_err(), "{speed} u/s: stopped by the wall");
+            let hits = hits(&world);
+            assert_eq!(hits.len(), 1, "{speed} u/s");
+            assert_eq!(hits[0].target, wall);
+            assert!(
+                (hits[0].position.z - (-249.999 + PROJECTILE_RADIUS)).abs() < 1e-2,
+                "{speed} u/s: hit on the near face, got {}",
+                hits[0].position
+            );
+        }
+    }
+
+    #[test]
+    fn ricochets_reflect_about_the_normal_and_lose_speed() {
+        let mut world = setup_world();
+        // Face at z = -2.5; the projectile's radius touches it at z = -2.4.
+        let wall = spawn_slab(&mut world, -3.0, 0.5, SurfaceMaterial::Stone);
+        let projectile = spawn_projectile(&mut world, 10.0, |p| {
+            p.bounces_left = 1;
+            p.bounce_damping = 0.5;
+        });
+
+        // 1 unit per tick: -1, -2, then contact at -2.4 with 0.6 left, halved.
+        step(&mut world);
+        step(&mut world);
+        assert!((z_of(&world, projectile) + 2.0).abs() < 1e-5);
+        step(&mut world);
+
+        assert!((z_of(&world, projectile) + 2.1).abs() < 1e-5);
+        assert_eq!(world.get::<Velocity>(projectile).unwrap().0, Vec3::Z * 5.0);
+        assert_eq!(world.get::<Projectile>(projectile).unwrap().bounces_left, 0);
+        let hits = hits(&world);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].target, hits[0].normal), (wall, Vec3::Z));
+        assert!((hits[0].position.z + 2.4).abs() < 1e-5);
+
+        // Then plain flight away from the wall.
+        step(&mut world);
+        assert!((z_of(&world, projectile) + 1.6).abs() < 1e-5);
+    }
+
+    #[test]
+    fn penetration_passes_thin_weak_material_but_not_stone() {
+        let mut world = setup_world();
+        // 0.1 thick wood (0.3 along the path with the projectile's radius), then a wall.
+        let plank = spawn_slab(&mut world, -1.0, 0.05, SurfaceMaterial::Wood);
+        let wall = spawn_slab(&mut world, -2.5, 0.5, SurfaceMaterial::Stone);
+        let piercing = spawn_projectile(&mut world, 30.0, |p| p.penetration = 0.5);
+
+        step(&mut world);
+
+        let targets: Vec<Entity> = hits(&world).iter().map(|hit| hit.target).collect();
+        assert_eq!(targets, [plank, wall]);
+        assert!(world.get_entity(piercing).is_err(), "stone stops it");
+
+        // Not enough budget for the plank: stops there.
+        let mut world = setup_world();
+        let plank = spawn_slab(&mut world, -1.0, 0.05, SurfaceMaterial::Wood);
+        let blunt = spawn_projectile(&mut world, 30.0, |p| p.penetration = 0.2);
+        step(&mut world);
+        let targets: Vec<Entity> = hits(&world).iter().map(|hit| hit.target).collect();
+        assert_eq!(targets, [plank]);
+        assert!(world.get_entity(blunt).is_err());
+    }
+
+    #[test]
+    fn damageable_targets_stop_even_bouncy_piercing_shots() {
+        let mut world = setup_world();
+        let target = spawn_slab(&mut world, -1.0, 0.05, SurfaceMaterial::Grass);
+        world.entity_mut(target).insert(Health::new(10.0));
+        let projectile = spawn_projectile(&mut world, 30.0, |p| {
+            p.bounces_left = 3;
+            p.bounce_damping = 1.0;
+            p.penetration = 10.0;
+        });
+
+        step(&mut world);
+
+        assert_eq!(hits(&world).len(), 1);
+        assert!(world.get_entity(projectile).is_err());
+    }
+
+    #[test]
+    fn explosive_projectiles_blow_up_where_they_stop() {
+        let mut world = setup_world();
+        let wall = spawn_slab(&mut world, -3.0, 0.5, SurfaceMaterial::Stone);
+        let blast = ExplosionDef {
+            radius: 3.0,
+            damage: 20.0,
+            knockback: 0.0,
+        };
+        let projectile = spawn_projectile(&mut world, 30.0, |p| p.explosion = Some(blast));
+
+        step(&mut world);
+
+        assert!(world.get_entity(projectile).is_err());
+        assert_eq!(hits(&world)[0].target, wall);
+        let explosions: Vec<Explosion> = world
+            .resource::<Messages<Explosion>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(explosions.len(), 1);
+        assert_eq!(explosions[0].source, Some(Entity::PLACEHOLDER));
+        assert!((explosions[0].position.z + 2.4).abs() < 1e-5);
+        assert_eq!(explosions[0].def, blast);
+    }
+
+    #[test]
+    fn weapon_hits_deal_unparryable_damage_and_impact_the_surface() {
+        let mut world = setup_world();
+        let (source, target) = (world.spawn_empty().id(), world.spawn_empty().id());
+        world.write_message(WeaponHit {
+            source,
+            target,
+            damage: 12.0,
+            position: Vec3::ONE,
+            normal: Vec3::Z,
+            direction: Vec3::NEG_Z,
+        });
+
+        let _ = world.run_system_once(apply_weapon_hits);
+
+        let damage: Vec<_> = world
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(damage.len(), 1);
+        assert_eq!((damage[0].target, damage[0].amount), (target, 12.0));
+        assert!(!damage[0].attack.unwrap().parryable);
+        assert_eq!(world.resource::<Messages<SurfaceHit>>().len(), 1);
+    }
+}
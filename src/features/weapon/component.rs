@@ -0,0 +1,397 @@
+// src/features/weapon/component.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::features::explosion::component::ExplosionDef;
+
+/// How a weapon delivers its damage.
+///
+/// Both variants resolve into the same `WeaponHit` (damage + surface impact); only
+/// the travel differs.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum WeaponDelivery {
+    /// Instant ray up to `range` world units, optionally drawing a brief tracer line.
+    Hitscan {
+        range: f32,
+        #[serde(default)]
+        tracer: bool,
+    },
+    /// Simulated projectile: flies at `speed` and expires after `lifetime_secs`.
+    ///
+    /// On contact it punches through colliders whose thickness times material
+    /// resistance fits in its remaining `penetration`, otherwise ricochets off world
+    /// geometry while it has `bounces` left (keeping `bounce_damping` of its speed),
+    /// otherwise stops. Anything with `Health` always stops it. With an `explosion`
+    /// it blows up wherever it ends (stopped or expired).
+    ///
+    /// Lobbed / thrown projectiles leave `pitch_deg` above the shooter's facing and
+    /// fall with `gravity`; the aim arc previews their flight.
+    Projectile {
+        speed: f32,
+        lifetime_secs: f32,
+        #[serde(default)]
+        bounces: u32,
+        #[serde(default = "default_bounce_damping")]
+        bounce_damping: f32,
+        #[serde(default)]
+        penetration: f32,
+        #[serde(default)]
+        explosion: Option<ExplosionDef>,
+        #[serde(default)]
+        gravity: f32,
+        #[serde(default)]
+        pitch_deg: f32,
+    },
+}
+
+fn default_bounce_damping() -> f32 {
+    0.6
+}
+
+/// One weapon definition, loaded from `assets/data/weapons.ron`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WeaponDef {
+    /// Stable key used by loadouts and saves.
+    pub id: String,
+    /// Display name (HUD).
+    pub name: String,
+    pub damage: f32,
+    /// Shots per second.
+    pub fire_rate: f32,
+    pub delivery: WeaponDelivery,
+    /// Rounds per magazine.
+    pub magazine_size: u32,
+    /// Spare rounds carried for this weapon.
+    pub reserve_max: u32,
+    /// Seconds to reload a magazine.
+    pub reload_secs: f32,
+    /// Placeholder model box size (world units).
+    pub model_size: (f32, f32, f32),
+    /// Placeholder model color (linear-ish sRGB 0..1).
+    pub model_color: (f32, f32, f32),
+}
+
+impl WeaponDef {
+    /// Seconds between shots.
+    pub fn fire_interval(&self) -> f32 {
+        if self.fire_rate > 0.0 {
+            1.0 / self.fire_rate
+        } else {
+            f32::INFINITY
+        }
+    }
+}
+
+/// All known weapon definitions.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WeaponLibrary(pub Vec<WeaponDef>);
+
+/// The definitions shipped with the game (compiled in, so they can't go missing).
+const BUILTIN_WEAPONS: &str = include_str!("../../../assets/data/weapons.ron");
+
+impl WeaponLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source).map(Self)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&WeaponDef> {
+        self.0.iter().find(|d| d.id == id)
+    }
+}
+
+impl Default for WeaponLibrary {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_WEAPONS).expect("assets/data/weapons.ron must parse")
+    }
+}
+
+/// Weapons a character carries (by `WeaponDef::id`) and which slot is in hand.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct WeaponLoadout {
+    pub slots: Vec<String>,
+    pub equipped: usize,
+}
+
+impl WeaponLoadout {
+    pub fn new(slots: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            slots: slots.into_iter().map(Into::into).collect(),
+            equipped: 0,
+        }
+    }
+
+    pub fn equipped_id(&self) -> Option<&str> {
+        self.slots.get(self.equipped).map(String::as_str)
+    }
+
+    /// Slot index `steps` away from the equipped one, wrapping around.
+    pub fn cycled(&self, steps: i32) -> Option<usize> {
+        let len = self.slots.len() as i32;
+        (len > 0).then(|| (self.equipped as i32 + steps).rem_euclid(len) as usize)
+    }
+}
+
+/// Rounds of one weapon: loaded in the magazine and spare in reserve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmmoState {
+    pub magazine: u32,
+    pub reserve: u32,
+}
+
+impl AmmoState {
+    /// Full magazine and full reserve for `def`.
+    pub fn full(def: &WeaponDef) -> Self {
+        Self {
+            magazine: def.magazine_size,
+            reserve: def.reserve_max,
+        }
+    }
+
+    /// Whether a reload would load anything.
+    pub fn can_reload(&self, magazine_size: u32) -> bool {
+        self.magazine < magazine_size && self.reserve > 0
+    }
+
+    /// Move rounds from reserve into the magazine (up to `magazine_size`).
+    pub fn reload(&mut self, magazine_size: u32) {
+        let moved = magazine_size.saturating_sub(self.magazine).min(self.reserve);
+        self.magazine += moved;
+        self.reserve -= moved;
+    }
+
+    /// Spend one round from the magazine.
+    pub fn try_consume(&mut self) -> bool {
+        if self.magazine == 0 {
+            return false;
+        }
+        self.magazine -= 1;
+        true
+    }
+}
+
+/// Per-weapon ammo of a character, keyed by `WeaponDef::id`.
+///
+/// Each weapon keeps its own magazine and reserve across switches.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct WeaponAmmo(pub BTreeMap<String, AmmoState>);
+
+impl WeaponAmmo {
+    /// Full ammo for every weapon in `loadout` known to `library`.
+    pub fn full_for(loadout: &WeaponLoadout, library: &WeaponLibrary) -> Self {
+        Self(
+            loadout
+                .slots
+                .iter()
+                .filter_map(|id| library.get(id).map(|def| (id.clone(), AmmoState::full(def))))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, id: &str) -> Option<&AmmoState> {
+        self.0.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut AmmoState> {
+        self.0.get_mut(id)
+    }
+}
+
+/// Trigger intent, written by input (held = keep firing at the weapon's fire rate).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WeaponTrigger {
+    pub pulled: bool,
+}
+
+/// Seconds until the equipped weapon may fire again.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct WeaponCooldown {
+    pub remaining: f32,
+}
+
+/// Present while a reload is in progress. Removed when done or cancelled
+/// (weapon switch, ability cast).
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Reloading {
+    pub weapon_id: String,
+    pub timer: Timer,
+}
+
+/// Shot in flight from a `WeaponDelivery::Projectile` weapon.
+///
+/// A mover (`Velocity` + `Transform`): the shared movement pipeline flies it, and
+/// each tick its path is swept for hits before integration.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Projectile {
+    /// Who fired it (never hit by its own shot).
+    pub source: Entity,
+    pub damage: f32,
+    pub lifetime: f32,
+    /// Ricochets left.
+    pub bounces_left: u32,
+    /// Fraction of speed kept per ricochet.
+    pub bounce_damping: f32,
+    /// Remaining penetration budget (thickness x material resistance).
+    pub penetration: f32,
+    /// Blast where it ends, if any.
+    pub explosion: Option<ExplosionDef>,
+    /// Downward pull while in flight (world units/sec², 0 = flies straight).
+    pub gravity: f32,
+}
+
+impl Projectile {
+    /// A straight-flying projectile that stops at the first thing it touches.
+    pub fn new(source: Entity, damage: f32, lifetime: f32) -> Self {
+        Self {
+            source,
+            damage,
+            lifetime,
+            bounces_left: 0,
+            bounce_damping: 0.0,
+            penetration: 0.0,
+            explosion: None,
+            gravity: 0.0,
+        }
+    }
+}
+
+/// Brief line from muzzle to impact drawn for hitscan shots.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Tracer {
+    pub from: Vec3,
+    pub to: Vec3,
+    /// Seconds left before the tracer disappears.
+    pub remaining: f32,
+}
+
+/// Where a character's weapon model attaches (hand bone or socket entity).
+///
+/// Put this on the bone / socket entity; `owner` is the character.
+/// Characters without a socket get their model parented to themselves.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeaponSocket {
+    pub owner: Entity,
+}
+
+/// The spawned model of an equipped weapon.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeaponModel {
+    pub owner: Entity,
+}
+
+/// Input bindings for weapon switching, firing and reloading.
+///
+/// Scrolling switches weapons only while `scroll_modifier` is held; plain scrolling
+/// stays camera zoom (the camera skips zoom while the same key is down).
+#[derive(Resource, Debug, Clone)]
+pub struct WeaponBindings {
+    /// Index = slot number.
+    pub slots: Vec<KeyCode>,
+    pub scroll_modifier: KeyCode,
+    pub fire: MouseButton,
+    pub fire_gamepad: GamepadButton,
+    pub reload: KeyCode,
+    pub reload_gamepad: GamepadButton,
+}
+
+impl Default for WeaponBindings {
+    fn default() -> Self {
+        Self {
+            slots: vec![
+                KeyCode::Digit1,
+                KeyCode::Digit2,
+                KeyCode::Digit3,
+                KeyCode::Digit4,
+                KeyCode::Digit5,
+            ],
+            scroll_modifier: KeyCode::AltLeft,
+            fire: MouseButton::Left,
+            fire_gamepad: GamepadButton::RightTrigger2,
+            // `R` is an ability slot.
+            reload: KeyCode::KeyX,
+            reload_gamepad: GamepadButton::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_library_parses_with_both_deliveries() {
+        let lib = WeaponLibrary::default();
+        assert!(lib.get("pistol").is_some());
+        assert!(matches!(lib.get("rifle").unwrap().delivery, WeaponDelivery::Hitscan { .. }));
+        assert!(matches!(
+            lib.get("launcher").unwrap().delivery,
+            WeaponDelivery::Projectile { .. }
+        ));
+        assert!((lib.get("rifle").unwrap().fire_interval() - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn projectile_rules_default_to_stopping() {
+        let lib = WeaponLibrary::from_ron(
+            "[(id: \"x\", name: \"X\", damage: 1.0, fire_rate: 1.0, \
+             delivery: Projectile(speed: 1.0, lifetime_secs: 1.0), magazine_size: 1, \
+             reserve_max: 1, reload_secs: 1.0, model_size: (1.0, 1.0, 1.0), \
+             model_color: (1.0, 1.0, 1.0))]",
+        )
+        .unwrap();
+        assert_eq!(
+            lib.get("x").unwrap().delivery,
+            WeaponDelivery::Projectile {
+                speed: 1.0,
+                lifetime_secs: 1.0,
+                bounces: 0,
+                bounce_damping: 0.6,
+                penetration: 0.0,
+                explosion: None,
+                gravity: 0.0,
+                pitch_deg: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_data_is_an_error() {
+        assert!(WeaponLibrary::from_ron("[(id: \"x\")]").is_err());
+    }
+
+    #[test]
+    fn reload_moves_only_what_fits_and_what_is_left() {
+        let mut ammo = AmmoState {
+            magazine: 4,
+            reserve: 5,
+        };
+        assert!(ammo.can_reload(12));
+        ammo.reload(12);
+        assert_eq!((ammo.magazine, ammo.reserve), (9, 0));
+        assert!(!ammo.can_reload(12));
+
+        let mut empty = AmmoState {
+            magazine: 0,
+            reserve: 0,
+        };
+        assert!(!empty.try_consume());
+    }
+
+    #[test]
+    fn full_ammo_covers_known_loadout_weapons() {
+        let lib = WeaponLibrary::default();
+        let ammo = WeaponAmmo::full_for(&WeaponLoadout::new(["pistol", "nope"]), &lib);
+        assert_eq!(ammo.0.len(), 1);
+        assert_eq!(ammo.get("pistol").unwrap().magazine, lib.get("pistol").unwrap().magazine_size);
+    }
+
+    #[test]
+    fn loadout_cycles_and_wraps() {
+        let loadout = WeaponLoadout::new(["a", "b", "c"]);
+        assert_eq!(loadout.equipped_id(), Some("a"));
+        assert_eq!(loadout.cycled(1), Some(1));
+        assert_eq!(loadout.cycled(-1), Some(2));
+        assert_eq!(WeaponLoadout::new(Vec::<String>::new()).cycled(1), None);
+    }
+}
@@ -0,0 +1,347 @@
+// src/features/weapon/firing.rs
+use bevy::prelude::*;
+
+use super::component::{
+    Reloading, WeaponAmmo, WeaponBindings, WeaponCooldown, WeaponLibrary, WeaponLoadout,
+    WeaponTrigger,
+};
+use super::systems::WeaponEquipped;
+use crate::features::ability::component::AbilitySlots;
+use crate::features::ability::systems::AbilityActivated;
+use crate::features::player::death::AlivePlayer;
+
+/// Request a reload of the equipped weapon.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReloadWeapon {
+    pub entity: Entity,
+}
+
+/// A round was fired (hit resolution, audio, muzzle flash, recoil, HUD bloom).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct WeaponFired {
+    pub entity: Entity,
+    pub weapon_id: String,
+}
+
+/// Trigger pulled on an empty magazine ("click" audio cue).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DryFire {
+    pub entity: Entity,
+    pub weapon_id: String,
+}
+
+/// Reload lifecycle (audio / animation hooks).
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum ReloadEvent {
+    Started { entity: Entity, weapon_id: String, duration_secs: f32 },
+    Finished { entity: Entity, weapon_id: String },
+    Cancelled { entity: Entity, weapon_id: String },
+}
+
+/// Every loadout carrier gets full ammo plus trigger / cooldown state.
+pub fn grant_weapon_ammo(
+    mut commands: Commands,
+    library: Res<WeaponLibrary>,
+    q_new: Query<(Entity, &WeaponLoadout), Without<WeaponAmmo>>,
+) {
+    for (entity, loadout) in &q_new {
+        commands.entity(entity).insert((
+            WeaponAmmo::full_for(loadout, &library),
+            WeaponTrigger::default(),
+            WeaponCooldown::default(),
+        ));
+    }
+}
+
+/// Update: fire button held -> `WeaponTrigger`, reload button -> `ReloadWeapon`.
+pub fn read_fire_input(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<WeaponBindings>,
+    q_gamepads: Query<&Gamepad>,
+    mut q_players: Query<(Entity, &mut WeaponTrigger), AlivePlayer>,
+    mut reloads: MessageWriter<ReloadWeapon>,
+) {
+    let pulled = mouse.pressed(bindings.fire)
+        || q_gamepads.iter().any(|g| g.pressed(bindings.fire_gamepad));
+    let reload = keyboard.just_pressed(bindings.reload)
+        || q_gamepads.iter().any(|g| g.just_pressed(bindings.reload_gamepad));
+
+    for (entity, mut trigger) in &mut q_players {
+        if trigger.pulled != pulled {
+            trigger.pulled = pulled;
+        }
+        if reload {
+            reloads.write(ReloadWeapon { entity });
+        }
+    }
+}
+
+/// FixedUpdate: start reloads (explicit requests).
+///
+/// Refused while an ability is casting / channeling, when already reloading,
+/// or when there is nothing to load.
+pub fn start_reloads(
+    mut commands: Commands,
+    library: Res<WeaponLibrary>,
+    mut requests: MessageReader<ReloadWeapon>,
+    q_characters: Query<(&WeaponLoadout, &WeaponAmmo, Option<&AbilitySlots>), Without<Reloading>>,
+    mut events: MessageWriter<ReloadEvent>,
+) {
+    for req in requests.read() {
+        let Ok((loadout, ammo, abilities)) = q_characters.get(req.entity) else {
+            continue;
+        };
+        if abilities.is_some_and(AbilitySlots::is_busy) {
+            continue;
+        }
+        if let Some(reloading) = begin_reload(loadout, ammo, &library) {
+            events.write(ReloadEvent::Started {
+                entity: req.entity,
+                weapon_id: reloading.weapon_id.clone(),
+                duration_secs: reloading.timer.duration().as_secs_f32(),
+            });
+            commands.entity(req.entity).insert(reloading);
+        }
+    }
+}
+
+fn begin_reload(loadout: &WeaponLoadout, ammo: &WeaponAmmo, library: &WeaponLibrary) -> Option<Reloading> {
+    let id = loadout.equipped_id()?;
+    let def = library.get(id)?;
+    ammo.get(id)?.can_reload(def.magazine_size).then(|| Reloading {
+        weapon_id: id.to_string(),
+        timer: Timer::from_seconds(def.reload_secs, TimerMode::Once),
+    })
+}
+
+/// FixedUpdate: cancel reloads when the weapon changes or an ability goes off.
+pub fn cancel_reloads(
+    mut commands: Commands,
+    mut equipped: MessageReader<WeaponEquipped>,
+    mut activated: MessageReader<AbilityActivated>,
+    q_reloading: Query<&Reloading>,
+    mut events: MessageWriter<ReloadEvent>,
+) {
+    let interrupted = equipped
+        .read()
+        .map(|ev| ev.entity)
+        .chain(activated.read().map(|ev| ev.entity))
+        .collect::<Vec<_>>();
+
+    for entity in interrupted {
+        let Ok(reloading) = q_reloading.get(entity) else {
+            continue;
+        };
+        events.write(ReloadEvent::Cancelled {
+            entity,
+            weapon_id: reloading.weapon_id.clone(),
+        });
+        commands.entity(entity).remove::<Reloading>();
+    }
+}
+
+/// FixedUpdate: advance reloads; completed ones move rounds from reserve to magazine.
+pub fn tick_reloads(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    library: Res<WeaponLibrary>,
+    mut q_reloading: Query<(Entity, &mut Reloading, &mut WeaponAmmo)>,
+    mut events: MessageWriter<ReloadEvent>,
+) {
+    for (entity, mut reloading, mut ammo) in &mut q_reloading {
+        reloading.timer.tick(time.delta());
+        if !reloading.timer.is_finished() {
+            continue;
+        }
+
+        let id = reloading.weapon_id.clone();
+        if let (Some(def), Some(state)) = (library.get(&id), ammo.get_mut(&id)) {
+            state.reload(def.magazine_size);
+        }
+        events.write(ReloadEvent::Finished {
+            entity,
+            weapon_id: id,
+        });
+        commands.entity(entity).remove::<Reloading>();
+    }
+}
+
+/// FixedUpdate: fire the equipped weapon while the trigger is held, at its fire rate.
+///
+/// - Blocked while reloading or while an ability is casting / channeling.
+/// - Empty magazine: `DryFire`, and an automatic reload if there is reserve ammo.
+#[expect(clippy::type_complexity)]
+pub fn fire_weapons(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    library: Res<WeaponLibrary>,
+    mut q_shooters: Query<
+        (
+            Entity,
+            &WeaponTrigger,
+            &WeaponLoadout,
+            &mut WeaponAmmo,
+            &mut WeaponCooldown,
+            Option<&AbilitySlots>,
+        ),
+        (AlivePlayer, Without<Reloading>),
+    >,
+    mut fired: MessageWriter<WeaponFired>,
+    mut dry: MessageWriter<DryFire>,
+    mut reload_events: MessageWriter<ReloadEvent>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, trigger, loadout, mut ammo, mut cooldown, abilities) in &mut q_shooters {
+        cooldown.remaining = (cooldown.remaining - dt).max(0.0);
+
+        if !trigger.pulled || cooldown.remaining > 0.0 || abilities.is_some_and(AbilitySlots::is_busy) {
+            continue;
+        }
+        let Some((id, def)) = loadout.equipped_id().and_then(|id| library.get(id).map(|d| (id, d))) else {
+            continue;
+        };
+        let Some(state) = ammo.get_mut(id) else {
+            continue;
+        };
+
+        cooldown.remaining = def.fire_interval();
+        if state.try_consume() {
+            fired.write(WeaponFired {
+                entity,
+                weapon_id: id.to_string(),
+            });
+            continue;
+        }
+
+        dry.write(DryFire {
+            entity,
+            weapon_id: id.to_string(),
+        });
+        if let Some(reloading) = begin_reload(loadout, &ammo, &library) {
+            reload_events.write(ReloadEvent::Started {
+                entity,
+                weapon_id: reloading.weapon_id.clone(),
+                duration_secs: reloading.timer.duration().as_secs_f32(),
+            });
+            commands.entity(entity).insert(reloading);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::weapon::component::AmmoState;
+    use crate::features::ability::component::{AbilityDef, AbilitySlot, AbilityState};
+    use crate::features::player::component::Player;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world(dt: f32) -> World {
+        let mut world = World::new();
+        world.insert_resource(WeaponLibrary::default());
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(dt));
+        world.insert_resource(fixed);
+        world.init_resource::<Messages<ReloadWeapon>>();
+        world.init_resource::<Messages<WeaponFired>>();
+        world.init_resource::<Messages<DryFire>>();
+        world.init_resource::<Messages<ReloadEvent>>();
+        world.init_resource::<Messages<WeaponEquipped>>();
+        world.init_resource::<Messages<AbilityActivated>>();
+        world
+    }
+
+    fn spawn_shooter(world: &mut World) -> Entity {
+        let e = world
+            .spawn((Player, WeaponLoadout::new(["pistol", "rifle"])))
+            .id();
+        let _ = world.run_system_once(grant_weapon_ammo);
+        e
+    }
+
+    fn pistol(world: &World, e: Entity) -> AmmoState {
+        *world.get::<WeaponAmmo>(e).unwrap().get("pistol").unwrap()
+    }
+
+    #[test]
+    fn held_trigger_fires_at_fire_rate_and_spends_rounds() {
+        let mut world = setup_world(0.1);
+        let e = spawn_shooter(&mut world);
+        let full = pistol(&world, e);
+        world.get_mut::<WeaponTrigger>(e).unwrap().pulled = true;
+
+        // Pistol: 4 shots/sec -> 0.25s between shots; ticks of 0.1s.
+        for _ in 0..6 {
+            let _ = world.run_system_once(fire_weapons);
+        }
+
+        assert_eq!(world.resource::<Messages<WeaponFired>>().len(), 2);
+        assert_eq!(pistol(&world, e).magazine, full.magazine - 2);
+    }
+
+    #[test]
+    fn empty_magazine_dry_fires_and_auto_reloads() {
+        let mut world = setup_world(0.1);
+        let e = spawn_shooter(&mut world);
+        world.get_mut::<WeaponAmmo>(e).unwrap().get_mut("pistol").unwrap().magazine = 0;
+        world.get_mut::<WeaponTrigger>(e).unwrap().pulled = true;
+
+        let _ = world.run_system_once(fire_weapons);
+
+        assert_eq!(world.resource::<Messages<DryFire>>().len(), 1);
+        assert_eq!(world.resource::<Messages<WeaponFired>>().len(), 0);
+        assert_eq!(world.get::<Reloading>(e).unwrap().weapon_id, "pistol");
+    }
+
+    #[test]
+    fn reload_completes_after_its_duration() {
+        let reload_secs = WeaponLibrary::default().get("pistol").unwrap().reload_secs;
+        let mut world = setup_world(reload_secs);
+        let e = spawn_shooter(&mut world);
+        world.get_mut::<WeaponAmmo>(e).unwrap().get_mut("pistol").unwrap().magazine = 2;
+        let before = pistol(&world, e);
+
+        world.write_message(ReloadWeapon { entity: e });
+        let _ = world.run_system_once(start_reloads);
+        assert!(world.get::<Reloading>(e).is_some());
+
+        let _ = world.run_system_once(tick_reloads);
+
+        let after = pistol(&world, e);
+        assert!(world.get::<Reloading>(e).is_none());
+        assert_eq!(after.magazine + after.reserve, before.magazine + before.reserve);
+        assert!(after.magazine > before.magazine);
+    }
+
+    #[test]
+    fn casting_blocks_reload_and_activation_cancels_it() {
+        let mut world = setup_world(0.0);
+        let e = spawn_shooter(&mut world);
+        world.get_mut::<WeaponAmmo>(e).unwrap().get_mut("pistol").unwrap().magazine = 0;
+
+        let mut slot = AbilitySlot::new(AbilityDef::dash());
+        slot.state = AbilityState::Casting(Timer::from_seconds(1.0, TimerMode::Once));
+        world.entity_mut(e).insert(AbilitySlots(vec![slot]));
+        world.write_message(ReloadWeapon { entity: e });
+        let _ = world.run_system_once(start_reloads);
+        assert!(world.get::<Reloading>(e).is_none());
+
+        world.entity_mut(e).insert(AbilitySlots::default());
+        world.write_message(ReloadWeapon { entity: e });
+        let _ = world.run_system_once(start_reloads);
+        assert!(world.get::<Reloading>(e).is_some());
+
+        world.write_message(WeaponEquipped {
+            entity: e,
+            slot: 1,
+            weapon_id: "rifle".into(),
+        });
+        let _ = world.run_system_once(cancel_reloads);
+        assert!(world.get::<Reloading>(e).is_none());
+        assert_eq!(pistol(&world, e).magazine, 0, "cancelled reloads load nothing");
+    }
+}
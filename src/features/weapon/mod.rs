@@ -0,0 +1,113 @@
+// src/features/weapon/mod.rs
+use bevy::input::mouse::AccumulatedMouseScroll;
+use bevy::prelude::*;
+
+use crate::app::{AppSet, GAMEPLAY, in_input_context};
+use crate::features::ability::systems::{AbilityActivated, process_ability_requests};
+use crate::features::explosion::systems::Explosion;
+use crate::features::health::damage::DamageEvent;
+use crate::features::meshes::component::MeshLibrary;
+use crate::features::movement::systems::compute_velocity_from_input;
+use crate::features::surface::systems::SurfaceHit;
+
+pub mod ballistics;
+pub mod component;
+pub mod firing;
+pub mod systems;
+pub mod trajectory;
+
+/// Weapon equip / switching, ammo and reload feature.
+///
+/// Scope:
+/// - `WeaponLibrary`: definitions from `assets/data/weapons.ron`
+///   (damage, fire rate, hitscan vs projectile, placeholder model)
+/// - `WeaponLoadout` on characters; players get a default loadout and hand socket
+/// - Update: number keys / modifier + scroll -> `EquipWeapon` -> `WeaponEquipped`
+/// - the equipped model is attached to the owner's `WeaponSocket` (hand bone / socket)
+/// - `WeaponAmmo`: per-weapon magazine + reserve; trigger held -> `WeaponFired` at the
+///   weapon's fire rate, empty magazine -> `DryFire` + automatic reload
+/// - reloads (`Reloading` timer) are refused while an ability casts / channels and
+///   cancelled by weapon switches or ability activations (`ReloadEvent` hooks)
+///
+/// - shots resolve by the weapon's `WeaponDelivery`: hitscan rays (optional tracer) or
+///   `Projectile` movers swept for hits each tick; both end in the same `WeaponHit`
+///   -> `DamageEvent` + `SurfaceHit` path
+/// - projectiles may ricochet (bounce count + damping) and penetrate thin / weak
+///   materials (`SurfaceMaterial` resistance), per weapon data
+/// - explosive projectiles raise an `Explosion` where they end (see `ExplosionPlugin`)
+/// - lobbed projectiles (pitched up, falling with gravity) show an aim arc predicted
+///   with the same per-tick flight math (`trajectory`)
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::WeaponLibrary>();
+        app.init_resource::<component::WeaponBindings>();
+        app.init_resource::<trajectory::TrajectoryPreview>();
+        app.init_resource::<MeshLibrary>();
+        // Normally provided by `InputPlugin`; keeps the feature usable headless.
+        app.init_resource::<AccumulatedMouseScroll>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+
+        app.add_message::<systems::EquipWeapon>();
+        app.add_message::<systems::WeaponEquipped>();
+        app.add_message::<firing::ReloadWeapon>();
+        app.add_message::<firing::WeaponFired>();
+        app.add_message::<firing::DryFire>();
+        app.add_message::<firing::ReloadEvent>();
+        app.add_message::<ballistics::WeaponHit>();
+        app.add_message::<Explosion>();
+        app.add_message::<AbilityActivated>();
+        app.add_message::<DamageEvent>();
+        app.add_message::<SurfaceHit>();
+
+        app.add_systems(Startup, ballistics::setup_projectile_assets);
+
+        app.add_systems(
+            Update,
+            (systems::read_weapon_input, firing::read_fire_input)
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(GAMEPLAY)),
+        );
+        app.add_systems(
+            Update,
+            (systems::equip_weapons, systems::attach_weapon_models)
+                .chain()
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::grant_player_loadout,
+                firing::grant_weapon_ammo,
+                firing::cancel_reloads,
+                firing::start_reloads,
+                firing::tick_reloads,
+                firing::fire_weapons,
+                ballistics::launch_shots,
+                ballistics::apply_weapon_hits,
+            )
+                .chain()
+                .after(process_ability_requests)
+                .in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            ballistics::sweep_projectiles
+                .after(compute_velocity_from_input)
+                .in_set(AppSet::ApplyForces),
+        );
+        app.add_systems(
+            Update,
+            ballistics::draw_tracers
+                .run_if(any_with_component::<component::Tracer>)
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            Update,
+            trajectory::draw_aim_arc
+                .run_if(trajectory::holding_lobbed_weapon)
+                .after(AppSet::Input),
+        );
+    }
+}
@@ -0,0 +1,265 @@
+// src/features/weapon/systems.rs
+use bevy::input::mouse::AccumulatedMouseScroll;
+use bevy::prelude::*;
+
+use super::component::{WeaponBindings, WeaponLibrary, WeaponLoadout, WeaponModel, WeaponSocket};
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::player::component::Player;
+use crate::features::player::death::AlivePlayer;
+
+/// Default player loadout (ids from `assets/data/weapons.ron`).
+pub const DEFAULT_PLAYER_WEAPONS: [&str; 5] = ["pistol", "rifle", "launcher", "nailgun", "grenade"];
+
+/// Where the player's hand socket sits until a rigged model provides a hand bone.
+const PLAYER_HAND_OFFSET: Vec3 = Vec3::new(0.35, 1.1, -0.3);
+
+/// Request to switch a character to a loadout slot.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EquipWeapon {
+    pub entity: Entity,
+    pub slot: usize,
+}
+
+/// A weapon was put in hand (model attachment, HUD, audio / animation hooks).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct WeaponEquipped {
+    pub entity: Entity,
+    pub slot: usize,
+    pub weapon_id: String,
+}
+
+/// Every player gets the default loadout and a hand socket, with slot 0 in hand.
+pub fn grant_player_loadout(
+    mut commands: Commands,
+    q_new_players: Query<Entity, (Added<Player>, Without<WeaponLoadout>)>,
+    mut equipped: MessageWriter<WeaponEquipped>,
+) {
+    for entity in &q_new_players {
+        let loadout = WeaponLoadout::new(DEFAULT_PLAYER_WEAPONS);
+        if let Some(id) = loadout.equipped_id() {
+            equipped.write(WeaponEquipped {
+                entity,
+                slot: 0,
+                weapon_id: id.to_string(),
+            });
+        }
+        commands.entity(entity).insert(loadout);
+        commands.spawn((
+            WeaponSocket { owner: entity },
+            Transform::from_translation(PLAYER_HAND_OFFSET),
+            ChildOf(entity),
+            Name::new("Hand Socket"),
+        ));
+    }
+}
+
+/// Update: number keys / modifier + scroll -> `EquipWeapon` for living players.
+pub fn read_weapon_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    scroll: Res<AccumulatedMouseScroll>,
+    bindings: Res<WeaponBindings>,
+    q_players: Query<(Entity, &WeaponLoadout), AlivePlayer>,
+    mut requests: MessageWriter<EquipWeapon>,
+) {
+    let pressed_slot = bindings
+        .slots
+        .iter()
+        .position(|key| keyboard.just_pressed(*key));
+    let scroll_steps = if keyboard.pressed(bindings.scroll_modifier) && scroll.delta.y != 0.0 {
+        // Scroll up = previous weapon, like most shooters.
+        -(scroll.delta.y.signum() as i32)
+    } else {
+        0
+    };
+
+    for (entity, loadout) in &q_players {
+        let slot = pressed_slot.or_else(|| {
+            (scroll_steps != 0)
+                .then(|| loadout.cycled(scroll_steps))
+                .flatten()
+        });
+        if let Some(slot) = slot {
+            requests.write(EquipWeapon { entity, slot });
+        }
+    }
+}
+
+/// Update: apply equip requests (ignores empty slots, unknown weapons and no-op switches).
+pub fn equip_weapons(
+    library: Res<WeaponLibrary>,
+    mut requests: MessageReader<EquipWeapon>,
+    mut q_loadouts: Query<&mut WeaponLoadout>,
+    mut equipped: MessageWriter<WeaponEquipped>,
+) {
+    for req in requests.read() {
+        let Ok(mut loadout) = q_loadouts.get_mut(req.entity) else {
+            continue;
+        };
+        if req.slot == loadout.equipped {
+            continue;
+        }
+        let Some(id) = loadout.slots.get(req.slot).cloned() else {
+            continue;
+        };
+        if library.get(&id).is_none() {
+            continue;
+        }
+
+        loadout.equipped = req.slot;
+        equipped.write(WeaponEquipped {
+            entity: req.entity,
+            slot: req.slot,
+            weapon_id: id,
+        });
+    }
+}
+
+/// Update: swap the model in the owner's socket (or on the owner) when a weapon is equipped.
+pub fn attach_weapon_models(
+    mut commands: Commands,
+    library: Res<WeaponLibrary>,
+    mut equipped: MessageReader<WeaponEquipped>,
+    q_sockets: Query<(Entity, &WeaponSocket)>,
+    q_models: Query<(Entity, &WeaponModel)>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for ev in equipped.read() {
+        for (model, owned) in &q_models {
+            if owned.owner == ev.entity {
+                commands.entity(model).despawn();
+            }
+        }
+
+        let Some(def) = library.get(&ev.weapon_id) else {
+            continue;
+        };
+        let parent = q_sockets
+            .iter()
+            .find(|(_, socket)| socket.owner == ev.entity)
+            .map_or(ev.entity, |(socket, _)| socket);
+
+        let (w, h, d) = def.model_size;
+        let (r, g, b) = def.model_color;
+        commands.spawn((
+            WeaponModel { owner: ev.entity },
+            Mesh3d(meshes.get(MeshPrimitive::Cube)),
+            MeshMaterial3d(materials.add(Color::srgb(r, g, b))),
+            Transform::from_scale(Vec3::new(w, h, d)),
+            ChildOf(parent),
+            Name::new(def.name.clone()),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(WeaponLibrary::default());
+        world.insert_resource(WeaponBindings::default());
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(AccumulatedMouseScroll::default());
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Messages<EquipWeapon>>();
+        world.init_resource::<Messages<WeaponEquipped>>();
+        world
+    }
+
+    fn models_of(world: &mut World, owner: Entity) -> Vec<(Entity, Option<Entity>)> {
+        world
+            .query::<(Entity, &WeaponModel, Option<&ChildOf>)>()
+            .iter(world)
+            .filter(|(_, m, _)| m.owner == owner)
+            .map(|(e, _, c)| (e, c.map(ChildOf::parent)))
+            .collect()
+    }
+
+    #[test]
+    fn new_player_gets_loadout_socket_and_model() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+
+        let _ = world.run_system_once(grant_player_loadout);
+        let _ = world.run_system_once(attach_weapon_models);
+
+        assert_eq!(
+            world.get::<WeaponLoadout>(player).unwrap().equipped_id(),
+            Some("pistol")
+        );
+        let socket = world
+            .query::<(Entity, &WeaponSocket)>()
+            .iter(&world)
+            .find(|(_, s)| s.owner == player)
+            .map(|(e, _)| e)
+            .unwrap();
+        let models = models_of(&mut world, player);
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].1, Some(socket));
+    }
+
+    #[test]
+    fn number_key_switches_and_replaces_model() {
+        let mut world = setup_world();
+        let player = world
+            .spawn((Player, WeaponLoadout::new(DEFAULT_PLAYER_WEAPONS)))
+            .id();
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Digit2);
+
+        let _ = world.run_system_once(read_weapon_input);
+        let _ = world.run_system_once(equip_weapons);
+        let _ = world.run_system_once(attach_weapon_models);
+
+        assert_eq!(world.get::<WeaponLoadout>(player).unwrap().equipped, 1);
+        let models = models_of(&mut world, player);
+        assert_eq!(models.len(), 1);
+        // No socket: the model hangs off the character itself.
+        assert_eq!(models[0].1, Some(player));
+    }
+
+    #[test]
+    fn modifier_scroll_cycles_but_plain_scroll_does_not() {
+        let mut world = setup_world();
+        let player = world
+            .spawn((Player, WeaponLoadout::new(DEFAULT_PLAYER_WEAPONS)))
+            .id();
+        world.resource_mut::<AccumulatedMouseScroll>().delta.y = -1.0;
+
+        let _ = world.run_system_once(read_weapon_input);
+        assert_eq!(world.resource::<Messages<EquipWeapon>>().len(), 0);
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::AltLeft);
+        let _ = world.run_system_once(read_weapon_input);
+        let _ = world.run_system_once(equip_weapons);
+
+        assert_eq!(world.get::<WeaponLoadout>(player).unwrap().equipped, 1);
+    }
+
+    #[test]
+    fn unknown_or_empty_slots_are_ignored() {
+        let mut world = setup_world();
+        let player = world
+            .spawn(WeaponLoadout::new(["pistol", "does_not_exist"]))
+            .id();
+
+        for slot in [1, 7] {
+            world.write_message(EquipWeapon {
+                entity: player,
+                slot,
+            });
+        }
+        let _ = world.run_system_once(equip_weapons);
+
+        assert_eq!(world.get::<WeaponLoadout>(player).unwrap().equipped, 0);
+        assert_eq!(world.resource::<Messages<WeaponEquipped>>().len(), 0);
+    }
+}
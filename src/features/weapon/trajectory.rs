@@ -0,0 +1,258 @@
+// src/features/weapon/trajectory.rs
+use bevy::prelude::*;
+
+use super::ballistics::{PROJECTILE_RADIUS, fall, projectile_launch};
+use super::component::{WeaponDelivery, WeaponLibrary, WeaponLoadout};
+use crate::app::SimMath;
+use crate::features::collision::query::CollisionWorld;
+use crate::features::player::component::Player;
+
+/// How the aim arc of lobbed weapons is drawn.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TrajectoryPreview {
+    /// Most fixed ticks looked ahead (the projectile's lifetime caps it too).
+    pub max_ticks: u32,
+    pub color: Color,
+    /// Radius of the marker where the arc meets something.
+    pub impact_radius: f32,
+}
+
+impl Default for TrajectoryPreview {
+    fn default() -> Self {
+        Self {
+            max_ticks: 180,
+            color: Color::srgba(1.0, 0.85, 0.4, 0.8),
+            impact_radius: 0.25,
+        }
+    }
+}
+
+/// Where a projectile is predicted to be after each fixed tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PredictedPath {
+    /// Launch point, then one point per tick; the last is the impact on a hit.
+    pub points: Vec<Vec3>,
+    pub hit: bool,
+}
+
+/// Step a projectile's flight forward `ticks` fixed ticks of `dt`, the way
+/// `sweep_projectiles` + `integrate_velocity` fly it: gravity (`fall`), a sweep of
+/// the tick's path (`sweep(position, direction, distance)` -> hit distance), then
+/// integration. Stops at the first contact (ricochets / penetration aren't previewed).
+pub fn predict_trajectory(
+    math: SimMath,
+    origin: Vec3,
+    velocity: Vec3,
+    gravity: f32,
+    dt: f32,
+    ticks: u32,
+    mut sweep: impl FnMut(Vec3, Vec3, f32) -> Option<f32>,
+) -> PredictedPath {
+    let mut points = vec![origin];
+    let mut position = origin;
+    let mut velocity = velocity;
+
+    for _ in 0..ticks {
+        velocity = fall(velocity, gravity, dt);
+        let direction = velocity.normalize_or_zero();
+        if let Some(distance) = sweep(position, direction, velocity.length() * dt) {
+            points.push(position + direction * distance);
+            return PredictedPath { points, hit: true };
+        }
+        position = math.quantize_vec3(position + velocity * dt);
+        points.push(position);
+    }
+    PredictedPath { points, hit: false }
+}
+
+/// Run condition: a player has a lobbed weapon (projectile with gravity) equipped.
+pub fn holding_lobbed_weapon(
+    library: Res<WeaponLibrary>,
+    q_players: Query<&WeaponLoadout, With<Player>>,
+) -> bool {
+    q_players.iter().any(|loadout| {
+        loadout
+            .equipped_id()
+            .and_then(|id| library.get(id))
+            .is_some_and(|def| {
+                matches!(def.delivery, WeaponDelivery::Projectile { gravity, .. } if gravity > 0.0)
+            })
+    })
+}
+
+/// Update: draw the predicted arc of each player's lobbed weapon, ending in a
+/// marker where it would land.
+pub fn draw_aim_arc(
+    mut gizmos: Gizmos,
+    preview: Res<TrajectoryPreview>,
+    library: Res<WeaponLibrary>,
+    math: Res<SimMath>,
+    fixed: Res<Time<Fixed>>,
+    collision: CollisionWorld,
+    q_players: Query<(Entity, &Transform, &WeaponLoadout), With<Player>>,
+) {
+    let dt = fixed.timestep().as_secs_f32();
+    for (entity, transform, loadout) in &q_players {
+        let Some(def) = loadout.equipped_id().and_then(|id| library.get(id)) else {
+            continue;
+        };
+        let WeaponDelivery::Projectile {
+            speed,
+            lifetime_secs,
+            gravity,
+            pitch_deg,
+            ..
+        } = def.delivery
+        else {
+            continue;
+        };
+        if gravity <= 0.0 {
+            continue;
+        }
+
+        let (origin, velocity) = projectile_launch(transform, speed, pitch_deg);
+        let ticks = preview.max_ticks.min((lifetime_secs / dt) as u32);
+        let path = predict_trajectory(
+            *math,
+            origin,
+            velocity,
+            gravity,
+            dt,
+            ticks,
+            |from, dir, distance| {
+                collision
+                    .sphere_cast(from, dir, distance, PROJECTILE_RADIUS, &[entity])
+                    .map(|hit| hit.distance)
+            },
+        );
+
+        gizmos.linestrip(path.points.iter().copied(), preview.color);
+        if let (true, Some(&end)) = (path.hit, path.points.last()) {
+            gizmos.sphere(
+                Isometry3d::from_translation(end),
+                preview.impact_radius,
+                preview.color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::collision::component::Collider;
+    use crate::features::explosion::systems::Explosion;
+    use crate::features::movement::component::Velocity;
+    use crate::features::movement::systems::integrate_velocity;
+    use crate::features::weapon::ballistics::{WeaponHit, sweep_projectiles};
+    use crate::features::weapon::component::Projectile;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use std::time::Duration;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    fn setup_world() -> World {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<SimMath>();
+        let mut fixed = Time::<Fixed>::from_hz(60.0);
+        fixed.advance_by(Duration::from_secs_f32(DT));
+        world.insert_resource(fixed);
+        world.init_resource::<Messages<WeaponHit>>();
+        world.init_resource::<Messages<Explosion>>();
+        // Ground slab 12 units ahead, top at y = 0.
+        world.spawn((
+            Collider::cuboid(Vec3::new(20.0, 0.5, 20.0)),
+            GlobalTransform::from_translation(Vec3::new(0.0, -0.5, -12.0)),
+        ));
+        world
+    }
+
+    #[test]
+    fn the_preview_matches_the_flight_tick_for_tick() {
+        let mut world = setup_world();
+        let shooter = Transform::from_xyz(0.0, 0.5, 0.0);
+        let (origin, velocity) = projectile_launch(&shooter, 12.0, 30.0);
+        assert!(
+            velocity.y > 0.0 && velocity.z < 0.0,
+            "lobbed up and forward: {velocity}"
+        );
+
+        let path = world
+            .run_system_once(move |collision: CollisionWorld| {
+                predict_trajectory(
+                    SimMath::Float,
+                    origin,
+                    velocity,
+                    9.81,
+                    DT,
+                    300,
+                    |from, dir, distance| {
+                        collision
+                            .sphere_cast(from, dir, distance, PROJECTILE_RADIUS, &[])
+                            .map(|hit| hit.distance)
+                    },
+                )
+            })
+            .unwrap();
+        assert!(path.hit, "lands on the ground");
+
+        let projectile = world
+            .spawn((
+                Projectile {
+                    gravity: 9.81,
+                    ..Projectile::new(Entity::PLACEHOLDER, 1.0, 10.0)
+                },
+                Velocity(velocity),
+                Transform::from_translation(origin),
+            ))
+            .id();
+        for (tick, expected) in path.points.iter().enumerate().skip(1) {
+            let _ = world.run_system_once(sweep_projectiles);
+            if world.get_entity(projectile).is_err() {
+                // It stopped: right where the preview said.
+                assert_eq!(tick, path.points.len() - 1);
+                let messages = world.resource::<Messages<WeaponHit>>();
+                let hit = messages.iter_current_update_messages().next().unwrap();
+                assert!(
+                    hit.position.abs_diff_eq(*expected, 1e-4),
+                    "{} vs {expected}",
+                    hit.position
+                );
+                return;
+            }
+            let _ = world.run_system_once(integrate_velocity);
+            let at = world.get::<Transform>(projectile).unwrap().translation;
+            assert!(
+                at.abs_diff_eq(*expected, 1e-4),
+                "tick {tick}: {at} vs {expected}"
+            );
+        }
+        panic!("the projectile outlived its preview");
+    }
+
+    #[test]
+    fn straight_shots_preview_a_straight_line() {
+        let path = predict_trajectory(
+            SimMath::Float,
+            Vec3::ZERO,
+            Vec3::NEG_Z * 10.0,
+            0.0,
+            0.1,
+            3,
+            |_, _, _| None,
+        );
+        assert!(!path.hit);
+        assert_eq!(
+            path.points,
+            vec![
+                Vec3::ZERO,
+                Vec3::NEG_Z,
+                Vec3::NEG_Z * 2.0,
+                Vec3::NEG_Z * 3.0
+            ]
+        );
+    }
+}
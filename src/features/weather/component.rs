@@ -0,0 +1,275 @@
+// src/features/weather/component.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Weather states, in the order the debug key cycles through them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+pub enum WeatherKind {
+    #[default]
+    Clear,
+    Rain,
+    Snow,
+    Storm,
+}
+
+impl WeatherKind {
+    pub const ALL: [Self; 4] = [Self::Clear, Self::Rain, Self::Snow, Self::Storm];
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|k| *k == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Fraction of the particle pool in use (`0.0..=1.0`).
+    pub fn density(self) -> f32 {
+        match self {
+            Self::Clear => 0.0,
+            Self::Rain => 0.6,
+            Self::Snow => 0.5,
+            Self::Storm => 1.0,
+        }
+    }
+
+    /// Whether surfaces get wet (rain, storm); snow and clear skies dry them.
+    pub fn wets_surfaces(self) -> bool {
+        matches!(self, Self::Rain | Self::Storm)
+    }
+
+    pub fn is_snow(self) -> bool {
+        self == Self::Snow
+    }
+}
+
+/// Current weather and the transition into it.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Weather {
+    pub kind: WeatherKind,
+    /// Weather being faded out.
+    pub previous: WeatherKind,
+    /// `0.0` = all `previous`, `1.0` = all `kind`.
+    pub blend: f32,
+    pub transition_secs: f32,
+    /// Surface wetness, `0.0` (dry) ..= `1.0` (soaked).
+    pub wetness: f32,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self {
+            kind: WeatherKind::Clear,
+            previous: WeatherKind::Clear,
+            blend: 1.0,
+            transition_secs: 0.0,
+            wetness: 0.0,
+        }
+    }
+}
+
+/// Wetness gained per second in rain / lost per second otherwise.
+pub const WETTING_RATE: f32 = 1.0 / 20.0;
+pub const DRYING_RATE: f32 = 1.0 / 60.0;
+
+impl Weather {
+    /// Start fading into `kind` over `transition_secs` (`0.0` = instantly).
+    pub fn set(&mut self, kind: WeatherKind, transition_secs: f32) {
+        self.previous = self.kind;
+        self.kind = kind;
+        self.transition_secs = transition_secs.max(0.0);
+        self.blend = if self.transition_secs > 0.0 { 0.0 } else { 1.0 };
+    }
+
+    pub fn advance(&mut self, dt: f32) {
+        if self.blend < 1.0 {
+            self.blend = (self.blend + dt / self.transition_secs.max(f32::EPSILON)).min(1.0);
+        }
+        self.wetness = if self.kind.wets_surfaces() {
+            (self.wetness + WETTING_RATE * self.blend * dt).min(1.0)
+        } else {
+            (self.wetness - DRYING_RATE * dt).max(0.0)
+        };
+    }
+
+    /// Blended precipitation density (`0.0..=1.0`).
+    pub fn density(&self) -> f32 {
+        self.previous.density().lerp(self.kind.density(), self.blend)
+    }
+
+    /// Kind of particles to show: the incoming weather once it dominates.
+    pub fn visible_kind(&self) -> WeatherKind {
+        if self.blend >= 0.5 {
+            self.kind
+        } else {
+            self.previous
+        }
+    }
+
+    /// Volume (`0.0..=1.0`) of the ambience loop for `kind`.
+    pub fn ambience_volume(&self, kind: WeatherKind) -> f32 {
+        let mut volume = 0.0;
+        if self.kind == kind {
+            volume += self.blend;
+        }
+        if self.previous == kind {
+            volume += 1.0 - self.blend;
+        }
+        volume
+    }
+}
+
+/// Switch the weather at `at_hour` every day.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct WeatherScheduleEntry {
+    pub at_hour: f32,
+    pub kind: WeatherKind,
+    pub transition_secs: f32,
+}
+
+/// Weather data, loaded from `assets/data/weather.ron`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct WeatherData {
+    pub schedule: Vec<WeatherScheduleEntry>,
+    /// Looping ambience per weather (asset paths).
+    pub ambience: BTreeMap<WeatherKind, String>,
+}
+
+const BUILTIN_WEATHER: &str = include_str!("../../../assets/data/weather.ron");
+
+impl WeatherData {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+}
+
+impl Default for WeatherData {
+    fn default() -> Self {
+        Self::from_ron(BUILTIN_WEATHER).expect("assets/data/weather.ron must parse")
+    }
+}
+
+/// Precipitation look and volume.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct WeatherSettings {
+    /// Pooled particles (max on screen during a storm).
+    pub particle_pool: usize,
+    /// Half-size of the box around the camera particles fall through.
+    pub area_half_extents: Vec3,
+    pub rain_fall_speed: f32,
+    pub snow_fall_speed: f32,
+    /// Snow side-to-side sway amplitude (world units).
+    pub snow_sway: f32,
+    /// Roughness of `Wettable` surfaces when soaked.
+    pub wet_roughness: f32,
+    /// Sideways particle speed per unit of wind acceleration at the emitter.
+    pub wind_drift: f32,
+}
+
+impl Default for WeatherSettings {
+    fn default() -> Self {
+        Self {
+            particle_pool: 600,
+            area_half_extents: Vec3::new(12.0, 8.0, 12.0),
+            rain_fall_speed: 16.0,
+            snow_fall_speed: 1.5,
+            snow_sway: 0.4,
+            wet_roughness: 0.15,
+            wind_drift: 0.5,
+        }
+    }
+}
+
+/// Debug key that cycles the weather.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct WeatherKeybinding(pub KeyCode);
+
+impl Default for WeatherKeybinding {
+    fn default() -> Self {
+        Self(KeyCode::F7)
+    }
+}
+
+/// Follows the active camera; precipitation particles are its children.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct PrecipitationEmitter;
+
+/// One pooled rain drop / snow flake (local position inside the emitter box).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct WeatherParticle {
+    pub index: usize,
+}
+
+/// Surface whose material gets glossier while wet.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Wettable {
+    pub dry_roughness: f32,
+}
+
+impl Default for Wettable {
+    fn default() -> Self {
+        Self {
+            dry_roughness: 0.5,
+        }
+    }
+}
+
+/// Looping ambience for one weather kind (volume follows the blend).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeatherAmbience {
+    pub kind: WeatherKind,
+}
+
+/// Deterministic start position of particle `index` inside a box of `half_extents`.
+pub fn particle_start(index: usize, half_extents: Vec3) -> Vec3 {
+    let hash = |salt: u32| {
+        let mut x = (index as u32).wrapping_mul(0x9E37_79B9) ^ salt;
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7FEB_352D);
+        x ^= x >> 15;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    };
+    Vec3::new(hash(0x68E3_1DA4), hash(0xB529_7A4D), hash(0x1B56_C4E9)) * half_extents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_blends_density_and_ambience() {
+        let mut weather = Weather::default();
+        weather.set(WeatherKind::Storm, 10.0);
+        assert_eq!(weather.density(), 0.0);
+        assert_eq!(weather.ambience_volume(WeatherKind::Clear), 1.0);
+
+        weather.advance(5.0);
+        assert!((weather.density() - 0.5).abs() < 1e-5);
+        assert!((weather.ambience_volume(WeatherKind::Storm) - 0.5).abs() < 1e-5);
+
+        weather.advance(100.0);
+        assert_eq!(weather.density(), 1.0);
+        assert!(weather.wetness > 0.0);
+        assert_eq!(weather.visible_kind(), WeatherKind::Storm);
+    }
+
+    #[test]
+    fn surfaces_dry_out_without_rain() {
+        let mut weather = Weather {
+            wetness: 0.5,
+            ..default()
+        };
+        weather.set(WeatherKind::Snow, 0.0);
+        weather.advance(1000.0);
+        assert_eq!(weather.wetness, 0.0);
+    }
+
+    #[test]
+    fn builtin_data_parses_and_particles_stay_in_the_box() {
+        assert!(!WeatherData::default().schedule.is_empty());
+        let half = WeatherSettings::default().area_half_extents;
+        for i in 0..100 {
+            let p = particle_start(i, half);
+            assert!(p.abs().cmple(half).all(), "{p:?}");
+        }
+    }
+}
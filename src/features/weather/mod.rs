@@ -0,0 +1,63 @@
+// src/features/weather/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+use crate::features::time_of_day::advance_time_of_day;
+
+pub mod component;
+pub mod systems;
+
+/// Weather feature.
+///
+/// Scope:
+/// - `Weather`: clear / rain / snow / storm with timed cross-fades and surface wetness
+/// - changes come from `SetWeather` (debug key `F7` cycles, scripts) and the daily
+///   schedule in `assets/data/weather.ron`; `WeatherChanged` announces them
+/// - precipitation: a pooled particle box that follows the active camera and drifts
+///   with the wind (`features::wind`)
+/// - `Wettable` surfaces get glossier while wet
+/// - looping ambience per weather (paths in the data file) fades with the transition
+///
+/// Design constraints:
+/// - state advances on the fixed timestep; visuals and audio only read it.
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::Weather>();
+        app.init_resource::<component::WeatherData>();
+        app.init_resource::<component::WeatherSettings>();
+        app.init_resource::<component::WeatherKeybinding>();
+
+        app.add_message::<systems::SetWeather>();
+        app.add_message::<systems::WeatherChanged>();
+
+        app.add_systems(
+            Startup,
+            (systems::spawn_precipitation, systems::spawn_weather_ambience),
+        );
+        app.add_systems(
+            Update,
+            systems::read_weather_debug_input
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(
+            Update,
+            (
+                systems::follow_camera_with_emitter,
+                systems::animate_precipitation,
+                systems::apply_surface_wetness,
+                systems::fade_weather_ambience,
+            )
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (systems::apply_weather_schedule, systems::update_weather)
+                .chain()
+                .after(advance_time_of_day)
+                .in_set(AppSet::FixedGameplay),
+        );
+    }
+}
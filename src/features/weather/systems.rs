@@ -0,0 +1,295 @@
+// src/features/weather/systems.rs
+use bevy::prelude::*;
+
+use super::component::{
+    PrecipitationEmitter, Weather, WeatherAmbience, WeatherData, WeatherKeybinding, WeatherKind,
+    WeatherParticle, WeatherSettings, Wettable, particle_start,
+};
+use crate::features::audio::component::{AudioBus, SoundVolume};
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::time_of_day::{TimeOfDay, crossed_hour};
+use crate::features::wind::component::{GlobalWind, WindZone, wind_at};
+
+/// Fade into another weather (debug key, schedules, scripts).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SetWeather {
+    pub kind: WeatherKind,
+    pub transition_secs: f32,
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeatherChanged {
+    pub from: WeatherKind,
+    pub to: WeatherKind,
+}
+
+/// Seconds the debug key's weather change takes.
+const DEBUG_TRANSITION_SECS: f32 = 3.0;
+
+/// Update: the debug key cycles to the next weather.
+pub fn read_weather_debug_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    binding: Res<WeatherKeybinding>,
+    weather: Res<Weather>,
+    mut requests: MessageWriter<SetWeather>,
+) {
+    if keyboard.just_pressed(binding.0) {
+        requests.write(SetWeather {
+            kind: weather.kind.next(),
+            transition_secs: DEBUG_TRANSITION_SECS,
+        });
+    }
+}
+
+/// FixedUpdate: schedule entries whose hour the clock just passed request their weather.
+pub fn apply_weather_schedule(
+    data: Res<WeatherData>,
+    clock: Option<Res<TimeOfDay>>,
+    mut last_hour: Local<Option<f32>>,
+    mut requests: MessageWriter<SetWeather>,
+) {
+    let Some(clock) = clock else {
+        return;
+    };
+    if let Some(last) = *last_hour {
+        for entry in &data.schedule {
+            if crossed_hour(last, clock.hour, entry.at_hour) {
+                requests.write(SetWeather {
+                    kind: entry.kind,
+                    transition_secs: entry.transition_secs,
+                });
+            }
+        }
+    }
+    *last_hour = Some(clock.hour);
+}
+
+/// FixedUpdate: apply weather requests, then advance the transition and wetness.
+pub fn update_weather(
+    time: Res<Time<Fixed>>,
+    mut weather: ResMut<Weather>,
+    mut requests: MessageReader<SetWeather>,
+    mut changed: MessageWriter<WeatherChanged>,
+) {
+    for req in requests.read() {
+        if req.kind == weather.kind {
+            continue;
+        }
+        let from = weather.kind;
+        weather.set(req.kind, req.transition_secs);
+        changed.write(WeatherChanged { from, to: req.kind });
+    }
+    weather.advance(time.delta_secs());
+}
+
+/// Startup: the particle pool (hidden until the weather needs it).
+pub fn spawn_precipitation(
+    mut commands: Commands,
+    settings: Res<WeatherSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Cuboid::from_size(Vec3::ONE));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.85, 0.9, 1.0, 0.6),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+
+    commands
+        .spawn((
+            PrecipitationEmitter,
+            Transform::default(),
+            Visibility::default(),
+            Name::new("PrecipitationEmitter"),
+        ))
+        .with_children(|emitter| {
+            for index in 0..settings.particle_pool {
+                emitter.spawn((
+                    WeatherParticle { index },
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(material.clone()),
+                    Transform::from_translation(particle_start(index, settings.area_half_extents)),
+                    Visibility::Hidden,
+                ));
+            }
+        });
+}
+
+/// Update: keep the emitter box centered on the active camera.
+#[expect(clippy::type_complexity)]
+pub fn follow_camera_with_emitter(
+    q_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<OffscreenCamera>)>,
+    mut q_emitter: Query<&mut Transform, With<PrecipitationEmitter>>,
+) {
+    let Some((_, camera_tr)) = q_camera.iter().find(|(c, _)| c.is_active) else {
+        return;
+    };
+    for mut transform in &mut q_emitter {
+        transform.translation = camera_tr.translation();
+    }
+}
+
+/// Update: drop the first `density * pool` particles through the box (drifting with
+/// the wind at the emitter), wrapping around its faces.
+pub fn animate_precipitation(
+    time: Res<Time>,
+    settings: Res<WeatherSettings>,
+    weather: Res<Weather>,
+    wind: Option<Res<GlobalWind>>,
+    q_zones: Query<(&WindZone, &GlobalTransform)>,
+    q_emitter: Query<&GlobalTransform, With<PrecipitationEmitter>>,
+    mut q_particles: Query<(&WeatherParticle, &mut Transform, &mut Visibility)>,
+) {
+    let dt = time.delta_secs();
+    let active = (weather.density() * settings.particle_pool as f32).round() as usize;
+    let snow = weather.visible_kind().is_snow();
+    let half = settings.area_half_extents;
+    let drift = match (&wind, q_emitter.iter().next()) {
+        (Some(wind), Some(emitter)) => {
+            let zones = q_zones.iter().map(|(z, tr)| (z, tr.translation()));
+            wind_at(wind.at(time.elapsed_secs()), zones, emitter.translation()).with_y(0.0)
+                * settings.wind_drift
+        }
+        _ => Vec3::ZERO,
+    };
+    let (fall, scale) = if snow {
+        (settings.snow_fall_speed, Vec3::splat(0.06))
+    } else {
+        (settings.rain_fall_speed, Vec3::new(0.015, 0.35, 0.015))
+    };
+
+    for (particle, mut transform, mut vis) in &mut q_particles {
+        if particle.index >= active {
+            *vis = Visibility::Hidden;
+            continue;
+        }
+        *vis = Visibility::Inherited;
+        transform.scale = scale;
+        transform.translation += (drift - Vec3::Y * fall) * dt;
+        if snow {
+            let phase = time.elapsed_secs() + particle.index as f32;
+            transform.translation.x += phase.sin() * settings.snow_sway * dt;
+        }
+        let p = &mut transform.translation;
+        *p = (*p + half).rem_euclid(half * 2.0) - half;
+    }
+}
+
+/// Update: wet surfaces get glossier (lower roughness) as wetness rises.
+pub fn apply_surface_wetness(
+    settings: Res<WeatherSettings>,
+    weather: Res<Weather>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_surfaces: Query<(&Wettable, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    if !weather.is_changed() {
+        return;
+    }
+    for (wettable, material) in &q_surfaces {
+        let roughness = wettable.dry_roughness.lerp(settings.wet_roughness, weather.wetness);
+        if let Some(material) = materials.get_mut(&material.0)
+            && (material.perceptual_roughness - roughness).abs() > 1e-3
+        {
+            material.perceptual_roughness = roughness;
+        }
+    }
+}
+
+/// Startup: one silent looping player per configured ambience (none without an
+/// `AssetServer`, e.g. headless).
+pub fn spawn_weather_ambience(
+    mut commands: Commands,
+    data: Res<WeatherData>,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    for (&kind, path) in &data.ambience {
+        commands.spawn((
+            WeatherAmbience { kind },
+            AudioBus::Sfx,
+            AudioPlayer::new(asset_server.load(path.clone())),
+            PlaybackSettings::LOOP.with_volume(bevy::audio::Volume::SILENT),
+            Name::new(format!("{kind:?} Ambience")),
+        ));
+    }
+}
+
+/// Update: ambience volumes follow the weather blend.
+pub fn fade_weather_ambience(
+    weather: Res<Weather>,
+    mut q_ambience: Query<(&WeatherAmbience, &mut SoundVolume)>,
+) {
+    for (ambience, mut volume) in &mut q_ambience {
+        volume.0 = weather.ambience_volume(ambience.kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<Fixed>::from_hz(60.0);
+        time.advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.init_resource::<Weather>();
+        world.init_resource::<WeatherData>();
+        world.init_resource::<Messages<SetWeather>>();
+        world.init_resource::<Messages<WeatherChanged>>();
+        world
+    }
+
+    #[test]
+    fn schedule_requests_weather_when_its_hour_passes() {
+        let mut world = setup_world();
+        world.insert_resource(TimeOfDay {
+            hour: 14.9,
+            hours_per_sec: 0.0,
+        });
+        let mut system = IntoSystem::into_system(apply_weather_schedule);
+        system.initialize(&mut world);
+        let _ = system.run((), &mut world);
+        world.resource_mut::<TimeOfDay>().hour = 15.1;
+        let _ = system.run((), &mut world);
+
+        let _ = world.run_system_once(update_weather);
+
+        let weather = *world.resource::<Weather>();
+        assert_eq!(weather.kind, WeatherKind::Rain);
+        assert_eq!(weather.previous, WeatherKind::Clear);
+        assert_eq!(world.resource::<Messages<WeatherChanged>>().len(), 1);
+    }
+
+    #[test]
+    fn only_dense_enough_pool_particles_are_shown() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(WeatherSettings {
+            particle_pool: 10,
+            ..default()
+        });
+        let mut weather = Weather::default();
+        weather.set(WeatherKind::Rain, 0.0);
+        world.insert_resource(weather);
+        for index in 0..10 {
+            world.spawn((WeatherParticle { index }, Transform::default(), Visibility::Hidden));
+        }
+
+        let _ = world.run_system_once(animate_precipitation);
+
+        let shown = world
+            .query::<&Visibility>()
+            .iter(&world)
+            .filter(|v| **v == Visibility::Inherited)
+            .count();
+        assert_eq!(shown, 6);
+    }
+}
@@ -0,0 +1,138 @@
+// src/features/wind/component.rs
+use bevy::prelude::*;
+
+/// Wind everywhere in the world, with a slow sinusoidal gust.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GlobalWind {
+    /// Horizontal direction the wind blows towards (normalized on use).
+    pub direction: Vec3,
+    /// Base acceleration (world units/sec²).
+    pub strength: f32,
+    /// Gust swing as a fraction of `strength` (`0.0` = steady).
+    pub gust: f32,
+    pub gust_period_secs: f32,
+}
+
+impl Default for GlobalWind {
+    fn default() -> Self {
+        Self {
+            direction: Vec3::X,
+            strength: 2.0,
+            gust: 0.5,
+            gust_period_secs: 6.0,
+        }
+    }
+}
+
+impl GlobalWind {
+    /// Acceleration at time `elapsed_secs`.
+    pub fn at(&self, elapsed_secs: f32) -> Vec3 {
+        let phase = elapsed_secs * std::f32::consts::TAU / self.gust_period_secs.max(f32::EPSILON);
+        let strength = self.strength * (1.0 + self.gust * phase.sin());
+        self.direction.normalize_or_zero() * strength.max(0.0)
+    }
+}
+
+/// Axis-aligned box of extra wind (vents, canyons, fans) around the entity's translation.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct WindZone {
+    pub half_extents: Vec3,
+    /// Acceleration added inside the box (world units/sec²).
+    pub acceleration: Vec3,
+}
+
+impl WindZone {
+    pub fn contains(&self, center: Vec3, point: Vec3) -> bool {
+        (point - center).abs().cmple(self.half_extents).all()
+    }
+}
+
+/// Opt-in: how strongly wind pushes this entity's `ExternalForce`
+/// (`1.0` = full, light debris more, heavy props less).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct WindExposure(pub f32);
+
+impl Default for WindExposure {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Wind zones placed at startup: `(center, half_extents, acceleration)`.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WindZoneSpawns(pub Vec<(Vec3, Vec3, Vec3)>);
+
+impl Default for WindZoneSpawns {
+    fn default() -> Self {
+        // An updraft-ish gust corridor next to the training ground.
+        Self(vec![(
+            Vec3::new(-8.0, 1.5, 0.0),
+            Vec3::new(2.0, 1.5, 4.0),
+            Vec3::new(0.0, 0.0, 30.0),
+        )])
+    }
+}
+
+/// Debug view: arrow field showing the wind around the camera.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct WindDebug {
+    pub enabled: bool,
+    pub toggle: KeyCode,
+    /// Arrows per side of the sampled grid.
+    pub grid: u32,
+    pub spacing: f32,
+}
+
+impl Default for WindDebug {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle: KeyCode::F8,
+            grid: 9,
+            spacing: 2.0,
+        }
+    }
+}
+
+/// Total wind acceleration at `point`.
+pub fn wind_at<'a>(
+    global: Vec3,
+    zones: impl IntoIterator<Item = (&'a WindZone, Vec3)>,
+    point: Vec3,
+) -> Vec3 {
+    zones
+        .into_iter()
+        .filter(|(zone, center)| zone.contains(*center, point))
+        .fold(global, |acc, (zone, _)| acc + zone.acceleration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zones_add_to_global_wind_only_inside() {
+        let zone = WindZone {
+            half_extents: Vec3::ONE,
+            acceleration: Vec3::Z * 10.0,
+        };
+        let zones = [(&zone, Vec3::new(5.0, 0.0, 0.0))];
+        let global = Vec3::X;
+
+        assert_eq!(wind_at(global, zones, Vec3::new(5.5, 0.5, -0.5)), Vec3::new(1.0, 0.0, 10.0));
+        assert_eq!(wind_at(global, zones, Vec3::ZERO), global);
+    }
+
+    #[test]
+    fn gusts_swing_around_the_base_strength() {
+        let wind = GlobalWind {
+            direction: Vec3::new(0.0, 0.0, 2.0),
+            strength: 2.0,
+            gust: 0.5,
+            gust_period_secs: 4.0,
+        };
+        assert!((wind.at(0.0) - Vec3::Z * 2.0).length() < 1e-5);
+        assert!((wind.at(1.0) - Vec3::Z * 3.0).length() < 1e-5);
+        assert!((wind.at(3.0) - Vec3::Z * 1.0).length() < 1e-5);
+    }
+}
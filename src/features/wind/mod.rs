@@ -0,0 +1,50 @@
+// src/features/wind/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{AppSet, HOTKEYS, in_input_context};
+
+pub mod component;
+pub mod systems;
+
+/// Wind feature.
+///
+/// Scope:
+/// - `GlobalWind` (direction, strength, gusts) plus box-shaped `WindZone` volumes
+/// - entities with `WindExposure` + `ExternalForce` get the wind at their position as
+///   acceleration each fixed tick (players by default; projectiles and props opt in)
+/// - weather particles drift with the wind at the emitter
+/// - debug view (`F8`): arrow field around the camera and zone outlines
+///
+/// Design constraints:
+/// - wind never writes `Transform`; it only feeds the `ExternalForce` pipeline.
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<component::GlobalWind>();
+        app.init_resource::<component::WindZoneSpawns>();
+        app.init_resource::<component::WindDebug>();
+
+        app.add_systems(Startup, systems::spawn_wind_zones);
+        app.add_systems(
+            Update,
+            systems::toggle_wind_debug
+                .in_set(AppSet::Input)
+                .run_if(in_input_context(HOTKEYS)),
+        );
+        app.add_systems(
+            Update,
+            systems::draw_wind_debug
+                .run_if(systems::wind_debug_enabled)
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::grant_player_wind_exposure.in_set(AppSet::FixedGameplay),
+        );
+        app.add_systems(
+            FixedUpdate,
+            systems::apply_wind.in_set(AppSet::ApplyForces),
+        );
+    }
+}
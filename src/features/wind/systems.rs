@@ -0,0 +1,136 @@
+// src/features/wind/systems.rs
+use bevy::prelude::*;
+
+use super::component::{GlobalWind, WindDebug, WindExposure, WindZone, WindZoneSpawns, wind_at};
+use crate::features::camera::component::OffscreenCamera;
+use crate::features::movement::component::ExternalForce;
+use crate::features::player::component::Player;
+
+/// Startup: place the `WindZoneSpawns` volumes.
+pub fn spawn_wind_zones(mut commands: Commands, spawns: Res<WindZoneSpawns>) {
+    for &(center, half_extents, acceleration) in &spawns.0 {
+        commands.spawn((
+            WindZone {
+                half_extents,
+                acceleration,
+            },
+            Transform::from_translation(center),
+            Name::new("WindZone"),
+        ));
+    }
+}
+
+/// FixedUpdate: players are exposed to the wind.
+pub fn grant_player_wind_exposure(
+    mut commands: Commands,
+    q_new: Query<Entity, (Added<Player>, Without<WindExposure>)>,
+) {
+    for entity in &q_new {
+        commands.entity(entity).insert(WindExposure::default());
+    }
+}
+
+/// FixedUpdate: push exposed entities through their `ExternalForce`
+/// (integrated by the movers after this).
+pub fn apply_wind(
+    time: Res<Time<Fixed>>,
+    wind: Res<GlobalWind>,
+    q_zones: Query<(&WindZone, &GlobalTransform)>,
+    mut q_exposed: Query<(&Transform, &WindExposure, &mut ExternalForce)>,
+) {
+    let global = wind.at(time.elapsed_secs());
+    for (transform, exposure, mut external) in &mut q_exposed {
+        let zones = q_zones.iter().map(|(z, tr)| (z, tr.translation()));
+        external.apply_acceleration(wind_at(global, zones, transform.translation) * exposure.0);
+    }
+}
+
+/// Update: toggle the wind debug view.
+pub fn toggle_wind_debug(keyboard: Res<ButtonInput<KeyCode>>, mut debug: ResMut<WindDebug>) {
+    if keyboard.just_pressed(debug.toggle) {
+        debug.enabled = !debug.enabled;
+    }
+}
+
+pub fn wind_debug_enabled(debug: Res<WindDebug>) -> bool {
+    debug.enabled
+}
+
+/// Update (debug only): arrow field of the wind on a ground grid around the camera,
+/// plus zone outlines.
+pub fn draw_wind_debug(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    wind: Res<GlobalWind>,
+    debug: Res<WindDebug>,
+    q_camera: Query<&GlobalTransform, (With<Camera3d>, Without<OffscreenCamera>)>,
+    q_zones: Query<(&WindZone, &GlobalTransform)>,
+) {
+    let Some(camera) = q_camera.iter().next() else {
+        return;
+    };
+    let global = wind.at(time.elapsed_secs());
+    let origin = (camera.translation() / debug.spacing).round().with_y(0.0) * debug.spacing;
+    let half = debug.grid as i32 / 2;
+
+    for x in -half..=half {
+        for z in -half..=half {
+            let start = origin + Vec3::new(x as f32, 0.0, z as f32) * debug.spacing + Vec3::Y;
+            let zones = q_zones.iter().map(|(zone, tr)| (zone, tr.translation()));
+            let accel = wind_at(global, zones, start);
+            // Log-ish scale keeps strong zones readable next to a light breeze.
+            let length = (1.0 + accel.length()).ln() * 0.5;
+            let end = start + accel.normalize_or_zero() * length;
+            gizmos.arrow(start, end, Color::srgb(0.5, 0.8, 1.0));
+        }
+    }
+    for (zone, tr) in &q_zones {
+        gizmos.cube(
+            Transform::from_translation(tr.translation()).with_scale(zone.half_extents * 2.0),
+            Color::srgba(0.5, 0.8, 1.0, 0.4),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn exposure_scales_the_push_and_unexposed_entities_are_left_alone() {
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::from_hz(60.0));
+        world.insert_resource(GlobalWind {
+            direction: Vec3::X,
+            strength: 2.0,
+            gust: 0.0,
+            gust_period_secs: 1.0,
+        });
+        world.spawn((
+            WindZone {
+                half_extents: Vec3::splat(2.0),
+                acceleration: Vec3::Z * 10.0,
+            },
+            GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+        ));
+        let open = world
+            .spawn((Transform::default(), WindExposure(0.5), ExternalForce::default()))
+            .id();
+        let in_zone = world
+            .spawn((
+                Transform::from_xyz(10.0, 0.0, 0.0),
+                WindExposure::default(),
+                ExternalForce::default(),
+            ))
+            .id();
+        let sheltered = world.spawn((Transform::default(), ExternalForce::default())).id();
+
+        let _ = world.run_system_once(apply_wind);
+
+        let accel = |world: &World, e| world.get::<ExternalForce>(e).unwrap().acceleration;
+        assert_eq!(accel(&world, open), Vec3::X);
+        assert_eq!(accel(&world, in_zone), Vec3::new(2.0, 0.0, 10.0));
+        assert_eq!(accel(&world, sheltered), Vec3::ZERO);
+    }
+}
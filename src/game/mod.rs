@@ -1,8 +1,10 @@
 // src/game/mod.rs
 use bevy::prelude::*;
 
+pub mod modes;
 mod scene;
 
+use crate::app::AppSet;
 use crate::features;
 
 /// Game-level composition plugin.
@@ -10,6 +12,8 @@ use crate::features;
 /// This is where we wire together the "world" parts of the application:
 /// - minimal scene setup (camera / light / any debug ground)
 /// - gameplay feature plugins (player, later: rotation, collision, UI, etc.)
+/// - game modes (`GameMode`): free play, survival and time trial, one plugin each,
+///   setting the rules, HUD, spawners and scoring of their runs
 ///
 /// Design rule:
 /// - `game` decides *what runs*
@@ -26,5 +30,17 @@ impl Plugin for GamePlugin {
         // We keep feature registration centralized so it's easy to toggle/replace
         // features later (e.g. swap Player controller, add physics, etc.).
         app.add_plugins(features::FeaturesPlugin);
+
+        // Game modes: each starts its runs on `RunStarted`; leftovers of the
+        // previous run are cleared first, whatever its mode.
+        app.add_plugins((
+            modes::free_play::FreePlayPlugin,
+            modes::survival::SurvivalPlugin,
+            modes::time_trial::TimeTrialPlugin,
+        ));
+        app.add_systems(
+            Update,
+            (modes::clear_mode_entities, modes::show_active_mode_hud).after(AppSet::Input),
+        );
     }
 }
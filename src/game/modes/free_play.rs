@@ -0,0 +1,33 @@
+// src/game/modes/free_play.rs
+use bevy::prelude::*;
+
+use super::{ModeEntity, set_ambient_spawners};
+use crate::app::{AppSet, GameMode, RunStarted};
+use crate::features::enemy::component::EnemySpawner;
+
+/// Free play (sandbox) mode.
+///
+/// Scope:
+/// - the world as authored: its own spawners run, nothing is won or lost
+/// - no HUD line and no score (nothing reaches the leaderboard)
+///
+/// It is also what the game runs before any run is started from the menu.
+pub struct FreePlayPlugin;
+
+impl Plugin for FreePlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<RunStarted>();
+        app.add_systems(Update, start_free_play.after(AppSet::Input));
+    }
+}
+
+/// Update: a free play run wakes the world's spawners other modes put to sleep.
+pub fn start_free_play(
+    mut commands: Commands,
+    mut started: MessageReader<RunStarted>,
+    q_spawners: Query<Entity, (With<EnemySpawner>, Without<ModeEntity>)>,
+) {
+    if started.read().last().is_some_and(|run| run.mode == GameMode::FreePlay) {
+        set_ambient_spawners(&mut commands, &q_spawners, true);
+    }
+}
@@ -0,0 +1,129 @@
+// src/game/modes/mod.rs
+use bevy::prelude::*;
+
+use crate::app::{DespawnQueue, GameMode, RunStarted};
+use crate::features::enemy::component::{Dormant, EnemySpawner, SpawnedBy};
+
+pub mod free_play;
+pub mod survival;
+pub mod time_trial;
+
+/// Something a mode put into the world for its run (wave spawners, finish lines);
+/// removed when the next run starts, whatever its mode.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeEntity(pub GameMode);
+
+/// HUD node of a mode, shown only while that mode is being played.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeHud(pub GameMode);
+
+/// Update: a new run clears the previous run's mode entities and the enemies their
+/// spawners produced.
+pub fn clear_mode_entities(
+    mut despawns: DespawnQueue,
+    mut started: MessageReader<RunStarted>,
+    q_mode_entities: Query<Entity, With<ModeEntity>>,
+    q_spawned: Query<(Entity, &SpawnedBy)>,
+) {
+    if started.read().last().is_none() {
+        return;
+    }
+    for (enemy, by) in &q_spawned {
+        if q_mode_entities.contains(by.0) {
+            despawns.despawn(enemy);
+        }
+    }
+    for entity in &q_mode_entities {
+        despawns.despawn(entity);
+    }
+}
+
+/// Switch the world's own spawners (data, biome, dungeon) on or off; the modes'
+/// spawners are left alone.
+pub fn set_ambient_spawners(
+    commands: &mut Commands,
+    q_spawners: &Query<Entity, (With<EnemySpawner>, Without<ModeEntity>)>,
+    awake: bool,
+) {
+    for spawner in q_spawners {
+        if awake {
+            commands.entity(spawner).remove::<Dormant>();
+        } else {
+            commands.entity(spawner).insert(Dormant);
+        }
+    }
+}
+
+/// Update: show the HUD of the mode being played, hide the others.
+pub fn show_active_mode_hud(
+    mode: Res<State<GameMode>>,
+    mut q_hud: Query<(&ModeHud, &mut Visibility)>,
+) {
+    for (hud, mut vis) in &mut q_hud {
+        let want = if hud.0 == *mode.get() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        vis.set_if_neq(want);
+    }
+}
+
+/// A top-center HUD line for `mode` (text filled in by the mode).
+pub fn mode_hud_text(mode: GameMode) -> impl Bundle {
+    (
+        ModeHud(mode),
+        Text::new(""),
+        TextFont::from_font_size(22.0),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(24.0),
+            left: Val::Percent(45.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        Name::new(format!("{} HUD", mode.label())),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use crate::features::enemy::component::{Enemy, SpawnerDef};
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn a_new_run_clears_the_previous_modes_leftovers() {
+        let mut world = World::new();
+        world.init_resource::<Messages<RunStarted>>();
+        let wave = world.spawn(ModeEntity(GameMode::Survival)).id();
+        let wave_enemy = world.spawn((Enemy, SpawnedBy(wave))).id();
+        let ambient = world
+            .spawn(EnemySpawner::new(SpawnerDef {
+                position: (0.0, 0.0, 0.0),
+                radius: 1.0,
+                max_alive: 1,
+                interval_secs: 1.0,
+                hours: None,
+                retreat_outside_hours: false,
+                total: None,
+            }))
+            .id();
+        let ambient_enemy = world.spawn((Enemy, SpawnedBy(ambient))).id();
+
+        let _ = world.run_system_once(clear_mode_entities);
+        assert!(!world.entity(wave).contains::<Despawning>(), "nothing started yet");
+
+        world.write_message(RunStarted {
+            mode: GameMode::TimeTrial,
+        });
+        let _ = world.run_system_once(clear_mode_entities);
+
+        assert!(world.entity(wave).contains::<Despawning>());
+        assert!(world.entity(wave_enemy).contains::<Despawning>());
+        assert!(!world.entity(ambient).contains::<Despawning>());
+        assert!(!world.entity(ambient_enemy).contains::<Despawning>());
+    }
+}
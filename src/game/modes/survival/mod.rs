@@ -0,0 +1,675 @@
+// src/game/modes/survival/mod.rs
+use bevy::prelude::*;
+
+use super::{ModeEntity, ModeHud, mode_hud_text, set_ambient_spawners};
+use crate::app::{
+    AppSet, DespawnQueue, Faction, GameMode, InputContextStack, RunStarted, SimTick, TickRate,
+};
+use crate::features::achievements::component::{AchievementLibrary, Stat};
+use crate::features::achievements::systems::{AchievementUnlocked, RecordStat, UnlockAchievement};
+use crate::features::character_material::toon::Stylized;
+use crate::features::collision::component::Collider;
+use crate::features::dialogue::component::Talkable;
+use crate::features::enemy::component::{Enemy, EnemySpawner, SpawnedBy, SpawnerDef};
+use crate::features::health::component::Health;
+use crate::features::health::damage::{DamageEvent, Died};
+use crate::features::interaction::component::Interactable;
+use crate::features::leaderboard::systems::{RunFinished, RunRecorded};
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::notifications::systems::Notify;
+use crate::features::npc::component::{NPC_HALF_HEIGHT, NPC_RADIUS, Npc};
+use crate::features::player::component::Player;
+use crate::features::player::death::PlayerDied;
+use crate::features::race::component::ticks_to_secs;
+use crate::features::shop::component::Vendor;
+use crate::features::wallet::systems::EarnCoins;
+
+pub mod summary;
+
+/// Arena, pacing and scoring of survival runs.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SurvivalSettings {
+    /// Ground point waves spawn around.
+    pub arena: Vec3,
+    pub radius: f32,
+    /// Enemies in wave 1; every later wave adds `wave_growth`.
+    pub first_wave: u32,
+    pub wave_growth: u32,
+    /// Delay between two enemies of a wave.
+    pub spawn_interval_secs: f32,
+    pub points_per_kill: u64,
+    /// Bonus for clearing wave `n` is `n * points_per_wave`.
+    pub points_per_wave: u64,
+    /// Every wave after the first scales its points by this much more
+    /// (wave 3 at `0.25`: x1.5).
+    pub multiplier_per_wave: f32,
+    /// Scales the clear bonus of a wave the player took no damage in.
+    pub flawless_multiplier: f32,
+    /// Break between a cleared wave and the next one; the quartermaster trades
+    /// meanwhile.
+    pub intermission_secs: f32,
+    /// Where the quartermaster stands during intermissions.
+    pub quartermaster: Vec3,
+    /// Coins for clearing wave `n` are `n * coins_per_wave`.
+    pub coins_per_wave: u32,
+    /// Reaching this wave unlocks "last_stand".
+    pub milestone_wave: u32,
+}
+
+impl Default for SurvivalSettings {
+    fn default() -> Self {
+        Self {
+            arena: Vec3::new(0.0, 0.0, -8.0),
+            radius: 6.0,
+            first_wave: 3,
+            wave_growth: 2,
+            spawn_interval_secs: 0.75,
+            points_per_kill: 10,
+            points_per_wave: 50,
+            multiplier_per_wave: 0.25,
+            flawless_multiplier: 1.5,
+            intermission_secs: 20.0,
+            quartermaster: Vec3::new(3.0, 0.0, -1.0),
+            coins_per_wave: 10,
+            milestone_wave: 10,
+        }
+    }
+}
+
+impl SurvivalSettings {
+    pub fn wave_size(&self, wave: u32) -> u32 {
+        self.first_wave + self.wave_growth * wave.saturating_sub(1)
+    }
+
+    /// Points multiplier of `wave` (x1 for the first).
+    pub fn multiplier(&self, wave: u32) -> f32 {
+        1.0 + self.multiplier_per_wave * wave.saturating_sub(1) as f32
+    }
+
+    /// Points for a kill in `wave`.
+    pub fn kill_points(&self, wave: u32) -> u64 {
+        scaled(self.points_per_kill, self.multiplier(wave))
+    }
+
+    /// Bonus for clearing `wave`.
+    pub fn clear_bonus(&self, wave: u32, flawless: bool) -> u64 {
+        let flawless = if flawless { self.flawless_multiplier } else { 1.0 };
+        scaled(self.points_per_wave * wave as u64, self.multiplier(wave) * flawless)
+    }
+}
+
+fn scaled(points: u64, multiplier: f32) -> u64 {
+    (points as f64 * f64::from(multiplier)).round() as u64
+}
+
+/// How a cleared wave went.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaveResult {
+    pub wave: u32,
+    pub kills: u32,
+    /// Kill points and clear bonus, multipliers applied.
+    pub points: u64,
+    pub multiplier: f32,
+    /// The player took no damage during the wave.
+    pub flawless: bool,
+}
+
+/// The survival run in progress (`active` until the player dies).
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct SurvivalRun {
+    pub active: bool,
+    /// Current wave (`0` until the first one spawns).
+    pub wave: u32,
+    pub kills: u32,
+    pub score: u64,
+    /// `SimTick` the run started on.
+    pub started_tick: u64,
+    /// Seconds survived, once the run is over.
+    pub survived_secs: Option<f32>,
+    /// Between waves: the `SimTick` the next wave spawns on.
+    pub next_wave_tick: Option<u64>,
+    /// Kills and points of the current wave so far.
+    pub wave_kills: u32,
+    pub wave_points: u64,
+    /// The player was hurt during the current wave (no flawless bonus).
+    pub wave_hurt: bool,
+    /// Cleared waves, oldest first.
+    pub cleared: Vec<WaveResult>,
+}
+
+impl SurvivalRun {
+    /// Between two waves (the quartermaster is in).
+    pub fn in_intermission(&self) -> bool {
+        self.active && self.next_wave_tick.is_some()
+    }
+}
+
+/// The spawner of the current wave.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SurvivalWave;
+
+/// The vendor who shows up between waves.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Quartermaster;
+
+/// A survival run just ended (opens the summary).
+#[derive(Message, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SurvivalEnded;
+
+/// Survival mode.
+///
+/// Scope:
+/// - the world's spawners sleep; waves of growing size spawn around the arena
+///   (`SurvivalSettings`), the next one an intermission after every enemy of the
+///   last is dead
+/// - score: points per kill plus a bonus per cleared wave, both scaled by the
+///   wave's multiplier; the bonus grows further when the wave was cleared without
+///   taking damage
+/// - intermissions: the quartermaster (shop "quartermaster") trades next to the
+///   arena and cleared waves pay coins
+/// - cleared waves count towards the `WavesCleared` statistic; flawless waves and
+///   the milestone wave unlock achievements
+/// - the run ends when the player dies: the score and wave reached go to the
+///   leaderboard (`RunFinished`) and the summary screen (`summary`) opens
+/// - HUD: wave, kills and score; the countdown during intermissions
+pub struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SurvivalSettings>();
+        app.init_resource::<SurvivalRun>();
+        app.init_resource::<summary::SurvivalSummary>();
+        app.init_resource::<AchievementLibrary>();
+        app.init_resource::<InputContextStack>();
+        app.init_resource::<MeshLibrary>();
+        app.add_message::<RunStarted>();
+        app.add_message::<Died>();
+        app.add_message::<DamageEvent>();
+        app.add_message::<PlayerDied>();
+        app.add_message::<RunFinished>();
+        app.add_message::<RunRecorded>();
+        app.add_message::<EarnCoins>();
+        app.add_message::<RecordStat>();
+        app.add_message::<UnlockAchievement>();
+        app.add_message::<AchievementUnlocked>();
+        app.add_message::<SurvivalEnded>();
+        app.add_message::<Notify>();
+
+        app.add_systems(
+            Startup,
+            (spawn_survival_hud, summary::spawn_survival_summary),
+        );
+        app.add_systems(Update, start_survival.after(AppSet::Input));
+        app.add_systems(
+            Update,
+            (
+                count_survival_kills,
+                track_survival_damage,
+                advance_survival_waves,
+                staff_survival_quartermaster,
+                end_survival_on_death,
+                update_survival_hud,
+            )
+                .chain()
+                .after(start_survival)
+                .run_if(in_state(GameMode::Survival)),
+        );
+        app.add_systems(
+            Update,
+            (
+                summary::open_survival_summary,
+                summary::collect_survival_summary,
+                summary::click_survival_summary,
+                summary::update_survival_summary,
+            )
+                .chain()
+                .after(end_survival_on_death),
+        );
+    }
+}
+
+/// Startup: spawn the (hidden) survival HUD line.
+pub fn spawn_survival_hud(mut commands: Commands) {
+    commands.spawn(mode_hud_text(GameMode::Survival));
+}
+
+/// Update: a new run resets survival; a survival run puts the world's spawners to
+/// sleep.
+pub fn start_survival(
+    mut commands: Commands,
+    tick: Res<SimTick>,
+    mut started: MessageReader<RunStarted>,
+    mut run: ResMut<SurvivalRun>,
+    q_spawners: Query<Entity, (With<EnemySpawner>, Without<ModeEntity>)>,
+) {
+    let Some(started) = started.read().last() else {
+        return;
+    };
+    *run = SurvivalRun {
+        active: started.mode == GameMode::Survival,
+        started_tick: tick.0,
+        ..default()
+    };
+    if run.active {
+        set_ambient_spawners(&mut commands, &q_spawners, false);
+    }
+}
+
+/// Update: score wave enemies as they die, at the wave's multiplier.
+pub fn count_survival_kills(
+    settings: Res<SurvivalSettings>,
+    mut run: ResMut<SurvivalRun>,
+    mut died: MessageReader<Died>,
+    q_enemies: Query<&SpawnedBy, With<Enemy>>,
+    q_waves: Query<(), With<SurvivalWave>>,
+) {
+    for death in died.read() {
+        let from_wave = q_enemies.get(death.entity).is_ok_and(|by| q_waves.contains(by.0));
+        if run.active && from_wave {
+            let points = settings.kill_points(run.wave);
+            run.kills += 1;
+            run.wave_kills += 1;
+            run.score += points;
+            run.wave_points += points;
+        }
+    }
+}
+
+/// Update: a hit on the player while a wave is up costs that wave its flawless
+/// bonus.
+pub fn track_survival_damage(
+    mut run: ResMut<SurvivalRun>,
+    mut damage: MessageReader<DamageEvent>,
+    q_players: Query<(), With<Player>>,
+) {
+    let hurt = damage
+        .read()
+        .any(|hit| hit.amount > 0.0 && q_players.contains(hit.target));
+    if hurt && run.active && run.wave > 0 && run.next_wave_tick.is_none() && !run.wave_hurt {
+        run.wave_hurt = true;
+    }
+}
+
+/// Update: once the current wave has spawned and died out, bank its bonus, pay
+/// the player and start the intermission; when that is over, spawn the next
+/// (bigger) wave.
+#[expect(clippy::too_many_arguments)]
+pub fn advance_survival_waves(
+    mut commands: Commands,
+    settings: Res<SurvivalSettings>,
+    tick: Res<SimTick>,
+    rate: Res<TickRate>,
+    mut run: ResMut<SurvivalRun>,
+    q_waves: Query<(Entity, &EnemySpawner), With<SurvivalWave>>,
+    q_enemies: Query<(&SpawnedBy, &Health), With<Enemy>>,
+    q_players: Query<Entity, With<Player>>,
+    mut coins: MessageWriter<EarnCoins>,
+    mut stats: MessageWriter<RecordStat>,
+    mut unlock: MessageWriter<UnlockAchievement>,
+    mut notify: MessageWriter<Notify>,
+) {
+    if !run.active {
+        return;
+    }
+    if let Some((wave, spawner)) = q_waves.iter().next() {
+        let alive = q_enemies
+            .iter()
+            .any(|(by, health)| by.0 == wave && !health.is_depleted());
+        if !spawner.is_exhausted() || alive {
+            return;
+        }
+        // Stop tracking the cleared wave; its ragdolls go with the next run.
+        commands.entity(wave).remove::<SurvivalWave>();
+
+        let flawless = !run.wave_hurt;
+        let bonus = settings.clear_bonus(run.wave, flawless);
+        run.score += bonus;
+        let result = WaveResult {
+            wave: run.wave,
+            kills: run.wave_kills,
+            points: run.wave_points + bonus,
+            multiplier: settings.multiplier(run.wave),
+            flawless,
+        };
+        run.cleared.push(result);
+
+        let paid = settings.coins_per_wave * run.wave;
+        for player in &q_players {
+            coins.write(EarnCoins {
+                entity: player,
+                amount: paid,
+            });
+        }
+        stats.write(RecordStat {
+            stat: Stat::WavesCleared,
+            amount: 1,
+        });
+        if flawless {
+            unlock.write(UnlockAchievement {
+                id: "untouchable".into(),
+            });
+        }
+
+        let break_ticks = (f64::from(settings.intermission_secs) * rate.0).round() as u64;
+        run.next_wave_tick = Some(tick.0 + break_ticks);
+        notify.write(Notify::info(format!(
+            "Wave {} cleared: +{} pts{}, +{paid} coins. Next wave in {:.0}s",
+            result.wave,
+            result.points,
+            if flawless { " (flawless)" } else { "" },
+            settings.intermission_secs
+        )));
+        return;
+    }
+    if run.next_wave_tick.is_some_and(|at| tick.0 < at) {
+        return;
+    }
+
+    run.next_wave_tick = None;
+    run.wave += 1;
+    run.wave_kills = 0;
+    run.wave_points = 0;
+    run.wave_hurt = false;
+    if run.wave == settings.milestone_wave {
+        unlock.write(UnlockAchievement {
+            id: "last_stand".into(),
+        });
+    }
+    commands.spawn((
+        SurvivalWave,
+        ModeEntity(GameMode::Survival),
+        EnemySpawner::new(SpawnerDef {
+            position: settings.arena.into(),
+            radius: settings.radius,
+            max_alive: settings.wave_size(run.wave),
+            interval_secs: settings.spawn_interval_secs,
+            hours: None,
+            retreat_outside_hours: false,
+            total: Some(settings.wave_size(run.wave)),
+        }),
+        Transform::from_translation(settings.arena),
+        Name::new(format!("Survival Wave {}", run.wave)),
+    ));
+}
+
+/// Update: the quartermaster stands by the arena during intermissions and leaves
+/// when the next wave comes (or the run ends).
+pub fn staff_survival_quartermaster(
+    mut commands: Commands,
+    mut despawns: DespawnQueue,
+    settings: Res<SurvivalSettings>,
+    run: Res<SurvivalRun>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_quartermasters: Query<Entity, With<Quartermaster>>,
+) {
+    if !run.in_intermission() {
+        for quartermaster in &q_quartermasters {
+            despawns.despawn(quartermaster);
+        }
+        return;
+    }
+    if !q_quartermasters.is_empty() {
+        return;
+    }
+    let position = settings.quartermaster + Vec3::Y * NPC_HALF_HEIGHT;
+    // The shared capsule is 1 wide and 2 tall.
+    let scale = Vec3::new(NPC_RADIUS * 2.0, NPC_HALF_HEIGHT, NPC_RADIUS * 2.0);
+    commands.spawn((
+        Quartermaster,
+        ModeEntity(GameMode::Survival),
+        Npc {
+            name: "Quartermaster".into(),
+        },
+        Faction::Villagers,
+        Vendor::new("quartermaster"),
+        Interactable::new("Talk to the Quartermaster"),
+        Talkable {
+            dialogue: "quartermaster".into(),
+        },
+        Collider::cuboid(Vec3::new(NPC_RADIUS, NPC_HALF_HEIGHT, NPC_RADIUS)),
+        Mesh3d(meshes.get(MeshPrimitive::Capsule)),
+        MeshMaterial3d(materials.add(Color::srgb_u8(200, 160, 70))),
+        Stylized,
+        Transform::from_translation(position).with_scale(scale),
+        Name::new("Quartermaster"),
+    ));
+}
+
+/// Update: the player's death ends the run, records it and opens the summary.
+pub fn end_survival_on_death(
+    tick: Res<SimTick>,
+    rate: Res<TickRate>,
+    mut run: ResMut<SurvivalRun>,
+    mut player_died: MessageReader<PlayerDied>,
+    mut finished: MessageWriter<RunFinished>,
+    mut ended: MessageWriter<SurvivalEnded>,
+    mut notify: MessageWriter<Notify>,
+) {
+    if player_died.read().last().is_none() || !run.active {
+        return;
+    }
+    run.active = false;
+    run.next_wave_tick = None;
+    run.survived_secs = Some(ticks_to_secs(tick.0.saturating_sub(run.started_tick), *rate));
+    finished.write(RunFinished {
+        mode: GameMode::Survival.id().into(),
+        score: run.score,
+        wave_reached: Some(run.wave),
+        completion_secs: None,
+    });
+    ended.write(SurvivalEnded);
+    notify.write(Notify::info(format!(
+        "Survived to wave {} ({} points)",
+        run.wave, run.score
+    )));
+}
+
+/// Update: mirror the run into the HUD line.
+pub fn update_survival_hud(
+    tick: Res<SimTick>,
+    rate: Res<TickRate>,
+    run: Res<SurvivalRun>,
+    mut q_hud: Query<(&ModeHud, &mut Text)>,
+) {
+    let mut text = format!("Wave {}  ·  {} kills  ·  {} pts", run.wave, run.kills, run.score);
+    if let Some(at) = run.next_wave_tick.filter(|_| run.active) {
+        let secs = ticks_to_secs(at.saturating_sub(tick.0), *rate);
+        text.push_str(&format!("  ·  next wave in {:.0}s", secs.ceil()));
+    }
+    for (hud, mut line) in &mut q_hud {
+        if hud.0 == GameMode::Survival && line.0 != text {
+            line.0.clone_from(&text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Despawning;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<SurvivalSettings>();
+        world.init_resource::<SurvivalRun>();
+        world.init_resource::<SimTick>();
+        world.init_resource::<TickRate>();
+        world.init_resource::<Messages<RunStarted>>();
+        world.init_resource::<Messages<Died>>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.init_resource::<Messages<PlayerDied>>();
+        world.init_resource::<Messages<RunFinished>>();
+        world.init_resource::<Messages<EarnCoins>>();
+        world.init_resource::<Messages<RecordStat>>();
+        world.init_resource::<Messages<UnlockAchievement>>();
+        world.init_resource::<Messages<SurvivalEnded>>();
+        world.init_resource::<Messages<Notify>>();
+        world.write_message(RunStarted {
+            mode: GameMode::Survival,
+        });
+        let _ = world.run_system_once(start_survival);
+        world
+    }
+
+    fn current_wave(world: &mut World) -> (Entity, EnemySpawner) {
+        let mut q = world.query_filtered::<(Entity, &EnemySpawner), With<SurvivalWave>>();
+        let (entity, spawner) = q.single(world).unwrap();
+        (entity, spawner.clone())
+    }
+
+    /// Pretend the wave spawned all its enemies and they died.
+    fn kill_wave(world: &mut World) {
+        let (wave, spawner) = current_wave(world);
+        let total = spawner.def.total.unwrap();
+        world.get_mut::<EnemySpawner>(wave).unwrap().spawned = total;
+        for _ in 0..total {
+            let enemy = world.spawn((Enemy, SpawnedBy(wave), Health::new(0.0))).id();
+            world.write_message(Died { entity: enemy });
+        }
+        let _ = world.run_system_once(count_survival_kills);
+    }
+
+    /// Clear the current wave and sit out the intermission.
+    fn clear_wave(world: &mut World) {
+        kill_wave(world);
+        let _ = world.run_system_once(advance_survival_waves);
+        let at = world.resource::<SurvivalRun>().next_wave_tick.unwrap();
+        world.resource_mut::<SimTick>().0 = at;
+        let _ = world.run_system_once(advance_survival_waves);
+    }
+
+    fn hurt_player(world: &mut World, player: Entity) {
+        world.write_message(DamageEvent::new(player, 5.0));
+        let _ = world.run_system_once(track_survival_damage);
+    }
+
+    #[test]
+    fn cleared_waves_score_and_bring_bigger_ones() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+        let _ = world.run_system_once(advance_survival_waves);
+        assert_eq!(current_wave(&mut world).1.def.total, Some(3));
+
+        hurt_player(&mut world, player);
+        clear_wave(&mut world);
+
+        let run = world.resource::<SurvivalRun>().clone();
+        assert_eq!((run.wave, run.kills, run.score), (2, 3, 3 * 10 + 50));
+        assert_eq!(current_wave(&mut world).1.def.total, Some(5));
+        let earned: Vec<_> = world.resource_mut::<Messages<EarnCoins>>().drain().collect();
+        assert_eq!(earned, [EarnCoins { entity: player, amount: 10 }]);
+        let stats: Vec<_> = world.resource_mut::<Messages<RecordStat>>().drain().collect();
+        assert_eq!(
+            stats,
+            [RecordStat {
+                stat: Stat::WavesCleared,
+                amount: 1
+            }]
+        );
+        assert!(world.resource::<Messages<UnlockAchievement>>().is_empty(), "not flawless");
+    }
+
+    #[test]
+    fn later_waves_and_flawless_clears_score_more() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+        let _ = world.run_system_once(advance_survival_waves);
+        hurt_player(&mut world, player);
+        clear_wave(&mut world);
+        // Wave 2 at x1.25, untouched: 5 kills of 12.5 -> 13 points each, bonus
+        // 100 * 1.25 * 1.5.
+        clear_wave(&mut world);
+
+        let run = world.resource::<SurvivalRun>().clone();
+        assert_eq!(
+            run.cleared,
+            [
+                WaveResult {
+                    wave: 1,
+                    kills: 3,
+                    points: 80,
+                    multiplier: 1.0,
+                    flawless: false,
+                },
+                WaveResult {
+                    wave: 2,
+                    kills: 5,
+                    points: 5 * 13 + 188,
+                    multiplier: 1.25,
+                    flawless: true,
+                },
+            ]
+        );
+        assert_eq!(run.score, 80 + 5 * 13 + 188);
+        let unlocked: Vec<_> = world
+            .resource_mut::<Messages<UnlockAchievement>>()
+            .drain()
+            .map(|u| u.id)
+            .collect();
+        assert_eq!(unlocked, ["untouchable"]);
+    }
+
+    #[test]
+    fn the_quartermaster_trades_only_between_waves() {
+        let mut world = setup_world();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        let _ = world.run_system_once(advance_survival_waves);
+        kill_wave(&mut world);
+        let _ = world.run_system_once(advance_survival_waves);
+        assert!(world.resource::<SurvivalRun>().in_intermission());
+
+        // Halfway through the break the next wave is still coming.
+        world.resource_mut::<SimTick>().0 += 600;
+        let _ = world.run_system_once(advance_survival_waves);
+        let _ = world.run_system_once(staff_survival_quartermaster);
+        let _ = world.run_system_once(staff_survival_quartermaster);
+        let mut q = world.query_filtered::<(Entity, &Vendor), With<Quartermaster>>();
+        let (quartermaster, vendor) = q.single(&world).unwrap();
+        assert_eq!(vendor.shop, "quartermaster");
+        assert_eq!(world.resource::<SurvivalRun>().wave, 1);
+
+        world.resource_mut::<SimTick>().0 += 600;
+        let _ = world.run_system_once(advance_survival_waves);
+        let _ = world.run_system_once(staff_survival_quartermaster);
+        assert_eq!(world.resource::<SurvivalRun>().wave, 2);
+        assert!(world.entity(quartermaster).contains::<Despawning>());
+    }
+
+    #[test]
+    fn dying_ends_the_run_with_the_wave_reached() {
+        let mut world = setup_world();
+        let player = world.spawn(Player).id();
+        let _ = world.run_system_once(advance_survival_waves);
+        hurt_player(&mut world, player);
+        clear_wave(&mut world);
+        world.resource_mut::<SimTick>().0 = 3600;
+
+        world.write_message(PlayerDied {
+            entity: Entity::PLACEHOLDER,
+        });
+        let _ = world.run_system_once(end_survival_on_death);
+        world.write_message(PlayerDied {
+            entity: Entity::PLACEHOLDER,
+        });
+        let _ = world.run_system_once(end_survival_on_death);
+
+        let finished: Vec<_> = world.resource_mut::<Messages<RunFinished>>().drain().collect();
+        assert_eq!(
+            finished,
+            [RunFinished {
+                mode: "survival".into(),
+                score: 80,
+                wave_reached: Some(2),
+                completion_secs: None,
+            }]
+        );
+        let run = world.resource::<SurvivalRun>();
+        assert!(!run.active);
+        assert_eq!(run.survived_secs, Some(60.0));
+        assert_eq!(world.resource::<Messages<SurvivalEnded>>().len(), 1);
+    }
+}
@@ -0,0 +1,335 @@
+// src/game/modes/survival/summary.rs
+use bevy::prelude::*;
+
+use super::{SurvivalEnded, SurvivalRun};
+use crate::app::{GameMode, InputContext, InputContextStack, RunStarted};
+use crate::features::achievements::component::AchievementLibrary;
+use crate::features::achievements::systems::AchievementUnlocked;
+use crate::features::leaderboard::systems::RunRecorded;
+
+/// Cleared waves listed on the summary (the latest ones).
+const SUMMARY_WAVES: usize = 10;
+
+/// The end-of-run summary: whether it is up, and what it shows beyond
+/// `SurvivalRun`.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SurvivalSummary {
+    pub open: bool,
+    /// Leaderboard rank of the run, once it is recorded.
+    pub rank: Option<usize>,
+    /// Names of the achievements unlocked during the run.
+    pub achievements: Vec<String>,
+}
+
+impl SurvivalSummary {
+    /// Open or close; the summary owns the keyboard (`InputContext::Menu`) while up.
+    fn set_open(&mut self, contexts: &mut InputContextStack, open: bool) {
+        if self.open == open {
+            return;
+        }
+        self.open = open;
+        if open {
+            contexts.push(InputContext::Menu);
+        } else {
+            contexts.pop(InputContext::Menu);
+        }
+    }
+}
+
+/// Root of the summary screen.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SurvivalSummaryScreen;
+
+/// The summary's body text.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SurvivalSummaryText;
+
+/// Buttons of the summary screen.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryAction {
+    PlayAgain,
+    Close,
+}
+
+impl SummaryAction {
+    pub const ALL: [Self; 2] = [Self::PlayAgain, Self::Close];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::PlayAgain => "Play again",
+            Self::Close => "Close",
+        }
+    }
+}
+
+/// Startup: spawn the (hidden) summary screen.
+pub fn spawn_survival_summary(mut commands: Commands) {
+    commands
+        .spawn((
+            SurvivalSummaryScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.85)),
+            GlobalZIndex(8),
+            Visibility::Hidden,
+            Name::new("SurvivalSummary"),
+        ))
+        .with_children(|screen| {
+            screen.spawn((Text::new("Run Over"), TextFont::from_font_size(32.0)));
+            screen.spawn((
+                SurvivalSummaryText,
+                Text::new(""),
+                TextFont::from_font_size(18.0),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            screen
+                .spawn(Node {
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for action in SummaryAction::ALL {
+                        row.spawn((
+                            Button,
+                            action,
+                            Node {
+                                width: Val::Px(180.0),
+                                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((Text::new(action.label()), TextFont::from_font_size(18.0)));
+                        });
+                    }
+                });
+        });
+}
+
+/// Update: a new run closes the summary and forgets the last one's extras; a
+/// survival run ending opens it.
+pub fn open_survival_summary(
+    mut started: MessageReader<RunStarted>,
+    mut ended: MessageReader<SurvivalEnded>,
+    mut summary: ResMut<SurvivalSummary>,
+    mut contexts: ResMut<InputContextStack>,
+) {
+    if started.read().last().is_some() {
+        summary.set_open(&mut contexts, false);
+        summary.rank = None;
+        summary.achievements.clear();
+    }
+    if ended.read().last().is_some() {
+        summary.set_open(&mut contexts, true);
+    }
+}
+
+/// Update: note achievements unlocked during the run (and on its last frames,
+/// while the summary is up) and the leaderboard rank once the run is recorded.
+pub fn collect_survival_summary(
+    run: Res<SurvivalRun>,
+    library: Res<AchievementLibrary>,
+    mut summary: ResMut<SurvivalSummary>,
+    mut unlocked: MessageReader<AchievementUnlocked>,
+    mut recorded: MessageReader<RunRecorded>,
+) {
+    for achievement in unlocked.read() {
+        if run.active || summary.open {
+            let name = library
+                .get(&achievement.id)
+                .map_or_else(|| achievement.id.clone(), |def| def.name.clone());
+            summary.achievements.push(name);
+        }
+    }
+    for finished in recorded.read() {
+        if finished.result.mode == GameMode::Survival.id() {
+            summary.rank = Some(finished.rank);
+        }
+    }
+}
+
+/// Update: "Play again" starts a new survival run; both buttons close the summary.
+pub fn click_survival_summary(
+    q_buttons: Query<(&Interaction, &SummaryAction), Changed<Interaction>>,
+    mut summary: ResMut<SurvivalSummary>,
+    mut contexts: ResMut<InputContextStack>,
+    mut started: MessageWriter<RunStarted>,
+) {
+    for (interaction, action) in &q_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if *action == SummaryAction::PlayAgain {
+            started.write(RunStarted {
+                mode: GameMode::Survival,
+            });
+        }
+        summary.set_open(&mut contexts, false);
+    }
+}
+
+/// Update: show or hide the summary and fill in its text.
+pub fn update_survival_summary(
+    run: Res<SurvivalRun>,
+    summary: Res<SurvivalSummary>,
+    mut q_screen: Query<&mut Visibility, With<SurvivalSummaryScreen>>,
+    mut q_text: Query<&mut Text, With<SurvivalSummaryText>>,
+) {
+    if !run.is_changed() && !summary.is_changed() {
+        return;
+    }
+    let want = if summary.open {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut vis in &mut q_screen {
+        vis.set_if_neq(want);
+    }
+    if !summary.open {
+        return;
+    }
+    let text = summary_text(&run, &summary);
+    for mut body in &mut q_text {
+        if body.0 != text {
+            body.0.clone_from(&text);
+        }
+    }
+}
+
+/// Totals, time, rank, the latest cleared waves and the run's achievements.
+pub fn summary_text(run: &SurvivalRun, summary: &SurvivalSummary) -> String {
+    let mut lines = vec![format!(
+        "Wave {} reached  ·  {} kills  ·  {} pts",
+        run.wave, run.kills, run.score
+    )];
+    if let Some(secs) = run.survived_secs {
+        let secs = secs as u32;
+        lines.push(format!("Survived {}:{:02}", secs / 60, secs % 60));
+    }
+    lines.push(match summary.rank {
+        Some(rank) => format!("Leaderboard: #{rank}"),
+        None => "Leaderboard: -".into(),
+    });
+    let skipped = run.cleared.len().saturating_sub(SUMMARY_WAVES);
+    if skipped > 0 {
+        lines.push(format!("({skipped} earlier waves)"));
+    }
+    for wave in &run.cleared[skipped..] {
+        lines.push(format!(
+            "Wave {}: {} kills, {} pts (x{:.2}{})",
+            wave.wave,
+            wave.kills,
+            wave.points,
+            wave.multiplier,
+            if wave.flawless { ", flawless" } else { "" }
+        ));
+    }
+    if !summary.achievements.is_empty() {
+        lines.push(format!("Achievements: {}", summary.achievements.join(", ")));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Difficulty;
+    use crate::features::leaderboard::component::{GAME_VERSION, RunResult};
+    use crate::game::modes::survival::WaveResult;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<SurvivalRun>();
+        world.init_resource::<SurvivalSummary>();
+        world.init_resource::<AchievementLibrary>();
+        world.init_resource::<InputContextStack>();
+        world.init_resource::<Messages<RunStarted>>();
+        world.init_resource::<Messages<SurvivalEnded>>();
+        world.init_resource::<Messages<AchievementUnlocked>>();
+        world.init_resource::<Messages<RunRecorded>>();
+        world
+    }
+
+    #[test]
+    fn the_summary_opens_on_death_with_rank_and_achievements() {
+        let mut world = setup_world();
+        *world.resource_mut::<SurvivalRun>() = SurvivalRun {
+            wave: 2,
+            kills: 3,
+            score: 80,
+            survived_secs: Some(75.0),
+            cleared: vec![WaveResult {
+                wave: 1,
+                kills: 3,
+                points: 80,
+                multiplier: 1.0,
+                flawless: false,
+            }],
+            ..default()
+        };
+        world.write_message(SurvivalEnded);
+        world.write_message(AchievementUnlocked {
+            id: "untouchable".into(),
+        });
+        world.write_message(RunRecorded {
+            result: RunResult {
+                mode: "survival".into(),
+                score: 80,
+                wave_reached: Some(2),
+                completion_secs: None,
+                seed: 1,
+                difficulty: Difficulty::Normal,
+                version: GAME_VERSION.into(),
+                finished_at: 0,
+            },
+            rank: 3,
+        });
+        let _ = world.run_system_once(open_survival_summary);
+        let _ = world.run_system_once(collect_survival_summary);
+
+        let summary = world.resource::<SurvivalSummary>();
+        assert!(summary.open);
+        assert_eq!(world.resource::<InputContextStack>().top(), InputContext::Menu);
+        assert_eq!(
+            summary_text(world.resource::<SurvivalRun>(), summary),
+            "Wave 2 reached  ·  3 kills  ·  80 pts\n\
+             Survived 1:15\n\
+             Leaderboard: #3\n\
+             Wave 1: 3 kills, 80 pts (x1.00)\n\
+             Achievements: Untouchable"
+        );
+    }
+
+    #[test]
+    fn play_again_starts_a_new_run_and_closes_the_summary() {
+        let mut world = setup_world();
+        world.write_message(SurvivalEnded);
+        let _ = world.run_system_once(open_survival_summary);
+        world.spawn((Button, SummaryAction::PlayAgain, Interaction::Pressed));
+
+        let _ = world.run_system_once(click_survival_summary);
+
+        assert!(!world.resource::<SurvivalSummary>().open);
+        assert_eq!(world.resource::<InputContextStack>().top(), InputContext::Gameplay);
+        let started: Vec<_> = world.resource_mut::<Messages<RunStarted>>().drain().collect();
+        assert_eq!(
+            started,
+            [RunStarted {
+                mode: GameMode::Survival
+            }]
+        );
+    }
+}
@@ -0,0 +1,450 @@
+// src/game/modes/time_trial.rs
+use bevy::prelude::*;
+
+use super::{ModeEntity, ModeHud, mode_hud_text, set_ambient_spawners};
+use crate::app::{AppSet, GameMode, RunStarted, SimTick, TickRate};
+use crate::features::enemy::component::EnemySpawner;
+use crate::features::leaderboard::ghost::{GhostFrame, GhostRecorded, GhostRun, Ghosts};
+use crate::features::leaderboard::systems::RunFinished;
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::movement::component::{ExternalForce, Velocity};
+use crate::features::notifications::systems::Notify;
+use crate::features::player::component::Player;
+use crate::features::player::death::PlayerDied;
+use crate::features::race::component::{Race, RaceCheckpoint, RaceStatus, ticks_to_secs};
+use crate::features::race::systems::{RaceFinished, RaceInvalidated, pass_race_checkpoints};
+use crate::features::trigger::component::TriggerVolume;
+
+/// Where time trials start, the checkpoints to pass and the finish.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TimeTrialCourse {
+    /// The player is placed here when the run starts.
+    pub start: Vec3,
+    /// Centers of the checkpoint volumes, in the order they must be passed.
+    pub checkpoints: Vec<Vec3>,
+    /// Center of the finish volume.
+    pub finish: Vec3,
+    pub gate_half_extents: Vec3,
+}
+
+impl Default for TimeTrialCourse {
+    fn default() -> Self {
+        Self {
+            start: Vec3::new(0.0, 1.0, 0.0),
+            checkpoints: vec![Vec3::new(0.0, 1.0, -15.0), Vec3::new(10.0, 1.0, -28.0)],
+            finish: Vec3::new(0.0, 1.0, -40.0),
+            gate_half_extents: Vec3::new(4.0, 2.0, 1.0),
+        }
+    }
+}
+
+/// The player's pose on every tick of the current run (frame `0` = the start).
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct TimeTrialRecording(pub Vec<GhostFrame>);
+
+/// Translucent replay of the best run so far, racing alongside the player.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TimeTrialGhost(pub GhostRun);
+
+/// The finish volume of the course (its last `RaceCheckpoint`).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FinishLine;
+
+/// Time trial mode.
+///
+/// Scope:
+/// - the world's spawners sleep; the player starts at `TimeTrialCourse::start`
+///   and races through its checkpoints to the finish (a `Race`)
+/// - the clock counts fixed ticks (`SimTick`), so times don't depend on frame rate
+/// - reaching the finish records the time (`RunFinished`, ranked fastest first);
+///   missing a checkpoint or dying fails the run
+/// - the player's pose is recorded every tick; finished runs are offered as the
+///   mode's ghost (`GhostRecorded`, kept when fastest), and the best ghost so far
+///   races along as a translucent figure; its splits are the ones to beat
+/// - HUD: the running clock (splits are the race HUD's)
+pub struct TimeTrialPlugin;
+
+impl Plugin for TimeTrialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeTrialCourse>();
+        app.init_resource::<TimeTrialRecording>();
+        app.init_resource::<Race>();
+        app.init_resource::<Ghosts>();
+        app.init_resource::<MeshLibrary>();
+        app.add_message::<RunStarted>();
+        app.add_message::<GhostRecorded>();
+        app.add_message::<RaceFinished>();
+        app.add_message::<RaceInvalidated>();
+        app.add_message::<PlayerDied>();
+        app.add_message::<RunFinished>();
+        app.add_message::<Notify>();
+
+        app.add_systems(Startup, spawn_time_trial_hud);
+        app.add_systems(
+            Update,
+            (start_time_trial, spawn_time_trial_ghost)
+                .chain()
+                .after(AppSet::Input),
+        );
+        app.add_systems(
+            Update,
+            (fail_time_trial_on_death, update_time_trial_hud)
+                .chain()
+                .after(start_time_trial)
+                .run_if(in_state(GameMode::TimeTrial)),
+        );
+        // The finishing tick's pose is recorded before the race stops the clock.
+        app.add_systems(
+            FixedUpdate,
+            record_time_trial
+                .in_set(AppSet::FixedGameplay)
+                .before(pass_race_checkpoints)
+                .run_if(in_state(GameMode::TimeTrial)),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (finish_time_trial, move_time_trial_ghosts)
+                .chain()
+                .in_set(AppSet::FixedGameplay)
+                .after(pass_race_checkpoints)
+                .run_if(in_state(GameMode::TimeTrial)),
+        );
+    }
+}
+
+/// Startup: spawn the (hidden) time trial clock.
+pub fn spawn_time_trial_hud(mut commands: Commands) {
+    commands.spawn(mode_hud_text(GameMode::TimeTrial));
+}
+
+/// Update: a new run resets the race; a time trial run puts the world's spawners
+/// to sleep, lays out the checkpoints and finish and starts the clock with the
+/// player at the start.
+#[expect(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn start_time_trial(
+    mut commands: Commands,
+    course: Res<TimeTrialCourse>,
+    tick: Res<SimTick>,
+    mut started: MessageReader<RunStarted>,
+    mut race: ResMut<Race>,
+    mut recording: ResMut<TimeTrialRecording>,
+    q_spawners: Query<Entity, (With<EnemySpawner>, Without<ModeEntity>)>,
+    mut q_players: Query<
+        (&mut Transform, Option<&mut Velocity>, Option<&mut ExternalForce>),
+        With<Player>,
+    >,
+) {
+    let Some(started) = started.read().last() else {
+        return;
+    };
+    *race = Race::default();
+    recording.0.clear();
+    if started.mode != GameMode::TimeTrial {
+        return;
+    }
+    set_ambient_spawners(&mut commands, &q_spawners, false);
+
+    let gates = course.checkpoints.iter().chain([&course.finish]);
+    for (index, &center) in gates.enumerate() {
+        let mut gate = commands.spawn((
+            RaceCheckpoint { index },
+            ModeEntity(GameMode::TimeTrial),
+            TriggerVolume {
+                half_extents: course.gate_half_extents,
+            },
+            Transform::from_translation(center),
+        ));
+        if index == course.checkpoints.len() {
+            gate.insert((FinishLine, Name::new("Finish Line")));
+        } else {
+            gate.insert(Name::new(format!("Checkpoint {}", index + 1)));
+        }
+    }
+    *race = Race::start(*tick, course.checkpoints.len() + 1);
+
+    for (mut transform, velocity, external) in &mut q_players {
+        transform.translation = course.start;
+        recording.0.push(GhostFrame::from_transform(&transform));
+        if let Some(mut velocity) = velocity {
+            velocity.0 = Vec3::ZERO;
+        }
+        if let Some(mut external) = external {
+            *external = ExternalForce::default();
+        }
+    }
+}
+
+/// Update (after `start_time_trial`): a time trial run brings the best ghost of
+/// the mode along, and its splits become the ones to beat.
+pub fn spawn_time_trial_ghost(
+    mut commands: Commands,
+    ghosts: Res<Ghosts>,
+    rate: Res<TickRate>,
+    mut race: ResMut<Race>,
+    mut started: MessageReader<RunStarted>,
+    meshes: Res<MeshLibrary>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !started.read().last().is_some_and(|run| run.mode == GameMode::TimeTrial) {
+        return;
+    }
+    let Some(best) = ghosts.best(GameMode::TimeTrial.id()) else {
+        return;
+    };
+    // Splits are in ticks: only comparable at the rate they were recorded at.
+    if best.tick_hz == rate.0 && best.splits.len() == race.checkpoints {
+        race.reference.clone_from(&best.splits);
+    }
+    let start = best.frame_at(0.0).map(GhostFrame::to_transform).unwrap_or_default();
+    commands.spawn((
+        TimeTrialGhost(best.clone()),
+        ModeEntity(GameMode::TimeTrial),
+        Mesh3d(meshes.get(MeshPrimitive::Cube)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(0.6, 0.85, 1.0, 0.35),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        start,
+        Name::new(format!("Ghost ({:.2}s)", best.completion_secs)),
+    ));
+}
+
+/// FixedUpdate: record the player's pose on this tick.
+pub fn record_time_trial(
+    race: Res<Race>,
+    mut recording: ResMut<TimeTrialRecording>,
+    q_player: Query<&Transform, With<Player>>,
+) {
+    if !race.is_running() {
+        return;
+    }
+    if let Some(transform) = q_player.iter().next() {
+        recording.0.push(GhostFrame::from_transform(transform));
+    }
+}
+
+/// FixedUpdate: ghosts show where the best run was at this point of the clock.
+pub fn move_time_trial_ghosts(
+    tick: Res<SimTick>,
+    rate: Res<TickRate>,
+    race: Res<Race>,
+    mut q_ghosts: Query<(&TimeTrialGhost, &mut Transform)>,
+) {
+    let secs = ticks_to_secs(tick.0.saturating_sub(race.started_tick), *rate);
+    for (ghost, mut transform) in &mut q_ghosts {
+        if let Some(frame) = ghost.0.frame_at(secs) {
+            *transform = frame.to_transform();
+        }
+    }
+}
+
+/// FixedUpdate (after the race timing): a finished race records the time and
+/// offers the run as the new ghost; a missed checkpoint voids it.
+pub fn finish_time_trial(
+    rate: Res<TickRate>,
+    mut recording: ResMut<TimeTrialRecording>,
+    mut race_finished: MessageReader<RaceFinished>,
+    mut race_invalidated: MessageReader<RaceInvalidated>,
+    mut finished: MessageWriter<RunFinished>,
+    mut ghosts: MessageWriter<GhostRecorded>,
+    mut notify: MessageWriter<Notify>,
+) {
+    for missed in race_invalidated.read() {
+        recording.0.clear();
+        notify.write(Notify::info(format!(
+            "Missed checkpoint {}: run invalid",
+            missed.expected + 1
+        )));
+    }
+    for race in race_finished.read() {
+        let Some(&total) = race.splits.last() else {
+            continue;
+        };
+        let secs = ticks_to_secs(total, *rate);
+        finished.write(RunFinished {
+            mode: GameMode::TimeTrial.id().into(),
+            score: 0,
+            wave_reached: None,
+            completion_secs: Some(secs),
+        });
+        ghosts.write(GhostRecorded {
+            mode: GameMode::TimeTrial.id().into(),
+            run: GhostRun {
+                completion_secs: secs,
+                tick_hz: rate.0,
+                splits: race.splits.clone(),
+                frames: std::mem::take(&mut recording.0),
+            },
+        });
+        notify.write(Notify::info(format!("Finished in {secs:.2}s")));
+    }
+}
+
+/// Update: dying fails the run (nothing is recorded).
+pub fn fail_time_trial_on_death(
+    mut race: ResMut<Race>,
+    mut player_died: MessageReader<PlayerDied>,
+    mut notify: MessageWriter<Notify>,
+) {
+    if player_died.read().last().is_some() && race.is_running() {
+        race.status = RaceStatus::Invalidated;
+        notify.write(Notify::info("Time trial failed"));
+    }
+}
+
+/// Update: show the running clock (stopped at the finish, frozen when failed).
+pub fn update_time_trial_hud(
+    tick: Res<SimTick>,
+    rate: Res<TickRate>,
+    race: Res<Race>,
+    mut last_secs: Local<f32>,
+    mut q_hud: Query<(&ModeHud, &mut Text)>,
+) {
+    if race.status != RaceStatus::Invalidated {
+        *last_secs = race.elapsed_secs(*tick, *rate);
+    }
+    let text = format!("{:.2}s", *last_secs);
+    for (hud, mut line) in &mut q_hud {
+        if hud.0 == GameMode::TimeTrial && line.0 != text {
+            line.0.clone_from(&text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::race::systems::CheckpointPassed;
+    use crate::features::trigger::systems::TriggerEntered;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_world() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<TimeTrialCourse>();
+        world.init_resource::<TimeTrialRecording>();
+        world.init_resource::<Race>();
+        world.init_resource::<Ghosts>();
+        world.init_resource::<MeshLibrary>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.insert_resource(SimTick(100));
+        world.insert_resource(TickRate(60.0));
+        world.init_resource::<Messages<RunStarted>>();
+        world.init_resource::<Messages<TriggerEntered>>();
+        world.init_resource::<Messages<CheckpointPassed>>();
+        world.init_resource::<Messages<RaceFinished>>();
+        world.init_resource::<Messages<RaceInvalidated>>();
+        world.init_resource::<Messages<PlayerDied>>();
+        world.init_resource::<Messages<RunFinished>>();
+        world.init_resource::<Messages<GhostRecorded>>();
+        world.init_resource::<Messages<Notify>>();
+        let player = world
+            .spawn((Player, Transform::from_xyz(9.0, 0.0, 9.0), Velocity(Vec3::X)))
+            .id();
+        world.write_message(RunStarted {
+            mode: GameMode::TimeTrial,
+        });
+        let _ = world.run_system_once(start_time_trial);
+        (world, player)
+    }
+
+    /// The player enters gate `index` (the last one is the finish) on `tick`.
+    fn pass_gate(world: &mut World, player: Entity, index: usize, tick: u64) {
+        let volume = world
+            .query::<(Entity, &RaceCheckpoint)>()
+            .iter(world)
+            .find(|(_, gate)| gate.index == index)
+            .unwrap()
+            .0;
+        world.resource_mut::<SimTick>().0 = tick;
+        world.write_message(TriggerEntered {
+            volume,
+            entity: player,
+        });
+        let _ = world.run_system_once(pass_race_checkpoints);
+        let _ = world.run_system_once(finish_time_trial);
+    }
+
+    #[test]
+    fn passing_every_checkpoint_records_the_tick_exact_time() {
+        let (mut world, player) = setup_world();
+        assert_eq!(
+            world.get::<Transform>(player).unwrap().translation,
+            TimeTrialCourse::default().start
+        );
+        assert_eq!(world.get::<Velocity>(player).unwrap().0, Vec3::ZERO);
+        assert_eq!(world.query::<&FinishLine>().iter(&world).count(), 1);
+
+        pass_gate(&mut world, player, 0, 160);
+        pass_gate(&mut world, player, 1, 190);
+        assert!(world.resource::<Messages<RunFinished>>().is_empty());
+        pass_gate(&mut world, player, 2, 250);
+
+        let finished: Vec<_> = world.resource_mut::<Messages<RunFinished>>().drain().collect();
+        assert_eq!(
+            finished,
+            [RunFinished {
+                mode: "time_trial".into(),
+                score: 0,
+                wave_reached: None,
+                completion_secs: Some(2.5),
+            }]
+        );
+        assert_eq!(world.resource::<Race>().splits, vec![60, 90, 150]);
+    }
+
+    #[test]
+    fn missing_a_checkpoint_or_dying_voids_the_run() {
+        let (mut world, player) = setup_world();
+        pass_gate(&mut world, player, 0, 160);
+        pass_gate(&mut world, player, 2, 250);
+        assert_eq!(world.resource::<Race>().status, RaceStatus::Invalidated);
+        assert!(world.resource::<Messages<RunFinished>>().is_empty());
+        assert!(world.resource::<Messages<GhostRecorded>>().is_empty());
+
+        let (mut world, player) = setup_world();
+        world.write_message(PlayerDied { entity: player });
+        let _ = world.run_system_once(fail_time_trial_on_death);
+        pass_gate(&mut world, player, 0, 160);
+        assert!(world.resource::<Race>().splits.is_empty());
+    }
+
+    #[test]
+    fn finished_runs_become_ghosts_that_replay_on_the_next_attempt() {
+        let (mut world, player) = setup_world();
+        for step in 1..=3 {
+            world.resource_mut::<SimTick>().0 = 100 + step;
+            world.get_mut::<Transform>(player).unwrap().translation.z = -(step as f32);
+            let _ = world.run_system_once(record_time_trial);
+        }
+        pass_gate(&mut world, player, 0, 101);
+        pass_gate(&mut world, player, 1, 102);
+        pass_gate(&mut world, player, 2, 103);
+
+        let recorded: Vec<_> = world.resource_mut::<Messages<GhostRecorded>>().drain().collect();
+        let ghost = recorded[0].run.clone();
+        assert_eq!(ghost.frames.len(), 4, "start + one frame per tick");
+        assert_eq!(ghost.completion_secs, 3.0 / 60.0);
+        assert_eq!(ghost.splits, vec![1, 2, 3]);
+        world
+            .resource_mut::<Ghosts>()
+            .offer(GameMode::TimeTrial.id(), ghost);
+
+        // Next attempt: the ghost starts at the start and follows the recording,
+        // and its splits are the ones to beat.
+        world.write_message(RunStarted {
+            mode: GameMode::TimeTrial,
+        });
+        let _ = world.run_system_once(start_time_trial);
+        let _ = world.run_system_once(spawn_time_trial_ghost);
+        assert_eq!(world.resource::<Race>().reference, vec![1, 2, 3]);
+        world.resource_mut::<SimTick>().0 += 2;
+        let _ = world.run_system_once(move_time_trial_ghosts);
+
+        let mut q_ghost = world.query_filtered::<&Transform, With<TimeTrialGhost>>();
+        let at = q_ghost.single(&world).unwrap().translation;
+        assert_eq!(at.z, -2.0);
+    }
+}
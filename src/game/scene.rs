@@ -1,32 +1,53 @@
 // src/game/scene.rs
 use bevy::prelude::*;
 
+use crate::features::camera::component::{FollowCamera, OrbitCamera};
+use crate::features::camera::pip::PipCamera;
+use crate::features::camera::rig::{CameraMode, CameraRig};
+use crate::features::collision::component::Collider;
+use crate::features::materials::component::MaterialLibrary;
+use crate::features::meshes::component::{MeshLibrary, MeshPrimitive};
+use crate::features::surface::component::SurfaceMaterial;
+use crate::features::weather::component::Wettable;
+
 /// Sets up a minimal 3D scene:
 /// - a ground base
 /// - a visible cube (so you can immediately see lighting / depth)
 /// - one point light (shadows on by default)
-/// - one 3D camera looking at the origin
+/// - one 3D camera following the player, plus a security camera feeding the
+///   picture-in-picture panel
+/// - box colliders on the ground and cube (camera obstruction, later movement)
+/// - ground and cube use the shared "ground" / "accent" materials (`MaterialLibrary`)
+///   and the shared disc / cube meshes (`MeshLibrary`)
+/// - the ground gets glossy in the rain (`Wettable`); ground and cube carry a
+///   `SurfaceMaterial` for footsteps and impacts
 ///
 /// Bevy 0.18 note:
 /// The official examples use `Mesh3d` + `MeshMaterial3d` instead of `PbrBundle`.
 /// This is the most "current" style and keeps the spawn tuples minimal. :contentReference[oaicite:0]{index=0}
 pub fn setup_scene(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    meshes: Res<MeshLibrary>,
+    library: Res<MaterialLibrary>,
 ) {
-    // Ground base (a circle rotated to lie on the XZ plane).
+    // Ground base (the flat unit disc, radius 6).
     commands.spawn((
-        Mesh3d(meshes.add(Circle::new(6.0))),
-        MeshMaterial3d(materials.add(Color::WHITE)),
-        Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        Mesh3d(meshes.get(MeshPrimitive::Disc)),
+        MeshMaterial3d(library.get("ground")),
+        Transform::from_scale(Vec3::new(6.0, 1.0, 6.0)),
+        // Thin slab around y = 0 (collider half-extents are world-axis aligned).
+        Collider::cuboid(Vec3::new(6.0, 0.05, 6.0)),
+        Wettable::default(),
+        SurfaceMaterial::Grass,
     ));
 
     // A cube at the origin, raised by half its height so it rests on the ground.
     commands.spawn((
-        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
-        MeshMaterial3d(materials.add(Color::srgb_u8(124, 144, 255))),
+        Mesh3d(meshes.get(MeshPrimitive::Cube)),
+        MeshMaterial3d(library.get("accent")),
         Transform::from_xyz(0.0, 0.5, 0.0),
+        Collider::cuboid(Vec3::splat(0.5)),
+        SurfaceMaterial::Stone,
     ));
 
     // Light.
@@ -38,9 +59,22 @@ pub fn setup_scene(
         Transform::from_xyz(4.0, 8.0, 4.0),
     ));
 
+    // Security camera overlooking the pond (picture-in-picture feed).
+    commands.spawn((
+        Camera3d::default(),
+        PipCamera {
+            label: "Pond camera".into(),
+        },
+        Transform::from_xyz(4.0, 4.0, 14.0).looking_at(Vec3::new(0.0, 0.5, 9.0), Vec3::Y),
+        Name::new("Security Camera"),
+    ));
+
     // Camera.
     commands.spawn((
         Camera3d::default(),
+        FollowCamera::default(),
+        OrbitCamera::default(),
+        CameraRig::new(CameraMode::Orbit),
         Transform::from_xyz(-2.5, 4.5, 9.0).looking_at(Vec3::ZERO, Vec3::Y),
     ));
 }
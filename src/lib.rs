@@ -1,20 +1,106 @@
+use std::time::Duration;
+
+use bevy::app::ScheduleRunnerPlugin;
 use bevy::prelude::*;
 
+use features::character_material::component::CharacterMaterial;
+use features::net::SnapshotInterpolation;
+
 /// Public module tree used by integration tests and the binary.
 pub mod app;
 pub mod features;
 pub mod game;
 
+/// What kind of process the app is.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AppMode {
+    /// Windowed game.
+    #[default]
+    Client,
+    /// Headless authoritative simulation: no window, renderer or audio, and the
+    /// main loop paced at the server tick rate.
+    DedicatedServer,
+}
+
+/// Startup options of `build_app_with`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AppSettings {
+    pub mode: AppMode,
+    /// `FixedUpdate` rate of clients.
+    pub client_tick_hz: f64,
+    /// `FixedUpdate` rate of dedicated servers; clients interpolate snapshots sent at
+    /// this rate.
+    pub server_tick_hz: f64,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            mode: AppMode::Client,
+            client_tick_hz: app::CLIENT_TICK_HZ,
+            server_tick_hz: app::SERVER_TICK_HZ,
+        }
+    }
+}
+
+impl AppSettings {
+    /// A dedicated server simulating at `tick_hz`.
+    pub fn dedicated_server(tick_hz: f64) -> Self {
+        Self {
+            mode: AppMode::DedicatedServer,
+            server_tick_hz: tick_hz,
+            ..default()
+        }
+    }
+
+    /// `FixedUpdate` rate of this process.
+    pub fn tick_hz(&self) -> f64 {
+        match self.mode {
+            AppMode::Client => self.client_tick_hz,
+            AppMode::DedicatedServer => self.server_tick_hz,
+        }
+    }
+}
+
 /// Build the game `App` with all required plugins.
 ///
 /// Contract:
 /// - This is the one place where the "real game wiring" lives.
 /// - Both `main.rs` and tests can use this to avoid drift.
 pub fn build_app() -> App {
+    build_app_with(AppSettings::default())
+}
+
+/// `build_app` for a given mode and tick rates.
+pub fn build_app_with(settings: AppSettings) -> App {
     let mut app = App::new();
 
-    app.add_plugins(DefaultPlugins)
-        .add_plugins(app::AppPlugin)
+    // Before any plugin builds: features derive their file paths from it, and
+    // `AppPlugin` its fixed timestep.
+    app.insert_resource(app::StoragePaths::from_env());
+    app.insert_resource(settings.mode);
+    app.insert_resource(app::TickRate(settings.tick_hz()));
+    app.insert_resource(SnapshotInterpolation {
+        server_tick_hz: settings.server_tick_hz,
+        ..default()
+    });
+
+    match settings.mode {
+        AppMode::Client => {
+            app.add_plugins(DefaultPlugins);
+        }
+        AppMode::DedicatedServer => {
+            // Wake once per server tick; asset storages and input are stand-ins for
+            // what the windowed plugins would provide.
+            let tick = Duration::from_secs_f64(1.0 / settings.server_tick_hz.max(1.0));
+            app.add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(tick)));
+            app.init_resource::<Assets<Mesh>>();
+            app.init_resource::<Assets<StandardMaterial>>();
+            app.init_resource::<Assets<CharacterMaterial>>();
+            app.init_resource::<ButtonInput<KeyCode>>();
+        }
+    }
+    app.add_plugins(app::AppPlugin)
         .add_plugins(game::GamePlugin);
 
     app
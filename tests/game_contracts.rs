@@ -1,7 +1,17 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
-use to_be_free::app::AppPlugin;
+use bevy::time::TimeUpdateStrategy;
+use to_be_free::app::{AppPlugin, CLIENT_TICK_HZ, InputContext, InputContextStack, SimTick, TickRate};
+use to_be_free::features::FeaturesPlugin;
+use to_be_free::features::character_material::component::CharacterMaterial;
+use to_be_free::features::minimap::component::ExplorationFile;
+use to_be_free::features::movement::component::MoveInput;
 use to_be_free::features::player::PlayerPlugin;
 use to_be_free::features::player::component::Player;
+use to_be_free::features::settings::component::{SettingsFile, UserSettings};
+use to_be_free::features::tutorial::component::TutorialFile;
+use to_be_free::{AppSettings, build_app_with};
 
 #[test]
 fn player_plugin_runs_with_app_prereqs_and_spawns_player() {
@@ -16,14 +26,122 @@ fn player_plugin_runs_with_app_prereqs_and_spawns_player() {
     // - spawn_player needs asset storages
     app.insert_resource(Assets::<Mesh>::default());
     app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(Assets::<CharacterMaterial>::default());
     // - read_player_input needs keyboard input resource (normally created by InputPlugin)
     app.insert_resource(ButtonInput::<KeyCode>::default());
 
-    // Run one frame: Startup should spawn the player, Update will run too (now safe).
+    // Run one frame: Startup requests the default player, Update spawns it.
+    app.update();
+
+    let world = app.world_mut();
+    let count = world.query::<&Player>().iter(world).count();
+
+    assert_eq!(count, 1, "the auto-spawn should create exactly one Player");
+}
+
+#[test]
+fn console_context_suppresses_player_movement() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AppPlugin);
+    app.add_plugins(PlayerPlugin);
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(Assets::<CharacterMaterial>::default());
+    app.insert_resource(ButtonInput::<KeyCode>::default());
+    app.update();
+
+    let move_input = |app: &mut App| {
+        let world = app.world_mut();
+        world.query_filtered::<&MoveInput, With<Player>>().single(world).unwrap().0
+    };
+
+    app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyW);
+    app.update();
+    assert_ne!(move_input(&mut app), Vec3::ZERO, "W walks in gameplay");
+
+    // Key still held, but the console took the keyboard.
+    app.world_mut().resource_mut::<InputContextStack>().push(InputContext::Console);
     app.update();
+    assert_eq!(move_input(&mut app), Vec3::ZERO);
+
+    app.world_mut().resource_mut::<InputContextStack>().pop(InputContext::Console);
+    app.update();
+    assert_ne!(move_input(&mut app), Vec3::ZERO);
+}
+
+#[test]
+fn features_plugin_composes_and_runs_headless() {
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AppPlugin);
+    app.add_plugins(FeaturesPlugin);
+
+    // Same headless prerequisites as above (normally provided by DefaultPlugins).
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(Assets::<CharacterMaterial>::default());
+    app.insert_resource(ButtonInput::<KeyCode>::default());
+
+    // Saves stay out of the working tree. The player spawns inside a tutorial zone,
+    // and a settings file that finished onboarding skips the first-run flow (which
+    // would pause the simulation).
+    let saves = std::env::temp_dir().join(format!("to_be_free_contracts_{}", std::process::id()));
+    app.insert_resource(TutorialFile(saves.join("tutorials.ron")));
+    app.insert_resource(ExplorationFile(saves.join("exploration.ron")));
+    let settings = saves.join("settings.ron");
+    UserSettings {
+        onboarded: true,
+        ..Default::default()
+    }
+    .save(&settings)
+    .unwrap();
+    app.insert_resource(SettingsFile(settings));
+
+    // Advance virtual time deterministically so FixedUpdate actually runs.
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(20)));
+
+    // A few frames: every feature's systems must find the resources they need.
+    for _ in 0..5 {
+        app.update();
+    }
 
     let world = app.world_mut();
     let count = world.query::<&Player>().iter(world).count();
+    assert_eq!(count, 1, "FeaturesPlugin should still spawn exactly one Player");
+    let _ = std::fs::remove_dir_all(&saves);
+}
+
+#[test]
+fn dedicated_server_ticks_at_its_own_rate_headless() {
+    let mut app = build_app_with(AppSettings::dedicated_server(30.0));
+    // Files stay out of the working tree; finished onboarding keeps the simulation
+    // running.
+    let saves = std::env::temp_dir().join(format!("to_be_free_server_{}", std::process::id()));
+    app.insert_resource(TutorialFile(saves.join("tutorials.ron")));
+    app.insert_resource(ExplorationFile(saves.join("exploration.ron")));
+    let settings = saves.join("settings.ron");
+    UserSettings {
+        onboarded: true,
+        ..Default::default()
+    }
+    .save(&settings)
+    .unwrap();
+    app.insert_resource(SettingsFile(settings));
+
+    let timestep = app.world().resource::<Time<Fixed>>().timestep();
+    assert_eq!(timestep, Duration::from_secs_f64(1.0 / 30.0));
+    assert_eq!(app.world().resource::<TickRate>().0, 30.0);
+
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+    for _ in 0..4 {
+        app.update();
+    }
+    // 3 frames of 100 ms after the clock starts: 9 server ticks.
+    assert_eq!(app.world().resource::<SimTick>().0, 9);
 
-    assert_eq!(count, 1, "Startup should spawn exactly one Player");
+    let client = AppSettings::default();
+    assert_eq!(client.tick_hz(), CLIENT_TICK_HZ);
+    let _ = std::fs::remove_dir_all(&saves);
 }
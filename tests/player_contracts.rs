@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use to_be_free::features::player::{input::PlayerKeybindings, PlayerPlugin};
+use to_be_free::features::player::{PlayerPlugin, death::RespawnSettings, input::PlayerKeybindings};
 
 #[test]
 fn player_plugin_inserts_keybindings_resource() {
@@ -12,3 +12,15 @@ fn player_plugin_inserts_keybindings_resource() {
         "PlayerPlugin must insert PlayerKeybindings so input system can run."
     );
 }
+
+#[test]
+fn player_plugin_inserts_respawn_settings_resource() {
+    let mut app = App::new();
+
+    app.add_plugins(PlayerPlugin);
+
+    assert!(
+        app.world().contains_resource::<RespawnSettings>(),
+        "PlayerPlugin must insert RespawnSettings so the respawn systems can run."
+    );
+}
@@ -1,5 +1,18 @@
+use std::collections::BTreeMap;
 use std::env;
+use std::fs;
+use std::path::Path;
 use std::process::{Command, ExitCode};
+use std::time::SystemTime;
+
+/// Criterion output directory (relative to the workspace root).
+const CRITERION_DIR: &str = "target/criterion";
+
+/// Mean slowdown against the baseline that counts as a regression (5%).
+const REGRESSION_THRESHOLD: f64 = 0.05;
+
+/// Where `cargo llvm-cov --html` writes its report.
+const COVERAGE_HTML: &str = "target/llvm-cov/html/index.html";
 
 /// Supported `cargo xtask` subcommands.
 ///
@@ -17,6 +30,16 @@ enum XTaskCmd {
 
     /// Run `cargo test` first, then `cargo run` if tests pass.
     Run,
+
+    /// Run `cargo test` first, then the criterion benches if tests pass.
+    Bench,
+
+    /// Run the tests under `cargo-llvm-cov`; report coverage only if they pass.
+    Coverage,
+
+    /// Run `cargo test` first, then write a seeded scene file if tests pass (the
+    /// `gen_scene` example parses the options and does the generating).
+    GenScene,
 }
 
 /// Entry point for the `xtask` helper binary.
@@ -73,7 +96,263 @@ fn main() -> ExitCode {
 
             run_cargo_owned(&cargo_args)
         }
+
+        // `cargo xtask bench [--save <baseline> | --compare <baseline>]`
+        // Contract: benchmarks are only meaningful (and only run) if tests pass.
+        XTaskCmd::Bench => {
+            let Some(options) = parse_bench_args(args) else {
+                print_usage();
+                return ExitCode::from(2);
+            };
+            if !run_cargo_ok(&["test"]) {
+                return ExitCode::from(1);
+            }
+
+            let started = SystemTime::now();
+            let mut cargo_args = vec!["bench".to_string(), "--benches".to_string(), "--".to_string()];
+            if let Some(name) = &options.save {
+                cargo_args.extend(["--save-baseline".to_string(), name.clone()]);
+            }
+            if let Some(name) = &options.compare {
+                cargo_args.extend(["--baseline".to_string(), name.clone()]);
+            }
+            let status = run_cargo_owned(&cargo_args);
+            if status != ExitCode::SUCCESS {
+                return status;
+            }
+
+            match &options.compare {
+                Some(baseline) => report_regressions(baseline, started),
+                None => ExitCode::SUCCESS,
+            }
+        }
+
+        // `cargo xtask coverage [--install]`
+        // Contract: the instrumented test run *is* the test gate; no report if it fails.
+        XTaskCmd::Coverage => {
+            let install = match args.next().as_deref() {
+                None => false,
+                Some("--install") => true,
+                Some(other) => {
+                    eprintln!("unknown coverage option: {other}");
+                    print_usage();
+                    return ExitCode::from(2);
+                }
+            };
+            if !llvm_cov_available() {
+                if !install {
+                    eprintln!("cargo-llvm-cov is not installed.");
+                    eprintln!("install it with `cargo install cargo-llvm-cov` (plus `rustup component add llvm-tools-preview`),");
+                    eprintln!("or rerun as `cargo xtask coverage --install`.");
+                    return ExitCode::from(1);
+                }
+                if !run_cargo_ok(&["install", "cargo-llvm-cov"]) {
+                    return ExitCode::from(1);
+                }
+            }
+
+            if !run_cargo_ok(&["llvm-cov", "--workspace", "--html"]) {
+                return ExitCode::from(1);
+            }
+            eprintln!("html report: {COVERAGE_HTML}");
+
+            let Some(summary) = capture_cargo(&["llvm-cov", "report", "--summary-only"]) else {
+                return ExitCode::from(1);
+            };
+            print_module_coverage(&summary);
+            ExitCode::SUCCESS
+        }
+
+        // `cargo xtask gen-scene --size <units> --seed <seed> [--out <path>]`
+        // Contract: scenes come from tested generator code; the example checks the options.
+        XTaskCmd::GenScene => {
+            if !run_cargo_ok(&["test"]) {
+                return ExitCode::from(1);
+            }
+            let mut cargo_args = vec![
+                "run".to_string(),
+                "--release".to_string(),
+                "--example".to_string(),
+                "gen_scene".to_string(),
+                "--".to_string(),
+            ];
+            cargo_args.extend(args);
+            run_cargo_owned(&cargo_args)
+        }
+    }
+}
+
+/// Whether the `cargo llvm-cov` subcommand can be run.
+fn llvm_cov_available() -> bool {
+    Command::new("cargo")
+        .args(["llvm-cov", "--version"])
+        .output()
+        .is_ok_and(|out| out.status.success())
+}
+
+/// Line coverage per module, aggregated from `cargo llvm-cov report --summary-only`.
+///
+/// A module is `features/<name>` for feature files, otherwise the first path
+/// component under `src/` (`app`, `game`, ...). Files of other workspace crates are
+/// prefixed with the crate's directory (`xtask/main`).
+fn print_module_coverage(summary: &str) {
+    // module -> (lines, missed lines)
+    let mut modules: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+    for line in summary.lines() {
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        // Filename, Regions, Missed, Cover, Functions, Missed, Executed, Lines, Missed, Cover, ...
+        let (Some(file), Some(lines), Some(missed)) = (columns.first(), columns.get(7), columns.get(8)) else {
+            continue;
+        };
+        let (Ok(lines), Ok(missed)) = (lines.parse::<u64>(), missed.parse::<u64>()) else {
+            continue;
+        };
+        if !file.ends_with(".rs") {
+            continue;
+        }
+        let entry = modules.entry(module_of(file)).or_default();
+        entry.0 += lines;
+        entry.1 += missed;
+    }
+
+    eprintln!("{:<32} {:>7} {:>7}", "module", "lines", "cover");
+    for (module, (lines, missed)) in &modules {
+        let cover = if *lines == 0 {
+            100.0
+        } else {
+            (lines - missed) as f64 / *lines as f64 * 100.0
+        };
+        eprintln!("{module:<32} {lines:>7} {cover:>6.1}%");
+    }
+}
+
+/// Module name of a report path such as `src/features/player/movement.rs`.
+///
+/// Paths are keyed relative to the workspace root, so `xtask/src/main.rs` and
+/// `src/main.rs` stay apart.
+fn module_of(file: &str) -> String {
+    let file = file.replace('\\', "/");
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(|root| format!("{}/", root.display()).replace('\\', "/"))
+        .unwrap_or_default();
+    let file = file.strip_prefix(&root).unwrap_or(&file);
+    let (crate_dir, relative) = file
+        .split_once("src/")
+        .map_or(("", file), |(dir, rest)| (dir.trim_end_matches('/'), rest));
+    let mut parts = relative.split('/');
+    let module = match (parts.next(), parts.next()) {
+        (Some("features"), Some(feature)) if feature.ends_with(".rs") => "features".to_string(),
+        (Some("features"), Some(feature)) => format!("features/{feature}"),
+        (Some(first), _) => first.trim_end_matches(".rs").to_string(),
+        (None, _) => relative.to_string(),
+    };
+    if crate_dir.is_empty() {
+        module
+    } else {
+        format!("{crate_dir}/{module}")
+    }
+}
+
+/// Options of `cargo xtask bench`.
+#[derive(Debug, Default)]
+struct BenchOptions {
+    /// Store this run as a named criterion baseline.
+    save: Option<String>,
+    /// Compare this run against a named baseline and report regressions.
+    compare: Option<String>,
+}
+
+/// Parse `--save <name>` or `--compare <name>` (anything else, or both, is a usage
+/// error: criterion can't save and compare baselines in one run).
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Option<BenchOptions> {
+    let mut options = BenchOptions::default();
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--save" => options.save = Some(args.next()?),
+            "--compare" => options.compare = Some(args.next()?),
+            _ => {
+                eprintln!("unknown bench option: {flag}");
+                return None;
+            }
+        }
     }
+    if options.save.is_some() && options.compare.is_some() {
+        eprintln!("bench takes --save or --compare, not both");
+        return None;
+    }
+    Some(options)
+}
+
+/// Print every benchmark whose mean changed by more than the threshold in this
+/// run, and fail if any got slower.
+///
+/// Reads criterion's `change/estimates.json` files written after `started`, so
+/// leftovers from older comparisons are ignored.
+fn report_regressions(baseline: &str, started: SystemTime) -> ExitCode {
+    let mut changes = Vec::new();
+    collect_changes(Path::new(CRITERION_DIR), started, &mut changes);
+    changes.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let regressions: Vec<_> = changes
+        .iter()
+        .filter(|(_, change)| *change > REGRESSION_THRESHOLD)
+        .collect();
+    eprintln!("compared {} benchmarks against baseline `{baseline}`", changes.len());
+    for (name, change) in &changes {
+        if change.abs() > REGRESSION_THRESHOLD {
+            let verdict = if *change > 0.0 { "REGRESSED" } else { "improved" };
+            eprintln!("  {verdict:>9} {:+6.1}%  {name}", change * 100.0);
+        }
+    }
+
+    if regressions.is_empty() {
+        eprintln!("no regressions over {:.0}%", REGRESSION_THRESHOLD * 100.0);
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{} regression(s) over {:.0}%", regressions.len(), REGRESSION_THRESHOLD * 100.0);
+        ExitCode::from(1)
+    }
+}
+
+/// Walk the criterion tree for fresh `<bench>/change/estimates.json` files and
+/// collect `(bench name, relative mean change)`.
+fn collect_changes(dir: &Path, started: SystemTime, out: &mut Vec<(String, f64)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let estimates = path.join("change").join("estimates.json");
+        let fresh = fs::metadata(&estimates)
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| modified >= started);
+        if fresh
+            && let Some(change) = fs::read_to_string(&estimates).ok().and_then(|json| mean_change(&json))
+        {
+            let name = path
+                .strip_prefix(CRITERION_DIR)
+                .unwrap_or(&path)
+                .display()
+                .to_string();
+            out.push((name, change));
+        }
+        collect_changes(&path, started, out);
+    }
+}
+
+/// `mean.point_estimate` from a criterion `change/estimates.json`.
+///
+/// A targeted scan rather than a JSON parser: xtask stays dependency-free.
+fn mean_change(json: &str) -> Option<f64> {
+    let mean = &json[json.find("\"mean\"")?..];
+    let value = &mean[mean.find("\"point_estimate\"")? + "\"point_estimate\"".len()..];
+    let value = value.trim_start().strip_prefix(':')?.trim_start();
+    let end = value.find([',', '}']).unwrap_or(value.len());
+    value[..end].trim().parse().ok()
 }
 
 /// Print user-facing usage instructions.
@@ -82,6 +361,9 @@ fn main() -> ExitCode {
 /// this is a developer tool, not a CLI framework.
 fn print_usage() {
     eprintln!("usage: cargo xtask <test|build|run> [-- <args forwarded to cargo>]");
+    eprintln!("       cargo xtask bench [--save <baseline> | --compare <baseline>]");
+    eprintln!("       cargo xtask coverage [--install]");
+    eprintln!("       cargo xtask gen-scene --size <units> --seed <seed> [--out <path>]");
 }
 
 /// Parse a raw string into an `XTaskCmd`.
@@ -93,6 +375,9 @@ fn parse_cmd(s: &str) -> Option<XTaskCmd> {
         "test" => Some(XTaskCmd::Test),
         "build" => Some(XTaskCmd::Build),
         "run" => Some(XTaskCmd::Run),
+        "bench" => Some(XTaskCmd::Bench),
+        "coverage" => Some(XTaskCmd::Coverage),
+        "gen-scene" => Some(XTaskCmd::GenScene),
         _ => None,
     }
 }
@@ -128,6 +413,21 @@ fn run_cargo(args: &[&str]) -> ExitCode {
     }
 }
 
+/// Run a Cargo command and capture its stdout (stderr still goes to the terminal).
+///
+/// Returns `None` if the command failed; the failure has already been printed.
+fn capture_cargo(args: &[&str]) -> Option<String> {
+    eprintln!("> cargo {}", args.join(" "));
+    match Command::new("cargo").args(args).stderr(std::process::Stdio::inherit()).output() {
+        Ok(out) if out.status.success() => Some(String::from_utf8_lossy(&out.stdout).into_owned()),
+        Ok(_) => None,
+        Err(err) => {
+            eprintln!("failed to run cargo: {err}");
+            None
+        }
+    }
+}
+
 /// Same as `run_cargo`, but accepts owned arguments.
 ///
 /// This exists to support argument forwarding (e.g. `--release`)